@@ -19,6 +19,7 @@ export 'src/embeddings/model_manager.dart'
 // Advanced/low-level access to generated bindings (optional).
 export 'src/rust/api/tokenizer.dart' show EncodeOutput, TokenOffsets;
 export 'src/rust/api/ort.dart' show initOrt;
+export 'src/rust/api/error.dart' show ErrorKind, FlutterEmbedderError;
 
 // init wrapper
 import 'dart:ffi' as ffi;
@@ -0,0 +1,52 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `compute`, `mel_filters`, `n_mels`, `new`, `parse`, `reflect`, `resample`, `sample`, `sinc`, `to_hz`, `to_mel`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `MelConfig`, `MelScale`, `MelSpectrogram`, `WavFormat`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            /// Reads a PCM (8/16/24/32-bit integer or 32-bit float) WAV file, mixing
+/// multiple channels down to mono.
+AudioClip  readWav({required String path }) => RustLib.instance.api.crateApiAudioReadWav(path: path);
+
+/// `read_wav` from the bytes of a WAV file.
+AudioClip  decodeWav({required List<int> bytes }) => RustLib.instance.api.crateApiAudioDecodeWav(bytes: bytes);
+
+/// Resamples mono `samples` from `from_rate` to `to_rate` Hz with a
+/// windowed-sinc filter, which also removes frequencies above the new
+/// Nyquist limit when downsampling.
+Float32List  resampleAudio({required List<double> samples , required int fromRate , required int toRate }) => RustLib.instance.api.crateApiAudioResampleAudio(samples: samples, fromRate: fromRate, toRate: toRate);
+
+            /// Mono PCM audio with samples in `[-1, 1]`.
+class AudioClip  {
+                final Float32List samples;
+final int sampleRate;
+
+                const AudioClip({required this.samples ,required this.sampleRate ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => samples.hashCode^sampleRate.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AudioClip &&
+                runtimeType == other.runtimeType
+                && samples == other.samples&& sampleRate == other.sampleRate;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,119 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../error.dart';
+import '../logging.dart';
+import '../ort.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `decode`, `detect_language`, `encode`, `feed`, `float_tensor`, `from_parts`, `language_tokens`, `token_id`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `EncoderOutput`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WhisperTranscriber>>
+                abstract class WhisperTranscriber implements RustOpaqueInterface {
+                    static WhisperTranscriber  create({required String encoderModelPath , required String decoderModelPath , required String tokenizerPath })=>RustLib.instance.api.crateApiAudioWhisperWhisperTranscriberCreate(encoderModelPath: encoderModelPath, decoderModelPath: decoderModelPath, tokenizerPath: tokenizerPath);
+
+
+/// `create` from in-memory model bytes and tokenizer JSON.
+static WhisperTranscriber  createFromBytes({required List<int> encoderModelBytes , required List<int> decoderModelBytes , required String tokenizerJson })=>RustLib.instance.api.crateApiAudioWhisperWhisperTranscriberCreateFromBytes(encoderModelBytes: encoderModelBytes, decoderModelBytes: decoderModelBytes, tokenizerJson: tokenizerJson);
+
+
+static WhisperTranscriber  createFromBytesWithOptions({required List<int> encoderModelBytes , required List<int> decoderModelBytes , required String tokenizerJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiAudioWhisperWhisperTranscriberCreateFromBytesWithOptions(encoderModelBytes: encoderModelBytes, decoderModelBytes: decoderModelBytes, tokenizerJson: tokenizerJson, ortOptions: ortOptions);
+
+
+static WhisperTranscriber  createWithOptions({required String encoderModelPath , required String decoderModelPath , required String tokenizerPath , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiAudioWhisperWhisperTranscriberCreateWithOptions(encoderModelPath: encoderModelPath, decoderModelPath: decoderModelPath, tokenizerPath: tokenizerPath, ortOptions: ortOptions);
+
+
+ ModelInfo  decoderModelInfo();
+
+
+ ModelInfo  encoderModelInfo();
+
+
+/// Language codes the model can transcribe, e.g. `en`, `de`, `zh`.
+ List<String>  languages();
+
+
+/// Transcribes mono PCM `samples` recorded at `sample_rate` Hz.
+ Transcription  transcribe({required List<double> samples , required int sampleRate , TranscriptionOptions? options });
+
+
+/// Same as `transcribe`, but not `sync` on the Dart side.
+ Future<Transcription>  transcribeAsync({required List<double> samples , required int sampleRate , TranscriptionOptions? options });
+
+
+/// Transcribes one second of silence so the first real `transcribe`
+/// doesn't pay for graph initialization.
+ Future<void>  warmup();
+
+
+
+                    
+                }
+                
+
+class Transcription  {
+                final String text;
+/// The language code used for decoding, given or detected.
+final String language;
+
+                const Transcription({required this.text ,required this.language ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^language.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Transcription &&
+                runtimeType == other.runtimeType
+                && text == other.text&& language == other.language;
+        
+            }
+
+class TranscriptionOptions  {
+                /// Spoken language as a Whisper code (`en`, `de`, ...). Detected from
+/// the first 30 seconds when `None`.
+final String? language;
+/// Translate the speech to English instead of transcribing it.
+final bool translate;
+/// Upper bound on tokens per 30 s window (default: the model's limit).
+final int? maxNewTokens;
+
+                const TranscriptionOptions({this.language ,required this.translate ,this.maxNewTokens ,});
+
+                static Future<TranscriptionOptions>  default_()=>RustLib.instance.api.crateApiAudioWhisperTranscriptionOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => language.hashCode^translate.hashCode^maxNewTokens.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TranscriptionOptions &&
+                runtimeType == other.runtimeType
+                && language == other.language&& translate == other.translate&& maxNewTokens == other.maxNewTokens;
+        
+            }
+            
\ No newline at end of file
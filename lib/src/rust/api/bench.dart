@@ -0,0 +1,120 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'embeddings.dart';
+import 'error.dart';
+import 'logging.dart';
+import 'ort.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `or_default`, `percentile`, `summarize`, `text_of_tokens`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            /// Loads the model, then times `embed` for every combination of
+/// `config.batch_sizes` and `config.sequence_lengths`. Runs for a while on
+/// slow devices; keep it off the UI thread.
+Future<BenchmarkReport>  runBenchmark({required EmbedderKind kind , required String modelPath , required String tokenizerPath , BenchmarkConfig? config }) => RustLib.instance.api.crateApiBenchRunBenchmark(kind: kind, modelPath: modelPath, tokenizerPath: tokenizerPath, config: config);
+
+            class BenchmarkConfig  {
+                /// Texts per `embed` call (default 1, 8 and 32).
+final Uint32List batchSizes;
+/// Tokens per text, special tokens excluded (default 16, 128 and 512).
+/// Lengths above the model's limit are truncated by the tokenizer.
+final Uint32List sequenceLengths;
+/// Timed calls per combination (default 10).
+final int? iterations;
+/// Untimed calls before the first combination (default 2).
+final int? warmupIterations;
+final OrtInitOptions? ortOptions;
+
+                const BenchmarkConfig({required this.batchSizes ,required this.sequenceLengths ,this.iterations ,this.warmupIterations ,this.ortOptions ,});
+
+                static Future<BenchmarkConfig>  default_()=>RustLib.instance.api.crateApiBenchBenchmarkConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => batchSizes.hashCode^sequenceLengths.hashCode^iterations.hashCode^warmupIterations.hashCode^ortOptions.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BenchmarkConfig &&
+                runtimeType == other.runtimeType
+                && batchSizes == other.batchSizes&& sequenceLengths == other.sequenceLengths&& iterations == other.iterations&& warmupIterations == other.warmupIterations&& ortOptions == other.ortOptions;
+        
+            }
+
+class BenchmarkReport  {
+                final EmbedderKind kind;
+final DeviceCapabilities device;
+/// Time to build the session and load the tokenizer.
+final double loadMs;
+final int dimension;
+/// One entry per batch size and sequence length, batch sizes outermost.
+final List<BenchmarkResult> results;
+/// Peak resident memory of the process after the run; `None` where the
+/// OS doesn't report it.
+final BigInt? peakMemoryBytes;
+
+                const BenchmarkReport({required this.kind ,required this.device ,required this.loadMs ,required this.dimension ,required this.results ,this.peakMemoryBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^device.hashCode^loadMs.hashCode^dimension.hashCode^results.hashCode^peakMemoryBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BenchmarkReport &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& device == other.device&& loadMs == other.loadMs&& dimension == other.dimension&& results == other.results&& peakMemoryBytes == other.peakMemoryBytes;
+        
+            }
+
+/// Timings for one batch size and sequence length.
+class BenchmarkResult  {
+                final int batchSize;
+final int sequenceLength;
+/// Tokens per text the model actually saw, e.g. after truncation.
+final int tokensPerText;
+final double p50Ms;
+final double p95Ms;
+final double meanMs;
+final double tokensPerSecond;
+final double textsPerSecond;
+
+                const BenchmarkResult({required this.batchSize ,required this.sequenceLength ,required this.tokensPerText ,required this.p50Ms ,required this.p95Ms ,required this.meanMs ,required this.tokensPerSecond ,required this.textsPerSecond ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => batchSize.hashCode^sequenceLength.hashCode^tokensPerText.hashCode^p50Ms.hashCode^p95Ms.hashCode^meanMs.hashCode^tokensPerSecond.hashCode^textsPerSecond.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BenchmarkResult &&
+                runtimeType == other.runtimeType
+                && batchSize == other.batchSize&& sequenceLength == other.sequenceLength&& tokensPerText == other.tokensPerText&& p50Ms == other.p50Ms&& p95Ms == other.p95Ms&& meanMs == other.meanMs&& tokensPerSecond == other.tokensPerSecond&& textsPerSecond == other.textsPerSecond;
+        
+            }
+            
\ No newline at end of file
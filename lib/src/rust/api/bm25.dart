@@ -0,0 +1,88 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'search.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `analyze`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Document`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Bm25Index>>
+                abstract class Bm25Index implements RustOpaqueInterface {
+                    /// Indexes `text` under `id`, replacing any document already stored
+/// for it.
+ void  add({required String id , required String text });
+
+
+/// Indexes `texts[i]` under `ids[i]` for every pair.
+ void  addMany({required List<String> ids , required List<String> texts });
+
+
+ bool  contains({required String id });
+
+
+ bool  isEmpty();
+
+
+/// Number of indexed documents.
+ int  len();
+
+
+factory Bm25Index({Bm25Options? options })=>RustLib.instance.api.crateApiBm25Bm25IndexNew(options: options);
+
+
+/// Removes `id`; returns whether it was present.
+ bool  remove({required String id });
+
+
+/// The `k` documents scoring highest for `query`, best first. Documents
+/// sharing no term with the query are not returned.
+ List<SearchHit>  search({required String query , required int k });
+
+
+
+                    
+                }
+                
+
+class Bm25Options  {
+                /// Term-frequency saturation (default 1.2).
+final double? k1;
+/// Document-length normalization, 0 to 1 (default 0.75).
+final double? b;
+/// Analyze text into this tokenizer's tokens instead of lowercased
+/// words, so lexical and dense retrieval see the same units.
+final BigInt? tokenizerId;
+
+                const Bm25Options({this.k1 ,this.b ,this.tokenizerId ,});
+
+                static Future<Bm25Options>  default_()=>RustLib.instance.api.crateApiBm25Bm25OptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => k1.hashCode^b.hashCode^tokenizerId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Bm25Options &&
+                runtimeType == other.runtimeType
+                && k1 == other.k1&& b == other.b&& tokenizerId == other.tokenizerId;
+        
+            }
+            
\ No newline at end of file
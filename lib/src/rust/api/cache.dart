@@ -0,0 +1,52 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'embeddings.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `remove_other_caches`, `sha256_hex`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EmbeddingCache>>
+                abstract class EmbeddingCache implements RustOpaqueInterface {
+                    /// Rewrites the cache file without overwritten entries.
+ void  compact();
+
+
+/// `embedder.embed(texts)`, running the model only on texts that are not
+/// cached yet (in one batch) and caching their vectors with one write.
+ Future<List<Float32List>>  embedCached({required Embedder embedder , required List<String> texts });
+
+
+ Float32List?  get_({required String text });
+
+
+ bool  isEmpty();
+
+
+/// Number of cached vectors.
+ int  len();
+
+
+ String  modelTag();
+
+
+static EmbeddingCache  open({required String dir , required String modelTag })=>RustLib.instance.api.crateApiCacheEmbeddingCacheOpen(dir: dir, modelTag: modelTag);
+
+
+ void  put({required String text , required List<double> vector });
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
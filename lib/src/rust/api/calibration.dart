@@ -0,0 +1,140 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `check_temperature`, `curve`, `interpolate`, `sigmoid`, `softmax`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Maps raw cross-encoder logits to 0..1 with `sigmoid(logit / temperature)`.
+Float32List  sigmoidCalibrate({required List<double> logits , double? temperature }) => RustLib.instance.api.crateApiCalibrationSigmoidCalibrate(logits: logits, temperature: temperature);
+
+/// Softmax over a candidate list; higher temperatures flatten the distribution.
+Float32List  softmaxWithTemperature({required List<double> logits , double? temperature }) => RustLib.instance.api.crateApiCalibrationSoftmaxWithTemperature(logits: logits, temperature: temperature);
+
+Float32List  applyCalibration({required List<double> scores , required CalibrationParams params }) => RustLib.instance.api.crateApiCalibrationApplyCalibration(scores: scores, params: params);
+
+/// Fits Platt scaling parameters from labelled (score, relevant) pairs using
+/// Newton's method on the regularised log-loss.
+CalibrationParams  fitCalibration({required List<double> scores , required List<bool> labels }) => RustLib.instance.api.crateApiCalibrationFitCalibration(scores: scores, labels: labels);
+
+Float32List  calibrateSimilarities({required List<double> similarities , required SimilarityCalibration calibration }) => RustLib.instance.api.crateApiCalibrationCalibrateSimilarities(similarities: similarities, calibration: calibration);
+
+/// Min-max calibration from unlabelled similarities, e.g. between random
+/// pairs of the app's documents. Uses the 5th and 95th percentiles rather
+/// than the extremes so outliers don't squash the range.
+SimilarityCalibration  fitMinMaxCalibration({required List<double> similarities }) => RustLib.instance.api.crateApiCalibrationFitMinMaxCalibration(similarities: similarities);
+
+/// Temperature calibration from labelled pairs: the Platt fit of
+/// `fit_calibration`, expressed as the similarity that scores 0.5
+/// (`center`) and how sharply scores rise around it (`temperature`).
+SimilarityCalibration  fitTemperatureCalibration({required List<double> similarities , required List<bool> labels }) => RustLib.instance.api.crateApiCalibrationFitTemperatureCalibration(similarities: similarities, labels: labels);
+
+/// Curve calibration from labelled pairs by isotonic regression: the
+/// fraction of relevant pairs at each similarity, forced to be
+/// non-decreasing. Needs more labels than `fit_temperature_calibration`
+/// but assumes no particular shape.
+SimilarityCalibration  fitCurveCalibration({required List<double> similarities , required List<bool> labels }) => RustLib.instance.api.crateApiCalibrationFitCurveCalibration(similarities: similarities, labels: labels);
+
+String  calibrationToJson({required SimilarityCalibration calibration }) => RustLib.instance.api.crateApiCalibrationCalibrationToJson(calibration: calibration);
+
+SimilarityCalibration  calibrationFromJson({required String json }) => RustLib.instance.api.crateApiCalibrationCalibrationFromJson(json: json);
+
+            enum CalibrationMethod {
+                    minMax,
+temperature,
+curve,
+                    ;
+                    
+                }
+
+/// Platt-style calibration: `p = sigmoid(scale * score + bias)`.
+class CalibrationParams  {
+                final double scale;
+final double bias;
+
+                const CalibrationParams({required this.scale ,required this.bias ,});
+
+                static Future<CalibrationParams>  default_()=>RustLib.instance.api.crateApiCalibrationCalibrationParamsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => scale.hashCode^bias.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CalibrationParams &&
+                runtimeType == other.runtimeType
+                && scale == other.scale&& bias == other.bias;
+        
+            }
+
+class CalibrationPoint  {
+                final double similarity;
+final double score;
+
+                const CalibrationPoint({required this.similarity ,required this.score ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => similarity.hashCode^score.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CalibrationPoint &&
+                runtimeType == other.runtimeType
+                && similarity == other.similarity&& score == other.score;
+        
+            }
+
+/// Maps one model's raw cosine similarities to 0..1 relevance scores, so
+/// UI thresholds keep their meaning when the model changes (cosine ranges
+/// differ a lot between e.g. BGE, Gemma and Qwen3). Fit it once per model
+/// and store it with `calibration_to_json`.
+class SimilarityCalibration  {
+                final CalibrationMethod method;
+/// `MinMax` and `Curve`: piecewise-linear curve, flat beyond the ends.
+/// Min-max is the two points `(min, 0)` and `(max, 1)`.
+final List<CalibrationPoint> points;
+/// `Temperature`: `sigmoid((s - center) / temperature)`.
+final double center;
+final double temperature;
+
+                const SimilarityCalibration({required this.method ,required this.points ,required this.center ,required this.temperature ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => method.hashCode^points.hashCode^center.hashCode^temperature.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SimilarityCalibration &&
+                runtimeType == other.runtimeType
+                && method == other.method&& points == other.points&& center == other.center&& temperature == other.temperature;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `raise_exception`, `tojson`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PythonFormatter`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `begin_array_value`, `begin_object_key`, `begin_object_value`
+
+
+            /// Reads the chat template and BOS/EOS tokens from the contents of a
+/// `tokenizer_config.json`. Where a model lists several named templates the
+/// `default` one is used.
+ChatTemplate  chatTemplateFromConfig({required String configJson }) => RustLib.instance.api.crateApiChatChatTemplateFromConfig(configJson: configJson);
+
+/// Renders `messages_json`, a JSON array of `{"role": ..., "content": ...}`
+/// messages, with `template`. `add_generation_prompt` appends the opening of
+/// an assistant turn for generation. `extra_context_json` is an optional
+/// JSON object of further template variables, e.g. `tools` or
+/// `enable_thinking` for Qwen3.
+String  applyChatTemplate({required ChatTemplate template , required String messagesJson , required bool addGenerationPrompt , String? extraContextJson }) => RustLib.instance.api.crateApiChatApplyChatTemplate(template: template, messagesJson: messagesJson, addGenerationPrompt: addGenerationPrompt, extraContextJson: extraContextJson);
+
+            class ChatTemplate  {
+                /// Jinja source, e.g. the contents of `chat_template.jinja`.
+final String source;
+final String? bosToken;
+final String? eosToken;
+
+                const ChatTemplate({required this.source ,this.bosToken ,this.eosToken ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => source.hashCode^bosToken.hashCode^eosToken.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChatTemplate &&
+                runtimeType == other.runtimeType
+                && source == other.source&& bosToken == other.bosToken&& eosToken == other.eosToken;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,57 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `close`, `count`, `counted`, `pack`, `paragraph_spans`, `sentence_spans`, `to_chunks`, `token_windows`, `trim_span`, `unbounded`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Span`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Splits `text` into windows of at most `max_tokens` tokens, consecutive
+/// windows sharing `overlap` tokens. Token counts exclude special tokens, so
+/// leave room for them (e.g. `[CLS]`/`[SEP]`) when picking `max_tokens`.
+List<TextChunk>  splitByTokens({required BigInt tokenizerId , required String text , required int maxTokens , required int overlap }) => RustLib.instance.api.crateApiChunkingSplitByTokens(tokenizerId: tokenizerId, text: text, maxTokens: maxTokens, overlap: overlap);
+
+/// Packs whole sentences into chunks of at most `max_tokens` tokens.
+/// Sentences longer than the budget are split by tokens.
+List<TextChunk>  splitBySentences({required BigInt tokenizerId , required String text , required int maxTokens }) => RustLib.instance.api.crateApiChunkingSplitBySentences(tokenizerId: tokenizerId, text: text, maxTokens: maxTokens);
+
+/// Packs whole paragraphs (separated by blank lines) into chunks of at most
+/// `max_tokens` tokens. Paragraphs longer than the budget fall back to
+/// sentence packing, then to token windows.
+List<TextChunk>  splitByParagraphs({required BigInt tokenizerId , required String text , required int maxTokens }) => RustLib.instance.api.crateApiChunkingSplitByParagraphs(tokenizerId: tokenizerId, text: text, maxTokens: maxTokens);
+
+            class TextChunk  {
+                final String text;
+/// Character (Unicode scalar) offsets of the chunk in the source text.
+final int start;
+final int end;
+/// Tokens in `text`, not counting special tokens.
+final int tokenCount;
+
+                const TextChunk({required this.text ,required this.start ,required this.end ,required this.tokenCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^start.hashCode^end.hashCode^tokenCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TextChunk &&
+                runtimeType == other.runtimeType
+                && text == other.text&& start == other.start&& end == other.end&& tokenCount == other.tokenCount;
+        
+            }
+            
\ No newline at end of file
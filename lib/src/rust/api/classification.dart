@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'classify.dart';
+import 'error.dart';
+import 'logging.dart';
+import 'ort.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `label_config`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SequenceClassifier>>
+                abstract class SequenceClassifier implements RustOpaqueInterface {
+                    /// Label scores for each of `texts`, best first.
+ List<List<LabelScore>>  classify({required List<String> texts });
+
+
+/// Same as `classify`, but not `sync` on the Dart side.
+ Future<List<List<LabelScore>>>  classifyAsync({required List<String> texts });
+
+
+/// `config_json` is the model's Hugging Face `config.json`: `id2label`
+/// names the classes and `problem_type` selects multi-label scoring.
+/// Without it classes are named `LABEL_0`, `LABEL_1`, ... and scored as
+/// single-label.
+static SequenceClassifier  create({required String modelPath , required String tokenizerPath , String? configJson })=>RustLib.instance.api.crateApiClassificationSequenceClassifierCreate(modelPath: modelPath, tokenizerPath: tokenizerPath, configJson: configJson);
+
+
+/// `create` from in-memory model bytes and tokenizer JSON.
+static SequenceClassifier  createFromBytes({required List<int> modelBytes , required String tokenizerJson , String? configJson })=>RustLib.instance.api.crateApiClassificationSequenceClassifierCreateFromBytes(modelBytes: modelBytes, tokenizerJson: tokenizerJson, configJson: configJson);
+
+
+static SequenceClassifier  createFromBytesWithOptions({required List<int> modelBytes , required String tokenizerJson , String? configJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiClassificationSequenceClassifierCreateFromBytesWithOptions(modelBytes: modelBytes, tokenizerJson: tokenizerJson, configJson: configJson, ortOptions: ortOptions);
+
+
+static SequenceClassifier  createWithOptions({required String modelPath , required String tokenizerPath , String? configJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiClassificationSequenceClassifierCreateWithOptions(modelPath: modelPath, tokenizerPath: tokenizerPath, configJson: configJson, ortOptions: ortOptions);
+
+
+ bool  isMultiLabel();
+
+
+/// Inputs, outputs and metadata of the loaded ONNX model.
+ ModelInfo  modelInfo();
+
+
+/// Overrides the scoring mode picked from the config.
+ void  setMultiLabel({required bool multiLabel });
+
+
+/// Classifies one tiny input so the first real `classify` doesn't pay
+/// for graph initialization.
+ Future<void>  warmup();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
@@ -0,0 +1,91 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'logging.dart';
+import 'ort.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `classification_logits`, `from_parts`, `id2label`, `model_logits`, `nli_labels`, `parse_model_config`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ZeroShotClassifier>>
+                abstract class ZeroShotClassifier implements RustOpaqueInterface {
+                    /// Scores `candidate_labels` for `text`, best first. With `multi_label`
+/// each label is judged on its own (entailment vs. contradiction), so
+/// scores are independent; otherwise they are a softmax over the labels
+/// and sum to 1.
+ List<LabelScore>  classify({required String text , required List<String> candidateLabels , required bool multiLabel });
+
+
+/// Same as `classify`, but not `sync` on the Dart side.
+ Future<List<LabelScore>>  classifyAsync({required String text , required List<String> candidateLabels , required bool multiLabel });
+
+
+/// `config_json` is the model's Hugging Face `config.json`, used to find
+/// the entailment/contradiction labels in `id2label`. Without it the
+/// MNLI order (contradiction, neutral, entailment) is assumed.
+static ZeroShotClassifier  create({required String modelPath , required String tokenizerPath , String? configJson })=>RustLib.instance.api.crateApiClassifyZeroShotClassifierCreate(modelPath: modelPath, tokenizerPath: tokenizerPath, configJson: configJson);
+
+
+/// `create` from in-memory model bytes and tokenizer JSON.
+static ZeroShotClassifier  createFromBytes({required List<int> modelBytes , required String tokenizerJson , String? configJson })=>RustLib.instance.api.crateApiClassifyZeroShotClassifierCreateFromBytes(modelBytes: modelBytes, tokenizerJson: tokenizerJson, configJson: configJson);
+
+
+static ZeroShotClassifier  createFromBytesWithOptions({required List<int> modelBytes , required String tokenizerJson , String? configJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiClassifyZeroShotClassifierCreateFromBytesWithOptions(modelBytes: modelBytes, tokenizerJson: tokenizerJson, configJson: configJson, ortOptions: ortOptions);
+
+
+static ZeroShotClassifier  createWithOptions({required String modelPath , required String tokenizerPath , String? configJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiClassifyZeroShotClassifierCreateWithOptions(modelPath: modelPath, tokenizerPath: tokenizerPath, configJson: configJson, ortOptions: ortOptions);
+
+
+/// Inputs, outputs and metadata of the loaded ONNX model.
+ ModelInfo  modelInfo();
+
+
+/// Template turning a label into a hypothesis; `{}` is replaced by the
+/// label.
+ void  setHypothesisTemplate({required String template });
+
+
+/// Classifies one tiny input so the first real `classify` doesn't pay
+/// for graph initialization.
+ Future<void>  warmup();
+
+
+
+                    
+                }
+                
+
+class LabelScore  {
+                final String label;
+final double score;
+
+                const LabelScore({required this.label ,required this.score ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => label.hashCode^score.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LabelScore &&
+                runtimeType == other.runtimeType
+                && label == other.label&& score == other.score;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,533 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'chunking.dart';
+import 'error.dart';
+import 'hub.dart';
+import 'logging.dart';
+import 'ort.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'tokenizer.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `advance`, `apply_instructions`, `declared_dimension`, `embed_many_with`, `embed_stream_with`, `embedder_handle`, `embedder_store`, `exclude_prefix_from_pooling`, `insert_embedder`, `load_replacement`, `loaded`, `next_embedder_id`, `output_dimension`, `pad_encodings`, `pad_rows`, `peak_memory_bytes`, `pick_attention_tensor`, `pool_chunks`, `probe_dimension`, `salience_from_attention`, `start`, `token_embeddings_from_hidden`, `truncate_embeddings`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `tokenizer`
+
+
+            Embedder  createEmbedder({required EmbedderKind kind , required String modelPath , required String tokenizerPath , OrtInitOptions? ortOptions }) => RustLib.instance.api.crateApiEmbeddingsCreateEmbedder(kind: kind, modelPath: modelPath, tokenizerPath: tokenizerPath, ortOptions: ortOptions);
+
+/// `create_embedder` from in-memory model bytes and tokenizer JSON.
+Embedder  createEmbedderFromBytes({required EmbedderKind kind , required List<int> modelBytes , required String tokenizerJson , OrtInitOptions? ortOptions }) => RustLib.instance.api.crateApiEmbeddingsCreateEmbedderFromBytes(kind: kind, modelBytes: modelBytes, tokenizerJson: tokenizerJson, ortOptions: ortOptions);
+
+/// `create_embedder` from a Hugging Face Hub repo, downloading the model and
+/// `tokenizer.json` into `cache_dir` on first use.
+Future<Embedder>  createEmbedderFromHub({required EmbedderKind kind , required String repoId , required String cacheDir , HubOptions? hubOptions , OrtInitOptions? ortOptions }) => RustLib.instance.api.crateApiEmbeddingsCreateEmbedderFromHub(kind: kind, repoId: repoId, cacheDir: cacheDir, hubOptions: hubOptions, ortOptions: ortOptions);
+
+/// `create_embedder`, but keeps the embedder in a global store and returns
+/// its id, like the tokenizer functions. Ids are plain integers, so any Dart
+/// isolate can use the embedder without sharing an opaque object.
+BigInt  loadEmbedder({required EmbedderKind kind , required String modelPath , required String tokenizerPath , OrtInitOptions? ortOptions }) => RustLib.instance.api.crateApiEmbeddingsLoadEmbedder(kind: kind, modelPath: modelPath, tokenizerPath: tokenizerPath, ortOptions: ortOptions);
+
+/// `load_embedder` from in-memory model bytes and tokenizer JSON.
+BigInt  loadEmbedderFromBytes({required EmbedderKind kind , required List<int> modelBytes , required String tokenizerJson , OrtInitOptions? ortOptions }) => RustLib.instance.api.crateApiEmbeddingsLoadEmbedderFromBytes(kind: kind, modelBytes: modelBytes, tokenizerJson: tokenizerJson, ortOptions: ortOptions);
+
+/// `Embedder::embed` on the embedder loaded under `embedder_id`.
+List<Float32List>  embedWith({required BigInt embedderId , required List<String> texts }) => RustLib.instance.api.crateApiEmbeddingsEmbedWith(embedderId: embedderId, texts: texts);
+
+/// Same as `embed_with`, but runs off the Dart isolate.
+Future<List<Float32List>>  embedWithAsync({required BigInt embedderId , required List<String> texts }) => RustLib.instance.api.crateApiEmbeddingsEmbedWithAsync(embedderId: embedderId, texts: texts);
+
+/// Model family of the embedder loaded under `embedder_id`.
+EmbedderKind  embedderKind({required BigInt embedderId }) => RustLib.instance.api.crateApiEmbeddingsEmbedderKind(embedderId: embedderId);
+
+/// Removes an embedder from the store. Its session and tokenizer are freed
+/// once in-flight calls on it have finished. Returns whether `embedder_id`
+/// was loaded.
+bool  disposeEmbedder({required BigInt embedderId }) => RustLib.instance.api.crateApiEmbeddingsDisposeEmbedder(embedderId: embedderId);
+
+/// Ids of all loaded embedders, ascending.
+Uint64List  listEmbedders() => RustLib.instance.api.crateApiEmbeddingsListEmbedders();
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>>
+                abstract class CancellationToken implements RustOpaqueInterface {
+                     void  cancel();
+
+
+static Future<CancellationToken>  default_()=>RustLib.instance.api.crateApiEmbeddingsCancellationTokenDefault();
+
+
+ bool  isCancelled();
+
+
+factory CancellationToken()=>RustLib.instance.api.crateApiEmbeddingsCancellationTokenNew();
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EmbedProgress>>
+                abstract class EmbedProgress implements RustOpaqueInterface {
+                     int  completed();
+
+
+static Future<EmbedProgress>  default_()=>RustLib.instance.api.crateApiEmbeddingsEmbedProgressDefault();
+
+
+factory EmbedProgress()=>RustLib.instance.api.crateApiEmbeddingsEmbedProgressNew();
+
+
+ int  total();
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Embedder>>
+                abstract class Embedder implements RustOpaqueInterface {
+                    /// Frees the ONNX sessions and tokenizer now rather than when the Dart
+/// object is garbage collected. Waits for in-flight calls; later calls
+/// fail with `ErrorKind::Closed`.
+ void  close();
+
+
+ BigInt  dimension();
+
+
+ List<Float32List>  embed({required List<String> texts });
+
+
+/// Same as `embed`, but runs off the Dart isolate.
+ Future<List<Float32List>>  embedAsync({required List<String> texts });
+
+
+/// `embed_async` that fails with a cancellation error once `token` is
+/// cancelled.
+ Future<List<Float32List>>  embedAsyncCancellable({required List<String> texts , required CancellationToken token });
+
+
+/// `embed` for the documents being searched, with the document prefix
+/// applied.
+ List<Float32List>  embedDocuments({required List<String> texts });
+
+
+/// `embed` for search queries, with the query prefix or instruction the
+/// model expects already applied.
+ List<Float32List>  embedQueries({required List<String> queries });
+
+
+/// Embeds `texts` in input order, `batch_size` at a time, sending each
+/// batch to `sink` as soon as it is ready.
+ Stream<EmbeddingProgress>  embedStream({required List<String> texts , required int batchSize });
+
+
+/// `embed_stream` that ends after the current batch once `token` is
+/// cancelled.
+ Stream<EmbeddingProgress>  embedStreamCancellable({required List<String> texts , required int batchSize , required CancellationToken token });
+
+
+/// Per-token `last_hidden_state` vectors, padding dropped, for custom
+/// pooling, ColBERT-style scoring or attention visualization. Fails with
+/// `ErrorKind::InvalidInput` for decoder models such as Qwen3.
+ List<TokenEmbeddings>  embedTokens({required List<String> texts });
+
+
+/// Splits `text` into windows of `window_tokens` tokens (counted with
+/// `tokenizer_id`), starting every `stride` tokens, and embeds each as a
+/// document. Every embedding comes with its window's character span in
+/// `text`, e.g. to highlight the passage that matched a query.
+ Future<List<PassageEmbedding>>  embedWindows({required BigInt tokenizerId , required String text , required int windowTokens , required int stride });
+
+
+ List<Float32List>  embedWithOptions({required List<String> texts , required EmbedOptions options });
+
+
+/// `embed` plus tokenization/inference timings and peak memory, for
+/// picking batch sizes and models per device.
+ EmbeddingsWithStats  embedWithStats({required List<String> texts });
+
+
+ String  formatDocument({required String text });
+
+
+ String  formatQuery({required String query });
+
+
+ bool  isClosed();
+
+
+ EmbedderKind  kind();
+
+
+ ModelInfo  modelInfo();
+
+
+/// Replaces the model, e.g. with a larger variant of the same family,
+/// without recreating the embedder. The new session is built before the
+/// old one is dropped, and calls in flight finish on the old model. The
+/// tokenizer is kept unless `tokenizer_path` is given.
+ void  reloadModel({required String modelPath , String? tokenizerPath , OrtInitOptions? ortOptions });
+
+
+/// Registers the embedder's tokenizer with the tokenizer functions and
+/// returns its id, so `encode` and `count_tokens` see exactly what the
+/// model sees without loading `tokenizer.json` again. Repeated calls
+/// return the same id until it is disposed. The registered tokenizer
+/// stays alive after the embedder is closed until `dispose_tokenizer`;
+/// changing its truncation, padding or special tokens through the id
+/// leaves the embedder's copy untouched.
+ BigInt  tokenizerId();
+
+
+/// Runs a tiny input through every session of the model so the first
+/// real `embed` doesn't pay for graph initialization. Call it while the
+/// UI is still idle, e.g. right after creating the embedder.
+ Future<void>  warmup();
+
+
+
+                    
+                }
+                
+
+
+                abstract class TextEmbedder {
+                    /// Frees the model sessions and tokenizer; later calls fail with
+/// `ErrorKind::Closed`.
+ Future<void>  close();
+
+
+/// Length of the produced vectors, read from the model outputs or, when
+/// the export leaves it dynamic, from a one-token dry run.
+ Future<BigInt>  dimension();
+
+
+ Future<List<Float32List>>  embed({required List<String> texts });
+
+
+/// Embeds `texts` with the model's document prefix.
+ Future<List<Float32List>>  embedDocuments({required List<String> texts });
+
+
+/// Embeds `queries` with the model's query prefix or instruction.
+ Future<List<Float32List>>  embedQueries({required List<String> queries });
+
+
+/// Unpooled per-token vectors; only encoder models that expose
+/// `last_hidden_state` support it.
+ Future<List<TokenEmbeddings>>  embedTokens({required List<String> texts });
+
+
+/// `embed` with per-call options; by default Matryoshka truncation is
+/// applied to the normalized output.
+ Future<List<Float32List>>  embedWithOptions({required List<String> texts , required EmbedOptions options });
+
+
+/// `embed` that also reports where the time went.
+ Future<EmbeddingsWithStats>  embedWithStats({required List<String> texts });
+
+
+ Future<String>  formatDocument({required String text });
+
+
+ Future<String>  formatQuery({required String query });
+
+
+ Future<bool>  isClosed();
+
+
+ Future<ModelInfo>  modelInfo();
+
+
+/// Swaps in another model file (and optionally tokenizer) once it has
+/// loaded; see `BgeEmbedder::reload_model`.
+ Future<void>  reloadModel({required String modelPath , String? tokenizerPath , OrtInitOptions? ortOptions });
+
+
+/// Runs a one-word input through the model so the first real `embed`
+/// doesn't pay for graph initialization.
+ Future<void>  warmup();
+
+
+                }
+                
+
+/// Limits for the micro-batches `embed` splits its input into, so one long
+/// text can't pad a large batch into an out-of-memory tensor. Texts are
+/// grouped by token count and results are returned in input order.
+class BatchOptions  {
+                /// Texts per inference call (default 32).
+final int? maxBatchSize;
+/// Padded tokens (texts × longest text) per inference call (default
+/// 16384). A text longer than this still runs, on its own.
+final int? maxTokensPerBatch;
+
+                const BatchOptions({this.maxBatchSize ,this.maxTokensPerBatch ,});
+
+                static Future<BatchOptions>  default_()=>RustLib.instance.api.crateApiEmbeddingsBatchOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => maxBatchSize.hashCode^maxTokensPerBatch.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BatchOptions &&
+                runtimeType == other.runtimeType
+                && maxBatchSize == other.maxBatchSize&& maxTokensPerBatch == other.maxTokensPerBatch;
+        
+            }
+
+class EmbedManyOptions  {
+                /// Texts per inference call (default 32).
+final int? batchSize;
+/// Groups texts of similar length into the same batch to cut padding
+/// (default true). Results are always returned in input order.
+final bool? sortByLength;
+
+                const EmbedManyOptions({this.batchSize ,this.sortByLength ,});
+
+                static Future<EmbedManyOptions>  default_()=>RustLib.instance.api.crateApiEmbeddingsEmbedManyOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => batchSize.hashCode^sortByLength.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EmbedManyOptions &&
+                runtimeType == other.runtimeType
+                && batchSize == other.batchSize&& sortByLength == other.sortByLength;
+        
+            }
+
+class EmbedOptions  {
+                /// Matryoshka truncation: keep the first `output_dim` dimensions and
+/// renormalize, e.g. 256 instead of 768. Only meaningful for models
+/// trained for it (Gemma, Nomic, Qwen3). `None` keeps the full vector.
+final int? outputDim;
+
+                const EmbedOptions({this.outputDim ,});
+
+                static Future<EmbedOptions>  default_()=>RustLib.instance.api.crateApiEmbeddingsEmbedOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => outputDim.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EmbedOptions &&
+                runtimeType == other.runtimeType
+                && outputDim == other.outputDim;
+        
+            }
+
+/// Where the time of one `embed_with_stats` call went.
+class EmbedStats  {
+                final double tokenizationMs;
+/// Tensor building, model runs and pooling, across all micro-batches.
+final double inferenceMs;
+/// Tokens fed to the model, excluding padding.
+final BigInt tokens;
+/// `tokens` over the whole call, tokenization included.
+final double tokensPerSecond;
+/// Peak resident memory of the process so far. `None` where the OS
+/// doesn't report it (only Linux and Android do).
+final BigInt? peakMemoryBytes;
+
+                const EmbedStats({required this.tokenizationMs ,required this.inferenceMs ,required this.tokens ,required this.tokensPerSecond ,this.peakMemoryBytes ,});
+
+                static Future<EmbedStats>  default_()=>RustLib.instance.api.crateApiEmbeddingsEmbedStatsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => tokenizationMs.hashCode^inferenceMs.hashCode^tokens.hashCode^tokensPerSecond.hashCode^peakMemoryBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EmbedStats &&
+                runtimeType == other.runtimeType
+                && tokenizationMs == other.tokenizationMs&& inferenceMs == other.inferenceMs&& tokens == other.tokens&& tokensPerSecond == other.tokensPerSecond&& peakMemoryBytes == other.peakMemoryBytes;
+        
+            }
+
+/// Model families supported by `create_embedder`.
+enum EmbedderKind {
+                    qwen3,
+gemma,
+bge,
+miniLm,
+jinaV3,
+e5,
+nomic,
+                    ;
+                    
+                }
+
+/// One batch of results from `embed_stream`.
+class EmbeddingProgress  {
+                /// Input index of the first text in `embeddings`.
+final int offset;
+final List<Float32List> embeddings;
+/// Texts embedded so far, this batch included.
+final int completed;
+final int total;
+
+                const EmbeddingProgress({required this.offset ,required this.embeddings ,required this.completed ,required this.total ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => offset.hashCode^embeddings.hashCode^completed.hashCode^total.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EmbeddingProgress &&
+                runtimeType == other.runtimeType
+                && offset == other.offset&& embeddings == other.embeddings&& completed == other.completed&& total == other.total;
+        
+            }
+
+class EmbeddingsWithStats  {
+                final List<Float32List> embeddings;
+final EmbedStats stats;
+
+                const EmbeddingsWithStats({required this.embeddings ,required this.stats ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => embeddings.hashCode^stats.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EmbeddingsWithStats &&
+                runtimeType == other.runtimeType
+                && embeddings == other.embeddings&& stats == other.stats;
+        
+            }
+
+/// One window produced by `Embedder::embed_windows`.
+class PassageEmbedding  {
+                final TextChunk passage;
+final Float32List embedding;
+
+                const PassageEmbedding({required this.passage ,required this.embedding ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => passage.hashCode^embedding.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PassageEmbedding &&
+                runtimeType == other.runtimeType
+                && passage == other.passage&& embedding == other.embedding;
+        
+            }
+
+/// Unpooled per-token hidden states for a single input text.
+class TokenEmbeddings  {
+                final List<String> tokens;
+final List<TokenOffsets> offsets;
+/// One hidden-state vector per (non-padding) token.
+final List<Float32List> embeddings;
+
+                const TokenEmbeddings({required this.tokens ,required this.offsets ,required this.embeddings ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => tokens.hashCode^offsets.hashCode^embeddings.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TokenEmbeddings &&
+                runtimeType == other.runtimeType
+                && tokens == other.tokens&& offsets == other.offsets&& embeddings == other.embeddings;
+        
+            }
+
+/// Attention-derived importance of each (non-padding) token of one input.
+class TokenSalience  {
+                final List<String> tokens;
+final List<TokenOffsets> offsets;
+/// Scores sum to 1 over the returned tokens.
+final Float32List scores;
+
+                const TokenSalience({required this.tokens ,required this.offsets ,required this.scores ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => tokens.hashCode^offsets.hashCode^scores.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TokenSalience &&
+                runtimeType == other.runtimeType
+                && tokens == other.tokens&& offsets == other.offsets&& scores == other.scores;
+        
+            }
+            
\ No newline at end of file
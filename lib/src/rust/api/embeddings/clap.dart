@@ -0,0 +1,80 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../error.dart';
+import '../logging.dart';
+import '../ort.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `embed_features`, `features`, `from_parts`, `log_mel`, `shrink_frames`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ClapEmbedder>>
+                abstract class ClapEmbedder implements RustOpaqueInterface {
+                    /// Inputs, outputs and metadata of the audio tower.
+ ModelInfo  audioModelInfo();
+
+
+/// Frees both ONNX sessions and the tokenizer now rather than when the
+/// object is dropped. Later calls fail with `ErrorKind::Closed`.
+ void  close();
+
+
+static ClapEmbedder  create({required String textModelPath , required String audioModelPath , required String tokenizerPath })=>RustLib.instance.api.crateApiEmbeddingsClapClapEmbedderCreate(textModelPath: textModelPath, audioModelPath: audioModelPath, tokenizerPath: tokenizerPath);
+
+
+/// `create` from in-memory models and tokenizer JSON.
+static ClapEmbedder  createFromBytes({required List<int> textModelBytes , required List<int> audioModelBytes , required String tokenizerJson })=>RustLib.instance.api.crateApiEmbeddingsClapClapEmbedderCreateFromBytes(textModelBytes: textModelBytes, audioModelBytes: audioModelBytes, tokenizerJson: tokenizerJson);
+
+
+static ClapEmbedder  createFromBytesWithOptions({required List<int> textModelBytes , required List<int> audioModelBytes , required String tokenizerJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsClapClapEmbedderCreateFromBytesWithOptions(textModelBytes: textModelBytes, audioModelBytes: audioModelBytes, tokenizerJson: tokenizerJson, ortOptions: ortOptions);
+
+
+static ClapEmbedder  createWithOptions({required String textModelPath , required String audioModelPath , required String tokenizerPath , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsClapClapEmbedderCreateWithOptions(textModelPath: textModelPath, audioModelPath: audioModelPath, tokenizerPath: tokenizerPath, ortOptions: ortOptions);
+
+
+/// Length of the shared text/audio vectors, read from the text tower's
+/// outputs or, when the export leaves it dynamic, from a dry run.
+ BigInt  dimension();
+
+
+/// Embeds mono PCM clips recorded at `sample_rate` Hz.
+ List<Float32List>  embedAudio({required List<Float32List> clips , required int sampleRate });
+
+
+/// Same as `embed_audio`, but runs on a worker thread. Prefer this for
+/// libraries of clips: resampling and spectrograms are not free.
+ Future<List<Float32List>>  embedAudioAsync({required List<Float32List> clips , required int sampleRate });
+
+
+ List<Float32List>  embedTexts({required List<String> texts });
+
+
+/// Same as `embed_texts`, but runs on a worker thread.
+ Future<List<Float32List>>  embedTextsAsync({required List<String> texts });
+
+
+ bool  isClosed();
+
+
+/// Inputs, outputs and metadata of the text tower.
+ ModelInfo  textModelInfo();
+
+
+/// Runs a one-word text and a second of silence through both towers so
+/// the first real calls don't pay for graph initialization.
+ Future<void>  warmup();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
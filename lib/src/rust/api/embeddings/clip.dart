@@ -0,0 +1,90 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../error.dart';
+import '../logging.dart';
+import '../ort.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `embed_pixels`, `from_parts`, `pooled_embeddings`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ClipEmbedder>>
+                abstract class ClipEmbedder implements RustOpaqueInterface {
+                    /// Frees both ONNX sessions and the tokenizer now rather than when the
+/// object is dropped. Later calls fail with `ErrorKind::Closed`.
+ void  close();
+
+
+static ClipEmbedder  create({required String textModelPath , required String visionModelPath , required String tokenizerPath })=>RustLib.instance.api.crateApiEmbeddingsClipClipEmbedderCreate(textModelPath: textModelPath, visionModelPath: visionModelPath, tokenizerPath: tokenizerPath);
+
+
+/// `create` from in-memory models and tokenizer JSON.
+static ClipEmbedder  createFromBytes({required List<int> textModelBytes , required List<int> visionModelBytes , required String tokenizerJson })=>RustLib.instance.api.crateApiEmbeddingsClipClipEmbedderCreateFromBytes(textModelBytes: textModelBytes, visionModelBytes: visionModelBytes, tokenizerJson: tokenizerJson);
+
+
+static ClipEmbedder  createFromBytesWithOptions({required List<int> textModelBytes , required List<int> visionModelBytes , required String tokenizerJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsClipClipEmbedderCreateFromBytesWithOptions(textModelBytes: textModelBytes, visionModelBytes: visionModelBytes, tokenizerJson: tokenizerJson, ortOptions: ortOptions);
+
+
+static ClipEmbedder  createWithOptions({required String textModelPath , required String visionModelPath , required String tokenizerPath , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsClipClipEmbedderCreateWithOptions(textModelPath: textModelPath, visionModelPath: visionModelPath, tokenizerPath: tokenizerPath, ortOptions: ortOptions);
+
+
+/// Length of the shared text/image vectors, read from the text tower's
+/// outputs or, when the export leaves it dynamic, from a dry run.
+ BigInt  dimension();
+
+
+/// Embeds encoded images (PNG or JPEG bytes): each is resized so its
+/// shorter side is `image_size`, center-cropped, and normalized.
+ List<Float32List>  embedImages({required List<Uint8List> imageBytes });
+
+
+/// Same as `embed_images`, but runs on a worker thread. Prefer this for
+/// gallery-sized batches: decoding and resizing are not free.
+ Future<List<Float32List>>  embedImagesAsync({required List<Uint8List> imageBytes });
+
+
+ List<Float32List>  embedTexts({required List<String> texts });
+
+
+/// Same as `embed_texts`, but runs on a worker thread.
+ Future<List<Float32List>>  embedTextsAsync({required List<String> texts });
+
+
+/// Side length images are resized and center-cropped to.
+ int  imageSize();
+
+
+ bool  isClosed();
+
+
+/// Overrides the per-channel (RGB) pixel normalization, e.g.
+/// `[0.5, 0.5, 0.5]` for both on SigLIP models. Defaults to CLIP's.
+ void  setImageNormalization({required List<double> mean , required List<double> std });
+
+
+/// Inputs, outputs and metadata of the text tower.
+ ModelInfo  textModelInfo();
+
+
+/// Inputs, outputs and metadata of the vision tower.
+ ModelInfo  visionModelInfo();
+
+
+/// Runs a one-word text and a blank image through both towers so the
+/// first real calls don't pay for graph initialization.
+ Future<void>  warmup();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
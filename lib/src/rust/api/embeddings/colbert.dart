@@ -0,0 +1,80 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../error.dart';
+import '../logging.dart';
+import '../ort.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `embed_tokens`, `from_parts`
+
+
+            /// Late-interaction relevance: for every query vector, the best dot product
+/// with any document vector, summed over the query.
+double  maxSim({required List<Float32List> queryVectors , required List<Float32List> docVectors }) => RustLib.instance.api.crateApiEmbeddingsColbertMaxSim(queryVectors: queryVectors, docVectors: docVectors);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ColbertEmbedder>>
+                abstract class ColbertEmbedder implements RustOpaqueInterface {
+                    /// Frees the ONNX session and tokenizer now rather than when the object
+/// is dropped. Later calls fail with `ErrorKind::Closed`.
+ void  close();
+
+
+static ColbertEmbedder  create({required String modelPath , required String tokenizerPath })=>RustLib.instance.api.crateApiEmbeddingsColbertColbertEmbedderCreate(modelPath: modelPath, tokenizerPath: tokenizerPath);
+
+
+/// `create` from in-memory model bytes and tokenizer JSON, e.g. for
+/// encrypted or downloaded assets that must not be written to disk.
+static ColbertEmbedder  createFromBytes({required List<int> modelBytes , required String tokenizerJson })=>RustLib.instance.api.crateApiEmbeddingsColbertColbertEmbedderCreateFromBytes(modelBytes: modelBytes, tokenizerJson: tokenizerJson);
+
+
+static ColbertEmbedder  createFromBytesWithOptions({required List<int> modelBytes , required String tokenizerJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsColbertColbertEmbedderCreateFromBytesWithOptions(modelBytes: modelBytes, tokenizerJson: tokenizerJson, ortOptions: ortOptions);
+
+
+static ColbertEmbedder  createWithOptions({required String modelPath , required String tokenizerPath , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsColbertColbertEmbedderCreateWithOptions(modelPath: modelPath, tokenizerPath: tokenizerPath, ortOptions: ortOptions);
+
+
+/// Length of each per-token vector, read from the model outputs or,
+/// when the export leaves it dynamic, from a one-query dry run.
+ BigInt  dimension();
+
+
+/// Same as `embed_documents`.
+ List<List<Float32List>>  embed({required List<String> texts });
+
+
+/// Per-token vectors for each document, without padding and punctuation.
+ List<List<Float32List>>  embedDocuments({required List<String> texts });
+
+
+/// Per-token vectors for each query, `[MASK]`-augmented.
+ List<List<Float32List>>  embedQueries({required List<String> texts });
+
+
+ bool  isClosed();
+
+
+/// Inputs, outputs and metadata of the loaded ONNX model.
+ ModelInfo  modelInfo();
+
+
+/// Queries shorter than this many tokens are padded with `[MASK]`
+/// (query augmentation). Default 32.
+ void  setQueryLength({required int queryLength });
+
+
+/// Runs a one-word query through the model so the first real call
+/// doesn't pay for graph initialization.
+ Future<void>  warmup();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
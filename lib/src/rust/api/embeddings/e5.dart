@@ -0,0 +1,90 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../embeddings.dart';
+import '../error.dart';
+import '../hub.dart';
+import '../logging.dart';
+import '../ort.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `from_parts`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `close`, `dimension`, `embed_tokens`, `embed_with_stats`, `embed`, `format_document`, `format_query`, `is_closed`, `model_info`, `reload_model`, `tokenizer`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<E5Embedder>>
+                abstract class E5Embedder implements RustOpaqueInterface, TextEmbedder {
+                    static E5Embedder  create({required String modelPath , required String tokenizerPath })=>RustLib.instance.api.crateApiEmbeddingsE5E5EmbedderCreate(modelPath: modelPath, tokenizerPath: tokenizerPath);
+
+
+/// `create` from in-memory model bytes and tokenizer JSON, e.g. for
+/// encrypted or downloaded assets that must not be written to disk.
+static E5Embedder  createFromBytes({required List<int> modelBytes , required String tokenizerJson })=>RustLib.instance.api.crateApiEmbeddingsE5E5EmbedderCreateFromBytes(modelBytes: modelBytes, tokenizerJson: tokenizerJson);
+
+
+static E5Embedder  createFromBytesWithOptions({required List<int> modelBytes , required String tokenizerJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsE5E5EmbedderCreateFromBytesWithOptions(modelBytes: modelBytes, tokenizerJson: tokenizerJson, ortOptions: ortOptions);
+
+
+/// `create` from a Hugging Face Hub repo, downloading the model and
+/// `tokenizer.json` into `cache_dir` on first use.
+static Future<E5Embedder>  createFromHub({required String repoId , required String cacheDir })=>RustLib.instance.api.crateApiEmbeddingsE5E5EmbedderCreateFromHub(repoId: repoId, cacheDir: cacheDir);
+
+
+static Future<E5Embedder>  createFromHubWithOptions({required String repoId , required String cacheDir , HubOptions? hubOptions , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsE5E5EmbedderCreateFromHubWithOptions(repoId: repoId, cacheDir: cacheDir, hubOptions: hubOptions, ortOptions: ortOptions);
+
+
+static E5Embedder  createWithOptions({required String modelPath , required String tokenizerPath , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsE5E5EmbedderCreateWithOptions(modelPath: modelPath, tokenizerPath: tokenizerPath, ortOptions: ortOptions);
+
+
+/// Same as `embed`, but runs off the Dart isolate.
+ Future<List<Float32List>>  embedAsync({required List<String> texts });
+
+
+/// `embed_async` that fails with a cancellation error once `token` is
+/// cancelled.
+ Future<List<Float32List>>  embedAsyncCancellable({required List<String> texts , required CancellationToken token });
+
+
+/// Formats every text with `format_document` and embeds the batch.
+ List<Float32List>  embedDocuments({required List<String> texts });
+
+
+/// Embeds large corpora in length-sorted chunks (see `EmbedManyOptions`).
+ Future<List<Float32List>>  embedMany({required List<String> texts , EmbedManyOptions? options });
+
+
+/// Formats every query with `format_query` and embeds the batch.
+ List<Float32List>  embedQueries({required List<String> queries });
+
+
+/// Embeds `texts` in input order, `batch_size` at a time, sending each
+/// batch to `sink` as soon as it is ready.
+ Stream<EmbeddingProgress>  embedStream({required List<String> texts , required int batchSize });
+
+
+/// `embed` with per-call options; by default Matryoshka truncation is
+/// applied to the normalized output.
+ Future<List<Float32List>>  embedWithOptions({required List<String> texts , required EmbedOptions options });
+
+
+/// Limits for the micro-batches `embed` splits large inputs into.
+ void  setBatchOptions({required BatchOptions options });
+
+
+/// Runs a one-word batch through the model so the first real `embed`
+/// doesn't pay for graph initialization.
+ Future<void>  warmup();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
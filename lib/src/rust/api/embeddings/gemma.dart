@@ -4,35 +4,126 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
+import '../embeddings.dart';
+import '../error.dart';
+import '../hub.dart';
+import '../logging.dart';
 import '../ort.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GemmaEmbedder>>
-abstract class GemmaEmbedder implements RustOpaqueInterface {
-  static GemmaEmbedder create({
-    required String modelPath,
-    required String tokenizerPath,
-  }) => RustLib.instance.api.crateApiEmbeddingsGemmaGemmaEmbedderCreate(
-    modelPath: modelPath,
-    tokenizerPath: tokenizerPath,
-  );
-
-  static GemmaEmbedder createWithOptions({
-    required String modelPath,
-    required String tokenizerPath,
-    OrtInitOptions? ortOptions,
-  }) => RustLib.instance.api
-      .crateApiEmbeddingsGemmaGemmaEmbedderCreateWithOptions(
-        modelPath: modelPath,
-        tokenizerPath: tokenizerPath,
-        ortOptions: ortOptions,
-      );
-
-  List<Float32List> embed({required List<String> texts});
-
-  static String formatDocument({required String text}) => RustLib.instance.api
-      .crateApiEmbeddingsGemmaGemmaEmbedderFormatDocument(text: text);
-
-  static String formatQuery({required String query}) => RustLib.instance.api
-      .crateApiEmbeddingsGemmaGemmaEmbedderFormatQuery(query: query);
-}
+
+            // These functions are ignored because they are not marked as `pub`: `from_parts`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `close`, `dimension`, `embed_tokens`, `embed_with_stats`, `embed`, `format_document`, `format_query`, `is_closed`, `model_info`, `reload_model`, `tokenizer`, `warmup`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GemmaEmbedder>>
+                abstract class GemmaEmbedder implements RustOpaqueInterface, TextEmbedder {
+                    /// Registers an extra session of the same model for `workload`, e.g. a
+/// CoreML/NNAPI session for interactive queries or a single-threaded CPU
+/// session for indexing. Calls without a matching route use the session
+/// the embedder was created with.
+ void  addSession({required Workload workload , required String modelPath , OrtInitOptions? ortOptions });
+
+
+static GemmaEmbedder  create({required String modelPath , required String tokenizerPath })=>RustLib.instance.api.crateApiEmbeddingsGemmaGemmaEmbedderCreate(modelPath: modelPath, tokenizerPath: tokenizerPath);
+
+
+/// `create` from in-memory model bytes and tokenizer JSON, e.g. for
+/// encrypted or downloaded assets that must not be written to disk.
+static GemmaEmbedder  createFromBytes({required List<int> modelBytes , required String tokenizerJson })=>RustLib.instance.api.crateApiEmbeddingsGemmaGemmaEmbedderCreateFromBytes(modelBytes: modelBytes, tokenizerJson: tokenizerJson);
+
+
+static GemmaEmbedder  createFromBytesWithOptions({required List<int> modelBytes , required String tokenizerJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsGemmaGemmaEmbedderCreateFromBytesWithOptions(modelBytes: modelBytes, tokenizerJson: tokenizerJson, ortOptions: ortOptions);
+
+
+/// `create` from a Hugging Face Hub repo, downloading the model and
+/// `tokenizer.json` into `cache_dir` on first use.
+static Future<GemmaEmbedder>  createFromHub({required String repoId , required String cacheDir })=>RustLib.instance.api.crateApiEmbeddingsGemmaGemmaEmbedderCreateFromHub(repoId: repoId, cacheDir: cacheDir);
+
+
+static Future<GemmaEmbedder>  createFromHubWithOptions({required String repoId , required String cacheDir , HubOptions? hubOptions , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsGemmaGemmaEmbedderCreateFromHubWithOptions(repoId: repoId, cacheDir: cacheDir, hubOptions: hubOptions, ortOptions: ortOptions);
+
+
+static GemmaEmbedder  createWithOptions({required String modelPath , required String tokenizerPath , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsGemmaGemmaEmbedderCreateWithOptions(modelPath: modelPath, tokenizerPath: tokenizerPath, ortOptions: ortOptions);
+
+
+/// Same as `embed`, but not `sync` on the Dart side: inference runs on a
+/// worker thread instead of blocking the calling isolate.
+ Future<List<Float32List>>  embedAsync({required List<String> texts });
+
+
+/// `embed_async` that checks `token` between micro-batches and fails
+/// with a cancellation error once it is cancelled.
+ Future<List<Float32List>>  embedAsyncCancellable({required List<String> texts , required CancellationToken token });
+
+
+/// Formats every text with `format_document` and embeds the batch.
+ List<Float32List>  embedDocuments({required List<String> texts });
+
+
+/// Batch API for indexing large corpora: embeds in length-sorted chunks
+/// (see `EmbedManyOptions`) on the `Workload::Bulk` session. Use `embed`
+/// for small, latency-sensitive calls.
+ Future<List<Float32List>>  embedMany({required List<String> texts , EmbedManyOptions? options });
+
+
+/// Same as `embed_many`, updating `progress` after every chunk.
+ Future<List<Float32List>>  embedManyWithProgress({required List<String> texts , EmbedManyOptions? options , required EmbedProgress progress });
+
+
+/// Formats every query with `format_query` and embeds the batch.
+ List<Float32List>  embedQueries({required List<String> queries });
+
+
+/// Embeds `texts` in input order, `batch_size` at a time on the
+/// `Workload::Bulk` session, sending each batch to `sink` as soon as it
+/// is ready.
+ Stream<EmbeddingProgress>  embedStream({required List<String> texts , required int batchSize });
+
+
+/// `embed_stream` that ends after the current batch once `token` is
+/// cancelled.
+ Stream<EmbeddingProgress>  embedStreamCancellable({required List<String> texts , required int batchSize , required CancellationToken token });
+
+
+/// Embeds `(instruction, text)` pairs, so one batch can mix queries,
+/// passages and classification inputs. Each instruction is prepended to
+/// its text as-is.
+ List<Float32List>  embedWithInstructions({required List<(String?,String)> items });
+
+
+/// `embed` with Matryoshka truncation to `options.output_dim`.
+ List<Float32List>  embedWithOptions({required List<String> texts , required EmbedOptions options });
+
+
+/// `embed` on the session registered for `workload`.
+ List<Float32List>  embedWithWorkload({required List<String> texts , required Workload workload });
+
+
+/// Number of sessions serving `workload`.
+ int  poolSize({required Workload workload });
+
+
+ bool  removeSession({required Workload workload });
+
+
+/// Limits for the micro-batches `embed` splits large inputs into.
+ void  setBatchOptions({required BatchOptions options });
+
+
+/// Keeps `size` sessions of the model for `workload` (the sessions the
+/// embedder was created with when no route is registered), so that many
+/// concurrent calls can run in parallel. Each extra session is loaded from
+/// `model_path` with its own copy of the weights; shrinking drops the
+/// extras.
+ void  setPoolSize({required Workload workload , required int size , required String modelPath , OrtInitOptions? ortOptions });
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
@@ -0,0 +1,83 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../embeddings.dart';
+import '../error.dart';
+import '../logging.dart';
+import '../ort.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `parse_config`, `render`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `GenericConfig`, `InputMapping`, `InstructionFormat`, `OutputNames`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `close`, `dimension`, `embed_tokens`, `embed_with_stats`, `embed`, `format_document`, `format_query`, `is_closed`, `model_info`, `reload_model`, `tokenizer`, `warmup`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GenericEmbedder>>
+                abstract class GenericEmbedder implements RustOpaqueInterface, TextEmbedder {
+                    static GenericEmbedder  create({required String modelPath , required String tokenizerPath , required String configJson })=>RustLib.instance.api.crateApiEmbeddingsGenericGenericEmbedderCreate(modelPath: modelPath, tokenizerPath: tokenizerPath, configJson: configJson);
+
+
+/// `create` from in-memory model bytes and tokenizer JSON.
+static GenericEmbedder  createFromBytes({required List<int> modelBytes , required String tokenizerJson , required String configJson })=>RustLib.instance.api.crateApiEmbeddingsGenericGenericEmbedderCreateFromBytes(modelBytes: modelBytes, tokenizerJson: tokenizerJson, configJson: configJson);
+
+
+static GenericEmbedder  createFromBytesWithOptions({required List<int> modelBytes , required String tokenizerJson , required String configJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsGenericGenericEmbedderCreateFromBytesWithOptions(modelBytes: modelBytes, tokenizerJson: tokenizerJson, configJson: configJson, ortOptions: ortOptions);
+
+
+static GenericEmbedder  createWithOptions({required String modelPath , required String tokenizerPath , required String configJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsGenericGenericEmbedderCreateWithOptions(modelPath: modelPath, tokenizerPath: tokenizerPath, configJson: configJson, ortOptions: ortOptions);
+
+
+/// Same as `embed`, but runs off the Dart isolate.
+ Future<List<Float32List>>  embedAsync({required List<String> texts });
+
+
+/// `embed_async` that fails with a cancellation error once `token` is
+/// cancelled.
+ Future<List<Float32List>>  embedAsyncCancellable({required List<String> texts , required CancellationToken token });
+
+
+/// Prepends the configured `document_prefix` to every text and embeds
+/// the batch.
+ List<Float32List>  embedDocuments({required List<String> texts });
+
+
+/// Embeds large corpora in length-sorted chunks (see `EmbedManyOptions`).
+ Future<List<Float32List>>  embedMany({required List<String> texts , EmbedManyOptions? options });
+
+
+/// Prepends the configured `query_prefix` to every query and embeds
+/// the batch.
+ List<Float32List>  embedQueries({required List<String> queries });
+
+
+/// Embeds `texts` in input order, `batch_size` at a time, sending each
+/// batch to `sink` as soon as it is ready.
+ Stream<EmbeddingProgress>  embedStream({required List<String> texts , required int batchSize });
+
+
+/// Embeds `(instruction, text)` pairs, rendering each instruction as
+/// configured by `instructions`. Texts without one are embedded as-is.
+ List<Float32List>  embedWithInstructions({required List<(String?,String)> items });
+
+
+/// `embed` with per-call options; by default Matryoshka truncation is
+/// applied to the normalized output.
+ Future<List<Float32List>>  embedWithOptions({required List<String> texts , required EmbedOptions options });
+
+
+/// Limits for the micro-batches `embed` splits large inputs into.
+ void  setBatchOptions({required BatchOptions options });
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
@@ -4,37 +4,143 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
+import '../chunking.dart';
+import '../embeddings.dart';
+import '../error.dart';
+import '../hub.dart';
+import '../logging.dart';
 import '../ort.dart';
+import '../tokenizer.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `fit_mask`, `pick_embedding_tensor`
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MiniLmEmbedder>>
-abstract class MiniLmEmbedder implements RustOpaqueInterface {
-  static MiniLmEmbedder create({
-    required String modelPath,
-    required String tokenizerPath,
-  }) => RustLib.instance.api.crateApiEmbeddingsMinilmMiniLmEmbedderCreate(
-    modelPath: modelPath,
-    tokenizerPath: tokenizerPath,
-  );
-
-  static MiniLmEmbedder createWithOptions({
-    required String modelPath,
-    required String tokenizerPath,
-    OrtInitOptions? ortOptions,
-  }) => RustLib.instance.api
-      .crateApiEmbeddingsMinilmMiniLmEmbedderCreateWithOptions(
-        modelPath: modelPath,
-        tokenizerPath: tokenizerPath,
-        ortOptions: ortOptions,
-      );
-
-  List<Float32List> embed({required List<String> texts});
-
-  static String formatDocument({required String text}) => RustLib.instance.api
-      .crateApiEmbeddingsMinilmMiniLmEmbedderFormatDocument(text: text);
-
-  static String formatQuery({required String query}) => RustLib.instance.api
-      .crateApiEmbeddingsMinilmMiniLmEmbedderFormatQuery(query: query);
-}
+
+            // These functions are ignored because they are not marked as `pub`: `embed_pooled`, `forward`, `from_parts`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `close`, `dimension`, `embed_tokens`, `embed_with_stats`, `embed`, `format_document`, `format_query`, `is_closed`, `model_info`, `reload_model`, `tokenizer`, `warmup`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MiniLmEmbedder>>
+                abstract class MiniLmEmbedder implements RustOpaqueInterface, TextEmbedder {
+                    /// Registers an extra session of the same model for `workload`, e.g. a
+/// CoreML/NNAPI session for interactive queries or a single-threaded CPU
+/// session for indexing. Calls without a matching route use the session
+/// the embedder was created with.
+ void  addSession({required Workload workload , required String modelPath , OrtInitOptions? ortOptions });
+
+
+static MiniLmEmbedder  create({required String modelPath , required String tokenizerPath })=>RustLib.instance.api.crateApiEmbeddingsMinilmMiniLmEmbedderCreate(modelPath: modelPath, tokenizerPath: tokenizerPath);
+
+
+/// `create` from in-memory model bytes and tokenizer JSON, e.g. for
+/// encrypted or downloaded assets that must not be written to disk.
+static MiniLmEmbedder  createFromBytes({required List<int> modelBytes , required String tokenizerJson })=>RustLib.instance.api.crateApiEmbeddingsMinilmMiniLmEmbedderCreateFromBytes(modelBytes: modelBytes, tokenizerJson: tokenizerJson);
+
+
+static MiniLmEmbedder  createFromBytesWithOptions({required List<int> modelBytes , required String tokenizerJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsMinilmMiniLmEmbedderCreateFromBytesWithOptions(modelBytes: modelBytes, tokenizerJson: tokenizerJson, ortOptions: ortOptions);
+
+
+/// `create` from a Hugging Face Hub repo, downloading the model and
+/// `tokenizer.json` into `cache_dir` on first use.
+static Future<MiniLmEmbedder>  createFromHub({required String repoId , required String cacheDir })=>RustLib.instance.api.crateApiEmbeddingsMinilmMiniLmEmbedderCreateFromHub(repoId: repoId, cacheDir: cacheDir);
+
+
+static Future<MiniLmEmbedder>  createFromHubWithOptions({required String repoId , required String cacheDir , HubOptions? hubOptions , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsMinilmMiniLmEmbedderCreateFromHubWithOptions(repoId: repoId, cacheDir: cacheDir, hubOptions: hubOptions, ortOptions: ortOptions);
+
+
+static MiniLmEmbedder  createWithOptions({required String modelPath , required String tokenizerPath , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsMinilmMiniLmEmbedderCreateWithOptions(modelPath: modelPath, tokenizerPath: tokenizerPath, ortOptions: ortOptions);
+
+
+/// Same as `embed`, but not `sync` on the Dart side: inference runs on a
+/// worker thread instead of blocking the calling isolate.
+ Future<List<Float32List>>  embedAsync({required List<String> texts });
+
+
+/// `embed_async` that checks `token` between micro-batches and fails
+/// with a cancellation error once it is cancelled.
+ Future<List<Float32List>>  embedAsyncCancellable({required List<String> texts , required CancellationToken token });
+
+
+/// Same as `embed`: MiniLM takes no document prefix.
+ List<Float32List>  embedDocuments({required List<String> texts });
+
+
+/// Late chunking: runs the whole `text` through the model once, then
+/// mean-pools each chunk's tokens (chunks from e.g. `split_by_sentences`),
+/// so every chunk vector keeps the context around it. `text` must fit
+/// in the model's context.
+ List<Float32List>  embedLateChunks({required String text , required List<TextChunk> chunks });
+
+
+/// Batch API for indexing large corpora: embeds in length-sorted chunks
+/// (see `EmbedManyOptions`) on the `Workload::Bulk` session. Use `embed`
+/// for small, latency-sensitive calls.
+ Future<List<Float32List>>  embedMany({required List<String> texts , EmbedManyOptions? options });
+
+
+/// Same as `embed_many`, updating `progress` after every chunk.
+ Future<List<Float32List>>  embedManyWithProgress({required List<String> texts , EmbedManyOptions? options , required EmbedProgress progress });
+
+
+/// Same as `embed`: MiniLM takes no query prefix. Provided so callers
+/// can use one API for every model.
+ List<Float32List>  embedQueries({required List<String> queries });
+
+
+/// Embeds `texts` in input order, `batch_size` at a time on the
+/// `Workload::Bulk` session, sending each batch to `sink` as soon as it
+/// is ready.
+ Stream<EmbeddingProgress>  embedStream({required List<String> texts , required int batchSize });
+
+
+/// `embed_stream` that ends after the current batch once `token` is
+/// cancelled.
+ Stream<EmbeddingProgress>  embedStreamCancellable({required List<String> texts , required int batchSize , required CancellationToken token });
+
+
+/// Embeds `(instruction, text)` pairs, so one batch can mix queries,
+/// passages and classification inputs. Instruction tokens are excluded
+/// from mean pooling.
+ List<Float32List>  embedWithInstructions({required List<(String?,String)> items });
+
+
+/// `embed` with per-call options; by default Matryoshka truncation is
+/// applied to the normalized output.
+ Future<List<Float32List>>  embedWithOptions({required List<String> texts , required EmbedOptions options });
+
+
+/// `embed` on the session registered for `workload`.
+ List<Float32List>  embedWithWorkload({required List<String> texts , required Workload workload });
+
+
+/// Per-token salience derived from the model's attention outputs, aligned
+/// to character offsets. Requires an ONNX export that exposes attentions;
+/// `attention_output` selects the output name when there are several.
+ List<TokenSalience>  explainTokens({required List<String> texts , String? attentionOutput });
+
+
+/// Number of sessions serving `workload`.
+ int  poolSize({required Workload workload });
+
+
+ bool  removeSession({required Workload workload });
+
+
+/// Limits for the micro-batches `embed` splits large inputs into.
+ void  setBatchOptions({required BatchOptions options });
+
+
+/// Keeps `size` sessions of the model for `workload` (the sessions the
+/// embedder was created with when no route is registered), so that many
+/// concurrent calls can run in parallel. Each extra session is loaded from
+/// `model_path` with its own copy of the weights; shrinking drops the
+/// extras.
+ void  setPoolSize({required Workload workload , required int size , required String modelPath , OrtInitOptions? ortOptions });
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
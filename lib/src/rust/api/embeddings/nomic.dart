@@ -0,0 +1,110 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../embeddings.dart';
+import '../error.dart';
+import '../hub.dart';
+import '../logging.dart';
+import '../ort.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `embed_truncated`, `from_parts`, `layer_norm`, `prefix`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `close`, `dimension`, `embed_tokens`, `embed_with_options`, `embed_with_stats`, `embed`, `format_document`, `format_query`, `is_closed`, `model_info`, `reload_model`, `tokenizer`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NomicEmbedder>>
+                abstract class NomicEmbedder implements RustOpaqueInterface, TextEmbedder {
+                    static NomicEmbedder  create({required String modelPath , required String tokenizerPath })=>RustLib.instance.api.crateApiEmbeddingsNomicNomicEmbedderCreate(modelPath: modelPath, tokenizerPath: tokenizerPath);
+
+
+/// `create` from in-memory model bytes and tokenizer JSON, e.g. for
+/// encrypted or downloaded assets that must not be written to disk.
+static NomicEmbedder  createFromBytes({required List<int> modelBytes , required String tokenizerJson })=>RustLib.instance.api.crateApiEmbeddingsNomicNomicEmbedderCreateFromBytes(modelBytes: modelBytes, tokenizerJson: tokenizerJson);
+
+
+static NomicEmbedder  createFromBytesWithOptions({required List<int> modelBytes , required String tokenizerJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsNomicNomicEmbedderCreateFromBytesWithOptions(modelBytes: modelBytes, tokenizerJson: tokenizerJson, ortOptions: ortOptions);
+
+
+/// `create` from a Hugging Face Hub repo, downloading the model and
+/// `tokenizer.json` into `cache_dir` on first use.
+static Future<NomicEmbedder>  createFromHub({required String repoId , required String cacheDir })=>RustLib.instance.api.crateApiEmbeddingsNomicNomicEmbedderCreateFromHub(repoId: repoId, cacheDir: cacheDir);
+
+
+static Future<NomicEmbedder>  createFromHubWithOptions({required String repoId , required String cacheDir , HubOptions? hubOptions , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsNomicNomicEmbedderCreateFromHubWithOptions(repoId: repoId, cacheDir: cacheDir, hubOptions: hubOptions, ortOptions: ortOptions);
+
+
+static NomicEmbedder  createWithOptions({required String modelPath , required String tokenizerPath , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsNomicNomicEmbedderCreateWithOptions(modelPath: modelPath, tokenizerPath: tokenizerPath, ortOptions: ortOptions);
+
+
+/// Same as `embed`, but runs off the Dart isolate.
+ Future<List<Float32List>>  embedAsync({required List<String> texts });
+
+
+/// `embed_async` that fails with a cancellation error once `token` is
+/// cancelled.
+ Future<List<Float32List>>  embedAsyncCancellable({required List<String> texts , required CancellationToken token });
+
+
+/// `embed_with_task` for the documents being searched.
+ List<Float32List>  embedDocuments({required List<String> texts });
+
+
+/// Embeds large corpora in length-sorted chunks (see `EmbedManyOptions`).
+ Future<List<Float32List>>  embedMany({required List<String> texts , EmbedManyOptions? options });
+
+
+/// `embed_with_task` for search queries.
+ List<Float32List>  embedQueries({required List<String> queries });
+
+
+/// Embeds `texts` in input order, `batch_size` at a time, sending each
+/// batch to `sink` as soon as it is ready.
+ Stream<EmbeddingProgress>  embedStream({required List<String> texts , required int batchSize });
+
+
+/// Prefixes every text for `task` and embeds the batch.
+ List<Float32List>  embedWithTask({required List<String> texts , required NomicTask task });
+
+
+/// Prefixes `text` for `task`.
+static String  formatWithTask({required String text , required NomicTask task })=>RustLib.instance.api.crateApiEmbeddingsNomicNomicEmbedderFormatWithTask(text: text, task: task);
+
+
+/// Limits for the micro-batches `embed` splits large inputs into.
+ void  setBatchOptions({required BatchOptions options });
+
+
+/// Matryoshka truncation for v1.5: vectors are layer-normed, cut to
+/// `output_dim` (e.g. 512, 256, 128, 64) and renormalized. `None`
+/// returns the full 768 dimensions.
+ void  setOutputDim({int? outputDim });
+
+
+/// Runs a one-word batch through the model so the first real `embed`
+/// doesn't pay for graph initialization.
+ Future<void>  warmup();
+
+
+
+                    
+                }
+                
+
+/// Task prefixes nomic-embed-text was trained with; every input needs one.
+enum NomicTask {
+                    searchQuery,
+searchDocument,
+clustering,
+classification,
+                    ;
+                    
+                }
+            
\ No newline at end of file
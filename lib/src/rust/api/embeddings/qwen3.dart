@@ -4,37 +4,161 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
+import '../embeddings.dart';
+import '../error.dart';
+import '../hub.dart';
+import '../logging.dart';
 import '../ort.dart';
+import '../tokenizer.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `fit_mask`, `pick_embedding_tensor`, `repeat_i64`, `resolve_past_kv_shape`, `resolve_shape_with_fallback`, `tensor_from_i64`, `zeros_tensor`
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Qwen3Embedder>>
-abstract class Qwen3Embedder implements RustOpaqueInterface {
-  static Qwen3Embedder create({
-    required String modelPath,
-    required String tokenizerPath,
-  }) => RustLib.instance.api.crateApiEmbeddingsQwen3Qwen3EmbedderCreate(
-    modelPath: modelPath,
-    tokenizerPath: tokenizerPath,
-  );
-
-  static Qwen3Embedder createWithOptions({
-    required String modelPath,
-    required String tokenizerPath,
-    OrtInitOptions? ortOptions,
-  }) => RustLib.instance.api
-      .crateApiEmbeddingsQwen3Qwen3EmbedderCreateWithOptions(
-        modelPath: modelPath,
-        tokenizerPath: tokenizerPath,
-        ortOptions: ortOptions,
-      );
-
-  List<Float32List> embed({required List<String> texts});
-
-  static String formatDocument({required String text}) => RustLib.instance.api
-      .crateApiEmbeddingsQwen3Qwen3EmbedderFormatDocument(text: text);
-
-  static String formatQuery({required String query}) => RustLib.instance.api
-      .crateApiEmbeddingsQwen3Qwen3EmbedderFormatQuery(query: query);
-}
+
+            // These functions are ignored because they are not marked as `pub`: `from_parts`, `instruct_prefix`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `close`, `dimension`, `embed_with_stats`, `embed`, `format_document`, `format_query`, `is_closed`, `model_info`, `reload_model`, `tokenizer`, `warmup`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Qwen3Embedder>>
+                abstract class Qwen3Embedder implements RustOpaqueInterface, TextEmbedder {
+                    /// Registers an extra session of the same model for `workload`, e.g. a
+/// CoreML/NNAPI session for interactive queries or a single-threaded CPU
+/// session for indexing. Calls without a matching route use the session
+/// the embedder was created with.
+ void  addSession({required Workload workload , required String modelPath , OrtInitOptions? ortOptions });
+
+
+static Qwen3Embedder  create({required String modelPath , required String tokenizerPath })=>RustLib.instance.api.crateApiEmbeddingsQwen3Qwen3EmbedderCreate(modelPath: modelPath, tokenizerPath: tokenizerPath);
+
+
+/// `create` from in-memory model bytes and tokenizer JSON, e.g. for
+/// encrypted or downloaded assets that must not be written to disk.
+static Qwen3Embedder  createFromBytes({required List<int> modelBytes , required String tokenizerJson })=>RustLib.instance.api.crateApiEmbeddingsQwen3Qwen3EmbedderCreateFromBytes(modelBytes: modelBytes, tokenizerJson: tokenizerJson);
+
+
+static Qwen3Embedder  createFromBytesWithOptions({required List<int> modelBytes , required String tokenizerJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsQwen3Qwen3EmbedderCreateFromBytesWithOptions(modelBytes: modelBytes, tokenizerJson: tokenizerJson, ortOptions: ortOptions);
+
+
+/// `create` from a Hugging Face Hub repo, downloading the model and
+/// `tokenizer.json` into `cache_dir` on first use.
+static Future<Qwen3Embedder>  createFromHub({required String repoId , required String cacheDir })=>RustLib.instance.api.crateApiEmbeddingsQwen3Qwen3EmbedderCreateFromHub(repoId: repoId, cacheDir: cacheDir);
+
+
+static Future<Qwen3Embedder>  createFromHubWithOptions({required String repoId , required String cacheDir , HubOptions? hubOptions , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsQwen3Qwen3EmbedderCreateFromHubWithOptions(repoId: repoId, cacheDir: cacheDir, hubOptions: hubOptions, ortOptions: ortOptions);
+
+
+static Qwen3Embedder  createWithOptions({required String modelPath , required String tokenizerPath , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsQwen3Qwen3EmbedderCreateWithOptions(modelPath: modelPath, tokenizerPath: tokenizerPath, ortOptions: ortOptions);
+
+
+/// Same as `embed`, but not `sync` on the Dart side: inference runs on a
+/// worker thread instead of blocking the calling isolate.
+ Future<List<Float32List>>  embedAsync({required List<String> texts });
+
+
+/// `embed_async` that checks `token` between micro-batches and fails
+/// with a cancellation error once it is cancelled.
+ Future<List<Float32List>>  embedAsyncCancellable({required List<String> texts , required CancellationToken token });
+
+
+/// Formats every text with `format_document` and embeds the batch.
+ List<Float32List>  embedDocuments({required List<String> texts });
+
+
+/// Batch API for indexing large corpora: embeds in length-sorted chunks
+/// (see `EmbedManyOptions`) on the `Workload::Bulk` session. Use `embed`
+/// for small, latency-sensitive calls.
+ Future<List<Float32List>>  embedMany({required List<String> texts , EmbedManyOptions? options });
+
+
+/// Same as `embed_many`, updating `progress` after every chunk.
+ Future<List<Float32List>>  embedManyWithProgress({required List<String> texts , EmbedManyOptions? options , required EmbedProgress progress });
+
+
+/// Formats every query with the instruction set by `set_task` and
+/// embeds the batch.
+ List<Float32List>  embedQueries({required List<String> queries });
+
+
+/// Embeds `texts` in input order, `batch_size` at a time on the
+/// `Workload::Bulk` session, sending each batch to `sink` as soon as it
+/// is ready.
+ Stream<EmbeddingProgress>  embedStream({required List<String> texts , required int batchSize });
+
+
+/// `embed_stream` that ends after the current batch once `token` is
+/// cancelled.
+ Stream<EmbeddingProgress>  embedStreamCancellable({required List<String> texts , required int batchSize , required CancellationToken token });
+
+
+/// Unpooled per-token vectors; only encoder models that expose
+/// `last_hidden_state` support it.
+ Future<List<TokenEmbeddings>>  embedTokens({required List<String> texts });
+
+
+/// Embeds `(instruction, text)` pairs, so one batch can mix queries,
+/// passages and classification inputs. Texts with an instruction use the
+/// `Instruct: …\nQuery:` template; texts without one are embedded as-is.
+ List<Float32List>  embedWithInstructions({required List<(String?,String)> items });
+
+
+/// `embed` with Matryoshka truncation to `options.output_dim`.
+ List<Float32List>  embedWithOptions({required List<String> texts , required EmbedOptions options });
+
+
+/// `embed` on the session registered for `workload`.
+ List<Float32List>  embedWithWorkload({required List<String> texts , required Workload workload });
+
+
+/// EOS id detected from the tokenizer vocabulary, if any.
+ int?  eosTokenId();
+
+
+/// `format_query` with the instruction set by `set_task`.
+ String  formatQueryForTask({required String query });
+
+
+/// Formats `query` with a one-sentence `task` description, e.g. "Given a
+/// code question, retrieve code snippets that answer it" or "Classify the
+/// sentiment of a product review". Documents need no instruction.
+static String  formatQueryWithTask({required String task , required String query })=>RustLib.instance.api.crateApiEmbeddingsQwen3Qwen3EmbedderFormatQueryWithTask(task: task, query: query);
+
+
+/// Number of sessions serving `workload`.
+ int  poolSize({required Workload workload });
+
+
+ bool  removeSession({required Workload workload });
+
+
+/// Last-token pooling expects every sequence to end with EOS. Enabled by
+/// default; the token is only appended when the tokenizer didn't add it.
+ void  setAppendEos({required bool enabled });
+
+
+/// Limits for the micro-batches `embed` splits large inputs into.
+ void  setBatchOptions({required BatchOptions options });
+
+
+/// Keeps `size` sessions of the model for `workload` (the sessions the
+/// embedder was created with when no route is registered), so that many
+/// concurrent calls can run in parallel. Each extra session is loaded from
+/// `model_path` with its own copy of the weights; shrinking drops the
+/// extras.
+ void  setPoolSize({required Workload workload , required int size , required String modelPath , OrtInitOptions? ortOptions });
+
+
+/// Instruction used when formatting queries through `TextEmbedder` (e.g.
+/// `Embedder.format_query`) and `format_query_for_task`. `None` restores
+/// the web-search retrieval default.
+ void  setTask({String? task });
+
+
+ String  task();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
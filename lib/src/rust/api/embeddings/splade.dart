@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../error.dart';
+import '../logging.dart';
+import '../ort.dart';
+import '../utils.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `from_parts`, `to_sparse`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SpladeEmbedder>>
+                abstract class SpladeEmbedder implements RustOpaqueInterface {
+                    /// Frees the ONNX session and tokenizer now rather than when the object
+/// is dropped. Later calls fail with `ErrorKind::Closed`.
+ void  close();
+
+
+static SpladeEmbedder  create({required String modelPath , required String tokenizerPath })=>RustLib.instance.api.crateApiEmbeddingsSpladeSpladeEmbedderCreate(modelPath: modelPath, tokenizerPath: tokenizerPath);
+
+
+/// `create` from in-memory model bytes and tokenizer JSON, e.g. for
+/// encrypted or downloaded assets that must not be written to disk.
+static SpladeEmbedder  createFromBytes({required List<int> modelBytes , required String tokenizerJson })=>RustLib.instance.api.crateApiEmbeddingsSpladeSpladeEmbedderCreateFromBytes(modelBytes: modelBytes, tokenizerJson: tokenizerJson);
+
+
+static SpladeEmbedder  createFromBytesWithOptions({required List<int> modelBytes , required String tokenizerJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsSpladeSpladeEmbedderCreateFromBytesWithOptions(modelBytes: modelBytes, tokenizerJson: tokenizerJson, ortOptions: ortOptions);
+
+
+static SpladeEmbedder  createWithOptions({required String modelPath , required String tokenizerPath , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsSpladeSpladeEmbedderCreateWithOptions(modelPath: modelPath, tokenizerPath: tokenizerPath, ortOptions: ortOptions);
+
+
+/// Size of the vocabulary; every `SparseVector` index is below it.
+ BigInt  dimension();
+
+
+ List<SparseVector>  embed({required List<String> texts });
+
+
+ bool  isClosed();
+
+
+/// Inputs, outputs and metadata of the loaded ONNX model.
+ ModelInfo  modelInfo();
+
+
+/// The `k` highest-weighted terms of `vector` as `(token, weight)`, for
+/// inspecting what a document was expanded to. Empty once closed.
+ List<(String,double)>  topTerms({required SparseVector vector , required int k });
+
+
+/// Runs a one-word batch through the model so the first real `embed`
+/// doesn't pay for graph initialization.
+ Future<void>  warmup();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
@@ -0,0 +1,105 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../error.dart';
+import '../image.dart';
+import '../logging.dart';
+import '../ort.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `embed_pixels`, `embed_rgb`, `from_parts`, `pooled_or_cls`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VisionEmbedder>>
+                abstract class VisionEmbedder implements RustOpaqueInterface {
+                    /// Frees the ONNX session now rather than when the object is dropped.
+/// Later calls fail with `ErrorKind::Closed`.
+ void  close();
+
+
+static VisionEmbedder  create({required String modelPath , required VisionPreset preset })=>RustLib.instance.api.crateApiEmbeddingsVisionVisionEmbedderCreate(modelPath: modelPath, preset: preset);
+
+
+/// `create` from in-memory model bytes.
+static VisionEmbedder  createFromBytes({required List<int> modelBytes , required VisionPreset preset })=>RustLib.instance.api.crateApiEmbeddingsVisionVisionEmbedderCreateFromBytes(modelBytes: modelBytes, preset: preset);
+
+
+static VisionEmbedder  createFromBytesWithOptions({required List<int> modelBytes , required VisionPreset preset , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsVisionVisionEmbedderCreateFromBytesWithOptions(modelBytes: modelBytes, preset: preset, ortOptions: ortOptions);
+
+
+static VisionEmbedder  createWithOptions({required String modelPath , required VisionPreset preset , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiEmbeddingsVisionVisionEmbedderCreateWithOptions(modelPath: modelPath, preset: preset, ortOptions: ortOptions);
+
+
+/// Length of the image vectors, read from the model outputs or, when
+/// the export leaves it dynamic, from a blank-image dry run.
+ BigInt  dimension();
+
+
+/// Embeds encoded images (PNG or JPEG bytes).
+ List<Float32List>  embedImages({required List<Uint8List> imageBytes });
+
+
+/// Same as `embed_images`, but runs on a worker thread.
+ Future<List<Float32List>>  embedImagesAsync({required List<Uint8List> imageBytes });
+
+
+/// Embeds raw RGBA buffers; alpha is ignored.
+ List<Float32List>  embedRgba({required List<RgbaPixels> images });
+
+
+/// Same as `embed_rgba`, but runs on a worker thread.
+ Future<List<Float32List>>  embedRgbaAsync({required List<RgbaPixels> images });
+
+
+/// Side length of the square model input.
+ int  imageSize();
+
+
+ bool  isClosed();
+
+
+/// Inputs, outputs and metadata of the loaded ONNX model.
+ ModelInfo  modelInfo();
+
+
+/// The resize and normalization applied to every image.
+ PreprocessOptions  preprocessing();
+
+
+/// Replaces the preset's preprocessing, e.g. for a fine-tuned model
+/// with different normalization. The layout must stay `Chw`.
+ void  setPreprocessing({required PreprocessOptions preprocessing });
+
+
+/// Runs a blank image through the model so the first real call doesn't
+/// pay for graph initialization.
+ Future<void>  warmup();
+
+
+
+                    
+                }
+                
+
+/// Preprocessing conventions of the supported vision towers.
+enum VisionPreset {
+                    /// DINOv2: shorter side to 256/224 of the input size, center crop,
+/// ImageNet normalization. Embeddings are the `[CLS]` token.
+dinov2,
+/// SigLIP: squashed to the input size, normalized to `[-1, 1]`.
+siglip,
+/// CLIP: shorter side to the input size, center crop, CLIP
+/// normalization.
+clip,
+                    ;
+                    
+                }
+            
\ No newline at end of file
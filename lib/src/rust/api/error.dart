@@ -0,0 +1,75 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `new`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`
+
+
+            
+
+            /// What went wrong, so Dart can branch on the cause instead of the message.
+enum ErrorKind {
+                    /// `tokenizer.json` is missing, unreadable or malformed.
+tokenizerLoad,
+/// The tokenizer rejected an input.
+tokenization,
+/// The ONNX model is missing, unreadable or malformed.
+modelLoad,
+/// An argument is out of range or inconsistent with the model.
+invalidInput,
+/// A model output does not have the expected shape.
+shapeMismatch,
+/// The model lacks an output the embedder needs.
+missingOutput,
+/// A tensor uses an element type that is not supported.
+unsupportedDtype,
+/// ONNX Runtime failed while running the model.
+ortRuntime,
+/// The call was cancelled through a `CancellationToken`.
+cancelled,
+/// Reading or writing a file failed, or its contents are corrupt.
+io,
+/// A download failed or the file is not available offline.
+network,
+/// A downloaded file does not match its expected checksum.
+checksumMismatch,
+/// The embedder was closed and its model freed.
+closed,
+/// A call panicked, or a shared store was left unusable by a panic in
+/// another call; a bug in this crate rather than in the caller's input.
+internal,
+                    ;
+                    
+                }
+
+/// Error returned by every fallible API in this crate.
+class FlutterEmbedderError implements FrbException {
+                final ErrorKind kind;
+final String message;
+
+                const FlutterEmbedderError({required this.kind ,required this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterEmbedderError &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& message == other.message;
+        
+            }
+            
\ No newline at end of file
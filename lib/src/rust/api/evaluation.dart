@@ -0,0 +1,183 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'embeddings.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `check_case`, `mean_std`, `nearest`, `pearson`, `set_stats`, `similarity_matrix`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Compares two embeddings of the same texts produced by different models.
+///
+/// Vectors in `a` and `b` must be aligned (same text at the same index); the
+/// two sets may have different dimensions.
+Future<EmbedderComparison>  compareEmbeddingSets({required List<Float32List> a , required List<Float32List> b , int? k }) => RustLib.instance.api.crateApiEvaluationCompareEmbeddingSets(a: a, b: b, k: k);
+
+/// Embeds `texts` with the embedders loaded under `embedder_id_a` and
+/// `embedder_id_b` and compares the results like `compare_embedding_sets`,
+/// for A/B-testing a candidate model against the current one on-device.
+Future<EmbedderComparison>  compareEmbedders({required BigInt embedderIdA , required BigInt embedderIdB , required List<String> texts , int? k }) => RustLib.instance.api.crateApiEvaluationCompareEmbedders(embedderIdA: embedderIdA, embedderIdB: embedderIdB, texts: texts, k: k);
+
+/// Checks vectors produced on-device against shipped reference vectors.
+///
+/// `actual[i]` must be the embedding of `cases[i].text`. Use this at startup
+/// to detect broken quantized builds or execution-provider numerical drift.
+List<ReferenceCheck>  checkReferenceVectors({required List<ReferenceCase> cases , required List<Float32List> actual }) => RustLib.instance.api.crateApiEvaluationCheckReferenceVectors(cases: cases, actual: actual);
+
+/// Embeds each case's text with the embedder loaded under `embedder_id` and
+/// checks it against the case's reference vector and tolerance, e.g.
+/// vectors shipped with the app and produced on a desktop with the same
+/// model.
+Future<List<ReferenceCheck>>  verifyAgainstReference({required BigInt embedderId , required List<ReferenceCase> cases }) => RustLib.instance.api.crateApiEvaluationVerifyAgainstReference(embedderId: embedderId, cases: cases);
+
+/// Self-test for a loaded model: embeds `reference_inputs` and compares
+/// each vector with the matching `reference_embeddings` entry, e.g. vectors
+/// produced on a desktop with the same model and shipped with the app. A
+/// failure points at a corrupted download or execution-provider drift.
+Future<ModelVerification>  verifyModel({required Embedder embedder , required List<String> referenceInputs , required List<Float32List> referenceEmbeddings , required double tolerance }) => RustLib.instance.api.crateApiEvaluationVerifyModel(embedder: embedder, referenceInputs: referenceInputs, referenceEmbeddings: referenceEmbeddings, tolerance: tolerance);
+
+            class EmbedderComparison  {
+                final int count;
+/// Pearson correlation of the pairwise similarity structure.
+final double pearson;
+/// Spearman rank correlation of the pairwise similarity structure.
+final double spearman;
+/// Mean fraction of shared nearest neighbours at `k`.
+final double neighborOverlap;
+final int k;
+final EmbeddingSetStats statsA;
+final EmbeddingSetStats statsB;
+
+                const EmbedderComparison({required this.count ,required this.pearson ,required this.spearman ,required this.neighborOverlap ,required this.k ,required this.statsA ,required this.statsB ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => count.hashCode^pearson.hashCode^spearman.hashCode^neighborOverlap.hashCode^k.hashCode^statsA.hashCode^statsB.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EmbedderComparison &&
+                runtimeType == other.runtimeType
+                && count == other.count&& pearson == other.pearson&& spearman == other.spearman&& neighborOverlap == other.neighborOverlap&& k == other.k&& statsA == other.statsA&& statsB == other.statsB;
+        
+            }
+
+class EmbeddingSetStats  {
+                final int dimension;
+final double meanNorm;
+/// Mean and standard deviation of the off-diagonal cosine similarities.
+final double meanSimilarity;
+final double stdSimilarity;
+
+                const EmbeddingSetStats({required this.dimension ,required this.meanNorm ,required this.meanSimilarity ,required this.stdSimilarity ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => dimension.hashCode^meanNorm.hashCode^meanSimilarity.hashCode^stdSimilarity.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EmbeddingSetStats &&
+                runtimeType == other.runtimeType
+                && dimension == other.dimension&& meanNorm == other.meanNorm&& meanSimilarity == other.meanSimilarity&& stdSimilarity == other.stdSimilarity;
+        
+            }
+
+class ModelVerification  {
+                /// Whether every reference input stayed within the tolerance.
+final bool passed;
+/// Largest `1 - cosine similarity` over the inputs.
+final double maxDeviation;
+final List<ReferenceCheck> checks;
+
+                const ModelVerification({required this.passed ,required this.maxDeviation ,required this.checks ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => passed.hashCode^maxDeviation.hashCode^checks.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ModelVerification &&
+                runtimeType == other.runtimeType
+                && passed == other.passed&& maxDeviation == other.maxDeviation&& checks == other.checks;
+        
+            }
+
+class ReferenceCase  {
+                final String text;
+final Float32List expected;
+/// Maximum accepted cosine deviation (`1 - cosine similarity`).
+final double tolerance;
+
+                const ReferenceCase({required this.text ,required this.expected ,required this.tolerance ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^expected.hashCode^tolerance.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReferenceCase &&
+                runtimeType == other.runtimeType
+                && text == other.text&& expected == other.expected&& tolerance == other.tolerance;
+        
+            }
+
+class ReferenceCheck  {
+                final String text;
+/// `1 - cosine similarity` between the produced and the reference vector.
+final double deviation;
+final bool passed;
+final String? message;
+
+                const ReferenceCheck({required this.text ,required this.deviation ,required this.passed ,this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^deviation.hashCode^passed.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReferenceCheck &&
+                runtimeType == other.runtimeType
+                && text == other.text&& deviation == other.deviation&& passed == other.passed&& message == other.message;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,22 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `bytes`, `cached_fp16_model`, `cast_node`, `convert_attribute`, `convert_elem_type`, `convert_graph`, `convert_nested_type`, `convert_node`, `convert_onnx_to_fp16`, `convert_tensor`, `convert_type`, `convert_value_info`, `encode_fields`, `find_string`, `has_float_tensor_type`, `message`, `parse_fields`, `read_varint`, `take`, `to_f16`, `varint`, `write_atomically`, `write_varint`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Field`, `Value`
+
+
+            /// Converts the fp32 weights and activations of an ONNX model to fp16,
+/// keeping fp32 graph inputs and outputs (casts are inserted at the edges).
+///
+/// Models whose weights live in external data files are not supported.
+Future<void>  convertModelToFp16({required String modelPath , required String outputPath }) => RustLib.instance.api.crateApiFp16ConvertModelToFp16(modelPath: modelPath, outputPath: outputPath);
+
+            
+            
\ No newline at end of file
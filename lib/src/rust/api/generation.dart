@@ -0,0 +1,149 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'logging.dart';
+import 'ort.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `argmax`, `decode_tokens`, `decode`, `decoder_step`, `from_parts`, `last_row`, `new`, `partial_stop_len`, `present_name`, `rank`, `sample`, `stop_position`, `supports_kv_cache`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Sampler`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TextGenerator>>
+                abstract class TextGenerator implements RustOpaqueInterface {
+                    static TextGenerator  create({required String modelPath , required String tokenizerPath })=>RustLib.instance.api.crateApiGenerationTextGeneratorCreate(modelPath: modelPath, tokenizerPath: tokenizerPath);
+
+
+/// `create` from in-memory model bytes and tokenizer JSON.
+static TextGenerator  createFromBytes({required List<int> modelBytes , required String tokenizerJson })=>RustLib.instance.api.crateApiGenerationTextGeneratorCreateFromBytes(modelBytes: modelBytes, tokenizerJson: tokenizerJson);
+
+
+static TextGenerator  createFromBytesWithOptions({required List<int> modelBytes , required String tokenizerJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiGenerationTextGeneratorCreateFromBytesWithOptions(modelBytes: modelBytes, tokenizerJson: tokenizerJson, ortOptions: ortOptions);
+
+
+static TextGenerator  createWithOptions({required String modelPath , required String tokenizerPath , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiGenerationTextGeneratorCreateWithOptions(modelPath: modelPath, tokenizerPath: tokenizerPath, ortOptions: ortOptions);
+
+
+/// End-of-sequence tokens used when the options don't name any.
+ Uint32List  eosTokenIds();
+
+
+/// Continues `prompt` until an end-of-sequence token, a stop sequence or
+/// `max_new_tokens`.
+ GenerationResult  generate({required String prompt , GenerationOptions? options });
+
+
+/// Same as `generate`, but not `sync` on the Dart side.
+ Future<GenerationResult>  generateAsync({required String prompt , GenerationOptions? options });
+
+
+/// `generate` that sends the text to `sink` piece by piece as it is
+/// produced. Text that could be the start of a stop sequence is held
+/// back until it is clear it isn't one. Generation ends early once the
+/// Dart side stops listening.
+ Stream<String>  generateStream({required String prompt , GenerationOptions? options });
+
+
+/// Whether the model reuses its key/value cache between steps.
+ bool  hasKvCache();
+
+
+/// Inputs, outputs and metadata of the loaded ONNX model.
+ ModelInfo  modelInfo();
+
+
+
+                    
+                }
+                
+
+enum FinishReason {
+                    /// An end-of-sequence token was produced.
+eos,
+/// `max_new_tokens` was reached.
+maxTokens,
+/// One of the `stop_sequences` was produced.
+stopSequence,
+/// The Dart side stopped listening to the stream.
+cancelled,
+                    ;
+                    
+                }
+
+class GenerationOptions  {
+                /// Upper bound on generated tokens (default 128).
+final int? maxNewTokens;
+/// Sampling temperature; `None` or 0 decodes greedily.
+final double? temperature;
+/// Sample only among the `top_k` most likely tokens.
+final int? topK;
+/// Sample only among the most likely tokens whose probabilities add up
+/// to `top_p` (nucleus sampling).
+final double? topP;
+/// Seed for sampling, so runs are reproducible (default 42).
+final BigInt? seed;
+/// Tokens that end generation. Defaults to the tokenizer's known
+/// end-of-sequence tokens (`<|endoftext|>`, `<|im_end|>`, `</s>`, ...).
+final Uint32List? eosTokenIds;
+/// Generation stops before the first occurrence of any of these
+/// strings, which are not part of the result.
+final List<String>? stopSequences;
+
+                const GenerationOptions({this.maxNewTokens ,this.temperature ,this.topK ,this.topP ,this.seed ,this.eosTokenIds ,this.stopSequences ,});
+
+                static Future<GenerationOptions>  default_()=>RustLib.instance.api.crateApiGenerationGenerationOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => maxNewTokens.hashCode^temperature.hashCode^topK.hashCode^topP.hashCode^seed.hashCode^eosTokenIds.hashCode^stopSequences.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GenerationOptions &&
+                runtimeType == other.runtimeType
+                && maxNewTokens == other.maxNewTokens&& temperature == other.temperature&& topK == other.topK&& topP == other.topP&& seed == other.seed&& eosTokenIds == other.eosTokenIds&& stopSequences == other.stopSequences;
+        
+            }
+
+class GenerationResult  {
+                /// The generated text, without the prompt and special tokens.
+final String text;
+/// Generated token ids, including a final EOS token.
+final Uint32List tokenIds;
+final FinishReason finishReason;
+
+                const GenerationResult({required this.text ,required this.tokenIds ,required this.finishReason ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^tokenIds.hashCode^finishReason.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GenerationResult &&
+                runtimeType == other.runtimeType
+                && text == other.text&& tokenIds == other.tokenIds&& finishReason == other.finishReason;
+        
+            }
+            
\ No newline at end of file
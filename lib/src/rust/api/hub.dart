@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cache_path`, `download`, `file_url`, `remote_exists`, `resolve_hub_files`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Local path of `filename` from the Hub repo `repo_id` at `revision`
+/// (default `main`), downloading it into `cache_dir` on first use. With
+/// `offline`, a cache miss is an error instead of a download.
+///
+/// Files are cached per revision and never re-checked, so pin a commit or
+/// tag when `main` may move.
+Future<String>  resolveHfModel({required String repoId , required String filename , String? revision , required String cacheDir , required bool offline }) => RustLib.instance.api.crateApiHubResolveHfModel(repoId: repoId, filename: filename, revision: revision, cacheDir: cacheDir, offline: offline);
+
+            /// Where `create_from_hub` finds its files in a Hub repo.
+class HubOptions  {
+                /// Branch, tag or commit. Default `main`.
+final String? revision;
+/// ONNX file within the repo. Default `onnx/model.onnx`; pick e.g.
+/// `onnx/model_quantized.onnx` for a smaller variant.
+final String? modelFile;
+/// Only use files already in the cache; never touch the network.
+final bool offline;
+
+                const HubOptions({this.revision ,this.modelFile ,required this.offline ,});
+
+                static Future<HubOptions>  default_()=>RustLib.instance.api.crateApiHubHubOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => revision.hashCode^modelFile.hashCode^offline.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HubOptions &&
+                runtimeType == other.runtimeType
+                && revision == other.revision&& modelFile == other.modelFile&& offline == other.offline;
+        
+            }
+            
\ No newline at end of file
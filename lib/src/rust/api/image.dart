@@ -0,0 +1,114 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `decode_image`, `preprocess_rgb`, `rgba_to_rgb`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Decodes an encoded image (PNG or JPEG) and returns it as a normalized
+/// `width * height * 3` float tensor in `options.layout` order.
+Float32List  preprocess({required List<int> imageBytes , required PreprocessOptions options }) => RustLib.instance.api.crateApiImagePreprocess(imageBytes: imageBytes, options: options);
+
+/// `preprocess` for a raw RGBA buffer; alpha is ignored.
+Float32List  preprocessRgba({required RgbaPixels pixels , required PreprocessOptions options }) => RustLib.instance.api.crateApiImagePreprocessRgba(pixels: pixels, options: options);
+
+            class PreprocessOptions  {
+                final int width;
+final int height;
+/// Per-channel (RGB) mean and standard deviation; pixels are scaled to
+/// `[0, 1]` and then normalized as `(x - mean) / std`.
+final Float32List mean;
+final Float32List std;
+final TensorLayout layout;
+final ResizeFilter filter;
+final ResizeMode mode;
+/// For `CenterCrop`, the fraction of the resized image kept by the
+/// crop, e.g. 0.875 for the "resize to 256, crop 224" convention
+/// (default 1).
+final double? cropFraction;
+
+                const PreprocessOptions({required this.width ,required this.height ,required this.mean ,required this.std ,required this.layout ,required this.filter ,required this.mode ,this.cropFraction ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => width.hashCode^height.hashCode^mean.hashCode^std.hashCode^layout.hashCode^filter.hashCode^mode.hashCode^cropFraction.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PreprocessOptions &&
+                runtimeType == other.runtimeType
+                && width == other.width&& height == other.height&& mean == other.mean&& std == other.std&& layout == other.layout&& filter == other.filter&& mode == other.mode&& cropFraction == other.cropFraction;
+        
+            }
+
+/// Resampling filter used when resizing.
+enum ResizeFilter {
+                    nearest,
+bilinear,
+bicubic,
+                    ;
+                    
+                }
+
+/// How the image is fitted to the target size.
+enum ResizeMode {
+                    /// Resize to exactly the target size, ignoring the aspect ratio.
+stretch,
+/// Scale to cover the target, then crop the center.
+centerCrop,
+/// Scale to fit inside the target and pad the borders with black.
+pad,
+                    ;
+                    
+                }
+
+/// An uncompressed RGBA image, e.g. from `ui.Image.toByteData`.
+class RgbaPixels  {
+                final int width;
+final int height;
+/// `width * height * 4` bytes, row-major.
+final Uint8List rgba;
+
+                const RgbaPixels({required this.width ,required this.height ,required this.rgba ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => width.hashCode^height.hashCode^rgba.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RgbaPixels &&
+                runtimeType == other.runtimeType
+                && width == other.width&& height == other.height&& rgba == other.rgba;
+        
+            }
+
+/// Memory order of the returned tensor.
+enum TensorLayout {
+                    /// `[3, height, width]`, as PyTorch-exported models expect.
+chw,
+/// `[height, width, 3]`, as TensorFlow-exported models expect.
+hwc,
+                    ;
+                    
+                }
+            
\ No newline at end of file
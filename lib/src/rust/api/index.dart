@@ -0,0 +1,112 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'search.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `as_slice`, `decode`, `distance`, `floats_from_le`, `greedy_closest`, `insert`, `link`, `max_links`, `push`, `random_level`, `read_u32`, `search_layer`, `select_neighbours`, `vector`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Candidate`, `Node`, `Vectors`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `cmp`, `eq`, `fmt`, `fmt`, `partial_cmp`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VectorIndex>>
+                abstract class VectorIndex implements RustOpaqueInterface {
+                    /// Inserts `vector` under `id`, replacing any vector already stored for it.
+ void  add({required String id , required List<double> vector });
+
+
+/// Inserts `ids[i]` → `vectors[i]` for every pair.
+ void  addMany({required List<String> ids , required List<Float32List> vectors });
+
+
+/// Rebuilds the graph without removed vectors, reclaiming their memory.
+ void  compact();
+
+
+ bool  contains({required String id });
+
+
+/// Vector dimension, fixed by the first insert (0 while empty).
+ int  dimension();
+
+
+ bool  isEmpty();
+
+
+/// Number of live (not removed) vectors.
+ int  len();
+
+
+/// Reads an index written by `save` into memory.
+static VectorIndex  load({required String path })=>RustLib.instance.api.crateApiIndexVectorIndexLoad(path: path);
+
+
+factory VectorIndex({VectorIndexOptions? options })=>RustLib.instance.api.crateApiIndexVectorIndexNew(options: options);
+
+
+/// Like `load`, but memory-maps the file and searches the vectors in
+/// place instead of reading them, so opening a large index costs little
+/// more than reading its graph. The file must not be modified while the
+/// index is open; the first `add` or `compact` copies the vectors to
+/// memory.
+static VectorIndex  open({required String path })=>RustLib.instance.api.crateApiIndexVectorIndexOpen(path: path);
+
+
+/// Removes `id`; returns whether it was present.
+ bool  remove({required String id });
+
+
+/// Writes the index, graph included, to `path`. The file is written
+/// next to `path` and renamed over it, so an index `open`ed from `path`
+/// can be saved back to it.
+ void  save({required String path });
+
+
+/// The `k` stored vectors most similar to `query`.
+ List<SearchHit>  search({required List<double> query , required int k });
+
+
+
+                    
+                }
+                
+
+class VectorIndexOptions  {
+                /// Links per node on the upper layers (twice that on layer 0). Default 16.
+final int? m;
+/// Candidate list size while inserting. Default 100.
+final int? efConstruction;
+/// Candidate list size while searching; raised to `k` when smaller.
+/// Default 64.
+final int? efSearch;
+
+                const VectorIndexOptions({this.m ,this.efConstruction ,this.efSearch ,});
+
+                static Future<VectorIndexOptions>  default_()=>RustLib.instance.api.crateApiIndexVectorIndexOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => m.hashCode^efConstruction.hashCode^efSearch.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VectorIndexOptions &&
+                runtimeType == other.runtimeType
+                && m == other.m&& efConstruction == other.efConstruction&& efSearch == other.efSearch;
+        
+            }
+            
\ No newline at end of file
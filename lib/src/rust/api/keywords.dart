@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'embeddings.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'tokenizer.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `word_spans`, `word_weights`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Keyword weights from hidden-state norms: tokens whose contextual vectors
+/// have a larger L2 norm tend to carry more content than function words.
+List<KeywordWeight>  keywordsFromTokenEmbeddings({required String text , required TokenEmbeddings tokenEmbeddings , int? topK }) => RustLib.instance.api.crateApiKeywordsKeywordsFromTokenEmbeddings(text: text, tokenEmbeddings: tokenEmbeddings, topK: topK);
+
+/// Keyword weights from attention salience (see `explain_tokens`).
+List<KeywordWeight>  keywordsFromSalience({required String text , required TokenSalience salience , int? topK }) => RustLib.instance.api.crateApiKeywordsKeywordsFromSalience(text: text, salience: salience, topK: topK);
+
+            class KeywordWeight  {
+                final String word;
+/// Byte offsets of the word in the source text.
+final int start;
+final int end;
+final double weight;
+
+                const KeywordWeight({required this.word ,required this.start ,required this.end ,required this.weight ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => word.hashCode^start.hashCode^end.hashCode^weight.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is KeywordWeight &&
+                runtimeType == other.runtimeType
+                && word == other.word&& start == other.start&& end == other.end&& weight == other.weight;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,63 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `from_tracing`, `ort_logger`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `MessageVisitor`, `SinkSubscriber`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `cmp`, `eq`, `fmt`, `fmt`, `partial_cmp`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `enabled`, `enter`, `event`, `exit`, `new_span`, `record_debug`, `record_follows_from`, `record_str`, `record`, `register_callsite`
+
+
+            /// Sends log entries at `level` and more severe to `sink`, e.g. which
+/// output tensor an embedder picked or why an execution provider fell back
+/// to CPU. Calling it again replaces the sink and level. Fails when another
+/// `tracing` subscriber was installed globally first.
+Stream<LogEntry>  initLogging({required LogLevel level }) => RustLib.instance.api.crateApiLoggingInitLogging(level: level);
+
+/// Stops forwarding log entries and closes the Dart stream.
+void  stopLogging() => RustLib.instance.api.crateApiLoggingStopLogging();
+
+            class LogEntry  {
+                final LogLevel level;
+/// Module that logged, e.g. `flutter_embedder::api::ort` or `ort`.
+final String target;
+/// The message followed by any structured fields as `key=value`.
+final String message;
+final BigInt timestampMs;
+
+                const LogEntry({required this.level ,required this.target ,required this.message ,required this.timestampMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => level.hashCode^target.hashCode^message.hashCode^timestampMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LogEntry &&
+                runtimeType == other.runtimeType
+                && level == other.level&& target == other.target&& message == other.message&& timestampMs == other.timestampMs;
+        
+            }
+
+enum LogLevel {
+                    error,
+warn,
+info,
+debug,
+trace,
+                    ;
+                    
+                }
+            
\ No newline at end of file
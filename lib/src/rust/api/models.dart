@@ -0,0 +1,96 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `download_with`, `finish`, `hash_reader`, `part_path`, `read_registry`, `to_hex`, `write_registry`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Downloads `url` to `dest`, streaming progress to `sink`.
+///
+/// Data is written to `<dest>.part` first, so an interrupted download is
+/// resumed with a range request on the next call. When `expected_sha256`
+/// (hex) is given, the finished file must match it or it is deleted and an
+/// error returned. The file is moved to `dest` only once complete.
+Stream<DownloadProgress>  downloadModel({required String url , required String dest , String? expectedSha256 }) => RustLib.instance.api.crateApiModelsDownloadModel(url: url, dest: dest, expectedSha256: expectedSha256);
+
+/// Lowercase hex SHA-256 of the file at `path`.
+String  sha256File({required String path }) => RustLib.instance.api.crateApiModelsSha256File(path: path);
+
+/// Records `model` in the registry of `models_dir`, replacing any entry with
+/// the same name and revision.
+void  registerModel({required String modelsDir , required InstalledModel model }) => RustLib.instance.api.crateApiModelsRegisterModel(modelsDir: modelsDir, model: model);
+
+/// Installed models in `models_dir`, sorted by name then revision. Entries
+/// whose file has since been deleted are skipped.
+List<InstalledModel>  listInstalledModels({required String modelsDir }) => RustLib.instance.api.crateApiModelsListInstalledModels(modelsDir: modelsDir);
+
+/// The installed model called `name`; with no `revision`, the most recently
+/// registered one.
+InstalledModel?  findInstalledModel({required String modelsDir , required String name , String? revision }) => RustLib.instance.api.crateApiModelsFindInstalledModel(modelsDir: modelsDir, name: name, revision: revision);
+
+/// Removes `name` (all revisions unless `revision` is given) from the
+/// registry, deleting the files too when `delete_files` is set. Returns
+/// whether anything was removed.
+bool  removeInstalledModel({required String modelsDir , required String name , String? revision , required bool deleteFiles }) => RustLib.instance.api.crateApiModelsRemoveInstalledModel(modelsDir: modelsDir, name: name, revision: revision, deleteFiles: deleteFiles);
+
+            /// Progress of a `download_model` call.
+class DownloadProgress  {
+                /// Bytes on disk so far, including any resumed partial download.
+final BigInt downloaded;
+/// Full size of the file; `None` when the server does not report it.
+final BigInt? total;
+
+                const DownloadProgress({required this.downloaded ,this.total ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => downloaded.hashCode^total.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DownloadProgress &&
+                runtimeType == other.runtimeType
+                && downloaded == other.downloaded&& total == other.total;
+        
+            }
+
+/// A model file recorded in a models directory (see `register_model`).
+class InstalledModel  {
+                final String name;
+final String revision;
+final String path;
+final BigInt size;
+
+                const InstalledModel({required this.name ,required this.revision ,required this.path ,required this.size ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^revision.hashCode^path.hashCode^size.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is InstalledModel &&
+                runtimeType == other.runtimeType
+                && name == other.name&& revision == other.revision&& path == other.path&& size == other.size;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,96 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'logging.dart';
+import 'ort.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `entities`, `finish`, `split_label`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NerTagger>>
+                abstract class NerTagger implements RustOpaqueInterface {
+                    /// `config_json` is the model's Hugging Face `config.json`, whose
+/// `id2label` names the classes (`O`, `B-PER`, `I-PER`, ...).
+static NerTagger  create({required String modelPath , required String tokenizerPath , required String configJson })=>RustLib.instance.api.crateApiNerNerTaggerCreate(modelPath: modelPath, tokenizerPath: tokenizerPath, configJson: configJson);
+
+
+/// `create` from in-memory model bytes and tokenizer JSON.
+static NerTagger  createFromBytes({required List<int> modelBytes , required String tokenizerJson , required String configJson })=>RustLib.instance.api.crateApiNerNerTaggerCreateFromBytes(modelBytes: modelBytes, tokenizerJson: tokenizerJson, configJson: configJson);
+
+
+static NerTagger  createFromBytesWithOptions({required List<int> modelBytes , required String tokenizerJson , required String configJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiNerNerTaggerCreateFromBytesWithOptions(modelBytes: modelBytes, tokenizerJson: tokenizerJson, configJson: configJson, ortOptions: ortOptions);
+
+
+static NerTagger  createWithOptions({required String modelPath , required String tokenizerPath , required String configJson , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiNerNerTaggerCreateWithOptions(modelPath: modelPath, tokenizerPath: tokenizerPath, configJson: configJson, ortOptions: ortOptions);
+
+
+/// Entities found in `text`, in text order.
+ List<NamedEntity>  extract({required String text });
+
+
+/// `extract` for each of `texts`, run as one batch.
+ List<List<NamedEntity>>  extractBatch({required List<String> texts });
+
+
+/// Same as `extract_batch`, but not `sync` on the Dart side.
+ Future<List<List<NamedEntity>>>  extractBatchAsync({required List<String> texts });
+
+
+/// Class names by index, from `id2label`.
+ List<String>  labels();
+
+
+/// Inputs, outputs and metadata of the loaded ONNX model.
+ ModelInfo  modelInfo();
+
+
+/// Tags one tiny input so the first real `extract` doesn't pay for graph
+/// initialization.
+ Future<void>  warmup();
+
+
+
+                    
+                }
+                
+
+class NamedEntity  {
+                /// Entity type with the `B-`/`I-` prefix removed, e.g. `PER`.
+final String label;
+final String text;
+/// Character (Unicode scalar) offsets of the entity in the source text.
+final int start;
+final int end;
+/// Mean probability of the entity's tokens.
+final double score;
+
+                const NamedEntity({required this.label ,required this.text ,required this.start ,required this.end ,required this.score ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => label.hashCode^text.hashCode^start.hashCode^end.hashCode^score.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NamedEntity &&
+                runtimeType == other.runtimeType
+                && label == other.label&& text == other.text&& start == other.start&& end == other.end&& score == other.score;
+        
+            }
+            
\ No newline at end of file
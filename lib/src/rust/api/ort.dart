@@ -4,135 +4,462 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
+import 'logging.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `apply_session_options`, `has_thread_options`, `init_ort_from_options`, `to_positive_usize`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
-
-bool initOrt({required String name, String? path}) =>
-    RustLib.instance.api.crateApiOrtInitOrt(name: name, path: path);
-
-bool initOrtWithOptions({required OrtEnvironmentOptions options}) =>
-    RustLib.instance.api.crateApiOrtInitOrtWithOptions(options: options);
-
-Future<Session> buildSessionFromFileWithInit({
-  required String modelPath,
-  OrtInitOptions? ortOptions,
-}) => RustLib.instance.api.crateApiOrtBuildSessionFromFileWithInit(
-  modelPath: modelPath,
-  ortOptions: ortOptions,
-);
-
-Future<Session> buildSessionFromFile({
-  required String modelPath,
-  OrtSessionOptions? sessionOptions,
-}) => RustLib.instance.api.crateApiOrtBuildSessionFromFile(
-  modelPath: modelPath,
-  sessionOptions: sessionOptions,
-);
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Session>>
-abstract class Session implements RustOpaqueInterface {}
-
-class OrtEnvironmentOptions {
-  final String? name;
-  final String? dylibPath;
-  final PlatformInt64? interThreads;
-  final PlatformInt64? intraThreads;
-  final bool? spinControl;
-  final String? intraAffinity;
-  final bool? telemetry;
-
-  const OrtEnvironmentOptions({
-    this.name,
-    this.dylibPath,
-    this.interThreads,
-    this.intraThreads,
-    this.spinControl,
-    this.intraAffinity,
-    this.telemetry,
-  });
-
-  static Future<OrtEnvironmentOptions> default_() =>
-      RustLib.instance.api.crateApiOrtOrtEnvironmentOptionsDefault();
-
-  @override
-  int get hashCode =>
-      name.hashCode ^
-      dylibPath.hashCode ^
-      interThreads.hashCode ^
-      intraThreads.hashCode ^
-      spinControl.hashCode ^
-      intraAffinity.hashCode ^
-      telemetry.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is OrtEnvironmentOptions &&
-          runtimeType == other.runtimeType &&
-          name == other.name &&
-          dylibPath == other.dylibPath &&
-          interThreads == other.interThreads &&
-          intraThreads == other.intraThreads &&
-          spinControl == other.spinControl &&
-          intraAffinity == other.intraAffinity &&
-          telemetry == other.telemetry;
-}
-
-class OrtInitOptions {
-  final OrtEnvironmentOptions? environment;
-  final OrtSessionOptions? session;
-
-  const OrtInitOptions({this.environment, this.session});
-
-  static Future<OrtInitOptions> default_() =>
-      RustLib.instance.api.crateApiOrtOrtInitOptionsDefault();
-
-  @override
-  int get hashCode => environment.hashCode ^ session.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is OrtInitOptions &&
-          runtimeType == other.runtimeType &&
-          environment == other.environment &&
-          session == other.session;
-}
-
-class OrtSessionOptions {
-  final PlatformInt64? intraThreads;
-  final PlatformInt64? interThreads;
-  final bool? parallelExecution;
-
-  /// 0..=3 mapping to ORT graph optimization levels.
-  final PlatformInt64? optimizationLevel;
-
-  const OrtSessionOptions({
-    this.intraThreads,
-    this.interThreads,
-    this.parallelExecution,
-    this.optimizationLevel,
-  });
-
-  static Future<OrtSessionOptions> default_() =>
-      RustLib.instance.api.crateApiOrtOrtSessionOptionsDefault();
-
-  @override
-  int get hashCode =>
-      intraThreads.hashCode ^
-      interThreads.hashCode ^
-      parallelExecution.hashCode ^
-      optimizationLevel.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is OrtSessionOptions &&
-          runtimeType == other.runtimeType &&
-          intraThreads == other.intraThreads &&
-          interThreads == other.interThreads &&
-          parallelExecution == other.parallelExecution &&
-          optimizationLevel == other.optimizationLevel;
-}
+
+            // These functions are ignored because they are not marked as `pub`: `acquire`, `acquire`, `acquire`, `apply_session_options`, `build_session_from_memory_with_init`, `build_session_from_memory`, `close`, `close`, `closed_error`, `commit_from_file`, `commit_model`, `execution_provider`, `extract`, `has_thread_options`, `init_ort_from_options`, `input_tensor`, `is_closed`, `is_closed`, `is_deterministic`, `log_session_created`, `model_load_error`, `new`, `new`, `optimized_model_path`, `output_tensor`, `pool_size`, `pool`, `push_session_warning`, `register_execution_providers`, `remove_route`, `resize_pool`, `resize`, `session_model_info`, `session_workloads`, `set_route`, `tensor_from_f32`, `to_positive_usize`, `usable_execution_providers`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SessionPool`, `SessionRouter`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            bool  initOrt({required String name , String? path }) => RustLib.instance.api.crateApiOrtInitOrt(name: name, path: path);
+
+bool  initOrtWithOptions({required OrtEnvironmentOptions options }) => RustLib.instance.api.crateApiOrtInitOrtWithOptions(options: options);
+
+/// Returns and clears the warnings collected while building sessions, e.g. an
+/// execution provider that was requested but fell back to CPU.
+List<String>  takeSessionWarnings() => RustLib.instance.api.crateApiOrtTakeSessionWarnings();
+
+/// ONNX Runtime names (e.g. `CoreMLExecutionProvider`) of the providers in
+/// `device_capabilities`. Loads ONNX Runtime, so call it after `init_ort`.
+List<String>  availableExecutionProviders() => RustLib.instance.api.crateApiOrtAvailableExecutionProviders();
+
+/// Platform, core count and usable execution providers, so callers can pick
+/// providers up front instead of relying on registration fallbacks. A listed
+/// provider can still skip nodes, or a whole model, the hardware cannot run.
+DeviceCapabilities  deviceCapabilities() => RustLib.instance.api.crateApiOrtDeviceCapabilities();
+
+/// Loads `model_path` on the CPU and reports its inputs, outputs and
+/// metadata without creating an embedder.
+ModelInfo  inspectOnnxModel({required String modelPath }) => RustLib.instance.api.crateApiOrtInspectOnnxModel(modelPath: modelPath);
+
+Future<Session>  buildSessionFromFileWithInit({required String modelPath , OrtInitOptions? ortOptions }) => RustLib.instance.api.crateApiOrtBuildSessionFromFileWithInit(modelPath: modelPath, ortOptions: ortOptions);
+
+Future<Session>  buildSessionFromFile({required String modelPath , OrtSessionOptions? sessionOptions }) => RustLib.instance.api.crateApiOrtBuildSessionFromFile(modelPath: modelPath, sessionOptions: sessionOptions);
+
+/// Deletes the optimized graphs saved in `cache_dir` (see
+/// `OrtSessionOptions::optimized_model_cache_dir`) and returns how many were
+/// removed. Other files in the directory are left alone.
+int  clearOptimizedModelCache({required String cacheDir }) => RustLib.instance.api.crateApiOrtClearOptimizedModelCache(cacheDir: cacheDir);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<OrtSessionHandle>>
+                abstract class OrtSessionHandle implements RustOpaqueInterface {
+                    /// Frees the session now rather than when the Dart object is garbage
+/// collected. Later calls fail with `ErrorKind::Closed`.
+ void  close();
+
+
+static OrtSessionHandle  create({required String modelPath , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiOrtOrtSessionHandleCreate(modelPath: modelPath, ortOptions: ortOptions);
+
+
+/// `create` for a model held in memory; nothing is written to disk.
+static OrtSessionHandle  createFromBytes({required List<int> modelBytes , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiOrtOrtSessionHandleCreateFromBytes(modelBytes: modelBytes, ortOptions: ortOptions);
+
+
+ bool  isClosed();
+
+
+ ModelInfo  modelInfo();
+
+
+/// Runs the model on `inputs`, each converted to the element type the
+/// model declares for it (e.g. `Int64` data to an `int32` input, `Float32`
+/// data to an `fp16` one). Returns every output in the model's order, or
+/// only `output_names` when given. Float outputs come back as `Float32`,
+/// `uint8` ones as `Uint8` and other integer and bool outputs as `Int64`.
+ Future<List<NamedTensor>>  run({required List<NamedTensor> inputs , List<String>? outputNames });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Session>>
+                abstract class Session implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+/// Hardware CoreML may schedule a model on.
+enum CoreMlComputeUnits {
+                    all,
+cpuAndNeuralEngine,
+cpuAndGpu,
+cpuOnly,
+                    ;
+                    
+                }
+
+/// Format CoreML compiles the model to. `MlProgram` needs iOS 15 / macOS 12
+/// and supports more operators; `NeuralNetwork` runs on older devices.
+enum CoreMlModelFormat {
+                    mlProgram,
+neuralNetwork,
+                    ;
+                    
+                }
+
+/// What the current device and the loaded ONNX Runtime can run.
+class DeviceCapabilities  {
+                /// `android`, `ios`, `macos`, `windows` or `linux`.
+final String os;
+/// `aarch64`, `x86_64`, ...
+final String arch;
+/// Logical cores available to the process.
+final int cpuCores;
+/// Providers supported on this platform and present in the loaded ONNX
+/// Runtime build. Always includes `Cpu`.
+final List<ExecutionProviderKind> executionProviders;
+
+                const DeviceCapabilities({required this.os ,required this.arch ,required this.cpuCores ,required this.executionProviders ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => os.hashCode^arch.hashCode^cpuCores.hashCode^executionProviders.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DeviceCapabilities &&
+                runtimeType == other.runtimeType
+                && os == other.os&& arch == other.arch&& cpuCores == other.cpuCores&& executionProviders == other.executionProviders;
+        
+            }
+
+/// One provider to register. Provider-specific fields are ignored by the
+/// other providers; `None` keeps the ORT default.
+class ExecutionProviderConfig  {
+                final ExecutionProviderKind kind;
+/// GPU ordinal for CUDA and DirectML.
+final int? deviceId;
+/// CUDA: cap on the bytes ORT's device arena may allocate, leaving room
+/// for other GPU users (default: no limit).
+final PlatformInt64? cudaMemoryLimit;
+/// CoreML: restrict to e.g. CPU + Neural Engine (default: all units).
+final CoreMlComputeUnits? coremlComputeUnits;
+final CoreMlModelFormat? coremlModelFormat;
+/// CoreML: only offload subgraphs whose input shapes are static. The
+/// Neural Engine needs fixed shapes, so pair this with fixed-length
+/// padding.
+final bool? coremlStaticInputShapes;
+/// NNAPI: allow fp16 relaxation, faster on most NPUs at a small cost in
+/// precision.
+final bool? nnapiFp16;
+/// NNAPI: keep NNAPI off its own CPU implementation so unsupported
+/// operators run on ORT's faster CPU kernels instead (Android 10+).
+final bool? nnapiDisableCpu;
+/// QNN: backend library, e.g. `libQnnHtp.so` for the NPU or
+/// `libQnnCpu.so`.
+final String? qnnBackendPath;
+final QnnPerformanceMode? qnnPerformanceMode;
+/// XNNPACK: size of its own thread pool. XNNPACK does not share the
+/// session's intra-op pool, so leave `intra_threads` at 1 to avoid
+/// oversubscribing the cores.
+final PlatformInt64? xnnpackThreads;
+
+                const ExecutionProviderConfig({required this.kind ,this.deviceId ,this.cudaMemoryLimit ,this.coremlComputeUnits ,this.coremlModelFormat ,this.coremlStaticInputShapes ,this.nnapiFp16 ,this.nnapiDisableCpu ,this.qnnBackendPath ,this.qnnPerformanceMode ,this.xnnpackThreads ,});
+
+                static Future<ExecutionProviderConfig>  default_()=>RustLib.instance.api.crateApiOrtExecutionProviderConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^deviceId.hashCode^cudaMemoryLimit.hashCode^coremlComputeUnits.hashCode^coremlModelFormat.hashCode^coremlStaticInputShapes.hashCode^nnapiFp16.hashCode^nnapiDisableCpu.hashCode^qnnBackendPath.hashCode^qnnPerformanceMode.hashCode^xnnpackThreads.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ExecutionProviderConfig &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& deviceId == other.deviceId&& cudaMemoryLimit == other.cudaMemoryLimit&& coremlComputeUnits == other.coremlComputeUnits&& coremlModelFormat == other.coremlModelFormat&& coremlStaticInputShapes == other.coremlStaticInputShapes&& nnapiFp16 == other.nnapiFp16&& nnapiDisableCpu == other.nnapiDisableCpu&& qnnBackendPath == other.qnnBackendPath&& qnnPerformanceMode == other.qnnPerformanceMode&& xnnpackThreads == other.xnnpackThreads;
+        
+            }
+
+enum ExecutionProviderKind {
+                    cpu,
+coreMl,
+nnapi,
+directMl,
+cuda,
+/// Qualcomm AI Engine (Snapdragon NPU/DSP).
+qnn,
+/// Optimized CPU kernels for ARM and x86, mostly benefiting quantized
+/// (INT8) models.
+xnnpack,
+                    ;
+                    static Future<ExecutionProviderKind>  default_()=>RustLib.instance.api.crateApiOrtExecutionProviderKindDefault();
+
+
+                }
+
+/// What an ONNX model declares, for checking it against what an embedder
+/// expects.
+class ModelInfo  {
+                final List<TensorInfo> inputs;
+final List<TensorInfo> outputs;
+final String? producer;
+final String? graphName;
+final String? description;
+final PlatformInt64? version;
+/// Opset of the default `ai.onnx` domain; `None` when the ORT build
+/// cannot report it.
+final int? opset;
+
+                const ModelInfo({required this.inputs ,required this.outputs ,this.producer ,this.graphName ,this.description ,this.version ,this.opset ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => inputs.hashCode^outputs.hashCode^producer.hashCode^graphName.hashCode^description.hashCode^version.hashCode^opset.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ModelInfo &&
+                runtimeType == other.runtimeType
+                && inputs == other.inputs&& outputs == other.outputs&& producer == other.producer&& graphName == other.graphName&& description == other.description&& version == other.version&& opset == other.opset;
+        
+            }
+
+/// A named tensor passed to or returned from `OrtSessionHandle::run`. Only
+/// the buffer matching `dtype` is read or filled; the others stay empty.
+class NamedTensor  {
+                final String name;
+final TensorDtype dtype;
+final Int64List shape;
+final Float32List f32Data;
+final Int64List i64Data;
+final Uint8List u8Data;
+
+                const NamedTensor({required this.name ,required this.dtype ,required this.shape ,required this.f32Data ,required this.i64Data ,required this.u8Data ,});
+
+                static Future<NamedTensor>  default_()=>RustLib.instance.api.crateApiOrtNamedTensorDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^dtype.hashCode^shape.hashCode^f32Data.hashCode^i64Data.hashCode^u8Data.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NamedTensor &&
+                runtimeType == other.runtimeType
+                && name == other.name&& dtype == other.dtype&& shape == other.shape&& f32Data == other.f32Data&& i64Data == other.i64Data&& u8Data == other.u8Data;
+        
+            }
+
+class OrtEnvironmentOptions  {
+                final String? name;
+final String? dylibPath;
+final PlatformInt64? interThreads;
+final PlatformInt64? intraThreads;
+final bool? spinControl;
+final String? intraAffinity;
+final bool? telemetry;
+/// Least severe ONNX Runtime message to forward to `init_logging`
+/// (default `Warn`). `Debug` and `Trace` include ORT's verbose output,
+/// e.g. which nodes each execution provider took. Applies when the
+/// environment is created.
+final LogLevel? logLevel;
+
+                const OrtEnvironmentOptions({this.name ,this.dylibPath ,this.interThreads ,this.intraThreads ,this.spinControl ,this.intraAffinity ,this.telemetry ,this.logLevel ,});
+
+                static Future<OrtEnvironmentOptions>  default_()=>RustLib.instance.api.crateApiOrtOrtEnvironmentOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^dylibPath.hashCode^interThreads.hashCode^intraThreads.hashCode^spinControl.hashCode^intraAffinity.hashCode^telemetry.hashCode^logLevel.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OrtEnvironmentOptions &&
+                runtimeType == other.runtimeType
+                && name == other.name&& dylibPath == other.dylibPath&& interThreads == other.interThreads&& intraThreads == other.intraThreads&& spinControl == other.spinControl&& intraAffinity == other.intraAffinity&& telemetry == other.telemetry&& logLevel == other.logLevel;
+        
+            }
+
+class OrtInitOptions  {
+                final OrtEnvironmentOptions? environment;
+final OrtSessionOptions? session;
+
+                const OrtInitOptions({this.environment ,this.session ,});
+
+                static Future<OrtInitOptions>  default_()=>RustLib.instance.api.crateApiOrtOrtInitOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => environment.hashCode^session.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OrtInitOptions &&
+                runtimeType == other.runtimeType
+                && environment == other.environment&& session == other.session;
+        
+            }
+
+class OrtSessionOptions  {
+                final PlatformInt64? intraThreads;
+final PlatformInt64? interThreads;
+final bool? parallelExecution;
+/// 0..=3 mapping to ORT graph optimization levels.
+final PlatformInt64? optimizationLevel;
+/// Converts fp32 weights to fp16 on load and caches the converted model
+/// (next to the source or in `fp16_cache_dir`). Meant for CoreML, NNAPI
+/// and GPU providers; falls back to the original model if the conversion
+/// or the fp16 session fails.
+final bool? convertToFp16;
+final String? fp16CacheDir;
+/// Providers to register, highest priority first. Providers that are not
+/// supported or fail to register are skipped with a session warning; CPU
+/// always remains as the final fallback.
+final List<ExecutionProviderConfig> executionProviders;
+/// Writes an ORT profiling trace (Chrome trace JSON) for every run to a
+/// file starting with this path. The trace is finished when the embedder
+/// is dropped.
+final String? profilingPath;
+/// CPU memory arena (default on). The arena keeps freed buffers for
+/// reuse, which is faster but holds on to the peak allocation; turn it
+/// off on memory-constrained devices.
+final bool? memoryArena;
+/// Memory pattern planning (default on): preallocates buffers from the
+/// shapes of previous runs. Mostly helps fixed-shape inputs.
+final bool? memoryPattern;
+/// Saves the optimized graph to this directory on first load and loads
+/// it, without re-optimizing, on later launches. The cache is rebuilt
+/// when the source model is newer; `clear_optimized_model_cache` empties
+/// it.
+final String? optimizedModelCacheDir;
+/// Bit-identical outputs for identical inputs across runs on the same
+/// device: one intra-op thread unless `intra_threads` is set, sequential
+/// execution, no thread spinning and ORT's deterministic kernels. Slower;
+/// execution providers other than CPU may still be nondeterministic.
+/// Embedders run every text on their own rather than in padded batches,
+/// so a vector doesn't depend on what else is in the call.
+final bool? deterministic;
+
+                const OrtSessionOptions({this.intraThreads ,this.interThreads ,this.parallelExecution ,this.optimizationLevel ,this.convertToFp16 ,this.fp16CacheDir ,required this.executionProviders ,this.profilingPath ,this.memoryArena ,this.memoryPattern ,this.optimizedModelCacheDir ,this.deterministic ,});
+
+                static Future<OrtSessionOptions>  default_()=>RustLib.instance.api.crateApiOrtOrtSessionOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => intraThreads.hashCode^interThreads.hashCode^parallelExecution.hashCode^optimizationLevel.hashCode^convertToFp16.hashCode^fp16CacheDir.hashCode^executionProviders.hashCode^profilingPath.hashCode^memoryArena.hashCode^memoryPattern.hashCode^optimizedModelCacheDir.hashCode^deterministic.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OrtSessionOptions &&
+                runtimeType == other.runtimeType
+                && intraThreads == other.intraThreads&& interThreads == other.interThreads&& parallelExecution == other.parallelExecution&& optimizationLevel == other.optimizationLevel&& convertToFp16 == other.convertToFp16&& fp16CacheDir == other.fp16CacheDir&& executionProviders == other.executionProviders&& profilingPath == other.profilingPath&& memoryArena == other.memoryArena&& memoryPattern == other.memoryPattern&& optimizedModelCacheDir == other.optimizedModelCacheDir&& deterministic == other.deterministic;
+        
+            }
+
+/// Power/performance profile of the QNN HTP (Hexagon) backend.
+enum QnnPerformanceMode {
+                    default_,
+burst,
+balanced,
+highPerformance,
+highPowerSaver,
+lowPowerSaver,
+lowBalanced,
+powerSaver,
+extremePowerSaver,
+sustainedHighPerformance,
+                    ;
+                    
+                }
+
+/// Element type of a `NamedTensor`.
+enum TensorDtype {
+                    float32,
+int64,
+uint8,
+                    ;
+                    static Future<TensorDtype>  default_()=>RustLib.instance.api.crateApiOrtTensorDtypeDefault();
+
+
+                }
+
+/// One graph input or output.
+class TensorInfo  {
+                final String name;
+/// Element type for tensors (e.g. `f32`, `i64`), otherwise the value
+/// type (sequence, map).
+final String dtype;
+/// Declared dimensions, `-1` where dynamic. Empty for non-tensors.
+final Int64List shape;
+
+                const TensorInfo({required this.name ,required this.dtype ,required this.shape ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^dtype.hashCode^shape.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TensorInfo &&
+                runtimeType == other.runtimeType
+                && name == other.name&& dtype == other.dtype&& shape == other.shape;
+        
+            }
+
+/// Kind of call an embedder is serving, used to route it to a session built
+/// for that workload (e.g. an accelerated session for interactive queries and
+/// a low-thread CPU session for bulk indexing).
+enum Workload {
+                    interactive,
+bulk,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,81 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `kmeans`, `knn_graph`, `new`, `next_f32`, `next_u64`, `next_usize`, `pca`, `refine_layout`, `rescale`, `squared_distance`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SplitMix64`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            /// Projects embeddings to 2D/3D for "map" style visualisations.
+///
+/// Coordinates are initialised with PCA and then refined with a UMAP-like
+/// neighbour-graph layout; cluster hints come from k-means on the layout.
+Future<ProjectionResult>  projectEmbeddings({required List<Float32List> embeddings , ProjectionOptions? options }) => RustLib.instance.api.crateApiProjectionProjectEmbeddings(embeddings: embeddings, options: options);
+
+            class ProjectionOptions  {
+                /// Output dimensionality, 2 or 3 (default 2).
+final int? dims;
+/// Layout refinement steps after the PCA initialisation (default 200, 0 = PCA only).
+final int? iterations;
+/// Neighbours per point in the layout graph (default 15).
+final int? neighbors;
+/// Number of cluster hints; defaults to roughly sqrt(n / 2).
+final int? clusters;
+final BigInt? seed;
+
+                const ProjectionOptions({this.dims ,this.iterations ,this.neighbors ,this.clusters ,this.seed ,});
+
+                static Future<ProjectionOptions>  default_()=>RustLib.instance.api.crateApiProjectionProjectionOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => dims.hashCode^iterations.hashCode^neighbors.hashCode^clusters.hashCode^seed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProjectionOptions &&
+                runtimeType == other.runtimeType
+                && dims == other.dims&& iterations == other.iterations&& neighbors == other.neighbors&& clusters == other.clusters&& seed == other.seed;
+        
+            }
+
+class ProjectionResult  {
+                /// One `dims`-sized coordinate per input embedding, scaled to roughly [-1, 1].
+final List<Float32List> coordinates;
+/// Cluster index per input embedding.
+final Uint32List clusters;
+final int clusterCount;
+
+                const ProjectionResult({required this.coordinates ,required this.clusters ,required this.clusterCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => coordinates.hashCode^clusters.hashCode^clusterCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProjectionResult &&
+                runtimeType == other.runtimeType
+                && coordinates == other.coordinates&& clusters == other.clusters&& clusterCount == other.clusterCount;
+        
+            }
+            
\ No newline at end of file
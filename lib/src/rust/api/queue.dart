@@ -0,0 +1,87 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `lock_state`, `next_job`, `notify`, `panic_message`, `queue`, `run_worker`, `spawn_workers`, `status`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Job`, `Pending`, `QueueState`, `Queue`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `cmp`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `partial_cmp`
+
+
+            /// Queues `texts` for the embedder loaded under `embedder_id` and returns
+/// the job id. Jobs with a higher `priority` start first; equal priorities
+/// run in submission order.
+BigInt  submitJob({required BigInt embedderId , required List<String> texts , required int priority }) => RustLib.instance.api.crateApiQueueSubmitJob(embedderId: embedderId, texts: texts, priority: priority);
+
+/// Status of `job_id`; `None` once its result was taken or for unknown ids.
+JobStatus?  jobStatus({required BigInt jobId }) => RustLib.instance.api.crateApiQueueJobStatus(jobId: jobId);
+
+/// Statuses of all jobs whose result has not been taken, by job id.
+List<JobStatus>  listJobs() => RustLib.instance.api.crateApiQueueListJobs();
+
+/// The embeddings of a finished job, in input order, after which the job
+/// is forgotten. `None` while it is queued or running; a failed or
+/// cancelled job returns its error (and is forgotten too).
+List<Float32List>?  takeJobResult({required BigInt jobId }) => RustLib.instance.api.crateApiQueueTakeJobResult(jobId: jobId);
+
+/// Cancels a queued job. Returns `false` when it already started or
+/// finished; running jobs are not interrupted.
+bool  cancelJob({required BigInt jobId }) => RustLib.instance.api.crateApiQueueCancelJob(jobId: jobId);
+
+/// Stops starting new jobs until `resume_queue`. Running jobs finish.
+void  pauseQueue() => RustLib.instance.api.crateApiQueuePauseQueue();
+
+void  resumeQueue() => RustLib.instance.api.crateApiQueueResumeQueue();
+
+bool  isQueuePaused() => RustLib.instance.api.crateApiQueueIsQueuePaused();
+
+/// How many jobs may run at once (default 1). Each running job holds one
+/// of the embedder's sessions, so more than its session count only adds
+/// waiting threads.
+void  setQueueConcurrency({required int maxConcurrency }) => RustLib.instance.api.crateApiQueueSetQueueConcurrency(maxConcurrency: maxConcurrency);
+
+/// Sends every job status change to `sink` until the Dart side stops
+/// listening.
+Stream<JobStatus>  watchJobs() => RustLib.instance.api.crateApiQueueWatchJobs();
+
+            enum JobState {
+                    queued,
+running,
+completed,
+failed,
+cancelled,
+                    ;
+                    
+                }
+
+class JobStatus  {
+                final BigInt jobId;
+final JobState state;
+final int priority;
+final int textCount;
+
+                const JobStatus({required this.jobId ,required this.state ,required this.priority ,required this.textCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => jobId.hashCode^state.hashCode^priority.hashCode^textCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is JobStatus &&
+                runtimeType == other.runtimeType
+                && jobId == other.jobId&& state == other.state&& priority == other.priority&& textCount == other.textCount;
+        
+            }
+            
\ No newline at end of file
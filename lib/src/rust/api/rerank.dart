@@ -0,0 +1,105 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'logging.dart';
+import 'ort.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `from_parts`, `score_cross_encoder`, `score_qwen3`, `yes_no_ids`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Reranker>>
+                abstract class Reranker implements RustOpaqueInterface {
+                    static Reranker  create({required String modelPath , required String tokenizerPath , required RerankerKind kind })=>RustLib.instance.api.crateApiRerankRerankerCreate(modelPath: modelPath, tokenizerPath: tokenizerPath, kind: kind);
+
+
+/// `create` from in-memory model bytes and tokenizer JSON.
+static Reranker  createFromBytes({required List<int> modelBytes , required String tokenizerJson , required RerankerKind kind })=>RustLib.instance.api.crateApiRerankRerankerCreateFromBytes(modelBytes: modelBytes, tokenizerJson: tokenizerJson, kind: kind);
+
+
+static Reranker  createFromBytesWithOptions({required List<int> modelBytes , required String tokenizerJson , required RerankerKind kind , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiRerankRerankerCreateFromBytesWithOptions(modelBytes: modelBytes, tokenizerJson: tokenizerJson, kind: kind, ortOptions: ortOptions);
+
+
+static Reranker  createWithOptions({required String modelPath , required String tokenizerPath , required RerankerKind kind , OrtInitOptions? ortOptions })=>RustLib.instance.api.crateApiRerankRerankerCreateWithOptions(modelPath: modelPath, tokenizerPath: tokenizerPath, kind: kind, ortOptions: ortOptions);
+
+
+ RerankerKind  kind();
+
+
+/// Inputs, outputs and metadata of the loaded ONNX model.
+ ModelInfo  modelInfo();
+
+
+/// Scores `passages` and returns them best first, keeping at most
+/// `top_k` results.
+ List<RerankResult>  rerank({required String query , required List<String> passages , int? topK });
+
+
+/// Same as `rerank`, but not `sync` on the Dart side.
+ Future<List<RerankResult>>  rerankAsync({required String query , required List<String> passages , int? topK });
+
+
+/// Relevance logits of `passages` for `query`, in input order. Higher is
+/// more relevant; use `sigmoid_calibrate` to map them to 0..1.
+ Float32List  score({required String query , required List<String> passages });
+
+
+/// Task instruction rendered into Qwen3-Reranker prompts. Ignored by
+/// cross-encoders.
+ void  setInstruction({required String instruction });
+
+
+/// Scores one tiny pair so the first real `rerank` doesn't pay for graph
+/// initialization.
+ Future<void>  warmup();
+
+
+
+                    
+                }
+                
+
+class RerankResult  {
+                /// Position of the passage in the input list.
+final int index;
+final double score;
+
+                const RerankResult({required this.index ,required this.score ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^score.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RerankResult &&
+                runtimeType == other.runtimeType
+                && index == other.index&& score == other.score;
+        
+            }
+
+enum RerankerKind {
+                    /// Sequence-classification cross-encoders (bge-reranker, ms-marco
+/// MiniLM, ...) that output one relevance logit per query/passage pair.
+crossEncoder,
+/// Qwen3-Reranker: a causal LM scored on its "yes"/"no" next-token logits.
+qwen3,
+                    ;
+                    
+                }
+            
\ No newline at end of file
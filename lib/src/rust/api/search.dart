@@ -0,0 +1,77 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `elbow_index`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Drops hits scoring below `min_score`, keeping the input order.
+List<SearchHit>  filterByThreshold({required List<SearchHit> hits , required double minScore }) => RustLib.instance.api.crateApiSearchFilterByThreshold(hits: hits, minScore: minScore);
+
+/// Returns only the confident head of a result list: hits are sorted by
+/// score, thresholded, and cut at the largest score drop ("elbow") when that
+/// drop clearly stands out from the rest of the curve.
+List<SearchHit>  adaptiveCutoff({required List<SearchHit> hits , CutoffOptions? options }) => RustLib.instance.api.crateApiSearchAdaptiveCutoff(hits: hits, options: options);
+
+            class CutoffOptions  {
+                /// Absolute score threshold applied before the elbow detection.
+final double? minScore;
+/// Never cut below this many hits (as long as they pass `min_score`).
+final int? minResults;
+final int? maxResults;
+/// A drop between consecutive scores counts as an elbow when it is at
+/// least this many times the average drop (default 2.0).
+final double? gapFactor;
+
+                const CutoffOptions({this.minScore ,this.minResults ,this.maxResults ,this.gapFactor ,});
+
+                static Future<CutoffOptions>  default_()=>RustLib.instance.api.crateApiSearchCutoffOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => minScore.hashCode^minResults.hashCode^maxResults.hashCode^gapFactor.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CutoffOptions &&
+                runtimeType == other.runtimeType
+                && minScore == other.minScore&& minResults == other.minResults&& maxResults == other.maxResults&& gapFactor == other.gapFactor;
+        
+            }
+
+class SearchHit  {
+                final String id;
+final double score;
+
+                const SearchHit({required this.id ,required this.score ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^score.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchHit &&
+                runtimeType == other.runtimeType
+                && id == other.id&& score == other.score;
+        
+            }
+            
\ No newline at end of file
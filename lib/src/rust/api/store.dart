@@ -0,0 +1,121 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `append`, `checksum`, `encode_delete`, `encode_upsert`, `frame`, `metadata_matches`, `only_holds`, `put_bytes`, `reaches_end`, `read_record`, `take`, `u32`, `upsert_batch`, `write_log`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Cursor`, `LogOp`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<EmbeddingStore>>
+                abstract class EmbeddingStore implements RustOpaqueInterface {
+                    /// Rewrites the file with only the live entries. The new file replaces
+/// the old one atomically, so a crash leaves either of them intact.
+ void  compact();
+
+
+ bool  contains({required String id });
+
+
+/// Removes `id`; returns whether it was present.
+ bool  delete({required String id });
+
+
+/// Vector dimension of the stored entries (0 while empty).
+ int  dimension();
+
+
+ StoredEmbedding?  get_({required String id });
+
+
+ bool  isEmpty();
+
+
+/// Number of stored vectors.
+ int  len();
+
+
+/// Opens the store at `path`, creating it when missing.
+static EmbeddingStore  open({required String path })=>RustLib.instance.api.crateApiStoreEmbeddingStoreOpen(path: path);
+
+
+/// The `k` entries most similar to `vector`, best first. `filter` is a
+/// JSON object; only entries whose metadata has an equal value for every
+/// key in it are considered.
+ List<StoreHit>  query({required List<double> vector , required int k , String? filter });
+
+
+/// Number of log records `compact` would drop.
+ int  staleRecords();
+
+
+/// Inserts or replaces `id`. `metadata_json`, when given, must be valid
+/// JSON; objects can be matched by `query` filters.
+ void  upsert({required String id , required List<double> vector , String? metadataJson });
+
+
+
+                    
+                }
+                
+
+class StoreHit  {
+                final String id;
+/// Cosine similarity to the query.
+final double score;
+final String? metadataJson;
+
+                const StoreHit({required this.id ,required this.score ,this.metadataJson ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^score.hashCode^metadataJson.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StoreHit &&
+                runtimeType == other.runtimeType
+                && id == other.id&& score == other.score&& metadataJson == other.metadataJson;
+        
+            }
+
+/// One stored vector with its metadata.
+class StoredEmbedding  {
+                final Float32List vector;
+final String? metadataJson;
+
+                const StoredEmbedding({required this.vector ,this.metadataJson ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => vector.hashCode^metadataJson.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StoredEmbedding &&
+                runtimeType == other.runtimeType
+                && vector == other.vector&& metadataJson == other.metadataJson;
+        
+            }
+            
\ No newline at end of file
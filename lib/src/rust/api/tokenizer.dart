@@ -4,168 +4,321 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `add_special_tokens_internal`, `insert_tokenizer`, `load_tokenizer_from_json_inner`, `next_id`, `store`, `with_tokenizer_mut`, `with_tokenizer`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`, `from`
-
-BigInt loadTokenizerFromJson({required String json}) =>
-    RustLib.instance.api.crateApiTokenizerLoadTokenizerFromJson(json: json);
-
-BigInt loadTokenizerFromJsonWithSpecialTokens({
-  required String json,
-  required List<String> specialTokens,
-}) => RustLib.instance.api
-    .crateApiTokenizerLoadTokenizerFromJsonWithSpecialTokens(
-      json: json,
-      specialTokens: specialTokens,
-    );
-
-BigInt loadTokenizerFromBytes({required List<int> bytes}) =>
-    RustLib.instance.api.crateApiTokenizerLoadTokenizerFromBytes(bytes: bytes);
-
-BigInt loadTokenizerFromBytesWithSpecialTokens({
-  required List<int> bytes,
-  required List<String> specialTokens,
-}) => RustLib.instance.api
-    .crateApiTokenizerLoadTokenizerFromBytesWithSpecialTokens(
-      bytes: bytes,
-      specialTokens: specialTokens,
-    );
-
-BigInt loadTokenizerFromFile({required String path}) =>
-    RustLib.instance.api.crateApiTokenizerLoadTokenizerFromFile(path: path);
-
-BigInt loadTokenizerFromFileWithSpecialTokens({
-  required String path,
-  required List<String> specialTokens,
-}) => RustLib.instance.api
-    .crateApiTokenizerLoadTokenizerFromFileWithSpecialTokens(
-      path: path,
-      specialTokens: specialTokens,
-    );
-
-int addSpecialTokens({
-  required BigInt tokenizerId,
-  required List<String> tokens,
-}) => RustLib.instance.api.crateApiTokenizerAddSpecialTokens(
-  tokenizerId: tokenizerId,
-  tokens: tokens,
-);
-
-EncodeOutput encode({
-  required BigInt tokenizerId,
-  required String text,
-  bool? addSpecialTokens,
-}) => RustLib.instance.api.crateApiTokenizerEncode(
-  tokenizerId: tokenizerId,
-  text: text,
-  addSpecialTokens: addSpecialTokens,
-);
-
-List<EncodeOutput> encodeBatch({
-  required BigInt tokenizerId,
-  required List<String> texts,
-  bool? addSpecialTokens,
-}) => RustLib.instance.api.crateApiTokenizerEncodeBatch(
-  tokenizerId: tokenizerId,
-  texts: texts,
-  addSpecialTokens: addSpecialTokens,
-);
-
-String decode({
-  required BigInt tokenizerId,
-  required List<int> ids,
-  bool? skipSpecialTokens,
-}) => RustLib.instance.api.crateApiTokenizerDecode(
-  tokenizerId: tokenizerId,
-  ids: ids,
-  skipSpecialTokens: skipSpecialTokens,
-);
-
-List<String> decodeBatch({
-  required BigInt tokenizerId,
-  required List<Uint32List> batchIds,
-  bool? skipSpecialTokens,
-}) => RustLib.instance.api.crateApiTokenizerDecodeBatch(
-  tokenizerId: tokenizerId,
-  batchIds: batchIds,
-  skipSpecialTokens: skipSpecialTokens,
-);
-
-Future<List<EncodeOutput>> encodeBatchAsync({
-  required BigInt tokenizerId,
-  required List<String> texts,
-  bool? addSpecialTokens,
-}) => RustLib.instance.api.crateApiTokenizerEncodeBatchAsync(
-  tokenizerId: tokenizerId,
-  texts: texts,
-  addSpecialTokens: addSpecialTokens,
-);
-
-Future<List<String>> decodeBatchAsync({
-  required BigInt tokenizerId,
-  required List<Uint32List> batchIds,
-  bool? skipSpecialTokens,
-}) => RustLib.instance.api.crateApiTokenizerDecodeBatchAsync(
-  tokenizerId: tokenizerId,
-  batchIds: batchIds,
-  skipSpecialTokens: skipSpecialTokens,
-);
-
-class EncodeOutput {
-  final Uint32List ids;
-  final Uint32List attentionMask;
-  final Uint32List typeIds;
-  final Uint32List specialTokensMask;
-  final List<TokenOffsets> offsets;
-  final List<String> tokens;
-
-  const EncodeOutput({
-    required this.ids,
-    required this.attentionMask,
-    required this.typeIds,
-    required this.specialTokensMask,
-    required this.offsets,
-    required this.tokens,
-  });
-
-  @override
-  int get hashCode =>
-      ids.hashCode ^
-      attentionMask.hashCode ^
-      typeIds.hashCode ^
-      specialTokensMask.hashCode ^
-      offsets.hashCode ^
-      tokens.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is EncodeOutput &&
-          runtimeType == other.runtimeType &&
-          ids == other.ids &&
-          attentionMask == other.attentionMask &&
-          typeIds == other.typeIds &&
-          specialTokensMask == other.specialTokensMask &&
-          offsets == other.offsets &&
-          tokens == other.tokens;
-}
-
-class TokenOffsets {
-  final int start;
-  final int end;
-
-  const TokenOffsets({required this.start, required this.end});
-
-  @override
-  int get hashCode => start.hashCode ^ end.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is TokenOffsets &&
-          runtimeType == other.runtimeType &&
-          start == other.start &&
-          end == other.end;
-}
+
+            // These functions are ignored because they are not marked as `pub`: `add_special_tokens_internal`, `insert_tokenizer`, `load_tokenizer_from_json_inner`, `next_id`, `register_shared_tokenizer`, `store`, `token_byte_span`, `unpadded_len`, `with_tokenizer_mut`, `with_tokenizer`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`
+
+
+            BigInt  loadTokenizerFromJson({required String json }) => RustLib.instance.api.crateApiTokenizerLoadTokenizerFromJson(json: json);
+
+BigInt  loadTokenizerFromJsonWithSpecialTokens({required String json , required List<String> specialTokens }) => RustLib.instance.api.crateApiTokenizerLoadTokenizerFromJsonWithSpecialTokens(json: json, specialTokens: specialTokens);
+
+BigInt  loadTokenizerFromBytes({required List<int> bytes }) => RustLib.instance.api.crateApiTokenizerLoadTokenizerFromBytes(bytes: bytes);
+
+BigInt  loadTokenizerFromBytesWithSpecialTokens({required List<int> bytes , required List<String> specialTokens }) => RustLib.instance.api.crateApiTokenizerLoadTokenizerFromBytesWithSpecialTokens(bytes: bytes, specialTokens: specialTokens);
+
+BigInt  loadTokenizerFromFile({required String path }) => RustLib.instance.api.crateApiTokenizerLoadTokenizerFromFile(path: path);
+
+BigInt  loadTokenizerFromFileWithSpecialTokens({required String path , required List<String> specialTokens }) => RustLib.instance.api.crateApiTokenizerLoadTokenizerFromFileWithSpecialTokens(path: path, specialTokens: specialTokens);
+
+int  addSpecialTokens({required BigInt tokenizerId , required List<String> tokens }) => RustLib.instance.api.crateApiTokenizerAddSpecialTokens(tokenizerId: tokenizerId, tokens: tokens);
+
+EncodeOutput  encode({required BigInt tokenizerId , required String text , bool? addSpecialTokens }) => RustLib.instance.api.crateApiTokenizerEncode(tokenizerId: tokenizerId, text: text, addSpecialTokens: addSpecialTokens);
+
+List<EncodeOutput>  encodeBatch({required BigInt tokenizerId , required List<String> texts , bool? addSpecialTokens }) => RustLib.instance.api.crateApiTokenizerEncodeBatch(tokenizerId: tokenizerId, texts: texts, addSpecialTokens: addSpecialTokens);
+
+/// Encodes `text_a` and `text_b` as one sequence pair, e.g. query and
+/// passage for a cross-encoder or premise and hypothesis for NLI. Tokens of
+/// `text_b` get type id 1 when the post-processor assigns type ids, and
+/// their offsets point into `text_b`.
+EncodeOutput  encodePair({required BigInt tokenizerId , required String textA , required String textB , bool? addSpecialTokens }) => RustLib.instance.api.crateApiTokenizerEncodePair(tokenizerId: tokenizerId, textA: textA, textB: textB, addSpecialTokens: addSpecialTokens);
+
+/// `encode_pair` for each `(text_a, text_b)` of `pairs`.
+List<EncodeOutput>  encodePairBatch({required BigInt tokenizerId , required List<(String,String)> pairs , bool? addSpecialTokens }) => RustLib.instance.api.crateApiTokenizerEncodePairBatch(tokenizerId: tokenizerId, pairs: pairs, addSpecialTokens: addSpecialTokens);
+
+/// Encodes `texts` with special tokens and pads them into one tensor per
+/// input. Rows are as long as the longest encoding or, when `max_len` is
+/// given, exactly `max_len` tokens, for models exported with a fixed
+/// sequence length; longer texts are truncated as `set_truncation` would.
+/// Padding uses the tokenizer's pad id, or 0 when none is set.
+PaddedBatch  encodeBatchPadded({required BigInt tokenizerId , required List<String> texts , int? maxLen }) => RustLib.instance.api.crateApiTokenizerEncodeBatchPadded(tokenizerId: tokenizerId, texts: texts, maxLen: maxLen);
+
+/// Number of tokens `encode` would produce for `text`, after truncation but
+/// without padding.
+int  countTokens({required BigInt tokenizerId , required String text , bool? addSpecialTokens }) => RustLib.instance.api.crateApiTokenizerCountTokens(tokenizerId: tokenizerId, text: text, addSpecialTokens: addSpecialTokens);
+
+/// `count_tokens` for each of `texts`.
+Uint32List  countTokensBatch({required BigInt tokenizerId , required List<String> texts , bool? addSpecialTokens }) => RustLib.instance.api.crateApiTokenizerCountTokensBatch(tokenizerId: tokenizerId, texts: texts, addSpecialTokens: addSpecialTokens);
+
+String  decode({required BigInt tokenizerId , required List<int> ids , bool? skipSpecialTokens }) => RustLib.instance.api.crateApiTokenizerDecode(tokenizerId: tokenizerId, ids: ids, skipSpecialTokens: skipSpecialTokens);
+
+List<String>  decodeBatch({required BigInt tokenizerId , required List<Uint32List> batchIds , bool? skipSpecialTokens }) => RustLib.instance.api.crateApiTokenizerDecodeBatch(tokenizerId: tokenizerId, batchIds: batchIds, skipSpecialTokens: skipSpecialTokens);
+
+Future<List<EncodeOutput>>  encodeBatchAsync({required BigInt tokenizerId , required List<String> texts , bool? addSpecialTokens }) => RustLib.instance.api.crateApiTokenizerEncodeBatchAsync(tokenizerId: tokenizerId, texts: texts, addSpecialTokens: addSpecialTokens);
+
+Future<List<String>>  decodeBatchAsync({required BigInt tokenizerId , required List<Uint32List> batchIds , bool? skipSpecialTokens }) => RustLib.instance.api.crateApiTokenizerDecodeBatchAsync(tokenizerId: tokenizerId, batchIds: batchIds, skipSpecialTokens: skipSpecialTokens);
+
+/// Character (Unicode scalar) span of token `token_index` in `text`, the
+/// text `encoding` was produced from by `encode`. `None` for special and
+/// padding tokens. Spans cover whole characters even where a byte-level
+/// token splits one.
+TokenOffsets?  tokenToChars({required String text , required EncodeOutput encoding , required int tokenIndex }) => RustLib.instance.api.crateApiTokenizerTokenToChars(text: text, encoding: encoding, tokenIndex: tokenIndex);
+
+/// Index of the token in `encoding` that covers character `char_index` of
+/// `text`, the text `encoding` was produced from by `encode`. `None` when
+/// no token does, e.g. for whitespace or truncated text.
+int?  charToToken({required String text , required EncodeOutput encoding , required int charIndex }) => RustLib.instance.api.crateApiTokenizerCharToToken(text: text, encoding: encoding, charIndex: charIndex);
+
+/// Caps encodings at `max_length` tokens (special tokens included), e.g. to
+/// a model's context window. `stride` tokens overlap between the overflowing
+/// pieces. Replaces any truncation from the tokenizer JSON.
+void  setTruncation({required BigInt tokenizerId , required int maxLength , TruncationStrategy? strategy , int? stride }) => RustLib.instance.api.crateApiTokenizerSetTruncation(tokenizerId: tokenizerId, maxLength: maxLength, strategy: strategy, stride: stride);
+
+void  disableTruncation({required BigInt tokenizerId }) => RustLib.instance.api.crateApiTokenizerDisableTruncation(tokenizerId: tokenizerId);
+
+/// Pads encodings with `pad_token`/`pad_id`; `fixed_length` is required
+/// with `PaddingStrategy::Fixed`. When only `pad_token` is given its id is
+/// looked up in the vocabulary; when neither is given the tokenizer's
+/// current padding token (or `[PAD]`/0) is kept.
+void  setPadding({required BigInt tokenizerId , required PaddingStrategy strategy , int? fixedLength , int? padId , String? padToken }) => RustLib.instance.api.crateApiTokenizerSetPadding(tokenizerId: tokenizerId, strategy: strategy, fixedLength: fixedLength, padId: padId, padToken: padToken);
+
+void  disablePadding({required BigInt tokenizerId }) => RustLib.instance.api.crateApiTokenizerDisablePadding(tokenizerId: tokenizerId);
+
+/// Id of `token` in the vocabulary (added tokens included), or `None`.
+int?  tokenToId({required BigInt tokenizerId , required String token }) => RustLib.instance.api.crateApiTokenizerTokenToId(tokenizerId: tokenizerId, token: token);
+
+/// Token with `id` in the vocabulary (added tokens included), or `None`.
+String?  idToToken({required BigInt tokenizerId , required int id }) => RustLib.instance.api.crateApiTokenizerIdToToken(tokenizerId: tokenizerId, id: id);
+
+int  getVocabSize({required BigInt tokenizerId , required bool withAddedTokens }) => RustLib.instance.api.crateApiTokenizerGetVocabSize(tokenizerId: tokenizerId, withAddedTokens: withAddedTokens);
+
+/// One page of the vocabulary ordered by id: up to `limit` entries after
+/// skipping the first `offset`. Vocabularies run to hundreds of thousands
+/// of tokens, so fetch them in pages.
+List<VocabEntry>  getVocab({required BigInt tokenizerId , required bool withAddedTokens , required int offset , required int limit }) => RustLib.instance.api.crateApiTokenizerGetVocab(tokenizerId: tokenizerId, withAddedTokens: withAddedTokens, offset: offset, limit: limit);
+
+/// Removes a tokenizer from the store, freeing its memory. Returns whether
+/// `tokenizer_id` was loaded.
+bool  disposeTokenizer({required BigInt tokenizerId }) => RustLib.instance.api.crateApiTokenizerDisposeTokenizer(tokenizerId: tokenizerId);
+
+/// Ids of all loaded tokenizers, ascending.
+Uint64List  listTokenizers() => RustLib.instance.api.crateApiTokenizerListTokenizers();
+
+TokenizerInfo  tokenizerInfo({required BigInt tokenizerId }) => RustLib.instance.api.crateApiTokenizerTokenizerInfo(tokenizerId: tokenizerId);
+
+            class EncodeOutput  {
+                final Uint32List ids;
+final Uint32List attentionMask;
+final Uint32List typeIds;
+final Uint32List specialTokensMask;
+final List<TokenOffsets> offsets;
+final List<String> tokens;
+/// Index of the pre-tokenized word each token belongs to, e.g. to label
+/// whole words in NER; `None` for special and padding tokens.
+final List<int?> wordIds;
+/// Which text of a pair (0 or 1) each token comes from; `None` for
+/// special and padding tokens.
+final List<int?> sequenceIds;
+/// The rest of the text when truncation cut it off, as further
+/// encodings of at most the truncation length. With a truncation
+/// `stride`, each piece repeats that many tokens of the one before.
+final List<EncodeOutput> overflowing;
+
+                const EncodeOutput({required this.ids ,required this.attentionMask ,required this.typeIds ,required this.specialTokensMask ,required this.offsets ,required this.tokens ,required this.wordIds ,required this.sequenceIds ,required this.overflowing ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => ids.hashCode^attentionMask.hashCode^typeIds.hashCode^specialTokensMask.hashCode^offsets.hashCode^tokens.hashCode^wordIds.hashCode^sequenceIds.hashCode^overflowing.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EncodeOutput &&
+                runtimeType == other.runtimeType
+                && ids == other.ids&& attentionMask == other.attentionMask&& typeIds == other.typeIds&& specialTokensMask == other.specialTokensMask&& offsets == other.offsets&& tokens == other.tokens&& wordIds == other.wordIds&& sequenceIds == other.sequenceIds&& overflowing == other.overflowing;
+        
+            }
+
+/// A batch of encodings as row-major `[batch_size, seq_len]` tensors, ready
+/// for the `input_ids`, `attention_mask` and `token_type_ids` inputs of an
+/// ONNX session.
+class PaddedBatch  {
+                final Int64List inputIds;
+final Int64List attentionMask;
+final Int64List tokenTypeIds;
+/// `[batch_size, seq_len]`.
+final Uint32List shape;
+
+                const PaddedBatch({required this.inputIds ,required this.attentionMask ,required this.tokenTypeIds ,required this.shape ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => inputIds.hashCode^attentionMask.hashCode^tokenTypeIds.hashCode^shape.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PaddedBatch &&
+                runtimeType == other.runtimeType
+                && inputIds == other.inputIds&& attentionMask == other.attentionMask&& tokenTypeIds == other.tokenTypeIds&& shape == other.shape;
+        
+            }
+
+class PaddingInfo  {
+                final PaddingStrategy strategy;
+/// Set for `PaddingStrategy::Fixed`.
+final int? fixedLength;
+final int padId;
+final String padToken;
+
+                const PaddingInfo({required this.strategy ,this.fixedLength ,required this.padId ,required this.padToken ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => strategy.hashCode^fixedLength.hashCode^padId.hashCode^padToken.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PaddingInfo &&
+                runtimeType == other.runtimeType
+                && strategy == other.strategy&& fixedLength == other.fixedLength&& padId == other.padId&& padToken == other.padToken;
+        
+            }
+
+enum PaddingStrategy {
+                    /// Pad every encoding in a batch to the longest one.
+batchLongest,
+/// Pad every encoding to a fixed length.
+fixed,
+                    ;
+                    
+                }
+
+class TokenOffsets  {
+                final int start;
+final int end;
+
+                const TokenOffsets({required this.start ,required this.end ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TokenOffsets &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end;
+        
+            }
+
+class TokenizerInfo  {
+                final BigInt id;
+/// Vocabulary size including added tokens.
+final int vocabSize;
+final TruncationInfo? truncation;
+final PaddingInfo? padding;
+
+                const TokenizerInfo({required this.id ,required this.vocabSize ,this.truncation ,this.padding ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^vocabSize.hashCode^truncation.hashCode^padding.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TokenizerInfo &&
+                runtimeType == other.runtimeType
+                && id == other.id&& vocabSize == other.vocabSize&& truncation == other.truncation&& padding == other.padding;
+        
+            }
+
+class TruncationInfo  {
+                final int maxLength;
+final TruncationStrategy strategy;
+final int stride;
+
+                const TruncationInfo({required this.maxLength ,required this.strategy ,required this.stride ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => maxLength.hashCode^strategy.hashCode^stride.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TruncationInfo &&
+                runtimeType == other.runtimeType
+                && maxLength == other.maxLength&& strategy == other.strategy&& stride == other.stride;
+        
+            }
+
+/// Which sequence(s) of a pair lose tokens when truncating.
+enum TruncationStrategy {
+                    longestFirst,
+onlyFirst,
+onlySecond,
+                    ;
+                    
+                }
+
+class VocabEntry  {
+                final int id;
+final String token;
+
+                const VocabEntry({required this.id ,required this.token ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^token.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VocabEntry &&
+                runtimeType == other.runtimeType
+                && id == other.id&& token == other.token;
+        
+            }
+            
\ No newline at end of file
@@ -4,39 +4,193 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+
+            // These functions are ignored because they are not marked as `pub`: `add`, `check_dimensions`, `cosine_similarity`, `dot`, `embeddings_to_matrix`, `find_root`, `higher_is_closer`, `into_sorted`, `is_sorted`, `new`, `norm`, `pairwise`, `ranks`, `score`
 // These functions are ignored because they have generic arguments: `take`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FusedScores`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            double  cosineDistance({required List<double> a , required List<double> b }) => RustLib.instance.api.crateApiUtilsCosineDistance(a: a, b: b);
+
+Future<Float32List>  meanPoolingNdarray({required Array2F32 embeddings , required List<int> attentionMask }) => RustLib.instance.api.crateApiUtilsMeanPoolingNdarray(embeddings: embeddings, attentionMask: attentionMask);
+
+Float32List  meanPooling({required Array2F32 embeddings , required List<int> attentionMask }) => RustLib.instance.api.crateApiUtilsMeanPooling(embeddings: embeddings, attentionMask: attentionMask);
+
+Float32List  meanPoolingVec({required List<Float32List> embeddings , required List<int> attentionMask }) => RustLib.instance.api.crateApiUtilsMeanPoolingVec(embeddings: embeddings, attentionMask: attentionMask);
+
+/// Plain PCA: projects `embeddings` onto their top `target_dims` principal
+/// axes, unscaled. Unlike `projection::project_embeddings` there is no layout
+/// refinement, so distances along each axis keep their meaning.
+List<Float32List>  pcaProject({required List<Float32List> embeddings , required int targetDims }) => RustLib.instance.api.crateApiUtilsPcaProject(embeddings: embeddings, targetDims: targetDims);
+
+Float32List  normalize({required List<double> embedding }) => RustLib.instance.api.crateApiUtilsNormalize(embedding: embedding);
+
+/// Normalizes a batch of scores so results from different retrievers
+/// (dense, sparse, BM25) can be fused on a comparable scale.
+Float32List  normalizeScores({required List<double> scores , required ScoreNormalization method }) => RustLib.instance.api.crateApiUtilsNormalizeScores(scores: scores, method: method);
+
+Float32List  minMaxNormalize({required List<double> scores }) => RustLib.instance.api.crateApiUtilsMinMaxNormalize(scores: scores);
+
+Float32List  zScoreNormalize({required List<double> scores }) => RustLib.instance.api.crateApiUtilsZScoreNormalize(scores: scores);
+
+Float32List  percentileNormalize({required List<double> scores }) => RustLib.instance.api.crateApiUtilsPercentileNormalize(scores: scores);
+
+/// Dot product of two sparse vectors over their shared indices.
+double  sparseDot({required SparseVector a , required SparseVector b }) => RustLib.instance.api.crateApiUtilsSparseDot(a: a, b: b);
+
+/// Reciprocal rank fusion: every list adds `1 / (k + rank)` (rank starting at
+/// 1, taken from list order) to each id it contains. Input scores are only
+/// used by the caller to order the lists, so retrievers with incomparable
+/// score scales can be merged directly. `k` is commonly 60.
+List<(String,double)>  reciprocalRankFusion({required List<List<(String,double)>> rankings , required int k }) => RustLib.instance.api.crateApiUtilsReciprocalRankFusion(rankings: rankings, k: k);
+
+/// Weighted score fusion: normalizes each result list with `method`, then
+/// sums `weights[i] * score` per id. Ids missing from a list contribute
+/// nothing for that list.
+List<(String,double)>  weightedScoreFusion({required List<List<(String,double)>> results , required List<double> weights , required ScoreNormalization method }) => RustLib.instance.api.crateApiUtilsWeightedScoreFusion(results: results, weights: weights, method: method);
+
+/// Quantizes `embedding` to int8 with a per-vector scale (`max |x| / 127`),
+/// cutting storage 4x.
+Int8Embedding  quantizeInt8({required List<double> embedding }) => RustLib.instance.api.crateApiUtilsQuantizeInt8(embedding: embedding);
+
+/// Approximate dot product of two int8-quantized embeddings; equals the
+/// cosine similarity when the originals were normalized.
+double  int8Dot({required Int8Embedding a , required Int8Embedding b }) => RustLib.instance.api.crateApiUtilsInt8Dot(a: a, b: b);
+
+/// Binary quantization: one bit per dimension (set when the component is
+/// positive), packed most-significant bit first, cutting storage 32x.
+Uint8List  quantizeBinary({required List<double> embedding }) => RustLib.instance.api.crateApiUtilsQuantizeBinary(embedding: embedding);
+
+/// Number of differing bits between two binary-quantized embeddings; lower
+/// is more similar.
+int  hammingDistance({required List<int> a , required List<int> b }) => RustLib.instance.api.crateApiUtilsHammingDistance(a: a, b: b);
+
+/// Dot product of two vectors.
+double  dotProduct({required List<double> a , required List<double> b }) => RustLib.instance.api.crateApiUtilsDotProduct(a: a, b: b);
+
+/// Euclidean (L2) distance between two vectors.
+double  euclideanDistance({required List<double> a , required List<double> b }) => RustLib.instance.api.crateApiUtilsEuclideanDistance(a: a, b: b);
+
+/// Manhattan (L1) distance between two vectors.
+double  manhattanDistance({required List<double> a , required List<double> b }) => RustLib.instance.api.crateApiUtilsManhattanDistance(a: a, b: b);
+
+/// Cosine similarity of every query against every document:
+/// `result[i][j]` compares `queries[i]` with `docs[j]`. Rows are computed in
+/// parallel.
+List<Float32List>  cosineSimilarityMatrix({required List<Float32List> queries , required List<Float32List> docs }) => RustLib.instance.api.crateApiUtilsCosineSimilarityMatrix(queries: queries, docs: docs);
+
+/// The `k` documents closest to `query` as `(index into docs, score)`, best
+/// first. The score is the metric's value, so it descends for similarities
+/// and ascends for distances. Documents are scored in parallel.
+List<(int,double)>  topK({required List<double> query , required List<Float32List> docs , required int k , required DistanceMetric metric }) => RustLib.instance.api.crateApiUtilsTopK(query: query, docs: docs, k: k, metric: metric);
+
+/// Maximal marginal relevance: picks `k` candidates one at a time, each
+/// maximizing `lambda * sim(query, c) - (1 - lambda) * max sim(c, picked)`,
+/// so near-duplicates of earlier picks are skipped. `lambda = 1` is plain
+/// cosine top-k, `0` only maximizes diversity. Returns `(index into
+/// candidates, cosine similarity to query)` in pick order.
+List<(int,double)>  mmr({required List<double> queryVec , required List<Float32List> candidateVecs , required double lambda , required int k }) => RustLib.instance.api.crateApiUtilsMmr(queryVec: queryVec, candidateVecs: candidateVecs, lambda: lambda, k: k);
+
+/// Groups near-identical embeddings, e.g. to dedupe imported documents
+/// before indexing. Pairs with cosine similarity at or above `threshold`
+/// (typically 0.95 or higher) share a cluster, transitively. Returns the
+/// clusters with two or more members as ascending indices, ordered by their
+/// first index.
+///
+/// Exact: similarities are computed as matrix products tile by tile, in
+/// parallel, so the full similarity matrix is never held in memory. Low
+/// thresholds match most pairs and are slow.
+Future<List<Uint32List>>  findDuplicates({required List<Float32List> embeddings , required double threshold }) => RustLib.instance.api.crateApiUtilsFindDuplicates(embeddings: embeddings, threshold: threshold);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Array2 < f32 >>>
+                abstract class Array2F32 implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+/// How `top_k` compares a document with the query.
+enum DistanceMetric {
+                    /// Cosine similarity; higher is closer.
+cosine,
+/// Raw dot product; equals cosine for normalized embeddings, but cheaper.
+dotProduct,
+/// L2 distance; lower is closer.
+euclidean,
+/// L1 distance; lower is closer.
+manhattan,
+                    ;
+                    
+                }
+
+/// Symmetric int8 quantization of one embedding: `values[i] * scale`
+/// approximates the original component.
+class Int8Embedding  {
+                final Int8List values;
+final double scale;
+
+                const Int8Embedding({required this.values ,required this.scale ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => values.hashCode^scale.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Int8Embedding &&
+                runtimeType == other.runtimeType
+                && values == other.values&& scale == other.scale;
+        
+            }
+
+enum ScoreNormalization {
+                    /// Rescale to [0, 1] using the batch minimum and maximum.
+minMax,
+/// Standardise to zero mean and unit variance.
+zScore,
+/// Replace each score by its percentile rank in [0, 1].
+percentile,
+                    ;
+                    
+                }
+
+/// Sparse term-weight vector, e.g. SPLADE output: `values[i]` is the weight
+/// of vocabulary id `indices[i]`. Indices are sorted ascending.
+class SparseVector  {
+                final Uint32List indices;
+final Float32List values;
+
+                const SparseVector({required this.indices ,required this.values ,});
+
+                static Future<SparseVector>  default_()=>RustLib.instance.api.crateApiUtilsSparseVectorDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => indices.hashCode^values.hashCode;
+        
 
-double cosineDistance({required List<double> a, required List<double> b}) =>
-    RustLib.instance.api.crateApiUtilsCosineDistance(a: a, b: b);
-
-Future<Float32List> meanPoolingNdarray({
-  required Array2F32 embeddings,
-  required List<int> attentionMask,
-}) => RustLib.instance.api.crateApiUtilsMeanPoolingNdarray(
-  embeddings: embeddings,
-  attentionMask: attentionMask,
-);
-
-Float32List meanPooling({
-  required Array2F32 embeddings,
-  required List<int> attentionMask,
-}) => RustLib.instance.api.crateApiUtilsMeanPooling(
-  embeddings: embeddings,
-  attentionMask: attentionMask,
-);
-
-Float32List meanPoolingVec({
-  required List<Float32List> embeddings,
-  required List<int> attentionMask,
-}) => RustLib.instance.api.crateApiUtilsMeanPoolingVec(
-  embeddings: embeddings,
-  attentionMask: attentionMask,
-);
-
-Float32List normalize({required List<double> embedding}) =>
-    RustLib.instance.api.crateApiUtilsNormalize(embedding: embedding);
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Array2 < f32 >>>
-abstract class Array2F32 implements RustOpaqueInterface {}
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SparseVector &&
+                runtimeType == other.runtimeType
+                && indices == other.indices&& values == other.values;
+        
+            }
+            
\ No newline at end of file
@@ -3,12 +3,47 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/audio.dart';
+import 'api/audio/whisper.dart';
+import 'api/bench.dart';
+import 'api/bm25.dart';
+import 'api/cache.dart';
+import 'api/calibration.dart';
+import 'api/chat.dart';
+import 'api/chunking.dart';
+import 'api/classification.dart';
+import 'api/classify.dart';
+import 'api/embeddings.dart';
 import 'api/embeddings/bge.dart';
+import 'api/embeddings/clap.dart';
+import 'api/embeddings/clip.dart';
+import 'api/embeddings/colbert.dart';
+import 'api/embeddings/e5.dart';
 import 'api/embeddings/gemma.dart';
+import 'api/embeddings/generic.dart';
 import 'api/embeddings/jina_v3.dart';
 import 'api/embeddings/minilm.dart';
+import 'api/embeddings/nomic.dart';
 import 'api/embeddings/qwen3.dart';
+import 'api/embeddings/splade.dart';
+import 'api/embeddings/vision.dart';
+import 'api/error.dart';
+import 'api/evaluation.dart';
+import 'api/fp16.dart';
+import 'api/generation.dart';
+import 'api/hub.dart';
+import 'api/image.dart';
+import 'api/index.dart';
+import 'api/keywords.dart';
+import 'api/logging.dart';
+import 'api/models.dart';
+import 'api/ner.dart';
 import 'api/ort.dart';
+import 'api/projection.dart';
+import 'api/queue.dart';
+import 'api/rerank.dart';
+import 'api/search.dart';
+import 'api/store.dart';
 import 'api/tokenizer.dart';
 import 'api/utils.dart';
 import 'dart:async';
@@ -17,1085 +52,2788 @@ import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_Array2F32Ptr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_BgeEmbedderPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedderPtr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_GemmaEmbedderPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedderPtr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_JinaV3EmbedderPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3EmbedderPtr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_MiniLmEmbedderPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedderPtr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_Qwen3EmbedderPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3EmbedderPtr;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_SessionPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
-
-  @protected
-  BgeEmbedder
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
-    dynamic raw,
-  );
-
-  @protected
-  GemmaEmbedder
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
-    dynamic raw,
-  );
-
-  @protected
-  JinaV3Embedder
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
-    dynamic raw,
-  );
-
-  @protected
-  MiniLmEmbedder
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
-    dynamic raw,
-  );
-
-  @protected
-  Qwen3Embedder
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
-    dynamic raw,
-  );
-
-  @protected
-  Session
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-    dynamic raw,
-  );
-
-  @protected
-  BgeEmbedder
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
-    dynamic raw,
-  );
-
-  @protected
-  GemmaEmbedder
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
-    dynamic raw,
-  );
-
-  @protected
-  JinaV3Embedder
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
-    dynamic raw,
-  );
-
-  @protected
-  MiniLmEmbedder
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
-    dynamic raw,
-  );
-
-  @protected
-  Qwen3Embedder
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
-    dynamic raw,
-  );
-
-  @protected
-  Array2F32
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-    dynamic raw,
-  );
-
-  @protected
-  Array2F32
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-    dynamic raw,
-  );
-
-  @protected
-  BgeEmbedder
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
-    dynamic raw,
-  );
-
-  @protected
-  GemmaEmbedder
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
-    dynamic raw,
-  );
-
-  @protected
-  JinaV3Embedder
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
-    dynamic raw,
-  );
-
-  @protected
-  MiniLmEmbedder
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
-    dynamic raw,
-  );
-
-  @protected
-  Qwen3Embedder
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
-    dynamic raw,
-  );
-
-  @protected
-  Session
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-    dynamic raw,
-  );
-
-  @protected
-  String dco_decode_String(dynamic raw);
-
-  @protected
-  bool dco_decode_bool(dynamic raw);
-
-  @protected
-  bool dco_decode_box_autoadd_bool(dynamic raw);
 
-  @protected
-  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
-  @protected
-  OrtEnvironmentOptions dco_decode_box_autoadd_ort_environment_options(
-    dynamic raw,
-  );
 
-  @protected
-  OrtInitOptions dco_decode_box_autoadd_ort_init_options(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  OrtSessionOptions dco_decode_box_autoadd_ort_session_options(dynamic raw);
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Array2F32Ptr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr;
 
-  @protected
-  EncodeOutput dco_decode_encode_output(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_BgeEmbedderPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedderPtr;
 
-  @protected
-  double dco_decode_f_32(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Bm25IndexPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25IndexPtr;
 
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CancellationTokenPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationTokenPtr;
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ClapEmbedderPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedderPtr;
 
-  @protected
-  List<EncodeOutput> dco_decode_list_encode_output(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ClipEmbedderPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedderPtr;
 
-  @protected
-  List<Float32List> dco_decode_list_list_prim_f_32_strict(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ColbertEmbedderPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedderPtr;
 
-  @protected
-  List<Uint32List> dco_decode_list_list_prim_u_32_strict(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_E5EmbedderPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5EmbedderPtr;
 
-  @protected
-  List<double> dco_decode_list_prim_f_32_loose(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_EmbedProgressPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgressPtr;
 
-  @protected
-  Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_EmbedderPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedderPtr;
 
-  @protected
-  List<int> dco_decode_list_prim_u_32_loose(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_EmbeddingCachePtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCachePtr;
 
-  @protected
-  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_EmbeddingStorePtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStorePtr;
 
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_GemmaEmbedderPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedderPtr;
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_GenericEmbedderPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedderPtr;
 
-  @protected
-  List<TokenOffsets> dco_decode_list_token_offsets(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_JinaV3EmbedderPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3EmbedderPtr;
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MiniLmEmbedderPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedderPtr;
 
-  @protected
-  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_NerTaggerPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTaggerPtr;
 
-  @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_NomicEmbedderPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedderPtr;
 
-  @protected
-  OrtEnvironmentOptions? dco_decode_opt_box_autoadd_ort_environment_options(
-    dynamic raw,
-  );
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_OrtSessionHandlePtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandlePtr;
 
-  @protected
-  OrtInitOptions? dco_decode_opt_box_autoadd_ort_init_options(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Qwen3EmbedderPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3EmbedderPtr;
 
-  @protected
-  OrtSessionOptions? dco_decode_opt_box_autoadd_ort_session_options(
-    dynamic raw,
-  );
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RerankerPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRerankerPtr;
 
-  @protected
-  OrtEnvironmentOptions dco_decode_ort_environment_options(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SequenceClassifierPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifierPtr;
 
-  @protected
-  OrtInitOptions dco_decode_ort_init_options(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SessionPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr;
 
-  @protected
-  OrtSessionOptions dco_decode_ort_session_options(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SpladeEmbedderPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedderPtr;
 
-  @protected
-  TokenOffsets dco_decode_token_offsets(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TextGeneratorPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGeneratorPtr;
 
-  @protected
-  int dco_decode_u_32(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_VectorIndexPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndexPtr;
 
-  @protected
-  BigInt dco_decode_u_64(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_VisionEmbedderPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedderPtr;
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_WhisperTranscriberPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriberPtr;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ZeroShotClassifierPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifierPtr;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+@protected BgeEmbedder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(dynamic raw);
+
+@protected Bm25Index dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index(dynamic raw);
+
+@protected CancellationToken dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(dynamic raw);
+
+@protected ClapEmbedder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder(dynamic raw);
+
+@protected ClipEmbedder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder(dynamic raw);
+
+@protected ColbertEmbedder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder(dynamic raw);
+
+@protected E5Embedder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder(dynamic raw);
+
+@protected EmbedProgress dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress(dynamic raw);
+
+@protected Embedder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder(dynamic raw);
+
+@protected EmbeddingCache dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache(dynamic raw);
+
+@protected EmbeddingStore dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore(dynamic raw);
+
+@protected GemmaEmbedder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(dynamic raw);
+
+@protected GenericEmbedder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder(dynamic raw);
+
+@protected JinaV3Embedder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(dynamic raw);
+
+@protected MiniLmEmbedder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(dynamic raw);
+
+@protected NerTagger dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger(dynamic raw);
+
+@protected NomicEmbedder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder(dynamic raw);
+
+@protected OrtSessionHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle(dynamic raw);
+
+@protected Qwen3Embedder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(dynamic raw);
+
+@protected Reranker dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker(dynamic raw);
+
+@protected SequenceClassifier dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier(dynamic raw);
+
+@protected Session dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(dynamic raw);
+
+@protected SpladeEmbedder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder(dynamic raw);
+
+@protected TextGenerator dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator(dynamic raw);
+
+@protected VectorIndex dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex(dynamic raw);
+
+@protected VisionEmbedder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder(dynamic raw);
+
+@protected WhisperTranscriber dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber(dynamic raw);
+
+@protected ZeroShotClassifier dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier(dynamic raw);
+
+@protected BgeEmbedder dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(dynamic raw);
+
+@protected Bm25Index dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index(dynamic raw);
+
+@protected ClapEmbedder dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder(dynamic raw);
+
+@protected ClipEmbedder dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder(dynamic raw);
+
+@protected ColbertEmbedder dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder(dynamic raw);
+
+@protected E5Embedder dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder(dynamic raw);
+
+@protected Embedder dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder(dynamic raw);
+
+@protected EmbeddingCache dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache(dynamic raw);
+
+@protected EmbeddingStore dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore(dynamic raw);
+
+@protected GemmaEmbedder dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(dynamic raw);
+
+@protected GenericEmbedder dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder(dynamic raw);
+
+@protected JinaV3Embedder dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(dynamic raw);
+
+@protected MiniLmEmbedder dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(dynamic raw);
+
+@protected NerTagger dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger(dynamic raw);
+
+@protected NomicEmbedder dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder(dynamic raw);
+
+@protected OrtSessionHandle dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle(dynamic raw);
+
+@protected Qwen3Embedder dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(dynamic raw);
+
+@protected Reranker dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker(dynamic raw);
+
+@protected SequenceClassifier dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier(dynamic raw);
+
+@protected SpladeEmbedder dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder(dynamic raw);
+
+@protected TextGenerator dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator(dynamic raw);
+
+@protected VectorIndex dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex(dynamic raw);
+
+@protected VisionEmbedder dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder(dynamic raw);
+
+@protected WhisperTranscriber dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber(dynamic raw);
+
+@protected ZeroShotClassifier dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier(dynamic raw);
+
+@protected Array2F32 dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(dynamic raw);
+
+@protected BgeEmbedder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(dynamic raw);
+
+@protected Bm25Index dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index(dynamic raw);
+
+@protected CancellationToken dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(dynamic raw);
+
+@protected ClapEmbedder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder(dynamic raw);
+
+@protected ClipEmbedder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder(dynamic raw);
+
+@protected ColbertEmbedder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder(dynamic raw);
+
+@protected E5Embedder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder(dynamic raw);
+
+@protected EmbedProgress dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress(dynamic raw);
+
+@protected Embedder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder(dynamic raw);
+
+@protected EmbeddingCache dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache(dynamic raw);
+
+@protected EmbeddingStore dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore(dynamic raw);
+
+@protected GemmaEmbedder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(dynamic raw);
+
+@protected GenericEmbedder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder(dynamic raw);
+
+@protected JinaV3Embedder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(dynamic raw);
+
+@protected MiniLmEmbedder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(dynamic raw);
+
+@protected NerTagger dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger(dynamic raw);
+
+@protected NomicEmbedder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder(dynamic raw);
+
+@protected OrtSessionHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle(dynamic raw);
+
+@protected Qwen3Embedder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(dynamic raw);
+
+@protected Reranker dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker(dynamic raw);
+
+@protected SequenceClassifier dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier(dynamic raw);
+
+@protected SpladeEmbedder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder(dynamic raw);
+
+@protected TextGenerator dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator(dynamic raw);
+
+@protected VectorIndex dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex(dynamic raw);
+
+@protected VisionEmbedder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder(dynamic raw);
+
+@protected WhisperTranscriber dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber(dynamic raw);
+
+@protected ZeroShotClassifier dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier(dynamic raw);
+
+@protected Array2F32 dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(dynamic raw);
+
+@protected BgeEmbedder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(dynamic raw);
+
+@protected Bm25Index dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index(dynamic raw);
+
+@protected CancellationToken dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(dynamic raw);
+
+@protected ClapEmbedder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder(dynamic raw);
+
+@protected ClipEmbedder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder(dynamic raw);
+
+@protected ColbertEmbedder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder(dynamic raw);
+
+@protected E5Embedder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder(dynamic raw);
+
+@protected EmbedProgress dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress(dynamic raw);
+
+@protected Embedder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder(dynamic raw);
+
+@protected EmbeddingCache dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache(dynamic raw);
+
+@protected EmbeddingStore dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore(dynamic raw);
+
+@protected GemmaEmbedder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(dynamic raw);
+
+@protected GenericEmbedder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder(dynamic raw);
+
+@protected JinaV3Embedder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(dynamic raw);
+
+@protected MiniLmEmbedder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(dynamic raw);
+
+@protected NerTagger dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger(dynamic raw);
+
+@protected NomicEmbedder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder(dynamic raw);
+
+@protected OrtSessionHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle(dynamic raw);
+
+@protected Qwen3Embedder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(dynamic raw);
+
+@protected Reranker dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker(dynamic raw);
+
+@protected SequenceClassifier dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier(dynamic raw);
+
+@protected Session dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(dynamic raw);
+
+@protected SpladeEmbedder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder(dynamic raw);
+
+@protected TextGenerator dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator(dynamic raw);
+
+@protected VectorIndex dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex(dynamic raw);
+
+@protected VisionEmbedder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder(dynamic raw);
+
+@protected WhisperTranscriber dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber(dynamic raw);
+
+@protected ZeroShotClassifier dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier(dynamic raw);
+
+@protected RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
+
+@protected RustStreamSink<DownloadProgress> dco_decode_StreamSink_download_progress_Sse(dynamic raw);
+
+@protected RustStreamSink<EmbeddingProgress> dco_decode_StreamSink_embedding_progress_Sse(dynamic raw);
+
+@protected RustStreamSink<JobStatus> dco_decode_StreamSink_job_status_Sse(dynamic raw);
+
+@protected RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected TextEmbedder dco_decode_TraitDef_TextEmbedder(dynamic raw);
+
+@protected AudioClip dco_decode_audio_clip(dynamic raw);
+
+@protected BatchOptions dco_decode_batch_options(dynamic raw);
+
+@protected BenchmarkConfig dco_decode_benchmark_config(dynamic raw);
+
+@protected BenchmarkReport dco_decode_benchmark_report(dynamic raw);
+
+@protected BenchmarkResult dco_decode_benchmark_result(dynamic raw);
+
+@protected Bm25Options dco_decode_bm_25_options(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected BatchOptions dco_decode_box_autoadd_batch_options(dynamic raw);
+
+@protected BenchmarkConfig dco_decode_box_autoadd_benchmark_config(dynamic raw);
+
+@protected Bm25Options dco_decode_box_autoadd_bm_25_options(dynamic raw);
+
+@protected bool dco_decode_box_autoadd_bool(dynamic raw);
+
+@protected CalibrationParams dco_decode_box_autoadd_calibration_params(dynamic raw);
+
+@protected ChatTemplate dco_decode_box_autoadd_chat_template(dynamic raw);
+
+@protected CoreMlComputeUnits dco_decode_box_autoadd_core_ml_compute_units(dynamic raw);
+
+@protected CoreMlModelFormat dco_decode_box_autoadd_core_ml_model_format(dynamic raw);
+
+@protected CutoffOptions dco_decode_box_autoadd_cutoff_options(dynamic raw);
+
+@protected EmbedManyOptions dco_decode_box_autoadd_embed_many_options(dynamic raw);
+
+@protected EmbedOptions dco_decode_box_autoadd_embed_options(dynamic raw);
+
+@protected EncodeOutput dco_decode_box_autoadd_encode_output(dynamic raw);
+
+@protected double dco_decode_box_autoadd_f_32(dynamic raw);
+
+@protected GenerationOptions dco_decode_box_autoadd_generation_options(dynamic raw);
+
+@protected HubOptions dco_decode_box_autoadd_hub_options(dynamic raw);
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+@protected InstalledModel dco_decode_box_autoadd_installed_model(dynamic raw);
+
+@protected Int8Embedding dco_decode_box_autoadd_int_8_embedding(dynamic raw);
+
+@protected JobStatus dco_decode_box_autoadd_job_status(dynamic raw);
+
+@protected LogLevel dco_decode_box_autoadd_log_level(dynamic raw);
+
+@protected OrtEnvironmentOptions dco_decode_box_autoadd_ort_environment_options(dynamic raw);
+
+@protected OrtInitOptions dco_decode_box_autoadd_ort_init_options(dynamic raw);
+
+@protected OrtSessionOptions dco_decode_box_autoadd_ort_session_options(dynamic raw);
+
+@protected PaddingInfo dco_decode_box_autoadd_padding_info(dynamic raw);
+
+@protected PreprocessOptions dco_decode_box_autoadd_preprocess_options(dynamic raw);
+
+@protected ProjectionOptions dco_decode_box_autoadd_projection_options(dynamic raw);
+
+@protected QnnPerformanceMode dco_decode_box_autoadd_qnn_performance_mode(dynamic raw);
+
+@protected RgbaPixels dco_decode_box_autoadd_rgba_pixels(dynamic raw);
+
+@protected SimilarityCalibration dco_decode_box_autoadd_similarity_calibration(dynamic raw);
+
+@protected SparseVector dco_decode_box_autoadd_sparse_vector(dynamic raw);
+
+@protected StoredEmbedding dco_decode_box_autoadd_stored_embedding(dynamic raw);
+
+@protected TokenEmbeddings dco_decode_box_autoadd_token_embeddings(dynamic raw);
+
+@protected TokenOffsets dco_decode_box_autoadd_token_offsets(dynamic raw);
+
+@protected TokenSalience dco_decode_box_autoadd_token_salience(dynamic raw);
+
+@protected TranscriptionOptions dco_decode_box_autoadd_transcription_options(dynamic raw);
+
+@protected TruncationInfo dco_decode_box_autoadd_truncation_info(dynamic raw);
+
+@protected TruncationStrategy dco_decode_box_autoadd_truncation_strategy(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+@protected VectorIndexOptions dco_decode_box_autoadd_vector_index_options(dynamic raw);
+
+@protected CalibrationMethod dco_decode_calibration_method(dynamic raw);
+
+@protected CalibrationParams dco_decode_calibration_params(dynamic raw);
+
+@protected CalibrationPoint dco_decode_calibration_point(dynamic raw);
+
+@protected ChatTemplate dco_decode_chat_template(dynamic raw);
+
+@protected CoreMlComputeUnits dco_decode_core_ml_compute_units(dynamic raw);
+
+@protected CoreMlModelFormat dco_decode_core_ml_model_format(dynamic raw);
+
+@protected CutoffOptions dco_decode_cutoff_options(dynamic raw);
+
+@protected DeviceCapabilities dco_decode_device_capabilities(dynamic raw);
+
+@protected DistanceMetric dco_decode_distance_metric(dynamic raw);
+
+@protected DownloadProgress dco_decode_download_progress(dynamic raw);
+
+@protected EmbedManyOptions dco_decode_embed_many_options(dynamic raw);
+
+@protected EmbedOptions dco_decode_embed_options(dynamic raw);
+
+@protected EmbedStats dco_decode_embed_stats(dynamic raw);
+
+@protected EmbedderComparison dco_decode_embedder_comparison(dynamic raw);
+
+@protected EmbedderKind dco_decode_embedder_kind(dynamic raw);
+
+@protected EmbeddingProgress dco_decode_embedding_progress(dynamic raw);
+
+@protected EmbeddingSetStats dco_decode_embedding_set_stats(dynamic raw);
+
+@protected EmbeddingsWithStats dco_decode_embeddings_with_stats(dynamic raw);
+
+@protected EncodeOutput dco_decode_encode_output(dynamic raw);
+
+@protected ErrorKind dco_decode_error_kind(dynamic raw);
+
+@protected ExecutionProviderConfig dco_decode_execution_provider_config(dynamic raw);
+
+@protected ExecutionProviderKind dco_decode_execution_provider_kind(dynamic raw);
+
+@protected double dco_decode_f_32(dynamic raw);
+
+@protected double dco_decode_f_64(dynamic raw);
+
+@protected FinishReason dco_decode_finish_reason(dynamic raw);
+
+@protected FlutterEmbedderError dco_decode_flutter_embedder_error(dynamic raw);
+
+@protected GenerationOptions dco_decode_generation_options(dynamic raw);
+
+@protected GenerationResult dco_decode_generation_result(dynamic raw);
+
+@protected HubOptions dco_decode_hub_options(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected int dco_decode_i_8(dynamic raw);
+
+@protected InstalledModel dco_decode_installed_model(dynamic raw);
+
+@protected Int8Embedding dco_decode_int_8_embedding(dynamic raw);
+
+@protected JinaTask dco_decode_jina_task(dynamic raw);
+
+@protected JobState dco_decode_job_state(dynamic raw);
+
+@protected JobStatus dco_decode_job_status(dynamic raw);
+
+@protected KeywordWeight dco_decode_keyword_weight(dynamic raw);
+
+@protected LabelScore dco_decode_label_score(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<BenchmarkResult> dco_decode_list_benchmark_result(dynamic raw);
+
+@protected List<bool> dco_decode_list_bool(dynamic raw);
+
+@protected List<CalibrationPoint> dco_decode_list_calibration_point(dynamic raw);
+
+@protected List<EncodeOutput> dco_decode_list_encode_output(dynamic raw);
+
+@protected List<ExecutionProviderConfig> dco_decode_list_execution_provider_config(dynamic raw);
+
+@protected List<ExecutionProviderKind> dco_decode_list_execution_provider_kind(dynamic raw);
+
+@protected List<InstalledModel> dco_decode_list_installed_model(dynamic raw);
+
+@protected List<JobStatus> dco_decode_list_job_status(dynamic raw);
+
+@protected List<KeywordWeight> dco_decode_list_keyword_weight(dynamic raw);
+
+@protected List<LabelScore> dco_decode_list_label_score(dynamic raw);
+
+@protected List<List<LabelScore>> dco_decode_list_list_label_score(dynamic raw);
+
+@protected List<List<Float32List>> dco_decode_list_list_list_prim_f_32_strict(dynamic raw);
+
+@protected List<List<NamedEntity>> dco_decode_list_list_named_entity(dynamic raw);
+
+@protected List<Float32List> dco_decode_list_list_prim_f_32_strict(dynamic raw);
+
+@protected List<Uint32List> dco_decode_list_list_prim_u_32_strict(dynamic raw);
+
+@protected List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw);
+
+@protected List<List<(String,double)>> dco_decode_list_list_record_string_f_32(dynamic raw);
+
+@protected List<NamedEntity> dco_decode_list_named_entity(dynamic raw);
+
+@protected List<NamedTensor> dco_decode_list_named_tensor(dynamic raw);
+
+@protected List<int?> dco_decode_list_opt_box_autoadd_u_32(dynamic raw);
+
+@protected List<PassageEmbedding> dco_decode_list_passage_embedding(dynamic raw);
+
+@protected List<double> dco_decode_list_prim_f_32_loose(dynamic raw);
+
+@protected Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+
+@protected Int64List dco_decode_list_prim_i_64_strict(dynamic raw);
+
+@protected Int8List dco_decode_list_prim_i_8_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_32_loose(dynamic raw);
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<(String?,String)> dco_decode_list_record_opt_string_string(dynamic raw);
+
+@protected List<(String,double)> dco_decode_list_record_string_f_32(dynamic raw);
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw);
+
+@protected List<(int,double)> dco_decode_list_record_u_32_f_32(dynamic raw);
+
+@protected List<ReferenceCase> dco_decode_list_reference_case(dynamic raw);
+
+@protected List<ReferenceCheck> dco_decode_list_reference_check(dynamic raw);
+
+@protected List<RerankResult> dco_decode_list_rerank_result(dynamic raw);
+
+@protected List<RgbaPixels> dco_decode_list_rgba_pixels(dynamic raw);
+
+@protected List<SearchHit> dco_decode_list_search_hit(dynamic raw);
+
+@protected List<SparseVector> dco_decode_list_sparse_vector(dynamic raw);
+
+@protected List<StoreHit> dco_decode_list_store_hit(dynamic raw);
+
+@protected List<TensorInfo> dco_decode_list_tensor_info(dynamic raw);
+
+@protected List<TextChunk> dco_decode_list_text_chunk(dynamic raw);
+
+@protected List<TokenEmbeddings> dco_decode_list_token_embeddings(dynamic raw);
+
+@protected List<TokenOffsets> dco_decode_list_token_offsets(dynamic raw);
+
+@protected List<TokenSalience> dco_decode_list_token_salience(dynamic raw);
+
+@protected List<VocabEntry> dco_decode_list_vocab_entry(dynamic raw);
+
+@protected LogEntry dco_decode_log_entry(dynamic raw);
+
+@protected LogLevel dco_decode_log_level(dynamic raw);
+
+@protected ModelInfo dco_decode_model_info(dynamic raw);
+
+@protected ModelVerification dco_decode_model_verification(dynamic raw);
+
+@protected NamedEntity dco_decode_named_entity(dynamic raw);
+
+@protected NamedTensor dco_decode_named_tensor(dynamic raw);
+
+@protected NomicTask dco_decode_nomic_task(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected BenchmarkConfig? dco_decode_opt_box_autoadd_benchmark_config(dynamic raw);
+
+@protected Bm25Options? dco_decode_opt_box_autoadd_bm_25_options(dynamic raw);
+
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+@protected CoreMlComputeUnits? dco_decode_opt_box_autoadd_core_ml_compute_units(dynamic raw);
+
+@protected CoreMlModelFormat? dco_decode_opt_box_autoadd_core_ml_model_format(dynamic raw);
+
+@protected CutoffOptions? dco_decode_opt_box_autoadd_cutoff_options(dynamic raw);
+
+@protected EmbedManyOptions? dco_decode_opt_box_autoadd_embed_many_options(dynamic raw);
+
+@protected double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+
+@protected GenerationOptions? dco_decode_opt_box_autoadd_generation_options(dynamic raw);
+
+@protected HubOptions? dco_decode_opt_box_autoadd_hub_options(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+@protected InstalledModel? dco_decode_opt_box_autoadd_installed_model(dynamic raw);
+
+@protected JobStatus? dco_decode_opt_box_autoadd_job_status(dynamic raw);
+
+@protected LogLevel? dco_decode_opt_box_autoadd_log_level(dynamic raw);
+
+@protected OrtEnvironmentOptions? dco_decode_opt_box_autoadd_ort_environment_options(dynamic raw);
+
+@protected OrtInitOptions? dco_decode_opt_box_autoadd_ort_init_options(dynamic raw);
+
+@protected OrtSessionOptions? dco_decode_opt_box_autoadd_ort_session_options(dynamic raw);
+
+@protected PaddingInfo? dco_decode_opt_box_autoadd_padding_info(dynamic raw);
+
+@protected ProjectionOptions? dco_decode_opt_box_autoadd_projection_options(dynamic raw);
+
+@protected QnnPerformanceMode? dco_decode_opt_box_autoadd_qnn_performance_mode(dynamic raw);
+
+@protected StoredEmbedding? dco_decode_opt_box_autoadd_stored_embedding(dynamic raw);
+
+@protected TokenOffsets? dco_decode_opt_box_autoadd_token_offsets(dynamic raw);
+
+@protected TranscriptionOptions? dco_decode_opt_box_autoadd_transcription_options(dynamic raw);
+
+@protected TruncationInfo? dco_decode_opt_box_autoadd_truncation_info(dynamic raw);
+
+@protected TruncationStrategy? dco_decode_opt_box_autoadd_truncation_strategy(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected VectorIndexOptions? dco_decode_opt_box_autoadd_vector_index_options(dynamic raw);
+
+@protected List<String>? dco_decode_opt_list_String(dynamic raw);
+
+@protected List<Float32List>? dco_decode_opt_list_list_prim_f_32_strict(dynamic raw);
+
+@protected Float32List? dco_decode_opt_list_prim_f_32_strict(dynamic raw);
+
+@protected Uint32List? dco_decode_opt_list_prim_u_32_strict(dynamic raw);
+
+@protected OrtEnvironmentOptions dco_decode_ort_environment_options(dynamic raw);
+
+@protected OrtInitOptions dco_decode_ort_init_options(dynamic raw);
+
+@protected OrtSessionOptions dco_decode_ort_session_options(dynamic raw);
+
+@protected PaddedBatch dco_decode_padded_batch(dynamic raw);
+
+@protected PaddingInfo dco_decode_padding_info(dynamic raw);
+
+@protected PaddingStrategy dco_decode_padding_strategy(dynamic raw);
+
+@protected PassageEmbedding dco_decode_passage_embedding(dynamic raw);
+
+@protected PreprocessOptions dco_decode_preprocess_options(dynamic raw);
+
+@protected ProjectionOptions dco_decode_projection_options(dynamic raw);
+
+@protected ProjectionResult dco_decode_projection_result(dynamic raw);
+
+@protected QnnPerformanceMode dco_decode_qnn_performance_mode(dynamic raw);
+
+@protected (String?,String) dco_decode_record_opt_string_string(dynamic raw);
+
+@protected (String,double) dco_decode_record_string_f_32(dynamic raw);
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw);
+
+@protected (int,double) dco_decode_record_u_32_f_32(dynamic raw);
+
+@protected ReferenceCase dco_decode_reference_case(dynamic raw);
+
+@protected ReferenceCheck dco_decode_reference_check(dynamic raw);
+
+@protected RerankResult dco_decode_rerank_result(dynamic raw);
+
+@protected RerankerKind dco_decode_reranker_kind(dynamic raw);
+
+@protected ResizeFilter dco_decode_resize_filter(dynamic raw);
+
+@protected ResizeMode dco_decode_resize_mode(dynamic raw);
+
+@protected RgbaPixels dco_decode_rgba_pixels(dynamic raw);
+
+@protected ScoreNormalization dco_decode_score_normalization(dynamic raw);
+
+@protected SearchHit dco_decode_search_hit(dynamic raw);
+
+@protected SimilarityCalibration dco_decode_similarity_calibration(dynamic raw);
+
+@protected SparseVector dco_decode_sparse_vector(dynamic raw);
+
+@protected StoreHit dco_decode_store_hit(dynamic raw);
+
+@protected StoredEmbedding dco_decode_stored_embedding(dynamic raw);
+
+@protected TensorDtype dco_decode_tensor_dtype(dynamic raw);
+
+@protected TensorInfo dco_decode_tensor_info(dynamic raw);
+
+@protected TensorLayout dco_decode_tensor_layout(dynamic raw);
+
+@protected TextChunk dco_decode_text_chunk(dynamic raw);
+
+@protected TokenEmbeddings dco_decode_token_embeddings(dynamic raw);
+
+@protected TokenOffsets dco_decode_token_offsets(dynamic raw);
+
+@protected TokenSalience dco_decode_token_salience(dynamic raw);
+
+@protected TokenizerInfo dco_decode_tokenizer_info(dynamic raw);
+
+@protected Transcription dco_decode_transcription(dynamic raw);
+
+@protected TranscriptionOptions dco_decode_transcription_options(dynamic raw);
+
+@protected TruncationInfo dco_decode_truncation_info(dynamic raw);
+
+@protected TruncationStrategy dco_decode_truncation_strategy(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected VectorIndexOptions dco_decode_vector_index_options(dynamic raw);
+
+@protected VisionPreset dco_decode_vision_preset(dynamic raw);
+
+@protected VocabEntry dco_decode_vocab_entry(dynamic raw);
+
+@protected Workload dco_decode_workload(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected BgeEmbedder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(SseDeserializer deserializer);
+
+@protected Bm25Index sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index(SseDeserializer deserializer);
+
+@protected CancellationToken sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(SseDeserializer deserializer);
+
+@protected ClapEmbedder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder(SseDeserializer deserializer);
+
+@protected ClipEmbedder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder(SseDeserializer deserializer);
+
+@protected ColbertEmbedder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder(SseDeserializer deserializer);
+
+@protected E5Embedder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder(SseDeserializer deserializer);
+
+@protected EmbedProgress sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress(SseDeserializer deserializer);
+
+@protected Embedder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder(SseDeserializer deserializer);
+
+@protected EmbeddingCache sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache(SseDeserializer deserializer);
+
+@protected EmbeddingStore sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore(SseDeserializer deserializer);
+
+@protected GemmaEmbedder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(SseDeserializer deserializer);
+
+@protected GenericEmbedder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder(SseDeserializer deserializer);
+
+@protected JinaV3Embedder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(SseDeserializer deserializer);
+
+@protected MiniLmEmbedder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(SseDeserializer deserializer);
+
+@protected NerTagger sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger(SseDeserializer deserializer);
+
+@protected NomicEmbedder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder(SseDeserializer deserializer);
+
+@protected OrtSessionHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle(SseDeserializer deserializer);
+
+@protected Qwen3Embedder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(SseDeserializer deserializer);
+
+@protected Reranker sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker(SseDeserializer deserializer);
+
+@protected SequenceClassifier sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier(SseDeserializer deserializer);
+
+@protected Session sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(SseDeserializer deserializer);
+
+@protected SpladeEmbedder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder(SseDeserializer deserializer);
+
+@protected TextGenerator sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator(SseDeserializer deserializer);
+
+@protected VectorIndex sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex(SseDeserializer deserializer);
+
+@protected VisionEmbedder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder(SseDeserializer deserializer);
+
+@protected WhisperTranscriber sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber(SseDeserializer deserializer);
+
+@protected ZeroShotClassifier sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier(SseDeserializer deserializer);
+
+@protected BgeEmbedder sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(SseDeserializer deserializer);
+
+@protected Bm25Index sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index(SseDeserializer deserializer);
+
+@protected ClapEmbedder sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder(SseDeserializer deserializer);
+
+@protected ClipEmbedder sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder(SseDeserializer deserializer);
+
+@protected ColbertEmbedder sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder(SseDeserializer deserializer);
+
+@protected E5Embedder sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder(SseDeserializer deserializer);
+
+@protected Embedder sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder(SseDeserializer deserializer);
+
+@protected EmbeddingCache sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache(SseDeserializer deserializer);
+
+@protected EmbeddingStore sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore(SseDeserializer deserializer);
+
+@protected GemmaEmbedder sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(SseDeserializer deserializer);
+
+@protected GenericEmbedder sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder(SseDeserializer deserializer);
+
+@protected JinaV3Embedder sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(SseDeserializer deserializer);
+
+@protected MiniLmEmbedder sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(SseDeserializer deserializer);
+
+@protected NerTagger sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger(SseDeserializer deserializer);
+
+@protected NomicEmbedder sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder(SseDeserializer deserializer);
+
+@protected OrtSessionHandle sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle(SseDeserializer deserializer);
+
+@protected Qwen3Embedder sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(SseDeserializer deserializer);
+
+@protected Reranker sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker(SseDeserializer deserializer);
+
+@protected SequenceClassifier sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier(SseDeserializer deserializer);
+
+@protected SpladeEmbedder sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder(SseDeserializer deserializer);
+
+@protected TextGenerator sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator(SseDeserializer deserializer);
+
+@protected VectorIndex sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex(SseDeserializer deserializer);
+
+@protected VisionEmbedder sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder(SseDeserializer deserializer);
+
+@protected WhisperTranscriber sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber(SseDeserializer deserializer);
+
+@protected ZeroShotClassifier sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier(SseDeserializer deserializer);
+
+@protected Array2F32 sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(SseDeserializer deserializer);
+
+@protected BgeEmbedder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(SseDeserializer deserializer);
+
+@protected Bm25Index sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index(SseDeserializer deserializer);
+
+@protected CancellationToken sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(SseDeserializer deserializer);
+
+@protected ClapEmbedder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder(SseDeserializer deserializer);
+
+@protected ClipEmbedder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder(SseDeserializer deserializer);
+
+@protected ColbertEmbedder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder(SseDeserializer deserializer);
+
+@protected E5Embedder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder(SseDeserializer deserializer);
+
+@protected EmbedProgress sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress(SseDeserializer deserializer);
+
+@protected Embedder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder(SseDeserializer deserializer);
+
+@protected EmbeddingCache sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache(SseDeserializer deserializer);
+
+@protected EmbeddingStore sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore(SseDeserializer deserializer);
+
+@protected GemmaEmbedder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(SseDeserializer deserializer);
+
+@protected GenericEmbedder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder(SseDeserializer deserializer);
+
+@protected JinaV3Embedder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(SseDeserializer deserializer);
+
+@protected MiniLmEmbedder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(SseDeserializer deserializer);
+
+@protected NerTagger sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger(SseDeserializer deserializer);
+
+@protected NomicEmbedder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder(SseDeserializer deserializer);
+
+@protected OrtSessionHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle(SseDeserializer deserializer);
+
+@protected Qwen3Embedder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(SseDeserializer deserializer);
+
+@protected Reranker sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker(SseDeserializer deserializer);
+
+@protected SequenceClassifier sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier(SseDeserializer deserializer);
+
+@protected SpladeEmbedder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder(SseDeserializer deserializer);
+
+@protected TextGenerator sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator(SseDeserializer deserializer);
+
+@protected VectorIndex sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex(SseDeserializer deserializer);
+
+@protected VisionEmbedder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder(SseDeserializer deserializer);
+
+@protected WhisperTranscriber sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber(SseDeserializer deserializer);
+
+@protected ZeroShotClassifier sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier(SseDeserializer deserializer);
+
+@protected Array2F32 sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(SseDeserializer deserializer);
+
+@protected BgeEmbedder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(SseDeserializer deserializer);
+
+@protected Bm25Index sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index(SseDeserializer deserializer);
+
+@protected CancellationToken sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(SseDeserializer deserializer);
+
+@protected ClapEmbedder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder(SseDeserializer deserializer);
+
+@protected ClipEmbedder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder(SseDeserializer deserializer);
+
+@protected ColbertEmbedder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder(SseDeserializer deserializer);
+
+@protected E5Embedder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder(SseDeserializer deserializer);
+
+@protected EmbedProgress sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress(SseDeserializer deserializer);
+
+@protected Embedder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder(SseDeserializer deserializer);
+
+@protected EmbeddingCache sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache(SseDeserializer deserializer);
+
+@protected EmbeddingStore sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore(SseDeserializer deserializer);
+
+@protected GemmaEmbedder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(SseDeserializer deserializer);
+
+@protected GenericEmbedder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder(SseDeserializer deserializer);
+
+@protected JinaV3Embedder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(SseDeserializer deserializer);
+
+@protected MiniLmEmbedder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(SseDeserializer deserializer);
+
+@protected NerTagger sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger(SseDeserializer deserializer);
+
+@protected NomicEmbedder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder(SseDeserializer deserializer);
+
+@protected OrtSessionHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle(SseDeserializer deserializer);
+
+@protected Qwen3Embedder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(SseDeserializer deserializer);
+
+@protected Reranker sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker(SseDeserializer deserializer);
+
+@protected SequenceClassifier sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier(SseDeserializer deserializer);
+
+@protected Session sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(SseDeserializer deserializer);
+
+@protected SpladeEmbedder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder(SseDeserializer deserializer);
+
+@protected TextGenerator sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator(SseDeserializer deserializer);
+
+@protected VectorIndex sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex(SseDeserializer deserializer);
+
+@protected VisionEmbedder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder(SseDeserializer deserializer);
+
+@protected WhisperTranscriber sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber(SseDeserializer deserializer);
+
+@protected ZeroShotClassifier sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier(SseDeserializer deserializer);
+
+@protected RustStreamSink<String> sse_decode_StreamSink_String_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<DownloadProgress> sse_decode_StreamSink_download_progress_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<EmbeddingProgress> sse_decode_StreamSink_embedding_progress_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<JobStatus> sse_decode_StreamSink_job_status_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AudioClip sse_decode_audio_clip(SseDeserializer deserializer);
+
+@protected BatchOptions sse_decode_batch_options(SseDeserializer deserializer);
+
+@protected BenchmarkConfig sse_decode_benchmark_config(SseDeserializer deserializer);
+
+@protected BenchmarkReport sse_decode_benchmark_report(SseDeserializer deserializer);
+
+@protected BenchmarkResult sse_decode_benchmark_result(SseDeserializer deserializer);
+
+@protected Bm25Options sse_decode_bm_25_options(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected BatchOptions sse_decode_box_autoadd_batch_options(SseDeserializer deserializer);
+
+@protected BenchmarkConfig sse_decode_box_autoadd_benchmark_config(SseDeserializer deserializer);
+
+@protected Bm25Options sse_decode_box_autoadd_bm_25_options(SseDeserializer deserializer);
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected CalibrationParams sse_decode_box_autoadd_calibration_params(SseDeserializer deserializer);
+
+@protected ChatTemplate sse_decode_box_autoadd_chat_template(SseDeserializer deserializer);
+
+@protected CoreMlComputeUnits sse_decode_box_autoadd_core_ml_compute_units(SseDeserializer deserializer);
+
+@protected CoreMlModelFormat sse_decode_box_autoadd_core_ml_model_format(SseDeserializer deserializer);
+
+@protected CutoffOptions sse_decode_box_autoadd_cutoff_options(SseDeserializer deserializer);
+
+@protected EmbedManyOptions sse_decode_box_autoadd_embed_many_options(SseDeserializer deserializer);
+
+@protected EmbedOptions sse_decode_box_autoadd_embed_options(SseDeserializer deserializer);
+
+@protected EncodeOutput sse_decode_box_autoadd_encode_output(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+
+@protected GenerationOptions sse_decode_box_autoadd_generation_options(SseDeserializer deserializer);
+
+@protected HubOptions sse_decode_box_autoadd_hub_options(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected InstalledModel sse_decode_box_autoadd_installed_model(SseDeserializer deserializer);
+
+@protected Int8Embedding sse_decode_box_autoadd_int_8_embedding(SseDeserializer deserializer);
+
+@protected JobStatus sse_decode_box_autoadd_job_status(SseDeserializer deserializer);
+
+@protected LogLevel sse_decode_box_autoadd_log_level(SseDeserializer deserializer);
+
+@protected OrtEnvironmentOptions sse_decode_box_autoadd_ort_environment_options(SseDeserializer deserializer);
+
+@protected OrtInitOptions sse_decode_box_autoadd_ort_init_options(SseDeserializer deserializer);
+
+@protected OrtSessionOptions sse_decode_box_autoadd_ort_session_options(SseDeserializer deserializer);
+
+@protected PaddingInfo sse_decode_box_autoadd_padding_info(SseDeserializer deserializer);
+
+@protected PreprocessOptions sse_decode_box_autoadd_preprocess_options(SseDeserializer deserializer);
+
+@protected ProjectionOptions sse_decode_box_autoadd_projection_options(SseDeserializer deserializer);
+
+@protected QnnPerformanceMode sse_decode_box_autoadd_qnn_performance_mode(SseDeserializer deserializer);
+
+@protected RgbaPixels sse_decode_box_autoadd_rgba_pixels(SseDeserializer deserializer);
+
+@protected SimilarityCalibration sse_decode_box_autoadd_similarity_calibration(SseDeserializer deserializer);
+
+@protected SparseVector sse_decode_box_autoadd_sparse_vector(SseDeserializer deserializer);
+
+@protected StoredEmbedding sse_decode_box_autoadd_stored_embedding(SseDeserializer deserializer);
+
+@protected TokenEmbeddings sse_decode_box_autoadd_token_embeddings(SseDeserializer deserializer);
+
+@protected TokenOffsets sse_decode_box_autoadd_token_offsets(SseDeserializer deserializer);
+
+@protected TokenSalience sse_decode_box_autoadd_token_salience(SseDeserializer deserializer);
+
+@protected TranscriptionOptions sse_decode_box_autoadd_transcription_options(SseDeserializer deserializer);
+
+@protected TruncationInfo sse_decode_box_autoadd_truncation_info(SseDeserializer deserializer);
+
+@protected TruncationStrategy sse_decode_box_autoadd_truncation_strategy(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected VectorIndexOptions sse_decode_box_autoadd_vector_index_options(SseDeserializer deserializer);
+
+@protected CalibrationMethod sse_decode_calibration_method(SseDeserializer deserializer);
+
+@protected CalibrationParams sse_decode_calibration_params(SseDeserializer deserializer);
+
+@protected CalibrationPoint sse_decode_calibration_point(SseDeserializer deserializer);
+
+@protected ChatTemplate sse_decode_chat_template(SseDeserializer deserializer);
+
+@protected CoreMlComputeUnits sse_decode_core_ml_compute_units(SseDeserializer deserializer);
+
+@protected CoreMlModelFormat sse_decode_core_ml_model_format(SseDeserializer deserializer);
+
+@protected CutoffOptions sse_decode_cutoff_options(SseDeserializer deserializer);
+
+@protected DeviceCapabilities sse_decode_device_capabilities(SseDeserializer deserializer);
+
+@protected DistanceMetric sse_decode_distance_metric(SseDeserializer deserializer);
+
+@protected DownloadProgress sse_decode_download_progress(SseDeserializer deserializer);
+
+@protected EmbedManyOptions sse_decode_embed_many_options(SseDeserializer deserializer);
+
+@protected EmbedOptions sse_decode_embed_options(SseDeserializer deserializer);
+
+@protected EmbedStats sse_decode_embed_stats(SseDeserializer deserializer);
+
+@protected EmbedderComparison sse_decode_embedder_comparison(SseDeserializer deserializer);
+
+@protected EmbedderKind sse_decode_embedder_kind(SseDeserializer deserializer);
+
+@protected EmbeddingProgress sse_decode_embedding_progress(SseDeserializer deserializer);
+
+@protected EmbeddingSetStats sse_decode_embedding_set_stats(SseDeserializer deserializer);
+
+@protected EmbeddingsWithStats sse_decode_embeddings_with_stats(SseDeserializer deserializer);
+
+@protected EncodeOutput sse_decode_encode_output(SseDeserializer deserializer);
+
+@protected ErrorKind sse_decode_error_kind(SseDeserializer deserializer);
+
+@protected ExecutionProviderConfig sse_decode_execution_provider_config(SseDeserializer deserializer);
+
+@protected ExecutionProviderKind sse_decode_execution_provider_kind(SseDeserializer deserializer);
+
+@protected double sse_decode_f_32(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FinishReason sse_decode_finish_reason(SseDeserializer deserializer);
+
+@protected FlutterEmbedderError sse_decode_flutter_embedder_error(SseDeserializer deserializer);
+
+@protected GenerationOptions sse_decode_generation_options(SseDeserializer deserializer);
+
+@protected GenerationResult sse_decode_generation_result(SseDeserializer deserializer);
+
+@protected HubOptions sse_decode_hub_options(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected int sse_decode_i_8(SseDeserializer deserializer);
+
+@protected InstalledModel sse_decode_installed_model(SseDeserializer deserializer);
+
+@protected Int8Embedding sse_decode_int_8_embedding(SseDeserializer deserializer);
+
+@protected JinaTask sse_decode_jina_task(SseDeserializer deserializer);
+
+@protected JobState sse_decode_job_state(SseDeserializer deserializer);
+
+@protected JobStatus sse_decode_job_status(SseDeserializer deserializer);
+
+@protected KeywordWeight sse_decode_keyword_weight(SseDeserializer deserializer);
+
+@protected LabelScore sse_decode_label_score(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<BenchmarkResult> sse_decode_list_benchmark_result(SseDeserializer deserializer);
+
+@protected List<bool> sse_decode_list_bool(SseDeserializer deserializer);
+
+@protected List<CalibrationPoint> sse_decode_list_calibration_point(SseDeserializer deserializer);
+
+@protected List<EncodeOutput> sse_decode_list_encode_output(SseDeserializer deserializer);
+
+@protected List<ExecutionProviderConfig> sse_decode_list_execution_provider_config(SseDeserializer deserializer);
+
+@protected List<ExecutionProviderKind> sse_decode_list_execution_provider_kind(SseDeserializer deserializer);
+
+@protected List<InstalledModel> sse_decode_list_installed_model(SseDeserializer deserializer);
+
+@protected List<JobStatus> sse_decode_list_job_status(SseDeserializer deserializer);
+
+@protected List<KeywordWeight> sse_decode_list_keyword_weight(SseDeserializer deserializer);
+
+@protected List<LabelScore> sse_decode_list_label_score(SseDeserializer deserializer);
+
+@protected List<List<LabelScore>> sse_decode_list_list_label_score(SseDeserializer deserializer);
+
+@protected List<List<Float32List>> sse_decode_list_list_list_prim_f_32_strict(SseDeserializer deserializer);
+
+@protected List<List<NamedEntity>> sse_decode_list_list_named_entity(SseDeserializer deserializer);
+
+@protected List<Float32List> sse_decode_list_list_prim_f_32_strict(SseDeserializer deserializer);
+
+@protected List<Uint32List> sse_decode_list_list_prim_u_32_strict(SseDeserializer deserializer);
+
+@protected List<Uint8List> sse_decode_list_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<List<(String,double)>> sse_decode_list_list_record_string_f_32(SseDeserializer deserializer);
+
+@protected List<NamedEntity> sse_decode_list_named_entity(SseDeserializer deserializer);
+
+@protected List<NamedTensor> sse_decode_list_named_tensor(SseDeserializer deserializer);
+
+@protected List<int?> sse_decode_list_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected List<PassageEmbedding> sse_decode_list_passage_embedding(SseDeserializer deserializer);
+
+@protected List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
+
+@protected Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+
+@protected Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer);
+
+@protected Int8List sse_decode_list_prim_i_8_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer);
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<(String?,String)> sse_decode_list_record_opt_string_string(SseDeserializer deserializer);
+
+@protected List<(String,double)> sse_decode_list_record_string_f_32(SseDeserializer deserializer);
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer);
+
+@protected List<(int,double)> sse_decode_list_record_u_32_f_32(SseDeserializer deserializer);
+
+@protected List<ReferenceCase> sse_decode_list_reference_case(SseDeserializer deserializer);
+
+@protected List<ReferenceCheck> sse_decode_list_reference_check(SseDeserializer deserializer);
+
+@protected List<RerankResult> sse_decode_list_rerank_result(SseDeserializer deserializer);
+
+@protected List<RgbaPixels> sse_decode_list_rgba_pixels(SseDeserializer deserializer);
+
+@protected List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer);
+
+@protected List<SparseVector> sse_decode_list_sparse_vector(SseDeserializer deserializer);
+
+@protected List<StoreHit> sse_decode_list_store_hit(SseDeserializer deserializer);
+
+@protected List<TensorInfo> sse_decode_list_tensor_info(SseDeserializer deserializer);
+
+@protected List<TextChunk> sse_decode_list_text_chunk(SseDeserializer deserializer);
+
+@protected List<TokenEmbeddings> sse_decode_list_token_embeddings(SseDeserializer deserializer);
+
+@protected List<TokenOffsets> sse_decode_list_token_offsets(SseDeserializer deserializer);
+
+@protected List<TokenSalience> sse_decode_list_token_salience(SseDeserializer deserializer);
+
+@protected List<VocabEntry> sse_decode_list_vocab_entry(SseDeserializer deserializer);
+
+@protected LogEntry sse_decode_log_entry(SseDeserializer deserializer);
+
+@protected LogLevel sse_decode_log_level(SseDeserializer deserializer);
+
+@protected ModelInfo sse_decode_model_info(SseDeserializer deserializer);
+
+@protected ModelVerification sse_decode_model_verification(SseDeserializer deserializer);
+
+@protected NamedEntity sse_decode_named_entity(SseDeserializer deserializer);
+
+@protected NamedTensor sse_decode_named_tensor(SseDeserializer deserializer);
+
+@protected NomicTask sse_decode_nomic_task(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected BenchmarkConfig? sse_decode_opt_box_autoadd_benchmark_config(SseDeserializer deserializer);
+
+@protected Bm25Options? sse_decode_opt_box_autoadd_bm_25_options(SseDeserializer deserializer);
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected CoreMlComputeUnits? sse_decode_opt_box_autoadd_core_ml_compute_units(SseDeserializer deserializer);
+
+@protected CoreMlModelFormat? sse_decode_opt_box_autoadd_core_ml_model_format(SseDeserializer deserializer);
+
+@protected CutoffOptions? sse_decode_opt_box_autoadd_cutoff_options(SseDeserializer deserializer);
+
+@protected EmbedManyOptions? sse_decode_opt_box_autoadd_embed_many_options(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+@protected GenerationOptions? sse_decode_opt_box_autoadd_generation_options(SseDeserializer deserializer);
+
+@protected HubOptions? sse_decode_opt_box_autoadd_hub_options(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected InstalledModel? sse_decode_opt_box_autoadd_installed_model(SseDeserializer deserializer);
+
+@protected JobStatus? sse_decode_opt_box_autoadd_job_status(SseDeserializer deserializer);
+
+@protected LogLevel? sse_decode_opt_box_autoadd_log_level(SseDeserializer deserializer);
+
+@protected OrtEnvironmentOptions? sse_decode_opt_box_autoadd_ort_environment_options(SseDeserializer deserializer);
+
+@protected OrtInitOptions? sse_decode_opt_box_autoadd_ort_init_options(SseDeserializer deserializer);
+
+@protected OrtSessionOptions? sse_decode_opt_box_autoadd_ort_session_options(SseDeserializer deserializer);
+
+@protected PaddingInfo? sse_decode_opt_box_autoadd_padding_info(SseDeserializer deserializer);
+
+@protected ProjectionOptions? sse_decode_opt_box_autoadd_projection_options(SseDeserializer deserializer);
+
+@protected QnnPerformanceMode? sse_decode_opt_box_autoadd_qnn_performance_mode(SseDeserializer deserializer);
+
+@protected StoredEmbedding? sse_decode_opt_box_autoadd_stored_embedding(SseDeserializer deserializer);
+
+@protected TokenOffsets? sse_decode_opt_box_autoadd_token_offsets(SseDeserializer deserializer);
+
+@protected TranscriptionOptions? sse_decode_opt_box_autoadd_transcription_options(SseDeserializer deserializer);
+
+@protected TruncationInfo? sse_decode_opt_box_autoadd_truncation_info(SseDeserializer deserializer);
+
+@protected TruncationStrategy? sse_decode_opt_box_autoadd_truncation_strategy(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected VectorIndexOptions? sse_decode_opt_box_autoadd_vector_index_options(SseDeserializer deserializer);
+
+@protected List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+@protected List<Float32List>? sse_decode_opt_list_list_prim_f_32_strict(SseDeserializer deserializer);
+
+@protected Float32List? sse_decode_opt_list_prim_f_32_strict(SseDeserializer deserializer);
+
+@protected Uint32List? sse_decode_opt_list_prim_u_32_strict(SseDeserializer deserializer);
+
+@protected OrtEnvironmentOptions sse_decode_ort_environment_options(SseDeserializer deserializer);
+
+@protected OrtInitOptions sse_decode_ort_init_options(SseDeserializer deserializer);
+
+@protected OrtSessionOptions sse_decode_ort_session_options(SseDeserializer deserializer);
+
+@protected PaddedBatch sse_decode_padded_batch(SseDeserializer deserializer);
+
+@protected PaddingInfo sse_decode_padding_info(SseDeserializer deserializer);
+
+@protected PaddingStrategy sse_decode_padding_strategy(SseDeserializer deserializer);
+
+@protected PassageEmbedding sse_decode_passage_embedding(SseDeserializer deserializer);
+
+@protected PreprocessOptions sse_decode_preprocess_options(SseDeserializer deserializer);
+
+@protected ProjectionOptions sse_decode_projection_options(SseDeserializer deserializer);
+
+@protected ProjectionResult sse_decode_projection_result(SseDeserializer deserializer);
+
+@protected QnnPerformanceMode sse_decode_qnn_performance_mode(SseDeserializer deserializer);
+
+@protected (String?,String) sse_decode_record_opt_string_string(SseDeserializer deserializer);
+
+@protected (String,double) sse_decode_record_string_f_32(SseDeserializer deserializer);
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer);
+
+@protected (int,double) sse_decode_record_u_32_f_32(SseDeserializer deserializer);
+
+@protected ReferenceCase sse_decode_reference_case(SseDeserializer deserializer);
+
+@protected ReferenceCheck sse_decode_reference_check(SseDeserializer deserializer);
+
+@protected RerankResult sse_decode_rerank_result(SseDeserializer deserializer);
+
+@protected RerankerKind sse_decode_reranker_kind(SseDeserializer deserializer);
+
+@protected ResizeFilter sse_decode_resize_filter(SseDeserializer deserializer);
+
+@protected ResizeMode sse_decode_resize_mode(SseDeserializer deserializer);
+
+@protected RgbaPixels sse_decode_rgba_pixels(SseDeserializer deserializer);
+
+@protected ScoreNormalization sse_decode_score_normalization(SseDeserializer deserializer);
+
+@protected SearchHit sse_decode_search_hit(SseDeserializer deserializer);
+
+@protected SimilarityCalibration sse_decode_similarity_calibration(SseDeserializer deserializer);
+
+@protected SparseVector sse_decode_sparse_vector(SseDeserializer deserializer);
+
+@protected StoreHit sse_decode_store_hit(SseDeserializer deserializer);
+
+@protected StoredEmbedding sse_decode_stored_embedding(SseDeserializer deserializer);
+
+@protected TensorDtype sse_decode_tensor_dtype(SseDeserializer deserializer);
+
+@protected TensorInfo sse_decode_tensor_info(SseDeserializer deserializer);
+
+@protected TensorLayout sse_decode_tensor_layout(SseDeserializer deserializer);
+
+@protected TextChunk sse_decode_text_chunk(SseDeserializer deserializer);
+
+@protected TokenEmbeddings sse_decode_token_embeddings(SseDeserializer deserializer);
+
+@protected TokenOffsets sse_decode_token_offsets(SseDeserializer deserializer);
+
+@protected TokenSalience sse_decode_token_salience(SseDeserializer deserializer);
+
+@protected TokenizerInfo sse_decode_tokenizer_info(SseDeserializer deserializer);
+
+@protected Transcription sse_decode_transcription(SseDeserializer deserializer);
+
+@protected TranscriptionOptions sse_decode_transcription_options(SseDeserializer deserializer);
+
+@protected TruncationInfo sse_decode_truncation_info(SseDeserializer deserializer);
+
+@protected TruncationStrategy sse_decode_truncation_strategy(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected VectorIndexOptions sse_decode_vector_index_options(SseDeserializer deserializer);
+
+@protected VisionPreset sse_decode_vision_preset(SseDeserializer deserializer);
+
+@protected VocabEntry sse_decode_vocab_entry(SseDeserializer deserializer);
+
+@protected Workload sse_decode_workload(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(BgeEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index(Bm25Index self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(CancellationToken self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder(ClapEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder(ClipEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder(ColbertEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder(E5Embedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress(EmbedProgress self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder(Embedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache(EmbeddingCache self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore(EmbeddingStore self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(GemmaEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder(GenericEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(JinaV3Embedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(MiniLmEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger(NerTagger self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder(NomicEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle(OrtSessionHandle self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(Qwen3Embedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker(Reranker self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier(SequenceClassifier self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(Session self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder(SpladeEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator(TextGenerator self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex(VectorIndex self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder(VisionEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber(WhisperTranscriber self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier(ZeroShotClassifier self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(BgeEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index(Bm25Index self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder(ClapEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder(ClipEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder(ColbertEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder(E5Embedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder(Embedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache(EmbeddingCache self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore(EmbeddingStore self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(GemmaEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder(GenericEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(JinaV3Embedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(MiniLmEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger(NerTagger self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder(NomicEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle(OrtSessionHandle self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(Qwen3Embedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker(Reranker self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier(SequenceClassifier self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder(SpladeEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator(TextGenerator self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex(VectorIndex self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder(VisionEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber(WhisperTranscriber self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier(ZeroShotClassifier self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(Array2F32 self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(BgeEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index(Bm25Index self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(CancellationToken self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder(ClapEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder(ClipEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder(ColbertEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder(E5Embedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress(EmbedProgress self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder(Embedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache(EmbeddingCache self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore(EmbeddingStore self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(GemmaEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder(GenericEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(JinaV3Embedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(MiniLmEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger(NerTagger self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder(NomicEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle(OrtSessionHandle self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(Qwen3Embedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker(Reranker self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier(SequenceClassifier self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder(SpladeEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator(TextGenerator self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex(VectorIndex self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder(VisionEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber(WhisperTranscriber self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier(ZeroShotClassifier self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(Array2F32 self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(BgeEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index(Bm25Index self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(CancellationToken self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder(ClapEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder(ClipEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder(ColbertEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder(E5Embedder self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress(EmbedProgress self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder(Embedder self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache(EmbeddingCache self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore(EmbeddingStore self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(GemmaEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder(GenericEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(JinaV3Embedder self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(MiniLmEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger(NerTagger self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder(NomicEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle(OrtSessionHandle self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(Qwen3Embedder self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker(Reranker self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier(SequenceClassifier self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(Session self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder(SpladeEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator(TextGenerator self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex(VectorIndex self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder(VisionEmbedder self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber(WhisperTranscriber self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier(ZeroShotClassifier self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_String_Sse(RustStreamSink<String> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_download_progress_Sse(RustStreamSink<DownloadProgress> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_embedding_progress_Sse(RustStreamSink<EmbeddingProgress> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_job_status_Sse(RustStreamSink<JobStatus> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_log_entry_Sse(RustStreamSink<LogEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_audio_clip(AudioClip self, SseSerializer serializer);
+
+@protected void sse_encode_batch_options(BatchOptions self, SseSerializer serializer);
+
+@protected void sse_encode_benchmark_config(BenchmarkConfig self, SseSerializer serializer);
+
+@protected void sse_encode_benchmark_report(BenchmarkReport self, SseSerializer serializer);
+
+@protected void sse_encode_benchmark_result(BenchmarkResult self, SseSerializer serializer);
+
+@protected void sse_encode_bm_25_options(Bm25Options self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_batch_options(BatchOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_benchmark_config(BenchmarkConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bm_25_options(Bm25Options self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_calibration_params(CalibrationParams self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_chat_template(ChatTemplate self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_core_ml_compute_units(CoreMlComputeUnits self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_core_ml_model_format(CoreMlModelFormat self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_cutoff_options(CutoffOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_embed_many_options(EmbedManyOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_embed_options(EmbedOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_encode_output(EncodeOutput self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_generation_options(GenerationOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_hub_options(HubOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_installed_model(InstalledModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_int_8_embedding(Int8Embedding self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_job_status(JobStatus self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_log_level(LogLevel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_ort_environment_options(OrtEnvironmentOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_ort_init_options(OrtInitOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_ort_session_options(OrtSessionOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_padding_info(PaddingInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_preprocess_options(PreprocessOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_projection_options(ProjectionOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_qnn_performance_mode(QnnPerformanceMode self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_rgba_pixels(RgbaPixels self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_similarity_calibration(SimilarityCalibration self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_sparse_vector(SparseVector self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_stored_embedding(StoredEmbedding self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_token_embeddings(TokenEmbeddings self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_token_offsets(TokenOffsets self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_token_salience(TokenSalience self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_transcription_options(TranscriptionOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_truncation_info(TruncationInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_truncation_strategy(TruncationStrategy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vector_index_options(VectorIndexOptions self, SseSerializer serializer);
+
+@protected void sse_encode_calibration_method(CalibrationMethod self, SseSerializer serializer);
+
+@protected void sse_encode_calibration_params(CalibrationParams self, SseSerializer serializer);
+
+@protected void sse_encode_calibration_point(CalibrationPoint self, SseSerializer serializer);
+
+@protected void sse_encode_chat_template(ChatTemplate self, SseSerializer serializer);
+
+@protected void sse_encode_core_ml_compute_units(CoreMlComputeUnits self, SseSerializer serializer);
+
+@protected void sse_encode_core_ml_model_format(CoreMlModelFormat self, SseSerializer serializer);
+
+@protected void sse_encode_cutoff_options(CutoffOptions self, SseSerializer serializer);
+
+@protected void sse_encode_device_capabilities(DeviceCapabilities self, SseSerializer serializer);
+
+@protected void sse_encode_distance_metric(DistanceMetric self, SseSerializer serializer);
+
+@protected void sse_encode_download_progress(DownloadProgress self, SseSerializer serializer);
+
+@protected void sse_encode_embed_many_options(EmbedManyOptions self, SseSerializer serializer);
+
+@protected void sse_encode_embed_options(EmbedOptions self, SseSerializer serializer);
+
+@protected void sse_encode_embed_stats(EmbedStats self, SseSerializer serializer);
+
+@protected void sse_encode_embedder_comparison(EmbedderComparison self, SseSerializer serializer);
+
+@protected void sse_encode_embedder_kind(EmbedderKind self, SseSerializer serializer);
+
+@protected void sse_encode_embedding_progress(EmbeddingProgress self, SseSerializer serializer);
+
+@protected void sse_encode_embedding_set_stats(EmbeddingSetStats self, SseSerializer serializer);
+
+@protected void sse_encode_embeddings_with_stats(EmbeddingsWithStats self, SseSerializer serializer);
+
+@protected void sse_encode_encode_output(EncodeOutput self, SseSerializer serializer);
+
+@protected void sse_encode_error_kind(ErrorKind self, SseSerializer serializer);
+
+@protected void sse_encode_execution_provider_config(ExecutionProviderConfig self, SseSerializer serializer);
+
+@protected void sse_encode_execution_provider_kind(ExecutionProviderKind self, SseSerializer serializer);
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_finish_reason(FinishReason self, SseSerializer serializer);
+
+@protected void sse_encode_flutter_embedder_error(FlutterEmbedderError self, SseSerializer serializer);
+
+@protected void sse_encode_generation_options(GenerationOptions self, SseSerializer serializer);
+
+@protected void sse_encode_generation_result(GenerationResult self, SseSerializer serializer);
+
+@protected void sse_encode_hub_options(HubOptions self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_i_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_installed_model(InstalledModel self, SseSerializer serializer);
+
+@protected void sse_encode_int_8_embedding(Int8Embedding self, SseSerializer serializer);
+
+@protected void sse_encode_jina_task(JinaTask self, SseSerializer serializer);
+
+@protected void sse_encode_job_state(JobState self, SseSerializer serializer);
+
+@protected void sse_encode_job_status(JobStatus self, SseSerializer serializer);
+
+@protected void sse_encode_keyword_weight(KeywordWeight self, SseSerializer serializer);
+
+@protected void sse_encode_label_score(LabelScore self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_benchmark_result(List<BenchmarkResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_bool(List<bool> self, SseSerializer serializer);
+
+@protected void sse_encode_list_calibration_point(List<CalibrationPoint> self, SseSerializer serializer);
+
+@protected void sse_encode_list_encode_output(List<EncodeOutput> self, SseSerializer serializer);
+
+@protected void sse_encode_list_execution_provider_config(List<ExecutionProviderConfig> self, SseSerializer serializer);
+
+@protected void sse_encode_list_execution_provider_kind(List<ExecutionProviderKind> self, SseSerializer serializer);
+
+@protected void sse_encode_list_installed_model(List<InstalledModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_job_status(List<JobStatus> self, SseSerializer serializer);
+
+@protected void sse_encode_list_keyword_weight(List<KeywordWeight> self, SseSerializer serializer);
+
+@protected void sse_encode_list_label_score(List<LabelScore> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_label_score(List<List<LabelScore>> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_list_prim_f_32_strict(List<List<Float32List>> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_named_entity(List<List<NamedEntity>> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_prim_f_32_strict(List<Float32List> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_prim_u_32_strict(List<Uint32List> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_prim_u_8_strict(List<Uint8List> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_record_string_f_32(List<List<(String,double)>> self, SseSerializer serializer);
+
+@protected void sse_encode_list_named_entity(List<NamedEntity> self, SseSerializer serializer);
+
+@protected void sse_encode_list_named_tensor(List<NamedTensor> self, SseSerializer serializer);
+
+@protected void sse_encode_list_opt_box_autoadd_u_32(List<int?> self, SseSerializer serializer);
+
+@protected void sse_encode_list_passage_embedding(List<PassageEmbedding> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_f_32_loose(List<double> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_f_32_strict(Float32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_i_64_strict(Int64List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_i_8_strict(Int8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_32_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_opt_string_string(List<(String?,String)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_f_32(List<(String,double)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_u_32_f_32(List<(int,double)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_reference_case(List<ReferenceCase> self, SseSerializer serializer);
+
+@protected void sse_encode_list_reference_check(List<ReferenceCheck> self, SseSerializer serializer);
+
+@protected void sse_encode_list_rerank_result(List<RerankResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_rgba_pixels(List<RgbaPixels> self, SseSerializer serializer);
+
+@protected void sse_encode_list_search_hit(List<SearchHit> self, SseSerializer serializer);
+
+@protected void sse_encode_list_sparse_vector(List<SparseVector> self, SseSerializer serializer);
+
+@protected void sse_encode_list_store_hit(List<StoreHit> self, SseSerializer serializer);
+
+@protected void sse_encode_list_tensor_info(List<TensorInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_text_chunk(List<TextChunk> self, SseSerializer serializer);
+
+@protected void sse_encode_list_token_embeddings(List<TokenEmbeddings> self, SseSerializer serializer);
+
+@protected void sse_encode_list_token_offsets(List<TokenOffsets> self, SseSerializer serializer);
+
+@protected void sse_encode_list_token_salience(List<TokenSalience> self, SseSerializer serializer);
+
+@protected void sse_encode_list_vocab_entry(List<VocabEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_log_entry(LogEntry self, SseSerializer serializer);
+
+@protected void sse_encode_log_level(LogLevel self, SseSerializer serializer);
+
+@protected void sse_encode_model_info(ModelInfo self, SseSerializer serializer);
+
+@protected void sse_encode_model_verification(ModelVerification self, SseSerializer serializer);
+
+@protected void sse_encode_named_entity(NamedEntity self, SseSerializer serializer);
+
+@protected void sse_encode_named_tensor(NamedTensor self, SseSerializer serializer);
+
+@protected void sse_encode_nomic_task(NomicTask self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_benchmark_config(BenchmarkConfig? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_bm_25_options(Bm25Options? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_core_ml_compute_units(CoreMlComputeUnits? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_core_ml_model_format(CoreMlModelFormat? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_cutoff_options(CutoffOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_embed_many_options(EmbedManyOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_generation_options(GenerationOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_hub_options(HubOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_installed_model(InstalledModel? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_job_status(JobStatus? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_log_level(LogLevel? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_ort_environment_options(OrtEnvironmentOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_ort_init_options(OrtInitOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_ort_session_options(OrtSessionOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_padding_info(PaddingInfo? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_projection_options(ProjectionOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_qnn_performance_mode(QnnPerformanceMode? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_stored_embedding(StoredEmbedding? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_token_offsets(TokenOffsets? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_transcription_options(TranscriptionOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_truncation_info(TruncationInfo? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_truncation_strategy(TruncationStrategy? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_vector_index_options(VectorIndexOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_list_prim_f_32_strict(List<Float32List>? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_f_32_strict(Float32List? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_u_32_strict(Uint32List? self, SseSerializer serializer);
+
+@protected void sse_encode_ort_environment_options(OrtEnvironmentOptions self, SseSerializer serializer);
+
+@protected void sse_encode_ort_init_options(OrtInitOptions self, SseSerializer serializer);
+
+@protected void sse_encode_ort_session_options(OrtSessionOptions self, SseSerializer serializer);
+
+@protected void sse_encode_padded_batch(PaddedBatch self, SseSerializer serializer);
+
+@protected void sse_encode_padding_info(PaddingInfo self, SseSerializer serializer);
+
+@protected void sse_encode_padding_strategy(PaddingStrategy self, SseSerializer serializer);
+
+@protected void sse_encode_passage_embedding(PassageEmbedding self, SseSerializer serializer);
+
+@protected void sse_encode_preprocess_options(PreprocessOptions self, SseSerializer serializer);
+
+@protected void sse_encode_projection_options(ProjectionOptions self, SseSerializer serializer);
+
+@protected void sse_encode_projection_result(ProjectionResult self, SseSerializer serializer);
+
+@protected void sse_encode_qnn_performance_mode(QnnPerformanceMode self, SseSerializer serializer);
+
+@protected void sse_encode_record_opt_string_string((String?,String) self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_f_32((String,double) self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer);
+
+@protected void sse_encode_record_u_32_f_32((int,double) self, SseSerializer serializer);
+
+@protected void sse_encode_reference_case(ReferenceCase self, SseSerializer serializer);
+
+@protected void sse_encode_reference_check(ReferenceCheck self, SseSerializer serializer);
+
+@protected void sse_encode_rerank_result(RerankResult self, SseSerializer serializer);
+
+@protected void sse_encode_reranker_kind(RerankerKind self, SseSerializer serializer);
+
+@protected void sse_encode_resize_filter(ResizeFilter self, SseSerializer serializer);
+
+@protected void sse_encode_resize_mode(ResizeMode self, SseSerializer serializer);
+
+@protected void sse_encode_rgba_pixels(RgbaPixels self, SseSerializer serializer);
+
+@protected void sse_encode_score_normalization(ScoreNormalization self, SseSerializer serializer);
+
+@protected void sse_encode_search_hit(SearchHit self, SseSerializer serializer);
+
+@protected void sse_encode_similarity_calibration(SimilarityCalibration self, SseSerializer serializer);
+
+@protected void sse_encode_sparse_vector(SparseVector self, SseSerializer serializer);
+
+@protected void sse_encode_store_hit(StoreHit self, SseSerializer serializer);
+
+@protected void sse_encode_stored_embedding(StoredEmbedding self, SseSerializer serializer);
+
+@protected void sse_encode_tensor_dtype(TensorDtype self, SseSerializer serializer);
+
+@protected void sse_encode_tensor_info(TensorInfo self, SseSerializer serializer);
+
+@protected void sse_encode_tensor_layout(TensorLayout self, SseSerializer serializer);
+
+@protected void sse_encode_text_chunk(TextChunk self, SseSerializer serializer);
+
+@protected void sse_encode_token_embeddings(TokenEmbeddings self, SseSerializer serializer);
+
+@protected void sse_encode_token_offsets(TokenOffsets self, SseSerializer serializer);
+
+@protected void sse_encode_token_salience(TokenSalience self, SseSerializer serializer);
+
+@protected void sse_encode_tokenizer_info(TokenizerInfo self, SseSerializer serializer);
+
+@protected void sse_encode_transcription(Transcription self, SseSerializer serializer);
+
+@protected void sse_encode_transcription_options(TranscriptionOptions self, SseSerializer serializer);
+
+@protected void sse_encode_truncation_info(TruncationInfo self, SseSerializer serializer);
+
+@protected void sse_encode_truncation_strategy(TruncationStrategy self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_vector_index_options(VectorIndexOptions self, SseSerializer serializer);
+
+@protected void sse_encode_vision_preset(VisionPreset self, SseSerializer serializer);
+
+@protected void sse_encode_vocab_entry(VocabEntry self, SseSerializer serializer);
+
+@protected void sse_encode_workload(Workload self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void dco_decode_unit(dynamic raw);
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
-
-  @protected
-  BgeEmbedder
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  GemmaEmbedder
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  JinaV3Embedder
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  MiniLmEmbedder
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Qwen3Embedder
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Session
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  BgeEmbedder
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  GemmaEmbedder
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  JinaV3Embedder
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  MiniLmEmbedder
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Qwen3Embedder
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Array2F32
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Array2F32
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  BgeEmbedder
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  GemmaEmbedder
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  JinaV3Embedder
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  MiniLmEmbedder
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Qwen3Embedder
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Session
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
-
-  @protected
-  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
-
-  @protected
-  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
-
-  @protected
-  OrtEnvironmentOptions sse_decode_box_autoadd_ort_environment_options(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  OrtInitOptions sse_decode_box_autoadd_ort_init_options(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  OrtSessionOptions sse_decode_box_autoadd_ort_session_options(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  EncodeOutput sse_decode_encode_output(SseDeserializer deserializer);
-
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
-
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
-
-  @protected
-  List<EncodeOutput> sse_decode_list_encode_output(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  List<Float32List> sse_decode_list_list_prim_f_32_strict(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  List<Uint32List> sse_decode_list_list_prim_u_32_strict(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
-
-  @protected
-  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
-
-  @protected
-  List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer);
-
-  @protected
-  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
-
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
-
-  @protected
-  List<TokenOffsets> sse_decode_list_token_offsets(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
-
-  @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
-
-  @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
-
-  @protected
-  OrtEnvironmentOptions? sse_decode_opt_box_autoadd_ort_environment_options(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  OrtInitOptions? sse_decode_opt_box_autoadd_ort_init_options(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  OrtSessionOptions? sse_decode_opt_box_autoadd_ort_session_options(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  OrtEnvironmentOptions sse_decode_ort_environment_options(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  OrtInitOptions sse_decode_ort_init_options(SseDeserializer deserializer);
-
-  @protected
-  OrtSessionOptions sse_decode_ort_session_options(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  TokenOffsets sse_decode_token_offsets(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
-    BgeEmbedder self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
-    GemmaEmbedder self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
-    JinaV3Embedder self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
-    MiniLmEmbedder self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
-    Qwen3Embedder self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-    Session self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
-    BgeEmbedder self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
-    GemmaEmbedder self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
-    JinaV3Embedder self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
-    MiniLmEmbedder self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
-    Qwen3Embedder self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-    Array2F32 self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-    Array2F32 self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
-    BgeEmbedder self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
-    GemmaEmbedder self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
-    JinaV3Embedder self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
-    MiniLmEmbedder self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
-    Qwen3Embedder self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-    Session self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_i_64(
-    PlatformInt64 self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_ort_environment_options(
-    OrtEnvironmentOptions self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_ort_init_options(
-    OrtInitOptions self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_ort_session_options(
-    OrtSessionOptions self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_encode_output(EncodeOutput self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_encode_output(
-    List<EncodeOutput> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_list_prim_f_32_strict(
-    List<Float32List> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_list_prim_u_32_strict(
-    List<Uint32List> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_f_32_loose(
-    List<double> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_f_32_strict(
-    Float32List self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_u_32_loose(
-    List<int> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_u_32_strict(
-    Uint32List self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_token_offsets(
-    List<TokenOffsets> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_opt_box_autoadd_i_64(
-    PlatformInt64? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_box_autoadd_ort_environment_options(
-    OrtEnvironmentOptions? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_box_autoadd_ort_init_options(
-    OrtInitOptions? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_box_autoadd_ort_session_options(
-    OrtSessionOptions? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_ort_environment_options(
-    OrtEnvironmentOptions self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_ort_init_options(
-    OrtInitOptions self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_ort_session_options(
-    OrtSessionOptions self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_token_offsets(TokenOffsets self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
-
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-  _lookup;
-
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-    : _lookup = dynamicLibrary.lookup;
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32 =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32 =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedderPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedderPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedderPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedderPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedderPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedderPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedderPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedderPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3EmbedderPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3EmbedderPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3EmbedderPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3EmbedderPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedderPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedderPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedderPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedderPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3EmbedderPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3EmbedderPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3EmbedderPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3EmbedderPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession',
-      );
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-        'frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession',
-      );
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-}
+
+        class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+            /// Holds the symbol lookup function.
+            final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+                _lookup;
+  
+            /// The symbols are looked up in [dynamicLibrary].
+            RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+                : _lookup = dynamicLibrary.lookup;
+
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32 = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32 = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedder = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBgeEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25IndexPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25IndexPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25IndexPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25Index = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBm25IndexPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationTokenPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationTokenPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationTokenPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationTokenPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedder = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClapEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedder = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClipEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedder = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerColbertEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5EmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5EmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5EmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5Embedder = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerE5EmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgressPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgressPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgressPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgress = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedProgressPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedder = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCachePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCachePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCachePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCache = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingCachePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStorePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStorePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStorePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStore = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEmbeddingStorePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedder = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGemmaEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedder = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGenericEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3EmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3EmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3EmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3Embedder = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerJinaV3EmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedder = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMiniLmEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTaggerPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTaggerPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTaggerPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTagger = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNerTaggerPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedder = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNomicEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandlePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandlePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandlePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandle = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOrtSessionHandlePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3EmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3EmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3EmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3Embedder = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQwen3EmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRerankerPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRerankerPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRerankerPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReranker = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRerankerPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifierPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifierPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifierPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifier = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSequenceClassifierPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedder = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSpladeEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGeneratorPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGeneratorPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGeneratorPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGenerator = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTextGeneratorPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndexPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndexPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndexPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndex = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVectorIndexPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedderPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedder = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVisionEmbedderPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriberPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriberPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriberPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriber = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWhisperTranscriberPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifierPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifierPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifierPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_flutter_embedder_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifier = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerZeroShotClassifierPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+        }
+        
\ No newline at end of file
@@ -0,0 +1,336 @@
+//! Audio preprocessing for speech and audio models: WAV decoding,
+//! resampling and mel spectrograms are done in Rust, so Dart only has to
+//! hand over a file or PCM samples.
+
+pub mod whisper;
+
+use std::f32::consts::PI;
+
+use flutter_rust_bridge::frb;
+use rayon::prelude::*;
+
+use crate::api::error::{err, FlutterEmbedderError, Result};
+
+/// Taps on each side of an output sample in `resample_audio`.
+const RESAMPLE_HALF_TAPS: f32 = 16.0;
+
+/// Mono PCM audio with samples in `[-1, 1]`.
+#[derive(Debug, Clone)]
+pub struct AudioClip {
+    pub samples: Vec<f32>,
+    pub sample_rate: u32,
+}
+
+/// Reads a PCM (8/16/24/32-bit integer or 32-bit float) WAV file, mixing
+/// multiple channels down to mono.
+#[frb(sync)]
+pub fn read_wav(path: String) -> Result<AudioClip, FlutterEmbedderError> {
+    let bytes = std::fs::read(&path).map_err(|e| err!(Io, "Failed to read {path}: {e}"))?;
+    decode_wav(bytes)
+}
+
+/// `read_wav` from the bytes of a WAV file.
+#[frb(sync)]
+pub fn decode_wav(bytes: Vec<u8>) -> Result<AudioClip, FlutterEmbedderError> {
+    if bytes.len() < 12 || &bytes[..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Err(err!(InvalidInput, "Not a RIFF/WAVE file"));
+    }
+    let mut format = None;
+    let mut data = None;
+    let mut offset = 12;
+    while offset + 8 <= bytes.len() {
+        let id = &bytes[offset..offset + 4];
+        let size = u32::from_le_bytes(bytes[offset + 4..offset + 8].try_into().unwrap()) as usize;
+        let body = &bytes[offset + 8..(offset + 8 + size).min(bytes.len())];
+        match id {
+            b"fmt " if body.len() >= 16 => format = Some(WavFormat::parse(body)?),
+            b"data" => data = Some(body),
+            _ => {}
+        }
+        // Chunks are padded to an even size.
+        offset += 8 + size + (size & 1);
+    }
+    let format = format.ok_or(err!(InvalidInput, "WAV file has no fmt chunk"))?;
+    let data = data.ok_or(err!(InvalidInput, "WAV file has no data chunk"))?;
+
+    let width = format.bits as usize / 8;
+    let frame = width * format.channels as usize;
+    let samples = data
+        .chunks_exact(frame)
+        .map(|frame| {
+            let sum: f32 = frame.chunks_exact(width).map(|s| format.sample(s)).sum();
+            sum / format.channels as f32
+        })
+        .collect();
+    Ok(AudioClip {
+        samples,
+        sample_rate: format.sample_rate,
+    })
+}
+
+/// Resamples mono `samples` from `from_rate` to `to_rate` Hz with a
+/// windowed-sinc filter, which also removes frequencies above the new
+/// Nyquist limit when downsampling.
+#[frb(sync)]
+pub fn resample_audio(
+    samples: Vec<f32>,
+    from_rate: u32,
+    to_rate: u32,
+) -> Result<Vec<f32>, FlutterEmbedderError> {
+    resample(&samples, from_rate, to_rate)
+}
+
+struct WavFormat {
+    float: bool,
+    channels: u16,
+    sample_rate: u32,
+    bits: u16,
+}
+
+impl WavFormat {
+    fn parse(chunk: &[u8]) -> Result<Self> {
+        let u16_at = |i: usize| u16::from_le_bytes([chunk[i], chunk[i + 1]]);
+        let mut tag = u16_at(0);
+        // WAVE_FORMAT_EXTENSIBLE keeps the real format in its sub-format GUID.
+        if tag == 0xFFFE && chunk.len() >= 26 {
+            tag = u16_at(24);
+        }
+        let format = Self {
+            float: tag == 3,
+            channels: u16_at(2),
+            sample_rate: u32::from_le_bytes(chunk[4..8].try_into().unwrap()),
+            bits: u16_at(14),
+        };
+        let supported = match tag {
+            1 => matches!(format.bits, 8 | 16 | 24 | 32),
+            3 => format.bits == 32,
+            _ => false,
+        };
+        if !supported || format.channels == 0 || format.sample_rate == 0 {
+            return Err(err!(
+                InvalidInput,
+                "Unsupported WAV format {tag} with {} bits",
+                format.bits
+            ));
+        }
+        Ok(format)
+    }
+
+    fn sample(&self, bytes: &[u8]) -> f32 {
+        match (self.float, self.bits) {
+            (true, _) => f32::from_le_bytes(bytes.try_into().unwrap()),
+            (false, 8) => (bytes[0] as f32 - 128.0) / 128.0,
+            (false, 16) => i16::from_le_bytes([bytes[0], bytes[1]]) as f32 / 32768.0,
+            (false, 24) => {
+                (i32::from_le_bytes([0, bytes[0], bytes[1], bytes[2]]) >> 8) as f32 / 8388608.0
+            }
+            _ => i32::from_le_bytes(bytes.try_into().unwrap()) as f32 / 2147483648.0,
+        }
+    }
+}
+
+pub(crate) fn resample(samples: &[f32], from_rate: u32, to_rate: u32) -> Result<Vec<f32>> {
+    if from_rate == 0 || to_rate == 0 {
+        return Err(err!(InvalidInput, "Sample rates must be positive"));
+    }
+    if from_rate == to_rate || samples.is_empty() {
+        return Ok(samples.to_vec());
+    }
+    let ratio = to_rate as f64 / from_rate as f64;
+    let out_len = (samples.len() as f64 * ratio).round() as usize;
+    // Lowpass at the lower of the two Nyquist frequencies.
+    let cutoff = ratio.min(1.0) as f32;
+    let half_width = RESAMPLE_HALF_TAPS / cutoff;
+    Ok((0..out_len)
+        .into_par_iter()
+        .map(|i| {
+            let center = (i as f64 / ratio) as f32;
+            let first = (center - half_width).ceil().max(0.0) as usize;
+            let last = ((center + half_width).floor() as usize).min(samples.len() - 1);
+            let mut sum = 0.0;
+            let mut weights = 0.0;
+            for (j, &sample) in samples.iter().enumerate().take(last + 1).skip(first) {
+                let t = j as f32 - center;
+                let window = 0.5 + 0.5 * (PI * t / half_width).cos();
+                let weight = cutoff * sinc(cutoff * t) * window;
+                sum += weight * sample;
+                weights += weight;
+            }
+            if weights.abs() > f32::EPSILON {
+                sum / weights
+            } else {
+                0.0
+            }
+        })
+        .collect())
+}
+
+fn sinc(x: f32) -> f32 {
+    if x.abs() < 1e-6 {
+        1.0
+    } else {
+        (PI * x).sin() / (PI * x)
+    }
+}
+
+/// Frequency ↔ mel conversion used to space the mel filters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MelScale {
+    /// `2595 * log10(1 + hz / 700)`.
+    Htk,
+    /// Linear below 1 kHz and logarithmic above, as in librosa.
+    Slaney,
+}
+
+impl MelScale {
+    fn to_mel(self, hz: f32) -> f32 {
+        match self {
+            Self::Htk => 2595.0 * (1.0 + hz / 700.0).log10(),
+            Self::Slaney if hz >= 1000.0 => 15.0 + (hz / 1000.0).ln() / (6.4f32.ln() / 27.0),
+            Self::Slaney => hz * 3.0 / 200.0,
+        }
+    }
+
+    fn to_hz(self, mel: f32) -> f32 {
+        match self {
+            Self::Htk => 700.0 * (10f32.powf(mel / 2595.0) - 1.0),
+            Self::Slaney if mel >= 15.0 => 1000.0 * ((mel - 15.0) * (6.4f32.ln() / 27.0)).exp(),
+            Self::Slaney => mel * 200.0 / 3.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct MelConfig {
+    pub(crate) sample_rate: u32,
+    pub(crate) n_fft: usize,
+    pub(crate) hop_length: usize,
+    pub(crate) n_mels: usize,
+    pub(crate) f_min: f32,
+    pub(crate) f_max: f32,
+    pub(crate) scale: MelScale,
+    /// Scale each filter to unit area (librosa's `norm="slaney"`).
+    pub(crate) slaney_norm: bool,
+}
+
+/// Power mel spectrogram of centered (reflect-padded) Hann-windowed STFT
+/// frames, matching librosa and the Hugging Face feature extractors.
+pub(crate) struct MelSpectrogram {
+    config: MelConfig,
+    window: Vec<f32>,
+    cos: Vec<f32>,
+    sin: Vec<f32>,
+    /// `[n_mels][n_fft / 2 + 1]` filter weights.
+    filters: Vec<Vec<f32>>,
+}
+
+impl MelSpectrogram {
+    pub(crate) fn new(config: MelConfig) -> Self {
+        let n = config.n_fft;
+        let window = (0..n)
+            .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f32 / n as f32).cos())
+            .collect();
+        let angle = |i: usize| 2.0 * PI * i as f32 / n as f32;
+        let cos = (0..n).map(|i| angle(i).cos()).collect();
+        let sin = (0..n).map(|i| angle(i).sin()).collect();
+        let filters = mel_filters(&config);
+        Self {
+            config,
+            window,
+            cos,
+            sin,
+            filters,
+        }
+    }
+
+    pub(crate) fn n_mels(&self) -> usize {
+        self.config.n_mels
+    }
+
+    /// Mel power per `[frame][mel]` for `1 + samples.len() / hop_length`
+    /// frames.
+    pub(crate) fn compute(&self, samples: &[f32]) -> Vec<Vec<f32>> {
+        let n = self.config.n_fft;
+        let pad = n / 2;
+        let frames = 1 + samples.len() / self.config.hop_length;
+        let padded: Vec<f32> = (0..samples.len() + 2 * pad)
+            .map(|i| reflect(samples, i as isize - pad as isize))
+            .collect();
+        (0..frames)
+            .into_par_iter()
+            .map(|frame| {
+                let start = frame * self.config.hop_length;
+                let windowed: Vec<f32> = padded[start..start + n]
+                    .iter()
+                    .zip(&self.window)
+                    .map(|(x, w)| x * w)
+                    .collect();
+                let power: Vec<f32> = (0..n / 2 + 1)
+                    .map(|k| {
+                        let (mut re, mut im) = (0.0, 0.0);
+                        let mut index = 0;
+                        for &x in &windowed {
+                            re += x * self.cos[index];
+                            im -= x * self.sin[index];
+                            index += k;
+                            if index >= n {
+                                index -= n;
+                            }
+                        }
+                        re * re + im * im
+                    })
+                    .collect();
+                self.filters
+                    .iter()
+                    .map(|filter| filter.iter().zip(&power).map(|(f, p)| f * p).sum())
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// `samples[i]` with out-of-range indices reflected at the edges (without
+/// repeating the edge sample); zero when there is nothing to reflect.
+fn reflect(samples: &[f32], i: isize) -> f32 {
+    let len = samples.len() as isize;
+    if len < 2 {
+        return if i == 0 && len == 1 { samples[0] } else { 0.0 };
+    }
+    let period = 2 * (len - 1);
+    let mut i = i.rem_euclid(period);
+    if i >= len {
+        i = period - i;
+    }
+    samples[i as usize]
+}
+
+fn mel_filters(config: &MelConfig) -> Vec<Vec<f32>> {
+    let bins = config.n_fft / 2 + 1;
+    let nyquist = config.sample_rate as f32 / 2.0;
+    let fft_freqs: Vec<f32> = (0..bins)
+        .map(|i| i as f32 * nyquist / (bins - 1) as f32)
+        .collect();
+    let scale = config.scale;
+    let (mel_min, mel_max) = (scale.to_mel(config.f_min), scale.to_mel(config.f_max));
+    let edges: Vec<f32> = (0..config.n_mels + 2)
+        .map(|i| scale.to_hz(mel_min + (mel_max - mel_min) * i as f32 / (config.n_mels + 1) as f32))
+        .collect();
+    (0..config.n_mels)
+        .map(|m| {
+            let (low, center, high) = (edges[m], edges[m + 1], edges[m + 2]);
+            let norm = if config.slaney_norm {
+                2.0 / (high - low)
+            } else {
+                1.0
+            };
+            fft_freqs
+                .iter()
+                .map(|&f| {
+                    let rising = (f - low) / (center - low);
+                    let falling = (high - f) / (high - center);
+                    rising.min(falling).max(0.0) * norm
+                })
+                .collect()
+        })
+        .collect()
+}
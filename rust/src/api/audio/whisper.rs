@@ -0,0 +1,403 @@
+use flutter_rust_bridge::frb;
+use ort::session::Session;
+use ort::tensor::TensorElementType;
+use ort::value::{DynValue, Tensor, ValueType};
+use tokenizers::Tokenizer;
+
+use crate::api::audio::{resample, MelConfig, MelScale, MelSpectrogram};
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::generation::{argmax, decoder_step, supports_kv_cache};
+use crate::api::ort::{
+    build_session_from_file_with_init, build_session_from_memory_with_init, session_model_info,
+    ModelInfo, OrtInitOptions,
+};
+
+const SAMPLE_RATE: u32 = 16_000;
+/// Whisper always sees 30 s windows, padded with silence.
+const WINDOW_SAMPLES: usize = 30 * SAMPLE_RATE as usize;
+const WINDOW_FRAMES: usize = 3000;
+const N_FFT: usize = 400;
+const HOP_LENGTH: usize = 160;
+const DEFAULT_N_MELS: usize = 80;
+/// Decoder context length of all Whisper sizes.
+const MAX_TARGET_POSITIONS: usize = 448;
+
+const START_OF_TRANSCRIPT: &str = "<|startoftranscript|>";
+const TRANSCRIBE: &str = "<|transcribe|>";
+const TRANSLATE: &str = "<|translate|>";
+const NO_TIMESTAMPS: &str = "<|notimestamps|>";
+const END_OF_TEXT: &str = "<|endoftext|>";
+
+#[derive(Debug, Clone, Default)]
+pub struct TranscriptionOptions {
+    /// Spoken language as a Whisper code (`en`, `de`, ...). Detected from
+    /// the first 30 seconds when `None`.
+    pub language: Option<String>,
+    /// Translate the speech to English instead of transcribing it.
+    pub translate: bool,
+    /// Upper bound on tokens per 30 s window (default: the model's limit).
+    pub max_new_tokens: Option<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Transcription {
+    pub text: String,
+    /// The language code used for decoding, given or detected.
+    pub language: String,
+}
+
+/// Speech-to-text with a Whisper encoder/decoder ONNX export (Optimum's
+/// `encoder_model.onnx` and `decoder_model_merged.onnx`). Audio is split into
+/// 30 second windows which are decoded greedily and joined.
+#[frb(opaque)]
+pub struct WhisperTranscriber {
+    tokenizer: Tokenizer,
+    encoder: Session,
+    decoder: Session,
+    mel: MelSpectrogram,
+    kv_cache: bool,
+}
+
+#[frb(sync)]
+impl WhisperTranscriber {
+    pub fn create(
+        encoder_model_path: String,
+        decoder_model_path: String,
+        tokenizer_path: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_with_options(encoder_model_path, decoder_model_path, tokenizer_path, None)
+    }
+
+    pub fn create_with_options(
+        encoder_model_path: String,
+        decoder_model_path: String,
+        tokenizer_path: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer =
+            Tokenizer::from_file(tokenizer_path).map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let encoder = build_session_from_file_with_init(encoder_model_path, ort_options.clone())?;
+        let decoder = build_session_from_file_with_init(decoder_model_path, ort_options)?;
+        Self::from_parts(tokenizer, encoder, decoder)
+    }
+
+    /// `create` from in-memory model bytes and tokenizer JSON.
+    pub fn create_from_bytes(
+        encoder_model_bytes: Vec<u8>,
+        decoder_model_bytes: Vec<u8>,
+        tokenizer_json: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_bytes_with_options(
+            encoder_model_bytes,
+            decoder_model_bytes,
+            tokenizer_json,
+            None,
+        )
+    }
+
+    pub fn create_from_bytes_with_options(
+        encoder_model_bytes: Vec<u8>,
+        decoder_model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer =
+            Tokenizer::from_bytes(tokenizer_json).map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let encoder =
+            build_session_from_memory_with_init(&encoder_model_bytes, ort_options.clone())?;
+        let decoder = build_session_from_memory_with_init(&decoder_model_bytes, ort_options)?;
+        Self::from_parts(tokenizer, encoder, decoder)
+    }
+
+    fn from_parts(
+        tokenizer: Tokenizer,
+        encoder: Session,
+        decoder: Session,
+    ) -> Result<Self, FlutterEmbedderError> {
+        for token in [START_OF_TRANSCRIPT, TRANSCRIBE, NO_TIMESTAMPS, END_OF_TEXT] {
+            if tokenizer.token_to_id(token).is_none() {
+                return Err(err!(TokenizerLoad, "Not a Whisper tokenizer: no {token}"));
+            }
+        }
+        // large-v3 uses 128 mel bins, earlier sizes 80.
+        let n_mels = encoder
+            .inputs()
+            .iter()
+            .find(|input| input.name() == "input_features")
+            .and_then(|input| match input.dtype() {
+                ValueType::Tensor { shape, .. } => shape.get(1).copied(),
+                _ => None,
+            })
+            .filter(|&dim| dim > 0)
+            .map_or(DEFAULT_N_MELS, |dim| dim as usize);
+        let mel = MelSpectrogram::new(MelConfig {
+            sample_rate: SAMPLE_RATE,
+            n_fft: N_FFT,
+            hop_length: HOP_LENGTH,
+            n_mels,
+            f_min: 0.0,
+            f_max: SAMPLE_RATE as f32 / 2.0,
+            scale: MelScale::Slaney,
+            slaney_norm: true,
+        });
+        let kv_cache = supports_kv_cache(&decoder);
+        Ok(Self {
+            tokenizer,
+            encoder,
+            decoder,
+            mel,
+            kv_cache,
+        })
+    }
+
+    pub fn encoder_model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&self.encoder)
+    }
+
+    pub fn decoder_model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&self.decoder)
+    }
+
+    /// Language codes the model can transcribe, e.g. `en`, `de`, `zh`.
+    pub fn languages(&self) -> Vec<String> {
+        self.language_tokens()
+            .into_iter()
+            .map(|(code, _)| code)
+            .collect()
+    }
+
+    /// Transcribes mono PCM `samples` recorded at `sample_rate` Hz.
+    pub fn transcribe(
+        &mut self,
+        samples: Vec<f32>,
+        sample_rate: u32,
+        options: Option<TranscriptionOptions>,
+    ) -> Result<Transcription, FlutterEmbedderError> {
+        let options = options.unwrap_or_default();
+        let samples = resample(&samples, sample_rate, SAMPLE_RATE)?;
+        if samples.is_empty() {
+            return Err(err!(InvalidInput, "No audio samples"));
+        }
+
+        let mut language = options.language.clone();
+        let mut texts = Vec::new();
+        for window in samples.chunks(WINDOW_SAMPLES) {
+            let encoded = self.encode(window)?;
+            let language = match &language {
+                Some(code) => code.clone(),
+                None => language.insert(self.detect_language(&encoded)?).clone(),
+            };
+            let text = self.decode(&encoded, &language, &options)?;
+            let text = text.trim();
+            if !text.is_empty() {
+                texts.push(text.to_string());
+            }
+        }
+        Ok(Transcription {
+            text: texts.join(" "),
+            language: language.unwrap_or_default(),
+        })
+    }
+}
+
+impl WhisperTranscriber {
+    /// Transcribes one second of silence so the first real `transcribe`
+    /// doesn't pay for graph initialization.
+    pub fn warmup(&mut self) -> Result<(), FlutterEmbedderError> {
+        self.transcribe(
+            vec![0.0; SAMPLE_RATE as usize],
+            SAMPLE_RATE,
+            Some(TranscriptionOptions {
+                language: Some("en".to_string()),
+                max_new_tokens: Some(1),
+                ..Default::default()
+            }),
+        )?;
+        Ok(())
+    }
+
+    /// Same as `transcribe`, but not `sync` on the Dart side.
+    pub fn transcribe_async(
+        &mut self,
+        samples: Vec<f32>,
+        sample_rate: u32,
+        options: Option<TranscriptionOptions>,
+    ) -> Result<Transcription, FlutterEmbedderError> {
+        self.transcribe(samples, sample_rate, options)
+    }
+
+    /// Log-mel features of a window, run through the encoder.
+    fn encode(&mut self, window: &[f32]) -> Result<EncoderOutput> {
+        let mut padded = window.to_vec();
+        padded.resize(WINDOW_SAMPLES, 0.0);
+        let mut frames = self.mel.compute(&padded);
+        frames.truncate(WINDOW_FRAMES);
+
+        // Whisper's log-mel: log10, clamped to 8 below the peak, rescaled.
+        let n_mels = self.mel.n_mels();
+        let mut features = vec![0.0f32; n_mels * WINDOW_FRAMES];
+        for (t, frame) in frames.iter().enumerate() {
+            for (m, &power) in frame.iter().enumerate() {
+                features[m * WINDOW_FRAMES + t] = power.max(1e-10).log10();
+            }
+        }
+        let peak = features.iter().copied().fold(f32::MIN, f32::max);
+        for value in &mut features {
+            *value = (value.max(peak - 8.0) + 4.0) / 4.0;
+        }
+
+        let input = self
+            .encoder
+            .inputs()
+            .first()
+            .ok_or(err!(ModelLoad, "Encoder has no inputs"))?;
+        let name = input.name().to_string();
+        let tensor = float_tensor(input.dtype(), vec![1, n_mels, WINDOW_FRAMES], features)?;
+        let outputs = self.encoder.run(vec![(name, tensor)])?;
+        let hidden = match outputs.get("last_hidden_state") {
+            Some(hidden) => hidden,
+            None => &outputs[0],
+        };
+        let (shape, data) = match hidden.try_extract_tensor::<f32>() {
+            Ok((shape, data)) => (shape.clone(), data.to_vec()),
+            Err(_) => {
+                let (shape, data) = hidden.try_extract_tensor::<half::f16>()?;
+                (shape.clone(), data.iter().map(|x| x.to_f32()).collect())
+            }
+        };
+        Ok(EncoderOutput {
+            shape: shape.iter().map(|&d| d as usize).collect(),
+            data,
+        })
+    }
+
+    /// The language whose token the decoder finds most likely right after
+    /// `<|startoftranscript|>`.
+    fn detect_language(&mut self, encoded: &EncoderOutput) -> Result<String> {
+        let languages = self.language_tokens();
+        let start = self.token_id(START_OF_TRANSCRIPT)?;
+        let logits = decoder_step(
+            &mut self.decoder,
+            false,
+            &[start],
+            0,
+            &mut Vec::new(),
+            |name, dtype| encoded.feed(name, dtype),
+        )?;
+        languages
+            .into_iter()
+            .filter(|&(_, id)| (id as usize) < logits.len())
+            .max_by(|a, b| logits[a.1 as usize].total_cmp(&logits[b.1 as usize]))
+            .map(|(code, _)| code)
+            .ok_or(err!(TokenizerLoad, "Tokenizer has no language tokens"))
+    }
+
+    fn decode(
+        &mut self,
+        encoded: &EncoderOutput,
+        language: &str,
+        options: &TranscriptionOptions,
+    ) -> Result<String> {
+        let language_token = self
+            .tokenizer
+            .token_to_id(&format!("<|{language}|>"))
+            .ok_or(err!(InvalidInput, "Unsupported language {language:?}"))?;
+        let task = if options.translate {
+            TRANSLATE
+        } else {
+            TRANSCRIBE
+        };
+        let mut tokens = vec![
+            self.token_id(START_OF_TRANSCRIPT)?,
+            language_token,
+            self.token_id(task)?,
+            self.token_id(NO_TIMESTAMPS)?,
+        ];
+        let prompt_len = tokens.len();
+        let end_of_text = self.token_id(END_OF_TEXT)?;
+        let limit = MAX_TARGET_POSITIONS - prompt_len;
+        let max_new_tokens = options
+            .max_new_tokens
+            .map_or(limit, |n| (n as usize).min(limit));
+
+        let mut cache = Vec::new();
+        let mut cached = 0;
+        for _ in 0..max_new_tokens {
+            let logits = decoder_step(
+                &mut self.decoder,
+                self.kv_cache,
+                &tokens,
+                cached,
+                &mut cache,
+                |name, dtype| encoded.feed(name, dtype),
+            )?;
+            if self.kv_cache {
+                cached = tokens.len();
+            }
+            let token = argmax(&logits);
+            if token == end_of_text {
+                break;
+            }
+            tokens.push(token);
+        }
+        self.tokenizer
+            .decode(&tokens[prompt_len..], true)
+            .map_err(|e| err!(Tokenization, "{e}"))
+    }
+
+    fn token_id(&self, token: &str) -> Result<u32> {
+        self.tokenizer
+            .token_to_id(token)
+            .ok_or(err!(TokenizerLoad, "Tokenizer has no {token} token"))
+    }
+
+    /// `(code, token id)` of every `<|xx|>` language token, by id.
+    fn language_tokens(&self) -> Vec<(String, u32)> {
+        let mut languages: Vec<(String, u32)> = self
+            .tokenizer
+            .get_vocab(true)
+            .into_iter()
+            .filter_map(|(token, id)| {
+                let code = token.strip_prefix("<|")?.strip_suffix("|>")?;
+                let is_language =
+                    (2..=3).contains(&code.len()) && code.chars().all(|c| c.is_ascii_lowercase());
+                is_language.then(|| (code.to_string(), id))
+            })
+            .collect();
+        languages.sort_by_key(|&(_, id)| id);
+        languages
+    }
+}
+
+/// The encoder's `[1, frames, hidden]` output, fed to every decoder step.
+struct EncoderOutput {
+    shape: Vec<usize>,
+    data: Vec<f32>,
+}
+
+impl EncoderOutput {
+    fn feed(&self, name: &str, dtype: &ValueType) -> Result<Option<DynValue>> {
+        if name != "encoder_hidden_states" {
+            return Ok(None);
+        }
+        Ok(Some(float_tensor(
+            dtype,
+            self.shape.clone(),
+            self.data.clone(),
+        )?))
+    }
+}
+
+/// An f32 or f16 tensor, whichever the model input expects.
+fn float_tensor(dtype: &ValueType, shape: Vec<usize>, data: Vec<f32>) -> Result<DynValue> {
+    match dtype {
+        ValueType::Tensor {
+            ty: TensorElementType::Float16,
+            ..
+        } => {
+            let data: Vec<half::f16> = data.into_iter().map(half::f16::from_f32).collect();
+            Ok(Tensor::from_array((shape, data))?.into_dyn())
+        }
+        _ => Ok(Tensor::from_array((shape, data))?.into_dyn()),
+    }
+}
@@ -0,0 +1,191 @@
+//! In-app latency and throughput benchmark, for picking a model and batch
+//! size per device tier.
+
+use std::time::Instant;
+
+use crate::api::embeddings::{create_embedder, peak_memory_bytes, EmbedderKind};
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::ort::{device_capabilities, DeviceCapabilities, OrtInitOptions};
+
+const DEFAULT_BATCH_SIZES: [u32; 3] = [1, 8, 32];
+const DEFAULT_SEQUENCE_LENGTHS: [u32; 3] = [16, 128, 512];
+const DEFAULT_ITERATIONS: u32 = 10;
+const DEFAULT_WARMUP_ITERATIONS: u32 = 2;
+/// Words the inputs are built from; one token each in the common
+/// vocabularies. Texts in a batch differ in their first words, since
+/// duplicates would only be embedded once.
+const FILLER_WORDS: [&str; 16] = [
+    "hello", "world", "house", "water", "music", "paper", "table", "light", "river", "green",
+    "happy", "money", "night", "stone", "bread", "chair",
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct BenchmarkConfig {
+    /// Texts per `embed` call (default 1, 8 and 32).
+    pub batch_sizes: Vec<u32>,
+    /// Tokens per text, special tokens excluded (default 16, 128 and 512).
+    /// Lengths above the model's limit are truncated by the tokenizer.
+    pub sequence_lengths: Vec<u32>,
+    /// Timed calls per combination (default 10).
+    pub iterations: Option<u32>,
+    /// Untimed calls before the first combination (default 2).
+    pub warmup_iterations: Option<u32>,
+    pub ort_options: Option<OrtInitOptions>,
+}
+
+/// Timings for one batch size and sequence length.
+#[derive(Debug, Clone)]
+pub struct BenchmarkResult {
+    pub batch_size: u32,
+    pub sequence_length: u32,
+    /// Tokens per text the model actually saw, e.g. after truncation.
+    pub tokens_per_text: u32,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub mean_ms: f64,
+    pub tokens_per_second: f64,
+    pub texts_per_second: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchmarkReport {
+    pub kind: EmbedderKind,
+    pub device: DeviceCapabilities,
+    /// Time to build the session and load the tokenizer.
+    pub load_ms: f64,
+    pub dimension: u32,
+    /// One entry per batch size and sequence length, batch sizes outermost.
+    pub results: Vec<BenchmarkResult>,
+    /// Peak resident memory of the process after the run; `None` where the
+    /// OS doesn't report it.
+    pub peak_memory_bytes: Option<u64>,
+}
+
+/// Loads the model, then times `embed` for every combination of
+/// `config.batch_sizes` and `config.sequence_lengths`. Runs for a while on
+/// slow devices; keep it off the UI thread.
+pub fn run_benchmark(
+    kind: EmbedderKind,
+    model_path: String,
+    tokenizer_path: String,
+    config: Option<BenchmarkConfig>,
+) -> Result<BenchmarkReport, FlutterEmbedderError> {
+    let config = config.unwrap_or_default();
+    let batch_sizes = or_default(config.batch_sizes, &DEFAULT_BATCH_SIZES);
+    let sequence_lengths = or_default(config.sequence_lengths, &DEFAULT_SEQUENCE_LENGTHS);
+    if batch_sizes.contains(&0) || sequence_lengths.contains(&0) {
+        return Err(err!(
+            InvalidInput,
+            "Batch sizes and sequence lengths must be at least 1"
+        ));
+    }
+    let iterations = config.iterations.unwrap_or(DEFAULT_ITERATIONS).max(1);
+    let warmup = config
+        .warmup_iterations
+        .unwrap_or(DEFAULT_WARMUP_ITERATIONS);
+
+    let tokenizer = tokenizers::Tokenizer::from_file(&tokenizer_path)
+        .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+    let started = Instant::now();
+    let embedder = create_embedder(kind, model_path, tokenizer_path, config.ort_options)?;
+    let load_ms = started.elapsed().as_secs_f64() * 1000.0;
+    for _ in 0..warmup {
+        embedder.warmup()?;
+    }
+    let dimension = embedder.dimension()? as u32;
+
+    let mut results = Vec::with_capacity(batch_sizes.len() * sequence_lengths.len());
+    for &batch_size in &batch_sizes {
+        for &sequence_length in &sequence_lengths {
+            let texts = (0..batch_size as usize)
+                .map(|i| text_of_tokens(&tokenizer, sequence_length as usize, i))
+                .collect::<Result<Vec<_>>>()?;
+            let mut latencies = Vec::with_capacity(iterations as usize);
+            let mut tokens = 0;
+            for _ in 0..iterations {
+                let started = Instant::now();
+                let output = embedder.embed_with_stats(texts.clone())?;
+                latencies.push(started.elapsed().as_secs_f64() * 1000.0);
+                tokens = output.stats.tokens;
+            }
+            results.push(summarize(batch_size, sequence_length, tokens, latencies));
+        }
+    }
+    Ok(BenchmarkReport {
+        kind,
+        device: device_capabilities(),
+        load_ms,
+        dimension,
+        results,
+        peak_memory_bytes: peak_memory_bytes(),
+    })
+}
+
+fn or_default(values: Vec<u32>, default: &[u32]) -> Vec<u32> {
+    if values.is_empty() {
+        default.to_vec()
+    } else {
+        values
+    }
+}
+
+/// Filler text cut to exactly `tokens` tokens of `tokenizer` (fewer only
+/// if a word splits oddly at the end). The first words spell `index` in
+/// base 16, so texts differ wherever the length allows.
+fn text_of_tokens(
+    tokenizer: &tokenizers::Tokenizer,
+    tokens: usize,
+    index: usize,
+) -> Result<String> {
+    let mut rest = index;
+    let words: Vec<&str> = (0..tokens)
+        .map(|_| {
+            let word = FILLER_WORDS[rest % FILLER_WORDS.len()];
+            rest /= FILLER_WORDS.len();
+            word
+        })
+        .collect();
+    let text = words.join(" ");
+    let encoding = tokenizer
+        .encode(text.as_str(), false)
+        .map_err(|e| err!(Tokenization, "{e}"))?;
+    let end = encoding
+        .get_offsets()
+        .get(tokens - 1)
+        .map_or(text.len(), |&(_, end)| end);
+    Ok(text[..end].to_string())
+}
+
+/// `tokens` is the model input of one call, i.e. for the whole batch.
+fn summarize(
+    batch_size: u32,
+    sequence_length: u32,
+    tokens: u64,
+    mut latencies: Vec<f64>,
+) -> BenchmarkResult {
+    latencies.sort_by(f64::total_cmp);
+    let mean_ms = latencies.iter().sum::<f64>() / latencies.len() as f64;
+    let per_second = |count: f64| {
+        if mean_ms > 0.0 {
+            count * 1000.0 / mean_ms
+        } else {
+            0.0
+        }
+    };
+    BenchmarkResult {
+        batch_size,
+        sequence_length,
+        tokens_per_text: (tokens / batch_size as u64) as u32,
+        p50_ms: percentile(&latencies, 0.5),
+        p95_ms: percentile(&latencies, 0.95),
+        mean_ms,
+        tokens_per_second: per_second(tokens as f64),
+        texts_per_second: per_second(batch_size as f64),
+    }
+}
+
+/// Nearest-rank percentile of ascending `sorted`.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
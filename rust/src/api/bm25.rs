@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use flutter_rust_bridge::frb;
+
+use crate::api::chunking::unbounded;
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::keywords::word_spans;
+use crate::api::search::SearchHit;
+use crate::api::tokenizer::with_tokenizer;
+
+const DEFAULT_K1: f32 = 1.2;
+const DEFAULT_B: f32 = 0.75;
+
+#[derive(Debug, Clone, Default)]
+pub struct Bm25Options {
+    /// Term-frequency saturation (default 1.2).
+    pub k1: Option<f32>,
+    /// Document-length normalization, 0 to 1 (default 0.75).
+    pub b: Option<f32>,
+    /// Analyze text into this tokenizer's tokens instead of lowercased
+    /// words, so lexical and dense retrieval see the same units.
+    pub tokenizer_id: Option<u64>,
+}
+
+/// In-memory inverted index ranking documents with Okapi BM25, for exact
+/// keyword and identifier matches that dense retrieval misses.
+#[frb(opaque)]
+pub struct Bm25Index {
+    k1: f32,
+    b: f32,
+    tokenizer_id: Option<u64>,
+    /// Slot per added document; removed documents leave `None`.
+    docs: Vec<Option<Document>>,
+    ids: HashMap<String, u32>,
+    /// Term → (document slot, term frequency).
+    postings: HashMap<String, Vec<(u32, u32)>>,
+    total_len: u64,
+}
+
+struct Document {
+    id: String,
+    len: u32,
+    terms: Vec<String>,
+}
+
+#[frb(sync)]
+impl Bm25Index {
+    pub fn new(options: Option<Bm25Options>) -> Result<Self, FlutterEmbedderError> {
+        let options = options.unwrap_or_default();
+        let k1 = options.k1.unwrap_or(DEFAULT_K1);
+        let b = options.b.unwrap_or(DEFAULT_B);
+        if k1 < 0.0 || !(0.0..=1.0).contains(&b) {
+            return Err(err!(InvalidInput, "k1 must be >= 0 and b within [0, 1]"));
+        }
+        Ok(Self {
+            k1,
+            b,
+            tokenizer_id: options.tokenizer_id,
+            docs: Vec::new(),
+            ids: HashMap::new(),
+            postings: HashMap::new(),
+            total_len: 0,
+        })
+    }
+
+    /// Number of indexed documents.
+    pub fn len(&self) -> u32 {
+        self.ids.len() as u32
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn contains(&self, id: String) -> bool {
+        self.ids.contains_key(&id)
+    }
+
+    /// Indexes `text` under `id`, replacing any document already stored
+    /// for it.
+    pub fn add(&mut self, id: String, text: String) -> Result<(), FlutterEmbedderError> {
+        let terms = self.analyze(&text)?;
+        self.remove(id.clone());
+
+        let slot = self.docs.len() as u32;
+        let mut frequencies: HashMap<String, u32> = HashMap::new();
+        for term in &terms {
+            *frequencies.entry(term.clone()).or_default() += 1;
+        }
+        for (term, frequency) in &frequencies {
+            self.postings
+                .entry(term.clone())
+                .or_default()
+                .push((slot, *frequency));
+        }
+        self.total_len += terms.len() as u64;
+        self.docs.push(Some(Document {
+            id: id.clone(),
+            len: terms.len() as u32,
+            terms: frequencies.into_keys().collect(),
+        }));
+        self.ids.insert(id, slot);
+        Ok(())
+    }
+
+    /// Indexes `texts[i]` under `ids[i]` for every pair.
+    pub fn add_many(
+        &mut self,
+        ids: Vec<String>,
+        texts: Vec<String>,
+    ) -> Result<(), FlutterEmbedderError> {
+        if ids.len() != texts.len() {
+            return Err(err!(
+                InvalidInput,
+                "ids and texts must have the same length"
+            ));
+        }
+        for (id, text) in ids.into_iter().zip(texts) {
+            self.add(id, text)?;
+        }
+        Ok(())
+    }
+
+    /// Removes `id`; returns whether it was present.
+    pub fn remove(&mut self, id: String) -> bool {
+        let Some(slot) = self.ids.remove(&id) else {
+            return false;
+        };
+        if let Some(doc) = self.docs[slot as usize].take() {
+            self.total_len -= doc.len as u64;
+            for term in doc.terms {
+                if let Some(postings) = self.postings.get_mut(&term) {
+                    postings.retain(|&(s, _)| s != slot);
+                    if postings.is_empty() {
+                        self.postings.remove(&term);
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// The `k` documents scoring highest for `query`, best first. Documents
+    /// sharing no term with the query are not returned.
+    pub fn search(&self, query: String, k: u32) -> Result<Vec<SearchHit>, FlutterEmbedderError> {
+        let mut terms = self.analyze(&query)?;
+        terms.sort();
+        terms.dedup();
+        let n = self.ids.len() as f32;
+        if n == 0.0 {
+            return Ok(Vec::new());
+        }
+        let avg_len = self.total_len as f32 / n;
+
+        let mut scores: HashMap<u32, f32> = HashMap::new();
+        for term in &terms {
+            let Some(postings) = self.postings.get(term) else {
+                continue;
+            };
+            let df = postings.len() as f32;
+            let idf = (1.0 + (n - df + 0.5) / (df + 0.5)).ln();
+            for &(slot, tf) in postings {
+                let len = self.docs[slot as usize].as_ref().map_or(0, |d| d.len) as f32;
+                let tf = tf as f32;
+                let norm = self.k1 * (1.0 - self.b + self.b * len / avg_len.max(1e-9));
+                *scores.entry(slot).or_default() += idf * tf * (self.k1 + 1.0) / (tf + norm);
+            }
+        }
+
+        let mut hits: Vec<SearchHit> = scores
+            .into_iter()
+            .filter_map(|(slot, score)| {
+                let doc = self.docs[slot as usize].as_ref()?;
+                Some(SearchHit {
+                    id: doc.id.clone(),
+                    score,
+                })
+            })
+            .collect();
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+        hits.truncate(k as usize);
+        Ok(hits)
+    }
+}
+
+impl Bm25Index {
+    fn analyze(&self, text: &str) -> Result<Vec<String>> {
+        match self.tokenizer_id {
+            Some(id) => with_tokenizer(id, |tokenizer| {
+                let encoding = unbounded(tokenizer)?
+                    .encode(text, false)
+                    .map_err(|err| err!(Tokenization, "Encode failed: {err}"))?;
+                Ok(encoding.get_tokens().to_vec())
+            }),
+            None => Ok(word_spans(text)
+                .into_iter()
+                .map(|(start, end)| text[start..end].to_lowercase())
+                .collect()),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use flutter_rust_bridge::frb;
+use sha2::{Digest, Sha256};
+
+use crate::api::embeddings::Embedder;
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::models::to_hex;
+use crate::api::store::EmbeddingStore;
+
+const CACHE_EXTENSION: &str = "cache";
+
+/// Disk-backed text → embedding cache that survives app restarts.
+///
+/// Vectors are keyed by the SHA-256 of the text and kept in an
+/// `EmbeddingStore` file in `dir` named after `model_tag`, e.g. the model
+/// name plus revision or the `sha256_file` of the model. Opening the cache
+/// with a new tag deletes the files of every other tag, so vectors from a
+/// previous model are never returned; keep `dir` for the cache alone.
+#[frb(opaque)]
+pub struct EmbeddingCache {
+    store: EmbeddingStore,
+    model_tag: String,
+}
+
+#[frb(sync)]
+impl EmbeddingCache {
+    pub fn open(dir: String, model_tag: String) -> Result<Self, FlutterEmbedderError> {
+        let dir = PathBuf::from(dir);
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| err!(Io, "Failed to create {}: {e}", dir.display()))?;
+        let name = &sha256_hex(&model_tag)[..16];
+        let path = dir.join(format!("{name}.{CACHE_EXTENSION}"));
+        remove_other_caches(&dir, &path)?;
+        let store = EmbeddingStore::open(path.to_string_lossy().into_owned())?;
+        Ok(Self { store, model_tag })
+    }
+
+    pub fn model_tag(&self) -> String {
+        self.model_tag.clone()
+    }
+
+    /// Number of cached vectors.
+    pub fn len(&self) -> u32 {
+        self.store.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.store.is_empty()
+    }
+
+    pub fn get(&self, text: String) -> Option<Vec<f32>> {
+        self.store.get(sha256_hex(&text)).map(|entry| entry.vector)
+    }
+
+    pub fn put(&mut self, text: String, vector: Vec<f32>) -> Result<(), FlutterEmbedderError> {
+        self.store.upsert(sha256_hex(&text), vector, None)
+    }
+
+    /// Rewrites the cache file without overwritten entries.
+    pub fn compact(&mut self) -> Result<(), FlutterEmbedderError> {
+        self.store.compact()
+    }
+}
+
+impl EmbeddingCache {
+    /// `embedder.embed(texts)`, running the model only on texts that are not
+    /// cached yet (in one batch) and caching their vectors with one write.
+    pub fn embed_cached(
+        &mut self,
+        embedder: &Embedder,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        let keys: Vec<String> = texts.iter().map(|text| sha256_hex(text)).collect();
+        let mut seen = HashSet::new();
+        let (missing_keys, missing_texts): (Vec<String>, Vec<String>) = keys
+            .iter()
+            .zip(&texts)
+            .filter(|(key, _)| !self.store.contains(key.to_string()) && seen.insert(*key))
+            .map(|(key, text)| (key.clone(), text.clone()))
+            .unzip();
+        if !missing_texts.is_empty() {
+            let vectors = embedder.embed(missing_texts)?;
+            self.store
+                .upsert_batch(missing_keys.into_iter().zip(vectors).collect())?;
+        }
+        keys.into_iter()
+            .map(|key| {
+                self.store
+                    .get(key)
+                    .map(|entry| entry.vector)
+                    .ok_or(err!(MissingOutput, "Model produced no embedding"))
+            })
+            .collect()
+    }
+}
+
+fn sha256_hex(text: &str) -> String {
+    to_hex(&Sha256::digest(text.as_bytes()))
+}
+
+/// Deletes the cache files of other model tags in `dir`.
+fn remove_other_caches(dir: &Path, keep: &Path) -> Result<()> {
+    let entries =
+        std::fs::read_dir(dir).map_err(|e| err!(Io, "Failed to read {}: {e}", dir.display()))?;
+    for entry in entries {
+        let path = entry?.path();
+        if path != keep && path.extension().is_some_and(|ext| ext == CACHE_EXTENSION) {
+            std::fs::remove_file(&path)
+                .map_err(|e| err!(Io, "Failed to remove {}: {e}", path.display()))?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,358 @@
+use crate::api::error::{err, FlutterEmbedderError, Result};
+
+const DEFAULT_FIT_ITERATIONS: usize = 100;
+
+/// Platt-style calibration: `p = sigmoid(scale * score + bias)`.
+#[derive(Debug, Clone, Copy)]
+pub struct CalibrationParams {
+    pub scale: f32,
+    pub bias: f32,
+}
+
+impl Default for CalibrationParams {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            bias: 0.0,
+        }
+    }
+}
+
+/// Maps raw cross-encoder logits to 0..1 with `sigmoid(logit / temperature)`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn sigmoid_calibrate(
+    logits: Vec<f32>,
+    temperature: Option<f32>,
+) -> Result<Vec<f32>, FlutterEmbedderError> {
+    let temperature = check_temperature(temperature)?;
+    Ok(logits.iter().map(|&l| sigmoid(l / temperature)).collect())
+}
+
+/// Softmax over a candidate list; higher temperatures flatten the distribution.
+#[flutter_rust_bridge::frb(sync)]
+pub fn softmax_with_temperature(
+    logits: Vec<f32>,
+    temperature: Option<f32>,
+) -> Result<Vec<f32>, FlutterEmbedderError> {
+    let temperature = check_temperature(temperature)?;
+    Ok(softmax(&logits, temperature))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn apply_calibration(scores: Vec<f32>, params: CalibrationParams) -> Vec<f32> {
+    scores
+        .iter()
+        .map(|&s| sigmoid(params.scale * s + params.bias))
+        .collect()
+}
+
+/// Fits Platt scaling parameters from labelled (score, relevant) pairs using
+/// Newton's method on the regularised log-loss.
+#[flutter_rust_bridge::frb(sync)]
+pub fn fit_calibration(
+    scores: Vec<f32>,
+    labels: Vec<bool>,
+) -> Result<CalibrationParams, FlutterEmbedderError> {
+    if scores.len() != labels.len() {
+        return Err(err!(
+            InvalidInput,
+            "Scores and labels must have the same length"
+        ));
+    }
+    let positives = labels.iter().filter(|&&l| l).count();
+    let negatives = labels.len() - positives;
+    if positives == 0 || negatives == 0 {
+        return Err(err!(
+            InvalidInput,
+            "Calibration needs at least one positive and one negative label"
+        ));
+    }
+
+    // Platt's smoothed targets avoid overfitting on small label sets.
+    let hi = (positives as f64 + 1.0) / (positives as f64 + 2.0);
+    let lo = 1.0 / (negatives as f64 + 2.0);
+    let targets: Vec<f64> = labels.iter().map(|&l| if l { hi } else { lo }).collect();
+
+    let mut a = 1.0f64;
+    let mut b = ((positives as f64 + 1.0) / (negatives as f64 + 1.0)).ln();
+    for _ in 0..DEFAULT_FIT_ITERATIONS {
+        let (mut g_a, mut g_b) = (0.0f64, 0.0f64);
+        let (mut h_aa, mut h_ab, mut h_bb) = (1e-12f64, 0.0f64, 1e-12f64);
+        for (&s, &t) in scores.iter().zip(targets.iter()) {
+            let s = s as f64;
+            let p = 1.0 / (1.0 + (-(a * s + b)).exp());
+            let d = p - t;
+            let w = p * (1.0 - p);
+            g_a += d * s;
+            g_b += d;
+            h_aa += w * s * s;
+            h_ab += w * s;
+            h_bb += w;
+        }
+        let det = h_aa * h_bb - h_ab * h_ab;
+        if det.abs() < 1e-18 {
+            break;
+        }
+        let step_a = (h_bb * g_a - h_ab * g_b) / det;
+        let step_b = (h_aa * g_b - h_ab * g_a) / det;
+        a -= step_a;
+        b -= step_b;
+        if step_a.abs() < 1e-9 && step_b.abs() < 1e-9 {
+            break;
+        }
+    }
+
+    if !a.is_finite() || !b.is_finite() {
+        return Err(err!(InvalidInput, "Calibration did not converge"));
+    }
+    Ok(CalibrationParams {
+        scale: a as f32,
+        bias: b as f32,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CalibrationMethod {
+    MinMax,
+    Temperature,
+    Curve,
+}
+
+/// Maps one model's raw cosine similarities to 0..1 relevance scores, so
+/// UI thresholds keep their meaning when the model changes (cosine ranges
+/// differ a lot between e.g. BGE, Gemma and Qwen3). Fit it once per model
+/// and store it with `calibration_to_json`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SimilarityCalibration {
+    pub method: CalibrationMethod,
+    /// `MinMax` and `Curve`: piecewise-linear curve, flat beyond the ends.
+    /// Min-max is the two points `(min, 0)` and `(max, 1)`.
+    pub points: Vec<CalibrationPoint>,
+    /// `Temperature`: `sigmoid((s - center) / temperature)`.
+    pub center: f32,
+    pub temperature: f32,
+}
+
+impl SimilarityCalibration {
+    fn curve(method: CalibrationMethod, points: Vec<CalibrationPoint>) -> Self {
+        Self {
+            method,
+            points,
+            center: 0.0,
+            temperature: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CalibrationPoint {
+    pub similarity: f32,
+    pub score: f32,
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn calibrate_similarities(
+    similarities: Vec<f32>,
+    calibration: SimilarityCalibration,
+) -> Vec<f32> {
+    similarities
+        .iter()
+        .map(|&s| match calibration.method {
+            CalibrationMethod::Temperature => {
+                sigmoid((s - calibration.center) / calibration.temperature)
+            }
+            CalibrationMethod::MinMax | CalibrationMethod::Curve => {
+                interpolate(&calibration.points, s)
+            }
+        })
+        .collect()
+}
+
+/// Min-max calibration from unlabelled similarities, e.g. between random
+/// pairs of the app's documents. Uses the 5th and 95th percentiles rather
+/// than the extremes so outliers don't squash the range.
+#[flutter_rust_bridge::frb(sync)]
+pub fn fit_min_max_calibration(
+    similarities: Vec<f32>,
+) -> Result<SimilarityCalibration, FlutterEmbedderError> {
+    let mut sorted: Vec<f32> = similarities.into_iter().filter(|s| s.is_finite()).collect();
+    if sorted.len() < 2 {
+        return Err(err!(InvalidInput, "Need at least two similarities"));
+    }
+    sorted.sort_by(f32::total_cmp);
+    let at = |q: f32| sorted[((sorted.len() - 1) as f32 * q).round() as usize];
+    let (min, max) = (at(0.05), at(0.95));
+    if max <= min {
+        return Err(err!(InvalidInput, "Similarities must not all be equal"));
+    }
+    Ok(SimilarityCalibration::curve(
+        CalibrationMethod::MinMax,
+        vec![
+            CalibrationPoint {
+                similarity: min,
+                score: 0.0,
+            },
+            CalibrationPoint {
+                similarity: max,
+                score: 1.0,
+            },
+        ],
+    ))
+}
+
+/// Temperature calibration from labelled pairs: the Platt fit of
+/// `fit_calibration`, expressed as the similarity that scores 0.5
+/// (`center`) and how sharply scores rise around it (`temperature`).
+#[flutter_rust_bridge::frb(sync)]
+pub fn fit_temperature_calibration(
+    similarities: Vec<f32>,
+    labels: Vec<bool>,
+) -> Result<SimilarityCalibration, FlutterEmbedderError> {
+    let params = fit_calibration(similarities, labels)?;
+    if params.scale <= 0.0 {
+        return Err(err!(
+            InvalidInput,
+            "Relevant pairs must have higher similarities than irrelevant ones"
+        ));
+    }
+    Ok(SimilarityCalibration {
+        method: CalibrationMethod::Temperature,
+        points: Vec::new(),
+        center: -params.bias / params.scale,
+        temperature: 1.0 / params.scale,
+    })
+}
+
+/// Curve calibration from labelled pairs by isotonic regression: the
+/// fraction of relevant pairs at each similarity, forced to be
+/// non-decreasing. Needs more labels than `fit_temperature_calibration`
+/// but assumes no particular shape.
+#[flutter_rust_bridge::frb(sync)]
+pub fn fit_curve_calibration(
+    similarities: Vec<f32>,
+    labels: Vec<bool>,
+) -> Result<SimilarityCalibration, FlutterEmbedderError> {
+    if similarities.len() != labels.len() {
+        return Err(err!(
+            InvalidInput,
+            "Similarities and labels must have the same length"
+        ));
+    }
+    if similarities.is_empty() {
+        return Err(err!(InvalidInput, "Need at least one labelled pair"));
+    }
+    let mut pairs: Vec<(f32, f32)> = similarities
+        .into_iter()
+        .zip(labels)
+        .map(|(s, l)| (s, if l { 1.0 } else { 0.0 }))
+        .collect();
+    pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    // Pool adjacent violators: blocks of (sum of similarities, sum of
+    // labels, count), merged while a block's mean label exceeds the next.
+    let mut blocks: Vec<(f32, f32, f32)> = Vec::new();
+    for (s, l) in pairs {
+        blocks.push((s, l, 1.0));
+        while blocks.len() > 1 {
+            let (s2, l2, n2) = blocks[blocks.len() - 1];
+            let (s1, l1, n1) = blocks[blocks.len() - 2];
+            if l1 / n1 < l2 / n2 {
+                break;
+            }
+            blocks.pop();
+            *blocks.last_mut().expect("two blocks") = (s1 + s2, l1 + l2, n1 + n2);
+        }
+    }
+    let points = blocks
+        .into_iter()
+        .map(|(s, l, n)| CalibrationPoint {
+            similarity: s / n,
+            score: l / n,
+        })
+        .collect();
+    Ok(SimilarityCalibration::curve(
+        CalibrationMethod::Curve,
+        points,
+    ))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn calibration_to_json(
+    calibration: SimilarityCalibration,
+) -> Result<String, FlutterEmbedderError> {
+    serde_json::to_string(&calibration)
+        .map_err(|e| err!(InvalidInput, "Failed to serialize calibration: {e}"))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn calibration_from_json(json: String) -> Result<SimilarityCalibration, FlutterEmbedderError> {
+    let calibration: SimilarityCalibration = serde_json::from_str(&json)
+        .map_err(|e| err!(InvalidInput, "Invalid calibration JSON: {e}"))?;
+    let valid = match calibration.method {
+        CalibrationMethod::Temperature => {
+            calibration.temperature > 0.0
+                && calibration.temperature.is_finite()
+                && calibration.center.is_finite()
+        }
+        CalibrationMethod::MinMax | CalibrationMethod::Curve => {
+            let points = &calibration.points;
+            !points.is_empty()
+                && points
+                    .iter()
+                    .all(|p| p.similarity.is_finite() && p.score.is_finite())
+                && points
+                    .windows(2)
+                    .all(|w| w[0].similarity <= w[1].similarity)
+        }
+    };
+    if !valid {
+        return Err(err!(InvalidInput, "Invalid calibration parameters"));
+    }
+    Ok(calibration)
+}
+
+/// Linear interpolation on `points` sorted by similarity. NaN, in `s` or in
+/// the points, gives NaN.
+fn interpolate(points: &[CalibrationPoint], s: f32) -> f32 {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return 0.0;
+    };
+    if s <= first.similarity {
+        return first.score;
+    }
+    if s >= last.similarity {
+        return last.score;
+    }
+    let i = points.partition_point(|p| p.similarity <= s);
+    let (Some(&a), Some(&b)) = (i.checked_sub(1).and_then(|i| points.get(i)), points.get(i)) else {
+        return f32::NAN;
+    };
+    let t = (s - a.similarity) / (b.similarity - a.similarity);
+    a.score + t * (b.score - a.score)
+}
+
+pub(crate) fn sigmoid(x: f32) -> f32 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+pub(crate) fn softmax(logits: &[f32], temperature: f32) -> Vec<f32> {
+    if logits.is_empty() {
+        return Vec::new();
+    }
+    let max = logits.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    let exps: Vec<f32> = logits
+        .iter()
+        .map(|&l| ((l - max) / temperature).exp())
+        .collect();
+    let sum: f32 = exps.iter().sum();
+    exps.iter().map(|e| e / sum).collect()
+}
+
+fn check_temperature(temperature: Option<f32>) -> Result<f32> {
+    let temperature = temperature.unwrap_or(1.0);
+    if temperature <= 0.0 || !temperature.is_finite() {
+        return Err(err!(InvalidInput, "Temperature must be a positive number"));
+    }
+    Ok(temperature)
+}
@@ -0,0 +1,151 @@
+//! Chat template rendering for instruction-tuned models. Templates are the
+//! Jinja sources Hugging Face ships in `tokenizer_config.json`, rendered
+//! with the same settings as `transformers`' `apply_chat_template`.
+
+use std::io;
+
+use flutter_rust_bridge::frb;
+use minijinja::value::{Kwargs, Value, ValueKind};
+use minijinja::{context, Environment, Error, ErrorKind};
+
+use crate::api::error::{err, FlutterEmbedderError, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatTemplate {
+    /// Jinja source, e.g. the contents of `chat_template.jinja`.
+    pub source: String,
+    pub bos_token: Option<String>,
+    pub eos_token: Option<String>,
+}
+
+/// Reads the chat template and BOS/EOS tokens from the contents of a
+/// `tokenizer_config.json`. Where a model lists several named templates the
+/// `default` one is used.
+#[flutter_rust_bridge::frb(sync)]
+pub fn chat_template_from_config(
+    config_json: String,
+) -> Result<ChatTemplate, FlutterEmbedderError> {
+    let config: serde_json::Value = serde_json::from_str(&config_json)
+        .map_err(|e| err!(TokenizerLoad, "Invalid tokenizer config: {e}"))?;
+    let source = match &config["chat_template"] {
+        serde_json::Value::String(source) => Some(source.clone()),
+        serde_json::Value::Array(templates) => templates
+            .iter()
+            .find(|t| t["name"] == "default")
+            .or(templates.first())
+            .and_then(|t| t["template"].as_str())
+            .map(str::to_string),
+        _ => None,
+    }
+    .ok_or_else(|| err!(TokenizerLoad, "Tokenizer config has no chat_template"))?;
+    // Special tokens are either plain strings or `AddedToken` objects.
+    let token = |key: &str| match &config[key] {
+        serde_json::Value::String(token) => Some(token.clone()),
+        value => value["content"].as_str().map(str::to_string),
+    };
+    Ok(ChatTemplate {
+        source,
+        bos_token: token("bos_token"),
+        eos_token: token("eos_token"),
+    })
+}
+
+/// Renders `messages_json`, a JSON array of `{"role": ..., "content": ...}`
+/// messages, with `template`. `add_generation_prompt` appends the opening of
+/// an assistant turn for generation. `extra_context_json` is an optional
+/// JSON object of further template variables, e.g. `tools` or
+/// `enable_thinking` for Qwen3.
+#[flutter_rust_bridge::frb(sync)]
+pub fn apply_chat_template(
+    template: ChatTemplate,
+    messages_json: String,
+    add_generation_prompt: bool,
+    extra_context_json: Option<String>,
+) -> Result<String, FlutterEmbedderError> {
+    let messages: Value = serde_json::from_str(&messages_json)
+        .map_err(|e| err!(InvalidInput, "Invalid messages JSON: {e}"))?;
+    if messages.kind() != ValueKind::Seq {
+        return Err(err!(InvalidInput, "Messages must be a JSON array"));
+    }
+    let extra: Value = match extra_context_json {
+        Some(json) => serde_json::from_str(&json)
+            .map_err(|e| err!(InvalidInput, "Invalid extra context JSON: {e}"))?,
+        None => Value::from(()),
+    };
+    if !matches!(extra.kind(), ValueKind::Map | ValueKind::None) {
+        return Err(err!(InvalidInput, "Extra context must be a JSON object"));
+    }
+
+    let mut env = Environment::new();
+    // `transformers` renders with these, and templates rely on them.
+    env.set_trim_blocks(true);
+    env.set_lstrip_blocks(true);
+    env.set_unknown_method_callback(minijinja_contrib::pycompat::unknown_method_callback);
+    env.add_function("raise_exception", raise_exception);
+    env.add_filter("tojson", tojson);
+    let ctx = context! {
+        messages,
+        add_generation_prompt,
+        bos_token => template.bos_token,
+        eos_token => template.eos_token,
+        ..extra
+    };
+    env.render_str(&template.source, ctx)
+        .map_err(|e| err!(InvalidInput, "Chat template failed: {e:#}"))
+}
+
+fn raise_exception(message: String) -> std::result::Result<Value, Error> {
+    Err(Error::new(ErrorKind::InvalidOperation, message))
+}
+
+/// Python's `json.dumps`, which `transformers` uses for `tojson`: non-ASCII
+/// is kept, and items are separated by `", "` unless indented.
+fn tojson(value: &Value, kwargs: Kwargs) -> std::result::Result<String, Error> {
+    let indent: Option<usize> = kwargs.get("indent")?;
+    kwargs.assert_all_used()?;
+    let to_error = |e: serde_json::Error| Error::new(ErrorKind::BadSerialization, e.to_string());
+    let mut out = Vec::new();
+    match indent {
+        Some(indent) => {
+            let indent = vec![b' '; indent];
+            let formatter = serde_json::ser::PrettyFormatter::with_indent(&indent);
+            let mut serializer = serde_json::Serializer::with_formatter(&mut out, formatter);
+            serde::Serialize::serialize(value, &mut serializer).map_err(to_error)?;
+        }
+        None => {
+            let mut serializer = serde_json::Serializer::with_formatter(&mut out, PythonFormatter);
+            serde::Serialize::serialize(value, &mut serializer).map_err(to_error)?;
+        }
+    }
+    Ok(String::from_utf8(out).expect("serde_json writes UTF-8"))
+}
+
+/// Compact `json.dumps` spacing: `", "` between items, `": "` after keys.
+struct PythonFormatter;
+
+#[frb(ignore)]
+impl serde_json::ser::Formatter for PythonFormatter {
+    fn begin_array_value<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        if first {
+            Ok(())
+        } else {
+            writer.write_all(b", ")
+        }
+    }
+
+    fn begin_object_key<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        self.begin_array_value(writer, first)
+    }
+
+    fn begin_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(b": ")
+    }
+}
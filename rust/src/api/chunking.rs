@@ -0,0 +1,313 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+use tokenizers::Tokenizer;
+
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::tokenizer::with_tokenizer;
+
+/// Closing punctuation allowed between a sentence terminator and the
+/// following whitespace, e.g. `He said "no."`.
+const SENTENCE_CLOSERS: [char; 6] = ['"', '\'', ')', ']', '”', '’'];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextChunk {
+    pub text: String,
+    /// Character (Unicode scalar) offsets of the chunk in the source text.
+    pub start: u32,
+    pub end: u32,
+    /// Tokens in `text`, not counting special tokens.
+    pub token_count: u32,
+}
+
+/// Splits `text` into windows of at most `max_tokens` tokens, consecutive
+/// windows sharing `overlap` tokens. Token counts exclude special tokens, so
+/// leave room for them (e.g. `[CLS]`/`[SEP]`) when picking `max_tokens`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn split_by_tokens(
+    tokenizer_id: u64,
+    text: String,
+    max_tokens: u32,
+    overlap: u32,
+) -> Result<Vec<TextChunk>, FlutterEmbedderError> {
+    if max_tokens == 0 {
+        return Err(err!(InvalidInput, "max_tokens must be positive"));
+    }
+    if overlap >= max_tokens {
+        return Err(err!(
+            InvalidInput,
+            "overlap must be smaller than max_tokens"
+        ));
+    }
+    with_tokenizer(tokenizer_id, |tokenizer| {
+        let tokenizer = &*unbounded(tokenizer)?;
+        let spans = token_windows(
+            tokenizer,
+            &text,
+            (0, text.len()),
+            max_tokens as usize,
+            overlap as usize,
+        )?;
+        Ok(to_chunks(&text, spans))
+    })
+}
+
+/// Packs whole sentences into chunks of at most `max_tokens` tokens.
+/// Sentences longer than the budget are split by tokens.
+#[flutter_rust_bridge::frb(sync)]
+pub fn split_by_sentences(
+    tokenizer_id: u64,
+    text: String,
+    max_tokens: u32,
+) -> Result<Vec<TextChunk>, FlutterEmbedderError> {
+    if max_tokens == 0 {
+        return Err(err!(InvalidInput, "max_tokens must be positive"));
+    }
+    with_tokenizer(tokenizer_id, |tokenizer| {
+        let tokenizer = &*unbounded(tokenizer)?;
+        let max = max_tokens as usize;
+        let sentences = counted(tokenizer, &text, sentence_spans(&text, (0, text.len())))?;
+        let spans = pack(tokenizer, &text, sentences, max)?;
+        Ok(to_chunks(&text, spans))
+    })
+}
+
+/// Packs whole paragraphs (separated by blank lines) into chunks of at most
+/// `max_tokens` tokens. Paragraphs longer than the budget fall back to
+/// sentence packing, then to token windows.
+#[flutter_rust_bridge::frb(sync)]
+pub fn split_by_paragraphs(
+    tokenizer_id: u64,
+    text: String,
+    max_tokens: u32,
+) -> Result<Vec<TextChunk>, FlutterEmbedderError> {
+    if max_tokens == 0 {
+        return Err(err!(InvalidInput, "max_tokens must be positive"));
+    }
+    with_tokenizer(tokenizer_id, |tokenizer| {
+        let tokenizer = &*unbounded(tokenizer)?;
+        let max = max_tokens as usize;
+        let mut spans = Vec::new();
+        for paragraph in counted(tokenizer, &text, paragraph_spans(&text))? {
+            if paragraph.tokens <= max {
+                spans.push(paragraph);
+            } else {
+                let range = (paragraph.start, paragraph.end);
+                let sentences = counted(tokenizer, &text, sentence_spans(&text, range))?;
+                spans.extend(pack(tokenizer, &text, sentences, max)?);
+            }
+        }
+        let spans = pack(tokenizer, &text, spans, max)?;
+        Ok(to_chunks(&text, spans))
+    })
+}
+
+/// A byte span of the source text and its token count.
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    start: usize,
+    end: usize,
+    tokens: usize,
+}
+
+/// Greedily merges consecutive spans while their summed token counts stay
+/// within `max`. Spans that exceed `max` on their own are split by tokens.
+fn pack(tokenizer: &Tokenizer, text: &str, spans: Vec<Span>, max: usize) -> Result<Vec<Span>> {
+    let mut packed = Vec::new();
+    let mut pending = VecDeque::from(spans);
+    let mut members: Vec<Span> = Vec::new();
+    let mut tokens = 0;
+    while let Some(span) = pending.pop_front() {
+        if span.tokens > max {
+            packed.extend(close(tokenizer, text, &mut members, &mut pending, max)?);
+            packed.extend(token_windows(
+                tokenizer,
+                text,
+                (span.start, span.end),
+                max,
+                0,
+            )?);
+            tokens = 0;
+            continue;
+        }
+        if !members.is_empty() && tokens + span.tokens > max {
+            pending.push_front(span);
+            packed.extend(close(tokenizer, text, &mut members, &mut pending, max)?);
+            tokens = 0;
+            continue;
+        }
+        tokens += span.tokens;
+        members.push(span);
+    }
+    packed.extend(close(tokenizer, text, &mut members, &mut pending, max)?);
+    Ok(packed)
+}
+
+/// Merges `members` into one span. Counts of neighbouring spans don't always
+/// add up (the text between them may tokenize too), so the merged text is
+/// counted once here, and trailing members go back to `pending` while it is
+/// over `max`.
+fn close(
+    tokenizer: &Tokenizer,
+    text: &str,
+    members: &mut Vec<Span>,
+    pending: &mut VecDeque<Span>,
+    max: usize,
+) -> Result<Option<Span>> {
+    while let [first, .., last] = members[..] {
+        let merged = count(tokenizer, text, (first.start, last.end))?;
+        if merged.tokens <= max {
+            members.clear();
+            return Ok(Some(merged));
+        }
+        pending.push_front(last);
+        members.pop();
+    }
+    Ok(members.pop())
+}
+
+/// `max`-token windows over `text[range]`, stepping by `max - overlap`
+/// tokens.
+fn token_windows(
+    tokenizer: &Tokenizer,
+    text: &str,
+    range: (usize, usize),
+    max: usize,
+    overlap: usize,
+) -> Result<Vec<Span>> {
+    let encoding = tokenizer
+        .encode(&text[range.0..range.1], false)
+        .map_err(|err| err!(Tokenization, "Encode failed: {err}"))?;
+    let offsets: Vec<(usize, usize)> = encoding
+        .get_offsets()
+        .iter()
+        .filter(|(start, end)| end > start)
+        .map(|(start, end)| (range.0 + start, range.0 + end))
+        .collect();
+    let mut spans = Vec::new();
+    let mut start = 0;
+    while start < offsets.len() {
+        let end = (start + max).min(offsets.len());
+        spans.push(Span {
+            start: offsets[start].0,
+            end: offsets[end - 1].1,
+            tokens: end - start,
+        });
+        if end == offsets.len() {
+            break;
+        }
+        start = end - overlap;
+    }
+    Ok(spans)
+}
+
+/// Byte spans of the sentences in `text[range]`, trimmed of whitespace.
+fn sentence_spans(text: &str, range: (usize, usize)) -> Vec<(usize, usize)> {
+    let slice = &text[range.0..range.1];
+    let mut spans = Vec::new();
+    let mut start = 0;
+    let mut chars = slice.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let mut end = i + c.len_utf8();
+        let boundary = match c {
+            '\n' => true,
+            '.' | '!' | '?' | '。' | '！' | '？' => {
+                while let Some(&(j, closer)) = chars.peek() {
+                    if !SENTENCE_CLOSERS.contains(&closer) {
+                        break;
+                    }
+                    end = j + closer.len_utf8();
+                    chars.next();
+                }
+                matches!(c, '。' | '！' | '？')
+                    || chars.peek().is_none_or(|(_, next)| next.is_whitespace())
+            }
+            _ => false,
+        };
+        if boundary {
+            spans.extend(trim_span(slice, start, end));
+            start = end;
+        }
+    }
+    spans.extend(trim_span(slice, start, slice.len()));
+    spans
+        .into_iter()
+        .map(|(s, e)| (range.0 + s, range.0 + e))
+        .collect()
+}
+
+/// Byte spans of the paragraphs in `text`, split at blank lines.
+fn paragraph_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = 0;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            spans.extend(trim_span(text, start, offset));
+            start = offset + line.len();
+        }
+        offset += line.len();
+    }
+    spans.extend(trim_span(text, start, text.len()));
+    spans
+}
+
+fn trim_span(text: &str, start: usize, end: usize) -> Option<(usize, usize)> {
+    let slice = &text[start..end];
+    let trimmed = slice.trim_start();
+    let start = start + (slice.len() - trimmed.len());
+    let trimmed = trimmed.trim_end();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some((start, start + trimmed.len()))
+    }
+}
+
+/// The tokenizer without truncation and padding, which would cut long texts
+/// short or inflate counts.
+pub(crate) fn unbounded(tokenizer: &Tokenizer) -> Result<Cow<'_, Tokenizer>> {
+    if tokenizer.get_truncation().is_none() && tokenizer.get_padding().is_none() {
+        return Ok(Cow::Borrowed(tokenizer));
+    }
+    let mut tokenizer = tokenizer.clone();
+    tokenizer
+        .with_truncation(None)
+        .map_err(|err| err!(InvalidInput, "Failed to disable truncation: {err}"))?
+        .with_padding(None);
+    Ok(Cow::Owned(tokenizer))
+}
+
+fn count(tokenizer: &Tokenizer, text: &str, (start, end): (usize, usize)) -> Result<Span> {
+    let tokens = tokenizer
+        .encode(&text[start..end], false)
+        .map(|encoding| encoding.len())
+        .map_err(|err| err!(Tokenization, "Encode failed: {err}"))?;
+    Ok(Span { start, end, tokens })
+}
+
+fn counted(tokenizer: &Tokenizer, text: &str, spans: Vec<(usize, usize)>) -> Result<Vec<Span>> {
+    spans
+        .into_iter()
+        .map(|span| count(tokenizer, text, span))
+        .collect()
+}
+
+fn to_chunks(text: &str, spans: Vec<Span>) -> Vec<TextChunk> {
+    // Char offset of every char boundary, for byte -> char conversion.
+    let boundaries: Vec<usize> = text
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(text.len()))
+        .collect();
+    let to_char = |byte: usize| boundaries.partition_point(|&b| b < byte) as u32;
+    spans
+        .into_iter()
+        .map(|span| TextChunk {
+            text: text[span.start..span.end].to_string(),
+            start: to_char(span.start),
+            end: to_char(span.end),
+            token_count: span.tokens as u32,
+        })
+        .collect()
+}
@@ -0,0 +1,173 @@
+use flutter_rust_bridge::frb;
+use ort::session::Session;
+use tokenizers::Tokenizer;
+
+use crate::api::calibration::{sigmoid, softmax};
+use crate::api::classify::{classification_logits, id2label, parse_model_config, LabelScore};
+use crate::api::embeddings::WARMUP_TEXT;
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::ort::{
+    build_session_from_file_with_init, build_session_from_memory_with_init, session_model_info,
+    ModelInfo, OrtInitOptions,
+};
+
+/// Text classifier for sequence-classification exports (sentiment,
+/// moderation, topic, ...). Single-label models get a softmax over the
+/// labels, multi-label models an independent sigmoid per label.
+#[frb(opaque)]
+pub struct SequenceClassifier {
+    tokenizer: Tokenizer,
+    session: Session,
+    /// Class names from `id2label`; empty when no config was given.
+    labels: Vec<String>,
+    multi_label: bool,
+}
+
+#[frb(sync)]
+impl SequenceClassifier {
+    /// `config_json` is the model's Hugging Face `config.json`: `id2label`
+    /// names the classes and `problem_type` selects multi-label scoring.
+    /// Without it classes are named `LABEL_0`, `LABEL_1`, ... and scored as
+    /// single-label.
+    pub fn create(
+        model_path: String,
+        tokenizer_path: String,
+        config_json: Option<String>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_with_options(model_path, tokenizer_path, config_json, None)
+    }
+
+    pub fn create_with_options(
+        model_path: String,
+        tokenizer_path: String,
+        config_json: Option<String>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let (labels, multi_label) = label_config(config_json.as_deref())?;
+        let tokenizer =
+            Tokenizer::from_file(tokenizer_path).map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let session = build_session_from_file_with_init(model_path, ort_options)?;
+        Ok(Self {
+            tokenizer,
+            session,
+            labels,
+            multi_label,
+        })
+    }
+
+    /// `create` from in-memory model bytes and tokenizer JSON.
+    pub fn create_from_bytes(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        config_json: Option<String>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_bytes_with_options(model_bytes, tokenizer_json, config_json, None)
+    }
+
+    pub fn create_from_bytes_with_options(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        config_json: Option<String>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let (labels, multi_label) = label_config(config_json.as_deref())?;
+        let tokenizer =
+            Tokenizer::from_bytes(tokenizer_json).map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let session = build_session_from_memory_with_init(&model_bytes, ort_options)?;
+        Ok(Self {
+            tokenizer,
+            session,
+            labels,
+            multi_label,
+        })
+    }
+
+    /// Inputs, outputs and metadata of the loaded ONNX model.
+    pub fn model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&self.session)
+    }
+
+    /// Overrides the scoring mode picked from the config.
+    pub fn set_multi_label(&mut self, multi_label: bool) {
+        self.multi_label = multi_label;
+    }
+
+    pub fn is_multi_label(&self) -> bool {
+        self.multi_label
+    }
+
+    /// Label scores for each of `texts`, best first.
+    pub fn classify(
+        &mut self,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<LabelScore>>, FlutterEmbedderError> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let encodings = self
+            .tokenizer
+            .encode_batch(texts, true)
+            .map_err(|e| err!(Tokenization, "{e}"))?;
+        let (logits, classes) =
+            classification_logits(&mut self.session, &self.tokenizer, &encodings)?;
+        if !self.labels.is_empty() && classes != self.labels.len() {
+            return Err(err!(
+                ShapeMismatch,
+                "Model has {classes} labels, config names {}",
+                self.labels.len()
+            ));
+        }
+        Ok(logits
+            .chunks(classes)
+            .map(|row| {
+                let scores = if self.multi_label {
+                    row.iter().map(|&x| sigmoid(x)).collect()
+                } else {
+                    softmax(row, 1.0)
+                };
+                let mut results: Vec<LabelScore> = scores
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, score)| LabelScore {
+                        label: self
+                            .labels
+                            .get(i)
+                            .cloned()
+                            .unwrap_or_else(|| format!("LABEL_{i}")),
+                        score,
+                    })
+                    .collect();
+                results.sort_by(|a, b| b.score.total_cmp(&a.score));
+                results
+            })
+            .collect())
+    }
+}
+
+impl SequenceClassifier {
+    /// Classifies one tiny input so the first real `classify` doesn't pay
+    /// for graph initialization.
+    pub fn warmup(&mut self) -> Result<(), FlutterEmbedderError> {
+        self.classify(vec![WARMUP_TEXT.to_string()])?;
+        Ok(())
+    }
+
+    /// Same as `classify`, but not `sync` on the Dart side.
+    pub fn classify_async(
+        &mut self,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<LabelScore>>, FlutterEmbedderError> {
+        self.classify(texts)
+    }
+}
+
+fn label_config(config_json: Option<&str>) -> Result<(Vec<String>, bool)> {
+    let Some(config_json) = config_json else {
+        return Ok((Vec::new(), false));
+    };
+    let multi_label = parse_model_config(config_json)?
+        .get("problem_type")
+        .and_then(|v| v.as_str())
+        == Some("multi_label_classification");
+    Ok((id2label(config_json)?, multi_label))
+}
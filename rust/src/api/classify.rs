@@ -0,0 +1,297 @@
+use flutter_rust_bridge::frb;
+use ort::session::Session;
+use ort::value::{DynTensor, Tensor};
+use tokenizers::{Encoding, Tokenizer};
+
+use crate::api::calibration::softmax;
+use crate::api::embeddings::{pad_encodings, WARMUP_TEXT};
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::ort::{
+    build_session_from_file_with_init, build_session_from_memory_with_init, session_model_info,
+    ModelInfo, OrtInitOptions,
+};
+
+const DEFAULT_HYPOTHESIS_TEMPLATE: &str = "This example is {}.";
+/// MNLI label order used by most NLI exports (bart-large-mnli,
+/// deberta-v3-*-mnli, ...) when no `config.json` is given.
+const MNLI_CONTRADICTION: usize = 0;
+const MNLI_ENTAILMENT: usize = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabelScore {
+    pub label: String,
+    pub score: f32,
+}
+
+/// Zero-shot text classification with an NLI cross-encoder: every candidate
+/// label is turned into a hypothesis ("This example is {label}.") and scored
+/// by how strongly the text entails it.
+#[frb(opaque)]
+pub struct ZeroShotClassifier {
+    tokenizer: Tokenizer,
+    session: Session,
+    entailment: usize,
+    contradiction: usize,
+    hypothesis_template: String,
+}
+
+#[frb(sync)]
+impl ZeroShotClassifier {
+    /// `config_json` is the model's Hugging Face `config.json`, used to find
+    /// the entailment/contradiction labels in `id2label`. Without it the
+    /// MNLI order (contradiction, neutral, entailment) is assumed.
+    pub fn create(
+        model_path: String,
+        tokenizer_path: String,
+        config_json: Option<String>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_with_options(model_path, tokenizer_path, config_json, None)
+    }
+
+    pub fn create_with_options(
+        model_path: String,
+        tokenizer_path: String,
+        config_json: Option<String>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let labels = nli_labels(config_json.as_deref())?;
+        let tokenizer =
+            Tokenizer::from_file(tokenizer_path).map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let session = build_session_from_file_with_init(model_path, ort_options)?;
+        Ok(Self::from_parts(tokenizer, session, labels))
+    }
+
+    /// `create` from in-memory model bytes and tokenizer JSON.
+    pub fn create_from_bytes(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        config_json: Option<String>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_bytes_with_options(model_bytes, tokenizer_json, config_json, None)
+    }
+
+    pub fn create_from_bytes_with_options(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        config_json: Option<String>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let labels = nli_labels(config_json.as_deref())?;
+        let tokenizer =
+            Tokenizer::from_bytes(tokenizer_json).map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let session = build_session_from_memory_with_init(&model_bytes, ort_options)?;
+        Ok(Self::from_parts(tokenizer, session, labels))
+    }
+
+    fn from_parts(tokenizer: Tokenizer, session: Session, labels: (usize, usize)) -> Self {
+        let (entailment, contradiction) = labels;
+        Self {
+            tokenizer,
+            session,
+            entailment,
+            contradiction,
+            hypothesis_template: DEFAULT_HYPOTHESIS_TEMPLATE.to_string(),
+        }
+    }
+
+    /// Inputs, outputs and metadata of the loaded ONNX model.
+    pub fn model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&self.session)
+    }
+
+    /// Template turning a label into a hypothesis; `{}` is replaced by the
+    /// label.
+    pub fn set_hypothesis_template(
+        &mut self,
+        template: String,
+    ) -> Result<(), FlutterEmbedderError> {
+        if !template.contains("{}") {
+            return Err(err!(
+                InvalidInput,
+                "Hypothesis template must contain \"{{}}\""
+            ));
+        }
+        self.hypothesis_template = template;
+        Ok(())
+    }
+
+    /// Scores `candidate_labels` for `text`, best first. With `multi_label`
+    /// each label is judged on its own (entailment vs. contradiction), so
+    /// scores are independent; otherwise they are a softmax over the labels
+    /// and sum to 1.
+    pub fn classify(
+        &mut self,
+        text: String,
+        candidate_labels: Vec<String>,
+        multi_label: bool,
+    ) -> Result<Vec<LabelScore>, FlutterEmbedderError> {
+        if candidate_labels.is_empty() {
+            return Ok(Vec::new());
+        }
+        let pairs: Vec<(String, String)> = candidate_labels
+            .iter()
+            .map(|label| (text.clone(), self.hypothesis_template.replace("{}", label)))
+            .collect();
+        let encodings = self
+            .tokenizer
+            .encode_batch(pairs, true)
+            .map_err(|e| err!(Tokenization, "{e}"))?;
+        let (logits, classes) =
+            classification_logits(&mut self.session, &self.tokenizer, &encodings)?;
+        if classes <= self.entailment.max(self.contradiction) {
+            return Err(err!(
+                ShapeMismatch,
+                "Model has {classes} labels, expected NLI logits"
+            ));
+        }
+
+        let rows = logits.chunks(classes);
+        let scores: Vec<f32> = if multi_label {
+            rows.map(|row| softmax(&[row[self.contradiction], row[self.entailment]], 1.0)[1])
+                .collect()
+        } else {
+            let entailment: Vec<f32> = rows.map(|row| row[self.entailment]).collect();
+            softmax(&entailment, 1.0)
+        };
+        let mut results: Vec<LabelScore> = candidate_labels
+            .into_iter()
+            .zip(scores)
+            .map(|(label, score)| LabelScore { label, score })
+            .collect();
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+        Ok(results)
+    }
+}
+
+impl ZeroShotClassifier {
+    /// Classifies one tiny input so the first real `classify` doesn't pay
+    /// for graph initialization.
+    pub fn warmup(&mut self) -> Result<(), FlutterEmbedderError> {
+        self.classify(
+            WARMUP_TEXT.to_string(),
+            vec![WARMUP_TEXT.to_string()],
+            false,
+        )?;
+        Ok(())
+    }
+
+    /// Same as `classify`, but not `sync` on the Dart side.
+    pub fn classify_async(
+        &mut self,
+        text: String,
+        candidate_labels: Vec<String>,
+        multi_label: bool,
+    ) -> Result<Vec<LabelScore>, FlutterEmbedderError> {
+        self.classify(text, candidate_labels, multi_label)
+    }
+}
+
+/// Runs a sequence-classification model over `encodings` (single texts or
+/// pairs) and returns the row-major `[batch, labels]` logits with the label
+/// count.
+pub(crate) fn classification_logits(
+    session: &mut Session,
+    tokenizer: &Tokenizer,
+    encodings: &[Encoding],
+) -> Result<(Vec<f32>, usize)> {
+    let (shape, data) = model_logits(session, tokenizer, encodings)?;
+    let labels = if shape.len() > 1 { shape[1] } else { 1 };
+    Ok((data, labels))
+}
+
+/// Pads `encodings`, runs the model and returns the shape and data of its
+/// `logits` output (or first output), checked to have one row per input.
+pub(crate) fn model_logits(
+    session: &mut Session,
+    tokenizer: &Tokenizer,
+    encodings: &[Encoding],
+) -> Result<(Vec<usize>, Vec<f32>)> {
+    let pad_id = tokenizer
+        .get_padding()
+        .map(|p| p.pad_id as i64)
+        .unwrap_or(0);
+    let batch = encodings.len();
+    let (max_len, input_ids_batch, mask_batch) = pad_encodings(encodings, pad_id);
+    if max_len == 0 {
+        return Err(err!(InvalidInput, "Inputs produced no tokens"));
+    }
+
+    let mut inputs: Vec<(String, DynTensor)> = vec![
+        (
+            "input_ids".into(),
+            Tensor::from_array(([batch, max_len], input_ids_batch))?.upcast(),
+        ),
+        (
+            "attention_mask".into(),
+            Tensor::from_array(([batch, max_len], mask_batch))?.upcast(),
+        ),
+    ];
+    if session
+        .inputs()
+        .iter()
+        .any(|input| input.name() == "token_type_ids")
+    {
+        // Pairs need their segment ids, unlike single-text embedders.
+        let mut type_ids = Vec::with_capacity(batch * max_len);
+        for encoding in encodings {
+            let ids = encoding.get_type_ids();
+            type_ids.extend(ids.iter().map(|&x| x as i64));
+            type_ids.extend(std::iter::repeat_n(0, max_len - ids.len()));
+        }
+        inputs.push((
+            "token_type_ids".into(),
+            Tensor::from_array(([batch, max_len], type_ids))?.upcast(),
+        ));
+    }
+
+    let outputs = session.run(inputs)?;
+    let logits = match outputs.get("logits") {
+        Some(logits) => logits,
+        None => &outputs[0],
+    };
+    let (shape, data) = logits.try_extract_tensor::<f32>()?;
+    if shape.first().map(|d| *d as usize) != Some(batch) {
+        return Err(err!(ShapeMismatch, "Batch size mismatch in outputs"));
+    }
+    let shape = shape.iter().map(|&d| d as usize).collect();
+    Ok((shape, data.to_vec()))
+}
+
+/// Label names by class index from a Hugging Face `config.json`'s
+/// `id2label` map.
+pub(crate) fn id2label(config_json: &str) -> Result<Vec<String>> {
+    let config = parse_model_config(config_json)?;
+    let map = config
+        .get("id2label")
+        .and_then(|v| v.as_object())
+        .ok_or(err!(InvalidInput, "Model config has no id2label"))?;
+    let mut labels = vec![String::new(); map.len()];
+    for (id, label) in map {
+        let index = id
+            .parse::<usize>()
+            .ok()
+            .filter(|&i| i < labels.len())
+            .ok_or(err!(InvalidInput, "Invalid id2label key {id:?}"))?;
+        labels[index] = label.as_str().unwrap_or_default().to_string();
+    }
+    Ok(labels)
+}
+
+pub(crate) fn parse_model_config(config_json: &str) -> Result<serde_json::Value> {
+    serde_json::from_str(config_json).map_err(|e| err!(InvalidInput, "Invalid model config: {e}"))
+}
+
+/// Indices of the entailment and contradiction classes.
+fn nli_labels(config_json: Option<&str>) -> Result<(usize, usize)> {
+    let Some(config_json) = config_json else {
+        return Ok((MNLI_ENTAILMENT, MNLI_CONTRADICTION));
+    };
+    let labels = id2label(config_json)?;
+    let find = |prefix: &str| {
+        labels
+            .iter()
+            .position(|l| l.to_lowercase().starts_with(prefix))
+            .ok_or(err!(InvalidInput, "Model config has no {prefix}* label"))
+    };
+    Ok((find("entail")?, find("contradict")?))
+}
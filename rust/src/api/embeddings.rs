@@ -1,5 +1,1174 @@
-pub mod jina_v3;
-pub mod qwen3;
-pub mod gemma;
 pub mod bge;
+pub mod clap;
+pub mod clip;
+pub mod colbert;
+pub mod e5;
+pub mod gemma;
+pub mod generic;
+pub mod jina_v3;
 pub mod minilm;
+pub mod nomic;
+pub(crate) mod pipeline;
+pub mod qwen3;
+pub mod splade;
+pub mod vision;
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+
+use flutter_rust_bridge::frb;
+use ort::session::Session;
+use ort::value::ValueType;
+
+use crate::api::chunking::{split_by_tokens, TextChunk};
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::hub::{resolve_hub_files, HubOptions};
+use crate::api::ort::{build_session_from_file_with_init, closed_error, ModelInfo, OrtInitOptions};
+use crate::api::tokenizer::{register_shared_tokenizer, TokenOffsets};
+use crate::api::utils::normalize;
+use crate::frb_generated::StreamSink;
+
+const DEFAULT_EMBED_MANY_BATCH_SIZE: usize = 32;
+const DIMENSION_PROBE: &str = "dimension probe";
+/// Output names the embedders read their vectors from, in lookup order.
+const EMBEDDING_OUTPUTS: [&str; 5] = [
+    "sentence_embedding",
+    "pooled_output",
+    "pooler_output",
+    "embedding",
+    "last_hidden_state",
+];
+
+/// Model families supported by `create_embedder`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbedderKind {
+    Qwen3,
+    Gemma,
+    Bge,
+    MiniLm,
+    JinaV3,
+    E5,
+    Nomic,
+}
+
+/// Common interface of the model-specific embedders, so callers can swap
+/// models at runtime without branching on the concrete type.
+///
+/// Implementations are `#[frb(ignore)]`: Dart reaches them through
+/// `Embedder`, and bridging them per struct would clash with the inherent
+/// `embed`/`format_query`/`format_document`.
+pub trait TextEmbedder: Send + Sync {
+    fn embed(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>>;
+    fn format_query(&self, query: String) -> String;
+    fn format_document(&self, text: String) -> String;
+    /// Embeds `queries` with the model's query prefix or instruction.
+    fn embed_queries(&self, queries: Vec<String>) -> Result<Vec<Vec<f32>>> {
+        let queries = queries
+            .into_iter()
+            .map(|query| self.format_query(query))
+            .collect();
+        self.embed(queries)
+    }
+    /// Embeds `texts` with the model's document prefix.
+    fn embed_documents(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>> {
+        let texts = texts
+            .into_iter()
+            .map(|text| self.format_document(text))
+            .collect();
+        self.embed(texts)
+    }
+    /// Unpooled per-token vectors; only encoder models that expose
+    /// `last_hidden_state` support it.
+    fn embed_tokens(&self, _texts: Vec<String>) -> Result<Vec<TokenEmbeddings>> {
+        Err(err!(
+            InvalidInput,
+            "This model does not support token embeddings"
+        ))
+    }
+    fn model_info(&self) -> Result<ModelInfo>;
+    /// Length of the produced vectors, read from the model outputs or, when
+    /// the export leaves it dynamic, from a one-token dry run.
+    fn dimension(&self) -> Result<usize>;
+    /// `embed` with per-call options; by default Matryoshka truncation is
+    /// applied to the normalized output.
+    fn embed_with_options(
+        &self,
+        texts: Vec<String>,
+        options: &EmbedOptions,
+    ) -> Result<Vec<Vec<f32>>> {
+        truncate_embeddings(self.embed(texts)?, options.output_dim)
+    }
+    /// `embed` that also reports where the time went.
+    fn embed_with_stats(&self, texts: Vec<String>) -> Result<EmbeddingsWithStats>;
+    /// Runs a one-word input through the model so the first real `embed`
+    /// doesn't pay for graph initialization.
+    fn warmup(&self) -> Result<()> {
+        self.embed(vec![WARMUP_TEXT.to_string()]).map(|_| ())
+    }
+    /// Swaps in another model file (and optionally tokenizer) once it has
+    /// loaded; see `BgeEmbedder::reload_model`.
+    fn reload_model(
+        &mut self,
+        model_path: String,
+        tokenizer_path: Option<String>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<()>;
+    /// Frees the model sessions and tokenizer; later calls fail with
+    /// `ErrorKind::Closed`.
+    fn close(&mut self);
+    fn is_closed(&self) -> bool;
+    /// The tokenizer the model runs with, shared rather than copied.
+    #[frb(ignore)]
+    fn tokenizer(&self) -> Result<Arc<tokenizers::Tokenizer>>;
+}
+
+/// `value`, or the `Closed` error once the embedder holding it was closed.
+pub(crate) fn loaded<T>(value: &Option<T>) -> Result<&T> {
+    value.as_ref().ok_or_else(closed_error)
+}
+
+/// Loads what `reload_model` swaps in, before anything is replaced: the new
+/// session and, when `tokenizer_path` is given, a new tokenizer. A closed
+/// embedder has no tokenizer left to keep, so it needs one.
+pub(crate) fn load_replacement(
+    tokenizer: &Option<Arc<tokenizers::Tokenizer>>,
+    model_path: String,
+    tokenizer_path: Option<String>,
+    ort_options: Option<OrtInitOptions>,
+) -> Result<(Session, Option<Arc<tokenizers::Tokenizer>>)> {
+    let tokenizer = match tokenizer_path {
+        Some(path) => Some(Arc::new(
+            tokenizers::Tokenizer::from_file(path).map_err(|e| err!(TokenizerLoad, "{e}"))?,
+        )),
+        None => {
+            loaded(tokenizer)?;
+            None
+        }
+    };
+    let session = build_session_from_file_with_init(model_path, ort_options)?;
+    Ok((session, tokenizer))
+}
+
+/// Input used by `warmup`.
+pub(crate) const WARMUP_TEXT: &str = "warmup";
+
+/// Type-erased embedder returned by `create_embedder`.
+#[frb(opaque)]
+pub struct Embedder {
+    kind: EmbedderKind,
+    inner: Box<dyn TextEmbedder>,
+    /// Id handed out by `tokenizer_id`, reused by later calls.
+    tokenizer_id: Mutex<Option<u64>>,
+}
+
+#[frb(sync)]
+impl Embedder {
+    pub fn kind(&self) -> EmbedderKind {
+        self.kind
+    }
+
+    pub fn embed(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.inner.embed(texts)
+    }
+
+    pub fn format_query(&self, query: String) -> String {
+        self.inner.format_query(query)
+    }
+
+    pub fn format_document(&self, text: String) -> String {
+        self.inner.format_document(text)
+    }
+
+    /// `embed` for search queries, with the query prefix or instruction the
+    /// model expects already applied.
+    pub fn embed_queries(
+        &self,
+        queries: Vec<String>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.inner.embed_queries(queries)
+    }
+
+    /// `embed` for the documents being searched, with the document prefix
+    /// applied.
+    pub fn embed_documents(
+        &self,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.inner.embed_documents(texts)
+    }
+
+    pub fn dimension(&self) -> Result<usize, FlutterEmbedderError> {
+        self.inner.dimension()
+    }
+
+    /// Per-token `last_hidden_state` vectors, padding dropped, for custom
+    /// pooling, ColBERT-style scoring or attention visualization. Fails with
+    /// `ErrorKind::InvalidInput` for decoder models such as Qwen3.
+    pub fn embed_tokens(
+        &self,
+        texts: Vec<String>,
+    ) -> Result<Vec<TokenEmbeddings>, FlutterEmbedderError> {
+        self.inner.embed_tokens(texts)
+    }
+
+    pub fn model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        self.inner.model_info()
+    }
+
+    pub fn embed_with_options(
+        &self,
+        texts: Vec<String>,
+        options: EmbedOptions,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.inner.embed_with_options(texts, &options)
+    }
+
+    /// `embed` plus tokenization/inference timings and peak memory, for
+    /// picking batch sizes and models per device.
+    pub fn embed_with_stats(
+        &self,
+        texts: Vec<String>,
+    ) -> Result<EmbeddingsWithStats, FlutterEmbedderError> {
+        self.inner.embed_with_stats(texts)
+    }
+
+    /// Frees the ONNX sessions and tokenizer now rather than when the Dart
+    /// object is garbage collected. Waits for in-flight calls; later calls
+    /// fail with `ErrorKind::Closed`.
+    pub fn close(&mut self) {
+        self.inner.close()
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.inner.is_closed()
+    }
+
+    /// Registers the embedder's tokenizer with the tokenizer functions and
+    /// returns its id, so `encode` and `count_tokens` see exactly what the
+    /// model sees without loading `tokenizer.json` again. Repeated calls
+    /// return the same id until it is disposed. The registered tokenizer
+    /// stays alive after the embedder is closed until `dispose_tokenizer`;
+    /// changing its truncation, padding or special tokens through the id
+    /// leaves the embedder's copy untouched.
+    pub fn tokenizer_id(&self) -> Result<u64, FlutterEmbedderError> {
+        let tokenizer = self.inner.tokenizer()?;
+        let mut known = self
+            .tokenizer_id
+            .lock()
+            .map_err(|e| err!(Internal, "Failed to acquire tokenizer id: {e}"))?;
+        let id = register_shared_tokenizer(tokenizer, *known)?;
+        *known = Some(id);
+        Ok(id)
+    }
+
+    /// Replaces the model, e.g. with a larger variant of the same family,
+    /// without recreating the embedder. The new session is built before the
+    /// old one is dropped, and calls in flight finish on the old model. The
+    /// tokenizer is kept unless `tokenizer_path` is given.
+    pub fn reload_model(
+        &mut self,
+        model_path: String,
+        tokenizer_path: Option<String>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<(), FlutterEmbedderError> {
+        let new_tokenizer = tokenizer_path.is_some();
+        self.inner
+            .reload_model(model_path, tokenizer_path, ort_options)?;
+        if new_tokenizer {
+            // The registered id still refers to the old tokenizer.
+            let known = self.tokenizer_id.get_mut();
+            *known.unwrap_or_else(|e| e.into_inner()) = None;
+        }
+        Ok(())
+    }
+}
+
+impl Embedder {
+    /// Runs a tiny input through every session of the model so the first
+    /// real `embed` doesn't pay for graph initialization. Call it while the
+    /// UI is still idle, e.g. right after creating the embedder.
+    pub fn warmup(&self) -> Result<(), FlutterEmbedderError> {
+        self.inner.warmup()
+    }
+
+    /// Same as `embed`, but runs off the Dart isolate.
+    pub fn embed_async(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.inner.embed(texts)
+    }
+
+    /// Embeds `texts` in input order, `batch_size` at a time, sending each
+    /// batch to `sink` as soon as it is ready.
+    pub fn embed_stream(
+        &self,
+        texts: Vec<String>,
+        batch_size: u32,
+        sink: StreamSink<EmbeddingProgress>,
+    ) -> Result<(), FlutterEmbedderError> {
+        embed_stream_with(texts, batch_size, None, &sink, |batch| {
+            self.inner.embed(batch)
+        })
+    }
+
+    /// `embed_async` that fails with a cancellation error once `token` is
+    /// cancelled.
+    pub fn embed_async_cancellable(
+        &self,
+        texts: Vec<String>,
+        token: &CancellationToken,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        embed_many_with(texts, None, None, Some(token), |batch| {
+            self.inner.embed(batch)
+        })
+    }
+
+    /// Splits `text` into windows of `window_tokens` tokens (counted with
+    /// `tokenizer_id`), starting every `stride` tokens, and embeds each as a
+    /// document. Every embedding comes with its window's character span in
+    /// `text`, e.g. to highlight the passage that matched a query.
+    pub fn embed_windows(
+        &self,
+        tokenizer_id: u64,
+        text: String,
+        window_tokens: u32,
+        stride: u32,
+    ) -> Result<Vec<PassageEmbedding>, FlutterEmbedderError> {
+        if stride == 0 || stride > window_tokens {
+            return Err(err!(
+                InvalidInput,
+                "stride must be between 1 and window_tokens"
+            ));
+        }
+        let passages = split_by_tokens(tokenizer_id, text, window_tokens, window_tokens - stride)?;
+        let texts = passages.iter().map(|p| p.text.clone()).collect();
+        let embeddings = self.inner.embed_documents(texts)?;
+        Ok(passages
+            .into_iter()
+            .zip(embeddings)
+            .map(|(passage, embedding)| PassageEmbedding { passage, embedding })
+            .collect())
+    }
+
+    /// `embed_stream` that ends after the current batch once `token` is
+    /// cancelled.
+    pub fn embed_stream_cancellable(
+        &self,
+        texts: Vec<String>,
+        batch_size: u32,
+        token: &CancellationToken,
+        sink: StreamSink<EmbeddingProgress>,
+    ) -> Result<(), FlutterEmbedderError> {
+        embed_stream_with(texts, batch_size, Some(token), &sink, |batch| {
+            self.inner.embed(batch)
+        })
+    }
+}
+
+#[frb(sync)]
+pub fn create_embedder(
+    kind: EmbedderKind,
+    model_path: String,
+    tokenizer_path: String,
+    ort_options: Option<OrtInitOptions>,
+) -> Result<Embedder, FlutterEmbedderError> {
+    let inner: Box<dyn TextEmbedder> = match kind {
+        EmbedderKind::Qwen3 => Box::new(qwen3::Qwen3Embedder::create_with_options(
+            model_path,
+            tokenizer_path,
+            ort_options,
+        )?),
+        EmbedderKind::Gemma => Box::new(gemma::GemmaEmbedder::create_with_options(
+            model_path,
+            tokenizer_path,
+            ort_options,
+        )?),
+        EmbedderKind::Bge => Box::new(bge::BgeEmbedder::create_with_options(
+            model_path,
+            tokenizer_path,
+            ort_options,
+        )?),
+        EmbedderKind::MiniLm => Box::new(minilm::MiniLmEmbedder::create_with_options(
+            model_path,
+            tokenizer_path,
+            ort_options,
+        )?),
+        EmbedderKind::JinaV3 => Box::new(jina_v3::JinaV3Embedder::create_with_options(
+            model_path,
+            tokenizer_path,
+            ort_options,
+        )?),
+        EmbedderKind::E5 => Box::new(e5::E5Embedder::create_with_options(
+            model_path,
+            tokenizer_path,
+            ort_options,
+        )?),
+        EmbedderKind::Nomic => Box::new(nomic::NomicEmbedder::create_with_options(
+            model_path,
+            tokenizer_path,
+            ort_options,
+        )?),
+    };
+    Ok(Embedder {
+        kind,
+        inner,
+        tokenizer_id: Mutex::new(None),
+    })
+}
+
+/// `create_embedder` from in-memory model bytes and tokenizer JSON.
+#[frb(sync)]
+pub fn create_embedder_from_bytes(
+    kind: EmbedderKind,
+    model_bytes: Vec<u8>,
+    tokenizer_json: String,
+    ort_options: Option<OrtInitOptions>,
+) -> Result<Embedder, FlutterEmbedderError> {
+    let inner: Box<dyn TextEmbedder> = match kind {
+        EmbedderKind::Qwen3 => Box::new(qwen3::Qwen3Embedder::create_from_bytes_with_options(
+            model_bytes,
+            tokenizer_json,
+            ort_options,
+        )?),
+        EmbedderKind::Gemma => Box::new(gemma::GemmaEmbedder::create_from_bytes_with_options(
+            model_bytes,
+            tokenizer_json,
+            ort_options,
+        )?),
+        EmbedderKind::Bge => Box::new(bge::BgeEmbedder::create_from_bytes_with_options(
+            model_bytes,
+            tokenizer_json,
+            ort_options,
+        )?),
+        EmbedderKind::MiniLm => Box::new(minilm::MiniLmEmbedder::create_from_bytes_with_options(
+            model_bytes,
+            tokenizer_json,
+            ort_options,
+        )?),
+        EmbedderKind::JinaV3 => Box::new(jina_v3::JinaV3Embedder::create_from_bytes_with_options(
+            model_bytes,
+            tokenizer_json,
+            ort_options,
+        )?),
+        EmbedderKind::E5 => Box::new(e5::E5Embedder::create_from_bytes_with_options(
+            model_bytes,
+            tokenizer_json,
+            ort_options,
+        )?),
+        EmbedderKind::Nomic => Box::new(nomic::NomicEmbedder::create_from_bytes_with_options(
+            model_bytes,
+            tokenizer_json,
+            ort_options,
+        )?),
+    };
+    Ok(Embedder {
+        kind,
+        inner,
+        tokenizer_id: Mutex::new(None),
+    })
+}
+
+/// `create_embedder` from a Hugging Face Hub repo, downloading the model and
+/// `tokenizer.json` into `cache_dir` on first use.
+pub fn create_embedder_from_hub(
+    kind: EmbedderKind,
+    repo_id: String,
+    cache_dir: String,
+    hub_options: Option<HubOptions>,
+    ort_options: Option<OrtInitOptions>,
+) -> Result<Embedder, FlutterEmbedderError> {
+    let (model_path, tokenizer_path) = resolve_hub_files(&repo_id, &cache_dir, hub_options)?;
+    create_embedder(kind, model_path, tokenizer_path, ort_options)
+}
+
+type EmbedderStore = HashMap<u64, Arc<Embedder>>;
+
+fn embedder_store() -> &'static RwLock<EmbedderStore> {
+    static STORE: OnceLock<RwLock<EmbedderStore>> = OnceLock::new();
+    STORE.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+fn next_embedder_id() -> u64 {
+    static COUNTER: AtomicU64 = AtomicU64::new(1);
+    COUNTER.fetch_add(1, Ordering::Relaxed)
+}
+
+fn insert_embedder(embedder: Embedder) -> Result<u64> {
+    let id = next_embedder_id();
+    embedder_store()
+        .write()
+        .map_err(|e| err!(Internal, "Failed to acquire embedder store: {e}"))?
+        .insert(id, Arc::new(embedder));
+    Ok(id)
+}
+
+/// The embedder stored under `id`. The store lock is released before the
+/// caller runs inference, so slow calls don't block other handles.
+pub(crate) fn embedder_handle(id: u64) -> Result<Arc<Embedder>> {
+    embedder_store()
+        .read()
+        .map_err(|e| err!(Internal, "Failed to acquire embedder store: {e}"))?
+        .get(&id)
+        .cloned()
+        .ok_or_else(|| err!(InvalidInput, "Unknown embedder id"))
+}
+
+/// `create_embedder`, but keeps the embedder in a global store and returns
+/// its id, like the tokenizer functions. Ids are plain integers, so any Dart
+/// isolate can use the embedder without sharing an opaque object.
+#[frb(sync)]
+pub fn load_embedder(
+    kind: EmbedderKind,
+    model_path: String,
+    tokenizer_path: String,
+    ort_options: Option<OrtInitOptions>,
+) -> Result<u64, FlutterEmbedderError> {
+    insert_embedder(create_embedder(
+        kind,
+        model_path,
+        tokenizer_path,
+        ort_options,
+    )?)
+}
+
+/// `load_embedder` from in-memory model bytes and tokenizer JSON.
+#[frb(sync)]
+pub fn load_embedder_from_bytes(
+    kind: EmbedderKind,
+    model_bytes: Vec<u8>,
+    tokenizer_json: String,
+    ort_options: Option<OrtInitOptions>,
+) -> Result<u64, FlutterEmbedderError> {
+    insert_embedder(create_embedder_from_bytes(
+        kind,
+        model_bytes,
+        tokenizer_json,
+        ort_options,
+    )?)
+}
+
+/// `Embedder::embed` on the embedder loaded under `embedder_id`.
+#[frb(sync)]
+pub fn embed_with(
+    embedder_id: u64,
+    texts: Vec<String>,
+) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+    embedder_handle(embedder_id)?.embed(texts)
+}
+
+/// Same as `embed_with`, but runs off the Dart isolate.
+pub fn embed_with_async(
+    embedder_id: u64,
+    texts: Vec<String>,
+) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+    embedder_handle(embedder_id)?.embed(texts)
+}
+
+/// Model family of the embedder loaded under `embedder_id`.
+#[frb(sync)]
+pub fn embedder_kind(embedder_id: u64) -> Result<EmbedderKind, FlutterEmbedderError> {
+    Ok(embedder_handle(embedder_id)?.kind)
+}
+
+/// Removes an embedder from the store. Its session and tokenizer are freed
+/// once in-flight calls on it have finished. Returns whether `embedder_id`
+/// was loaded.
+#[frb(sync)]
+pub fn dispose_embedder(embedder_id: u64) -> Result<bool, FlutterEmbedderError> {
+    let mut guard = embedder_store()
+        .write()
+        .map_err(|e| err!(Internal, "Failed to acquire embedder store: {e}"))?;
+    Ok(guard.remove(&embedder_id).is_some())
+}
+
+/// Ids of all loaded embedders, ascending.
+#[frb(sync)]
+pub fn list_embedders() -> Result<Vec<u64>, FlutterEmbedderError> {
+    let guard = embedder_store()
+        .read()
+        .map_err(|e| err!(Internal, "Failed to acquire embedder store: {e}"))?;
+    let mut ids: Vec<u64> = guard.keys().copied().collect();
+    ids.sort_unstable();
+    Ok(ids)
+}
+
+/// Embedding size declared by the model outputs; `None` when the export
+/// leaves it dynamic.
+pub(crate) fn declared_dimension(session: &ort::session::Session) -> Option<usize> {
+    output_dimension(session, &EMBEDDING_OUTPUTS)
+}
+
+/// Last axis of the first output in `names` the session has, if static.
+pub(crate) fn output_dimension(session: &ort::session::Session, names: &[&str]) -> Option<usize> {
+    names
+        .iter()
+        .find_map(|name| {
+            let output = session.outputs().iter().find(|o| o.name() == *name)?;
+            match output.dtype() {
+                ValueType::Tensor { shape, .. } => Some(shape.last().copied().unwrap_or(-1)),
+                _ => None,
+            }
+        })
+        .filter(|&dim| dim > 0)
+        .map(|dim| dim as usize)
+}
+
+/// Measures the embedding size with a dry run through `embed`.
+pub(crate) fn probe_dimension(
+    embed: impl FnOnce(Vec<String>) -> Result<Vec<Vec<f32>>>,
+) -> Result<usize> {
+    embed(vec![DIMENSION_PROBE.to_string()])?
+        .first()
+        .map(Vec::len)
+        .ok_or(err!(MissingOutput, "Model produced no embedding"))
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct EmbedOptions {
+    /// Matryoshka truncation: keep the first `output_dim` dimensions and
+    /// renormalize, e.g. 256 instead of 768. Only meaningful for models
+    /// trained for it (Gemma, Nomic, Qwen3). `None` keeps the full vector.
+    pub output_dim: Option<u32>,
+}
+
+/// Truncates each embedding to `output_dim` and renormalizes it. Scaling
+/// does not change the direction of the kept prefix, so this is correct on
+/// both raw and already-normalized outputs.
+pub(crate) fn truncate_embeddings(
+    embeddings: Vec<Vec<f32>>,
+    output_dim: Option<u32>,
+) -> Result<Vec<Vec<f32>>> {
+    let Some(dim) = output_dim.map(|dim| dim as usize) else {
+        return Ok(embeddings);
+    };
+    embeddings
+        .into_iter()
+        .map(|embedding| {
+            if dim == 0 || dim > embedding.len() {
+                return Err(err!(
+                    InvalidInput,
+                    "output_dim must be between 1 and {}",
+                    embedding.len()
+                ));
+            }
+            Ok(normalize(&embedding[..dim]))
+        })
+        .collect()
+}
+
+/// Where the time of one `embed_with_stats` call went.
+#[derive(Debug, Clone, Default)]
+pub struct EmbedStats {
+    pub tokenization_ms: f64,
+    /// Tensor building, model runs and pooling, across all micro-batches.
+    pub inference_ms: f64,
+    /// Tokens fed to the model, excluding padding.
+    pub tokens: u64,
+    /// `tokens` over the whole call, tokenization included.
+    pub tokens_per_second: f64,
+    /// Peak resident memory of the process so far. `None` where the OS
+    /// doesn't report it (only Linux and Android do).
+    pub peak_memory_bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EmbeddingsWithStats {
+    pub embeddings: Vec<Vec<f32>>,
+    pub stats: EmbedStats,
+}
+
+/// One window produced by `Embedder::embed_windows`.
+#[derive(Debug, Clone)]
+pub struct PassageEmbedding {
+    pub passage: TextChunk,
+    pub embedding: Vec<f32>,
+}
+
+/// `VmHWM` from `/proc/self/status`.
+pub(crate) fn peak_memory_bytes() -> Option<u64> {
+    if !cfg!(any(target_os = "linux", target_os = "android")) {
+        return None;
+    }
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kib: u64 = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kib * 1024)
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct EmbedManyOptions {
+    /// Texts per inference call (default 32).
+    pub batch_size: Option<u32>,
+    /// Groups texts of similar length into the same batch to cut padding
+    /// (default true). Results are always returned in input order.
+    pub sort_by_length: Option<bool>,
+}
+
+/// Limits for the micro-batches `embed` splits its input into, so one long
+/// text can't pad a large batch into an out-of-memory tensor. Texts are
+/// grouped by token count and results are returned in input order.
+#[derive(Debug, Clone, Default)]
+pub struct BatchOptions {
+    /// Texts per inference call (default 32).
+    pub max_batch_size: Option<u32>,
+    /// Padded tokens (texts × longest text) per inference call (default
+    /// 16384). A text longer than this still runs, on its own.
+    pub max_tokens_per_batch: Option<u32>,
+}
+
+/// Progress of an `embed_many` call. Create one, pass it in and poll it from
+/// another isolate or a timer while the call runs.
+#[frb(opaque)]
+#[derive(Debug, Default)]
+pub struct EmbedProgress {
+    completed: AtomicU32,
+    total: AtomicU32,
+}
+
+#[frb(sync)]
+impl EmbedProgress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn completed(&self) -> u32 {
+        self.completed.load(Ordering::Relaxed)
+    }
+
+    pub fn total(&self) -> u32 {
+        self.total.load(Ordering::Relaxed)
+    }
+}
+
+impl EmbedProgress {
+    fn start(&self, total: usize) {
+        self.completed.store(0, Ordering::Relaxed);
+        self.total.store(total as u32, Ordering::Relaxed);
+    }
+
+    fn advance(&self, count: usize) {
+        self.completed.fetch_add(count as u32, Ordering::Relaxed);
+    }
+}
+
+/// Cooperative cancellation for long embedding calls. Pass it to a
+/// `*_cancellable` method and call `cancel` from Dart (e.g. when the screen
+/// is closed); the call stops before its next micro-batch.
+#[frb(opaque)]
+#[derive(Debug, Default)]
+pub struct CancellationToken {
+    cancelled: AtomicBool,
+}
+
+#[frb(sync)]
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Runs `embed` over `texts` in length-sorted chunks and scatters the vectors
+/// back into input order. Shared by the `embed_many` method of each embedder.
+pub(crate) fn embed_many_with(
+    mut texts: Vec<String>,
+    options: Option<EmbedManyOptions>,
+    progress: Option<&EmbedProgress>,
+    cancel: Option<&CancellationToken>,
+    mut embed: impl FnMut(Vec<String>) -> Result<Vec<Vec<f32>>>,
+) -> Result<Vec<Vec<f32>>> {
+    let options = options.unwrap_or_default();
+    let batch_size = options
+        .batch_size
+        .map(|b| b as usize)
+        .unwrap_or(DEFAULT_EMBED_MANY_BATCH_SIZE)
+        .max(1);
+    let total = texts.len();
+    if let Some(progress) = progress {
+        progress.start(total);
+    }
+
+    let mut order: Vec<usize> = (0..total).collect();
+    if options.sort_by_length.unwrap_or(true) {
+        // Byte length is a cheap proxy for token count; good enough to group.
+        order.sort_by_key(|&i| texts[i].len());
+    }
+
+    let mut results = vec![Vec::new(); total];
+    for chunk in order.chunks(batch_size) {
+        if cancel.is_some_and(CancellationToken::is_cancelled) {
+            return Err(err!(Cancelled, "Embedding cancelled"));
+        }
+        let batch: Vec<String> = chunk
+            .iter()
+            .map(|&i| std::mem::take(&mut texts[i]))
+            .collect();
+        let vectors = embed(batch)?;
+        if vectors.len() != chunk.len() {
+            return Err(err!(
+                InvalidInput,
+                "Expected {} embeddings, got {}",
+                chunk.len(),
+                vectors.len()
+            ));
+        }
+        for (&i, vector) in chunk.iter().zip(vectors) {
+            results[i] = vector;
+        }
+        if let Some(progress) = progress {
+            progress.advance(chunk.len());
+        }
+    }
+    Ok(results)
+}
+
+/// One batch of results from `embed_stream`.
+#[derive(Debug, Clone)]
+pub struct EmbeddingProgress {
+    /// Input index of the first text in `embeddings`.
+    pub offset: u32,
+    pub embeddings: Vec<Vec<f32>>,
+    /// Texts embedded so far, this batch included.
+    pub completed: u32,
+    pub total: u32,
+}
+
+/// Runs `embed` over consecutive `batch_size` slices of `texts`, sending each
+/// result to `sink`. Stops early once `cancel` is cancelled or the Dart side
+/// stops listening; batches already sent stay valid.
+pub(crate) fn embed_stream_with(
+    texts: Vec<String>,
+    batch_size: u32,
+    cancel: Option<&CancellationToken>,
+    sink: &StreamSink<EmbeddingProgress>,
+    mut embed: impl FnMut(Vec<String>) -> Result<Vec<Vec<f32>>>,
+) -> Result<()> {
+    let total = texts.len();
+    let batch_size = (batch_size as usize).max(1);
+    let mut texts = texts.into_iter();
+    let mut offset = 0;
+    while offset < total {
+        if cancel.is_some_and(CancellationToken::is_cancelled) {
+            break;
+        }
+        let batch: Vec<String> = texts.by_ref().take(batch_size).collect();
+        let len = batch.len();
+        let embeddings = embed(batch)?;
+        if embeddings.len() != len {
+            return Err(err!(
+                InvalidInput,
+                "Expected {} embeddings, got {}",
+                len,
+                embeddings.len()
+            ));
+        }
+        let progress = EmbeddingProgress {
+            offset: offset as u32,
+            embeddings,
+            completed: (offset + len) as u32,
+            total: total as u32,
+        };
+        if sink.add(progress).is_err() {
+            break;
+        }
+        offset += len;
+    }
+    Ok(())
+}
+
+/// Unpooled per-token hidden states for a single input text.
+#[derive(Debug, Clone)]
+pub struct TokenEmbeddings {
+    pub tokens: Vec<String>,
+    pub offsets: Vec<TokenOffsets>,
+    /// One hidden-state vector per (non-padding) token.
+    pub embeddings: Vec<Vec<f32>>,
+}
+
+/// Pads a batch of encodings to the longest sequence, returning
+/// `(max_len, input_ids, attention_mask)` flattened row-major.
+pub(crate) fn pad_encodings(
+    encodings: &[tokenizers::Encoding],
+    pad_id: i64,
+) -> (usize, Vec<i64>, Vec<i64>) {
+    let max_len = encodings
+        .iter()
+        .map(|e| e.get_ids().len())
+        .max()
+        .unwrap_or(0);
+    let input_ids = pad_rows(encodings, max_len, pad_id, tokenizers::Encoding::get_ids);
+    let mask = pad_rows(
+        encodings,
+        max_len,
+        0,
+        tokenizers::Encoding::get_attention_mask,
+    );
+    (max_len, input_ids, mask)
+}
+
+/// `field` of every encoding flattened row-major into `[encodings, len]`,
+/// each row padded with `pad` or cut to `len`.
+pub(crate) fn pad_rows(
+    encodings: &[tokenizers::Encoding],
+    len: usize,
+    pad: i64,
+    field: fn(&tokenizers::Encoding) -> &[u32],
+) -> Vec<i64> {
+    let mut rows = Vec::with_capacity(encodings.len() * len);
+    for encoding in encodings {
+        let values = field(encoding);
+        rows.extend(values.iter().take(len).map(|&x| x as i64));
+        rows.extend(std::iter::repeat_n(pad, len.saturating_sub(values.len())));
+    }
+    rows
+}
+
+/// Joins `(instruction, text)` pairs into model inputs, returning the texts
+/// and the byte length of each rendered instruction prefix.
+pub(crate) fn apply_instructions(
+    items: Vec<(Option<String>, String)>,
+    render: impl Fn(&str) -> String,
+) -> (Vec<String>, Vec<usize>) {
+    items
+        .into_iter()
+        .map(|(instruction, text)| match instruction {
+            Some(instruction) => {
+                let prefix = render(&instruction);
+                let len = prefix.len();
+                (prefix + &text, len)
+            }
+            None => (text, 0),
+        })
+        .unzip()
+}
+
+/// Drops the leading tokens that cover the first `prefix_len` bytes (the
+/// instruction, plus the leading special token) from a pooling mask, as
+/// Instructor-style models expect. Leaves the mask alone if nothing would
+/// remain.
+pub(crate) fn exclude_prefix_from_pooling(
+    mask: &mut [u32],
+    offsets: &[(usize, usize)],
+    prefix_len: usize,
+) {
+    if prefix_len == 0 {
+        return;
+    }
+    let prefix_tokens = offsets
+        .iter()
+        .take_while(|(_, end)| *end <= prefix_len)
+        .count();
+    let attended = mask.iter().filter(|&&m| m != 0).count();
+    if prefix_tokens >= attended {
+        return;
+    }
+    mask.iter_mut().take(prefix_tokens).for_each(|m| *m = 0);
+}
+
+/// Splits a `[batch, seq_len, hidden]` tensor into per-text token embeddings,
+/// dropping padding positions.
+pub(crate) fn token_embeddings_from_hidden(
+    encodings: &[tokenizers::Encoding],
+    shape: &[usize],
+    data: &[f32],
+) -> Result<Vec<TokenEmbeddings>> {
+    if shape.len() != 3 {
+        return Err(err!(
+            ShapeMismatch,
+            "Unexpected hidden state shape: {shape:?}"
+        ));
+    }
+    let (batch, seq_len, hidden_dim) = (shape[0], shape[1], shape[2]);
+    if batch != encodings.len() {
+        return Err(err!(ShapeMismatch, "Batch size mismatch in outputs"));
+    }
+
+    let mut results = Vec::with_capacity(batch);
+    for (i, encoding) in encodings.iter().enumerate() {
+        let mask = encoding.get_attention_mask();
+        let mut tokens = Vec::new();
+        let mut offsets = Vec::new();
+        let mut embeddings = Vec::new();
+        for (t, &m) in mask.iter().enumerate().take(seq_len) {
+            if m == 0 {
+                continue;
+            }
+            let start = (i * seq_len + t) * hidden_dim;
+            let slice = data
+                .get(start..start + hidden_dim)
+                .ok_or(err!(ShapeMismatch, "Invalid hidden state slice"))?;
+            let (s, e) = encoding.get_offsets()[t];
+            tokens.push(encoding.get_tokens()[t].clone());
+            offsets.push(TokenOffsets {
+                start: s as u32,
+                end: e as u32,
+            });
+            embeddings.push(slice.to_vec());
+        }
+        results.push(TokenEmbeddings {
+            tokens,
+            offsets,
+            embeddings,
+        });
+    }
+    Ok(results)
+}
+
+/// Late chunking: mean-pools the token vectors of one long text over each
+/// chunk's span and normalizes the result, so every chunk embedding carries
+/// context from the whole text. `prefix_len` bytes were prepended to `text`
+/// before encoding. Chunks with no tokens, e.g. past the model's maximum
+/// length, are an error.
+pub(crate) fn pool_chunks(
+    text: &str,
+    prefix_len: usize,
+    tokens: &TokenEmbeddings,
+    chunks: &[TextChunk],
+) -> Result<Vec<Vec<f32>>> {
+    let char_to_byte: Vec<usize> = text
+        .char_indices()
+        .map(|(byte, _)| byte)
+        .chain(std::iter::once(text.len()))
+        .collect();
+    let hidden_dim = tokens.embeddings.first().map_or(0, Vec::len);
+    chunks
+        .iter()
+        .map(|chunk| {
+            let byte = |offset: u32| {
+                char_to_byte
+                    .get(offset as usize)
+                    .map(|&byte| byte + prefix_len)
+                    .ok_or(err!(InvalidInput, "Chunk offsets out of range"))
+            };
+            let (start, end) = (byte(chunk.start)?, byte(chunk.end)?);
+            let mut sum = vec![0.0; hidden_dim];
+            let mut count = 0;
+            for (offsets, vector) in tokens.offsets.iter().zip(&tokens.embeddings) {
+                let (s, e) = (offsets.start as usize, offsets.end as usize);
+                // Special tokens have empty spans.
+                if s == e || s < start || s >= end {
+                    continue;
+                }
+                for (acc, x) in sum.iter_mut().zip(vector) {
+                    *acc += x;
+                }
+                count += 1;
+            }
+            if count == 0 {
+                return Err(err!(
+                    InvalidInput,
+                    "Chunk {}..{} has no tokens within the model's context",
+                    chunk.start,
+                    chunk.end
+                ));
+            }
+            Ok(normalize(&sum))
+        })
+        .collect()
+}
+
+/// Attention-derived importance of each (non-padding) token of one input.
+#[derive(Debug, Clone)]
+pub struct TokenSalience {
+    pub tokens: Vec<String>,
+    pub offsets: Vec<TokenOffsets>,
+    /// Scores sum to 1 over the returned tokens.
+    pub scores: Vec<f32>,
+}
+
+/// Finds an attention output: the explicitly requested name, otherwise the
+/// last output whose name mentions "attention".
+pub(crate) fn pick_attention_tensor(
+    outputs: &ort::session::SessionOutputs<'_>,
+    name: Option<&str>,
+) -> Result<(Vec<usize>, Vec<f32>)> {
+    let key = match name {
+        Some(name) => name.to_string(),
+        None => outputs
+            .keys()
+            .filter(|k| k.contains("attention"))
+            .last()
+            .map(|k| k.to_string())
+            .ok_or(err!(
+                MissingOutput,
+                "Model does not expose attention outputs"
+            ))?,
+    };
+    let (shape, data) = outputs
+        .get(key.as_str())
+        .ok_or(err!(MissingOutput, "Missing output {key}"))?
+        .try_extract_tensor::<f32>()?;
+    Ok((shape.iter().map(|d| *d as usize).collect(), data.to_vec()))
+}
+
+/// Averages attention over heads and non-padding query positions to get the
+/// attention each token receives. Accepts `[batch, heads, seq, seq]` or
+/// head-averaged `[batch, seq, seq]` tensors.
+pub(crate) fn salience_from_attention(
+    encodings: &[tokenizers::Encoding],
+    shape: &[usize],
+    data: &[f32],
+) -> Result<Vec<TokenSalience>> {
+    let (batch, heads, seq_len) = match shape {
+        [b, h, q, k] if q == k => (*b, *h, *q),
+        [b, q, k] if q == k => (*b, 1, *q),
+        _ => return Err(err!(ShapeMismatch, "Unexpected attention shape: {shape:?}")),
+    };
+    if batch != encodings.len() {
+        return Err(err!(ShapeMismatch, "Batch size mismatch in outputs"));
+    }
+
+    let mut results = Vec::with_capacity(batch);
+    for (i, encoding) in encodings.iter().enumerate() {
+        let mask: Vec<bool> = (0..seq_len)
+            .map(|t| encoding.get_attention_mask().get(t).copied().unwrap_or(0) != 0)
+            .collect();
+        let mut received = vec![0.0f32; seq_len];
+        for h in 0..heads {
+            for (q, _) in mask.iter().enumerate().filter(|(_, &m)| m) {
+                let start = ((i * heads + h) * seq_len + q) * seq_len;
+                let row = data
+                    .get(start..start + seq_len)
+                    .ok_or(err!(ShapeMismatch, "Invalid attention slice"))?;
+                for (acc, value) in received.iter_mut().zip(row) {
+                    *acc += value;
+                }
+            }
+        }
+
+        let mut tokens = Vec::new();
+        let mut offsets = Vec::new();
+        let mut scores = Vec::new();
+        for (t, _) in mask.iter().enumerate().filter(|(_, &m)| m) {
+            let (s, e) = encoding.get_offsets()[t];
+            tokens.push(encoding.get_tokens()[t].clone());
+            offsets.push(TokenOffsets {
+                start: s as u32,
+                end: e as u32,
+            });
+            scores.push(received[t]);
+        }
+        let total: f32 = scores.iter().sum();
+        if total > 0.0 {
+            scores.iter_mut().for_each(|s| *s /= total);
+        }
+        results.push(TokenSalience {
+            tokens,
+            offsets,
+            scores,
+        });
+    }
+    Ok(results)
+}
@@ -1,22 +1,43 @@
-use anyhow::{anyhow, Result};
+use std::sync::Arc;
+
 use flutter_rust_bridge::frb;
-use ort::value::Tensor;
+use ort::session::Session;
 
-use crate::api::ort::{build_session_from_file_with_init, OrtInitOptions};
-use crate::api::utils::normalize;
+use crate::api::chunking::TextChunk;
+use crate::api::embeddings::pipeline::{
+    self, output_names, InputNames, InputStyle, ModelSpec, Pooling,
+};
+use crate::api::embeddings::{
+    apply_instructions, declared_dimension, embed_many_with, embed_stream_with, load_replacement,
+    loaded, pick_attention_tensor, pool_chunks, probe_dimension, salience_from_attention,
+    BatchOptions, CancellationToken, EmbedManyOptions, EmbedProgress, EmbedStats,
+    EmbeddingProgress, EmbeddingsWithStats, TextEmbedder, TokenEmbeddings, TokenSalience,
+    WARMUP_TEXT,
+};
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::hub::{resolve_hub_files, HubOptions};
+use crate::api::ort::{
+    build_session_from_file_with_init, build_session_from_memory_with_init, is_deterministic,
+    session_model_info, ModelInfo, OrtInitOptions, SessionRouter, Workload,
+};
+use crate::frb_generated::StreamSink;
 
 pub const PREFIX_QUERY: &str = "Represent this sentence for searching relevant passages: ";
 pub const PREFIX_DOCUMENT: &str = "";
 
 #[frb(opaque)]
 pub struct BgeEmbedder {
-    tokenizer: tokenizers::Tokenizer,
-    session: ort::session::Session,
+    tokenizer: Option<Arc<tokenizers::Tokenizer>>,
+    session: SessionRouter,
+    spec: ModelSpec,
 }
 
 #[frb(sync)]
 impl BgeEmbedder {
-    pub fn create(model_path: String, tokenizer_path: String) -> Result<Self> {
+    pub fn create(
+        model_path: String,
+        tokenizer_path: String,
+    ) -> Result<Self, FlutterEmbedderError> {
         Self::create_with_options(model_path, tokenizer_path, None)
     }
 
@@ -24,113 +45,264 @@ impl BgeEmbedder {
         model_path: String,
         tokenizer_path: String,
         ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer = tokenizers::Tokenizer::from_file(tokenizer_path)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let deterministic = is_deterministic(ort_options.as_ref());
+        let session = build_session_from_file_with_init(model_path, ort_options)?;
+        Self::from_parts(tokenizer, session, deterministic)
+    }
+
+    /// `create` from in-memory model bytes and tokenizer JSON, e.g. for
+    /// encrypted or downloaded assets that must not be written to disk.
+    pub fn create_from_bytes(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_bytes_with_options(model_bytes, tokenizer_json, None)
+    }
+
+    pub fn create_from_bytes_with_options(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer = tokenizers::Tokenizer::from_bytes(tokenizer_json)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let deterministic = is_deterministic(ort_options.as_ref());
+        let session = build_session_from_memory_with_init(&model_bytes, ort_options)?;
+        Self::from_parts(tokenizer, session, deterministic)
+    }
+
+    fn from_parts(
+        tokenizer: tokenizers::Tokenizer,
+        session: Session,
+        deterministic: bool,
     ) -> Result<Self> {
-        let tokenizer =
-            tokenizers::Tokenizer::from_file(tokenizer_path).map_err(|e| anyhow::anyhow!(e))?;
+        Ok(Self {
+            tokenizer: Some(Arc::new(tokenizer)),
+            session: SessionRouter::new(session),
+            spec: ModelSpec {
+                inputs: InputStyle::Encoder,
+                input_names: InputNames::standard(),
+                outputs: output_names(&[
+                    "sentence_embedding",
+                    "pooled_output",
+                    "pooler_output",
+                    "embedding",
+                    "last_hidden_state",
+                ]),
+                pooling: Pooling::Cls,
+                normalize: true,
+                query_prefix: PREFIX_QUERY.to_string(),
+                document_prefix: PREFIX_DOCUMENT.to_string(),
+                batching: BatchOptions::default(),
+                deterministic,
+            },
+        })
+    }
+
+    /// Registers an extra session of the same model for `workload`, e.g. a
+    /// CoreML/NNAPI session for interactive queries or a single-threaded CPU
+    /// session for indexing. Calls without a matching route use the session
+    /// the embedder was created with.
+    pub fn add_session(
+        &mut self,
+        workload: Workload,
+        model_path: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<(), FlutterEmbedderError> {
         let session = build_session_from_file_with_init(model_path, ort_options)?;
+        self.session.set_route(workload, session)
+    }
 
-        Ok(Self { tokenizer, session })
+    pub fn remove_session(&mut self, workload: Workload) -> bool {
+        self.session.remove_route(workload)
     }
 
-    pub fn embed(&mut self, texts: Vec<String>) -> Result<Vec<Vec<f32>>> {
-        if texts.is_empty() {
+    /// Keeps `size` sessions of the model for `workload` (the sessions the
+    /// embedder was created with when no route is registered), so that many
+    /// concurrent calls can run in parallel. Each extra session is loaded from
+    /// `model_path` with its own copy of the weights; shrinking drops the
+    /// extras.
+    pub fn set_pool_size(
+        &mut self,
+        workload: Workload,
+        size: u32,
+        model_path: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<(), FlutterEmbedderError> {
+        self.session.resize_pool(workload, size as usize, || {
+            build_session_from_file_with_init(model_path.clone(), ort_options.clone())
+        })
+    }
+
+    /// Number of sessions serving `workload`.
+    pub fn pool_size(&self, workload: Workload) -> u32 {
+        self.session.pool_size(workload) as u32
+    }
+
+    /// `embed` on the session registered for `workload`.
+    pub fn embed_with_workload(
+        &self,
+        texts: Vec<String>,
+        workload: Workload,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        pipeline::embed(
+            &mut *self.session.acquire(workload)?,
+            loaded(&self.tokenizer)?,
+            &self.spec,
+            texts,
+            &[],
+            &[],
+            None,
+        )
+    }
+
+    /// Embeds `(instruction, text)` pairs, so one batch can mix queries,
+    /// passages and classification inputs. Each instruction is prepended to
+    /// its text as-is.
+    pub fn embed_with_instructions(
+        &self,
+        items: Vec<(Option<String>, String)>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        let (texts, _) = apply_instructions(items, str::to_string);
+        self.embed(texts)
+    }
+
+    pub fn embed(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.embed_with_workload(texts, Workload::Interactive)
+    }
+
+    /// `embed` plus tokenization/inference timings and peak memory.
+    pub fn embed_with_stats(
+        &self,
+        texts: Vec<String>,
+    ) -> Result<EmbeddingsWithStats, FlutterEmbedderError> {
+        let mut stats = EmbedStats::default();
+        let embeddings = pipeline::embed(
+            &mut *self.session.acquire(Workload::Interactive)?,
+            loaded(&self.tokenizer)?,
+            &self.spec,
+            texts,
+            &[],
+            &[],
+            Some(&mut stats),
+        )?;
+        Ok(EmbeddingsWithStats { embeddings, stats })
+    }
+
+    /// Returns per-token `last_hidden_state` vectors (with offsets) instead of
+    /// a pooled embedding, for late-interaction scoring or span highlighting.
+    pub fn embed_tokens(
+        &self,
+        texts: Vec<String>,
+    ) -> Result<Vec<TokenEmbeddings>, FlutterEmbedderError> {
+        pipeline::token_embeddings(
+            &mut *self.session.acquire(Workload::Interactive)?,
+            loaded(&self.tokenizer)?,
+            &self.spec,
+            texts,
+            &[],
+        )
+    }
+
+    /// Late chunking: runs the whole `text` through the model once, then
+    /// mean-pools each chunk's tokens (chunks from e.g. `split_by_sentences`),
+    /// so every chunk vector keeps the context around it. `text` must fit
+    /// in the model's context.
+    pub fn embed_late_chunks(
+        &self,
+        text: String,
+        chunks: Vec<TextChunk>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        if chunks.is_empty() {
             return Ok(Vec::new());
         }
-        let encodings = self
-            .tokenizer
-            .encode_batch(texts, true)
-            .map_err(|e| anyhow::anyhow!(e))?;
-
-        let pad_id = self
-            .tokenizer
-            .get_padding()
-            .map(|p| p.pad_id as i64)
-            .unwrap_or(0);
-
-        let batch = encodings.len();
-        let max_len = encodings
-            .iter()
-            .map(|e| e.get_ids().len())
-            .max()
-            .unwrap_or(0);
-        if max_len == 0 {
-            return Ok(vec![Vec::new(); batch]);
+        let tokens = self.embed_tokens(vec![text.clone()])?;
+        let tokens = tokens
+            .first()
+            .ok_or(err!(MissingOutput, "Model produced no token embeddings"))?;
+        pool_chunks(&text, 0, tokens, &chunks)
+    }
+
+    /// Per-token salience derived from the model's attention outputs, aligned
+    /// to character offsets. Requires an ONNX export that exposes attentions;
+    /// `attention_output` selects the output name when there are several.
+    pub fn explain_tokens(
+        &self,
+        texts: Vec<String>,
+        attention_output: Option<String>,
+    ) -> Result<Vec<TokenSalience>, FlutterEmbedderError> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
         }
+        let mut session = self.session.acquire(Workload::Interactive)?;
+        let (encodings, outputs) = self.forward(&mut session, texts)?;
+        let (shape, data) = pick_attention_tensor(&outputs, attention_output.as_deref())?;
+        salience_from_attention(&encodings, &shape, &data)
+    }
 
-        let mut input_ids_batch = Vec::with_capacity(batch * max_len);
-        let mut mask_batch = Vec::with_capacity(batch * max_len);
-        for encoding in encodings {
-            let ids = encoding.get_ids();
-            let mask = encoding.get_attention_mask();
-            let pad_len = max_len.saturating_sub(ids.len());
+    fn forward<'s>(
+        &self,
+        session: &'s mut Session,
+        texts: Vec<String>,
+    ) -> Result<(Vec<tokenizers::Encoding>, ort::session::SessionOutputs<'s>)> {
+        pipeline::forward(session, loaded(&self.tokenizer)?, &self.spec, texts, &[])
+    }
 
-            let mut ids_i64: Vec<i64> = ids.iter().map(|&x| x as i64).collect();
-            let mut mask_i64: Vec<i64> = mask.iter().map(|&x| x as i64).collect();
-            ids_i64.extend(std::iter::repeat(pad_id).take(pad_len));
-            mask_i64.extend(std::iter::repeat(0).take(pad_len));
+    /// Limits for the micro-batches `embed` splits large inputs into.
+    pub fn set_batch_options(&mut self, options: BatchOptions) {
+        self.spec.batching = options;
+    }
 
-            input_ids_batch.extend_from_slice(&ids_i64);
-            mask_batch.extend_from_slice(&mask_i64);
-        }
+    /// Inputs, outputs and metadata of the loaded ONNX model.
+    pub fn model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&*self.session.acquire(Workload::Interactive)?)
+    }
 
-        let mut inputs = ort::inputs! {
-            "input_ids" => Tensor::from_array(([batch, max_len], input_ids_batch))?,
-            "attention_mask" => Tensor::from_array(([batch, max_len], mask_batch))?,
-        };
-        if self
-            .session
-            .inputs()
-            .iter()
-            .any(|input| input.name() == "token_type_ids")
-        {
-            inputs.push((
-                "token_type_ids".into(),
-                Tensor::from_array(([batch, max_len], vec![0i64; batch * max_len]))?.into(),
-            ));
+    /// Length of the vectors `embed` returns, read from the model outputs
+    /// or, when the export leaves it dynamic, from a one-token dry run.
+    pub fn dimension(&self) -> Result<usize, FlutterEmbedderError> {
+        if let Some(dim) = declared_dimension(&*self.session.acquire(Workload::Interactive)?) {
+            return Ok(dim);
         }
+        probe_dimension(|texts| self.embed(texts))
+    }
 
-        let outputs = self.session.run(inputs)?;
-        let (shape, data) = pick_embedding_tensor(&outputs)?;
-        if shape.len() == 2 {
-            let out_batch = shape[0];
-            let hidden = shape[1];
-            if out_batch != batch {
-                return Err(anyhow!("Batch size mismatch in outputs"));
-            }
-            let mut results = Vec::with_capacity(batch);
-            for i in 0..batch {
-                let start = i * hidden;
-                let end = start + hidden;
-                let slice = data
-                    .get(start..end)
-                    .ok_or(anyhow!("Invalid output slice"))?;
-                results.push(normalize(slice));
-            }
-            return Ok(results);
-        }
-        if shape.len() != 3 {
-            return Err(anyhow!("Unexpected output shape: {shape:?}"));
-        }
+    /// Frees the ONNX sessions and tokenizer now rather than when the object
+    /// is dropped, e.g. before loading another model on a memory-constrained
+    /// device. Later calls fail with `ErrorKind::Closed`.
+    pub fn close(&mut self) {
+        self.session.close();
+        self.tokenizer = None;
+    }
 
-        let out_batch = shape[0];
-        let seq_len = shape[1];
-        let hidden_dim = shape[2];
-        if out_batch != batch {
-            return Err(anyhow!("Batch size mismatch in outputs"));
-        }
+    pub fn is_closed(&self) -> bool {
+        self.session.is_closed()
+    }
 
-        let mut results = Vec::with_capacity(batch);
-        for i in 0..batch {
-            let cls_index = 0usize.min(seq_len.saturating_sub(1));
-            let start = (i * seq_len + cls_index) * hidden_dim;
-            let end = start + hidden_dim;
-            let slice = data
-                .get(start..end)
-                .ok_or(anyhow!("Invalid CLS slice"))?;
-            results.push(normalize(slice));
+    /// Replaces the model, e.g. with a larger variant of the same family,
+    /// without recreating the embedder. The new session is built before the
+    /// old one is dropped, so a failed load leaves the embedder untouched.
+    /// The tokenizer is kept unless `tokenizer_path` is given. Sessions added
+    /// with `add_session` or `set_pool_size` belong to the old model and are
+    /// dropped.
+    pub fn reload_model(
+        &mut self,
+        model_path: String,
+        tokenizer_path: Option<String>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<(), FlutterEmbedderError> {
+        let deterministic = is_deterministic(ort_options.as_ref());
+        let (session, tokenizer) =
+            load_replacement(&self.tokenizer, model_path, tokenizer_path, ort_options)?;
+        self.session = SessionRouter::new(session);
+        self.spec.deterministic = deterministic;
+        if let Some(tokenizer) = tokenizer {
+            self.tokenizer = Some(tokenizer);
         }
-
-        Ok(results)
+        Ok(())
     }
 
     pub fn format_query(query: String) -> String {
@@ -144,28 +316,177 @@ impl BgeEmbedder {
             format!("{PREFIX_DOCUMENT}{text}")
         }
     }
+
+    /// Formats every query with `format_query` and embeds the batch.
+    pub fn embed_queries(
+        &self,
+        queries: Vec<String>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        let queries = queries.into_iter().map(Self::format_query).collect();
+        self.embed(queries)
+    }
+
+    /// Formats every text with `format_document` and embeds the batch.
+    pub fn embed_documents(
+        &self,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        let texts = texts.into_iter().map(Self::format_document).collect();
+        self.embed(texts)
+    }
 }
 
-fn pick_embedding_tensor(
-    outputs: &ort::session::SessionOutputs<'_>,
-) -> Result<(Vec<usize>, Vec<f32>)> {
-    for key in [
-        "sentence_embedding",
-        "pooled_output",
-        "pooler_output",
-        "embedding",
-    ] {
-        if let Some(t) = outputs.get(key) {
-            let (shape, data) = t.try_extract_tensor::<f32>()?;
-            let shape_usize = shape.iter().map(|d| *d as usize).collect();
-            return Ok((shape_usize, data.to_vec()));
+impl BgeEmbedder {
+    /// `create` from a Hugging Face Hub repo, downloading the model and
+    /// `tokenizer.json` into `cache_dir` on first use.
+    pub fn create_from_hub(
+        repo_id: String,
+        cache_dir: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_hub_with_options(repo_id, cache_dir, None, None)
+    }
+
+    pub fn create_from_hub_with_options(
+        repo_id: String,
+        cache_dir: String,
+        hub_options: Option<HubOptions>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let (model_path, tokenizer_path) = resolve_hub_files(&repo_id, &cache_dir, hub_options)?;
+        Self::create_with_options(model_path, tokenizer_path, ort_options)
+    }
+
+    /// Runs a one-word batch through every session (see `add_session`) so
+    /// the first real `embed` doesn't pay for graph initialization.
+    pub fn warmup(&self) -> Result<(), FlutterEmbedderError> {
+        for workload in self.session.session_workloads() {
+            self.embed_with_workload(vec![WARMUP_TEXT.to_string()], workload)?;
         }
+        Ok(())
+    }
+
+    /// Same as `embed`, but not `sync` on the Dart side: inference runs on a
+    /// worker thread instead of blocking the calling isolate.
+    pub fn embed_async(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.embed(texts)
     }
-    if let Some(t) = outputs.get("last_hidden_state") {
-        let (shape, data) = t.try_extract_tensor::<f32>()?;
-        let shape_usize = shape.iter().map(|d| *d as usize).collect();
-        Ok((shape_usize, data.to_vec()))
-    } else {
-        Err(anyhow!("No embedding tensor found in outputs"))
+
+    /// Batch API for indexing large corpora: embeds in length-sorted chunks
+    /// (see `EmbedManyOptions`) on the `Workload::Bulk` session. Use `embed`
+    /// for small, latency-sensitive calls.
+    pub fn embed_many(
+        &self,
+        texts: Vec<String>,
+        options: Option<EmbedManyOptions>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        embed_many_with(texts, options, None, None, |batch| {
+            self.embed_with_workload(batch, Workload::Bulk)
+        })
+    }
+
+    /// Same as `embed_many`, updating `progress` after every chunk.
+    pub fn embed_many_with_progress(
+        &self,
+        texts: Vec<String>,
+        options: Option<EmbedManyOptions>,
+        progress: &EmbedProgress,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        embed_many_with(texts, options, Some(progress), None, |batch| {
+            self.embed_with_workload(batch, Workload::Bulk)
+        })
+    }
+
+    /// Embeds `texts` in input order, `batch_size` at a time on the
+    /// `Workload::Bulk` session, sending each batch to `sink` as soon as it
+    /// is ready.
+    pub fn embed_stream(
+        &self,
+        texts: Vec<String>,
+        batch_size: u32,
+        sink: StreamSink<EmbeddingProgress>,
+    ) -> Result<(), FlutterEmbedderError> {
+        embed_stream_with(texts, batch_size, None, &sink, |batch| {
+            self.embed_with_workload(batch, Workload::Bulk)
+        })
+    }
+
+    /// `embed_async` that checks `token` between micro-batches and fails
+    /// with a cancellation error once it is cancelled.
+    pub fn embed_async_cancellable(
+        &self,
+        texts: Vec<String>,
+        token: &CancellationToken,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        embed_many_with(texts, None, None, Some(token), |batch| self.embed(batch))
+    }
+
+    /// `embed_stream` that ends after the current batch once `token` is
+    /// cancelled.
+    pub fn embed_stream_cancellable(
+        &self,
+        texts: Vec<String>,
+        batch_size: u32,
+        token: &CancellationToken,
+        sink: StreamSink<EmbeddingProgress>,
+    ) -> Result<(), FlutterEmbedderError> {
+        embed_stream_with(texts, batch_size, Some(token), &sink, |batch| {
+            self.embed_with_workload(batch, Workload::Bulk)
+        })
+    }
+}
+
+#[frb(ignore)]
+impl TextEmbedder for BgeEmbedder {
+    fn embed(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>> {
+        self.embed(texts)
+    }
+
+    fn embed_with_stats(&self, texts: Vec<String>) -> Result<EmbeddingsWithStats> {
+        self.embed_with_stats(texts)
+    }
+
+    fn format_query(&self, query: String) -> String {
+        self.spec.format_query(&query)
+    }
+
+    fn format_document(&self, text: String) -> String {
+        self.spec.format_document(&text)
+    }
+
+    fn embed_tokens(&self, texts: Vec<String>) -> Result<Vec<TokenEmbeddings>> {
+        self.embed_tokens(texts)
+    }
+
+    fn model_info(&self) -> Result<ModelInfo> {
+        self.model_info()
+    }
+
+    fn close(&mut self) {
+        self.close()
+    }
+
+    fn is_closed(&self) -> bool {
+        self.is_closed()
+    }
+
+    fn tokenizer(&self) -> Result<Arc<tokenizers::Tokenizer>> {
+        loaded(&self.tokenizer).cloned()
+    }
+
+    fn reload_model(
+        &mut self,
+        model_path: String,
+        tokenizer_path: Option<String>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<()> {
+        self.reload_model(model_path, tokenizer_path, ort_options)
+    }
+
+    fn warmup(&self) -> Result<()> {
+        self.warmup()
+    }
+
+    fn dimension(&self) -> Result<usize> {
+        self.dimension()
     }
 }
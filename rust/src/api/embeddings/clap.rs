@@ -0,0 +1,342 @@
+use flutter_rust_bridge::frb;
+use ort::session::Session;
+use ort::value::{DynValue, Tensor, ValueType};
+
+use crate::api::audio::{resample, MelConfig, MelScale, MelSpectrogram};
+use crate::api::embeddings::clip::pooled_embeddings;
+use crate::api::embeddings::{
+    loaded, output_dimension, pad_encodings, probe_dimension, WARMUP_TEXT,
+};
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::ort::{
+    build_session_from_file_with_init, build_session_from_memory_with_init, session_model_info,
+    ModelInfo, OrtInitOptions, SessionPool,
+};
+
+/// Hugging Face `ClapFeatureExtractor` settings shared by the LAION
+/// checkpoints.
+const SAMPLE_RATE: u32 = 48_000;
+const CLIP_SAMPLES: usize = 10 * SAMPLE_RATE as usize;
+const N_FFT: usize = 1024;
+const HOP_LENGTH: usize = 480;
+const N_MELS: usize = 64;
+const F_MIN: f32 = 50.0;
+const F_MAX: f32 = 14_000.0;
+/// Mel frames in a 10 s clip.
+const CHUNK_FRAMES: usize = CLIP_SAMPLES / HOP_LENGTH + 1;
+
+/// Audio/text embedder for CLAP (`laion/clap-htsat-*`) built from separate
+/// text and audio ONNX graphs. Both towers project into one space, so text
+/// queries retrieve sounds directly.
+///
+/// Clips are resampled to 48 kHz and turned into 10 s log-mel
+/// spectrograms: shorter clips are repeated to fill the window, longer ones
+/// use their first 10 s on unfused models and a fused global + three local
+/// views on `fusion` models.
+#[frb(opaque)]
+pub struct ClapEmbedder {
+    tokenizer: Option<tokenizers::Tokenizer>,
+    text_session: SessionPool,
+    audio_session: SessionPool,
+    /// 4 for fusion models, which also take an `is_longer` input.
+    channels: usize,
+    mel: MelSpectrogram,
+}
+
+#[frb(sync)]
+impl ClapEmbedder {
+    pub fn create(
+        text_model_path: String,
+        audio_model_path: String,
+        tokenizer_path: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_with_options(text_model_path, audio_model_path, tokenizer_path, None)
+    }
+
+    pub fn create_with_options(
+        text_model_path: String,
+        audio_model_path: String,
+        tokenizer_path: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer = tokenizers::Tokenizer::from_file(tokenizer_path)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let text_session = build_session_from_file_with_init(text_model_path, ort_options.clone())?;
+        let audio_session = build_session_from_file_with_init(audio_model_path, ort_options)?;
+        Ok(Self::from_parts(tokenizer, text_session, audio_session))
+    }
+
+    /// `create` from in-memory models and tokenizer JSON.
+    pub fn create_from_bytes(
+        text_model_bytes: Vec<u8>,
+        audio_model_bytes: Vec<u8>,
+        tokenizer_json: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_bytes_with_options(
+            text_model_bytes,
+            audio_model_bytes,
+            tokenizer_json,
+            None,
+        )
+    }
+
+    pub fn create_from_bytes_with_options(
+        text_model_bytes: Vec<u8>,
+        audio_model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer = tokenizers::Tokenizer::from_bytes(tokenizer_json)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let text_session =
+            build_session_from_memory_with_init(&text_model_bytes, ort_options.clone())?;
+        let audio_session = build_session_from_memory_with_init(&audio_model_bytes, ort_options)?;
+        Ok(Self::from_parts(tokenizer, text_session, audio_session))
+    }
+
+    fn from_parts(
+        tokenizer: tokenizers::Tokenizer,
+        text_session: Session,
+        audio_session: Session,
+    ) -> Self {
+        // `input_features` is `[batch, 1 | 4, frames, mels]`.
+        let channels = audio_session
+            .inputs()
+            .iter()
+            .find(|input| input.name() == "input_features")
+            .and_then(|input| match input.dtype() {
+                ValueType::Tensor { shape, .. } if shape.len() == 4 => Some(shape[1]),
+                _ => None,
+            })
+            .filter(|&dim| dim > 0)
+            .map_or(1, |dim| dim as usize);
+        // Fusion models were trained on HTK filters, unfused ones on
+        // Slaney-normalized ones.
+        let (scale, slaney_norm) = if channels == 4 {
+            (MelScale::Htk, false)
+        } else {
+            (MelScale::Slaney, true)
+        };
+        let mel = MelSpectrogram::new(MelConfig {
+            sample_rate: SAMPLE_RATE,
+            n_fft: N_FFT,
+            hop_length: HOP_LENGTH,
+            n_mels: N_MELS,
+            f_min: F_MIN,
+            f_max: F_MAX,
+            scale,
+            slaney_norm,
+        });
+        Self {
+            tokenizer: Some(tokenizer),
+            text_session: SessionPool::new(text_session),
+            audio_session: SessionPool::new(audio_session),
+            channels,
+            mel,
+        }
+    }
+
+    /// Inputs, outputs and metadata of the text tower.
+    pub fn text_model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&*self.text_session.acquire()?)
+    }
+
+    /// Inputs, outputs and metadata of the audio tower.
+    pub fn audio_model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&*self.audio_session.acquire()?)
+    }
+
+    /// Length of the shared text/audio vectors, read from the text tower's
+    /// outputs or, when the export leaves it dynamic, from a dry run.
+    pub fn dimension(&mut self) -> Result<usize, FlutterEmbedderError> {
+        let declared = output_dimension(
+            &*self.text_session.acquire()?,
+            &["text_embeds", "pooler_output"],
+        );
+        match declared {
+            Some(dim) => Ok(dim),
+            None => probe_dimension(|texts| self.embed_texts(texts)),
+        }
+    }
+
+    /// Frees both ONNX sessions and the tokenizer now rather than when the
+    /// object is dropped. Later calls fail with `ErrorKind::Closed`.
+    pub fn close(&mut self) {
+        self.text_session.close();
+        self.audio_session.close();
+        self.tokenizer = None;
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.text_session.is_closed()
+    }
+
+    pub fn embed_texts(
+        &mut self,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let tokenizer = loaded(&self.tokenizer)?;
+        let encodings = tokenizer
+            .encode_batch(texts, true)
+            .map_err(|e| err!(Tokenization, "{e}"))?;
+        let pad_id = tokenizer
+            .get_padding()
+            .map(|p| p.pad_id as i64)
+            .unwrap_or(1);
+        let batch = encodings.len();
+        let (max_len, input_ids_batch, mask_batch) = pad_encodings(&encodings, pad_id);
+        if max_len == 0 {
+            return Err(err!(InvalidInput, "Inputs produced no tokens"));
+        }
+        let inputs = ort::inputs! {
+            "input_ids" => Tensor::from_array(([batch, max_len], input_ids_batch))?,
+            "attention_mask" => Tensor::from_array(([batch, max_len], mask_batch))?,
+        };
+        let mut session = self.text_session.acquire()?;
+        let outputs = session.run(inputs)?;
+        pooled_embeddings(&outputs, "text_embeds", batch)
+    }
+
+    /// Embeds mono PCM clips recorded at `sample_rate` Hz.
+    pub fn embed_audio(
+        &mut self,
+        clips: Vec<Vec<f32>>,
+        sample_rate: u32,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        if clips.is_empty() {
+            return Ok(Vec::new());
+        }
+        let batch = clips.len();
+        let mut features = Vec::with_capacity(batch * self.channels * CHUNK_FRAMES * N_MELS);
+        let mut longer = Vec::with_capacity(batch);
+        for clip in &clips {
+            let samples = resample(clip, sample_rate, SAMPLE_RATE)?;
+            if samples.is_empty() {
+                return Err(err!(InvalidInput, "Audio clip has no samples"));
+            }
+            let (clip_features, is_longer) = self.features(&samples);
+            features.extend(clip_features);
+            longer.push(is_longer);
+        }
+        self.embed_features(batch, features, longer)
+    }
+}
+
+impl ClapEmbedder {
+    /// Runs a one-word text and a second of silence through both towers so
+    /// the first real calls don't pay for graph initialization.
+    pub fn warmup(&mut self) -> Result<(), FlutterEmbedderError> {
+        self.embed_texts(vec![WARMUP_TEXT.to_string()])?;
+        self.embed_audio(vec![vec![0.0; SAMPLE_RATE as usize]], SAMPLE_RATE)?;
+        Ok(())
+    }
+
+    /// Same as `embed_texts`, but runs on a worker thread.
+    pub fn embed_texts_async(
+        &mut self,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.embed_texts(texts)
+    }
+
+    /// Same as `embed_audio`, but runs on a worker thread. Prefer this for
+    /// libraries of clips: resampling and spectrograms are not free.
+    pub fn embed_audio_async(
+        &mut self,
+        clips: Vec<Vec<f32>>,
+        sample_rate: u32,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.embed_audio(clips, sample_rate)
+    }
+
+    /// `[channels, frames, mels]` log-mel features of one 48 kHz clip, and
+    /// whether it was longer than the 10 s window.
+    fn features(&self, samples: &[f32]) -> (Vec<f32>, bool) {
+        if samples.len() <= CLIP_SAMPLES {
+            // "repeatpad": repeat whole copies, then pad with silence.
+            let repeats = CLIP_SAMPLES / samples.len();
+            let mut window = samples.repeat(repeats);
+            window.resize(CLIP_SAMPLES, 0.0);
+            let mel = self.log_mel(&window);
+            return (mel.repeat(self.channels), false);
+        }
+        if self.channels == 1 {
+            return (self.log_mel(&samples[..CLIP_SAMPLES]), true);
+        }
+
+        // Fusion: the whole clip squeezed into one window, plus windows from
+        // the start of each third.
+        let mel = self.log_mel(samples);
+        let frames = mel.len() / N_MELS;
+        let mut features = shrink_frames(&mel, frames, CHUNK_FRAMES);
+        let starts = frames - CHUNK_FRAMES + 1;
+        for part in 0..3 {
+            // First offset of each part of `numpy.array_split(starts, 3)`.
+            let start = (part * (starts / 3) + part.min(starts % 3)).min(starts - 1);
+            features.extend_from_slice(&mel[start * N_MELS..(start + CHUNK_FRAMES) * N_MELS]);
+        }
+        (features, true)
+    }
+
+    /// `[frames, mels]` log-mel power in dB.
+    fn log_mel(&self, samples: &[f32]) -> Vec<f32> {
+        self.mel
+            .compute(samples)
+            .into_iter()
+            .flatten()
+            .map(|power| 10.0 * power.max(1e-10).log10())
+            .collect()
+    }
+
+    fn embed_features(
+        &mut self,
+        batch: usize,
+        features: Vec<f32>,
+        longer: Vec<bool>,
+    ) -> Result<Vec<Vec<f32>>> {
+        let mut inputs: Vec<(String, DynValue)> = vec![(
+            "input_features".to_string(),
+            Tensor::from_array(([batch, self.channels, CHUNK_FRAMES, N_MELS], features))?
+                .into_dyn(),
+        )];
+        let mut session = self.audio_session.acquire()?;
+        if session
+            .inputs()
+            .iter()
+            .any(|input| input.name() == "is_longer")
+        {
+            inputs.push((
+                "is_longer".to_string(),
+                Tensor::from_array(([batch, 1], longer))?.into_dyn(),
+            ));
+        }
+        let outputs = session.run(inputs)?;
+        pooled_embeddings(&outputs, "audio_embeds", batch)
+    }
+}
+
+/// Linearly resamples `[frames, mels]` to `target` frames (bilinear
+/// interpolation along time, as `torch.nn.functional.interpolate` does).
+fn shrink_frames(mel: &[f32], frames: usize, target: usize) -> Vec<f32> {
+    let scale = frames as f32 / target as f32;
+    let mut out = Vec::with_capacity(target * N_MELS);
+    for t in 0..target {
+        let source = ((t as f32 + 0.5) * scale - 0.5).max(0.0);
+        let low = (source.floor() as usize).min(frames - 1);
+        let high = (low + 1).min(frames - 1);
+        let weight = source - low as f32;
+        let (a, b) = (
+            &mel[low * N_MELS..(low + 1) * N_MELS],
+            &mel[high * N_MELS..(high + 1) * N_MELS],
+        );
+        out.extend(
+            a.iter()
+                .zip(b)
+                .map(|(x, y)| x * (1.0 - weight) + y * weight),
+        );
+    }
+    out
+}
@@ -0,0 +1,310 @@
+use flutter_rust_bridge::frb;
+use ort::session::{Session, SessionOutputs};
+use ort::value::{Tensor, ValueType};
+
+use crate::api::embeddings::{
+    loaded, output_dimension, pad_encodings, probe_dimension, WARMUP_TEXT,
+};
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::image::{
+    decode_image, preprocess_rgb, PreprocessOptions, ResizeFilter, ResizeMode, TensorLayout,
+};
+use crate::api::ort::{
+    build_session_from_file_with_init, build_session_from_memory_with_init, session_model_info,
+    ModelInfo, OrtInitOptions, SessionPool,
+};
+use crate::api::utils::normalize;
+
+/// OpenAI CLIP preprocessing constants. SigLIP exports use 0.5 for both.
+const CLIP_MEAN: [f32; 3] = [0.481_454_66, 0.457_827_5, 0.408_210_73];
+const CLIP_STD: [f32; 3] = [0.268_629_54, 0.261_302_6, 0.275_777_1];
+const DEFAULT_IMAGE_SIZE: u32 = 224;
+
+/// Dual-encoder image/text embedder (CLIP, SigLIP) built from separate text
+/// and vision ONNX graphs. Text and image vectors share one space, so
+/// `cosine_distance` compares them directly.
+#[frb(opaque)]
+pub struct ClipEmbedder {
+    tokenizer: Option<tokenizers::Tokenizer>,
+    text_session: SessionPool,
+    vision_session: SessionPool,
+    preprocessing: PreprocessOptions,
+}
+
+#[frb(sync)]
+impl ClipEmbedder {
+    pub fn create(
+        text_model_path: String,
+        vision_model_path: String,
+        tokenizer_path: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_with_options(text_model_path, vision_model_path, tokenizer_path, None)
+    }
+
+    pub fn create_with_options(
+        text_model_path: String,
+        vision_model_path: String,
+        tokenizer_path: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer = tokenizers::Tokenizer::from_file(tokenizer_path)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let text_session = build_session_from_file_with_init(text_model_path, ort_options.clone())?;
+        let vision_session = build_session_from_file_with_init(vision_model_path, ort_options)?;
+        Ok(Self::from_parts(tokenizer, text_session, vision_session))
+    }
+
+    /// `create` from in-memory models and tokenizer JSON.
+    pub fn create_from_bytes(
+        text_model_bytes: Vec<u8>,
+        vision_model_bytes: Vec<u8>,
+        tokenizer_json: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_bytes_with_options(
+            text_model_bytes,
+            vision_model_bytes,
+            tokenizer_json,
+            None,
+        )
+    }
+
+    pub fn create_from_bytes_with_options(
+        text_model_bytes: Vec<u8>,
+        vision_model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer = tokenizers::Tokenizer::from_bytes(tokenizer_json)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let text_session =
+            build_session_from_memory_with_init(&text_model_bytes, ort_options.clone())?;
+        let vision_session = build_session_from_memory_with_init(&vision_model_bytes, ort_options)?;
+        Ok(Self::from_parts(tokenizer, text_session, vision_session))
+    }
+
+    fn from_parts(
+        tokenizer: tokenizers::Tokenizer,
+        text_session: Session,
+        vision_session: Session,
+    ) -> Self {
+        // Exports with a static `[batch, 3, H, W]` input fix the image size.
+        let image_size = vision_session
+            .inputs()
+            .first()
+            .and_then(|input| match input.dtype() {
+                ValueType::Tensor { shape, .. } if shape.len() == 4 && shape[2] > 0 => {
+                    Some(shape[2] as u32)
+                }
+                _ => None,
+            })
+            .unwrap_or(DEFAULT_IMAGE_SIZE);
+
+        Self {
+            tokenizer: Some(tokenizer),
+            text_session: SessionPool::new(text_session),
+            vision_session: SessionPool::new(vision_session),
+            preprocessing: PreprocessOptions {
+                width: image_size,
+                height: image_size,
+                mean: CLIP_MEAN.to_vec(),
+                std: CLIP_STD.to_vec(),
+                layout: TensorLayout::Chw,
+                filter: ResizeFilter::Bicubic,
+                mode: ResizeMode::CenterCrop,
+                crop_fraction: None,
+            },
+        }
+    }
+
+    /// Overrides the per-channel (RGB) pixel normalization, e.g.
+    /// `[0.5, 0.5, 0.5]` for both on SigLIP models. Defaults to CLIP's.
+    pub fn set_image_normalization(
+        &mut self,
+        mean: Vec<f32>,
+        std: Vec<f32>,
+    ) -> Result<(), FlutterEmbedderError> {
+        if mean.len() != 3 || std.len() != 3 {
+            return Err(err!(InvalidInput, "mean and std must have 3 values"));
+        }
+        self.preprocessing.mean = mean;
+        self.preprocessing.std = std;
+        Ok(())
+    }
+
+    /// Inputs, outputs and metadata of the text tower.
+    pub fn text_model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&*self.text_session.acquire()?)
+    }
+
+    /// Inputs, outputs and metadata of the vision tower.
+    pub fn vision_model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&*self.vision_session.acquire()?)
+    }
+
+    /// Frees both ONNX sessions and the tokenizer now rather than when the
+    /// object is dropped. Later calls fail with `ErrorKind::Closed`.
+    pub fn close(&mut self) {
+        self.text_session.close();
+        self.vision_session.close();
+        self.tokenizer = None;
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.text_session.is_closed()
+    }
+
+    /// Side length images are resized and center-cropped to.
+    pub fn image_size(&self) -> u32 {
+        self.preprocessing.width
+    }
+
+    /// Length of the shared text/image vectors, read from the text tower's
+    /// outputs or, when the export leaves it dynamic, from a dry run.
+    pub fn dimension(&mut self) -> Result<usize, FlutterEmbedderError> {
+        let declared = output_dimension(
+            &*self.text_session.acquire()?,
+            &["text_embeds", "pooler_output"],
+        );
+        match declared {
+            Some(dim) => Ok(dim),
+            None => probe_dimension(|texts| self.embed_texts(texts)),
+        }
+    }
+
+    pub fn embed_texts(
+        &mut self,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let tokenizer = loaded(&self.tokenizer)?;
+        let encodings = tokenizer
+            .encode_batch(texts, true)
+            .map_err(|e| err!(Tokenization, "{e}"))?;
+        let pad_id = tokenizer
+            .get_padding()
+            .map(|p| p.pad_id as i64)
+            .unwrap_or(0);
+        let batch = encodings.len();
+        let (max_len, input_ids_batch, mask_batch) = pad_encodings(&encodings, pad_id);
+        if max_len == 0 {
+            return Err(err!(InvalidInput, "Inputs produced no tokens"));
+        }
+
+        let mut inputs = ort::inputs! {
+            "input_ids" => Tensor::from_array(([batch, max_len], input_ids_batch))?,
+        };
+        // SigLIP text towers take no attention mask.
+        let mut session = self.text_session.acquire()?;
+        if session
+            .inputs()
+            .iter()
+            .any(|input| input.name() == "attention_mask")
+        {
+            inputs.push((
+                "attention_mask".into(),
+                Tensor::from_array(([batch, max_len], mask_batch))?.into(),
+            ));
+        }
+
+        let outputs = session.run(inputs)?;
+        pooled_embeddings(&outputs, "text_embeds", batch)
+    }
+
+    /// Embeds encoded images (PNG or JPEG bytes): each is resized so its
+    /// shorter side is `image_size`, center-cropped, and normalized.
+    pub fn embed_images(
+        &mut self,
+        image_bytes: Vec<Vec<u8>>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        if image_bytes.is_empty() {
+            return Ok(Vec::new());
+        }
+        let batch = image_bytes.len();
+        let size = self.image_size() as usize;
+        let mut pixels = Vec::with_capacity(batch * 3 * size * size);
+        for bytes in &image_bytes {
+            pixels.extend(preprocess_rgb(&decode_image(bytes)?, &self.preprocessing)?);
+        }
+
+        self.embed_pixels(batch, pixels)
+    }
+
+    /// Runs the vision tower on preprocessed `[batch, 3, size, size]` pixels.
+    fn embed_pixels(&mut self, batch: usize, pixels: Vec<f32>) -> Result<Vec<Vec<f32>>> {
+        let size = self.image_size() as usize;
+        let mut session = self.vision_session.acquire()?;
+        let input_name = session
+            .inputs()
+            .first()
+            .map(|input| input.name().to_string())
+            .unwrap_or_else(|| "pixel_values".to_string());
+        let inputs = ort::inputs! {
+            input_name => Tensor::from_array(([batch, 3, size, size], pixels))?,
+        };
+        let outputs = session.run(inputs)?;
+        pooled_embeddings(&outputs, "image_embeds", batch)
+    }
+}
+
+impl ClipEmbedder {
+    /// Runs a one-word text and a blank image through both towers so the
+    /// first real calls don't pay for graph initialization.
+    pub fn warmup(&mut self) -> Result<(), FlutterEmbedderError> {
+        self.embed_texts(vec![WARMUP_TEXT.to_string()])?;
+        let size = self.image_size() as usize;
+        self.embed_pixels(1, vec![0.0; 3 * size * size])?;
+        Ok(())
+    }
+
+    /// Same as `embed_texts`, but runs on a worker thread.
+    pub fn embed_texts_async(
+        &mut self,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.embed_texts(texts)
+    }
+
+    /// Same as `embed_images`, but runs on a worker thread. Prefer this for
+    /// gallery-sized batches: decoding and resizing are not free.
+    pub fn embed_images_async(
+        &mut self,
+        image_bytes: Vec<Vec<u8>>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.embed_images(image_bytes)
+    }
+}
+
+/// Reads the projected `[batch, dim]` embeddings: `preferred` (`text_embeds`
+/// / `image_embeds` in HF exports), then `pooler_output`, then the first
+/// rank-2 output.
+pub(crate) fn pooled_embeddings(
+    outputs: &SessionOutputs<'_>,
+    preferred: &str,
+    batch: usize,
+) -> Result<Vec<Vec<f32>>> {
+    let mut pooled = None;
+    for key in [preferred, "pooler_output"] {
+        if let Some(t) = outputs.get(key) {
+            let (shape, data) = t.try_extract_tensor::<f32>()?;
+            pooled = Some((shape.to_vec(), data.to_vec()));
+            break;
+        }
+    }
+    if pooled.is_none() {
+        for (_, t) in outputs.iter() {
+            if let Ok((shape, data)) = t.try_extract_tensor::<f32>() {
+                if shape.len() == 2 {
+                    pooled = Some((shape.to_vec(), data.to_vec()));
+                    break;
+                }
+            }
+        }
+    }
+    let (shape, data) = pooled.ok_or(err!(MissingOutput, "Model has no [batch, dim] output"))?;
+    if shape.len() != 2 || shape[0] as usize != batch {
+        return Err(err!(ShapeMismatch, "Unexpected output shape: {shape:?}"));
+    }
+    Ok(data.chunks(shape[1] as usize).map(normalize).collect())
+}
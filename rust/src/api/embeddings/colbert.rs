@@ -0,0 +1,295 @@
+use flutter_rust_bridge::frb;
+use ort::session::Session;
+use ort::value::{Tensor, ValueType};
+
+use crate::api::embeddings::{loaded, WARMUP_TEXT};
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::ort::{
+    build_session_from_file_with_init, build_session_from_memory_with_init, session_model_info,
+    ModelInfo, OrtInitOptions, SessionPool,
+};
+use crate::api::utils::normalize;
+
+/// Marker tokens inserted after `[CLS]`, by export convention: ColBERTv2
+/// reuses `[unused0]`/`[unused1]`, PyLate adds `[Q] `/`[D] `.
+const QUERY_MARKERS: [&str; 3] = ["[unused0]", "[Q] ", "[Q]"];
+const DOCUMENT_MARKERS: [&str; 3] = ["[unused1]", "[D] ", "[D]"];
+const DEFAULT_QUERY_LENGTH: usize = 32;
+
+/// Late-interaction (ColBERT) embedder: one normalized vector per token
+/// instead of a pooled embedding. Score query/document pairs with `max_sim`.
+#[frb(opaque)]
+pub struct ColbertEmbedder {
+    tokenizer: Option<tokenizers::Tokenizer>,
+    session: SessionPool,
+    query_marker: Option<u32>,
+    document_marker: Option<u32>,
+    mask_id: Option<u32>,
+    query_length: usize,
+}
+
+#[frb(sync)]
+impl ColbertEmbedder {
+    pub fn create(
+        model_path: String,
+        tokenizer_path: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_with_options(model_path, tokenizer_path, None)
+    }
+
+    pub fn create_with_options(
+        model_path: String,
+        tokenizer_path: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer = tokenizers::Tokenizer::from_file(tokenizer_path)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let session = build_session_from_file_with_init(model_path, ort_options)?;
+        Self::from_parts(tokenizer, session)
+    }
+
+    /// `create` from in-memory model bytes and tokenizer JSON, e.g. for
+    /// encrypted or downloaded assets that must not be written to disk.
+    pub fn create_from_bytes(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_bytes_with_options(model_bytes, tokenizer_json, None)
+    }
+
+    pub fn create_from_bytes_with_options(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer = tokenizers::Tokenizer::from_bytes(tokenizer_json)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let session = build_session_from_memory_with_init(&model_bytes, ort_options)?;
+        Self::from_parts(tokenizer, session)
+    }
+
+    fn from_parts(tokenizer: tokenizers::Tokenizer, session: Session) -> Result<Self> {
+        let find = |candidates: &[&str]| candidates.iter().find_map(|t| tokenizer.token_to_id(t));
+        Ok(Self {
+            query_marker: find(&QUERY_MARKERS),
+            document_marker: find(&DOCUMENT_MARKERS),
+            mask_id: tokenizer.token_to_id("[MASK]"),
+            query_length: DEFAULT_QUERY_LENGTH,
+            tokenizer: Some(tokenizer),
+            session: SessionPool::new(session),
+        })
+    }
+
+    /// Inputs, outputs and metadata of the loaded ONNX model.
+    pub fn model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&*self.session.acquire()?)
+    }
+
+    /// Frees the ONNX session and tokenizer now rather than when the object
+    /// is dropped. Later calls fail with `ErrorKind::Closed`.
+    pub fn close(&mut self) {
+        self.session.close();
+        self.tokenizer = None;
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.session.is_closed()
+    }
+
+    /// Length of each per-token vector, read from the model outputs or,
+    /// when the export leaves it dynamic, from a one-query dry run.
+    pub fn dimension(&mut self) -> Result<usize, FlutterEmbedderError> {
+        let declared =
+            self.session
+                .acquire()?
+                .outputs()
+                .iter()
+                .find_map(|output| match output.dtype() {
+                    ValueType::Tensor { shape, .. } if shape.len() == 3 => Some(shape[2]),
+                    _ => None,
+                });
+        if let Some(dim) = declared.filter(|&dim| dim > 0) {
+            return Ok(dim as usize);
+        }
+        self.embed_queries(vec![WARMUP_TEXT.to_string()])?
+            .first()
+            .and_then(|vectors| vectors.first())
+            .map(Vec::len)
+            .ok_or(err!(MissingOutput, "Model produced no token vectors"))
+    }
+
+    /// Queries shorter than this many tokens are padded with `[MASK]`
+    /// (query augmentation). Default 32.
+    pub fn set_query_length(&mut self, query_length: u32) {
+        self.query_length = query_length as usize;
+    }
+
+    /// Per-token vectors for each query, `[MASK]`-augmented.
+    pub fn embed_queries(
+        &mut self,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<Vec<f32>>>, FlutterEmbedderError> {
+        self.embed_tokens(texts, true)
+    }
+
+    /// Per-token vectors for each document, without padding and punctuation.
+    pub fn embed_documents(
+        &mut self,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<Vec<f32>>>, FlutterEmbedderError> {
+        self.embed_tokens(texts, false)
+    }
+
+    /// Same as `embed_documents`.
+    pub fn embed(
+        &mut self,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<Vec<f32>>>, FlutterEmbedderError> {
+        self.embed_documents(texts)
+    }
+
+    fn embed_tokens(&mut self, texts: Vec<String>, query: bool) -> Result<Vec<Vec<Vec<f32>>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let tokenizer = loaded(&self.tokenizer)?;
+        let encodings = tokenizer
+            .encode_batch(texts, true)
+            .map_err(|e| err!(Tokenization, "{e}"))?;
+        let marker = if query {
+            self.query_marker
+        } else {
+            self.document_marker
+        };
+
+        // (ids, mask, keep) per text; `keep` marks tokens that get a vector.
+        let mut sequences = Vec::with_capacity(encodings.len());
+        for encoding in &encodings {
+            let mut ids = Vec::new();
+            let mut keep = Vec::new();
+            for (i, (&id, &mask)) in encoding
+                .get_ids()
+                .iter()
+                .zip(encoding.get_attention_mask())
+                .enumerate()
+            {
+                if mask == 0 {
+                    continue;
+                }
+                ids.push(id);
+                let token = &encoding.get_tokens()[i];
+                keep.push(query || !token.chars().all(|c| c.is_ascii_punctuation()));
+                if i == 0 && encoding.get_special_tokens_mask()[0] == 1 {
+                    if let Some(marker) = marker {
+                        ids.push(marker);
+                        keep.push(true);
+                    }
+                }
+            }
+            if query {
+                if let Some(mask_id) = self.mask_id {
+                    let missing = self.query_length.saturating_sub(ids.len());
+                    ids.extend(std::iter::repeat_n(mask_id, missing));
+                    keep.extend(std::iter::repeat_n(true, missing));
+                }
+            }
+            let attention = vec![1u32; ids.len()];
+            sequences.push((ids, attention, keep));
+        }
+
+        let pad_id = tokenizer
+            .get_padding()
+            .map(|p| p.pad_id as i64)
+            .unwrap_or(0);
+        let batch = sequences.len();
+        let max_len = sequences
+            .iter()
+            .map(|(ids, _, _)| ids.len())
+            .max()
+            .unwrap_or(0);
+        if max_len == 0 {
+            return Ok(vec![Vec::new(); batch]);
+        }
+        let mut input_ids_batch = Vec::with_capacity(batch * max_len);
+        let mut mask_batch = Vec::with_capacity(batch * max_len);
+        for (ids, mask, _) in &sequences {
+            let pad_len = max_len - ids.len();
+            input_ids_batch.extend(ids.iter().map(|&x| x as i64));
+            input_ids_batch.extend(std::iter::repeat_n(pad_id, pad_len));
+            mask_batch.extend(mask.iter().map(|&x| x as i64));
+            mask_batch.extend(std::iter::repeat_n(0, pad_len));
+        }
+
+        let mut inputs = ort::inputs! {
+            "input_ids" => Tensor::from_array(([batch, max_len], input_ids_batch))?,
+            "attention_mask" => Tensor::from_array(([batch, max_len], mask_batch))?,
+        };
+        let mut session = self.session.acquire()?;
+        if session
+            .inputs()
+            .iter()
+            .any(|input| input.name() == "token_type_ids")
+        {
+            inputs.push((
+                "token_type_ids".into(),
+                Tensor::from_array(([batch, max_len], vec![0i64; batch * max_len]))?.into(),
+            ));
+        }
+
+        // The first rank-3 output holds the (projected) token vectors.
+        let output_name = session
+            .outputs()
+            .iter()
+            .find(|output| {
+                matches!(output.dtype(), ValueType::Tensor { shape, .. } if shape.len() == 3)
+            })
+            .map(|output| output.name().to_string())
+            .ok_or(err!(MissingOutput, "Model has no [batch, tokens, dim] output"))?;
+        let outputs = session.run(inputs)?;
+        let (shape, data) = outputs[output_name.as_str()].try_extract_tensor::<f32>()?;
+        let seq_len = shape[1] as usize;
+        let dim = shape[2] as usize;
+        if shape[0] as usize != batch || seq_len < max_len {
+            return Err(err!(ShapeMismatch, "Unexpected output shape: {shape:?}"));
+        }
+
+        let mut results = Vec::with_capacity(batch);
+        for (i, (_, _, keep)) in sequences.iter().enumerate() {
+            let mut vectors = Vec::new();
+            for (t, _) in keep.iter().enumerate().filter(|(_, &k)| k) {
+                let start = (i * seq_len + t) * dim;
+                let slice = data
+                    .get(start..start + dim)
+                    .ok_or(err!(ShapeMismatch, "Invalid output slice"))?;
+                vectors.push(normalize(slice));
+            }
+            results.push(vectors);
+        }
+        Ok(results)
+    }
+}
+
+impl ColbertEmbedder {
+    /// Runs a one-word query through the model so the first real call
+    /// doesn't pay for graph initialization.
+    pub fn warmup(&mut self) -> Result<(), FlutterEmbedderError> {
+        self.embed_queries(vec![WARMUP_TEXT.to_string()])?;
+        Ok(())
+    }
+}
+
+/// Late-interaction relevance: for every query vector, the best dot product
+/// with any document vector, summed over the query.
+#[frb(sync)]
+pub fn max_sim(query_vectors: Vec<Vec<f32>>, doc_vectors: Vec<Vec<f32>>) -> f32 {
+    query_vectors
+        .iter()
+        .map(|q| {
+            doc_vectors
+                .iter()
+                .map(|d| q.iter().zip(d).map(|(a, b)| a * b).sum::<f32>())
+                .fold(f32::NEG_INFINITY, f32::max)
+        })
+        .filter(|best| best.is_finite())
+        .sum()
+}
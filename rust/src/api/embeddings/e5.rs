@@ -0,0 +1,339 @@
+use std::sync::Arc;
+
+use flutter_rust_bridge::frb;
+use ort::session::Session;
+
+use crate::api::embeddings::pipeline::{
+    self, output_names, InputNames, InputStyle, ModelSpec, Pooling,
+};
+use crate::api::embeddings::{
+    declared_dimension, embed_many_with, embed_stream_with, load_replacement, loaded,
+    probe_dimension, BatchOptions, CancellationToken, EmbedManyOptions, EmbedStats,
+    EmbeddingProgress, EmbeddingsWithStats, TextEmbedder, TokenEmbeddings, WARMUP_TEXT,
+};
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::hub::{resolve_hub_files, HubOptions};
+use crate::api::ort::{
+    build_session_from_file_with_init, build_session_from_memory_with_init, is_deterministic,
+    session_model_info, ModelInfo, OrtInitOptions, SessionPool,
+};
+use crate::frb_generated::StreamSink;
+
+/// intfloat/e5 (and multilingual-e5) were trained with these prefixes on
+/// every input; leaving them out noticeably hurts retrieval.
+const PREFIX_QUERY: &str = "query: ";
+const PREFIX_DOCUMENT: &str = "passage: ";
+
+/// E5 / multilingual-e5 embedder: mean pooling over `last_hidden_state`.
+/// Pass queries through `format_query` and documents through
+/// `format_document` before `embed`.
+#[frb(opaque)]
+pub struct E5Embedder {
+    tokenizer: Option<Arc<tokenizers::Tokenizer>>,
+    session: SessionPool,
+    spec: ModelSpec,
+}
+
+#[frb(sync)]
+impl E5Embedder {
+    pub fn create(
+        model_path: String,
+        tokenizer_path: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_with_options(model_path, tokenizer_path, None)
+    }
+
+    pub fn create_with_options(
+        model_path: String,
+        tokenizer_path: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer = tokenizers::Tokenizer::from_file(tokenizer_path)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let deterministic = is_deterministic(ort_options.as_ref());
+        let session = build_session_from_file_with_init(model_path, ort_options)?;
+        Self::from_parts(tokenizer, session, deterministic)
+    }
+
+    /// `create` from in-memory model bytes and tokenizer JSON, e.g. for
+    /// encrypted or downloaded assets that must not be written to disk.
+    pub fn create_from_bytes(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_bytes_with_options(model_bytes, tokenizer_json, None)
+    }
+
+    pub fn create_from_bytes_with_options(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer = tokenizers::Tokenizer::from_bytes(tokenizer_json)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let deterministic = is_deterministic(ort_options.as_ref());
+        let session = build_session_from_memory_with_init(&model_bytes, ort_options)?;
+        Self::from_parts(tokenizer, session, deterministic)
+    }
+
+    fn from_parts(
+        tokenizer: tokenizers::Tokenizer,
+        session: Session,
+        deterministic: bool,
+    ) -> Result<Self> {
+        Ok(Self {
+            tokenizer: Some(Arc::new(tokenizer)),
+            session: SessionPool::new(session),
+            // Sentence-transformers exports already pool.
+            spec: ModelSpec {
+                inputs: InputStyle::Encoder,
+                input_names: InputNames::standard(),
+                outputs: output_names(&["sentence_embedding", "last_hidden_state"]),
+                pooling: Pooling::Mean,
+                normalize: true,
+                query_prefix: PREFIX_QUERY.to_string(),
+                document_prefix: PREFIX_DOCUMENT.to_string(),
+                batching: BatchOptions::default(),
+                deterministic,
+            },
+        })
+    }
+
+    pub fn embed(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        pipeline::embed(
+            &mut *self.session.acquire()?,
+            loaded(&self.tokenizer)?,
+            &self.spec,
+            texts,
+            &[],
+            &[],
+            None,
+        )
+    }
+
+    /// `embed` plus tokenization/inference timings and peak memory.
+    pub fn embed_with_stats(
+        &self,
+        texts: Vec<String>,
+    ) -> Result<EmbeddingsWithStats, FlutterEmbedderError> {
+        let mut stats = EmbedStats::default();
+        let embeddings = pipeline::embed(
+            &mut *self.session.acquire()?,
+            loaded(&self.tokenizer)?,
+            &self.spec,
+            texts,
+            &[],
+            &[],
+            Some(&mut stats),
+        )?;
+        Ok(EmbeddingsWithStats { embeddings, stats })
+    }
+
+    /// Returns per-token `last_hidden_state` vectors (with offsets) instead of
+    /// a pooled embedding, for custom pooling or late-interaction scoring.
+    pub fn embed_tokens(
+        &self,
+        texts: Vec<String>,
+    ) -> Result<Vec<TokenEmbeddings>, FlutterEmbedderError> {
+        pipeline::token_embeddings(
+            &mut *self.session.acquire()?,
+            loaded(&self.tokenizer)?,
+            &self.spec,
+            texts,
+            &[],
+        )
+    }
+
+    /// Limits for the micro-batches `embed` splits large inputs into.
+    pub fn set_batch_options(&mut self, options: BatchOptions) {
+        self.spec.batching = options;
+    }
+
+    /// Inputs, outputs and metadata of the loaded ONNX model.
+    pub fn model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&*self.session.acquire()?)
+    }
+
+    /// Length of the vectors `embed` returns, read from the model outputs
+    /// or, when the export leaves it dynamic, from a one-token dry run.
+    pub fn dimension(&self) -> Result<usize, FlutterEmbedderError> {
+        if let Some(dim) = declared_dimension(&*self.session.acquire()?) {
+            return Ok(dim);
+        }
+        probe_dimension(|texts| self.embed(texts))
+    }
+
+    /// Frees the ONNX sessions and tokenizer now rather than when the object
+    /// is dropped, e.g. before loading another model on a memory-constrained
+    /// device. Later calls fail with `ErrorKind::Closed`.
+    pub fn close(&mut self) {
+        self.session.close();
+        self.tokenizer = None;
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.session.is_closed()
+    }
+
+    /// Replaces the model, e.g. with a larger variant of the same family,
+    /// without recreating the embedder. The new session is built before the
+    /// old one is dropped, so a failed load leaves the embedder untouched.
+    /// The tokenizer is kept unless `tokenizer_path` is given.
+    pub fn reload_model(
+        &mut self,
+        model_path: String,
+        tokenizer_path: Option<String>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<(), FlutterEmbedderError> {
+        let deterministic = is_deterministic(ort_options.as_ref());
+        let (session, tokenizer) =
+            load_replacement(&self.tokenizer, model_path, tokenizer_path, ort_options)?;
+        self.session = SessionPool::new(session);
+        self.spec.deterministic = deterministic;
+        if let Some(tokenizer) = tokenizer {
+            self.tokenizer = Some(tokenizer);
+        }
+        Ok(())
+    }
+
+    pub fn format_query(query: String) -> String {
+        format!("{PREFIX_QUERY}{query}")
+    }
+
+    pub fn format_document(text: String) -> String {
+        format!("{PREFIX_DOCUMENT}{text}")
+    }
+
+    /// Formats every query with `format_query` and embeds the batch.
+    pub fn embed_queries(
+        &self,
+        queries: Vec<String>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        let queries = queries.into_iter().map(Self::format_query).collect();
+        self.embed(queries)
+    }
+
+    /// Formats every text with `format_document` and embeds the batch.
+    pub fn embed_documents(
+        &self,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        let texts = texts.into_iter().map(Self::format_document).collect();
+        self.embed(texts)
+    }
+}
+
+impl E5Embedder {
+    /// `create` from a Hugging Face Hub repo, downloading the model and
+    /// `tokenizer.json` into `cache_dir` on first use.
+    pub fn create_from_hub(
+        repo_id: String,
+        cache_dir: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_hub_with_options(repo_id, cache_dir, None, None)
+    }
+
+    pub fn create_from_hub_with_options(
+        repo_id: String,
+        cache_dir: String,
+        hub_options: Option<HubOptions>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let (model_path, tokenizer_path) = resolve_hub_files(&repo_id, &cache_dir, hub_options)?;
+        Self::create_with_options(model_path, tokenizer_path, ort_options)
+    }
+
+    /// Runs a one-word batch through the model so the first real `embed`
+    /// doesn't pay for graph initialization.
+    pub fn warmup(&self) -> Result<(), FlutterEmbedderError> {
+        self.embed(vec![WARMUP_TEXT.to_string()])?;
+        Ok(())
+    }
+
+    /// Same as `embed`, but runs off the Dart isolate.
+    pub fn embed_async(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.embed(texts)
+    }
+
+    /// Embeds large corpora in length-sorted chunks (see `EmbedManyOptions`).
+    pub fn embed_many(
+        &self,
+        texts: Vec<String>,
+        options: Option<EmbedManyOptions>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        embed_many_with(texts, options, None, None, |batch| self.embed(batch))
+    }
+
+    /// Embeds `texts` in input order, `batch_size` at a time, sending each
+    /// batch to `sink` as soon as it is ready.
+    pub fn embed_stream(
+        &self,
+        texts: Vec<String>,
+        batch_size: u32,
+        sink: StreamSink<EmbeddingProgress>,
+    ) -> Result<(), FlutterEmbedderError> {
+        embed_stream_with(texts, batch_size, None, &sink, |batch| self.embed(batch))
+    }
+
+    /// `embed_async` that fails with a cancellation error once `token` is
+    /// cancelled.
+    pub fn embed_async_cancellable(
+        &self,
+        texts: Vec<String>,
+        token: &CancellationToken,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        embed_many_with(texts, None, None, Some(token), |batch| self.embed(batch))
+    }
+}
+
+#[frb(ignore)]
+impl TextEmbedder for E5Embedder {
+    fn embed(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>> {
+        self.embed(texts)
+    }
+
+    fn embed_with_stats(&self, texts: Vec<String>) -> Result<EmbeddingsWithStats> {
+        self.embed_with_stats(texts)
+    }
+
+    fn format_query(&self, query: String) -> String {
+        Self::format_query(query)
+    }
+
+    fn format_document(&self, text: String) -> String {
+        Self::format_document(text)
+    }
+
+    fn embed_tokens(&self, texts: Vec<String>) -> Result<Vec<TokenEmbeddings>> {
+        self.embed_tokens(texts)
+    }
+
+    fn model_info(&self) -> Result<ModelInfo> {
+        self.model_info()
+    }
+
+    fn close(&mut self) {
+        self.close()
+    }
+
+    fn is_closed(&self) -> bool {
+        self.is_closed()
+    }
+
+    fn tokenizer(&self) -> Result<Arc<tokenizers::Tokenizer>> {
+        loaded(&self.tokenizer).cloned()
+    }
+
+    fn reload_model(
+        &mut self,
+        model_path: String,
+        tokenizer_path: Option<String>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<()> {
+        self.reload_model(model_path, tokenizer_path, ort_options)
+    }
+
+    fn dimension(&self) -> Result<usize> {
+        self.dimension()
+    }
+}
@@ -0,0 +1,462 @@
+use std::sync::Arc;
+
+use flutter_rust_bridge::frb;
+
+use crate::api::embeddings::pipeline::{self, InputNames, InputStyle, ModelSpec, Pooling};
+use crate::api::embeddings::{
+    apply_instructions, declared_dimension, embed_many_with, embed_stream_with, load_replacement,
+    loaded, probe_dimension, BatchOptions, CancellationToken, EmbedManyOptions, EmbedStats,
+    EmbeddingProgress, EmbeddingsWithStats, TextEmbedder, TokenEmbeddings, EMBEDDING_OUTPUTS,
+    WARMUP_TEXT,
+};
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::ort::{
+    build_session_from_file_with_init, build_session_from_memory_with_init, is_deterministic,
+    session_model_info, ModelInfo, OrtInitOptions, SessionPool,
+};
+use crate::frb_generated::StreamSink;
+
+/// Template used by gte-Qwen2 and e5-mistral style instruct models.
+const INSTRUCT_TEMPLATE: &str = "Instruct: {instruction}\nQuery: ";
+
+/// Embedder for any encoder export, described by a JSON config instead of a
+/// dedicated struct. Every key is optional:
+///
+/// ```json
+/// {
+///   "pooling": "mean",
+///   "normalize": true,
+///   "query_prefix": "query: ",
+///   "document_prefix": "passage: ",
+///   "output": "last_hidden_state",
+///   "instructions": "instructor",
+///   "inputs": {
+///     "input_ids": "input_ids",
+///     "attention_mask": "attention_mask",
+///     "token_type_ids": "token_type_ids"
+///   }
+/// }
+/// ```
+///
+/// `pooling` is `mean` (default), `cls` or `last_token` and only applies to
+/// `[batch, seq_len, hidden]` outputs. `output` is a name or a list tried in
+/// order; by default the usual pooled outputs, then `last_hidden_state`.
+/// `inputs` maps the standard input names to the ones in the graph.
+/// `instructions` sets how `embed_with_instructions` renders instructions:
+/// `instructor` prepends them and leaves their tokens out of mean pooling,
+/// `instruct` uses the `Instruct: …\nQuery: ` template of gte-Qwen and
+/// e5-mistral, and any string containing `{instruction}` is used as the
+/// template. By default instructions are prepended as-is.
+#[frb(opaque)]
+pub struct GenericEmbedder {
+    tokenizer: Option<Arc<tokenizers::Tokenizer>>,
+    session: SessionPool,
+    spec: ModelSpec,
+    instructions: InstructionFormat,
+}
+
+/// How `embed_with_instructions` turns an instruction into a text prefix.
+enum InstructionFormat {
+    Prepend,
+    /// Prepended, and excluded from mean pooling.
+    Instructor,
+    /// `{instruction}` is replaced with the instruction.
+    Template(String),
+}
+
+impl InstructionFormat {
+    fn render(&self, instruction: &str) -> String {
+        match self {
+            InstructionFormat::Prepend | InstructionFormat::Instructor => instruction.to_string(),
+            InstructionFormat::Template(template) => template.replace("{instruction}", instruction),
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GenericConfig {
+    pooling: Option<Pooling>,
+    normalize: Option<bool>,
+    query_prefix: Option<String>,
+    document_prefix: Option<String>,
+    output: Option<OutputNames>,
+    inputs: Option<InputMapping>,
+    instructions: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum OutputNames {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct InputMapping {
+    input_ids: Option<String>,
+    attention_mask: Option<String>,
+    token_type_ids: Option<String>,
+}
+
+#[frb(sync)]
+impl GenericEmbedder {
+    pub fn create(
+        model_path: String,
+        tokenizer_path: String,
+        config_json: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_with_options(model_path, tokenizer_path, config_json, None)
+    }
+
+    pub fn create_with_options(
+        model_path: String,
+        tokenizer_path: String,
+        config_json: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let (mut spec, instructions) = parse_config(&config_json)?;
+        spec.deterministic = is_deterministic(ort_options.as_ref());
+        let tokenizer = tokenizers::Tokenizer::from_file(tokenizer_path)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let session = build_session_from_file_with_init(model_path, ort_options)?;
+        Ok(Self {
+            tokenizer: Some(Arc::new(tokenizer)),
+            session: SessionPool::new(session),
+            spec,
+            instructions,
+        })
+    }
+
+    /// `create` from in-memory model bytes and tokenizer JSON.
+    pub fn create_from_bytes(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        config_json: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_bytes_with_options(model_bytes, tokenizer_json, config_json, None)
+    }
+
+    pub fn create_from_bytes_with_options(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        config_json: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let (mut spec, instructions) = parse_config(&config_json)?;
+        spec.deterministic = is_deterministic(ort_options.as_ref());
+        let tokenizer = tokenizers::Tokenizer::from_bytes(tokenizer_json)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let session = build_session_from_memory_with_init(&model_bytes, ort_options)?;
+        Ok(Self {
+            tokenizer: Some(Arc::new(tokenizer)),
+            session: SessionPool::new(session),
+            spec,
+            instructions,
+        })
+    }
+
+    pub fn embed(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        pipeline::embed(
+            &mut *self.session.acquire()?,
+            loaded(&self.tokenizer)?,
+            &self.spec,
+            texts,
+            &[],
+            &[],
+            None,
+        )
+    }
+
+    /// Embeds `(instruction, text)` pairs, rendering each instruction as
+    /// configured by `instructions`. Texts without one are embedded as-is.
+    pub fn embed_with_instructions(
+        &self,
+        items: Vec<(Option<String>, String)>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        let (texts, prefix_lens) =
+            apply_instructions(items, |instruction| self.instructions.render(instruction));
+        let prefix_lens = match self.instructions {
+            InstructionFormat::Instructor => prefix_lens,
+            _ => Vec::new(),
+        };
+        pipeline::embed(
+            &mut *self.session.acquire()?,
+            loaded(&self.tokenizer)?,
+            &self.spec,
+            texts,
+            &prefix_lens,
+            &[],
+            None,
+        )
+    }
+
+    /// `embed` plus tokenization/inference timings and peak memory.
+    pub fn embed_with_stats(
+        &self,
+        texts: Vec<String>,
+    ) -> Result<EmbeddingsWithStats, FlutterEmbedderError> {
+        let mut stats = EmbedStats::default();
+        let embeddings = pipeline::embed(
+            &mut *self.session.acquire()?,
+            loaded(&self.tokenizer)?,
+            &self.spec,
+            texts,
+            &[],
+            &[],
+            Some(&mut stats),
+        )?;
+        Ok(EmbeddingsWithStats { embeddings, stats })
+    }
+
+    /// Returns per-token `last_hidden_state` vectors (with offsets) instead of
+    /// a pooled embedding, for custom pooling or late-interaction scoring.
+    pub fn embed_tokens(
+        &self,
+        texts: Vec<String>,
+    ) -> Result<Vec<TokenEmbeddings>, FlutterEmbedderError> {
+        pipeline::token_embeddings(
+            &mut *self.session.acquire()?,
+            loaded(&self.tokenizer)?,
+            &self.spec,
+            texts,
+            &[],
+        )
+    }
+
+    /// Limits for the micro-batches `embed` splits large inputs into.
+    pub fn set_batch_options(&mut self, options: BatchOptions) {
+        self.spec.batching = options;
+    }
+
+    /// Inputs, outputs and metadata of the loaded ONNX model.
+    pub fn model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&*self.session.acquire()?)
+    }
+
+    /// Length of the vectors `embed` returns, read from the model outputs
+    /// or, when the export leaves it dynamic, from a one-token dry run.
+    pub fn dimension(&self) -> Result<usize, FlutterEmbedderError> {
+        if let Some(dim) = declared_dimension(&*self.session.acquire()?) {
+            return Ok(dim);
+        }
+        probe_dimension(|texts| self.embed(texts))
+    }
+
+    /// Frees the ONNX sessions and tokenizer now rather than when the object
+    /// is dropped, e.g. before loading another model on a memory-constrained
+    /// device. Later calls fail with `ErrorKind::Closed`.
+    pub fn close(&mut self) {
+        self.session.close();
+        self.tokenizer = None;
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.session.is_closed()
+    }
+
+    /// Replaces the model, e.g. with a larger variant of the same family,
+    /// without recreating the embedder. The new session is built before the
+    /// old one is dropped, so a failed load leaves the embedder untouched.
+    /// The tokenizer is kept unless `tokenizer_path` is given.
+    pub fn reload_model(
+        &mut self,
+        model_path: String,
+        tokenizer_path: Option<String>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<(), FlutterEmbedderError> {
+        let deterministic = is_deterministic(ort_options.as_ref());
+        let (session, tokenizer) =
+            load_replacement(&self.tokenizer, model_path, tokenizer_path, ort_options)?;
+        self.session = SessionPool::new(session);
+        self.spec.deterministic = deterministic;
+        if let Some(tokenizer) = tokenizer {
+            self.tokenizer = Some(tokenizer);
+        }
+        Ok(())
+    }
+
+    /// Prepends the configured `query_prefix`.
+    pub fn format_query(&self, query: String) -> String {
+        self.spec.format_query(&query)
+    }
+
+    /// Prepends the configured `document_prefix`.
+    pub fn format_document(&self, text: String) -> String {
+        self.spec.format_document(&text)
+    }
+
+    /// Prepends the configured `query_prefix` to every query and embeds
+    /// the batch.
+    pub fn embed_queries(
+        &self,
+        queries: Vec<String>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        let queries = queries
+            .into_iter()
+            .map(|query| self.format_query(query))
+            .collect();
+        self.embed(queries)
+    }
+
+    /// Prepends the configured `document_prefix` to every text and embeds
+    /// the batch.
+    pub fn embed_documents(
+        &self,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        let texts = texts
+            .into_iter()
+            .map(|text| self.format_document(text))
+            .collect();
+        self.embed(texts)
+    }
+}
+
+impl GenericEmbedder {
+    /// Runs a one-word batch through the model so the first real `embed`
+    /// doesn't pay for graph initialization.
+    pub fn warmup(&self) -> Result<(), FlutterEmbedderError> {
+        self.embed(vec![WARMUP_TEXT.to_string()])?;
+        Ok(())
+    }
+
+    /// Same as `embed`, but runs off the Dart isolate.
+    pub fn embed_async(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.embed(texts)
+    }
+
+    /// Embeds large corpora in length-sorted chunks (see `EmbedManyOptions`).
+    pub fn embed_many(
+        &self,
+        texts: Vec<String>,
+        options: Option<EmbedManyOptions>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        embed_many_with(texts, options, None, None, |batch| self.embed(batch))
+    }
+
+    /// Embeds `texts` in input order, `batch_size` at a time, sending each
+    /// batch to `sink` as soon as it is ready.
+    pub fn embed_stream(
+        &self,
+        texts: Vec<String>,
+        batch_size: u32,
+        sink: StreamSink<EmbeddingProgress>,
+    ) -> Result<(), FlutterEmbedderError> {
+        embed_stream_with(texts, batch_size, None, &sink, |batch| self.embed(batch))
+    }
+
+    /// `embed_async` that fails with a cancellation error once `token` is
+    /// cancelled.
+    pub fn embed_async_cancellable(
+        &self,
+        texts: Vec<String>,
+        token: &CancellationToken,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        embed_many_with(texts, None, None, Some(token), |batch| self.embed(batch))
+    }
+}
+
+#[frb(ignore)]
+impl TextEmbedder for GenericEmbedder {
+    fn embed(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>> {
+        self.embed(texts)
+    }
+
+    fn embed_with_stats(&self, texts: Vec<String>) -> Result<EmbeddingsWithStats> {
+        self.embed_with_stats(texts)
+    }
+
+    fn format_query(&self, query: String) -> String {
+        self.format_query(query)
+    }
+
+    fn format_document(&self, text: String) -> String {
+        self.format_document(text)
+    }
+
+    fn embed_tokens(&self, texts: Vec<String>) -> Result<Vec<TokenEmbeddings>> {
+        self.embed_tokens(texts)
+    }
+
+    fn model_info(&self) -> Result<ModelInfo> {
+        self.model_info()
+    }
+
+    fn close(&mut self) {
+        self.close()
+    }
+
+    fn is_closed(&self) -> bool {
+        self.is_closed()
+    }
+
+    fn tokenizer(&self) -> Result<Arc<tokenizers::Tokenizer>> {
+        loaded(&self.tokenizer).cloned()
+    }
+
+    fn reload_model(
+        &mut self,
+        model_path: String,
+        tokenizer_path: Option<String>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<()> {
+        self.reload_model(model_path, tokenizer_path, ort_options)
+    }
+
+    fn warmup(&self) -> Result<()> {
+        self.warmup()
+    }
+
+    fn dimension(&self) -> Result<usize> {
+        self.dimension()
+    }
+}
+
+fn parse_config(json: &str) -> Result<(ModelSpec, InstructionFormat)> {
+    let config: GenericConfig = serde_json::from_str(json)
+        .map_err(|e| err!(InvalidInput, "Invalid embedder config: {e}"))?;
+    let outputs = match config.output {
+        Some(OutputNames::One(name)) => vec![name],
+        Some(OutputNames::Many(names)) if !names.is_empty() => names,
+        Some(OutputNames::Many(_)) => {
+            return Err(err!(InvalidInput, "Embedder config lists no outputs"))
+        }
+        None => pipeline::output_names(&EMBEDDING_OUTPUTS),
+    };
+    let mut input_names = InputNames::standard();
+    if let Some(mapping) = config.inputs {
+        let fields = [
+            (&mut input_names.input_ids, mapping.input_ids),
+            (&mut input_names.attention_mask, mapping.attention_mask),
+            (&mut input_names.token_type_ids, mapping.token_type_ids),
+        ];
+        for (field, name) in fields {
+            if let Some(name) = name {
+                *field = name;
+            }
+        }
+    }
+    let instructions = match config.instructions.as_deref() {
+        None => InstructionFormat::Prepend,
+        Some("instructor") => InstructionFormat::Instructor,
+        Some("instruct") => InstructionFormat::Template(INSTRUCT_TEMPLATE.to_string()),
+        Some(template) if template.contains("{instruction}") => {
+            InstructionFormat::Template(template.to_string())
+        }
+        Some(other) => return Err(err!(InvalidInput, "Unknown instructions format: {other}")),
+    };
+    let spec = ModelSpec {
+        inputs: InputStyle::Encoder,
+        input_names,
+        outputs,
+        pooling: config.pooling.unwrap_or(Pooling::Mean),
+        normalize: config.normalize.unwrap_or(true),
+        query_prefix: config.query_prefix.unwrap_or_default(),
+        document_prefix: config.document_prefix.unwrap_or_default(),
+        batching: BatchOptions::default(),
+        deterministic: false,
+    };
+    Ok((spec, instructions))
+}
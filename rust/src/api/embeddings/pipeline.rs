@@ -0,0 +1,859 @@
+//! Tokenize, pad, run and pool: the steps shared by the text embedders,
+//! driven by a declarative `ModelSpec` so a model differs only in data.
+
+use ndarray::{Array2, ArrayD, IxDyn};
+use ort::session::{Session, SessionOutputs};
+use ort::tensor::TensorElementType;
+use ort::value::{DynTensor, Tensor, ValueType};
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::api::embeddings::{
+    exclude_prefix_from_pooling, pad_encodings, peak_memory_bytes, token_embeddings_from_hidden,
+    BatchOptions, EmbedStats, TokenEmbeddings,
+};
+use crate::api::error::{err, Result};
+use crate::api::tokenizer::unpadded_len;
+use crate::api::utils::{mean_pooling_ndarray, normalize};
+
+const DEFAULT_MAX_BATCH_SIZE: usize = 32;
+const DEFAULT_MAX_TOKENS_PER_BATCH: usize = 16 * 1024;
+
+/// How a `[batch, seq_len, hidden]` output becomes one vector per text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Pooling {
+    /// Average over attended tokens (instruction prefixes excluded).
+    Mean,
+    /// The first token, i.e. `[CLS]`.
+    Cls,
+    /// The last attended token, for decoder-only models.
+    LastToken,
+}
+
+/// How the model inputs are built from the tokenizer output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum InputStyle {
+    /// `input_ids`, `attention_mask` and, when the model declares it, zeroed
+    /// `token_type_ids`.
+    Encoder,
+    /// Whatever a decoder-only export declares (see `decoder_inputs`), with
+    /// `eos` appended to sequences that don't already end with it.
+    Decoder { eos: Option<u32> },
+}
+
+/// Model input names for the `InputStyle::Encoder` feeds.
+#[derive(Debug, Clone)]
+pub(crate) struct InputNames {
+    pub(crate) input_ids: String,
+    pub(crate) attention_mask: String,
+    pub(crate) token_type_ids: String,
+}
+
+impl InputNames {
+    /// The names used by Hugging Face exports.
+    pub(crate) fn standard() -> Self {
+        Self {
+            input_ids: "input_ids".to_string(),
+            attention_mask: "attention_mask".to_string(),
+            token_type_ids: "token_type_ids".to_string(),
+        }
+    }
+}
+
+/// Everything that distinguishes one text embedding model from another.
+#[derive(Debug, Clone)]
+pub(crate) struct ModelSpec {
+    pub(crate) inputs: InputStyle,
+    pub(crate) input_names: InputNames,
+    /// Output names tried in order. Rank-2 outputs are used as they are;
+    /// rank-3 ones are reduced with `pooling`.
+    pub(crate) outputs: Vec<String>,
+    pub(crate) pooling: Pooling,
+    pub(crate) normalize: bool,
+    pub(crate) query_prefix: String,
+    pub(crate) document_prefix: String,
+    pub(crate) batching: BatchOptions,
+    /// Runs each unique text alone and unpadded, so its vector doesn't
+    /// depend on the rest of the call. Set for deterministic sessions.
+    pub(crate) deterministic: bool,
+}
+
+impl ModelSpec {
+    pub(crate) fn format_query(&self, query: &str) -> String {
+        format!("{}{query}", self.query_prefix)
+    }
+
+    pub(crate) fn format_document(&self, text: &str) -> String {
+        format!("{}{text}", self.document_prefix)
+    }
+}
+
+/// Output names as owned strings, for building a `ModelSpec`.
+pub(crate) fn output_names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+/// Embeds `texts` as described by `spec`. `prefix_lens[i]` bytes at the
+/// start of `texts[i]` are left out of mean pooling, and every
+/// `extra_inputs` entry is fed as a `[batch]` tensor (e.g. Jina's
+/// `task_id`).
+///
+/// Duplicate inputs (same text and prefix) run once and share a vector.
+/// The rest are sorted by token count and run in micro-batches bounded by
+/// `spec.batching`, or one at a time when `spec.deterministic` is set;
+/// results come back in input order. Timings are written
+/// to `stats` when given and count each unique text once.
+pub(crate) fn embed(
+    session: &mut Session,
+    tokenizer: &tokenizers::Tokenizer,
+    spec: &ModelSpec,
+    texts: Vec<String>,
+    prefix_lens: &[usize],
+    extra_inputs: &[(&str, i64)],
+    stats: Option<&mut EmbedStats>,
+) -> Result<Vec<Vec<f32>>> {
+    if texts.is_empty() {
+        return Ok(Vec::new());
+    }
+    let started = Instant::now();
+    let (texts, prefix_lens, slots) = dedup(texts, prefix_lens);
+    // Padding is left to `embed_batch`, per micro-batch.
+    let mut encodings = encode(&without_padding(tokenizer), texts)?;
+    let tokenized = Instant::now();
+    let lengths: Vec<usize> = encodings.iter().map(|e| unpadded_len(e) as usize).collect();
+    let mut results = vec![Vec::new(); encodings.len()];
+    let batches = if spec.deterministic {
+        (0..lengths.len()).map(|i| vec![i]).collect()
+    } else {
+        micro_batches(&lengths, &spec.batching)
+    };
+    for chunk in batches {
+        let batch: Vec<tokenizers::Encoding> = chunk
+            .iter()
+            .map(|&i| std::mem::take(&mut encodings[i]))
+            .collect();
+        let batch_prefix_lens: Vec<usize> = chunk.iter().map(|&i| prefix_lens[i]).collect();
+        let vectors = embed_batch(
+            session,
+            tokenizer,
+            spec,
+            &batch,
+            &batch_prefix_lens,
+            extra_inputs,
+        )?;
+        for (&i, vector) in chunk.iter().zip(vectors) {
+            results[i] = vector;
+        }
+    }
+    if let Some(stats) = stats {
+        let tokens: usize = lengths.iter().sum();
+        let total = started.elapsed().as_secs_f64();
+        *stats = EmbedStats {
+            tokenization_ms: (tokenized - started).as_secs_f64() * 1000.0,
+            inference_ms: tokenized.elapsed().as_secs_f64() * 1000.0,
+            tokens: tokens as u64,
+            tokens_per_second: if total > 0.0 {
+                tokens as f64 / total
+            } else {
+                0.0
+            },
+            peak_memory_bytes: peak_memory_bytes(),
+        };
+    }
+    Ok(slots.into_iter().map(|i| results[i].clone()).collect())
+}
+
+/// Unique `(text, prefix_len)` pairs in first-seen order, plus the index
+/// into them of every input.
+fn dedup(texts: Vec<String>, prefix_lens: &[usize]) -> (Vec<String>, Vec<usize>, Vec<usize>) {
+    let mut seen: HashMap<(String, usize), usize> = HashMap::new();
+    let (mut unique, mut unique_prefix_lens) = (Vec::new(), Vec::new());
+    let slots = texts
+        .into_iter()
+        .enumerate()
+        .map(|(i, text)| {
+            let prefix_len = prefix_lens.get(i).copied().unwrap_or(0);
+            *seen
+                .entry((text, prefix_len))
+                .or_insert_with_key(|(text, _)| {
+                    unique.push(text.clone());
+                    unique_prefix_lens.push(prefix_len);
+                    unique.len() - 1
+                })
+        })
+        .collect();
+    (unique, unique_prefix_lens, slots)
+}
+
+/// Groups input indices into batches of similar length: sorted by token
+/// count, then cut whenever the next text would exceed `max_batch_size` or
+/// make the padded batch exceed `max_tokens_per_batch`.
+fn micro_batches(lengths: &[usize], options: &BatchOptions) -> Vec<Vec<usize>> {
+    let max_batch = options
+        .max_batch_size
+        .map(|b| b as usize)
+        .unwrap_or(DEFAULT_MAX_BATCH_SIZE)
+        .max(1);
+    let max_tokens = options
+        .max_tokens_per_batch
+        .map(|t| t as usize)
+        .unwrap_or(DEFAULT_MAX_TOKENS_PER_BATCH);
+    let mut order: Vec<usize> = (0..lengths.len()).collect();
+    order.sort_by_key(|&i| lengths[i]);
+
+    let mut batches: Vec<Vec<usize>> = Vec::new();
+    let mut current: Vec<usize> = Vec::new();
+    for i in order {
+        // Sorted ascending, so the newest text is the longest in the batch.
+        let padded = (current.len() + 1) * lengths[i];
+        if !current.is_empty() && (current.len() == max_batch || padded > max_tokens) {
+            batches.push(std::mem::take(&mut current));
+        }
+        current.push(i);
+    }
+    if !current.is_empty() {
+        batches.push(current);
+    }
+    batches
+}
+
+/// Runs one micro-batch; `prefix_lens` is aligned with `encodings`.
+fn embed_batch(
+    session: &mut Session,
+    tokenizer: &tokenizers::Tokenizer,
+    spec: &ModelSpec,
+    encodings: &[tokenizers::Encoding],
+    prefix_lens: &[usize],
+    extra_inputs: &[(&str, i64)],
+) -> Result<Vec<Vec<f32>>> {
+    let batch = encodings.len();
+    let pad_id = pad_id(tokenizer);
+    let (inputs, masks) = match spec.inputs {
+        InputStyle::Encoder => {
+            let (max_len, input_ids, attention_mask) = pad_encodings(encodings, pad_id);
+            if max_len == 0 {
+                return Ok(vec![Vec::new(); batch]);
+            }
+            let masks = encodings
+                .iter()
+                .enumerate()
+                .map(|(i, encoding)| {
+                    let mut mask = encoding.get_attention_mask().to_vec();
+                    if let Some(&prefix_len) = prefix_lens.get(i) {
+                        exclude_prefix_from_pooling(&mut mask, encoding.get_offsets(), prefix_len);
+                    }
+                    mask.resize(max_len, 0);
+                    mask
+                })
+                .collect();
+            let inputs = encoder_inputs(
+                session,
+                &spec.input_names,
+                batch,
+                max_len,
+                input_ids,
+                attention_mask,
+                extra_inputs,
+            )?;
+            (inputs, masks)
+        }
+        InputStyle::Decoder { eos } => {
+            let sequences: Vec<(Vec<u32>, Vec<u32>)> = encodings
+                .iter()
+                .map(|encoding| with_eos(encoding, eos))
+                .collect();
+            let DecoderInputs {
+                mut inputs, masks, ..
+            } = decoder_inputs(session, &sequences, pad_id)?;
+            for (name, value) in extra_inputs {
+                inputs.push((
+                    name.to_string(),
+                    Tensor::from_array(([batch], vec![*value; batch]))?.upcast(),
+                ));
+            }
+            (inputs, masks)
+        }
+    };
+
+    let outputs = session.run(inputs)?;
+    let (output, value) = spec
+        .outputs
+        .iter()
+        .find_map(|name| outputs.get(name.as_str()).map(|value| (name, value)))
+        .ok_or(err!(
+            MissingOutput,
+            "No embedding output found, expected one of {:?}",
+            spec.outputs
+        ))?;
+    let (shape, data) = value.try_extract_tensor::<f32>()?;
+    let shape: Vec<usize> = shape.iter().map(|d| *d as usize).collect();
+    tracing::trace!(output, ?shape, pooling = ?spec.pooling, "Picked embedding output");
+    if shape.first() != Some(&batch) {
+        return Err(err!(ShapeMismatch, "Batch size mismatch in outputs"));
+    }
+
+    let mut results = Vec::with_capacity(batch);
+    for (i, mask) in masks.iter().enumerate().take(batch) {
+        let embedding = match shape.as_slice() {
+            [_, hidden] => data
+                .get(i * hidden..(i + 1) * hidden)
+                .ok_or(err!(ShapeMismatch, "Invalid output slice"))?
+                .to_vec(),
+            [_, seq_len, hidden] => pool(data, i, *seq_len, *hidden, mask, spec.pooling)?,
+            _ => return Err(err!(ShapeMismatch, "Unexpected output shape: {shape:?}")),
+        };
+        results.push(if spec.normalize {
+            normalize(&embedding)
+        } else {
+            embedding
+        });
+    }
+    Ok(results)
+}
+
+/// Per-token `last_hidden_state` vectors of an encoder model, padding
+/// dropped.
+pub(crate) fn token_embeddings(
+    session: &mut Session,
+    tokenizer: &tokenizers::Tokenizer,
+    spec: &ModelSpec,
+    texts: Vec<String>,
+    extra_inputs: &[(&str, i64)],
+) -> Result<Vec<TokenEmbeddings>> {
+    if texts.is_empty() {
+        return Ok(Vec::new());
+    }
+    let (encodings, outputs) = forward(session, tokenizer, spec, texts, extra_inputs)?;
+    let (shape, data) = outputs
+        .get("last_hidden_state")
+        .ok_or(err!(MissingOutput, "Missing last_hidden_state"))?
+        .try_extract_tensor::<f32>()?;
+    let shape: Vec<usize> = shape.iter().map(|d| *d as usize).collect();
+    token_embeddings_from_hidden(&encodings, &shape, data)
+}
+
+/// Runs an encoder model on `texts` and returns the raw outputs alongside
+/// the encodings, for per-token APIs.
+pub(crate) fn forward<'s>(
+    session: &'s mut Session,
+    tokenizer: &tokenizers::Tokenizer,
+    spec: &ModelSpec,
+    texts: Vec<String>,
+    extra_inputs: &[(&str, i64)],
+) -> Result<(Vec<tokenizers::Encoding>, SessionOutputs<'s>)> {
+    let encodings = encode(tokenizer, texts)?;
+    let batch = encodings.len();
+    let (max_len, input_ids, attention_mask) = pad_encodings(&encodings, pad_id(tokenizer));
+    if max_len == 0 {
+        return Err(err!(InvalidInput, "Inputs produced no tokens"));
+    }
+    let inputs = encoder_inputs(
+        session,
+        &spec.input_names,
+        batch,
+        max_len,
+        input_ids,
+        attention_mask,
+        extra_inputs,
+    )?;
+    let outputs = session.run(inputs)?;
+    Ok((encodings, outputs))
+}
+
+fn encode(
+    tokenizer: &tokenizers::Tokenizer,
+    texts: Vec<String>,
+) -> Result<Vec<tokenizers::Encoding>> {
+    tokenizer
+        .encode_batch(texts, true)
+        .map_err(|e| err!(Tokenization, "{e}"))
+}
+
+/// `tokenizer` with its padding turned off, so every text keeps its own
+/// length instead of the longest or fixed one.
+fn without_padding(tokenizer: &tokenizers::Tokenizer) -> Cow<'_, tokenizers::Tokenizer> {
+    if tokenizer.get_padding().is_none() {
+        return Cow::Borrowed(tokenizer);
+    }
+    let mut tokenizer = tokenizer.clone();
+    tokenizer.with_padding(None);
+    Cow::Owned(tokenizer)
+}
+
+fn pad_id(tokenizer: &tokenizers::Tokenizer) -> i64 {
+    tokenizer
+        .get_padding()
+        .map(|p| p.pad_id as i64)
+        .unwrap_or(0)
+}
+
+fn encoder_inputs(
+    session: &Session,
+    names: &InputNames,
+    batch: usize,
+    max_len: usize,
+    input_ids: Vec<i64>,
+    attention_mask: Vec<i64>,
+    extra_inputs: &[(&str, i64)],
+) -> Result<Vec<(String, DynTensor)>> {
+    let mut inputs: Vec<(String, DynTensor)> = vec![
+        (
+            names.input_ids.clone(),
+            Tensor::from_array(([batch, max_len], input_ids))?.upcast(),
+        ),
+        (
+            names.attention_mask.clone(),
+            Tensor::from_array(([batch, max_len], attention_mask))?.upcast(),
+        ),
+    ];
+    if session
+        .inputs()
+        .iter()
+        .any(|input| input.name() == names.token_type_ids)
+    {
+        inputs.push((
+            names.token_type_ids.clone(),
+            Tensor::from_array(([batch, max_len], vec![0i64; batch * max_len]))?.upcast(),
+        ));
+    }
+    for (name, value) in extra_inputs {
+        inputs.push((
+            name.to_string(),
+            Tensor::from_array(([batch], vec![*value; batch]))?.upcast(),
+        ));
+    }
+    Ok(inputs)
+}
+
+/// Pools row `i` of a `[batch, seq_len, hidden]` tensor.
+fn pool(
+    data: &[f32],
+    i: usize,
+    seq_len: usize,
+    hidden: usize,
+    mask: &[u32],
+    pooling: Pooling,
+) -> Result<Vec<f32>> {
+    let mask = fit_mask(mask, seq_len);
+    let token = |t: usize| {
+        let start = (i * seq_len + t) * hidden;
+        data.get(start..start + hidden)
+            .map(<[f32]>::to_vec)
+            .ok_or(err!(ShapeMismatch, "Invalid token slice"))
+    };
+    match pooling {
+        Pooling::Cls => token(0),
+        Pooling::LastToken => token(
+            mask.iter()
+                .rposition(|&m| m == 1)
+                .unwrap_or(seq_len.saturating_sub(1)),
+        ),
+        Pooling::Mean => {
+            let start = i * seq_len * hidden;
+            let slice = data
+                .get(start..start + seq_len * hidden)
+                .ok_or(err!(ShapeMismatch, "Invalid output slice"))?;
+            let embeddings = Array2::from_shape_vec((seq_len, hidden), slice.to_vec())?;
+            Ok(mean_pooling_ndarray(&embeddings, &mask))
+        }
+    }
+}
+
+/// Returns the attended ids of `encoding` (padding stripped) with `eos`
+/// appended when the sequence doesn't already end with it.
+fn with_eos(encoding: &tokenizers::Encoding, eos: Option<u32>) -> (Vec<u32>, Vec<u32>) {
+    let mut ids: Vec<u32> = encoding
+        .get_ids()
+        .iter()
+        .zip(encoding.get_attention_mask())
+        .filter(|(_, &m)| m != 0)
+        .map(|(&id, _)| id)
+        .collect();
+    if let Some(eos) = eos {
+        if ids.last() != Some(&eos) {
+            ids.push(eos);
+        }
+    }
+    let mask = vec![1; ids.len()];
+    (ids, mask)
+}
+
+pub(crate) struct DecoderInputs {
+    pub(crate) inputs: Vec<(String, DynTensor)>,
+    pub(crate) batch: usize,
+    /// Right-padded attention masks, one per sequence.
+    pub(crate) masks: Vec<Vec<u32>>,
+}
+
+/// Builds the feeds for a decoder-only export from unpadded `(ids, mask)`
+/// sequences, adapting to whatever inputs the session declares (rank-1/4
+/// masks, position ids, empty past key values).
+pub(crate) fn decoder_inputs(
+    session: &Session,
+    sequences: &[(Vec<u32>, Vec<u32>)],
+    pad_id: i64,
+) -> Result<DecoderInputs> {
+    let mut batch = sequences.len();
+    for input in session.inputs() {
+        if input.name() == "input_ids" {
+            if let ValueType::Tensor { shape, .. } = input.dtype() {
+                if let Some(dim) = shape.first() {
+                    if *dim > 0 && *dim as usize != batch {
+                        return Err(err!(ShapeMismatch, "Batch size mismatch for input_ids"));
+                    }
+                    if *dim > 0 {
+                        batch = *dim as usize;
+                    }
+                }
+            }
+            break;
+        }
+    }
+
+    let max_len = sequences
+        .iter()
+        .map(|(ids, _)| ids.len())
+        .max()
+        .unwrap_or(0);
+    if max_len == 0 {
+        return Err(err!(InvalidInput, "Inputs produced no tokens"));
+    }
+
+    let mut input_ids_batch = Vec::with_capacity(batch * max_len);
+    let mut mask_batch = Vec::with_capacity(batch * max_len);
+    let mut masks_u32 = Vec::with_capacity(batch);
+
+    for (ids, mask) in sequences {
+        let pad_len = max_len.saturating_sub(ids.len());
+
+        let mut ids_i64: Vec<i64> = ids.iter().map(|&x| x as i64).collect();
+        let mut mask_i64: Vec<i64> = mask.iter().map(|&x| x as i64).collect();
+        let mut mask_u32: Vec<u32> = mask.to_vec();
+
+        ids_i64.extend(std::iter::repeat_n(pad_id, pad_len));
+        mask_i64.extend(std::iter::repeat_n(0, pad_len));
+        mask_u32.extend(std::iter::repeat_n(0, pad_len));
+
+        input_ids_batch.extend_from_slice(&ids_i64);
+        mask_batch.extend_from_slice(&mask_i64);
+        masks_u32.push(mask_u32);
+    }
+
+    let position_ids: Vec<i64> = (0..max_len as i64).collect();
+    let position_batch = repeat_i64(&position_ids, batch);
+
+    let mut inputs: Vec<(String, DynTensor)> = Vec::new();
+    for input in session.inputs() {
+        let name = input.name();
+        match name {
+            "input_ids" => {
+                let shape = resolve_shape_with_fallback(input.dtype(), &[batch, max_len])?;
+                let tensor = tensor_from_i64(input.dtype(), &shape, &input_ids_batch)?;
+                inputs.push((name.to_string(), tensor));
+            }
+            "attention_mask" => {
+                let rank = if let ValueType::Tensor { shape, .. } = input.dtype() {
+                    shape.len()
+                } else {
+                    2
+                };
+                let (shape, data) = if rank == 1 {
+                    if batch > 1 {
+                        return Err(err!(
+                            ShapeMismatch,
+                            "attention_mask rank 1 is not batch-compatible"
+                        ));
+                    }
+                    (
+                        resolve_shape_with_fallback(input.dtype(), &[max_len])?,
+                        mask_batch[..max_len].to_vec(),
+                    )
+                } else if rank == 4 {
+                    let shape =
+                        resolve_shape_with_fallback(input.dtype(), &[batch, 1, max_len, max_len])?;
+                    let mut data = Vec::with_capacity(batch * max_len * max_len);
+                    for mask in &masks_u32 {
+                        let mask_i64: Vec<i64> = mask.iter().map(|&v| v as i64).collect();
+                        for _ in 0..max_len {
+                            data.extend_from_slice(&mask_i64);
+                        }
+                    }
+                    (shape, data)
+                } else {
+                    (
+                        resolve_shape_with_fallback(input.dtype(), &[batch, max_len])?,
+                        mask_batch.clone(),
+                    )
+                };
+                let tensor = tensor_from_i64(input.dtype(), &shape, &data)?;
+                inputs.push((name.to_string(), tensor));
+            }
+            "position_ids" | "cache_position" => {
+                let rank = if let ValueType::Tensor { shape, .. } = input.dtype() {
+                    shape.len()
+                } else {
+                    2
+                };
+                let (shape, data) = if rank == 1 {
+                    if batch > 1 {
+                        return Err(err!(
+                            ShapeMismatch,
+                            "position_ids rank 1 is not batch-compatible"
+                        ));
+                    }
+                    (
+                        resolve_shape_with_fallback(input.dtype(), &[max_len])?,
+                        position_ids.clone(),
+                    )
+                } else {
+                    (
+                        resolve_shape_with_fallback(input.dtype(), &[batch, max_len])?,
+                        position_batch.clone(),
+                    )
+                };
+                let tensor = tensor_from_i64(input.dtype(), &shape, &data)?;
+                inputs.push((name.to_string(), tensor));
+            }
+            "token_type_ids" => {
+                let shape = resolve_shape_with_fallback(input.dtype(), &[batch, max_len])?;
+                let tensor = zeros_tensor(input.dtype(), &shape)?;
+                inputs.push((name.to_string(), tensor));
+            }
+            _ if name.starts_with("past_key_values") => {
+                let shape = resolve_past_kv_shape(input.dtype(), batch)?;
+                let tensor = zeros_tensor(input.dtype(), &shape)?;
+                inputs.push((name.to_string(), tensor));
+            }
+            _ => {
+                let rank = if let ValueType::Tensor { shape, .. } = input.dtype() {
+                    shape.len()
+                } else {
+                    1
+                };
+                let fallback = match rank {
+                    1 => vec![max_len],
+                    2 => vec![batch, max_len],
+                    _ => vec![1; rank],
+                };
+                let shape = resolve_shape_with_fallback(input.dtype(), &fallback)?;
+                let tensor = zeros_tensor(input.dtype(), &shape)?;
+                inputs.push((name.to_string(), tensor));
+            }
+        }
+    }
+    Ok(DecoderInputs {
+        inputs,
+        batch,
+        masks: masks_u32,
+    })
+}
+
+fn repeat_i64(data: &[i64], times: usize) -> Vec<i64> {
+    let mut out = Vec::with_capacity(data.len() * times);
+    for _ in 0..times {
+        out.extend_from_slice(data);
+    }
+    out
+}
+
+pub(crate) fn resolve_shape_with_fallback(
+    dtype: &ValueType,
+    fallback: &[usize],
+) -> Result<Vec<usize>> {
+    let ValueType::Tensor { shape, .. } = dtype else {
+        return Err(err!(UnsupportedDtype, "Unsupported input type: {dtype:?}"));
+    };
+    if shape.len() != fallback.len() {
+        tracing::debug!(
+            ?shape,
+            ?fallback,
+            "Input rank differs from the expected one; using the fallback shape"
+        );
+        return Ok(fallback.to_vec());
+    }
+    Ok(shape
+        .iter()
+        .zip(fallback.iter())
+        .map(|(dim, fb)| if *dim >= 0 { *dim as usize } else { *fb })
+        .collect())
+}
+
+pub(crate) fn resolve_past_kv_shape(dtype: &ValueType, batch: usize) -> Result<Vec<usize>> {
+    let ValueType::Tensor { shape, .. } = dtype else {
+        return Err(err!(UnsupportedDtype, "Unsupported input type: {dtype:?}"));
+    };
+    let rank = shape.len();
+    let mut resolved = Vec::with_capacity(rank);
+    for (idx, dim) in shape.iter().enumerate() {
+        if *dim >= 0 {
+            resolved.push(*dim as usize);
+            continue;
+        }
+        let value = if idx == 0 {
+            batch
+        } else if idx == rank.saturating_sub(2) {
+            0
+        } else {
+            1
+        };
+        resolved.push(value);
+    }
+    Ok(resolved)
+}
+
+fn fit_mask(mask: &[u32], target_len: usize) -> Vec<u32> {
+    if mask.len() == target_len {
+        return mask.to_vec();
+    }
+    if mask.len() > target_len {
+        return mask[..target_len].to_vec();
+    }
+    let mut out = mask.to_vec();
+    out.extend(std::iter::repeat_n(0, target_len - mask.len()));
+    out
+}
+
+pub(crate) fn tensor_from_i64(
+    dtype: &ValueType,
+    shape: &[usize],
+    data: &[i64],
+) -> Result<DynTensor> {
+    let ValueType::Tensor { ty, .. } = dtype else {
+        return Err(err!(UnsupportedDtype, "Unsupported input type: {dtype:?}"));
+    };
+    let expected: usize = shape.iter().product();
+    if expected != data.len() {
+        return Err(err!(
+            ShapeMismatch,
+            "Input data length mismatch: expected {expected}, got {}",
+            data.len()
+        ));
+    }
+    match ty {
+        TensorElementType::Int64 => {
+            Ok(Tensor::from_array((shape.to_vec(), data.to_vec()))?.upcast())
+        }
+        TensorElementType::Int32 => Ok(Tensor::from_array((
+            shape.to_vec(),
+            data.iter().map(|&v| v as i32).collect::<Vec<i32>>(),
+        ))?
+        .upcast()),
+        TensorElementType::Int16 => Ok(Tensor::from_array((
+            shape.to_vec(),
+            data.iter().map(|&v| v as i16).collect::<Vec<i16>>(),
+        ))?
+        .upcast()),
+        TensorElementType::Int8 => Ok(Tensor::from_array((
+            shape.to_vec(),
+            data.iter().map(|&v| v as i8).collect::<Vec<i8>>(),
+        ))?
+        .upcast()),
+        TensorElementType::Uint64 => Ok(Tensor::from_array((
+            shape.to_vec(),
+            data.iter().map(|&v| v as u64).collect::<Vec<u64>>(),
+        ))?
+        .upcast()),
+        TensorElementType::Uint32 => Ok(Tensor::from_array((
+            shape.to_vec(),
+            data.iter().map(|&v| v as u32).collect::<Vec<u32>>(),
+        ))?
+        .upcast()),
+        TensorElementType::Uint16 => Ok(Tensor::from_array((
+            shape.to_vec(),
+            data.iter().map(|&v| v as u16).collect::<Vec<u16>>(),
+        ))?
+        .upcast()),
+        TensorElementType::Uint8 => Ok(Tensor::from_array((
+            shape.to_vec(),
+            data.iter().map(|&v| v as u8).collect::<Vec<u8>>(),
+        ))?
+        .upcast()),
+        TensorElementType::Bool => Ok(Tensor::from_array((
+            shape.to_vec(),
+            data.iter().map(|&v| v != 0).collect::<Vec<bool>>(),
+        ))?
+        .upcast()),
+        TensorElementType::Float32 => Ok(Tensor::from_array((
+            shape.to_vec(),
+            data.iter().map(|&v| v as f32).collect::<Vec<f32>>(),
+        ))?
+        .upcast()),
+        TensorElementType::Float64 => Ok(Tensor::from_array((
+            shape.to_vec(),
+            data.iter().map(|&v| v as f64).collect::<Vec<f64>>(),
+        ))?
+        .upcast()),
+        TensorElementType::Float16 => Ok(Tensor::from_array((
+            shape.to_vec(),
+            data.iter()
+                .map(|&v| half::f16::from_f32(v as f32))
+                .collect::<Vec<half::f16>>(),
+        ))?
+        .upcast()),
+        TensorElementType::Bfloat16 => Ok(Tensor::from_array((
+            shape.to_vec(),
+            data.iter()
+                .map(|&v| half::bf16::from_f32(v as f32))
+                .collect::<Vec<half::bf16>>(),
+        ))?
+        .upcast()),
+        _ => Err(err!(
+            UnsupportedDtype,
+            "Unsupported tensor element type: {ty:?}"
+        )),
+    }
+}
+
+pub(crate) fn zeros_tensor(dtype: &ValueType, shape: &[usize]) -> Result<DynTensor> {
+    let ValueType::Tensor { ty, .. } = dtype else {
+        return Err(err!(UnsupportedDtype, "Unsupported input type: {dtype:?}"));
+    };
+    match ty {
+        TensorElementType::Float32 => {
+            Ok(Tensor::from_array(ArrayD::<f32>::zeros(IxDyn(shape)))?.upcast())
+        }
+        TensorElementType::Float64 => {
+            Ok(Tensor::from_array(ArrayD::<f64>::zeros(IxDyn(shape)))?.upcast())
+        }
+        TensorElementType::Float16 => {
+            Ok(Tensor::from_array(ArrayD::<half::f16>::zeros(IxDyn(shape)))?.upcast())
+        }
+        TensorElementType::Bfloat16 => {
+            Ok(Tensor::from_array(ArrayD::<half::bf16>::zeros(IxDyn(shape)))?.upcast())
+        }
+        TensorElementType::Int64 => {
+            Ok(Tensor::from_array(ArrayD::<i64>::zeros(IxDyn(shape)))?.upcast())
+        }
+        TensorElementType::Int32 => {
+            Ok(Tensor::from_array(ArrayD::<i32>::zeros(IxDyn(shape)))?.upcast())
+        }
+        TensorElementType::Int16 => {
+            Ok(Tensor::from_array(ArrayD::<i16>::zeros(IxDyn(shape)))?.upcast())
+        }
+        TensorElementType::Int8 => {
+            Ok(Tensor::from_array(ArrayD::<i8>::zeros(IxDyn(shape)))?.upcast())
+        }
+        TensorElementType::Uint64 => {
+            Ok(Tensor::from_array(ArrayD::<u64>::zeros(IxDyn(shape)))?.upcast())
+        }
+        TensorElementType::Uint32 => {
+            Ok(Tensor::from_array(ArrayD::<u32>::zeros(IxDyn(shape)))?.upcast())
+        }
+        TensorElementType::Uint16 => {
+            Ok(Tensor::from_array(ArrayD::<u16>::zeros(IxDyn(shape)))?.upcast())
+        }
+        TensorElementType::Uint8 => {
+            Ok(Tensor::from_array(ArrayD::<u8>::zeros(IxDyn(shape)))?.upcast())
+        }
+        TensorElementType::Bool => {
+            Ok(Tensor::from_array(ArrayD::<bool>::from_elem(IxDyn(shape), false))?.upcast())
+        }
+        _ => Err(err!(
+            UnsupportedDtype,
+            "Unsupported tensor element type: {ty:?}"
+        )),
+    }
+}
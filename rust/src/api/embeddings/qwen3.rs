@@ -1,26 +1,45 @@
-use anyhow::{anyhow, Result};
+use std::sync::Arc;
+
 use flutter_rust_bridge::frb;
-use ndarray::{ArrayD, IxDyn};
-use ort::{
-    tensor::TensorElementType,
-    value::{DynTensor, Tensor, ValueType},
-};
+use ort::session::Session;
 
-use crate::api::ort::{build_session_from_file_with_init, OrtInitOptions};
-use crate::api::utils::normalize;
+use crate::api::embeddings::pipeline::{
+    self, output_names, InputNames, InputStyle, ModelSpec, Pooling,
+};
+use crate::api::embeddings::{
+    apply_instructions, declared_dimension, embed_many_with, embed_stream_with, load_replacement,
+    loaded, probe_dimension, truncate_embeddings, BatchOptions, CancellationToken,
+    EmbedManyOptions, EmbedOptions, EmbedProgress, EmbedStats, EmbeddingProgress,
+    EmbeddingsWithStats, TextEmbedder, WARMUP_TEXT,
+};
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::hub::{resolve_hub_files, HubOptions};
+use crate::api::ort::{
+    build_session_from_file_with_init, build_session_from_memory_with_init, is_deterministic,
+    session_model_info, ModelInfo, OrtInitOptions, SessionRouter, Workload,
+};
+use crate::frb_generated::StreamSink;
 
+/// Default instruction, as used for retrieval in the model card.
 const QWEN3_TASK: &str =
     "Given a web search query, retrieve relevant passages that answer the query";
+const EOS_CANDIDATES: [&str; 3] = ["<|endoftext|>", "<|im_end|>", "</s>"];
 
 #[frb(opaque)]
 pub struct Qwen3Embedder {
-    tokenizer: tokenizers::Tokenizer,
-    session: ort::session::Session,
+    tokenizer: Option<Arc<tokenizers::Tokenizer>>,
+    session: SessionRouter,
+    eos_token_id: Option<u32>,
+    task: String,
+    spec: ModelSpec,
 }
 
 #[frb(sync)]
 impl Qwen3Embedder {
-    pub fn create(model_path: String, tokenizer_path: String) -> Result<Self> {
+    pub fn create(
+        model_path: String,
+        tokenizer_path: String,
+    ) -> Result<Self, FlutterEmbedderError> {
         Self::create_with_options(model_path, tokenizer_path, None)
     }
 
@@ -28,438 +47,446 @@ impl Qwen3Embedder {
         model_path: String,
         tokenizer_path: String,
         ort_options: Option<OrtInitOptions>,
-    ) -> Result<Self> {
-        let tokenizer =
-            tokenizers::Tokenizer::from_file(tokenizer_path).map_err(|e| anyhow::anyhow!(e))?;
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer = tokenizers::Tokenizer::from_file(tokenizer_path)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let deterministic = is_deterministic(ort_options.as_ref());
         let session = build_session_from_file_with_init(model_path, ort_options)?;
+        Self::from_parts(tokenizer, session, deterministic)
+    }
 
-        Ok(Self { tokenizer, session })
+    /// `create` from in-memory model bytes and tokenizer JSON, e.g. for
+    /// encrypted or downloaded assets that must not be written to disk.
+    pub fn create_from_bytes(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_bytes_with_options(model_bytes, tokenizer_json, None)
     }
 
-    pub fn embed(&mut self, texts: Vec<String>) -> Result<Vec<Vec<f32>>> {
-        if texts.is_empty() {
-            return Ok(Vec::new());
-        }
-        let encodings = self
-            .tokenizer
-            .encode_batch(texts, true)
-            .map_err(|e| anyhow::anyhow!(e))?;
-
-        let pad_id = self
-            .tokenizer
-            .get_padding()
-            .map(|p| p.pad_id as i64)
-            .unwrap_or(0);
-
-        let mut batch = encodings.len();
-        for input in self.session.inputs() {
-            if input.name() == "input_ids" {
-                if let ValueType::Tensor { shape, .. } = input.dtype() {
-                    if let Some(dim) = shape.first() {
-                        if *dim > 0 && *dim as usize != batch {
-                            return Err(anyhow::anyhow!("Batch size mismatch for input_ids"));
-                        }
-                        if *dim > 0 {
-                            batch = *dim as usize;
-                        }
-                    }
-                }
-                break;
-            }
-        }
+    pub fn create_from_bytes_with_options(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer = tokenizers::Tokenizer::from_bytes(tokenizer_json)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let deterministic = is_deterministic(ort_options.as_ref());
+        let session = build_session_from_memory_with_init(&model_bytes, ort_options)?;
+        Self::from_parts(tokenizer, session, deterministic)
+    }
 
-        let max_len = encodings
+    fn from_parts(
+        tokenizer: tokenizers::Tokenizer,
+        session: Session,
+        deterministic: bool,
+    ) -> Result<Self> {
+        let session = SessionRouter::new(session);
+        let eos_token_id = EOS_CANDIDATES
             .iter()
-            .map(|e| e.get_ids().len())
-            .max()
-            .unwrap_or(0);
-        if max_len == 0 {
-            return Ok(vec![Vec::new(); batch]);
-        }
+            .find_map(|token| tokenizer.token_to_id(token));
+        let spec = ModelSpec {
+            inputs: InputStyle::Decoder { eos: eos_token_id },
+            input_names: InputNames::standard(),
+            outputs: output_names(&[
+                "sentence_embedding",
+                "pooled_output",
+                "embedding",
+                "last_hidden_state",
+            ]),
+            pooling: Pooling::LastToken,
+            normalize: true,
+            query_prefix: instruct_prefix(QWEN3_TASK),
+            document_prefix: String::new(),
+            batching: BatchOptions::default(),
+            deterministic,
+        };
+        Ok(Self {
+            tokenizer: Some(Arc::new(tokenizer)),
+            session,
+            eos_token_id,
+            task: QWEN3_TASK.to_string(),
+            spec,
+        })
+    }
 
-        let mut input_ids_batch = Vec::with_capacity(batch * max_len);
-        let mut mask_batch = Vec::with_capacity(batch * max_len);
-        let mut masks_u32 = Vec::with_capacity(batch);
+    /// Last-token pooling expects every sequence to end with EOS. Enabled by
+    /// default; the token is only appended when the tokenizer didn't add it.
+    pub fn set_append_eos(&mut self, enabled: bool) {
+        let eos = self.eos_token_id.filter(|_| enabled);
+        self.spec.inputs = InputStyle::Decoder { eos };
+    }
 
-        for encoding in encodings {
-            let ids = encoding.get_ids();
-            let mask = encoding.get_attention_mask();
-            let pad_len = max_len.saturating_sub(ids.len());
+    /// Instruction used when formatting queries through `TextEmbedder` (e.g.
+    /// `Embedder.format_query`) and `format_query_for_task`. `None` restores
+    /// the web-search retrieval default.
+    pub fn set_task(&mut self, task: Option<String>) {
+        self.task = task.unwrap_or_else(|| QWEN3_TASK.to_string());
+        self.spec.query_prefix = instruct_prefix(&self.task);
+    }
 
-            let mut ids_i64: Vec<i64> = ids.iter().map(|&x| x as i64).collect();
-            let mut mask_i64: Vec<i64> = mask.iter().map(|&x| x as i64).collect();
-            let mut mask_u32: Vec<u32> = mask.to_vec();
+    pub fn task(&self) -> String {
+        self.task.clone()
+    }
 
-            ids_i64.extend(std::iter::repeat(pad_id).take(pad_len));
-            mask_i64.extend(std::iter::repeat(0).take(pad_len));
-            mask_u32.extend(std::iter::repeat(0).take(pad_len));
+    /// `format_query` with the instruction set by `set_task`.
+    pub fn format_query_for_task(&self, query: String) -> String {
+        Self::format_query_with_task(self.task.clone(), query)
+    }
 
-            input_ids_batch.extend_from_slice(&ids_i64);
-            mask_batch.extend_from_slice(&mask_i64);
-            masks_u32.push(mask_u32);
-        }
+    /// EOS id detected from the tokenizer vocabulary, if any.
+    pub fn eos_token_id(&self) -> Option<u32> {
+        self.eos_token_id
+    }
 
-        let position_ids: Vec<i64> = (0..max_len as i64).collect();
-        let position_batch = repeat_i64(&position_ids, batch);
-
-        let mut inputs: Vec<(String, DynTensor)> = Vec::new();
-        for input in self.session.inputs() {
-            let name = input.name();
-            match name {
-                "input_ids" => {
-                    let shape = resolve_shape_with_fallback(input.dtype(), &[batch, max_len])?;
-                    let tensor = tensor_from_i64(input.dtype(), &shape, &input_ids_batch)?;
-                    inputs.push((name.to_string(), tensor));
-                }
-                "attention_mask" => {
-                    let rank = if let ValueType::Tensor { shape, .. } = input.dtype() {
-                        shape.len()
-                    } else {
-                        2
-                    };
-                    let (shape, data) = if rank == 1 {
-                        if batch > 1 {
-                            return Err(anyhow::anyhow!(
-                                "attention_mask rank 1 is not batch-compatible"
-                            ));
-                        }
-                        (
-                            resolve_shape_with_fallback(input.dtype(), &[max_len])?,
-                            mask_batch[..max_len].to_vec(),
-                        )
-                    } else if rank == 4 {
-                        let shape = resolve_shape_with_fallback(
-                            input.dtype(),
-                            &[batch, 1, max_len, max_len],
-                        )?;
-                        let mut data = Vec::with_capacity(batch * max_len * max_len);
-                        for mask in &masks_u32 {
-                            let mask_i64: Vec<i64> =
-                                mask.iter().map(|&v| v as i64).collect();
-                            for _ in 0..max_len {
-                                data.extend_from_slice(&mask_i64);
-                            }
-                        }
-                        (shape, data)
-                    } else {
-                        (
-                            resolve_shape_with_fallback(input.dtype(), &[batch, max_len])?,
-                            mask_batch.clone(),
-                        )
-                    };
-                    let tensor = tensor_from_i64(input.dtype(), &shape, &data)?;
-                    inputs.push((name.to_string(), tensor));
-                }
-                "position_ids" | "cache_position" => {
-                    let rank = if let ValueType::Tensor { shape, .. } = input.dtype() {
-                        shape.len()
-                    } else {
-                        2
-                    };
-                    let (shape, data) = if rank == 1 {
-                        if batch > 1 {
-                            return Err(anyhow::anyhow!(
-                                "position_ids rank 1 is not batch-compatible"
-                            ));
-                        }
-                        (
-                            resolve_shape_with_fallback(input.dtype(), &[max_len])?,
-                            position_ids.clone(),
-                        )
-                    } else {
-                        (
-                            resolve_shape_with_fallback(input.dtype(), &[batch, max_len])?,
-                            position_batch.clone(),
-                        )
-                    };
-                    let tensor = tensor_from_i64(input.dtype(), &shape, &data)?;
-                    inputs.push((name.to_string(), tensor));
-                }
-                "token_type_ids" => {
-                    let shape = resolve_shape_with_fallback(input.dtype(), &[batch, max_len])?;
-                    let tensor = zeros_tensor(input.dtype(), &shape)?;
-                    inputs.push((name.to_string(), tensor));
-                }
-                _ if name.starts_with("past_key_values") => {
-                    let shape = resolve_past_kv_shape(input.dtype(), batch)?;
-                    let tensor = zeros_tensor(input.dtype(), &shape)?;
-                    inputs.push((name.to_string(), tensor));
-                }
-                _ => {
-                    let rank = if let ValueType::Tensor { shape, .. } = input.dtype() {
-                        shape.len()
-                    } else {
-                        1
-                    };
-                    let fallback = match rank {
-                        1 => vec![max_len],
-                        2 => vec![batch, max_len],
-                        _ => vec![1; rank],
-                    };
-                    let shape = resolve_shape_with_fallback(input.dtype(), &fallback)?;
-                    let tensor = zeros_tensor(input.dtype(), &shape)?;
-                    inputs.push((name.to_string(), tensor));
-                }
-            }
-        }
-        let outputs = self.session.run(inputs)?;
-        let (shape, data) = pick_embedding_tensor(&outputs)?;
-        if shape.len() == 2 {
-            let out_batch = shape[0];
-            let hidden_dim = shape[1];
-            if out_batch != batch {
-                return Err(anyhow::anyhow!("Batch size mismatch in outputs"));
-            }
-            let mut results = Vec::with_capacity(batch);
-            for i in 0..batch {
-                let start = i * hidden_dim;
-                let end = start + hidden_dim;
-                let slice = data
-                    .get(start..end)
-                    .ok_or(anyhow::anyhow!("Invalid output slice"))?;
-                results.push(normalize(slice));
-            }
-            return Ok(results);
-        }
-        // Otherwise use last token (Qwen uses last_token pooling).
-        let out_batch = shape[0];
-        let seq_len = shape[1];
-        let hidden_dim = shape[2];
-        if out_batch != batch {
-            return Err(anyhow::anyhow!("Batch size mismatch in outputs"));
+    /// Registers an extra session of the same model for `workload`, e.g. a
+    /// CoreML/NNAPI session for interactive queries or a single-threaded CPU
+    /// session for indexing. Calls without a matching route use the session
+    /// the embedder was created with.
+    pub fn add_session(
+        &mut self,
+        workload: Workload,
+        model_path: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<(), FlutterEmbedderError> {
+        let session = build_session_from_file_with_init(model_path, ort_options)?;
+        self.session.set_route(workload, session)
+    }
+
+    pub fn remove_session(&mut self, workload: Workload) -> bool {
+        self.session.remove_route(workload)
+    }
+
+    /// Keeps `size` sessions of the model for `workload` (the sessions the
+    /// embedder was created with when no route is registered), so that many
+    /// concurrent calls can run in parallel. Each extra session is loaded from
+    /// `model_path` with its own copy of the weights; shrinking drops the
+    /// extras.
+    pub fn set_pool_size(
+        &mut self,
+        workload: Workload,
+        size: u32,
+        model_path: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<(), FlutterEmbedderError> {
+        self.session.resize_pool(workload, size as usize, || {
+            build_session_from_file_with_init(model_path.clone(), ort_options.clone())
+        })
+    }
+
+    /// Number of sessions serving `workload`.
+    pub fn pool_size(&self, workload: Workload) -> u32 {
+        self.session.pool_size(workload) as u32
+    }
+
+    /// `embed` on the session registered for `workload`.
+    pub fn embed_with_workload(
+        &self,
+        texts: Vec<String>,
+        workload: Workload,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        pipeline::embed(
+            &mut *self.session.acquire(workload)?,
+            loaded(&self.tokenizer)?,
+            &self.spec,
+            texts,
+            &[],
+            &[],
+            None,
+        )
+    }
+
+    /// Embeds `(instruction, text)` pairs, so one batch can mix queries,
+    /// passages and classification inputs. Texts with an instruction use the
+    /// `Instruct: …\nQuery:` template; texts without one are embedded as-is.
+    pub fn embed_with_instructions(
+        &self,
+        items: Vec<(Option<String>, String)>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        let (texts, _) = apply_instructions(items, instruct_prefix);
+        self.embed(texts)
+    }
+
+    pub fn embed(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.embed_with_workload(texts, Workload::Interactive)
+    }
+
+    /// `embed` plus tokenization/inference timings and peak memory.
+    pub fn embed_with_stats(
+        &self,
+        texts: Vec<String>,
+    ) -> Result<EmbeddingsWithStats, FlutterEmbedderError> {
+        let mut stats = EmbedStats::default();
+        let embeddings = pipeline::embed(
+            &mut *self.session.acquire(Workload::Interactive)?,
+            loaded(&self.tokenizer)?,
+            &self.spec,
+            texts,
+            &[],
+            &[],
+            Some(&mut stats),
+        )?;
+        Ok(EmbeddingsWithStats { embeddings, stats })
+    }
+
+    /// `embed` with Matryoshka truncation to `options.output_dim`.
+    pub fn embed_with_options(
+        &self,
+        texts: Vec<String>,
+        options: EmbedOptions,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        truncate_embeddings(self.embed(texts)?, options.output_dim)
+    }
+
+    /// Limits for the micro-batches `embed` splits large inputs into.
+    pub fn set_batch_options(&mut self, options: BatchOptions) {
+        self.spec.batching = options;
+    }
+
+    /// Inputs, outputs and metadata of the loaded ONNX model.
+    pub fn model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&*self.session.acquire(Workload::Interactive)?)
+    }
+
+    /// Length of the vectors `embed` returns, read from the model outputs
+    /// or, when the export leaves it dynamic, from a one-token dry run.
+    pub fn dimension(&self) -> Result<usize, FlutterEmbedderError> {
+        if let Some(dim) = declared_dimension(&*self.session.acquire(Workload::Interactive)?) {
+            return Ok(dim);
         }
-        let mut results = Vec::with_capacity(batch);
-        for i in 0..batch {
-            let mask = fit_mask(&masks_u32[i], seq_len);
-            let last_index = mask
+        probe_dimension(|texts| self.embed(texts))
+    }
+
+    /// Frees the ONNX sessions and tokenizer now rather than when the object
+    /// is dropped, e.g. before loading another model on a memory-constrained
+    /// device. Later calls fail with `ErrorKind::Closed`.
+    pub fn close(&mut self) {
+        self.session.close();
+        self.tokenizer = None;
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.session.is_closed()
+    }
+
+    /// Replaces the model, e.g. with a larger variant of the same family,
+    /// without recreating the embedder. The new session is built before the
+    /// old one is dropped, so a failed load leaves the embedder untouched.
+    /// The tokenizer is kept unless `tokenizer_path` is given. Sessions added
+    /// with `add_session` or `set_pool_size` belong to the old model and are
+    /// dropped.
+    pub fn reload_model(
+        &mut self,
+        model_path: String,
+        tokenizer_path: Option<String>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<(), FlutterEmbedderError> {
+        let deterministic = is_deterministic(ort_options.as_ref());
+        let (session, tokenizer) =
+            load_replacement(&self.tokenizer, model_path, tokenizer_path, ort_options)?;
+        self.session = SessionRouter::new(session);
+        self.spec.deterministic = deterministic;
+        if let Some(tokenizer) = tokenizer {
+            self.eos_token_id = EOS_CANDIDATES
                 .iter()
-                .rposition(|&m| m == 1)
-                .unwrap_or(seq_len.saturating_sub(1));
-            let start = (i * seq_len + last_index) * hidden_dim;
-            let end = start + hidden_dim;
-            let slice = data
-                .get(start..end)
-                .ok_or(anyhow::anyhow!("Invalid last token slice"))?;
-            results.push(normalize(slice));
+                .find_map(|token| tokenizer.token_to_id(token));
+            let append_eos = matches!(self.spec.inputs, InputStyle::Decoder { eos: Some(_) });
+            self.set_append_eos(append_eos);
+            self.tokenizer = Some(tokenizer);
         }
-
-        Ok(results)
+        Ok(())
     }
 
+    /// Formats `query` with the default web-search retrieval instruction.
     pub fn format_query(query: String) -> String {
-        format!("Instruct: {}\nQuery:{}", QWEN3_TASK, query)
+        Self::format_query_with_task(QWEN3_TASK.to_string(), query)
+    }
+
+    /// Formats `query` with a one-sentence `task` description, e.g. "Given a
+    /// code question, retrieve code snippets that answer it" or "Classify the
+    /// sentiment of a product review". Documents need no instruction.
+    pub fn format_query_with_task(task: String, query: String) -> String {
+        format!("{}{query}", instruct_prefix(&task))
     }
 
     pub fn format_document(text: String) -> String {
         text
     }
-}
 
-fn repeat_i64(data: &[i64], times: usize) -> Vec<i64> {
-    let mut out = Vec::with_capacity(data.len() * times);
-    for _ in 0..times {
-        out.extend_from_slice(data);
+    /// Formats every query with the instruction set by `set_task` and
+    /// embeds the batch.
+    pub fn embed_queries(
+        &self,
+        queries: Vec<String>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        let queries = queries
+            .into_iter()
+            .map(|query| self.format_query_for_task(query))
+            .collect();
+        self.embed(queries)
     }
-    out
-}
 
-fn resolve_shape_with_fallback(dtype: &ValueType, fallback: &[usize]) -> Result<Vec<usize>> {
-    let ValueType::Tensor { shape, .. } = dtype else {
-        return Err(anyhow!("Unsupported input type: {dtype:?}"));
-    };
-    if shape.len() != fallback.len() {
-        return Ok(fallback.to_vec());
-    }
-    Ok(shape
-        .iter()
-        .zip(fallback.iter())
-        .map(|(dim, fb)| if *dim >= 0 { *dim as usize } else { *fb })
-        .collect())
+    /// Formats every text with `format_document` and embeds the batch.
+    pub fn embed_documents(
+        &self,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        let texts = texts.into_iter().map(Self::format_document).collect();
+        self.embed(texts)
+    }
 }
 
-fn resolve_past_kv_shape(dtype: &ValueType, batch: usize) -> Result<Vec<usize>> {
-    let ValueType::Tensor { shape, .. } = dtype else {
-        return Err(anyhow!("Unsupported input type: {dtype:?}"));
-    };
-    let rank = shape.len();
-    let mut resolved = Vec::with_capacity(rank);
-    for (idx, dim) in shape.iter().enumerate() {
-        if *dim >= 0 {
-            resolved.push(*dim as usize);
-            continue;
+impl Qwen3Embedder {
+    /// `create` from a Hugging Face Hub repo, downloading the model and
+    /// `tokenizer.json` into `cache_dir` on first use.
+    pub fn create_from_hub(
+        repo_id: String,
+        cache_dir: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_hub_with_options(repo_id, cache_dir, None, None)
+    }
+
+    pub fn create_from_hub_with_options(
+        repo_id: String,
+        cache_dir: String,
+        hub_options: Option<HubOptions>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let (model_path, tokenizer_path) = resolve_hub_files(&repo_id, &cache_dir, hub_options)?;
+        Self::create_with_options(model_path, tokenizer_path, ort_options)
+    }
+
+    /// Runs a one-word batch through every session (see `add_session`) so
+    /// the first real `embed` doesn't pay for graph initialization.
+    pub fn warmup(&self) -> Result<(), FlutterEmbedderError> {
+        for workload in self.session.session_workloads() {
+            self.embed_with_workload(vec![WARMUP_TEXT.to_string()], workload)?;
         }
-        let value = if idx == 0 {
-            batch
-        } else if idx == rank.saturating_sub(2) {
-            0
-        } else {
-            1
-        };
-        resolved.push(value);
+        Ok(())
     }
-    Ok(resolved)
-}
 
-fn fit_mask(mask: &[u32], target_len: usize) -> Vec<u32> {
-    if mask.len() == target_len {
-        return mask.to_vec();
+    /// Same as `embed`, but not `sync` on the Dart side: inference runs on a
+    /// worker thread instead of blocking the calling isolate.
+    pub fn embed_async(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.embed(texts)
     }
-    if mask.len() > target_len {
-        return mask[..target_len].to_vec();
+
+    /// Batch API for indexing large corpora: embeds in length-sorted chunks
+    /// (see `EmbedManyOptions`) on the `Workload::Bulk` session. Use `embed`
+    /// for small, latency-sensitive calls.
+    pub fn embed_many(
+        &self,
+        texts: Vec<String>,
+        options: Option<EmbedManyOptions>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        embed_many_with(texts, options, None, None, |batch| {
+            self.embed_with_workload(batch, Workload::Bulk)
+        })
     }
-    let mut out = mask.to_vec();
-    out.extend(std::iter::repeat(0).take(target_len - mask.len()));
-    out
-}
 
-fn tensor_from_i64(dtype: &ValueType, shape: &[usize], data: &[i64]) -> Result<DynTensor> {
-    let ValueType::Tensor { ty, .. } = dtype else {
-        return Err(anyhow!("Unsupported input type: {dtype:?}"));
-    };
-    let expected: usize = shape.iter().product();
-    if expected != data.len() {
-        return Err(anyhow!(
-            "Input data length mismatch: expected {expected}, got {}",
-            data.len()
-        ));
-    }
-    match ty {
-        TensorElementType::Int64 => {
-            Ok(Tensor::from_array((shape.to_vec(), data.to_vec()))?.upcast())
-        }
-        TensorElementType::Int32 => Ok(Tensor::from_array((
-            shape.to_vec(),
-            data.iter().map(|&v| v as i32).collect::<Vec<i32>>(),
-        ))?
-        .upcast()),
-        TensorElementType::Int16 => Ok(Tensor::from_array((
-            shape.to_vec(),
-            data.iter().map(|&v| v as i16).collect::<Vec<i16>>(),
-        ))?
-        .upcast()),
-        TensorElementType::Int8 => Ok(Tensor::from_array((
-            shape.to_vec(),
-            data.iter().map(|&v| v as i8).collect::<Vec<i8>>(),
-        ))?
-        .upcast()),
-        TensorElementType::Uint64 => Ok(Tensor::from_array((
-            shape.to_vec(),
-            data.iter().map(|&v| v as u64).collect::<Vec<u64>>(),
-        ))?
-        .upcast()),
-        TensorElementType::Uint32 => Ok(Tensor::from_array((
-            shape.to_vec(),
-            data.iter().map(|&v| v as u32).collect::<Vec<u32>>(),
-        ))?
-        .upcast()),
-        TensorElementType::Uint16 => Ok(Tensor::from_array((
-            shape.to_vec(),
-            data.iter().map(|&v| v as u16).collect::<Vec<u16>>(),
-        ))?
-        .upcast()),
-        TensorElementType::Uint8 => Ok(Tensor::from_array((
-            shape.to_vec(),
-            data.iter().map(|&v| v as u8).collect::<Vec<u8>>(),
-        ))?
-        .upcast()),
-        TensorElementType::Bool => Ok(Tensor::from_array((
-            shape.to_vec(),
-            data.iter().map(|&v| v != 0).collect::<Vec<bool>>(),
-        ))?
-        .upcast()),
-        TensorElementType::Float32 => Ok(Tensor::from_array((
-            shape.to_vec(),
-            data.iter().map(|&v| v as f32).collect::<Vec<f32>>(),
-        ))?
-        .upcast()),
-        TensorElementType::Float64 => Ok(Tensor::from_array((
-            shape.to_vec(),
-            data.iter().map(|&v| v as f64).collect::<Vec<f64>>(),
-        ))?
-        .upcast()),
-        TensorElementType::Float16 => Ok(Tensor::from_array((
-            shape.to_vec(),
-            data.iter()
-                .map(|&v| half::f16::from_f32(v as f32))
-                .collect::<Vec<half::f16>>(),
-        ))?
-        .upcast()),
-        TensorElementType::Bfloat16 => Ok(Tensor::from_array((
-            shape.to_vec(),
-            data.iter()
-                .map(|&v| half::bf16::from_f32(v as f32))
-                .collect::<Vec<half::bf16>>(),
-        ))?
-        .upcast()),
-        _ => Err(anyhow!("Unsupported tensor element type: {ty:?}")),
+    /// Same as `embed_many`, updating `progress` after every chunk.
+    pub fn embed_many_with_progress(
+        &self,
+        texts: Vec<String>,
+        options: Option<EmbedManyOptions>,
+        progress: &EmbedProgress,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        embed_many_with(texts, options, Some(progress), None, |batch| {
+            self.embed_with_workload(batch, Workload::Bulk)
+        })
     }
-}
 
-fn zeros_tensor(dtype: &ValueType, shape: &[usize]) -> Result<DynTensor> {
-    let ValueType::Tensor { ty, .. } = dtype else {
-        return Err(anyhow!("Unsupported input type: {dtype:?}"));
-    };
-    match ty {
-        TensorElementType::Float32 => {
-            Ok(Tensor::from_array(ArrayD::<f32>::zeros(IxDyn(shape)))?.upcast())
-        }
-        TensorElementType::Float64 => {
-            Ok(Tensor::from_array(ArrayD::<f64>::zeros(IxDyn(shape)))?.upcast())
-        }
-        TensorElementType::Float16 => {
-            Ok(Tensor::from_array(ArrayD::<half::f16>::zeros(IxDyn(shape)))?.upcast())
-        }
-        TensorElementType::Bfloat16 => {
-            Ok(Tensor::from_array(ArrayD::<half::bf16>::zeros(IxDyn(shape)))?.upcast())
-        }
-        TensorElementType::Int64 => {
-            Ok(Tensor::from_array(ArrayD::<i64>::zeros(IxDyn(shape)))?.upcast())
-        }
-        TensorElementType::Int32 => {
-            Ok(Tensor::from_array(ArrayD::<i32>::zeros(IxDyn(shape)))?.upcast())
-        }
-        TensorElementType::Int16 => {
-            Ok(Tensor::from_array(ArrayD::<i16>::zeros(IxDyn(shape)))?.upcast())
-        }
-        TensorElementType::Int8 => {
-            Ok(Tensor::from_array(ArrayD::<i8>::zeros(IxDyn(shape)))?.upcast())
-        }
-        TensorElementType::Uint64 => {
-            Ok(Tensor::from_array(ArrayD::<u64>::zeros(IxDyn(shape)))?.upcast())
-        }
-        TensorElementType::Uint32 => {
-            Ok(Tensor::from_array(ArrayD::<u32>::zeros(IxDyn(shape)))?.upcast())
-        }
-        TensorElementType::Uint16 => {
-            Ok(Tensor::from_array(ArrayD::<u16>::zeros(IxDyn(shape)))?.upcast())
-        }
-        TensorElementType::Uint8 => {
-            Ok(Tensor::from_array(ArrayD::<u8>::zeros(IxDyn(shape)))?.upcast())
-        }
-        TensorElementType::Bool => {
-            Ok(Tensor::from_array(ArrayD::<bool>::from_elem(IxDyn(shape), false))?.upcast())
-        }
-        _ => Err(anyhow!("Unsupported tensor element type: {ty:?}")),
+    /// Embeds `texts` in input order, `batch_size` at a time on the
+    /// `Workload::Bulk` session, sending each batch to `sink` as soon as it
+    /// is ready.
+    pub fn embed_stream(
+        &self,
+        texts: Vec<String>,
+        batch_size: u32,
+        sink: StreamSink<EmbeddingProgress>,
+    ) -> Result<(), FlutterEmbedderError> {
+        embed_stream_with(texts, batch_size, None, &sink, |batch| {
+            self.embed_with_workload(batch, Workload::Bulk)
+        })
+    }
+
+    /// `embed_async` that checks `token` between micro-batches and fails
+    /// with a cancellation error once it is cancelled.
+    pub fn embed_async_cancellable(
+        &self,
+        texts: Vec<String>,
+        token: &CancellationToken,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        embed_many_with(texts, None, None, Some(token), |batch| self.embed(batch))
+    }
+
+    /// `embed_stream` that ends after the current batch once `token` is
+    /// cancelled.
+    pub fn embed_stream_cancellable(
+        &self,
+        texts: Vec<String>,
+        batch_size: u32,
+        token: &CancellationToken,
+        sink: StreamSink<EmbeddingProgress>,
+    ) -> Result<(), FlutterEmbedderError> {
+        embed_stream_with(texts, batch_size, Some(token), &sink, |batch| {
+            self.embed_with_workload(batch, Workload::Bulk)
+        })
     }
 }
-// Shared helper to select output tensor key for embedding models.
-fn pick_embedding_tensor(
-    outputs: &ort::session::SessionOutputs<'_>,
-) -> Result<(Vec<usize>, Vec<f32>)> {
-    // Prefer common pooled outputs if present.
-    for key in ["sentence_embedding", "pooled_output", "embedding"] {
-        if let Some(t) = outputs.get(key) {
-            let (shape, data) = t.try_extract_tensor::<f32>()?;
-            let shape_usize = shape.iter().map(|d| *d as usize).collect();
-            return Ok((shape_usize, data.to_vec()));
-        }
+
+#[frb(ignore)]
+impl TextEmbedder for Qwen3Embedder {
+    fn embed(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>> {
+        self.embed(texts)
+    }
+
+    fn embed_with_stats(&self, texts: Vec<String>) -> Result<EmbeddingsWithStats> {
+        self.embed_with_stats(texts)
+    }
+
+    fn format_query(&self, query: String) -> String {
+        self.format_query_for_task(query)
+    }
+
+    fn format_document(&self, text: String) -> String {
+        Self::format_document(text)
+    }
+
+    fn model_info(&self) -> Result<ModelInfo> {
+        self.model_info()
     }
-    // Fallback to last_hidden_state; caller must pool.
-    if let Some(t) = outputs.get("last_hidden_state") {
-        let (shape, data) = t.try_extract_tensor::<f32>()?;
-        let shape_usize = shape.iter().map(|d| *d as usize).collect();
-        Ok((shape_usize, data.to_vec()))
-    } else {
-        Err(anyhow!("No embedding tensor found in outputs"))
+
+    fn close(&mut self) {
+        self.close()
+    }
+
+    fn is_closed(&self) -> bool {
+        self.is_closed()
+    }
+
+    fn tokenizer(&self) -> Result<Arc<tokenizers::Tokenizer>> {
+        loaded(&self.tokenizer).cloned()
     }
+
+    fn reload_model(
+        &mut self,
+        model_path: String,
+        tokenizer_path: Option<String>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<()> {
+        self.reload_model(model_path, tokenizer_path, ort_options)
+    }
+
+    fn warmup(&self) -> Result<()> {
+        self.warmup()
+    }
+
+    fn dimension(&self) -> Result<usize> {
+        self.dimension()
+    }
+}
+
+fn instruct_prefix(task: &str) -> String {
+    format!("Instruct: {task}\nQuery:")
 }
@@ -0,0 +1,202 @@
+use flutter_rust_bridge::frb;
+use ort::session::Session;
+use ort::value::Tensor;
+
+use crate::api::embeddings::{loaded, pad_encodings, WARMUP_TEXT};
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::ort::{
+    build_session_from_file_with_init, build_session_from_memory_with_init, session_model_info,
+    ModelInfo, OrtInitOptions, SessionPool,
+};
+use crate::api::utils::SparseVector;
+
+/// SPLADE sparse embedder: turns the MLM logits of a SPLADE model into one
+/// term-weight per vocabulary id, `max_t log(1 + relu(logit))`.
+#[frb(opaque)]
+pub struct SpladeEmbedder {
+    tokenizer: Option<tokenizers::Tokenizer>,
+    session: SessionPool,
+}
+
+#[frb(sync)]
+impl SpladeEmbedder {
+    pub fn create(
+        model_path: String,
+        tokenizer_path: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_with_options(model_path, tokenizer_path, None)
+    }
+
+    pub fn create_with_options(
+        model_path: String,
+        tokenizer_path: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer = tokenizers::Tokenizer::from_file(tokenizer_path)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let session = build_session_from_file_with_init(model_path, ort_options)?;
+        Self::from_parts(tokenizer, session)
+    }
+
+    /// `create` from in-memory model bytes and tokenizer JSON, e.g. for
+    /// encrypted or downloaded assets that must not be written to disk.
+    pub fn create_from_bytes(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_bytes_with_options(model_bytes, tokenizer_json, None)
+    }
+
+    pub fn create_from_bytes_with_options(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer = tokenizers::Tokenizer::from_bytes(tokenizer_json)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let session = build_session_from_memory_with_init(&model_bytes, ort_options)?;
+        Self::from_parts(tokenizer, session)
+    }
+
+    fn from_parts(tokenizer: tokenizers::Tokenizer, session: Session) -> Result<Self> {
+        Ok(Self {
+            tokenizer: Some(tokenizer),
+            session: SessionPool::new(session),
+        })
+    }
+
+    /// Inputs, outputs and metadata of the loaded ONNX model.
+    pub fn model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&*self.session.acquire()?)
+    }
+
+    /// Frees the ONNX session and tokenizer now rather than when the object
+    /// is dropped. Later calls fail with `ErrorKind::Closed`.
+    pub fn close(&mut self) {
+        self.session.close();
+        self.tokenizer = None;
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.session.is_closed()
+    }
+
+    /// Size of the vocabulary; every `SparseVector` index is below it.
+    pub fn dimension(&self) -> Result<usize, FlutterEmbedderError> {
+        Ok(loaded(&self.tokenizer)?.get_vocab_size(true))
+    }
+
+    pub fn embed(&mut self, texts: Vec<String>) -> Result<Vec<SparseVector>, FlutterEmbedderError> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let tokenizer = loaded(&self.tokenizer)?;
+        let encodings = tokenizer
+            .encode_batch(texts, true)
+            .map_err(|e| err!(Tokenization, "{e}"))?;
+        let pad_id = tokenizer
+            .get_padding()
+            .map(|p| p.pad_id as i64)
+            .unwrap_or(0);
+        let batch = encodings.len();
+        let (max_len, input_ids_batch, mask_batch) = pad_encodings(&encodings, pad_id);
+        if max_len == 0 {
+            return Ok(vec![SparseVector::default(); batch]);
+        }
+
+        let mut inputs = ort::inputs! {
+            "input_ids" => Tensor::from_array(([batch, max_len], input_ids_batch))?,
+            "attention_mask" => Tensor::from_array(([batch, max_len], mask_batch.clone()))?,
+        };
+        let mut session = self.session.acquire()?;
+        if session
+            .inputs()
+            .iter()
+            .any(|input| input.name() == "token_type_ids")
+        {
+            inputs.push((
+                "token_type_ids".into(),
+                Tensor::from_array(([batch, max_len], vec![0i64; batch * max_len]))?.into(),
+            ));
+        }
+
+        let outputs = session.run(inputs)?;
+        let logits = match outputs.get("logits") {
+            Some(logits) => logits,
+            None => &outputs[0],
+        };
+        let (shape, data) = logits.try_extract_tensor::<f32>()?;
+        if shape.first().map(|d| *d as usize) != Some(batch) {
+            return Err(err!(ShapeMismatch, "Batch size mismatch in outputs"));
+        }
+
+        let mut results = Vec::with_capacity(batch);
+        match shape.len() {
+            // `[batch, seq, vocab]` MLM logits: max-pool over unmasked tokens.
+            3 => {
+                let seq_len = shape[1] as usize;
+                let vocab = shape[2] as usize;
+                for i in 0..batch {
+                    let mut weights = vec![0f32; vocab];
+                    for t in 0..seq_len.min(max_len) {
+                        if mask_batch[i * max_len + t] == 0 {
+                            continue;
+                        }
+                        let start = (i * seq_len + t) * vocab;
+                        let row = data
+                            .get(start..start + vocab)
+                            .ok_or(err!(ShapeMismatch, "Invalid output slice"))?;
+                        for (w, &logit) in weights.iter_mut().zip(row) {
+                            *w = w.max(logit.max(0.0).ln_1p());
+                        }
+                    }
+                    results.push(to_sparse(&weights));
+                }
+            }
+            // Exports that already pool to `[batch, vocab]` weights.
+            2 => {
+                let vocab = shape[1] as usize;
+                for row in data.chunks(vocab) {
+                    results.push(to_sparse(row));
+                }
+            }
+            rank => return Err(err!(ShapeMismatch, "Unexpected output rank {rank}")),
+        }
+        Ok(results)
+    }
+
+    /// The `k` highest-weighted terms of `vector` as `(token, weight)`, for
+    /// inspecting what a document was expanded to. Empty once closed.
+    pub fn top_terms(&self, vector: SparseVector, k: u32) -> Vec<(String, f32)> {
+        let mut terms: Vec<(u32, f32)> = vector.indices.into_iter().zip(vector.values).collect();
+        terms.sort_by(|a, b| b.1.total_cmp(&a.1));
+        terms
+            .into_iter()
+            .take(k as usize)
+            .filter_map(|(id, weight)| {
+                let tokenizer = self.tokenizer.as_ref()?;
+                tokenizer.id_to_token(id).map(|t| (t, weight))
+            })
+            .collect()
+    }
+}
+
+impl SpladeEmbedder {
+    /// Runs a one-word batch through the model so the first real `embed`
+    /// doesn't pay for graph initialization.
+    pub fn warmup(&mut self) -> Result<(), FlutterEmbedderError> {
+        self.embed(vec![WARMUP_TEXT.to_string()])?;
+        Ok(())
+    }
+}
+
+fn to_sparse(weights: &[f32]) -> SparseVector {
+    let mut vector = SparseVector::default();
+    for (id, &weight) in weights.iter().enumerate() {
+        if weight > 0.0 {
+            vector.indices.push(id as u32);
+            vector.values.push(weight);
+        }
+    }
+    vector
+}
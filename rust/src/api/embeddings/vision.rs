@@ -0,0 +1,278 @@
+use flutter_rust_bridge::frb;
+use image::RgbImage;
+use ort::session::{Session, SessionOutputs};
+use ort::value::{Tensor, ValueType};
+
+use crate::api::embeddings::output_dimension;
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::image::{
+    decode_image, preprocess_rgb, rgba_to_rgb, PreprocessOptions, ResizeFilter, ResizeMode,
+    RgbaPixels, TensorLayout,
+};
+use crate::api::ort::{
+    build_session_from_file_with_init, build_session_from_memory_with_init, session_model_info,
+    ModelInfo, OrtInitOptions, SessionPool,
+};
+use crate::api::utils::normalize;
+
+const IMAGENET_MEAN: [f32; 3] = [0.485, 0.456, 0.406];
+const IMAGENET_STD: [f32; 3] = [0.229, 0.224, 0.225];
+const CLIP_MEAN: [f32; 3] = [0.481_454_66, 0.457_827_5, 0.408_210_73];
+const CLIP_STD: [f32; 3] = [0.268_629_54, 0.261_302_6, 0.275_777_1];
+const DEFAULT_IMAGE_SIZE: u32 = 224;
+
+/// Preprocessing conventions of the supported vision towers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VisionPreset {
+    /// DINOv2: shorter side to 256/224 of the input size, center crop,
+    /// ImageNet normalization. Embeddings are the `[CLS]` token.
+    Dinov2,
+    /// SigLIP: squashed to the input size, normalized to `[-1, 1]`.
+    Siglip,
+    /// CLIP: shorter side to the input size, center crop, CLIP
+    /// normalization.
+    Clip,
+}
+
+/// Image embedder for a standalone vision encoder (DINOv2, SigLIP or CLIP
+/// vision tower), e.g. for screenshot or photo similarity search. Images
+/// are decoded, resized and normalized in Rust and pooled to one
+/// L2-normalized vector each.
+#[frb(opaque)]
+pub struct VisionEmbedder {
+    session: SessionPool,
+    preprocessing: PreprocessOptions,
+}
+
+#[frb(sync)]
+impl VisionEmbedder {
+    pub fn create(model_path: String, preset: VisionPreset) -> Result<Self, FlutterEmbedderError> {
+        Self::create_with_options(model_path, preset, None)
+    }
+
+    pub fn create_with_options(
+        model_path: String,
+        preset: VisionPreset,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let session = build_session_from_file_with_init(model_path, ort_options)?;
+        Ok(Self::from_parts(session, preset))
+    }
+
+    /// `create` from in-memory model bytes.
+    pub fn create_from_bytes(
+        model_bytes: Vec<u8>,
+        preset: VisionPreset,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_bytes_with_options(model_bytes, preset, None)
+    }
+
+    pub fn create_from_bytes_with_options(
+        model_bytes: Vec<u8>,
+        preset: VisionPreset,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let session = build_session_from_memory_with_init(&model_bytes, ort_options)?;
+        Ok(Self::from_parts(session, preset))
+    }
+
+    fn from_parts(session: Session, preset: VisionPreset) -> Self {
+        // Exports with a static `[batch, 3, H, W]` input fix the image size.
+        let image_size = session
+            .inputs()
+            .first()
+            .and_then(|input| match input.dtype() {
+                ValueType::Tensor { shape, .. } if shape.len() == 4 && shape[2] > 0 => {
+                    Some(shape[2] as u32)
+                }
+                _ => None,
+            })
+            .unwrap_or(DEFAULT_IMAGE_SIZE);
+        let (mean, std, mode, crop_fraction) = match preset {
+            // 224 cropped out of 256.
+            VisionPreset::Dinov2 => (IMAGENET_MEAN, IMAGENET_STD, ResizeMode::CenterCrop, 0.875),
+            VisionPreset::Siglip => ([0.5; 3], [0.5; 3], ResizeMode::Stretch, 1.0),
+            VisionPreset::Clip => (CLIP_MEAN, CLIP_STD, ResizeMode::CenterCrop, 1.0),
+        };
+        let filter = match preset {
+            VisionPreset::Siglip => ResizeFilter::Bilinear,
+            _ => ResizeFilter::Bicubic,
+        };
+        Self {
+            session: SessionPool::new(session),
+            preprocessing: PreprocessOptions {
+                width: image_size,
+                height: image_size,
+                mean: mean.to_vec(),
+                std: std.to_vec(),
+                layout: TensorLayout::Chw,
+                filter,
+                mode,
+                crop_fraction: Some(crop_fraction),
+            },
+        }
+    }
+
+    /// The resize and normalization applied to every image.
+    pub fn preprocessing(&self) -> PreprocessOptions {
+        self.preprocessing.clone()
+    }
+
+    /// Replaces the preset's preprocessing, e.g. for a fine-tuned model
+    /// with different normalization. The layout must stay `Chw`.
+    pub fn set_preprocessing(
+        &mut self,
+        preprocessing: PreprocessOptions,
+    ) -> Result<(), FlutterEmbedderError> {
+        if preprocessing.layout != TensorLayout::Chw {
+            return Err(err!(InvalidInput, "Vision models take CHW input"));
+        }
+        self.preprocessing = preprocessing;
+        Ok(())
+    }
+
+    /// Inputs, outputs and metadata of the loaded ONNX model.
+    pub fn model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&*self.session.acquire()?)
+    }
+
+    /// Frees the ONNX session now rather than when the object is dropped.
+    /// Later calls fail with `ErrorKind::Closed`.
+    pub fn close(&mut self) {
+        self.session.close();
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.session.is_closed()
+    }
+
+    /// Side length of the square model input.
+    pub fn image_size(&self) -> u32 {
+        self.preprocessing.width
+    }
+
+    /// Length of the image vectors, read from the model outputs or, when
+    /// the export leaves it dynamic, from a blank-image dry run.
+    pub fn dimension(&mut self) -> Result<usize, FlutterEmbedderError> {
+        let declared = output_dimension(
+            &*self.session.acquire()?,
+            &["image_embeds", "pooler_output", "last_hidden_state"],
+        );
+        if let Some(dim) = declared {
+            return Ok(dim);
+        }
+        let (width, height) = (self.preprocessing.width, self.preprocessing.height);
+        self.embed_pixels(1, vec![0.0; 3 * (width * height) as usize])?
+            .first()
+            .map(Vec::len)
+            .ok_or(err!(MissingOutput, "Model produced no embedding"))
+    }
+
+    /// Embeds encoded images (PNG or JPEG bytes).
+    pub fn embed_images(
+        &mut self,
+        image_bytes: Vec<Vec<u8>>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        let images = image_bytes
+            .iter()
+            .map(|bytes| decode_image(bytes))
+            .collect::<Result<Vec<_>>>()?;
+        self.embed_rgb(&images)
+    }
+
+    /// Embeds raw RGBA buffers; alpha is ignored.
+    pub fn embed_rgba(
+        &mut self,
+        images: Vec<RgbaPixels>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        let images = images
+            .into_iter()
+            .map(rgba_to_rgb)
+            .collect::<Result<Vec<_>>>()?;
+        self.embed_rgb(&images)
+    }
+}
+
+impl VisionEmbedder {
+    /// Runs a blank image through the model so the first real call doesn't
+    /// pay for graph initialization.
+    pub fn warmup(&mut self) -> Result<(), FlutterEmbedderError> {
+        let (width, height) = (self.preprocessing.width, self.preprocessing.height);
+        self.embed_pixels(1, vec![0.0; 3 * (width * height) as usize])?;
+        Ok(())
+    }
+
+    /// Same as `embed_images`, but runs on a worker thread.
+    pub fn embed_images_async(
+        &mut self,
+        image_bytes: Vec<Vec<u8>>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.embed_images(image_bytes)
+    }
+
+    /// Same as `embed_rgba`, but runs on a worker thread.
+    pub fn embed_rgba_async(
+        &mut self,
+        images: Vec<RgbaPixels>,
+    ) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+        self.embed_rgba(images)
+    }
+
+    fn embed_rgb(&mut self, images: &[RgbImage]) -> Result<Vec<Vec<f32>>> {
+        if images.is_empty() {
+            return Ok(Vec::new());
+        }
+        let (width, height) = (self.preprocessing.width, self.preprocessing.height);
+        let mut pixels = Vec::with_capacity(images.len() * 3 * (width * height) as usize);
+        for image in images {
+            pixels.extend(preprocess_rgb(image, &self.preprocessing)?);
+        }
+        self.embed_pixels(images.len(), pixels)
+    }
+
+    /// Runs the model on preprocessed `[batch, 3, size, size]` pixels.
+    fn embed_pixels(&mut self, batch: usize, pixels: Vec<f32>) -> Result<Vec<Vec<f32>>> {
+        let (width, height) = (
+            self.preprocessing.width as usize,
+            self.preprocessing.height as usize,
+        );
+        let mut session = self.session.acquire()?;
+        let input_name = session
+            .inputs()
+            .first()
+            .map(|input| input.name().to_string())
+            .unwrap_or_else(|| "pixel_values".to_string());
+        let inputs = ort::inputs! {
+            input_name => Tensor::from_array(([batch, 3, height, width], pixels))?,
+        };
+        let outputs = session.run(inputs)?;
+        pooled_or_cls(&outputs, batch)
+    }
+}
+
+/// `image_embeds` or `pooler_output` when the export has them, otherwise
+/// the `[CLS]` token of `last_hidden_state`.
+fn pooled_or_cls(outputs: &SessionOutputs<'_>, batch: usize) -> Result<Vec<Vec<f32>>> {
+    for key in ["image_embeds", "pooler_output"] {
+        if let Some(t) = outputs.get(key) {
+            let (shape, data) = t.try_extract_tensor::<f32>()?;
+            if shape.len() != 2 || shape[0] as usize != batch {
+                return Err(err!(ShapeMismatch, "Unexpected {key} shape: {shape:?}"));
+            }
+            return Ok(data.chunks(shape[1] as usize).map(normalize).collect());
+        }
+    }
+    let hidden = match outputs.get("last_hidden_state") {
+        Some(hidden) => hidden,
+        None => &outputs[0],
+    };
+    let (shape, data) = hidden.try_extract_tensor::<f32>()?;
+    if shape.len() != 3 || shape[0] as usize != batch {
+        return Err(err!(ShapeMismatch, "Unexpected output shape: {shape:?}"));
+    }
+    let (tokens, dim) = (shape[1] as usize, shape[2] as usize);
+    Ok(data
+        .chunks(tokens * dim)
+        .map(|sequence| normalize(&sequence[..dim]))
+        .collect())
+}
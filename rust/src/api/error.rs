@@ -0,0 +1,97 @@
+use std::fmt;
+
+/// What went wrong, so Dart can branch on the cause instead of the message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// `tokenizer.json` is missing, unreadable or malformed.
+    TokenizerLoad,
+    /// The tokenizer rejected an input.
+    Tokenization,
+    /// The ONNX model is missing, unreadable or malformed.
+    ModelLoad,
+    /// An argument is out of range or inconsistent with the model.
+    InvalidInput,
+    /// A model output does not have the expected shape.
+    ShapeMismatch,
+    /// The model lacks an output the embedder needs.
+    MissingOutput,
+    /// A tensor uses an element type that is not supported.
+    UnsupportedDtype,
+    /// ONNX Runtime failed while running the model.
+    OrtRuntime,
+    /// The call was cancelled through a `CancellationToken`.
+    Cancelled,
+    /// Reading or writing a file failed, or its contents are corrupt.
+    Io,
+    /// A download failed or the file is not available offline.
+    Network,
+    /// A downloaded file does not match its expected checksum.
+    ChecksumMismatch,
+    /// The embedder was closed and its model freed.
+    Closed,
+    /// A call panicked, or a shared store was left unusable by a panic in
+    /// another call; a bug in this crate rather than in the caller's input.
+    Internal,
+}
+
+/// Error returned by every fallible API in this crate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlutterEmbedderError {
+    pub kind: ErrorKind,
+    pub message: String,
+}
+
+pub type Result<T, E = FlutterEmbedderError> = std::result::Result<T, E>;
+
+impl FlutterEmbedderError {
+    pub(crate) fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+/// `err!(Kind, "format {args}")` builds a `FlutterEmbedderError`.
+macro_rules! err {
+    ($kind:ident, $($arg:tt)+) => {
+        $crate::api::error::FlutterEmbedderError::new(
+            $crate::api::error::ErrorKind::$kind,
+            format!($($arg)+),
+        )
+    };
+}
+pub(crate) use err;
+
+impl fmt::Display for FlutterEmbedderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.kind, self.message)
+    }
+}
+
+impl std::error::Error for FlutterEmbedderError {}
+
+impl From<ort::Error> for FlutterEmbedderError {
+    fn from(e: ort::Error) -> Self {
+        Self::new(ErrorKind::OrtRuntime, e.to_string())
+    }
+}
+
+impl From<std::io::Error> for FlutterEmbedderError {
+    fn from(e: std::io::Error) -> Self {
+        Self::new(ErrorKind::Io, e.to_string())
+    }
+}
+
+impl From<ndarray::ShapeError> for FlutterEmbedderError {
+    fn from(e: ndarray::ShapeError) -> Self {
+        Self::new(ErrorKind::ShapeMismatch, e.to_string())
+    }
+}
+
+/// Lets bindings generated for `Result<_, String>` keep compiling.
+impl From<FlutterEmbedderError> for String {
+    fn from(e: FlutterEmbedderError) -> Self {
+        e.to_string()
+    }
+}
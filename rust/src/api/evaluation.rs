@@ -0,0 +1,296 @@
+use std::collections::HashSet;
+
+use crate::api::embeddings::{embed_with, Embedder};
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::utils::{cosine_distance, embeddings_to_matrix, normalize, ranks};
+
+const DEFAULT_OVERLAP_K: usize = 10;
+
+#[derive(Debug, Clone)]
+pub struct EmbeddingSetStats {
+    pub dimension: u32,
+    pub mean_norm: f32,
+    /// Mean and standard deviation of the off-diagonal cosine similarities.
+    pub mean_similarity: f32,
+    pub std_similarity: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct EmbedderComparison {
+    pub count: u32,
+    /// Pearson correlation of the pairwise similarity structure.
+    pub pearson: f32,
+    /// Spearman rank correlation of the pairwise similarity structure.
+    pub spearman: f32,
+    /// Mean fraction of shared nearest neighbours at `k`.
+    pub neighbor_overlap: f32,
+    pub k: u32,
+    pub stats_a: EmbeddingSetStats,
+    pub stats_b: EmbeddingSetStats,
+}
+
+/// Compares two embeddings of the same texts produced by different models.
+///
+/// Vectors in `a` and `b` must be aligned (same text at the same index); the
+/// two sets may have different dimensions.
+#[flutter_rust_bridge::frb]
+pub fn compare_embedding_sets(
+    a: Vec<Vec<f32>>,
+    b: Vec<Vec<f32>>,
+    k: Option<u32>,
+) -> Result<EmbedderComparison, FlutterEmbedderError> {
+    if a.len() != b.len() {
+        return Err(err!(
+            InvalidInput,
+            "Embedding sets must contain the same number of vectors"
+        ));
+    }
+    if a.len() < 2 {
+        return Err(err!(
+            InvalidInput,
+            "At least two embeddings are required for a comparison"
+        ));
+    }
+    let n = a.len();
+    let k = k
+        .map(|k| k as usize)
+        .unwrap_or(DEFAULT_OVERLAP_K)
+        .clamp(1, n - 1);
+
+    let sims_a = similarity_matrix(&a)?;
+    let sims_b = similarity_matrix(&b)?;
+
+    let mut upper_a = Vec::with_capacity(n * (n - 1) / 2);
+    let mut upper_b = Vec::with_capacity(n * (n - 1) / 2);
+    for i in 0..n {
+        for j in (i + 1)..n {
+            upper_a.push(sims_a[i][j]);
+            upper_b.push(sims_b[i][j]);
+        }
+    }
+
+    let mut overlap = 0.0f32;
+    for i in 0..n {
+        let na: HashSet<usize> = nearest(&sims_a[i], i, k).into_iter().collect();
+        let nb = nearest(&sims_b[i], i, k);
+        overlap += nb.iter().filter(|j| na.contains(j)).count() as f32 / k as f32;
+    }
+
+    Ok(EmbedderComparison {
+        count: n as u32,
+        pearson: pearson(&upper_a, &upper_b),
+        spearman: pearson(&ranks(&upper_a), &ranks(&upper_b)),
+        neighbor_overlap: overlap / n as f32,
+        k: k as u32,
+        stats_a: set_stats(&a, &upper_a),
+        stats_b: set_stats(&b, &upper_b),
+    })
+}
+
+/// Embeds `texts` with the embedders loaded under `embedder_id_a` and
+/// `embedder_id_b` and compares the results like `compare_embedding_sets`,
+/// for A/B-testing a candidate model against the current one on-device.
+#[flutter_rust_bridge::frb]
+pub fn compare_embedders(
+    embedder_id_a: u64,
+    embedder_id_b: u64,
+    texts: Vec<String>,
+    k: Option<u32>,
+) -> Result<EmbedderComparison, FlutterEmbedderError> {
+    let a = embed_with(embedder_id_a, texts.clone())?;
+    let b = embed_with(embedder_id_b, texts)?;
+    compare_embedding_sets(a, b, k)
+}
+
+#[derive(Debug, Clone)]
+pub struct ReferenceCase {
+    pub text: String,
+    pub expected: Vec<f32>,
+    /// Maximum accepted cosine deviation (`1 - cosine similarity`).
+    pub tolerance: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReferenceCheck {
+    pub text: String,
+    /// `1 - cosine similarity` between the produced and the reference vector.
+    pub deviation: f32,
+    pub passed: bool,
+    pub message: Option<String>,
+}
+
+/// Checks vectors produced on-device against shipped reference vectors.
+///
+/// `actual[i]` must be the embedding of `cases[i].text`. Use this at startup
+/// to detect broken quantized builds or execution-provider numerical drift.
+#[flutter_rust_bridge::frb(sync)]
+pub fn check_reference_vectors(
+    cases: Vec<ReferenceCase>,
+    actual: Vec<Vec<f32>>,
+) -> Result<Vec<ReferenceCheck>, FlutterEmbedderError> {
+    if cases.len() != actual.len() {
+        return Err(err!(
+            InvalidInput,
+            "Expected {} embeddings, got {}",
+            cases.len(),
+            actual.len()
+        ));
+    }
+    Ok(cases
+        .into_iter()
+        .zip(actual)
+        .map(|(case, vector)| check_case(case, &vector))
+        .collect())
+}
+
+/// Embeds each case's text with the embedder loaded under `embedder_id` and
+/// checks it against the case's reference vector and tolerance, e.g.
+/// vectors shipped with the app and produced on a desktop with the same
+/// model.
+#[flutter_rust_bridge::frb]
+pub fn verify_against_reference(
+    embedder_id: u64,
+    cases: Vec<ReferenceCase>,
+) -> Result<Vec<ReferenceCheck>, FlutterEmbedderError> {
+    if cases.iter().any(|case| !(case.tolerance >= 0.0)) {
+        return Err(err!(InvalidInput, "tolerance must be non-negative"));
+    }
+    let texts = cases.iter().map(|case| case.text.clone()).collect();
+    let actual = embed_with(embedder_id, texts)?;
+    check_reference_vectors(cases, actual)
+}
+
+#[derive(Debug, Clone)]
+pub struct ModelVerification {
+    /// Whether every reference input stayed within the tolerance.
+    pub passed: bool,
+    /// Largest `1 - cosine similarity` over the inputs.
+    pub max_deviation: f32,
+    pub checks: Vec<ReferenceCheck>,
+}
+
+/// Self-test for a loaded model: embeds `reference_inputs` and compares
+/// each vector with the matching `reference_embeddings` entry, e.g. vectors
+/// produced on a desktop with the same model and shipped with the app. A
+/// failure points at a corrupted download or execution-provider drift.
+#[flutter_rust_bridge::frb]
+pub fn verify_model(
+    embedder: &Embedder,
+    reference_inputs: Vec<String>,
+    reference_embeddings: Vec<Vec<f32>>,
+    tolerance: f32,
+) -> Result<ModelVerification, FlutterEmbedderError> {
+    if reference_inputs.len() != reference_embeddings.len() {
+        return Err(err!(
+            InvalidInput,
+            "Expected {} reference embeddings, got {}",
+            reference_inputs.len(),
+            reference_embeddings.len()
+        ));
+    }
+    if !(tolerance >= 0.0) {
+        return Err(err!(InvalidInput, "tolerance must be non-negative"));
+    }
+    let actual = embedder.embed(reference_inputs.clone())?;
+    let cases = reference_inputs
+        .into_iter()
+        .zip(reference_embeddings)
+        .map(|(text, expected)| ReferenceCase {
+            text,
+            expected,
+            tolerance,
+        })
+        .collect();
+    let checks = check_reference_vectors(cases, actual)?;
+    Ok(ModelVerification {
+        passed: checks.iter().all(|check| check.passed),
+        max_deviation: checks
+            .iter()
+            .map(|check| check.deviation)
+            .fold(0.0, f32::max),
+        checks,
+    })
+}
+
+fn check_case(case: ReferenceCase, actual: &[f32]) -> ReferenceCheck {
+    if actual.len() != case.expected.len() {
+        return ReferenceCheck {
+            text: case.text,
+            deviation: 2.0,
+            passed: false,
+            message: Some(format!(
+                "Dimension mismatch: expected {}, got {}",
+                case.expected.len(),
+                actual.len()
+            )),
+        };
+    }
+    match cosine_distance(actual.to_vec(), case.expected) {
+        Ok(deviation) => ReferenceCheck {
+            text: case.text,
+            deviation,
+            passed: deviation <= case.tolerance,
+            message: None,
+        },
+        Err(err) => ReferenceCheck {
+            text: case.text,
+            deviation: 2.0,
+            passed: false,
+            message: Some(err.message),
+        },
+    }
+}
+
+fn similarity_matrix(embeddings: &[Vec<f32>]) -> Result<Vec<Vec<f32>>> {
+    let normalized: Vec<Vec<f32>> = embeddings.iter().map(|e| normalize(e)).collect();
+    let matrix = embeddings_to_matrix(&normalized)?;
+    let sims = matrix.dot(&matrix.t());
+    Ok(sims.outer_iter().map(|row| row.to_vec()).collect())
+}
+
+fn nearest(row: &[f32], skip: usize, k: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..row.len()).filter(|&j| j != skip).collect();
+    order.sort_by(|&x, &y| row[y].total_cmp(&row[x]));
+    order.truncate(k);
+    order
+}
+
+fn set_stats(embeddings: &[Vec<f32>], upper: &[f32]) -> EmbeddingSetStats {
+    let mean_norm = embeddings
+        .iter()
+        .map(|e| e.iter().map(|x| x * x).sum::<f32>().sqrt())
+        .sum::<f32>()
+        / embeddings.len() as f32;
+    let (mean, std) = mean_std(upper);
+    EmbeddingSetStats {
+        dimension: embeddings.first().map(|e| e.len()).unwrap_or(0) as u32,
+        mean_norm,
+        mean_similarity: mean,
+        std_similarity: std,
+    }
+}
+
+fn mean_std(values: &[f32]) -> (f32, f32) {
+    if values.is_empty() {
+        return (0.0, 0.0);
+    }
+    let n = values.len() as f32;
+    let mean = values.iter().sum::<f32>() / n;
+    let variance = values.iter().map(|v| (v - mean) * (v - mean)).sum::<f32>() / n;
+    (mean, variance.sqrt())
+}
+
+fn pearson(x: &[f32], y: &[f32]) -> f32 {
+    let (mx, sx) = mean_std(x);
+    let (my, sy) = mean_std(y);
+    if sx < 1e-12 || sy < 1e-12 {
+        return 0.0;
+    }
+    let cov = x
+        .iter()
+        .zip(y)
+        .map(|(a, b)| (a - mx) * (b - my))
+        .sum::<f32>()
+        / x.len() as f32;
+    cov / (sx * sy)
+}
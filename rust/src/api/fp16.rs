@@ -0,0 +1,533 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use half::f16;
+
+use crate::api::error::{err, FlutterEmbedderError, Result};
+
+// ONNX protobuf field numbers used by the converter.
+const MODEL_GRAPH: u32 = 7;
+const GRAPH_NODE: u32 = 1;
+const GRAPH_INITIALIZER: u32 = 5;
+const GRAPH_INPUT: u32 = 11;
+const GRAPH_OUTPUT: u32 = 12;
+const GRAPH_VALUE_INFO: u32 = 13;
+const NODE_INPUT: u32 = 1;
+const NODE_OUTPUT: u32 = 2;
+const NODE_NAME: u32 = 3;
+const NODE_OP_TYPE: u32 = 4;
+const NODE_ATTRIBUTE: u32 = 5;
+const ATTR_NAME: u32 = 1;
+const ATTR_I: u32 = 3;
+const ATTR_T: u32 = 5;
+const ATTR_G: u32 = 6;
+const ATTR_TENSORS: u32 = 10;
+const ATTR_GRAPHS: u32 = 11;
+const ATTR_TYPE: u32 = 20;
+const ATTR_TYPE_INT: u64 = 2;
+const TENSOR_DATA_TYPE: u32 = 2;
+const TENSOR_FLOAT_DATA: u32 = 4;
+const TENSOR_NAME: u32 = 8;
+const TENSOR_RAW_DATA: u32 = 9;
+const TENSOR_DATA_LOCATION: u32 = 14;
+const VALUE_INFO_NAME: u32 = 1;
+const VALUE_INFO_TYPE: u32 = 2;
+const TYPE_TENSOR: u32 = 1;
+const TYPE_SEQUENCE: u32 = 4;
+const TYPE_MAP: u32 = 5;
+const TYPE_SPARSE_TENSOR: u32 = 8;
+const TYPE_OPTIONAL: u32 = 9;
+const TENSOR_TYPE_ELEM: u32 = 1;
+const MAP_VALUE_TYPE: u32 = 2;
+
+const DTYPE_FLOAT: u64 = 1;
+const DTYPE_FLOAT16: u64 = 10;
+
+/// Converts the fp32 weights and activations of an ONNX model to fp16,
+/// keeping fp32 graph inputs and outputs (casts are inserted at the edges).
+///
+/// Models whose weights live in external data files are not supported.
+#[flutter_rust_bridge::frb]
+pub fn convert_model_to_fp16(
+    model_path: String,
+    output_path: String,
+) -> Result<(), FlutterEmbedderError> {
+    let bytes = fs::read(&model_path)?;
+    let converted = convert_onnx_to_fp16(&bytes)?;
+    write_atomically(Path::new(&output_path), &converted)
+}
+
+/// Returns the path of the fp16 copy of `model_path`, converting and caching
+/// it first when the cache is missing or older than the source model.
+pub(crate) fn cached_fp16_model(model_path: &str, cache_dir: Option<&str>) -> Result<String> {
+    let source = Path::new(model_path);
+    let stem = source
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .ok_or(err!(ModelLoad, "Invalid model path: {model_path}"))?;
+    let dir = match cache_dir {
+        Some(dir) => PathBuf::from(dir),
+        None => source.parent().map(Path::to_path_buf).unwrap_or_default(),
+    };
+    let target = dir.join(format!("{stem}.fp16.onnx"));
+
+    let source_modified = fs::metadata(source)?.modified()?;
+    let is_fresh = fs::metadata(&target)
+        .and_then(|m| m.modified())
+        .map(|modified| modified >= source_modified)
+        .unwrap_or(false);
+    if !is_fresh {
+        let converted = convert_onnx_to_fp16(&fs::read(source)?)?;
+        fs::create_dir_all(&dir)?;
+        write_atomically(&target, &converted)?;
+    }
+    target
+        .to_str()
+        .map(str::to_string)
+        .ok_or(err!(ModelLoad, "Invalid cache path"))
+}
+
+pub(crate) fn convert_onnx_to_fp16(model: &[u8]) -> Result<Vec<u8>> {
+    let mut fields = parse_fields(model)?;
+    let mut found_graph = false;
+    for field in fields.iter_mut().filter(|f| f.number == MODEL_GRAPH) {
+        let graph = convert_graph(field.bytes()?, true, &[])?;
+        field.value = Value::Bytes(Cow::Owned(graph));
+        found_graph = true;
+    }
+    if !found_graph {
+        return Err(err!(ModelLoad, "Model has no graph"));
+    }
+    Ok(encode_fields(&fields))
+}
+
+fn write_atomically(path: &Path, data: &[u8]) -> Result<()> {
+    let tmp = path.with_extension("onnx.tmp");
+    fs::write(&tmp, data)?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+fn convert_graph(
+    graph: &[u8],
+    keep_io_types: bool,
+    outer_renames: &[(String, String)],
+) -> Result<Vec<u8>> {
+    let mut fields = parse_fields(graph)?;
+
+    let mut initializers = HashSet::new();
+    for field in fields.iter().filter(|f| f.number == GRAPH_INITIALIZER) {
+        if let Some(name) = find_string(field.bytes()?, TENSOR_NAME)? {
+            initializers.insert(name);
+        }
+    }
+
+    // Inputs and outputs of the top-level graph stay fp32; remember which
+    // ones need a cast so everything in between can run in fp16.
+    let mut cast_inputs = Vec::new();
+    let mut cast_outputs = Vec::new();
+    for field in fields.iter_mut() {
+        let is_io = field.number == GRAPH_INPUT || field.number == GRAPH_OUTPUT;
+        if field.number != GRAPH_VALUE_INFO && !is_io {
+            continue;
+        }
+        let name = find_string(field.bytes()?, VALUE_INFO_NAME)?.unwrap_or_default();
+        if keep_io_types && is_io && !initializers.contains(&name) {
+            if has_float_tensor_type(field.bytes()?)? {
+                if field.number == GRAPH_INPUT {
+                    cast_inputs.push(name);
+                } else {
+                    cast_outputs.push(name);
+                }
+            }
+        } else if let Some(converted) = convert_value_info(field.bytes()?)? {
+            field.value = Value::Bytes(Cow::Owned(converted));
+        }
+    }
+
+    let mut renames: Vec<(String, String)> = outer_renames.to_vec();
+    renames.extend(
+        cast_inputs
+            .iter()
+            .chain(cast_outputs.iter())
+            .map(|name| (name.clone(), format!("{name}__fp16"))),
+    );
+
+    for field in fields.iter_mut() {
+        let converted = match field.number {
+            GRAPH_INITIALIZER => convert_tensor(field.bytes()?)?,
+            GRAPH_NODE => Some(convert_node(field.bytes()?, &renames)?),
+            _ => None,
+        };
+        if let Some(converted) = converted {
+            field.value = Value::Bytes(Cow::Owned(converted));
+        }
+    }
+
+    let mut out = Vec::with_capacity(fields.len() + cast_inputs.len() + cast_outputs.len());
+    for name in &cast_inputs {
+        out.push(Field::message(
+            GRAPH_NODE,
+            cast_node(name, &format!("{name}__fp16"), DTYPE_FLOAT16),
+        ));
+    }
+    out.extend(fields);
+    for name in &cast_outputs {
+        out.push(Field::message(
+            GRAPH_NODE,
+            cast_node(&format!("{name}__fp16"), name, DTYPE_FLOAT),
+        ));
+    }
+    Ok(encode_fields(&out))
+}
+
+fn convert_node(node: &[u8], renames: &[(String, String)]) -> Result<Vec<u8>> {
+    let mut fields = parse_fields(node)?;
+    let is_cast = find_string(node, NODE_OP_TYPE)?.as_deref() == Some("Cast");
+    for field in fields.iter_mut() {
+        let converted = match field.number {
+            NODE_INPUT | NODE_OUTPUT => {
+                let name = std::str::from_utf8(field.bytes()?)
+                    .map_err(|e| err!(ModelLoad, "Invalid UTF-8 in model: {e}"))?;
+                renames
+                    .iter()
+                    .find(|(from, _)| from == name)
+                    .map(|(_, renamed)| renamed.as_bytes().to_vec())
+            }
+            NODE_ATTRIBUTE => Some(convert_attribute(field.bytes()?, is_cast, renames)?),
+            _ => None,
+        };
+        if let Some(converted) = converted {
+            field.value = Value::Bytes(Cow::Owned(converted));
+        }
+    }
+    Ok(encode_fields(&fields))
+}
+
+fn convert_attribute(
+    attribute: &[u8],
+    is_cast: bool,
+    renames: &[(String, String)],
+) -> Result<Vec<u8>> {
+    let mut fields = parse_fields(attribute)?;
+    let is_cast_to = is_cast && find_string(attribute, ATTR_NAME)?.as_deref() == Some("to");
+    for field in fields.iter_mut() {
+        let converted = match (field.number, &field.value) {
+            (ATTR_I, Value::Varint(DTYPE_FLOAT)) if is_cast_to => {
+                field.value = Value::Varint(DTYPE_FLOAT16);
+                None
+            }
+            (ATTR_T, _) | (ATTR_TENSORS, _) => convert_tensor(field.bytes()?)?,
+            // Subgraphs (If/Loop/Scan bodies) may capture renamed outer values.
+            (ATTR_G, _) | (ATTR_GRAPHS, _) => Some(convert_graph(field.bytes()?, false, renames)?),
+            _ => None,
+        };
+        if let Some(converted) = converted {
+            field.value = Value::Bytes(Cow::Owned(converted));
+        }
+    }
+    Ok(encode_fields(&fields))
+}
+
+/// Rewrites a FLOAT tensor as FLOAT16 `raw_data`; returns `None` for tensors
+/// of other types.
+fn convert_tensor(tensor: &[u8]) -> Result<Option<Vec<u8>>> {
+    let fields = parse_fields(tensor)?;
+    let is_float = fields
+        .iter()
+        .any(|f| f.number == TENSOR_DATA_TYPE && matches!(f.value, Value::Varint(DTYPE_FLOAT)));
+    if !is_float {
+        return Ok(None);
+    }
+    if fields
+        .iter()
+        .any(|f| f.number == TENSOR_DATA_LOCATION && matches!(f.value, Value::Varint(1)))
+    {
+        return Err(err!(
+            ModelLoad,
+            "Models with external data are not supported"
+        ));
+    }
+
+    let mut values: Vec<f32> = Vec::new();
+    for field in &fields {
+        match (field.number, &field.value) {
+            (TENSOR_RAW_DATA, Value::Bytes(data)) => {
+                values.extend(
+                    data.chunks_exact(4)
+                        .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]])),
+                );
+            }
+            (TENSOR_FLOAT_DATA, Value::Bytes(data)) => {
+                values.extend(
+                    data.chunks_exact(4)
+                        .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]])),
+                );
+            }
+            (TENSOR_FLOAT_DATA, Value::Fixed32(data)) => {
+                values.push(f32::from_le_bytes(*data));
+            }
+            _ => {}
+        }
+    }
+
+    let mut raw = Vec::with_capacity(values.len() * 2);
+    for value in values {
+        raw.extend_from_slice(&to_f16(value).to_le_bytes());
+    }
+
+    let mut out: Vec<Field> = fields
+        .into_iter()
+        .filter(|f| f.number != TENSOR_RAW_DATA && f.number != TENSOR_FLOAT_DATA)
+        .map(|mut f| {
+            if f.number == TENSOR_DATA_TYPE {
+                f.value = Value::Varint(DTYPE_FLOAT16);
+            }
+            f
+        })
+        .collect();
+    out.push(Field::message(TENSOR_RAW_DATA, raw));
+    Ok(Some(encode_fields(&out)))
+}
+
+/// Clamps to the finite fp16 range so large masking constants (e.g. -3.4e38)
+/// don't turn into infinities.
+fn to_f16(value: f32) -> f16 {
+    if value.is_nan() {
+        return f16::NAN;
+    }
+    f16::from_f32(value.clamp(f16::MIN.to_f32(), f16::MAX.to_f32()))
+}
+
+/// Converts the tensor element types of a ValueInfoProto; `None` when it
+/// has no fp32 types.
+fn convert_value_info(value_info: &[u8]) -> Result<Option<Vec<u8>>> {
+    let mut fields = parse_fields(value_info)?;
+    let mut changed = false;
+    for field in fields.iter_mut().filter(|f| f.number == VALUE_INFO_TYPE) {
+        if let Some(converted) = convert_type(field.bytes()?)? {
+            field.value = Value::Bytes(Cow::Owned(converted));
+            changed = true;
+        }
+    }
+    Ok(changed.then(|| encode_fields(&fields)))
+}
+
+fn convert_type(type_proto: &[u8]) -> Result<Option<Vec<u8>>> {
+    let mut fields = parse_fields(type_proto)?;
+    let mut changed = false;
+    for field in fields.iter_mut() {
+        let converted = match field.number {
+            TYPE_TENSOR | TYPE_SPARSE_TENSOR => convert_elem_type(field.bytes()?)?,
+            TYPE_SEQUENCE | TYPE_OPTIONAL => convert_nested_type(field.bytes()?, TENSOR_TYPE_ELEM)?,
+            TYPE_MAP => convert_nested_type(field.bytes()?, MAP_VALUE_TYPE)?,
+            _ => None,
+        };
+        if let Some(converted) = converted {
+            field.value = Value::Bytes(Cow::Owned(converted));
+            changed = true;
+        }
+    }
+    Ok(changed.then(|| encode_fields(&fields)))
+}
+
+fn convert_elem_type(tensor_type: &[u8]) -> Result<Option<Vec<u8>>> {
+    let mut fields = parse_fields(tensor_type)?;
+    let mut changed = false;
+    for field in fields.iter_mut().filter(|f| f.number == TENSOR_TYPE_ELEM) {
+        if matches!(field.value, Value::Varint(DTYPE_FLOAT)) {
+            field.value = Value::Varint(DTYPE_FLOAT16);
+            changed = true;
+        }
+    }
+    Ok(changed.then(|| encode_fields(&fields)))
+}
+
+fn convert_nested_type(message: &[u8], type_field: u32) -> Result<Option<Vec<u8>>> {
+    let mut fields = parse_fields(message)?;
+    let mut changed = false;
+    for field in fields.iter_mut().filter(|f| f.number == type_field) {
+        if let Some(converted) = convert_type(field.bytes()?)? {
+            field.value = Value::Bytes(Cow::Owned(converted));
+            changed = true;
+        }
+    }
+    Ok(changed.then(|| encode_fields(&fields)))
+}
+
+fn has_float_tensor_type(value_info: &[u8]) -> Result<bool> {
+    for field in parse_fields(value_info)?
+        .iter()
+        .filter(|f| f.number == VALUE_INFO_TYPE)
+    {
+        for tensor in parse_fields(field.bytes()?)?
+            .iter()
+            .filter(|f| f.number == TYPE_TENSOR)
+        {
+            if parse_fields(tensor.bytes()?)?.iter().any(|f| {
+                f.number == TENSOR_TYPE_ELEM && matches!(f.value, Value::Varint(DTYPE_FLOAT))
+            }) {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+fn cast_node(input: &str, output: &str, to: u64) -> Vec<u8> {
+    let attribute = encode_fields(&[
+        Field::message(ATTR_NAME, b"to".to_vec()),
+        Field::varint(ATTR_I, to),
+        Field::varint(ATTR_TYPE, ATTR_TYPE_INT),
+    ]);
+    encode_fields(&[
+        Field::message(NODE_INPUT, input.as_bytes().to_vec()),
+        Field::message(NODE_OUTPUT, output.as_bytes().to_vec()),
+        Field::message(NODE_NAME, format!("{output}_cast").into_bytes()),
+        Field::message(NODE_OP_TYPE, b"Cast".to_vec()),
+        Field::message(NODE_ATTRIBUTE, attribute),
+    ])
+}
+
+fn find_string(message: &[u8], number: u32) -> Result<Option<String>> {
+    for field in parse_fields(message)? {
+        if field.number == number {
+            return Ok(Some(
+                std::str::from_utf8(field.bytes()?)
+                    .map_err(|e| err!(ModelLoad, "Invalid UTF-8 in model: {e}"))?
+                    .to_string(),
+            ));
+        }
+    }
+    Ok(None)
+}
+
+// Minimal protobuf wire-format reader/writer. Unknown fields are carried
+// through untouched, so rewriting a message never drops data.
+
+enum Value<'a> {
+    Varint(u64),
+    Fixed64([u8; 8]),
+    Bytes(Cow<'a, [u8]>),
+    Fixed32([u8; 4]),
+}
+
+struct Field<'a> {
+    number: u32,
+    value: Value<'a>,
+}
+
+impl<'a> Field<'a> {
+    fn varint(number: u32, value: u64) -> Self {
+        Self {
+            number,
+            value: Value::Varint(value),
+        }
+    }
+
+    fn message(number: u32, value: Vec<u8>) -> Self {
+        Self {
+            number,
+            value: Value::Bytes(Cow::Owned(value)),
+        }
+    }
+
+    fn bytes(&self) -> Result<&[u8]> {
+        match &self.value {
+            Value::Bytes(data) => Ok(data),
+            _ => Err(err!(
+                InvalidInput,
+                "Field {} is not length-delimited",
+                self.number
+            )),
+        }
+    }
+}
+
+fn parse_fields(mut buf: &[u8]) -> Result<Vec<Field<'_>>> {
+    let mut fields = Vec::new();
+    while !buf.is_empty() {
+        let key = read_varint(&mut buf)?;
+        let number = (key >> 3) as u32;
+        let value = match key & 7 {
+            0 => Value::Varint(read_varint(&mut buf)?),
+            1 => Value::Fixed64(take::<8>(&mut buf)?),
+            2 => {
+                let len = read_varint(&mut buf)? as usize;
+                if len > buf.len() {
+                    return Err(err!(ModelLoad, "Truncated protobuf field {number}"));
+                }
+                let (data, rest) = buf.split_at(len);
+                buf = rest;
+                Value::Bytes(Cow::Borrowed(data))
+            }
+            5 => Value::Fixed32(take::<4>(&mut buf)?),
+            wire => return Err(err!(ModelLoad, "Unsupported protobuf wire type {wire}")),
+        };
+        fields.push(Field { number, value });
+    }
+    Ok(fields)
+}
+
+fn encode_fields(fields: &[Field]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for field in fields {
+        let key = (field.number as u64) << 3;
+        match &field.value {
+            Value::Varint(v) => {
+                write_varint(&mut out, key);
+                write_varint(&mut out, *v);
+            }
+            Value::Fixed64(b) => {
+                write_varint(&mut out, key | 1);
+                out.extend_from_slice(b);
+            }
+            Value::Bytes(b) => {
+                write_varint(&mut out, key | 2);
+                write_varint(&mut out, b.len() as u64);
+                out.extend_from_slice(b);
+            }
+            Value::Fixed32(b) => {
+                write_varint(&mut out, key | 5);
+                out.extend_from_slice(b);
+            }
+        }
+    }
+    out
+}
+
+fn read_varint(buf: &mut &[u8]) -> Result<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = buf
+            .split_first()
+            .ok_or(err!(ModelLoad, "Truncated protobuf varint"))?;
+        *buf = rest;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(err!(ModelLoad, "Invalid protobuf varint"))
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn take<const N: usize>(buf: &mut &[u8]) -> Result<[u8; N]> {
+    if buf.len() < N {
+        return Err(err!(ModelLoad, "Truncated protobuf field"));
+    }
+    let (head, rest) = buf.split_at(N);
+    *buf = rest;
+    let mut bytes = [0u8; N];
+    bytes.copy_from_slice(head);
+    Ok(bytes)
+}
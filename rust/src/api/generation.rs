@@ -0,0 +1,512 @@
+//! Autoregressive text generation with decoder-only ONNX exports (Qwen2.5,
+//! Llama, SmolLM, Phi, ...) in the Optimum layout: the `present.*` outputs
+//! of one step are fed back as the `past_key_values.*` inputs of the next, so
+//! each new token costs a single-token forward pass.
+
+use std::collections::HashMap;
+
+use flutter_rust_bridge::frb;
+use ort::session::Session;
+use ort::value::{DynValue, ValueType};
+use tokenizers::Tokenizer;
+
+use crate::api::calibration::softmax;
+use crate::api::embeddings::pipeline::{resolve_past_kv_shape, tensor_from_i64, zeros_tensor};
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::ort::{
+    build_session_from_file_with_init, build_session_from_memory_with_init, session_model_info,
+    ModelInfo, OrtInitOptions,
+};
+use crate::api::projection::SplitMix64;
+use crate::frb_generated::StreamSink;
+
+const DEFAULT_MAX_NEW_TOKENS: u32 = 128;
+const DEFAULT_SEED: u64 = 42;
+const PAST_PREFIX: &str = "past_key_values";
+const PRESENT_PREFIX: &str = "present";
+/// End-of-sequence tokens of common model families, used when the options
+/// don't name any.
+const EOS_TOKENS: [&str; 6] = [
+    "<|endoftext|>",
+    "<|im_end|>",
+    "<|eot_id|>",
+    "<|end|>",
+    "</s>",
+    "<eos>",
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct GenerationOptions {
+    /// Upper bound on generated tokens (default 128).
+    pub max_new_tokens: Option<u32>,
+    /// Sampling temperature; `None` or 0 decodes greedily.
+    pub temperature: Option<f32>,
+    /// Sample only among the `top_k` most likely tokens.
+    pub top_k: Option<u32>,
+    /// Sample only among the most likely tokens whose probabilities add up
+    /// to `top_p` (nucleus sampling).
+    pub top_p: Option<f32>,
+    /// Seed for sampling, so runs are reproducible (default 42).
+    pub seed: Option<u64>,
+    /// Tokens that end generation. Defaults to the tokenizer's known
+    /// end-of-sequence tokens (`<|endoftext|>`, `<|im_end|>`, `</s>`, ...).
+    pub eos_token_ids: Option<Vec<u32>>,
+    /// Generation stops before the first occurrence of any of these
+    /// strings, which are not part of the result.
+    pub stop_sequences: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FinishReason {
+    /// An end-of-sequence token was produced.
+    Eos,
+    /// `max_new_tokens` was reached.
+    MaxTokens,
+    /// One of the `stop_sequences` was produced.
+    StopSequence,
+    /// The Dart side stopped listening to the stream.
+    Cancelled,
+}
+
+#[derive(Debug, Clone)]
+pub struct GenerationResult {
+    /// The generated text, without the prompt and special tokens.
+    pub text: String,
+    /// Generated token ids, including a final EOS token.
+    pub token_ids: Vec<u32>,
+    pub finish_reason: FinishReason,
+}
+
+/// Text generator for decoder-only language models. The prompt is used as
+/// given, so chat models need it already wrapped in their chat template.
+#[frb(opaque)]
+pub struct TextGenerator {
+    tokenizer: Tokenizer,
+    session: Session,
+    /// Default end-of-sequence tokens found in the vocabulary.
+    eos_ids: Vec<u32>,
+    /// Whether every past input has a matching present output; without
+    /// them each step re-runs the whole sequence.
+    kv_cache: bool,
+}
+
+#[frb(sync)]
+impl TextGenerator {
+    pub fn create(
+        model_path: String,
+        tokenizer_path: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_with_options(model_path, tokenizer_path, None)
+    }
+
+    pub fn create_with_options(
+        model_path: String,
+        tokenizer_path: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer =
+            Tokenizer::from_file(tokenizer_path).map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let session = build_session_from_file_with_init(model_path, ort_options)?;
+        Self::from_parts(tokenizer, session)
+    }
+
+    /// `create` from in-memory model bytes and tokenizer JSON.
+    pub fn create_from_bytes(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_bytes_with_options(model_bytes, tokenizer_json, None)
+    }
+
+    pub fn create_from_bytes_with_options(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer =
+            Tokenizer::from_bytes(tokenizer_json).map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let session = build_session_from_memory_with_init(&model_bytes, ort_options)?;
+        Self::from_parts(tokenizer, session)
+    }
+
+    fn from_parts(tokenizer: Tokenizer, session: Session) -> Result<Self, FlutterEmbedderError> {
+        let inputs: Vec<&str> = session.inputs().iter().map(|i| i.name()).collect();
+        if !inputs.contains(&"input_ids") {
+            return Err(err!(ModelLoad, "Generation model has no input_ids input"));
+        }
+        if !session.outputs().iter().any(|o| o.name() == "logits") {
+            return Err(err!(MissingOutput, "Generation model has no logits output"));
+        }
+        let kv_cache = supports_kv_cache(&session);
+        let eos_ids = EOS_TOKENS
+            .iter()
+            .filter_map(|token| tokenizer.token_to_id(token))
+            .collect();
+        Ok(Self {
+            tokenizer,
+            session,
+            eos_ids,
+            kv_cache,
+        })
+    }
+
+    /// Inputs, outputs and metadata of the loaded ONNX model.
+    pub fn model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&self.session)
+    }
+
+    /// End-of-sequence tokens used when the options don't name any.
+    pub fn eos_token_ids(&self) -> Vec<u32> {
+        self.eos_ids.clone()
+    }
+
+    /// Whether the model reuses its key/value cache between steps.
+    pub fn has_kv_cache(&self) -> bool {
+        self.kv_cache
+    }
+
+    /// Continues `prompt` until an end-of-sequence token, a stop sequence or
+    /// `max_new_tokens`.
+    pub fn generate(
+        &mut self,
+        prompt: String,
+        options: Option<GenerationOptions>,
+    ) -> Result<GenerationResult, FlutterEmbedderError> {
+        self.decode(&prompt, options.unwrap_or_default(), |_| true)
+    }
+}
+
+impl TextGenerator {
+    /// Same as `generate`, but not `sync` on the Dart side.
+    pub fn generate_async(
+        &mut self,
+        prompt: String,
+        options: Option<GenerationOptions>,
+    ) -> Result<GenerationResult, FlutterEmbedderError> {
+        self.generate(prompt, options)
+    }
+
+    /// `generate` that sends the text to `sink` piece by piece as it is
+    /// produced. Text that could be the start of a stop sequence is held
+    /// back until it is clear it isn't one. Generation ends early once the
+    /// Dart side stops listening.
+    pub fn generate_stream(
+        &mut self,
+        prompt: String,
+        options: Option<GenerationOptions>,
+        sink: StreamSink<String>,
+    ) -> Result<GenerationResult, FlutterEmbedderError> {
+        self.decode(&prompt, options.unwrap_or_default(), |piece| {
+            sink.add(piece.to_string()).is_ok()
+        })
+    }
+
+    /// The decoding loop. `on_text` receives each newly completed piece of
+    /// text and returns `false` to stop early.
+    fn decode(
+        &mut self,
+        prompt: &str,
+        options: GenerationOptions,
+        mut on_text: impl FnMut(&str) -> bool,
+    ) -> Result<GenerationResult> {
+        let sampler = Sampler::new(&options)?;
+        let eos_ids = options
+            .eos_token_ids
+            .unwrap_or_else(|| self.eos_ids.clone());
+        let max_new_tokens = options.max_new_tokens.unwrap_or(DEFAULT_MAX_NEW_TOKENS);
+        let stop_sequences: Vec<String> = options
+            .stop_sequences
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .collect();
+        let mut rng = SplitMix64::new(options.seed.unwrap_or(DEFAULT_SEED));
+
+        let encoding = self
+            .tokenizer
+            .encode(prompt, true)
+            .map_err(|e| err!(Tokenization, "{e}"))?;
+        let mut tokens = encoding.get_ids().to_vec();
+        if tokens.is_empty() {
+            return Err(err!(InvalidInput, "Prompt produced no tokens"));
+        }
+        let prompt_len = tokens.len();
+        let mut cache = Vec::new();
+        let mut cached = 0;
+        let mut text = String::new();
+        let mut emitted = 0;
+        let mut finish_reason = FinishReason::MaxTokens;
+
+        for _ in 0..max_new_tokens {
+            let logits = decoder_step(
+                &mut self.session,
+                self.kv_cache,
+                &tokens,
+                cached,
+                &mut cache,
+                |_, _| Ok(None),
+            )?;
+            if self.kv_cache {
+                cached = tokens.len();
+            }
+            let token = sampler.sample(&logits, &mut rng);
+            tokens.push(token);
+            if eos_ids.contains(&token) {
+                finish_reason = FinishReason::Eos;
+                break;
+            }
+
+            text = self.decode_tokens(&tokens[prompt_len..])?;
+            if let Some(end) = stop_position(&text, &stop_sequences) {
+                text.truncate(end);
+                finish_reason = FinishReason::StopSequence;
+                break;
+            }
+            // A trailing U+FFFD is usually a multi-byte character whose
+            // remaining bytes are still to come, and a partial stop sequence
+            // may still turn into a full one.
+            if text.ends_with('\u{FFFD}') {
+                continue;
+            }
+            let ready = text.len() - partial_stop_len(&text, &stop_sequences);
+            let Some(piece) = text.get(emitted..ready).filter(|p| !p.is_empty()) else {
+                continue;
+            };
+            if !on_text(piece) {
+                finish_reason = FinishReason::Cancelled;
+                break;
+            }
+            emitted = ready;
+        }
+
+        let token_ids = tokens.split_off(prompt_len);
+        if finish_reason != FinishReason::StopSequence {
+            text = self.decode_tokens(&token_ids)?;
+        }
+        if finish_reason != FinishReason::Cancelled {
+            if let Some(piece) = text.get(emitted..).filter(|p| !p.is_empty()) {
+                on_text(piece);
+            }
+        }
+        Ok(GenerationResult {
+            text,
+            token_ids,
+            finish_reason,
+        })
+    }
+
+    fn decode_tokens(&self, ids: &[u32]) -> Result<String> {
+        self.tokenizer
+            .decode(ids, true)
+            .map_err(|e| err!(Tokenization, "{e}"))
+    }
+}
+
+/// Runs one decoder step over `tokens`, of which the first `cached` are
+/// already in `cache`, and returns the logits for the next token. With
+/// `kv_cache`, `cache` is replaced by the step's present key/values. Inputs
+/// other than the standard decoder feeds are asked of `extra`, e.g. the
+/// encoder output of an encoder-decoder model.
+pub(crate) fn decoder_step(
+    session: &mut Session,
+    kv_cache: bool,
+    tokens: &[u32],
+    cached: usize,
+    cache: &mut Vec<(String, DynValue)>,
+    mut extra: impl FnMut(&str, &ValueType) -> Result<Option<DynValue>>,
+) -> Result<Vec<f32>> {
+    let total = tokens.len();
+    let ids: Vec<i64> = tokens[cached..].iter().map(|&t| t as i64).collect();
+    let positions: Vec<i64> = (cached as i64..total as i64).collect();
+    let len = ids.len();
+    let mut past: HashMap<String, DynValue> = cache.drain(..).collect();
+
+    let mut inputs: Vec<(String, DynValue)> = Vec::new();
+    for input in session.inputs() {
+        let name = input.name();
+        let dtype = input.dtype();
+        if name.starts_with(PAST_PREFIX) {
+            let value = match past.remove(name) {
+                Some(value) => value,
+                None => zeros_tensor(dtype, &resolve_past_kv_shape(dtype, 1)?)?.into_dyn(),
+            };
+            inputs.push((name.to_string(), value));
+            continue;
+        }
+        let tensor = match name {
+            "input_ids" => tensor_from_i64(dtype, &[1, len], &ids)?,
+            "attention_mask" => tensor_from_i64(dtype, &[1, total], &vec![1; total])?,
+            "position_ids" if rank(dtype) == 1 => tensor_from_i64(dtype, &[len], &positions)?,
+            "position_ids" => tensor_from_i64(dtype, &[1, len], &positions)?,
+            "cache_position" => tensor_from_i64(dtype, &[len], &positions)?,
+            "use_cache_branch" => tensor_from_i64(dtype, &[1], &[(cached > 0) as i64])?,
+            "token_type_ids" => zeros_tensor(dtype, &[1, len])?,
+            _ => match extra(name, dtype)? {
+                Some(value) => {
+                    inputs.push((name.to_string(), value));
+                    continue;
+                }
+                None => {
+                    return Err(err!(
+                        InvalidInput,
+                        "Unsupported decoder model input {name:?}"
+                    ))
+                }
+            },
+        };
+        inputs.push((name.to_string(), tensor.into_dyn()));
+    }
+
+    let outputs = session.run(inputs)?;
+    let mut logits = None;
+    for (name, value) in outputs {
+        if name == "logits" {
+            logits = Some(last_row(&value)?);
+        } else if kv_cache {
+            if let Some(layer) = name.strip_prefix(PRESENT_PREFIX) {
+                cache.push((format!("{PAST_PREFIX}{layer}"), value));
+            }
+        }
+    }
+    logits.ok_or(err!(MissingOutput, "Missing logits output"))
+}
+
+/// Whether every past key/value input has a matching present output, so
+/// decoding can feed one token per step.
+pub(crate) fn supports_kv_cache(session: &Session) -> bool {
+    let past: Vec<&str> = session
+        .inputs()
+        .iter()
+        .map(|i| i.name())
+        .filter(|name| name.starts_with(PAST_PREFIX))
+        .collect();
+    !past.is_empty()
+        && past.iter().all(|name| {
+            let present = present_name(name);
+            session.outputs().iter().any(|o| o.name() == present)
+        })
+}
+
+pub(crate) fn argmax(logits: &[f32]) -> u32 {
+    logits
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .map_or(0, |(i, _)| i as u32)
+}
+
+/// Greedy, top-k and top-p token selection.
+struct Sampler {
+    temperature: f32,
+    top_k: usize,
+    top_p: f32,
+}
+
+impl Sampler {
+    fn new(options: &GenerationOptions) -> Result<Self> {
+        let temperature = options.temperature.unwrap_or(0.0);
+        let top_p = options.top_p.unwrap_or(1.0);
+        if temperature < 0.0 || !(top_p > 0.0 && top_p <= 1.0) || options.top_k == Some(0) {
+            return Err(err!(
+                InvalidInput,
+                "temperature must be >= 0, top_p within (0, 1] and top_k > 0"
+            ));
+        }
+        Ok(Self {
+            temperature,
+            top_k: options.top_k.map_or(usize::MAX, |k| k as usize),
+            top_p,
+        })
+    }
+
+    fn sample(&self, logits: &[f32], rng: &mut SplitMix64) -> u32 {
+        if self.temperature == 0.0 {
+            return argmax(logits);
+        }
+
+        let mut candidates: Vec<(u32, f32)> = logits
+            .iter()
+            .enumerate()
+            .map(|(i, &logit)| (i as u32, logit))
+            .collect();
+        candidates.sort_unstable_by(|a, b| b.1.total_cmp(&a.1));
+        candidates.truncate(self.top_k);
+        let top: Vec<f32> = candidates.iter().map(|&(_, logit)| logit).collect();
+        let mut probabilities = softmax(&top, self.temperature);
+
+        let mut mass = 0.0;
+        let keep = probabilities
+            .iter()
+            .position(|&p| {
+                mass += p;
+                mass >= self.top_p
+            })
+            .map_or(probabilities.len(), |i| i + 1);
+        probabilities.truncate(keep);
+
+        let mut draw = rng.next_f32() * probabilities.iter().sum::<f32>();
+        for (&(id, _), &p) in candidates.iter().zip(&probabilities) {
+            if draw < p {
+                return id;
+            }
+            draw -= p;
+        }
+        candidates
+            .get(keep - 1)
+            .map_or_else(|| argmax(logits), |&(id, _)| id)
+    }
+}
+
+/// Byte offset of the earliest stop sequence in `text`.
+fn stop_position(text: &str, stop_sequences: &[String]) -> Option<usize> {
+    stop_sequences
+        .iter()
+        .filter_map(|s| text.find(s.as_str()))
+        .min()
+}
+
+/// Length of the longest suffix of `text` that starts one of the stop
+/// sequences, i.e. text that must be held back until it is disambiguated.
+fn partial_stop_len(text: &str, stop_sequences: &[String]) -> usize {
+    stop_sequences
+        .iter()
+        .flat_map(|stop| {
+            stop.char_indices()
+                .skip(1)
+                .map(|(i, _)| &stop[..i])
+                .filter(|prefix| text.ends_with(prefix))
+                .map(str::len)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+fn present_name(past_name: &str) -> String {
+    format!("{PRESENT_PREFIX}{}", &past_name[PAST_PREFIX.len()..])
+}
+
+fn rank(dtype: &ValueType) -> usize {
+    match dtype {
+        ValueType::Tensor { shape, .. } => shape.len(),
+        _ => 0,
+    }
+}
+
+/// Logits of the last position from a `[1, seq_len, vocab]` output.
+fn last_row(value: &DynValue) -> Result<Vec<f32>> {
+    let (shape, data) = match value.try_extract_tensor::<f32>() {
+        Ok((shape, data)) => (shape.clone(), data.to_vec()),
+        Err(_) => {
+            let (shape, data) = value.try_extract_tensor::<half::f16>()?;
+            (shape.clone(), data.iter().map(|x| x.to_f32()).collect())
+        }
+    };
+    let vocab = shape.last().map_or(0, |&d| d as usize);
+    if shape.len() != 3 || vocab == 0 || data.len() < vocab {
+        return Err(err!(
+            ShapeMismatch,
+            "Expected [1, seq_len, vocab] logits, got {shape:?}"
+        ));
+    }
+    Ok(data[data.len() - vocab..].to_vec())
+}
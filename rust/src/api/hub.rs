@@ -0,0 +1,118 @@
+use std::path::{Component, Path, PathBuf};
+
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::models::download_with;
+
+const HF_ENDPOINT: &str = "https://huggingface.co";
+const DEFAULT_REVISION: &str = "main";
+const DEFAULT_MODEL_FILE: &str = "onnx/model.onnx";
+const TOKENIZER_FILE: &str = "tokenizer.json";
+
+/// Where `create_from_hub` finds its files in a Hub repo.
+#[derive(Debug, Clone, Default)]
+pub struct HubOptions {
+    /// Branch, tag or commit. Default `main`.
+    pub revision: Option<String>,
+    /// ONNX file within the repo. Default `onnx/model.onnx`; pick e.g.
+    /// `onnx/model_quantized.onnx` for a smaller variant.
+    pub model_file: Option<String>,
+    /// Only use files already in the cache; never touch the network.
+    pub offline: bool,
+}
+
+/// Local path of `filename` from the Hub repo `repo_id` at `revision`
+/// (default `main`), downloading it into `cache_dir` on first use. With
+/// `offline`, a cache miss is an error instead of a download.
+///
+/// Files are cached per revision and never re-checked, so pin a commit or
+/// tag when `main` may move.
+pub fn resolve_hf_model(
+    repo_id: String,
+    filename: String,
+    revision: Option<String>,
+    cache_dir: String,
+    offline: bool,
+) -> Result<String, FlutterEmbedderError> {
+    let revision = revision.as_deref().unwrap_or(DEFAULT_REVISION);
+    let path = cache_path(&cache_dir, &repo_id, revision, &filename)?;
+    if !path.is_file() {
+        if offline {
+            return Err(err!(
+                Network,
+                "{repo_id}/{filename}@{revision} is not cached in {cache_dir}"
+            ));
+        }
+        download(&repo_id, revision, &filename, &path)?;
+    }
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// Resolves `(model_path, tokenizer_path)` for an embedder repo. Models over
+/// 2GB keep their weights in `<model_file>_data` next to the graph; that
+/// file is fetched too when the repo has it.
+pub(crate) fn resolve_hub_files(
+    repo_id: &str,
+    cache_dir: &str,
+    options: Option<HubOptions>,
+) -> Result<(String, String)> {
+    let options = options.unwrap_or_default();
+    let revision = options.revision.as_deref().unwrap_or(DEFAULT_REVISION);
+    let model_file = options.model_file.as_deref().unwrap_or(DEFAULT_MODEL_FILE);
+
+    let data_file = format!("{model_file}_data");
+    let data_path = cache_path(cache_dir, repo_id, revision, &data_file)?;
+    if !options.offline && !data_path.is_file() && remote_exists(repo_id, revision, &data_file)? {
+        download(repo_id, revision, &data_file, &data_path)?;
+    }
+
+    let resolve = |filename: &str| {
+        resolve_hf_model(
+            repo_id.to_string(),
+            filename.to_string(),
+            Some(revision.to_string()),
+            cache_dir.to_string(),
+            options.offline,
+        )
+    };
+    Ok((resolve(model_file)?, resolve(TOKENIZER_FILE)?))
+}
+
+fn file_url(repo_id: &str, revision: &str, filename: &str) -> String {
+    format!("{HF_ENDPOINT}/{repo_id}/resolve/{revision}/{filename}")
+}
+
+fn download(repo_id: &str, revision: &str, filename: &str, path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| err!(Io, "Failed to create {}: {e}", parent.display()))?;
+    }
+    download_with(&file_url(repo_id, revision, filename), path, None, |_| {})
+}
+
+fn remote_exists(repo_id: &str, revision: &str, filename: &str) -> Result<bool> {
+    let url = file_url(repo_id, revision, filename);
+    match ureq::head(&url).call() {
+        Ok(_) => Ok(true),
+        Err(ureq::Error::Status(404, _)) => Ok(false),
+        Err(e) => Err(err!(Network, "Failed to reach {url}: {e}")),
+    }
+}
+
+/// `<cache_dir>/<owner>--<name>/<revision>/<filename>`, rejecting
+/// components that would escape the cache.
+fn cache_path(cache_dir: &str, repo_id: &str, revision: &str, filename: &str) -> Result<PathBuf> {
+    let mut path = PathBuf::from(cache_dir);
+    path.push(repo_id.replace('/', "--"));
+    for part in [revision, filename] {
+        let relative = Path::new(part);
+        if part.is_empty()
+            || !relative
+                .components()
+                .all(|c| matches!(c, Component::Normal(_)))
+        {
+            return Err(err!(InvalidInput, "Invalid Hub path component: {part}"));
+        }
+        path.push(relative);
+    }
+    Ok(path)
+}
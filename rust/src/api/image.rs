@@ -0,0 +1,165 @@
+//! Image decoding, resizing and normalization into model input tensors,
+//! shared by the vision models so Dart never copies raw floats over FFI.
+
+use flutter_rust_bridge::frb;
+use image::imageops::FilterType;
+use image::{DynamicImage, RgbImage, RgbaImage};
+
+use crate::api::error::{err, FlutterEmbedderError, Result};
+
+/// Resampling filter used when resizing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResizeFilter {
+    Nearest,
+    Bilinear,
+    Bicubic,
+}
+
+/// How the image is fitted to the target size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResizeMode {
+    /// Resize to exactly the target size, ignoring the aspect ratio.
+    Stretch,
+    /// Scale to cover the target, then crop the center.
+    CenterCrop,
+    /// Scale to fit inside the target and pad the borders with black.
+    Pad,
+}
+
+/// Memory order of the returned tensor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TensorLayout {
+    /// `[3, height, width]`, as PyTorch-exported models expect.
+    Chw,
+    /// `[height, width, 3]`, as TensorFlow-exported models expect.
+    Hwc,
+}
+
+#[derive(Debug, Clone)]
+pub struct PreprocessOptions {
+    pub width: u32,
+    pub height: u32,
+    /// Per-channel (RGB) mean and standard deviation; pixels are scaled to
+    /// `[0, 1]` and then normalized as `(x - mean) / std`.
+    pub mean: Vec<f32>,
+    pub std: Vec<f32>,
+    pub layout: TensorLayout,
+    pub filter: ResizeFilter,
+    pub mode: ResizeMode,
+    /// For `CenterCrop`, the fraction of the resized image kept by the
+    /// crop, e.g. 0.875 for the "resize to 256, crop 224" convention
+    /// (default 1).
+    pub crop_fraction: Option<f32>,
+}
+
+/// An uncompressed RGBA image, e.g. from `ui.Image.toByteData`.
+#[derive(Debug, Clone)]
+pub struct RgbaPixels {
+    pub width: u32,
+    pub height: u32,
+    /// `width * height * 4` bytes, row-major.
+    pub rgba: Vec<u8>,
+}
+
+/// Decodes an encoded image (PNG or JPEG) and returns it as a normalized
+/// `width * height * 3` float tensor in `options.layout` order.
+#[frb(sync)]
+pub fn preprocess(
+    image_bytes: Vec<u8>,
+    options: PreprocessOptions,
+) -> Result<Vec<f32>, FlutterEmbedderError> {
+    preprocess_rgb(&decode_image(&image_bytes)?, &options)
+}
+
+/// `preprocess` for a raw RGBA buffer; alpha is ignored.
+#[frb(sync)]
+pub fn preprocess_rgba(
+    pixels: RgbaPixels,
+    options: PreprocessOptions,
+) -> Result<Vec<f32>, FlutterEmbedderError> {
+    preprocess_rgb(&rgba_to_rgb(pixels)?, &options)
+}
+
+pub(crate) fn decode_image(bytes: &[u8]) -> Result<RgbImage> {
+    image::load_from_memory(bytes)
+        .map(|image| image.to_rgb8())
+        .map_err(|e| err!(InvalidInput, "Failed to decode image: {e}"))
+}
+
+pub(crate) fn rgba_to_rgb(pixels: RgbaPixels) -> Result<RgbImage> {
+    let (width, height) = (pixels.width, pixels.height);
+    RgbaImage::from_raw(width, height, pixels.rgba)
+        .map(|rgba| DynamicImage::ImageRgba8(rgba).to_rgb8())
+        .ok_or(err!(
+            InvalidInput,
+            "RGBA buffer does not match {width}x{height}"
+        ))
+}
+
+pub(crate) fn preprocess_rgb(image: &RgbImage, options: &PreprocessOptions) -> Result<Vec<f32>> {
+    let (width, height) = image.dimensions();
+    let (target_w, target_h) = (options.width, options.height);
+    if width == 0 || height == 0 {
+        return Err(err!(InvalidInput, "Image is empty"));
+    }
+    if target_w == 0 || target_h == 0 {
+        return Err(err!(InvalidInput, "Target size must be positive"));
+    }
+    let mean: [f32; 3] = options
+        .mean
+        .as_slice()
+        .try_into()
+        .map_err(|_| err!(InvalidInput, "mean must have 3 values"))?;
+    let std: [f32; 3] = options
+        .std
+        .as_slice()
+        .try_into()
+        .map_err(|_| err!(InvalidInput, "std must have 3 values"))?;
+    let filter = match options.filter {
+        ResizeFilter::Nearest => FilterType::Nearest,
+        ResizeFilter::Bilinear => FilterType::Triangle,
+        ResizeFilter::Bicubic => FilterType::CatmullRom,
+    };
+
+    let resized = match options.mode {
+        ResizeMode::Stretch => image::imageops::resize(image, target_w, target_h, filter),
+        ResizeMode::CenterCrop => {
+            let fraction = options.crop_fraction.unwrap_or(1.0);
+            if !(fraction > 0.0 && fraction <= 1.0) {
+                return Err(err!(InvalidInput, "crop_fraction must be within (0, 1]"));
+            }
+            let scale =
+                (target_w as f32 / width as f32).max(target_h as f32 / height as f32) / fraction;
+            let resized_w = ((width as f32 * scale).round() as u32).max(target_w);
+            let resized_h = ((height as f32 * scale).round() as u32).max(target_h);
+            let resized = image::imageops::resize(image, resized_w, resized_h, filter);
+            let left = (resized_w - target_w) / 2;
+            let top = (resized_h - target_h) / 2;
+            image::imageops::crop_imm(&resized, left, top, target_w, target_h).to_image()
+        }
+        ResizeMode::Pad => {
+            let scale = (target_w as f32 / width as f32).min(target_h as f32 / height as f32);
+            let resized_w = ((width as f32 * scale).round() as u32).clamp(1, target_w);
+            let resized_h = ((height as f32 * scale).round() as u32).clamp(1, target_h);
+            let resized = image::imageops::resize(image, resized_w, resized_h, filter);
+            let mut canvas = RgbImage::new(target_w, target_h);
+            let left = (target_w - resized_w) / 2;
+            let top = (target_h - resized_h) / 2;
+            image::imageops::replace(&mut canvas, &resized, left as i64, top as i64);
+            canvas
+        }
+    };
+
+    let plane = (target_w * target_h) as usize;
+    let mut pixels = vec![0f32; 3 * plane];
+    for (i, pixel) in resized.pixels().enumerate() {
+        for c in 0..3 {
+            let value = (pixel[c] as f32 / 255.0 - mean[c]) / std[c];
+            match options.layout {
+                TensorLayout::Chw => pixels[c * plane + i] = value,
+                TensorLayout::Hwc => pixels[i * 3 + c] = value,
+            }
+        }
+    }
+    Ok(pixels)
+}
@@ -0,0 +1,645 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::io::{BufWriter, Read, Write};
+use std::ops::Range;
+
+use flutter_rust_bridge::frb;
+use memmap2::Mmap;
+
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::search::SearchHit;
+use crate::api::utils::normalize;
+
+const MAGIC: &[u8; 4] = b"FEVI";
+const FORMAT_VERSION: u32 = 2;
+const DEFAULT_M: u32 = 16;
+const DEFAULT_EF_CONSTRUCTION: u32 = 100;
+const DEFAULT_EF_SEARCH: u32 = 64;
+
+#[derive(Debug, Clone, Default)]
+pub struct VectorIndexOptions {
+    /// Links per node on the upper layers (twice that on layer 0). Default 16.
+    pub m: Option<u32>,
+    /// Candidate list size while inserting. Default 100.
+    pub ef_construction: Option<u32>,
+    /// Candidate list size while searching; raised to `k` when smaller.
+    /// Default 64.
+    pub ef_search: Option<u32>,
+}
+
+/// Approximate nearest-neighbour index (HNSW) over cosine similarity.
+/// Vectors are normalized on insert; `search` returns hits with the cosine
+/// similarity as score, best first.
+#[frb(opaque)]
+pub struct VectorIndex {
+    m: usize,
+    ef_construction: usize,
+    ef_search: usize,
+    dimension: usize,
+    nodes: Vec<Node>,
+    vectors: Vectors,
+    ids: HashMap<String, u32>,
+    entry: Option<u32>,
+    rng: u64,
+}
+
+struct Node {
+    id: String,
+    /// Neighbour lists, one per layer from 0 up to the node's level.
+    links: Vec<Vec<u32>>,
+    /// Removed nodes stay in the graph for navigation but are never returned.
+    deleted: bool,
+}
+
+#[frb(sync)]
+impl VectorIndex {
+    pub fn new(options: Option<VectorIndexOptions>) -> Self {
+        let options = options.unwrap_or_default();
+        Self {
+            m: options.m.unwrap_or(DEFAULT_M).max(2) as usize,
+            ef_construction: options
+                .ef_construction
+                .unwrap_or(DEFAULT_EF_CONSTRUCTION)
+                .max(1) as usize,
+            ef_search: options.ef_search.unwrap_or(DEFAULT_EF_SEARCH).max(1) as usize,
+            dimension: 0,
+            nodes: Vec::new(),
+            vectors: Vectors::Owned(Vec::new()),
+            ids: HashMap::new(),
+            entry: None,
+            rng: 0x9e37_79b9_7f4a_7c15,
+        }
+    }
+
+    /// Number of live (not removed) vectors.
+    pub fn len(&self) -> u32 {
+        self.ids.len() as u32
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Vector dimension, fixed by the first insert (0 while empty).
+    pub fn dimension(&self) -> u32 {
+        self.dimension as u32
+    }
+
+    pub fn contains(&self, id: String) -> bool {
+        self.ids.contains_key(&id)
+    }
+
+    /// Inserts `vector` under `id`, replacing any vector already stored for it.
+    pub fn add(&mut self, id: String, vector: Vec<f32>) -> Result<(), FlutterEmbedderError> {
+        if vector.is_empty() {
+            return Err(err!(InvalidInput, "Vector must not be empty"));
+        }
+        if self.nodes.is_empty() {
+            self.dimension = vector.len();
+        } else if vector.len() != self.dimension {
+            return Err(err!(
+                InvalidInput,
+                "Vector has dimension {}, index expects {}",
+                vector.len(),
+                self.dimension
+            ));
+        }
+        self.remove(id.clone());
+        self.insert(id, normalize(&vector));
+        Ok(())
+    }
+
+    /// Inserts `ids[i]` → `vectors[i]` for every pair.
+    pub fn add_many(
+        &mut self,
+        ids: Vec<String>,
+        vectors: Vec<Vec<f32>>,
+    ) -> Result<(), FlutterEmbedderError> {
+        if ids.len() != vectors.len() {
+            return Err(err!(
+                InvalidInput,
+                "ids and vectors must have the same length"
+            ));
+        }
+        for (id, vector) in ids.into_iter().zip(vectors) {
+            self.add(id, vector)?;
+        }
+        Ok(())
+    }
+
+    /// Removes `id`; returns whether it was present.
+    pub fn remove(&mut self, id: String) -> bool {
+        match self.ids.remove(&id) {
+            Some(node) => {
+                self.nodes[node as usize].deleted = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The `k` stored vectors most similar to `query`.
+    pub fn search(&self, query: Vec<f32>, k: u32) -> Result<Vec<SearchHit>, FlutterEmbedderError> {
+        let k = k as usize;
+        let Some(entry) = self.entry else {
+            return Ok(Vec::new());
+        };
+        if k == 0 || self.ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        if query.len() != self.dimension {
+            return Err(err!(
+                InvalidInput,
+                "Query has dimension {}, index expects {}",
+                query.len(),
+                self.dimension
+            ));
+        }
+        let query = normalize(&query);
+        let top = self.nodes[entry as usize].links.len() - 1;
+        let mut entry = Candidate {
+            distance: self.distance(&query, entry),
+            node: entry,
+        };
+        for level in (1..=top).rev() {
+            entry = self.greedy_closest(&query, entry, level);
+        }
+        // Removed nodes take up candidate slots, so widen the beam by their
+        // count (bounded by the graph size).
+        let deleted = self.nodes.len() - self.ids.len();
+        let ef = self.ef_search.max(k) + deleted.min(self.nodes.len());
+        let found = self.search_layer(&query, &[entry], ef, 0);
+        Ok(found
+            .into_iter()
+            .filter(|c| !self.nodes[c.node as usize].deleted)
+            .take(k)
+            .map(|c| SearchHit {
+                id: self.nodes[c.node as usize].id.clone(),
+                score: 1.0 - c.distance,
+            })
+            .collect())
+    }
+
+    /// Rebuilds the graph without removed vectors, reclaiming their memory.
+    pub fn compact(&mut self) {
+        let mut live: Vec<(u32, String)> = self
+            .ids
+            .iter()
+            .map(|(id, &node)| (node, id.clone()))
+            .collect();
+        live.sort_by_key(|(node, _)| *node);
+        self.nodes.clear();
+        let vectors = std::mem::replace(&mut self.vectors, Vectors::Owned(Vec::new()));
+        self.ids.clear();
+        self.entry = None;
+        for (node, id) in live {
+            let start = node as usize * self.dimension;
+            let vector = vectors.as_slice()[start..start + self.dimension].to_vec();
+            self.insert(id, vector);
+        }
+    }
+
+    /// Writes the index, graph included, to `path`. The file is written
+    /// next to `path` and renamed over it, so an index `open`ed from `path`
+    /// can be saved back to it.
+    pub fn save(&self, path: String) -> Result<(), FlutterEmbedderError> {
+        let mut tmp = std::ffi::OsString::from(&path);
+        tmp.push(".tmp");
+        let file = std::fs::File::create(&tmp)
+            .map_err(|e| err!(Io, "Failed to create {}: {e}", tmp.to_string_lossy()))?;
+        let mut out = BufWriter::new(file);
+        out.write_all(MAGIC)?;
+        for value in [
+            FORMAT_VERSION,
+            self.m as u32,
+            self.ef_construction as u32,
+            self.ef_search as u32,
+            self.dimension as u32,
+            self.nodes.len() as u32,
+            self.entry.map_or(u32::MAX, |e| e),
+        ] {
+            out.write_all(&value.to_le_bytes())?;
+        }
+        out.write_all(&self.rng.to_le_bytes())?;
+        // All vectors in one block, 4-byte aligned after the 40-byte header,
+        // so `open` can use them in place.
+        for value in self.vectors.as_slice() {
+            out.write_all(&value.to_le_bytes())?;
+        }
+        for node in &self.nodes {
+            out.write_all(&(node.id.len() as u32).to_le_bytes())?;
+            out.write_all(node.id.as_bytes())?;
+            out.write_all(&[node.deleted as u8])?;
+            out.write_all(&(node.links.len() as u32).to_le_bytes())?;
+            for links in &node.links {
+                out.write_all(&(links.len() as u32).to_le_bytes())?;
+                for link in links {
+                    out.write_all(&link.to_le_bytes())?;
+                }
+            }
+        }
+        let file = out
+            .into_inner()
+            .map_err(|e| err!(Io, "Failed to write {}: {e}", tmp.to_string_lossy()))?;
+        file.sync_all()?;
+        std::fs::rename(&tmp, &path).map_err(|e| err!(Io, "Failed to replace {path}: {e}"))?;
+        Ok(())
+    }
+
+    /// Reads an index written by `save` into memory.
+    pub fn load(path: String) -> Result<Self, FlutterEmbedderError> {
+        let bytes = std::fs::read(&path).map_err(|e| err!(Io, "Failed to open {path}: {e}"))?;
+        let (mut index, block) = Self::decode(&bytes, &path)?;
+        if let Some(block) = block {
+            index.vectors = Vectors::Owned(floats_from_le(&bytes[block]));
+        }
+        Ok(index)
+    }
+
+    /// Like `load`, but memory-maps the file and searches the vectors in
+    /// place instead of reading them, so opening a large index costs little
+    /// more than reading its graph. The file must not be modified while the
+    /// index is open; the first `add` or `compact` copies the vectors to
+    /// memory.
+    pub fn open(path: String) -> Result<Self, FlutterEmbedderError> {
+        let file =
+            std::fs::File::open(&path).map_err(|e| err!(Io, "Failed to open {path}: {e}"))?;
+        // SAFETY: the mapping is read-only; callers are told not to modify
+        // the file while the index is open.
+        let map = unsafe { Mmap::map(&file) }.map_err(|e| err!(Io, "Failed to map {path}: {e}"))?;
+        let (mut index, block) = Self::decode(&map, &path)?;
+        if let Some(block) = block {
+            let aligned = (map.as_ptr() as usize + block.start) % std::mem::align_of::<f32>() == 0;
+            index.vectors = if aligned && cfg!(target_endian = "little") {
+                Vectors::Mapped { map, block }
+            } else {
+                Vectors::Owned(floats_from_le(&map[block]))
+            };
+        }
+        Ok(index)
+    }
+}
+
+impl VectorIndex {
+    /// Parses a saved index. Version 1 files store each vector next to its
+    /// node and are read into memory; for version 2 the byte range of the
+    /// vector block is returned and `vectors` is left empty.
+    fn decode(bytes: &[u8], path: &str) -> Result<(Self, Option<Range<usize>>)> {
+        let mut input = bytes;
+        let mut magic = [0u8; 4];
+        input.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(err!(Io, "{path} is not a vector index"));
+        }
+        let version = read_u32(&mut input)?;
+        if !(1..=FORMAT_VERSION).contains(&version) {
+            return Err(err!(Io, "Unsupported vector index version {version}"));
+        }
+        let m = read_u32(&mut input)? as usize;
+        let ef_construction = read_u32(&mut input)? as usize;
+        let ef_search = read_u32(&mut input)? as usize;
+        let dimension = read_u32(&mut input)? as usize;
+        let count = read_u32(&mut input)? as usize;
+        let entry = Some(read_u32(&mut input)?).filter(|&e| e != u32::MAX);
+        let mut rng = [0u8; 8];
+        input.read_exact(&mut rng)?;
+
+        let mut block = None;
+        if version >= 2 {
+            let start = bytes.len() - input.len();
+            let len = count
+                .checked_mul(dimension)
+                .and_then(|n| n.checked_mul(4))
+                .filter(|&len| len <= input.len())
+                .ok_or_else(|| err!(Io, "Corrupt vector index {path}"))?;
+            input = &input[len..];
+            block = Some(start..start + len);
+        }
+
+        // Lengths below come from the file, so each is checked against the
+        // bytes left before anything is allocated for it.
+        let corrupt = || err!(Io, "Corrupt vector index {path}");
+        let mut nodes = Vec::new();
+        let mut inline_vectors = Vec::new();
+        let mut ids = HashMap::new();
+        for index in 0..count {
+            let id_len = read_u32(&mut input)? as usize;
+            if id_len > input.len() {
+                return Err(corrupt());
+            }
+            let (id, rest) = input.split_at(id_len);
+            input = rest;
+            let id =
+                String::from_utf8(id.to_vec()).map_err(|_| err!(Io, "Invalid id in {path}"))?;
+            let mut deleted = [0u8; 1];
+            input.read_exact(&mut deleted)?;
+            if version == 1 {
+                for _ in 0..dimension {
+                    inline_vectors.push(f32::from_bits(read_u32(&mut input)?));
+                }
+            }
+            let levels = read_u32(&mut input)? as usize;
+            // Every level takes at least its 4-byte length.
+            if levels == 0 || levels > input.len() / 4 {
+                return Err(corrupt());
+            }
+            let mut links = Vec::new();
+            for _ in 0..levels {
+                let len = read_u32(&mut input)? as usize;
+                if len > input.len() / 4 {
+                    return Err(corrupt());
+                }
+                let mut level = Vec::new();
+                for _ in 0..len {
+                    let link = read_u32(&mut input)?;
+                    if link as usize >= count {
+                        return Err(corrupt());
+                    }
+                    level.push(link);
+                }
+                links.push(level);
+            }
+            let deleted = deleted[0] != 0;
+            if !deleted {
+                ids.insert(id.clone(), index as u32);
+            }
+            nodes.push(Node { id, links, deleted });
+        }
+        if entry.is_some_and(|e| e as usize >= count) || (entry.is_none() && count > 0) {
+            return Err(corrupt());
+        }
+
+        let index = Self {
+            m,
+            ef_construction,
+            ef_search,
+            dimension,
+            nodes,
+            vectors: Vectors::Owned(inline_vectors),
+            ids,
+            entry,
+            rng: u64::from_le_bytes(rng),
+        };
+        Ok((index, block))
+    }
+
+    fn vector(&self, node: u32) -> &[f32] {
+        let start = node as usize * self.dimension;
+        &self.vectors.as_slice()[start..start + self.dimension]
+    }
+
+    fn insert(&mut self, id: String, vector: Vec<f32>) {
+        let node = self.nodes.len() as u32;
+        let level = self.random_level();
+        self.nodes.push(Node {
+            id: id.clone(),
+            links: vec![Vec::new(); level + 1],
+            deleted: false,
+        });
+        self.vectors.push(&vector);
+        self.ids.insert(id, node);
+
+        let Some(entry) = self.entry else {
+            self.entry = Some(node);
+            return;
+        };
+        let query = vector;
+        let top = self.nodes[entry as usize].links.len() - 1;
+        let mut closest = Candidate {
+            distance: self.distance(&query, entry),
+            node: entry,
+        };
+        for layer in ((level + 1)..=top).rev() {
+            closest = self.greedy_closest(&query, closest, layer);
+        }
+        let mut entry_points = vec![closest];
+        for layer in (0..=level.min(top)).rev() {
+            let candidates = self.search_layer(&query, &entry_points, self.ef_construction, layer);
+            let neighbours = self.select_neighbours(&candidates, self.max_links(layer));
+            self.nodes[node as usize].links[layer] = neighbours.clone();
+            for neighbour in neighbours {
+                self.link(neighbour, node, layer);
+            }
+            entry_points = candidates;
+        }
+        if level > top {
+            self.entry = Some(node);
+        }
+    }
+
+    /// Adds `to` to `from`'s links on `layer`, pruning back to the closest
+    /// neighbours when the list overflows.
+    fn link(&mut self, from: u32, to: u32, layer: usize) {
+        let max = self.max_links(layer);
+        let links = &mut self.nodes[from as usize].links[layer];
+        links.push(to);
+        if links.len() <= max {
+            return;
+        }
+        let base = self.vector(from).to_vec();
+        let mut candidates: Vec<Candidate> = self.nodes[from as usize].links[layer]
+            .iter()
+            .map(|&n| Candidate {
+                distance: self.distance(&base, n),
+                node: n,
+            })
+            .collect();
+        candidates.sort();
+        self.nodes[from as usize].links[layer] = self.select_neighbours(&candidates, max);
+    }
+
+    /// Neighbour selection heuristic from the HNSW paper: keeps a candidate
+    /// only if it is closer to the base than to any already selected
+    /// neighbour, then tops up with the closest remaining ones. `candidates`
+    /// must be sorted by distance.
+    fn select_neighbours(&self, candidates: &[Candidate], max: usize) -> Vec<u32> {
+        let mut selected: Vec<u32> = Vec::with_capacity(max);
+        let mut skipped = Vec::new();
+        for candidate in candidates {
+            if selected.len() >= max {
+                break;
+            }
+            let vector = self.vector(candidate.node);
+            let diverse = selected
+                .iter()
+                .all(|&s| self.distance(vector, s) > candidate.distance);
+            if diverse {
+                selected.push(candidate.node);
+            } else {
+                skipped.push(candidate.node);
+            }
+        }
+        for node in skipped {
+            if selected.len() >= max {
+                break;
+            }
+            selected.push(node);
+        }
+        selected
+    }
+
+    fn greedy_closest(&self, query: &[f32], mut closest: Candidate, layer: usize) -> Candidate {
+        loop {
+            let mut improved = false;
+            for &neighbour in &self.nodes[closest.node as usize].links[layer] {
+                let distance = self.distance(query, neighbour);
+                if distance < closest.distance {
+                    closest = Candidate {
+                        distance,
+                        node: neighbour,
+                    };
+                    improved = true;
+                }
+            }
+            if !improved {
+                return closest;
+            }
+        }
+    }
+
+    /// Beam search on one layer; returns up to `ef` candidates, closest first.
+    fn search_layer(
+        &self,
+        query: &[f32],
+        entry_points: &[Candidate],
+        ef: usize,
+        layer: usize,
+    ) -> Vec<Candidate> {
+        let mut visited: HashSet<u32> = entry_points.iter().map(|c| c.node).collect();
+        // Min-heap of nodes to expand and max-heap of the best `ef` found.
+        let mut frontier: BinaryHeap<std::cmp::Reverse<Candidate>> =
+            entry_points.iter().map(|&c| std::cmp::Reverse(c)).collect();
+        let mut best: BinaryHeap<Candidate> = entry_points.iter().copied().collect();
+        while best.len() > ef {
+            best.pop();
+        }
+
+        while let Some(std::cmp::Reverse(current)) = frontier.pop() {
+            if let Some(worst) = best.peek() {
+                if best.len() >= ef && current.distance > worst.distance {
+                    break;
+                }
+            }
+            for &neighbour in &self.nodes[current.node as usize].links[layer] {
+                if !visited.insert(neighbour) {
+                    continue;
+                }
+                let candidate = Candidate {
+                    distance: self.distance(query, neighbour),
+                    node: neighbour,
+                };
+                let admit = best.len() < ef
+                    || best
+                        .peek()
+                        .is_some_and(|worst| candidate.distance < worst.distance);
+                if admit {
+                    frontier.push(std::cmp::Reverse(candidate));
+                    best.push(candidate);
+                    if best.len() > ef {
+                        best.pop();
+                    }
+                }
+            }
+        }
+        best.into_sorted_vec()
+    }
+
+    fn distance(&self, query: &[f32], node: u32) -> f32 {
+        let vector = self.vector(node);
+        1.0 - query.iter().zip(vector).map(|(a, b)| a * b).sum::<f32>()
+    }
+
+    fn max_links(&self, layer: usize) -> usize {
+        if layer == 0 {
+            self.m * 2
+        } else {
+            self.m
+        }
+    }
+
+    /// Exponentially distributed level with normalization factor 1/ln(M).
+    fn random_level(&mut self) -> usize {
+        // xorshift64*: deterministic, so rebuilding an index from the same
+        // inserts yields the same graph.
+        self.rng ^= self.rng >> 12;
+        self.rng ^= self.rng << 25;
+        self.rng ^= self.rng >> 27;
+        let bits = self.rng.wrapping_mul(0x2545_f491_4f6c_dd1d) >> 11;
+        let uniform = (bits as f64 + 1.0) / (1u64 << 53) as f64;
+        let level = -uniform.ln() / (self.m as f64).ln();
+        (level as usize).min(16)
+    }
+}
+
+/// Row-major storage for the node vectors, either on the heap or borrowed
+/// from a memory-mapped index file.
+enum Vectors {
+    Owned(Vec<f32>),
+    Mapped { map: Mmap, block: Range<usize> },
+}
+
+impl Vectors {
+    fn as_slice(&self) -> &[f32] {
+        match self {
+            Vectors::Owned(values) => values,
+            Vectors::Mapped { map, block } => {
+                // SAFETY: `open` only maps blocks that are f32-aligned on a
+                // little-endian target, and every bit pattern is a valid f32.
+                let (prefix, values, suffix) = unsafe { map[block.clone()].align_to::<f32>() };
+                debug_assert!(prefix.is_empty() && suffix.is_empty());
+                values
+            }
+        }
+    }
+
+    /// Appends one vector, first copying a mapped block to the heap.
+    fn push(&mut self, vector: &[f32]) {
+        if let Vectors::Mapped { .. } = self {
+            *self = Vectors::Owned(self.as_slice().to_vec());
+        }
+        if let Vectors::Owned(values) = self {
+            values.extend_from_slice(vector);
+        }
+    }
+}
+
+fn floats_from_le(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Candidate {
+    distance: f32,
+    node: u32,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.node.cmp(&other.node))
+    }
+}
+
+fn read_u32(input: &mut impl Read) -> Result<u32> {
+    let mut bytes = [0u8; 4];
+    input.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
@@ -0,0 +1,100 @@
+use crate::api::embeddings::{TokenEmbeddings, TokenSalience};
+use crate::api::tokenizer::TokenOffsets;
+
+#[derive(Debug, Clone)]
+pub struct KeywordWeight {
+    pub word: String,
+    /// Byte offsets of the word in the source text.
+    pub start: u32,
+    pub end: u32,
+    pub weight: f32,
+}
+
+/// Keyword weights from hidden-state norms: tokens whose contextual vectors
+/// have a larger L2 norm tend to carry more content than function words.
+#[flutter_rust_bridge::frb(sync)]
+pub fn keywords_from_token_embeddings(
+    text: String,
+    token_embeddings: TokenEmbeddings,
+    top_k: Option<u32>,
+) -> Vec<KeywordWeight> {
+    let scores: Vec<f32> = token_embeddings
+        .embeddings
+        .iter()
+        .map(|e| e.iter().map(|x| x * x).sum::<f32>().sqrt())
+        .collect();
+    word_weights(&text, &token_embeddings.offsets, &scores, top_k)
+}
+
+/// Keyword weights from attention salience (see `explain_tokens`).
+#[flutter_rust_bridge::frb(sync)]
+pub fn keywords_from_salience(
+    text: String,
+    salience: TokenSalience,
+    top_k: Option<u32>,
+) -> Vec<KeywordWeight> {
+    word_weights(&text, &salience.offsets, &salience.scores, top_k)
+}
+
+/// Sums token scores into the words they fall in, normalises the weights to
+/// sum to 1 and returns word occurrences sorted by weight.
+pub(crate) fn word_weights(
+    text: &str,
+    offsets: &[TokenOffsets],
+    scores: &[f32],
+    top_k: Option<u32>,
+) -> Vec<KeywordWeight> {
+    let mut words: Vec<KeywordWeight> = word_spans(text)
+        .into_iter()
+        .map(|(start, end)| KeywordWeight {
+            word: text[start..end].to_string(),
+            start: start as u32,
+            end: end as u32,
+            weight: 0.0,
+        })
+        .collect();
+
+    for (offset, &score) in offsets.iter().zip(scores) {
+        // Special tokens have empty spans and carry no lexical content.
+        if offset.start >= offset.end {
+            continue;
+        }
+        if let Some(word) = words
+            .iter_mut()
+            .find(|w| offset.start < w.end && offset.end > w.start)
+        {
+            word.weight += score;
+        }
+    }
+
+    words.retain(|w| w.weight > 0.0);
+    let total: f32 = words.iter().map(|w| w.weight).sum();
+    if total > 0.0 {
+        words.iter_mut().for_each(|w| w.weight /= total);
+    }
+    words.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+    if let Some(k) = top_k {
+        words.truncate(k as usize);
+    }
+    words
+}
+
+/// Byte spans of alphanumeric runs in `text`.
+pub(crate) fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        match (c.is_alphanumeric(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                spans.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        spans.push((s, text.len()));
+    }
+    spans
+}
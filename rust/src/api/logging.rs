@@ -0,0 +1,181 @@
+//! Forwards `tracing` events from this crate and from ONNX Runtime (which
+//! `ort` reports through `tracing`) to a Dart stream.
+
+use std::fmt::{self, Write as _};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use flutter_rust_bridge::frb;
+use ort::logging::{LogLevel as OrtLogLevel, LoggerFunction};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::subscriber::Interest;
+use tracing::{Event, Level, Metadata, Subscriber};
+
+use crate::api::error::{err, FlutterEmbedderError};
+use crate::frb_generated::StreamSink;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    fn from_tracing(level: &Level) -> Self {
+        match *level {
+            Level::ERROR => Self::Error,
+            Level::WARN => Self::Warn,
+            Level::INFO => Self::Info,
+            Level::DEBUG => Self::Debug,
+            _ => Self::Trace,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub level: LogLevel,
+    /// Module that logged, e.g. `flutter_embedder::api::ort` or `ort`.
+    pub target: String,
+    /// The message followed by any structured fields as `key=value`.
+    pub message: String,
+    pub timestamp_ms: u64,
+}
+
+/// Most verbose level forwarded, as `LogLevel as u8`; above `Trace` when
+/// logging is off.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(u8::MAX);
+static SINK: Mutex<Option<StreamSink<LogEntry>>> = Mutex::new(None);
+static INSTALLED: OnceLock<bool> = OnceLock::new();
+
+/// Sends log entries at `level` and more severe to `sink`, e.g. which
+/// output tensor an embedder picked or why an execution provider fell back
+/// to CPU. Calling it again replaces the sink and level. Fails when another
+/// `tracing` subscriber was installed globally first.
+pub fn init_logging(
+    level: LogLevel,
+    sink: StreamSink<LogEntry>,
+) -> Result<(), FlutterEmbedderError> {
+    let installed =
+        *INSTALLED.get_or_init(|| tracing::subscriber::set_global_default(SinkSubscriber).is_ok());
+    if !installed {
+        return Err(err!(InvalidInput, "A log subscriber is already installed"));
+    }
+    *SINK.lock().unwrap_or_else(|e| e.into_inner()) = Some(sink);
+    MAX_LEVEL.store(level as u8, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Stops forwarding log entries and closes the Dart stream.
+#[frb(sync)]
+pub fn stop_logging() {
+    MAX_LEVEL.store(u8::MAX, Ordering::Relaxed);
+    SINK.lock().unwrap_or_else(|e| e.into_inner()).take();
+}
+
+/// ONNX Runtime logger that re-emits messages at `min_level` and more
+/// severe as `tracing` events with target `ort`, so they reach the same
+/// stream as this crate's logs.
+pub(crate) fn ort_logger(min_level: LogLevel) -> LoggerFunction {
+    Arc::new(move |severity, _category, id, location, message| {
+        let level = match severity {
+            OrtLogLevel::Verbose => LogLevel::Debug,
+            OrtLogLevel::Info => LogLevel::Info,
+            OrtLogLevel::Warning => LogLevel::Warn,
+            OrtLogLevel::Error | OrtLogLevel::Fatal => LogLevel::Error,
+        };
+        if level > min_level {
+            return;
+        }
+        match level {
+            LogLevel::Error => tracing::error!(target: "ort", id, location, "{message}"),
+            LogLevel::Warn => tracing::warn!(target: "ort", id, location, "{message}"),
+            LogLevel::Info => tracing::info!(target: "ort", id, location, "{message}"),
+            LogLevel::Debug | LogLevel::Trace => {
+                tracing::debug!(target: "ort", id, location, "{message}")
+            }
+        }
+    })
+}
+
+/// Subscriber that only handles events; spans are accepted and ignored.
+struct SinkSubscriber;
+
+#[frb(ignore)]
+impl Subscriber for SinkSubscriber {
+    fn register_callsite(&self, _: &'static Metadata<'static>) -> Interest {
+        // The level can change at runtime, so ask `enabled` on every event.
+        Interest::sometimes()
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        LogLevel::from_tracing(metadata.level()) as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut guard = SINK.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(sink) = guard.as_ref() else {
+            return;
+        };
+        let metadata = event.metadata();
+        let mut visitor = MessageVisitor {
+            message: String::new(),
+            fields: String::new(),
+        };
+        event.record(&mut visitor);
+        let entry = LogEntry {
+            level: LogLevel::from_tracing(metadata.level()),
+            target: metadata.target().to_string(),
+            message: visitor.message + &visitor.fields,
+            timestamp_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_millis() as u64),
+        };
+        if sink.add(entry).is_err() {
+            // The Dart side stopped listening.
+            *guard = None;
+            MAX_LEVEL.store(u8::MAX, Ordering::Relaxed);
+        }
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+#[frb(ignore)]
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.fields, " {}={value}", field.name());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{value:?}");
+        } else {
+            let _ = write!(self.fields, " {}={value:?}", field.name());
+        }
+    }
+}
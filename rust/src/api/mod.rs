@@ -1,7 +1,32 @@
-pub mod tokenizer;
-pub mod utils;
+pub mod audio;
+pub mod bench;
+pub mod bm25;
+pub mod cache;
+pub mod calibration;
+pub mod chat;
+pub mod chunking;
+pub mod classification;
+pub mod classify;
 pub mod embeddings;
+pub mod error;
+pub mod evaluation;
+pub mod fp16;
+pub mod generation;
+pub mod hub;
+pub mod image;
+pub mod index;
+pub mod keywords;
+pub mod logging;
+pub mod models;
+pub mod ner;
 pub mod ort;
+pub mod projection;
+pub mod queue;
+pub mod rerank;
+pub mod search;
+pub mod store;
+pub mod tokenizer;
+pub mod utils;
 
 #[flutter_rust_bridge::frb(init)]
 pub fn init_app() {
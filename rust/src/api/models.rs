@@ -0,0 +1,260 @@
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use flutter_rust_bridge::frb;
+use sha2::{Digest, Sha256};
+
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::frb_generated::StreamSink;
+
+const CHUNK_SIZE: usize = 64 * 1024;
+/// Progress events are sent at most once per this many bytes.
+const PROGRESS_INTERVAL: u64 = 1024 * 1024;
+const REGISTRY_FILE: &str = "models.json";
+
+/// Progress of a `download_model` call.
+#[derive(Debug, Clone)]
+pub struct DownloadProgress {
+    /// Bytes on disk so far, including any resumed partial download.
+    pub downloaded: u64,
+    /// Full size of the file; `None` when the server does not report it.
+    pub total: Option<u64>,
+}
+
+/// A model file recorded in a models directory (see `register_model`).
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct InstalledModel {
+    pub name: String,
+    pub revision: String,
+    pub path: String,
+    pub size: u64,
+}
+
+/// Downloads `url` to `dest`, streaming progress to `sink`.
+///
+/// Data is written to `<dest>.part` first, so an interrupted download is
+/// resumed with a range request on the next call. When `expected_sha256`
+/// (hex) is given, the finished file must match it or it is deleted and an
+/// error returned. The file is moved to `dest` only once complete.
+pub fn download_model(
+    url: String,
+    dest: String,
+    expected_sha256: Option<String>,
+    sink: StreamSink<DownloadProgress>,
+) -> Result<(), FlutterEmbedderError> {
+    download_with(&url, Path::new(&dest), expected_sha256.as_deref(), |p| {
+        // The Dart side may stop listening; the download still finishes.
+        let _ = sink.add(p);
+    })
+}
+
+/// Lowercase hex SHA-256 of the file at `path`.
+#[frb(sync)]
+pub fn sha256_file(path: String) -> Result<String, FlutterEmbedderError> {
+    let mut file = File::open(&path).map_err(|e| err!(Io, "Failed to open {path}: {e}"))?;
+    let mut hasher = Sha256::new();
+    hash_reader(&mut file, &mut hasher)?;
+    Ok(to_hex(&hasher.finalize()))
+}
+
+/// Records `model` in the registry of `models_dir`, replacing any entry with
+/// the same name and revision.
+#[frb(sync)]
+pub fn register_model(
+    models_dir: String,
+    model: InstalledModel,
+) -> Result<(), FlutterEmbedderError> {
+    let mut models = read_registry(&models_dir)?;
+    models.retain(|m| m.name != model.name || m.revision != model.revision);
+    models.push(model);
+    write_registry(&models_dir, &models)
+}
+
+/// Installed models in `models_dir`, sorted by name then revision. Entries
+/// whose file has since been deleted are skipped.
+#[frb(sync)]
+pub fn list_installed_models(
+    models_dir: String,
+) -> Result<Vec<InstalledModel>, FlutterEmbedderError> {
+    let mut models: Vec<InstalledModel> = read_registry(&models_dir)?
+        .into_iter()
+        .filter(|m| Path::new(&m.path).is_file())
+        .collect();
+    models.sort_by(|a, b| (&a.name, &a.revision).cmp(&(&b.name, &b.revision)));
+    Ok(models)
+}
+
+/// The installed model called `name`; with no `revision`, the most recently
+/// registered one.
+#[frb(sync)]
+pub fn find_installed_model(
+    models_dir: String,
+    name: String,
+    revision: Option<String>,
+) -> Result<Option<InstalledModel>, FlutterEmbedderError> {
+    Ok(read_registry(&models_dir)?
+        .into_iter()
+        .rev()
+        .filter(|m| Path::new(&m.path).is_file())
+        .find(|m| m.name == name && revision.as_ref().is_none_or(|r| &m.revision == r)))
+}
+
+/// Removes `name` (all revisions unless `revision` is given) from the
+/// registry, deleting the files too when `delete_files` is set. Returns
+/// whether anything was removed.
+#[frb(sync)]
+pub fn remove_installed_model(
+    models_dir: String,
+    name: String,
+    revision: Option<String>,
+    delete_files: bool,
+) -> Result<bool, FlutterEmbedderError> {
+    let (removed, kept): (Vec<_>, Vec<_>) = read_registry(&models_dir)?
+        .into_iter()
+        .partition(|m| m.name == name && revision.as_ref().is_none_or(|r| &m.revision == r));
+    if removed.is_empty() {
+        return Ok(false);
+    }
+    if delete_files {
+        for model in &removed {
+            match std::fs::remove_file(&model.path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(err!(Io, "Failed to delete {}: {e}", model.path)),
+            }
+        }
+    }
+    write_registry(&models_dir, &kept)?;
+    Ok(true)
+}
+
+pub(crate) fn download_with(
+    url: &str,
+    dest: &Path,
+    expected_sha256: Option<&str>,
+    mut progress: impl FnMut(DownloadProgress),
+) -> Result<()> {
+    let part = part_path(dest);
+    let mut hasher = Sha256::new();
+    let mut downloaded = match File::open(&part) {
+        Ok(mut file) => hash_reader(&mut file, &mut hasher)?,
+        Err(_) => 0,
+    };
+
+    let mut request = ureq::get(url);
+    if downloaded > 0 {
+        request = request.set("Range", &format!("bytes={downloaded}-"));
+    }
+    let response = match request.call() {
+        Ok(response) => response,
+        // The partial file is already complete (or larger than the remote).
+        Err(ureq::Error::Status(416, _)) if downloaded > 0 => {
+            return finish(&part, dest, hasher, expected_sha256);
+        }
+        Err(e) => return Err(err!(Network, "Failed to download {url}: {e}")),
+    };
+
+    let length: Option<u64> = response
+        .header("Content-Length")
+        .and_then(|l| l.parse().ok());
+    let mut file = if response.status() == 206 {
+        OpenOptions::new().append(true).open(&part)?
+    } else {
+        // The server ignored the range; start over.
+        downloaded = 0;
+        hasher = Sha256::new();
+        File::create(&part).map_err(|e| err!(Io, "Failed to create {}: {e}", part.display()))?
+    };
+    let total = length.map(|l| l + downloaded);
+    progress(DownloadProgress { downloaded, total });
+
+    let mut reader = response.into_reader();
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    let mut reported = downloaded;
+    loop {
+        let n = reader.read(&mut buffer)?;
+        if n == 0 {
+            break;
+        }
+        file.write_all(&buffer[..n])?;
+        hasher.update(&buffer[..n]);
+        downloaded += n as u64;
+        if downloaded - reported >= PROGRESS_INTERVAL {
+            progress(DownloadProgress { downloaded, total });
+            reported = downloaded;
+        }
+    }
+    file.flush()?;
+    drop(file);
+    if total.is_some_and(|t| t != downloaded) {
+        return Err(err!(
+            Network,
+            "Download of {url} ended after {downloaded} bytes; call again to resume"
+        ));
+    }
+    progress(DownloadProgress { downloaded, total });
+    finish(&part, dest, hasher, expected_sha256)
+}
+
+fn finish(part: &Path, dest: &Path, hasher: Sha256, expected_sha256: Option<&str>) -> Result<()> {
+    if let Some(expected) = expected_sha256 {
+        let actual = to_hex(&hasher.finalize());
+        if !actual.eq_ignore_ascii_case(expected.trim()) {
+            let _ = std::fs::remove_file(part);
+            return Err(err!(
+                ChecksumMismatch,
+                "Checksum mismatch for {}: expected {expected}, got {actual}",
+                dest.display()
+            ));
+        }
+    }
+    std::fs::rename(part, dest)
+        .map_err(|e| err!(Io, "Failed to move download to {}: {e}", dest.display()))
+}
+
+fn part_path(dest: &Path) -> PathBuf {
+    let mut name = dest.as_os_str().to_owned();
+    name.push(".part");
+    PathBuf::from(name)
+}
+
+/// Feeds all of `reader` into `hasher`, returning the byte count.
+fn hash_reader(reader: &mut impl Read, hasher: &mut Sha256) -> Result<u64> {
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    let mut total = 0u64;
+    loop {
+        let n = reader.read(&mut buffer)?;
+        if n == 0 {
+            return Ok(total);
+        }
+        hasher.update(&buffer[..n]);
+        total += n as u64;
+    }
+}
+
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn read_registry(models_dir: &str) -> Result<Vec<InstalledModel>> {
+    let path = Path::new(models_dir).join(REGISTRY_FILE);
+    match std::fs::read_to_string(&path) {
+        Ok(json) => serde_json::from_str(&json)
+            .map_err(|e| err!(Io, "Invalid model registry {}: {e}", path.display())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(err!(Io, "Failed to read {}: {e}", path.display())),
+    }
+}
+
+fn write_registry(models_dir: &str, models: &[InstalledModel]) -> Result<()> {
+    std::fs::create_dir_all(models_dir)
+        .map_err(|e| err!(Io, "Failed to create {models_dir}: {e}"))?;
+    let path = Path::new(models_dir).join(REGISTRY_FILE);
+    // Write then rename so a crash never leaves a truncated registry.
+    let tmp = part_path(&path);
+    let json = serde_json::to_string_pretty(models)
+        .map_err(|e| err!(Io, "Failed to encode model registry: {e}"))?;
+    std::fs::write(&tmp, json)?;
+    std::fs::rename(&tmp, &path).map_err(|e| err!(Io, "Failed to write {}: {e}", path.display()))
+}
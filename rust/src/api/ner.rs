@@ -0,0 +1,232 @@
+use flutter_rust_bridge::frb;
+use ort::session::Session;
+use tokenizers::{Encoding, Tokenizer};
+
+use crate::api::calibration::softmax;
+use crate::api::classify::{id2label, model_logits};
+use crate::api::embeddings::WARMUP_TEXT;
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::ort::{
+    build_session_from_file_with_init, build_session_from_memory_with_init, session_model_info,
+    ModelInfo, OrtInitOptions,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedEntity {
+    /// Entity type with the `B-`/`I-` prefix removed, e.g. `PER`.
+    pub label: String,
+    pub text: String,
+    /// Character (Unicode scalar) offsets of the entity in the source text.
+    pub start: u32,
+    pub end: u32,
+    /// Mean probability of the entity's tokens.
+    pub score: f32,
+}
+
+/// Named-entity recognition with a token-classification model (BERT-NER,
+/// xlm-roberta-ner, ...). Tokens are labelled with the argmax of their
+/// logits and merged into entities: a `B-` label starts a new entity, an
+/// `I-` (or unprefixed) label of the same type extends it, and sub-word
+/// tokens always join the entity of their word's first token.
+#[frb(opaque)]
+pub struct NerTagger {
+    tokenizer: Tokenizer,
+    session: Session,
+    labels: Vec<String>,
+}
+
+#[frb(sync)]
+impl NerTagger {
+    /// `config_json` is the model's Hugging Face `config.json`, whose
+    /// `id2label` names the classes (`O`, `B-PER`, `I-PER`, ...).
+    pub fn create(
+        model_path: String,
+        tokenizer_path: String,
+        config_json: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_with_options(model_path, tokenizer_path, config_json, None)
+    }
+
+    pub fn create_with_options(
+        model_path: String,
+        tokenizer_path: String,
+        config_json: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let labels = id2label(&config_json)?;
+        let tokenizer =
+            Tokenizer::from_file(tokenizer_path).map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let session = build_session_from_file_with_init(model_path, ort_options)?;
+        Ok(Self {
+            tokenizer,
+            session,
+            labels,
+        })
+    }
+
+    /// `create` from in-memory model bytes and tokenizer JSON.
+    pub fn create_from_bytes(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        config_json: String,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_bytes_with_options(model_bytes, tokenizer_json, config_json, None)
+    }
+
+    pub fn create_from_bytes_with_options(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        config_json: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let labels = id2label(&config_json)?;
+        let tokenizer =
+            Tokenizer::from_bytes(tokenizer_json).map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let session = build_session_from_memory_with_init(&model_bytes, ort_options)?;
+        Ok(Self {
+            tokenizer,
+            session,
+            labels,
+        })
+    }
+
+    /// Inputs, outputs and metadata of the loaded ONNX model.
+    pub fn model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&self.session)
+    }
+
+    /// Class names by index, from `id2label`.
+    pub fn labels(&self) -> Vec<String> {
+        self.labels.clone()
+    }
+
+    /// Entities found in `text`, in text order.
+    pub fn extract(&mut self, text: String) -> Result<Vec<NamedEntity>, FlutterEmbedderError> {
+        Ok(self.extract_batch(vec![text])?.pop().unwrap_or_default())
+    }
+
+    /// `extract` for each of `texts`, run as one batch.
+    pub fn extract_batch(
+        &mut self,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<NamedEntity>>, FlutterEmbedderError> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let encodings = texts
+            .iter()
+            .map(|text| self.tokenizer.encode_char_offsets(text.as_str(), true))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| err!(Tokenization, "{e}"))?;
+        let (shape, logits) = model_logits(&mut self.session, &self.tokenizer, &encodings)?;
+        if shape.len() != 3 || shape[2] != self.labels.len() {
+            return Err(err!(
+                ShapeMismatch,
+                "Expected [batch, seq_len, {}] logits, got {shape:?}",
+                self.labels.len()
+            ));
+        }
+        let (seq_len, classes) = (shape[1], shape[2]);
+        Ok(texts
+            .iter()
+            .zip(&encodings)
+            .zip(logits.chunks(seq_len * classes))
+            .map(|((text, encoding), logits)| self.entities(text, encoding, logits, classes))
+            .collect())
+    }
+}
+
+impl NerTagger {
+    /// Tags one tiny input so the first real `extract` doesn't pay for graph
+    /// initialization.
+    pub fn warmup(&mut self) -> Result<(), FlutterEmbedderError> {
+        self.extract(WARMUP_TEXT.to_string())?;
+        Ok(())
+    }
+
+    /// Same as `extract_batch`, but not `sync` on the Dart side.
+    pub fn extract_batch_async(
+        &mut self,
+        texts: Vec<String>,
+    ) -> Result<Vec<Vec<NamedEntity>>, FlutterEmbedderError> {
+        self.extract_batch(texts)
+    }
+
+    fn entities(
+        &self,
+        text: &str,
+        encoding: &Encoding,
+        logits: &[f32],
+        classes: usize,
+    ) -> Vec<NamedEntity> {
+        let mut entities = Vec::new();
+        let mut current: Option<(NamedEntity, Vec<f32>)> = None;
+        let mut previous_word = None;
+        let tokens = encoding
+            .get_offsets()
+            .iter()
+            .zip(encoding.get_word_ids())
+            .zip(encoding.get_special_tokens_mask())
+            .zip(logits.chunks(classes));
+        for (((&(start, end), &word), &special), row) in tokens {
+            if special == 1 || start == end {
+                continue;
+            }
+            let probabilities = softmax(row, 1.0);
+            let (class, &probability) = probabilities
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(b.1))
+                .unwrap_or((0, &0.0));
+            let same_word = word.is_some() && word == previous_word;
+            previous_word = word;
+            let (prefix, kind) = split_label(&self.labels[class]);
+
+            if let Some((entity, scores)) = current.as_mut() {
+                let continues = same_word || (!matches!(prefix, "B" | "S") && kind == entity.label);
+                if continues {
+                    entity.end = end as u32;
+                    scores.push(probability);
+                    continue;
+                }
+            }
+            entities.extend(current.take().map(finish));
+            if kind.is_empty() || kind == "O" {
+                continue;
+            }
+            current = Some((
+                NamedEntity {
+                    label: kind.to_string(),
+                    text: String::new(),
+                    start: start as u32,
+                    end: end as u32,
+                    score: 0.0,
+                },
+                vec![probability],
+            ));
+        }
+        entities.extend(current.map(finish));
+        for entity in &mut entities {
+            entity.text = text
+                .chars()
+                .skip(entity.start as usize)
+                .take((entity.end - entity.start) as usize)
+                .collect();
+        }
+        entities
+    }
+}
+
+/// Splits `B-PER` into (`B`, `PER`); labels without a prefix (`O`, `PER`)
+/// have an empty one.
+fn split_label(label: &str) -> (&str, &str) {
+    match label.split_once('-') {
+        Some((prefix, kind)) if matches!(prefix, "B" | "I" | "E" | "S") => (prefix, kind),
+        _ => ("", label),
+    }
+}
+
+fn finish((mut entity, scores): (NamedEntity, Vec<f32>)) -> NamedEntity {
+    entity.score = scores.iter().sum::<f32>() / scores.len() as f32;
+    entity
+}
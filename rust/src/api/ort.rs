@@ -1,12 +1,38 @@
-use anyhow::{Ok, Result};
+use std::fs;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, TryLockError};
+
 use flutter_rust_bridge::frb;
 use ort::{
     environment::GlobalThreadPoolOptions,
-    session::{
-        builder::{GraphOptimizationLevel, SessionBuilder},
-        Session,
+    ep::{
+        coreml::{ComputeUnits, ModelFormat},
+        qnn::PerformanceMode,
+        CoreML, DirectML, ExecutionProvider, CPU, CUDA, NNAPI, QNN, XNNPACK,
     },
+    session::builder::{GraphOptimizationLevel, SessionBuilder},
+    tensor::{PrimitiveTensorElementType, TensorElementType},
+    value::{DynTensor, DynValue, Tensor, ValueType},
 };
+use sha2::{Digest, Sha256};
+
+// Re-exported so the generated bindings can name the opaque session type.
+pub use ort::session::Session;
+
+use crate::api::embeddings::pipeline::tensor_from_i64;
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::fp16::{cached_fp16_model, convert_onnx_to_fp16};
+use crate::api::logging::{ort_logger, LogLevel};
+use crate::api::models::to_hex;
+
+/// File name ending of cached optimized graphs.
+const OPTIMIZED_MODEL_SUFFIX: &str = ".opt.onnx";
+
+/// Warnings raised while building sessions (unavailable execution providers,
+/// fp16 fallbacks), drained by `take_session_warnings`.
+static SESSION_WARNINGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
 
 #[derive(Debug, Clone, Default)]
 pub struct OrtEnvironmentOptions {
@@ -17,6 +43,11 @@ pub struct OrtEnvironmentOptions {
     pub spin_control: Option<bool>,
     pub intra_affinity: Option<String>,
     pub telemetry: Option<bool>,
+    /// Least severe ONNX Runtime message to forward to `init_logging`
+    /// (default `Warn`). `Debug` and `Trace` include ORT's verbose output,
+    /// e.g. which nodes each execution provider took. Applies when the
+    /// environment is created.
+    pub log_level: Option<LogLevel>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -26,6 +57,144 @@ pub struct OrtSessionOptions {
     pub parallel_execution: Option<bool>,
     /// 0..=3 mapping to ORT graph optimization levels.
     pub optimization_level: Option<i64>,
+    /// Converts fp32 weights to fp16 on load and caches the converted model
+    /// (next to the source or in `fp16_cache_dir`). Meant for CoreML, NNAPI
+    /// and GPU providers; falls back to the original model if the conversion
+    /// or the fp16 session fails.
+    pub convert_to_fp16: Option<bool>,
+    pub fp16_cache_dir: Option<String>,
+    /// Providers to register, highest priority first. Providers that are not
+    /// supported or fail to register are skipped with a session warning; CPU
+    /// always remains as the final fallback.
+    pub execution_providers: Vec<ExecutionProviderConfig>,
+    /// Writes an ORT profiling trace (Chrome trace JSON) for every run to a
+    /// file starting with this path. The trace is finished when the embedder
+    /// is dropped.
+    pub profiling_path: Option<String>,
+    /// CPU memory arena (default on). The arena keeps freed buffers for
+    /// reuse, which is faster but holds on to the peak allocation; turn it
+    /// off on memory-constrained devices.
+    pub memory_arena: Option<bool>,
+    /// Memory pattern planning (default on): preallocates buffers from the
+    /// shapes of previous runs. Mostly helps fixed-shape inputs.
+    pub memory_pattern: Option<bool>,
+    /// Saves the optimized graph to this directory on first load and loads
+    /// it, without re-optimizing, on later launches. The cache is rebuilt
+    /// when the source model is newer; `clear_optimized_model_cache` empties
+    /// it.
+    pub optimized_model_cache_dir: Option<String>,
+    /// Bit-identical outputs for identical inputs across runs on the same
+    /// device: one intra-op thread unless `intra_threads` is set, sequential
+    /// execution, no thread spinning and ORT's deterministic kernels. Slower;
+    /// execution providers other than CPU may still be nondeterministic.
+    /// Embedders run every text on their own rather than in padded batches,
+    /// so a vector doesn't depend on what else is in the call.
+    pub deterministic: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExecutionProviderKind {
+    #[default]
+    Cpu,
+    CoreMl,
+    Nnapi,
+    DirectMl,
+    Cuda,
+    /// Qualcomm AI Engine (Snapdragon NPU/DSP).
+    Qnn,
+    /// Optimized CPU kernels for ARM and x86, mostly benefiting quantized
+    /// (INT8) models.
+    Xnnpack,
+}
+
+/// Hardware CoreML may schedule a model on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoreMlComputeUnits {
+    All,
+    CpuAndNeuralEngine,
+    CpuAndGpu,
+    CpuOnly,
+}
+
+/// Format CoreML compiles the model to. `MlProgram` needs iOS 15 / macOS 12
+/// and supports more operators; `NeuralNetwork` runs on older devices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoreMlModelFormat {
+    MlProgram,
+    NeuralNetwork,
+}
+
+/// Every provider `ExecutionProviderConfig` can register.
+const EXECUTION_PROVIDER_KINDS: [ExecutionProviderKind; 7] = [
+    ExecutionProviderKind::Cpu,
+    ExecutionProviderKind::CoreMl,
+    ExecutionProviderKind::Nnapi,
+    ExecutionProviderKind::DirectMl,
+    ExecutionProviderKind::Cuda,
+    ExecutionProviderKind::Qnn,
+    ExecutionProviderKind::Xnnpack,
+];
+
+/// Power/performance profile of the QNN HTP (Hexagon) backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QnnPerformanceMode {
+    Default,
+    Burst,
+    Balanced,
+    HighPerformance,
+    HighPowerSaver,
+    LowPowerSaver,
+    LowBalanced,
+    PowerSaver,
+    ExtremePowerSaver,
+    SustainedHighPerformance,
+}
+
+/// One provider to register. Provider-specific fields are ignored by the
+/// other providers; `None` keeps the ORT default.
+#[derive(Debug, Clone, Default)]
+pub struct ExecutionProviderConfig {
+    pub kind: ExecutionProviderKind,
+    /// GPU ordinal for CUDA and DirectML.
+    pub device_id: Option<i32>,
+    /// CUDA: cap on the bytes ORT's device arena may allocate, leaving room
+    /// for other GPU users (default: no limit).
+    pub cuda_memory_limit: Option<i64>,
+    /// CoreML: restrict to e.g. CPU + Neural Engine (default: all units).
+    pub coreml_compute_units: Option<CoreMlComputeUnits>,
+    pub coreml_model_format: Option<CoreMlModelFormat>,
+    /// CoreML: only offload subgraphs whose input shapes are static. The
+    /// Neural Engine needs fixed shapes, so pair this with fixed-length
+    /// padding.
+    pub coreml_static_input_shapes: Option<bool>,
+    /// NNAPI: allow fp16 relaxation, faster on most NPUs at a small cost in
+    /// precision.
+    pub nnapi_fp16: Option<bool>,
+    /// NNAPI: keep NNAPI off its own CPU implementation so unsupported
+    /// operators run on ORT's faster CPU kernels instead (Android 10+).
+    pub nnapi_disable_cpu: Option<bool>,
+    /// QNN: backend library, e.g. `libQnnHtp.so` for the NPU or
+    /// `libQnnCpu.so`.
+    pub qnn_backend_path: Option<String>,
+    pub qnn_performance_mode: Option<QnnPerformanceMode>,
+    /// XNNPACK: size of its own thread pool. XNNPACK does not share the
+    /// session's intra-op pool, so leave `intra_threads` at 1 to avoid
+    /// oversubscribing the cores.
+    pub xnnpack_threads: Option<i64>,
+}
+
+/// What the current device and the loaded ONNX Runtime can run.
+#[derive(Debug, Clone)]
+pub struct DeviceCapabilities {
+    /// `android`, `ios`, `macos`, `windows` or `linux`.
+    pub os: String,
+    /// `aarch64`, `x86_64`, ...
+    pub arch: String,
+    /// Logical cores available to the process.
+    pub cpu_cores: u32,
+    /// Providers supported on this platform and present in the loaded ONNX
+    /// Runtime build. Always includes `Cpu`.
+    pub execution_providers: Vec<ExecutionProviderKind>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -34,24 +203,549 @@ pub struct OrtInitOptions {
     pub session: Option<OrtSessionOptions>,
 }
 
+/// One graph input or output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TensorInfo {
+    pub name: String,
+    /// Element type for tensors (e.g. `f32`, `i64`), otherwise the value
+    /// type (sequence, map).
+    pub dtype: String,
+    /// Declared dimensions, `-1` where dynamic. Empty for non-tensors.
+    pub shape: Vec<i64>,
+}
+
+/// What an ONNX model declares, for checking it against what an embedder
+/// expects.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelInfo {
+    pub inputs: Vec<TensorInfo>,
+    pub outputs: Vec<TensorInfo>,
+    pub producer: Option<String>,
+    pub graph_name: Option<String>,
+    pub description: Option<String>,
+    pub version: Option<i64>,
+    /// Opset of the default `ai.onnx` domain; `None` when the ORT build
+    /// cannot report it.
+    pub opset: Option<u32>,
+}
+
+/// Kind of call an embedder is serving, used to route it to a session built
+/// for that workload (e.g. an accelerated session for interactive queries and
+/// a low-thread CPU session for bulk indexing).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Workload {
+    Interactive,
+    Bulk,
+}
+
+/// Element type of a `NamedTensor`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TensorDtype {
+    #[default]
+    Float32,
+    Int64,
+    Uint8,
+}
+
+/// A named tensor passed to or returned from `OrtSessionHandle::run`. Only
+/// the buffer matching `dtype` is read or filled; the others stay empty.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NamedTensor {
+    pub name: String,
+    pub dtype: TensorDtype,
+    pub shape: Vec<i64>,
+    pub f32_data: Vec<f32>,
+    pub i64_data: Vec<i64>,
+    pub u8_data: Vec<u8>,
+}
+
+/// Interchangeable sessions for one model, so concurrent callers don't
+/// serialize on a single `Session::run`. Each session owns its own copy of
+/// the weights.
+pub(crate) struct SessionPool {
+    sessions: Vec<Mutex<Session>>,
+    next: AtomicUsize,
+}
+
+impl SessionPool {
+    pub(crate) fn new(session: Session) -> Self {
+        Self {
+            sessions: vec![Mutex::new(session)],
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// Takes an idle session, or waits on one when all are busy. Fails once
+    /// the pool is closed.
+    pub(crate) fn acquire(&self) -> Result<MutexGuard<'_, Session>> {
+        let len = self.sessions.len();
+        if len == 0 {
+            return Err(closed_error());
+        }
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        for i in 0..len {
+            match self.sessions[(start + i) % len].try_lock() {
+                Ok(session) => return Ok(session),
+                Err(TryLockError::Poisoned(e)) => return Ok(e.into_inner()),
+                Err(TryLockError::WouldBlock) => {}
+            }
+        }
+        Ok(self.sessions[start % len]
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// Drops every session, freeing the model weights.
+    pub(crate) fn close(&mut self) {
+        self.sessions.clear();
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        self.sessions.is_empty()
+    }
+
+    /// Grows the pool with sessions from `build`, or drops the most recently
+    /// added ones. The first session is always kept.
+    fn resize(&mut self, size: usize, mut build: impl FnMut() -> Result<Session>) -> Result<()> {
+        if self.is_closed() {
+            return Err(closed_error());
+        }
+        let size = size.max(1);
+        self.sessions.truncate(size);
+        while self.sessions.len() < size {
+            self.sessions.push(Mutex::new(build()?));
+        }
+        Ok(())
+    }
+}
+
+/// The sessions of one embedder: the primary pool plus optional pools
+/// registered for specific workloads. `acquire` hands out a session of the
+/// pool registered for a workload, falling back to the primary pool.
+pub(crate) struct SessionRouter {
+    primary: SessionPool,
+    routes: Vec<(Workload, SessionPool)>,
+}
+
+impl SessionRouter {
+    pub(crate) fn new(primary: Session) -> Self {
+        Self {
+            primary: SessionPool::new(primary),
+            routes: Vec::new(),
+        }
+    }
+
+    pub(crate) fn set_route(&mut self, workload: Workload, session: Session) -> Result<()> {
+        if self.is_closed() {
+            return Err(closed_error());
+        }
+        self.routes.retain(|(w, _)| *w != workload);
+        self.routes.push((workload, SessionPool::new(session)));
+        Ok(())
+    }
+
+    pub(crate) fn remove_route(&mut self, workload: Workload) -> bool {
+        let before = self.routes.len();
+        self.routes.retain(|(w, _)| *w != workload);
+        self.routes.len() != before
+    }
+
+    /// One workload per distinct pool: every routed workload, plus one that
+    /// falls back to the primary pool if any does.
+    pub(crate) fn session_workloads(&self) -> Vec<Workload> {
+        let mut workloads: Vec<Workload> = self.routes.iter().map(|(w, _)| *w).collect();
+        if let Some(fallback) = [Workload::Interactive, Workload::Bulk]
+            .into_iter()
+            .find(|w| !workloads.contains(w))
+        {
+            workloads.push(fallback);
+        }
+        workloads
+    }
+
+    /// A session for `workload`, held until the guard is dropped.
+    pub(crate) fn acquire(&self, workload: Workload) -> Result<MutexGuard<'_, Session>> {
+        self.pool(workload).acquire()
+    }
+
+    /// Drops every session of every workload.
+    pub(crate) fn close(&mut self) {
+        self.primary.close();
+        self.routes.clear();
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        self.primary.is_closed()
+    }
+
+    /// Number of sessions serving `workload`.
+    pub(crate) fn pool_size(&self, workload: Workload) -> usize {
+        self.pool(workload).sessions.len()
+    }
+
+    /// Resizes the pool serving `workload` (the primary pool when the
+    /// workload isn't routed), building new sessions with `build`.
+    pub(crate) fn resize_pool(
+        &mut self,
+        workload: Workload,
+        size: usize,
+        build: impl FnMut() -> Result<Session>,
+    ) -> Result<()> {
+        match self.routes.iter_mut().find(|(w, _)| *w == workload) {
+            Some((_, pool)) => pool.resize(size, build),
+            None => self.primary.resize(size, build),
+        }
+    }
+
+    fn pool(&self, workload: Workload) -> &SessionPool {
+        self.routes
+            .iter()
+            .find(|(w, _)| *w == workload)
+            .map(|(_, pool)| pool)
+            .unwrap_or(&self.primary)
+    }
+}
+
 #[frb(sync)]
-pub fn init_ort(name: String, path: Option<String>) -> Result<bool> {
-    let res = match path {
-        Some(p) => ort::init_from(p)?.with_name(name).commit(),
-        None => ort::init().with_name(name).commit(),
+pub fn init_ort(name: String, path: Option<String>) -> Result<bool, FlutterEmbedderError> {
+    let builder = match path {
+        Some(p) => ort::init_from(p)?,
+        None => ort::init(),
     };
+    let res = builder
+        .with_name(name)
+        .with_logger(ort_logger(LogLevel::Warn))
+        .commit();
     return Ok(res);
 }
 
 #[frb(sync)]
-pub fn init_ort_with_options(options: OrtEnvironmentOptions) -> Result<bool> {
+pub fn init_ort_with_options(options: OrtEnvironmentOptions) -> Result<bool, FlutterEmbedderError> {
     init_ort_from_options(&options)
 }
 
+/// Returns and clears the warnings collected while building sessions, e.g. an
+/// execution provider that was requested but fell back to CPU.
+#[frb(sync)]
+pub fn take_session_warnings() -> Vec<String> {
+    let mut warnings = SESSION_WARNINGS.lock().unwrap_or_else(|e| e.into_inner());
+    std::mem::take(&mut *warnings)
+}
+
+/// ONNX Runtime names (e.g. `CoreMLExecutionProvider`) of the providers in
+/// `device_capabilities`. Loads ONNX Runtime, so call it after `init_ort`.
+#[frb(sync)]
+pub fn available_execution_providers() -> Vec<String> {
+    usable_execution_providers()
+        .into_iter()
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+/// Platform, core count and usable execution providers, so callers can pick
+/// providers up front instead of relying on registration fallbacks. A listed
+/// provider can still skip nodes, or a whole model, the hardware cannot run.
+#[frb(sync)]
+pub fn device_capabilities() -> DeviceCapabilities {
+    DeviceCapabilities {
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        cpu_cores: std::thread::available_parallelism().map_or(1, |n| n.get() as u32),
+        execution_providers: usable_execution_providers()
+            .into_iter()
+            .map(|(kind, _)| kind)
+            .collect(),
+    }
+}
+
+pub(crate) fn push_session_warning(warning: String) {
+    tracing::warn!("{warning}");
+    SESSION_WARNINGS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(warning);
+}
+
+/// Loads `model_path` on the CPU and reports its inputs, outputs and
+/// metadata without creating an embedder.
+#[frb(sync)]
+pub fn inspect_onnx_model(model_path: String) -> Result<ModelInfo, FlutterEmbedderError> {
+    let session = build_session_from_file(model_path, None)?;
+    session_model_info(&session)
+}
+
+/// Whether `options` ask for deterministic sessions, which the embedding
+/// pipeline matches by running every text on its own.
+pub(crate) fn is_deterministic(options: Option<&OrtInitOptions>) -> bool {
+    options
+        .and_then(|o| o.session.as_ref())
+        .and_then(|s| s.deterministic)
+        .unwrap_or(false)
+}
+
+/// Error returned by calls on an embedder after `close`.
+pub(crate) fn closed_error() -> FlutterEmbedderError {
+    err!(Closed, "Embedder is closed")
+}
+
+pub(crate) fn session_model_info(session: &Session) -> Result<ModelInfo> {
+    let tensor_info = |name: &str, dtype: &ValueType| match dtype {
+        ValueType::Tensor { ty, shape, .. } => TensorInfo {
+            name: name.to_string(),
+            dtype: ty.to_string(),
+            shape: shape.to_vec(),
+        },
+        other => TensorInfo {
+            name: name.to_string(),
+            dtype: other.to_string(),
+            shape: Vec::new(),
+        },
+    };
+    let metadata = session.metadata()?;
+    Ok(ModelInfo {
+        inputs: session
+            .inputs()
+            .iter()
+            .map(|input| tensor_info(input.name(), input.dtype()))
+            .collect(),
+        outputs: session
+            .outputs()
+            .iter()
+            .map(|output| tensor_info(output.name(), output.dtype()))
+            .collect(),
+        producer: metadata.producer(),
+        graph_name: metadata.name(),
+        description: metadata.description(),
+        version: metadata.version(),
+        opset: session.opset_for_domain("").ok(),
+    })
+}
+
+/// A session on an arbitrary ONNX model, for custom models none of the
+/// embedders cover. Built with the same session options, execution
+/// providers and caching as the embedders.
+#[frb(opaque)]
+pub struct OrtSessionHandle {
+    session: SessionPool,
+}
+
+#[frb(sync)]
+impl OrtSessionHandle {
+    pub fn create(
+        model_path: String,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let session = build_session_from_file_with_init(model_path, ort_options)?;
+        Ok(Self {
+            session: SessionPool::new(session),
+        })
+    }
+
+    /// `create` for a model held in memory; nothing is written to disk.
+    pub fn create_from_bytes(
+        model_bytes: Vec<u8>,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let session = build_session_from_memory_with_init(&model_bytes, ort_options)?;
+        Ok(Self {
+            session: SessionPool::new(session),
+        })
+    }
+
+    pub fn model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&*self.acquire()?)
+    }
+
+    /// Frees the session now rather than when the Dart object is garbage
+    /// collected. Later calls fail with `ErrorKind::Closed`.
+    pub fn close(&mut self) {
+        self.session.close()
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.session.is_closed()
+    }
+}
+
+impl OrtSessionHandle {
+    /// Runs the model on `inputs`, each converted to the element type the
+    /// model declares for it (e.g. `Int64` data to an `int32` input, `Float32`
+    /// data to an `fp16` one). Returns every output in the model's order, or
+    /// only `output_names` when given. Float outputs come back as `Float32`,
+    /// `uint8` ones as `Uint8` and other integer and bool outputs as `Int64`.
+    pub fn run(
+        &self,
+        inputs: Vec<NamedTensor>,
+        output_names: Option<Vec<String>>,
+    ) -> Result<Vec<NamedTensor>, FlutterEmbedderError> {
+        let mut session = self.acquire()?;
+        let mut values = Vec::with_capacity(inputs.len());
+        for input in &inputs {
+            let declared = session
+                .inputs()
+                .iter()
+                .find(|i| i.name() == input.name)
+                .ok_or_else(|| err!(InvalidInput, "Model has no input named {}", input.name))?;
+            values.push((input.name.clone(), input_tensor(declared.dtype(), input)?));
+        }
+        let names: Vec<String> = match output_names {
+            Some(names) => names,
+            None => session
+                .outputs()
+                .iter()
+                .map(|o| o.name().to_string())
+                .collect(),
+        };
+        let outputs = session.run(values)?;
+        names
+            .iter()
+            .map(|name| {
+                let value = outputs
+                    .get(name.as_str())
+                    .ok_or_else(|| err!(MissingOutput, "Model has no output named {name}"))?;
+                output_tensor(name, value)
+            })
+            .collect()
+    }
+
+    fn acquire(&self) -> Result<MutexGuard<'_, Session>> {
+        if self.session.is_closed() {
+            return Err(err!(Closed, "Session is closed"));
+        }
+        self.session.acquire()
+    }
+}
+
+fn input_tensor(dtype: &ValueType, input: &NamedTensor) -> Result<DynTensor> {
+    let shape = input
+        .shape
+        .iter()
+        .map(|&d| usize::try_from(d))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|_| err!(InvalidInput, "Negative dimension in input {}", input.name))?;
+    match input.dtype {
+        TensorDtype::Int64 => tensor_from_i64(dtype, &shape, &input.i64_data),
+        TensorDtype::Uint8 => {
+            let data: Vec<i64> = input.u8_data.iter().map(|&v| v as i64).collect();
+            tensor_from_i64(dtype, &shape, &data)
+        }
+        TensorDtype::Float32 => tensor_from_f32(dtype, &shape, &input.f32_data),
+    }
+}
+
+/// `tensor_from_i64` for float data, which only converts to float inputs.
+fn tensor_from_f32(dtype: &ValueType, shape: &[usize], data: &[f32]) -> Result<DynTensor> {
+    let ValueType::Tensor { ty, .. } = dtype else {
+        return Err(err!(UnsupportedDtype, "Unsupported input type: {dtype:?}"));
+    };
+    let expected: usize = shape.iter().product();
+    if expected != data.len() {
+        return Err(err!(
+            ShapeMismatch,
+            "Input data length mismatch: expected {expected}, got {}",
+            data.len()
+        ));
+    }
+    let shape = shape.to_vec();
+    match ty {
+        TensorElementType::Float32 => Ok(Tensor::from_array((shape, data.to_vec()))?.upcast()),
+        TensorElementType::Float64 => Ok(Tensor::from_array((
+            shape,
+            data.iter().map(|&v| v as f64).collect::<Vec<f64>>(),
+        ))?
+        .upcast()),
+        TensorElementType::Float16 => Ok(Tensor::from_array((
+            shape,
+            data.iter()
+                .map(|&v| half::f16::from_f32(v))
+                .collect::<Vec<half::f16>>(),
+        ))?
+        .upcast()),
+        TensorElementType::Bfloat16 => Ok(Tensor::from_array((
+            shape,
+            data.iter()
+                .map(|&v| half::bf16::from_f32(v))
+                .collect::<Vec<half::bf16>>(),
+        ))?
+        .upcast()),
+        _ => Err(err!(
+            UnsupportedDtype,
+            "Float data given for a {ty:?} input"
+        )),
+    }
+}
+
+fn output_tensor(name: &str, value: &DynValue) -> Result<NamedTensor> {
+    let ValueType::Tensor { ty, .. } = value.dtype() else {
+        return Err(err!(
+            UnsupportedDtype,
+            "Output {name} is not a tensor: {:?}",
+            value.dtype()
+        ));
+    };
+    let mut tensor = NamedTensor {
+        name: name.to_string(),
+        ..Default::default()
+    };
+    (tensor.dtype, tensor.shape) = match ty {
+        TensorElementType::Float32 => extract(value, &mut tensor.f32_data, |v: f32| v)?,
+        TensorElementType::Float64 => extract(value, &mut tensor.f32_data, |v: f64| v as f32)?,
+        TensorElementType::Float16 => extract(value, &mut tensor.f32_data, half::f16::to_f32)?,
+        TensorElementType::Bfloat16 => extract(value, &mut tensor.f32_data, half::bf16::to_f32)?,
+        TensorElementType::Uint8 => extract(value, &mut tensor.u8_data, |v: u8| v)?,
+        TensorElementType::Int64 => extract(value, &mut tensor.i64_data, |v: i64| v)?,
+        TensorElementType::Int32 => extract(value, &mut tensor.i64_data, |v: i32| v as i64)?,
+        TensorElementType::Int16 => extract(value, &mut tensor.i64_data, |v: i16| v as i64)?,
+        TensorElementType::Int8 => extract(value, &mut tensor.i64_data, |v: i8| v as i64)?,
+        TensorElementType::Uint32 => extract(value, &mut tensor.i64_data, |v: u32| v as i64)?,
+        TensorElementType::Uint16 => extract(value, &mut tensor.i64_data, |v: u16| v as i64)?,
+        TensorElementType::Uint64 => extract(value, &mut tensor.i64_data, |v: u64| v as i64)?,
+        TensorElementType::Bool => extract(value, &mut tensor.i64_data, |v: bool| v as i64)?,
+        _ => {
+            return Err(err!(
+                UnsupportedDtype,
+                "Unsupported element type {ty:?} for output {name}"
+            ))
+        }
+    };
+    Ok(tensor)
+}
+
+/// Copies `value` into `data`, converted with `convert`, and returns the
+/// resulting dtype and shape.
+fn extract<T: PrimitiveTensorElementType + Copy, U: OutputElement>(
+    value: &DynValue,
+    data: &mut Vec<U>,
+    convert: impl Fn(T) -> U,
+) -> Result<(TensorDtype, Vec<i64>)> {
+    let (shape, values) = value.try_extract_tensor::<T>()?;
+    *data = values.iter().map(|&v| convert(v)).collect();
+    Ok((U::DTYPE, shape.to_vec()))
+}
+
+trait OutputElement {
+    const DTYPE: TensorDtype;
+}
+
+#[frb(ignore)]
+impl OutputElement for f32 {
+    const DTYPE: TensorDtype = TensorDtype::Float32;
+}
+
+#[frb(ignore)]
+impl OutputElement for i64 {
+    const DTYPE: TensorDtype = TensorDtype::Int64;
+}
+
+#[frb(ignore)]
+impl OutputElement for u8 {
+    const DTYPE: TensorDtype = TensorDtype::Uint8;
+}
+
 pub fn build_session_from_file_with_init(
     model_path: String,
     ort_options: Option<OrtInitOptions>,
-) -> Result<Session> {
+) -> Result<Session, FlutterEmbedderError> {
     if let Some(options) = ort_options {
         if let Some(env) = options.environment {
             init_ort_from_options(&env)?;
@@ -64,12 +758,209 @@ pub fn build_session_from_file_with_init(
 pub fn build_session_from_file(
     model_path: String,
     session_options: Option<OrtSessionOptions>,
+) -> Result<Session, FlutterEmbedderError> {
+    if !Path::new(&model_path).is_file() {
+        return Err(err!(ModelLoad, "Model file not found: {model_path}"));
+    }
+    let convert_to_fp16 = session_options
+        .as_ref()
+        .and_then(|o| o.convert_to_fp16)
+        .unwrap_or(false);
+    if convert_to_fp16 {
+        let cache_dir = session_options
+            .as_ref()
+            .and_then(|o| o.fp16_cache_dir.as_deref());
+        let fp16_session = cached_fp16_model(&model_path, cache_dir).and_then(|fp16_path| {
+            let builder = apply_session_options(Session::builder()?, session_options.clone())?;
+            commit_from_file(builder, &fp16_path, session_options.as_ref())
+        });
+        match fp16_session {
+            Result::Ok(session) => {
+                log_session_created(&session, &model_path);
+                return Ok(session);
+            }
+            Err(e) => push_session_warning(format!(
+                "fp16 model unavailable ({e}); using the original model"
+            )),
+        }
+    }
+
+    let builder = Session::builder()?;
+    let builder = apply_session_options(builder, session_options.clone())?;
+    let session = commit_from_file(builder, &model_path, session_options.as_ref())?;
+    log_session_created(&session, &model_path);
+    Ok(session)
+}
+
+/// Deletes the optimized graphs saved in `cache_dir` (see
+/// `OrtSessionOptions::optimized_model_cache_dir`) and returns how many were
+/// removed. Other files in the directory are left alone.
+#[frb(sync)]
+pub fn clear_optimized_model_cache(cache_dir: String) -> Result<u32, FlutterEmbedderError> {
+    let entries = match fs::read_dir(&cache_dir) {
+        Result::Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(err!(Io, "Failed to read {cache_dir}: {e}")),
+    };
+    let mut removed = 0;
+    for entry in entries {
+        let path = entry?.path();
+        let is_cache = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.ends_with(OPTIMIZED_MODEL_SUFFIX));
+        if is_cache {
+            fs::remove_file(&path)?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+/// Commits `builder` on the model at `path`, going through the optimized
+/// graph cache when `options` ask for it.
+fn commit_from_file(
+    builder: SessionBuilder,
+    path: &str,
+    options: Option<&OrtSessionOptions>,
+) -> Result<Session> {
+    let Some(cache_dir) = options.and_then(|o| o.optimized_model_cache_dir.as_deref()) else {
+        return commit_model(builder, path);
+    };
+
+    let cached = optimized_model_path(path, cache_dir, options)?;
+    let source_modified = fs::metadata(path)?.modified()?;
+    let is_fresh = fs::metadata(&cached)
+        .and_then(|m| m.modified())
+        .map(|modified| modified >= source_modified)
+        .unwrap_or(false);
+    let cached_path = cached.to_string_lossy().to_string();
+    if is_fresh {
+        // Already optimized; running the optimizers again would only cost
+        // load time.
+        let cached_builder = builder
+            .clone()
+            .with_optimization_level(GraphOptimizationLevel::Disable)?;
+        match commit_model(cached_builder, &cached_path) {
+            Result::Ok(session) => return Ok(session),
+            Err(e) => {
+                push_session_warning(format!(
+                    "Optimized model cache unusable ({e}); rebuilding it"
+                ));
+                let _ = fs::remove_file(&cached);
+            }
+        }
+    }
+
+    fs::create_dir_all(cache_dir)?;
+    let saving_builder = builder.clone().with_optimized_model_path(&cached)?;
+    match commit_model(saving_builder, path) {
+        Result::Ok(session) => Ok(session),
+        Err(e) => {
+            // E.g. models over the 2 GB protobuf limit can't be saved.
+            push_session_warning(format!("Failed to save the optimized model ({e})"));
+            let _ = fs::remove_file(&cached);
+            commit_model(builder, path)
+        }
+    }
+}
+
+/// Cache file for the optimized graph of `model_path`. The name includes a
+/// hash of the source path, the optimization level and the providers, since
+/// the optimized graph depends on all three.
+fn optimized_model_path(
+    model_path: &str,
+    cache_dir: &str,
+    options: Option<&OrtSessionOptions>,
+) -> Result<PathBuf> {
+    let source = Path::new(model_path);
+    let stem = source
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .ok_or(err!(ModelLoad, "Invalid model path: {model_path}"))?;
+    let absolute = fs::canonicalize(source)?;
+    let level = options.and_then(|o| o.optimization_level).unwrap_or(3);
+    let providers: Vec<ExecutionProviderKind> = options
+        .map(|o| o.execution_providers.iter().map(|p| p.kind).collect())
+        .unwrap_or_default();
+    let key = Sha256::digest(format!("{}|{level}|{providers:?}", absolute.display()));
+    Ok(Path::new(cache_dir).join(format!(
+        "{stem}.{}{OPTIMIZED_MODEL_SUFFIX}",
+        to_hex(&key[..8])
+    )))
+}
+
+fn commit_model(builder: SessionBuilder, path: &str) -> Result<Session> {
+    builder.commit_from_file(path).map_err(model_load_error)
+}
+
+/// ORT errors while building a session mean the model itself is unusable,
+/// unlike the `OrtRuntime` errors of `Session::run`.
+fn model_load_error(e: ort::Error) -> FlutterEmbedderError {
+    err!(ModelLoad, "Failed to load the model: {e}")
+}
+
+/// `build_session_from_file_with_init` for a model held in memory, e.g. one
+/// decrypted from an app asset. Nothing is written to disk.
+pub(crate) fn build_session_from_memory_with_init(
+    model_bytes: &[u8],
+    ort_options: Option<OrtInitOptions>,
+) -> Result<Session> {
+    if let Some(options) = ort_options {
+        if let Some(env) = options.environment {
+            init_ort_from_options(&env)?;
+        }
+        return build_session_from_memory(model_bytes, options.session);
+    }
+    build_session_from_memory(model_bytes, None)
+}
+
+/// In-memory counterpart of `build_session_from_file`. The fp16 conversion
+/// runs on every call since there is no file to cache it next to.
+pub(crate) fn build_session_from_memory(
+    model_bytes: &[u8],
+    session_options: Option<OrtSessionOptions>,
 ) -> Result<Session> {
+    let convert_to_fp16 = session_options
+        .as_ref()
+        .and_then(|o| o.convert_to_fp16)
+        .unwrap_or(false);
+    if convert_to_fp16 {
+        let fp16_session = convert_onnx_to_fp16(model_bytes).and_then(|fp16_bytes| {
+            let builder = apply_session_options(Session::builder()?, session_options.clone())?;
+            builder
+                .commit_from_memory(&fp16_bytes)
+                .map_err(model_load_error)
+        });
+        match fp16_session {
+            Result::Ok(session) => {
+                log_session_created(&session, "<memory>");
+                return Ok(session);
+            }
+            Err(e) => push_session_warning(format!(
+                "fp16 model unavailable ({e}); using the original model"
+            )),
+        }
+    }
+
     let builder = Session::builder()?;
     let builder = apply_session_options(builder, session_options)?;
-    Ok(builder.commit_from_file(model_path)?)
+    let session = builder
+        .commit_from_memory(model_bytes)
+        .map_err(model_load_error)?;
+    log_session_created(&session, "<memory>");
+    Ok(session)
 }
- 
+
+fn log_session_created(session: &Session, model: &str) {
+    tracing::debug!(
+        model,
+        inputs = ?session.inputs().iter().map(|i| i.name()).collect::<Vec<_>>(),
+        outputs = ?session.outputs().iter().map(|o| o.name()).collect::<Vec<_>>(),
+        "Session created"
+    );
+}
+
 fn init_ort_from_options(options: &OrtEnvironmentOptions) -> Result<bool> {
     let mut builder = match &options.dylib_path {
         Some(path) => ort::init_from(path)?,
@@ -84,6 +975,7 @@ fn init_ort_from_options(options: &OrtEnvironmentOptions) -> Result<bool> {
     if let Some(telemetry) = options.telemetry {
         builder = builder.with_telemetry(telemetry);
     }
+    builder = builder.with_logger(ort_logger(options.log_level.unwrap_or(LogLevel::Warn)));
 
     if has_thread_options(options) {
         let mut thread_options = GlobalThreadPoolOptions::default();
@@ -115,6 +1007,11 @@ fn apply_session_options(
     let mut intra_threads = Some(1usize);
     let mut inter_threads = None;
     let mut parallel_execution = None;
+    let mut execution_providers = Vec::new();
+    let mut profiling_path = None;
+    let mut memory_arena = None;
+    let mut memory_pattern = None;
+    let mut deterministic = false;
 
     if let Some(opts) = options {
         if let Some(level) = opts.optimization_level {
@@ -130,6 +1027,15 @@ fn apply_session_options(
         }
         inter_threads = to_positive_usize(opts.inter_threads);
         parallel_execution = opts.parallel_execution;
+        execution_providers = opts.execution_providers;
+        profiling_path = opts.profiling_path;
+        memory_arena = opts.memory_arena;
+        memory_pattern = opts.memory_pattern;
+        deterministic = opts.deterministic.unwrap_or(false);
+    }
+    if deterministic {
+        inter_threads = None;
+        parallel_execution = Some(false);
     }
 
     builder = builder.with_optimization_level(optimization_level)?;
@@ -145,10 +1051,175 @@ fn apply_session_options(
     if let Some(enable) = parallel_execution {
         builder = builder.with_parallel_execution(enable)?;
     }
+    if deterministic {
+        builder = builder
+            .with_intra_op_spinning(false)?
+            .with_inter_op_spinning(false)?
+            .with_deterministic_compute(true)?;
+    }
+    if let Some(path) = profiling_path {
+        builder = builder.with_profiling(path)?;
+    }
+    if let Some(enable) = memory_pattern {
+        builder = builder.with_memory_pattern(enable)?;
+    }
+    register_execution_providers(&mut builder, &execution_providers, memory_arena);
+    // ORT always falls back to its CPU provider; registering `CPU` only sets
+    // the session's arena flag. Without a CPU entry in the list, set it here.
+    let has_cpu = execution_providers
+        .iter()
+        .any(|config| config.kind == ExecutionProviderKind::Cpu);
+    if let (Some(enable), false) = (memory_arena, has_cpu) {
+        CPU::default()
+            .with_arena_allocator(enable)
+            .register(&mut builder)
+            .map_err(|e| err!(OrtRuntime, "Failed to configure the memory arena: {e}"))?;
+    }
 
     Ok(builder)
 }
 
+/// Registers `configs` in priority order. ORT assigns each node to the first
+/// registered provider that supports it, so anything left over runs on CPU.
+/// `memory_arena` applies to a `Cpu` entry.
+fn register_execution_providers(
+    builder: &mut SessionBuilder,
+    configs: &[ExecutionProviderConfig],
+    memory_arena: Option<bool>,
+) {
+    for config in configs {
+        let provider = execution_provider(config, memory_arena);
+        let name = provider.name();
+        if !provider.supported_by_platform() {
+            push_session_warning(format!(
+                "{name} is not supported on this platform; falling back to CPU"
+            ));
+            continue;
+        }
+        if !provider.is_available().unwrap_or(false) {
+            push_session_warning(format!(
+                "{name} is not available in the loaded ONNX Runtime; falling back to CPU"
+            ));
+            continue;
+        }
+        if let Err(e) = provider.register(builder) {
+            push_session_warning(format!(
+                "{name} failed to register ({e}); falling back to CPU"
+            ));
+        }
+    }
+}
+
+/// Providers supported on this platform and compiled into the loaded ONNX
+/// Runtime, with their ORT names.
+fn usable_execution_providers() -> Vec<(ExecutionProviderKind, &'static str)> {
+    EXECUTION_PROVIDER_KINDS
+        .into_iter()
+        .filter_map(|kind| {
+            let config = ExecutionProviderConfig {
+                kind,
+                ..Default::default()
+            };
+            let provider = execution_provider(&config, None);
+            let usable =
+                provider.supported_by_platform() && provider.is_available().unwrap_or(false);
+            usable.then(|| (kind, provider.name()))
+        })
+        .collect()
+}
+
+fn execution_provider(
+    config: &ExecutionProviderConfig,
+    memory_arena: Option<bool>,
+) -> Box<dyn ExecutionProvider> {
+    match config.kind {
+        // ORT enables the arena by default; `CPU::default()` would turn it off.
+        ExecutionProviderKind::Cpu => {
+            Box::new(CPU::default().with_arena_allocator(memory_arena.unwrap_or(true)))
+        }
+        ExecutionProviderKind::CoreMl => {
+            let mut provider = CoreML::default();
+            if let Some(units) = config.coreml_compute_units {
+                provider = provider.with_compute_units(match units {
+                    CoreMlComputeUnits::All => ComputeUnits::All,
+                    CoreMlComputeUnits::CpuAndNeuralEngine => ComputeUnits::CPUAndNeuralEngine,
+                    CoreMlComputeUnits::CpuAndGpu => ComputeUnits::CPUAndGPU,
+                    CoreMlComputeUnits::CpuOnly => ComputeUnits::CPUOnly,
+                });
+            }
+            if let Some(format) = config.coreml_model_format {
+                provider = provider.with_model_format(match format {
+                    CoreMlModelFormat::MlProgram => ModelFormat::MLProgram,
+                    CoreMlModelFormat::NeuralNetwork => ModelFormat::NeuralNetwork,
+                });
+            }
+            if let Some(enable) = config.coreml_static_input_shapes {
+                provider = provider.with_static_input_shapes(enable);
+            }
+            Box::new(provider)
+        }
+        ExecutionProviderKind::Nnapi => {
+            let mut provider = NNAPI::default();
+            if let Some(enable) = config.nnapi_fp16 {
+                provider = provider.with_fp16(enable);
+            }
+            if let Some(enable) = config.nnapi_disable_cpu {
+                provider = provider.with_disable_cpu(enable);
+            }
+            Box::new(provider)
+        }
+        ExecutionProviderKind::DirectMl => {
+            let mut provider = DirectML::default();
+            if let Some(id) = config.device_id {
+                provider = provider.with_device_id(id);
+            }
+            Box::new(provider)
+        }
+        ExecutionProviderKind::Cuda => {
+            let mut provider = CUDA::default();
+            if let Some(id) = config.device_id {
+                provider = provider.with_device_id(id);
+            }
+            if let Some(limit) = to_positive_usize(config.cuda_memory_limit) {
+                provider = provider.with_memory_limit(limit);
+            }
+            Box::new(provider)
+        }
+        ExecutionProviderKind::Qnn => {
+            let mut provider = QNN::default();
+            if let Some(path) = config.qnn_backend_path.as_ref() {
+                provider = provider.with_backend_path(path);
+            }
+            if let Some(mode) = config.qnn_performance_mode {
+                provider = provider.with_performance_mode(match mode {
+                    QnnPerformanceMode::Default => PerformanceMode::Default,
+                    QnnPerformanceMode::Burst => PerformanceMode::Burst,
+                    QnnPerformanceMode::Balanced => PerformanceMode::Balanced,
+                    QnnPerformanceMode::HighPerformance => PerformanceMode::HighPerformance,
+                    QnnPerformanceMode::HighPowerSaver => PerformanceMode::HighPowerSaver,
+                    QnnPerformanceMode::LowPowerSaver => PerformanceMode::LowPowerSaver,
+                    QnnPerformanceMode::LowBalanced => PerformanceMode::LowBalanced,
+                    QnnPerformanceMode::PowerSaver => PerformanceMode::PowerSaver,
+                    QnnPerformanceMode::ExtremePowerSaver => PerformanceMode::ExtremePowerSaver,
+                    QnnPerformanceMode::SustainedHighPerformance => {
+                        PerformanceMode::SustainedHighPerformance
+                    }
+                });
+            }
+            Box::new(provider)
+        }
+        ExecutionProviderKind::Xnnpack => {
+            let mut provider = XNNPACK::default();
+            if let Some(count) =
+                to_positive_usize(config.xnnpack_threads).and_then(NonZeroUsize::new)
+            {
+                provider = provider.with_intra_op_num_threads(count);
+            }
+            Box::new(provider)
+        }
+    }
+}
+
 fn has_thread_options(options: &OrtEnvironmentOptions) -> bool {
     options.inter_threads.is_some()
         || options.intra_threads.is_some()
@@ -0,0 +1,268 @@
+use ndarray::{Array1, Array2, Axis};
+
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::utils::embeddings_to_matrix;
+
+const DEFAULT_DIMS: usize = 2;
+const DEFAULT_ITERATIONS: usize = 200;
+const DEFAULT_NEIGHBORS: usize = 15;
+const NEGATIVE_SAMPLES: usize = 5;
+const POWER_ITERATIONS: usize = 100;
+
+#[derive(Debug, Clone, Default)]
+pub struct ProjectionOptions {
+    /// Output dimensionality, 2 or 3 (default 2).
+    pub dims: Option<u32>,
+    /// Layout refinement steps after the PCA initialisation (default 200, 0 = PCA only).
+    pub iterations: Option<u32>,
+    /// Neighbours per point in the layout graph (default 15).
+    pub neighbors: Option<u32>,
+    /// Number of cluster hints; defaults to roughly sqrt(n / 2).
+    pub clusters: Option<u32>,
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProjectionResult {
+    /// One `dims`-sized coordinate per input embedding, scaled to roughly [-1, 1].
+    pub coordinates: Vec<Vec<f32>>,
+    /// Cluster index per input embedding.
+    pub clusters: Vec<u32>,
+    pub cluster_count: u32,
+}
+
+/// Projects embeddings to 2D/3D for "map" style visualisations.
+///
+/// Coordinates are initialised with PCA and then refined with a UMAP-like
+/// neighbour-graph layout; cluster hints come from k-means on the layout.
+#[flutter_rust_bridge::frb]
+pub fn project_embeddings(
+    embeddings: Vec<Vec<f32>>,
+    options: Option<ProjectionOptions>,
+) -> Result<ProjectionResult, FlutterEmbedderError> {
+    let options = options.unwrap_or_default();
+    let data = embeddings_to_matrix(&embeddings)?;
+    let n = data.nrows();
+
+    let dims = options.dims.map(|d| d as usize).unwrap_or(DEFAULT_DIMS);
+    if !(2..=3).contains(&dims) {
+        return Err(err!(InvalidInput, "Projection dims must be 2 or 3"));
+    }
+    let iterations = options
+        .iterations
+        .map(|i| i as usize)
+        .unwrap_or(DEFAULT_ITERATIONS);
+    let neighbors = options
+        .neighbors
+        .map(|k| k as usize)
+        .unwrap_or(DEFAULT_NEIGHBORS)
+        .min(n.saturating_sub(1));
+    let cluster_count = options
+        .clusters
+        .map(|c| c as usize)
+        .unwrap_or_else(|| ((n as f32 / 2.0).sqrt().round() as usize).max(1))
+        .clamp(1, n);
+    let mut rng = SplitMix64::new(options.seed.unwrap_or(42));
+
+    let mut coords = pca(&data, dims, &mut rng);
+    rescale(&mut coords);
+    if iterations > 0 && neighbors > 0 {
+        let graph = knn_graph(&data, neighbors);
+        refine_layout(&mut coords, &graph, iterations, &mut rng);
+        rescale(&mut coords);
+    }
+    let clusters = kmeans(&coords, cluster_count, &mut rng);
+
+    Ok(ProjectionResult {
+        coordinates: coords.outer_iter().map(|row| row.to_vec()).collect(),
+        clusters,
+        cluster_count: cluster_count as u32,
+    })
+}
+
+/// Projects `data` onto its top `components` principal axes using power
+/// iteration with deflation on the covariance matrix.
+pub(crate) fn pca(data: &Array2<f32>, components: usize, rng: &mut SplitMix64) -> Array2<f32> {
+    let (n, dim) = data.dim();
+    let mean = data
+        .mean_axis(Axis(0))
+        .unwrap_or_else(|| Array1::zeros(dim));
+    let centered = data - &mean.insert_axis(Axis(0));
+    let mut covariance = centered.t().dot(&centered) / (n.max(2) - 1) as f32;
+
+    let mut axes = Array2::<f32>::zeros((dim, components));
+    for c in 0..components.min(dim) {
+        let mut v = Array1::from_iter((0..dim).map(|_| rng.next_f32() - 0.5));
+        for _ in 0..POWER_ITERATIONS {
+            let next = covariance.dot(&v);
+            let norm = next.dot(&next).sqrt();
+            if norm < 1e-12 {
+                break;
+            }
+            v = next / norm;
+        }
+        let eigenvalue = v.dot(&covariance.dot(&v));
+        let outer = v
+            .view()
+            .insert_axis(Axis(1))
+            .dot(&v.view().insert_axis(Axis(0)));
+        covariance = covariance - outer * eigenvalue;
+        axes.column_mut(c).assign(&v);
+    }
+    centered.dot(&axes)
+}
+
+fn rescale(coords: &mut Array2<f32>) {
+    let max = coords.iter().fold(0.0f32, |acc, v| acc.max(v.abs()));
+    if max > 1e-9 {
+        coords.mapv_inplace(|v| v / max);
+    }
+}
+
+fn knn_graph(data: &Array2<f32>, k: usize) -> Vec<Vec<usize>> {
+    let n = data.nrows();
+    let norms: Vec<f32> = data.outer_iter().map(|row| row.dot(&row)).collect();
+    let gram = data.dot(&data.t());
+    (0..n)
+        .map(|i| {
+            let mut distances: Vec<(usize, f32)> = (0..n)
+                .filter(|&j| j != i)
+                .map(|j| (j, norms[i] + norms[j] - 2.0 * gram[[i, j]]))
+                .collect();
+            distances.sort_by(|a, b| a.1.total_cmp(&b.1));
+            distances.into_iter().take(k).map(|(j, _)| j).collect()
+        })
+        .collect()
+}
+
+fn refine_layout(
+    coords: &mut Array2<f32>,
+    graph: &[Vec<usize>],
+    iterations: usize,
+    rng: &mut SplitMix64,
+) {
+    let (n, dims) = coords.dim();
+    for epoch in 0..iterations {
+        let alpha = 1.0 - epoch as f32 / iterations as f32;
+        for (i, neighbors) in graph.iter().enumerate() {
+            for &j in neighbors {
+                // Attract along the neighbour graph.
+                let dist_sq = squared_distance(coords, i, j);
+                let grad = -2.0 / (1.0 + dist_sq);
+                for d in 0..dims {
+                    let delta = (grad * (coords[[i, d]] - coords[[j, d]])).clamp(-4.0, 4.0);
+                    coords[[i, d]] += alpha * 0.1 * delta;
+                    coords[[j, d]] -= alpha * 0.1 * delta;
+                }
+                // Repel from random non-neighbours.
+                for _ in 0..NEGATIVE_SAMPLES {
+                    let m = rng.next_usize(n);
+                    if m == i {
+                        continue;
+                    }
+                    let dist_sq = squared_distance(coords, i, m);
+                    let grad = 2.0 / ((0.001 + dist_sq) * (1.0 + dist_sq));
+                    for d in 0..dims {
+                        let delta = (grad * (coords[[i, d]] - coords[[m, d]])).clamp(-4.0, 4.0);
+                        coords[[i, d]] += alpha * 0.01 * delta;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn squared_distance(coords: &Array2<f32>, a: usize, b: usize) -> f32 {
+    coords
+        .row(a)
+        .iter()
+        .zip(coords.row(b).iter())
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum()
+}
+
+fn kmeans(coords: &Array2<f32>, k: usize, rng: &mut SplitMix64) -> Vec<u32> {
+    let n = coords.nrows();
+    // k-means++ seeding.
+    let mut centroids = vec![coords.row(rng.next_usize(n)).to_owned()];
+    while centroids.len() < k {
+        let weights: Vec<f32> = coords
+            .outer_iter()
+            .map(|row| {
+                centroids
+                    .iter()
+                    .map(|c| (&row - c).mapv(|v| v * v).sum())
+                    .fold(f32::MAX, f32::min)
+            })
+            .collect();
+        let total: f32 = weights.iter().sum();
+        let mut target = rng.next_f32() * total;
+        let mut chosen = n - 1;
+        for (i, w) in weights.iter().enumerate() {
+            if target <= *w {
+                chosen = i;
+                break;
+            }
+            target -= w;
+        }
+        centroids.push(coords.row(chosen).to_owned());
+    }
+
+    let mut assignments = vec![0u32; n];
+    for _ in 0..50 {
+        let mut changed = false;
+        for (i, row) in coords.outer_iter().enumerate() {
+            let best = centroids
+                .iter()
+                .enumerate()
+                .map(|(c, centroid)| (c, (&row - centroid).mapv(|v| v * v).sum()))
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(c, _)| c as u32)
+                .unwrap_or(0);
+            if assignments[i] != best {
+                assignments[i] = best;
+                changed = true;
+            }
+        }
+        for (c, centroid) in centroids.iter_mut().enumerate() {
+            let members: Vec<usize> = (0..n).filter(|&i| assignments[i] == c as u32).collect();
+            if members.is_empty() {
+                continue;
+            }
+            let mut sum = Array1::<f32>::zeros(coords.ncols());
+            for &i in &members {
+                sum += &coords.row(i);
+            }
+            *centroid = sum / members.len() as f32;
+        }
+        if !changed {
+            break;
+        }
+    }
+    assignments
+}
+
+/// Small deterministic PRNG so projections are reproducible for a given seed.
+pub(crate) struct SplitMix64(u64);
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    pub(crate) fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    pub(crate) fn next_usize(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound.max(1) as u64) as usize
+    }
+}
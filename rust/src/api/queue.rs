@@ -0,0 +1,342 @@
+//! Background embedding jobs. Texts are submitted with a priority against an
+//! embedder loaded with `load_embedder` and embedded on worker threads, at
+//! most `set_queue_concurrency` jobs at a time, while Dart polls or watches
+//! the job status. The queue can be paused, e.g. while the app is in the
+//! background or the battery is low; running jobs finish, queued ones wait.
+
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Condvar, Mutex, MutexGuard, OnceLock};
+
+use flutter_rust_bridge::frb;
+
+use crate::api::embeddings::embedder_handle;
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::frb_generated::StreamSink;
+
+const DEFAULT_CONCURRENCY: usize = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobState {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobStatus {
+    pub job_id: u64,
+    pub state: JobState,
+    pub priority: i32,
+    pub text_count: u32,
+}
+
+struct Job {
+    embedder_id: u64,
+    priority: i32,
+    text_count: u32,
+    state: JobState,
+    /// Taken by the worker that runs the job.
+    texts: Vec<String>,
+    result: Option<Result<Vec<Vec<f32>>>>,
+}
+
+impl Job {
+    fn status(&self, job_id: u64) -> JobStatus {
+        JobStatus {
+            job_id,
+            state: self.state,
+            priority: self.priority,
+            text_count: self.text_count,
+        }
+    }
+}
+
+/// Heap entry: higher priority first, then submission order.
+#[derive(PartialEq, Eq)]
+struct Pending {
+    priority: i32,
+    job_id: u64,
+}
+
+impl Ord for Pending {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority
+            .cmp(&other.priority)
+            .then(other.job_id.cmp(&self.job_id))
+    }
+}
+
+impl PartialOrd for Pending {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+struct QueueState {
+    jobs: HashMap<u64, Job>,
+    /// May hold cancelled jobs; workers skip them.
+    pending: BinaryHeap<Pending>,
+    next_id: u64,
+    paused: bool,
+    running: usize,
+    max_concurrency: usize,
+    workers: usize,
+    watchers: Vec<StreamSink<JobStatus>>,
+}
+
+impl QueueState {
+    /// Sends the job's current status to every watcher, dropping those
+    /// whose Dart stream was closed.
+    fn notify(&mut self, job_id: u64) {
+        if let Some(status) = self.jobs.get(&job_id).map(|job| job.status(job_id)) {
+            self.watchers
+                .retain(|sink| sink.add(status.clone()).is_ok());
+        }
+    }
+
+    /// The next queued job if a worker may start it now.
+    fn next_job(&mut self) -> Option<(u64, u64, Vec<String>)> {
+        if self.paused || self.running >= self.max_concurrency {
+            return None;
+        }
+        while let Some(Pending { job_id, .. }) = self.pending.pop() {
+            let Some(job) = self.jobs.get_mut(&job_id) else {
+                continue;
+            };
+            if job.state != JobState::Queued {
+                continue;
+            }
+            job.state = JobState::Running;
+            let work = (job_id, job.embedder_id, std::mem::take(&mut job.texts));
+            self.running += 1;
+            self.notify(job_id);
+            return Some(work);
+        }
+        None
+    }
+}
+
+struct Queue {
+    state: Mutex<QueueState>,
+    /// Signalled when a job is queued, a slot frees up or the queue resumes.
+    wakeup: Condvar,
+}
+
+fn queue() -> &'static Queue {
+    static QUEUE: OnceLock<Queue> = OnceLock::new();
+    QUEUE.get_or_init(|| Queue {
+        state: Mutex::new(QueueState {
+            jobs: HashMap::new(),
+            pending: BinaryHeap::new(),
+            next_id: 1,
+            paused: false,
+            running: 0,
+            max_concurrency: DEFAULT_CONCURRENCY,
+            workers: 0,
+            watchers: Vec::new(),
+        }),
+        wakeup: Condvar::new(),
+    })
+}
+
+fn lock_state() -> Result<MutexGuard<'static, QueueState>> {
+    queue()
+        .state
+        .lock()
+        .map_err(|e| err!(Internal, "Failed to acquire job queue: {e}"))
+}
+
+/// Starts worker threads until there is one per allowed concurrent job.
+/// Workers live for the rest of the process and sleep while idle.
+fn spawn_workers(state: &mut QueueState) -> Result<()> {
+    while state.workers < state.max_concurrency {
+        std::thread::Builder::new()
+            .name(format!("embedding-queue-{}", state.workers))
+            .spawn(run_worker)?;
+        state.workers += 1;
+    }
+    Ok(())
+}
+
+fn run_worker() {
+    let queue = queue();
+    let Ok(mut state) = queue.state.lock() else {
+        return;
+    };
+    loop {
+        let Some((job_id, embedder_id, texts)) = state.next_job() else {
+            state = match queue.wakeup.wait(state) {
+                Ok(state) => state,
+                Err(_) => return,
+            };
+            continue;
+        };
+        drop(state);
+        // A panic in ort or tokenizers fails the job instead of killing the
+        // worker with the job's slot still taken.
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            embedder_handle(embedder_id).and_then(|embedder| embedder.embed(texts))
+        }))
+        .unwrap_or_else(|payload| {
+            Err(err!(Internal, "Job panicked: {}", panic_message(&*payload)))
+        });
+        state = match queue.state.lock() {
+            Ok(state) => state,
+            Err(_) => return,
+        };
+        state.running -= 1;
+        if let Some(job) = state.jobs.get_mut(&job_id) {
+            job.state = if result.is_ok() {
+                JobState::Completed
+            } else {
+                JobState::Failed
+            };
+            job.result = Some(result);
+        }
+        state.notify(job_id);
+        queue.wakeup.notify_all();
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
+
+/// Queues `texts` for the embedder loaded under `embedder_id` and returns
+/// the job id. Jobs with a higher `priority` start first; equal priorities
+/// run in submission order.
+#[frb(sync)]
+pub fn submit_job(
+    embedder_id: u64,
+    texts: Vec<String>,
+    priority: i32,
+) -> Result<u64, FlutterEmbedderError> {
+    let mut state = lock_state()?;
+    spawn_workers(&mut state)?;
+    let job_id = state.next_id;
+    state.next_id += 1;
+    state.jobs.insert(
+        job_id,
+        Job {
+            embedder_id,
+            priority,
+            text_count: texts.len() as u32,
+            state: JobState::Queued,
+            texts,
+            result: None,
+        },
+    );
+    state.pending.push(Pending { priority, job_id });
+    state.notify(job_id);
+    queue().wakeup.notify_all();
+    Ok(job_id)
+}
+
+/// Status of `job_id`; `None` once its result was taken or for unknown ids.
+#[frb(sync)]
+pub fn job_status(job_id: u64) -> Result<Option<JobStatus>, FlutterEmbedderError> {
+    Ok(lock_state()?
+        .jobs
+        .get(&job_id)
+        .map(|job| job.status(job_id)))
+}
+
+/// Statuses of all jobs whose result has not been taken, by job id.
+#[frb(sync)]
+pub fn list_jobs() -> Result<Vec<JobStatus>, FlutterEmbedderError> {
+    let state = lock_state()?;
+    let mut statuses: Vec<JobStatus> = state
+        .jobs
+        .iter()
+        .map(|(&job_id, job)| job.status(job_id))
+        .collect();
+    statuses.sort_unstable_by_key(|status| status.job_id);
+    Ok(statuses)
+}
+
+/// The embeddings of a finished job, in input order, after which the job
+/// is forgotten. `None` while it is queued or running; a failed or
+/// cancelled job returns its error (and is forgotten too).
+#[frb(sync)]
+pub fn take_job_result(job_id: u64) -> Result<Option<Vec<Vec<f32>>>, FlutterEmbedderError> {
+    let mut state = lock_state()?;
+    let job = state
+        .jobs
+        .get(&job_id)
+        .ok_or_else(|| err!(InvalidInput, "Unknown job id"))?;
+    match job.state {
+        JobState::Queued | JobState::Running => return Ok(None),
+        JobState::Completed | JobState::Failed | JobState::Cancelled => {}
+    }
+    let job = state.jobs.remove(&job_id).expect("job checked above");
+    match job.result {
+        Some(result) => result.map(Some),
+        None => Err(err!(Cancelled, "Job cancelled")),
+    }
+}
+
+/// Cancels a queued job. Returns `false` when it already started or
+/// finished; running jobs are not interrupted.
+#[frb(sync)]
+pub fn cancel_job(job_id: u64) -> Result<bool, FlutterEmbedderError> {
+    let mut state = lock_state()?;
+    match state.jobs.get_mut(&job_id) {
+        Some(job) if job.state == JobState::Queued => {
+            job.state = JobState::Cancelled;
+            job.texts = Vec::new();
+        }
+        _ => return Ok(false),
+    }
+    state.notify(job_id);
+    Ok(true)
+}
+
+/// Stops starting new jobs until `resume_queue`. Running jobs finish.
+#[frb(sync)]
+pub fn pause_queue() -> Result<(), FlutterEmbedderError> {
+    lock_state()?.paused = true;
+    Ok(())
+}
+
+#[frb(sync)]
+pub fn resume_queue() -> Result<(), FlutterEmbedderError> {
+    lock_state()?.paused = false;
+    queue().wakeup.notify_all();
+    Ok(())
+}
+
+#[frb(sync)]
+pub fn is_queue_paused() -> Result<bool, FlutterEmbedderError> {
+    Ok(lock_state()?.paused)
+}
+
+/// How many jobs may run at once (default 1). Each running job holds one
+/// of the embedder's sessions, so more than its session count only adds
+/// waiting threads.
+#[frb(sync)]
+pub fn set_queue_concurrency(max_concurrency: u32) -> Result<(), FlutterEmbedderError> {
+    if max_concurrency == 0 {
+        return Err(err!(InvalidInput, "max_concurrency must be at least 1"));
+    }
+    let mut state = lock_state()?;
+    state.max_concurrency = max_concurrency as usize;
+    spawn_workers(&mut state)?;
+    queue().wakeup.notify_all();
+    Ok(())
+}
+
+/// Sends every job status change to `sink` until the Dart side stops
+/// listening.
+pub fn watch_jobs(sink: StreamSink<JobStatus>) -> Result<(), FlutterEmbedderError> {
+    lock_state()?.watchers.push(sink);
+    Ok(())
+}
@@ -0,0 +1,294 @@
+use flutter_rust_bridge::frb;
+use ort::session::Session;
+
+use crate::api::classify::classification_logits;
+use crate::api::embeddings::pipeline::{decoder_inputs, DecoderInputs};
+use crate::api::embeddings::WARMUP_TEXT;
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::ort::{
+    build_session_from_file_with_init, build_session_from_memory_with_init, session_model_info,
+    ModelInfo, OrtInitOptions,
+};
+
+const QWEN3_RERANK_INSTRUCTION: &str =
+    "Given a web search query, retrieve relevant passages that answer the query";
+const QWEN3_RERANK_PREFIX: &str = "<|im_start|>system\nJudge whether the Document meets the requirements based on the Query and the Instruct provided. Note that the answer can only be \"yes\" or \"no\".<|im_end|>\n<|im_start|>user\n";
+const QWEN3_RERANK_SUFFIX: &str = "<|im_end|>\n<|im_start|>assistant\n<think>\n\n</think>\n\n";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RerankerKind {
+    /// Sequence-classification cross-encoders (bge-reranker, ms-marco
+    /// MiniLM, ...) that output one relevance logit per query/passage pair.
+    CrossEncoder,
+    /// Qwen3-Reranker: a causal LM scored on its "yes"/"no" next-token logits.
+    Qwen3,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RerankResult {
+    /// Position of the passage in the input list.
+    pub index: u32,
+    pub score: f32,
+}
+
+#[frb(opaque)]
+pub struct Reranker {
+    tokenizer: tokenizers::Tokenizer,
+    session: Session,
+    kind: RerankerKind,
+    instruction: String,
+    yes_no_ids: Option<(u32, u32)>,
+}
+
+#[frb(sync)]
+impl Reranker {
+    pub fn create(
+        model_path: String,
+        tokenizer_path: String,
+        kind: RerankerKind,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_with_options(model_path, tokenizer_path, kind, None)
+    }
+
+    pub fn create_with_options(
+        model_path: String,
+        tokenizer_path: String,
+        kind: RerankerKind,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer = tokenizers::Tokenizer::from_file(tokenizer_path)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let yes_no_ids = yes_no_ids(&tokenizer, kind)?;
+        let session = build_session_from_file_with_init(model_path, ort_options)?;
+        Ok(Self::from_parts(tokenizer, session, kind, yes_no_ids))
+    }
+
+    /// `create` from in-memory model bytes and tokenizer JSON.
+    pub fn create_from_bytes(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        kind: RerankerKind,
+    ) -> Result<Self, FlutterEmbedderError> {
+        Self::create_from_bytes_with_options(model_bytes, tokenizer_json, kind, None)
+    }
+
+    pub fn create_from_bytes_with_options(
+        model_bytes: Vec<u8>,
+        tokenizer_json: String,
+        kind: RerankerKind,
+        ort_options: Option<OrtInitOptions>,
+    ) -> Result<Self, FlutterEmbedderError> {
+        let tokenizer = tokenizers::Tokenizer::from_bytes(tokenizer_json)
+            .map_err(|e| err!(TokenizerLoad, "{e}"))?;
+        let yes_no_ids = yes_no_ids(&tokenizer, kind)?;
+        let session = build_session_from_memory_with_init(&model_bytes, ort_options)?;
+        Ok(Self::from_parts(tokenizer, session, kind, yes_no_ids))
+    }
+
+    fn from_parts(
+        tokenizer: tokenizers::Tokenizer,
+        session: Session,
+        kind: RerankerKind,
+        yes_no_ids: Option<(u32, u32)>,
+    ) -> Self {
+        Self {
+            tokenizer,
+            session,
+            kind,
+            instruction: QWEN3_RERANK_INSTRUCTION.to_string(),
+            yes_no_ids,
+        }
+    }
+
+    /// Inputs, outputs and metadata of the loaded ONNX model.
+    pub fn model_info(&self) -> Result<ModelInfo, FlutterEmbedderError> {
+        session_model_info(&self.session)
+    }
+
+    pub fn kind(&self) -> RerankerKind {
+        self.kind
+    }
+
+    /// Task instruction rendered into Qwen3-Reranker prompts. Ignored by
+    /// cross-encoders.
+    pub fn set_instruction(&mut self, instruction: String) {
+        self.instruction = instruction;
+    }
+
+    /// Relevance logits of `passages` for `query`, in input order. Higher is
+    /// more relevant; use `sigmoid_calibrate` to map them to 0..1.
+    pub fn score(
+        &mut self,
+        query: String,
+        passages: Vec<String>,
+    ) -> Result<Vec<f32>, FlutterEmbedderError> {
+        if passages.is_empty() {
+            return Ok(Vec::new());
+        }
+        match self.kind {
+            RerankerKind::CrossEncoder => self.score_cross_encoder(&query, passages),
+            RerankerKind::Qwen3 => self.score_qwen3(&query, passages),
+        }
+    }
+
+    /// Scores `passages` and returns them best first, keeping at most
+    /// `top_k` results.
+    pub fn rerank(
+        &mut self,
+        query: String,
+        passages: Vec<String>,
+        top_k: Option<u32>,
+    ) -> Result<Vec<RerankResult>, FlutterEmbedderError> {
+        let scores = self.score(query, passages)?;
+        let mut results: Vec<RerankResult> = scores
+            .into_iter()
+            .enumerate()
+            .map(|(index, score)| RerankResult {
+                index: index as u32,
+                score,
+            })
+            .collect();
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+        if let Some(top_k) = top_k {
+            results.truncate(top_k as usize);
+        }
+        Ok(results)
+    }
+
+    fn score_cross_encoder(&mut self, query: &str, passages: Vec<String>) -> Result<Vec<f32>> {
+        let pairs: Vec<(String, String)> = passages
+            .into_iter()
+            .map(|passage| (query.to_string(), passage))
+            .collect();
+        let encodings = self
+            .tokenizer
+            .encode_batch(pairs, true)
+            .map_err(|e| err!(Tokenization, "{e}"))?;
+        let (data, labels) = classification_logits(&mut self.session, &self.tokenizer, &encodings)?;
+        match labels {
+            1 => Ok(data),
+            // Two-class heads: log-odds of the "relevant" class.
+            2 => Ok(data.chunks(2).map(|row| row[1] - row[0]).collect()),
+            _ => Err(err!(
+                ShapeMismatch,
+                "Expected 1 or 2 logits per pair, got {labels}"
+            )),
+        }
+    }
+
+    fn score_qwen3(&mut self, query: &str, passages: Vec<String>) -> Result<Vec<f32>> {
+        let (yes, no) = self
+            .yes_no_ids
+            .ok_or(err!(InvalidInput, "Missing \"yes\"/\"no\" token ids"))?;
+        let prompts: Vec<String> = passages
+            .iter()
+            .map(|passage| {
+                format!(
+                    "{QWEN3_RERANK_PREFIX}<Instruct>: {}\n<Query>: {query}\n<Document>: {passage}{QWEN3_RERANK_SUFFIX}",
+                    self.instruction
+                )
+            })
+            .collect();
+        let encodings = self
+            .tokenizer
+            .encode_batch(prompts, false)
+            .map_err(|e| err!(Tokenization, "{e}"))?;
+        let sequences: Vec<(Vec<u32>, Vec<u32>)> = encodings
+            .iter()
+            .map(|e| (e.get_ids().to_vec(), e.get_attention_mask().to_vec()))
+            .collect();
+        let pad_id = self
+            .tokenizer
+            .get_padding()
+            .map(|p| p.pad_id as i64)
+            .unwrap_or(0);
+
+        let DecoderInputs {
+            inputs,
+            batch,
+            masks,
+        } = decoder_inputs(&self.session, &sequences, pad_id)?;
+        let outputs = self.session.run(inputs)?;
+        let (shape, data) = outputs
+            .get("logits")
+            .ok_or(err!(MissingOutput, "Missing logits"))?
+            .try_extract_tensor::<f32>()?;
+        if shape.first().map(|d| *d as usize) != Some(batch) {
+            return Err(err!(ShapeMismatch, "Batch size mismatch in outputs"));
+        }
+
+        let mut scores = Vec::with_capacity(passages.len());
+        for (i, mask) in masks.iter().enumerate().take(passages.len()) {
+            // `[batch, seq, vocab]`, or `[batch, vocab]` for exports that
+            // only return the last position.
+            let (seq_len, vocab, last) = match shape.len() {
+                3 => {
+                    let seq_len = shape[1] as usize;
+                    let last = mask[..seq_len.min(mask.len())]
+                        .iter()
+                        .rposition(|&m| m == 1)
+                        .unwrap_or(seq_len.saturating_sub(1));
+                    (seq_len, shape[2] as usize, last)
+                }
+                2 => (1, shape[1] as usize, 0),
+                _ => {
+                    return Err(err!(
+                        ShapeMismatch,
+                        "Unexpected logits rank {}",
+                        shape.len()
+                    ))
+                }
+            };
+            let start = (i * seq_len + last) * vocab;
+            let row = data
+                .get(start..start + vocab)
+                .ok_or(err!(ShapeMismatch, "Invalid logits slice"))?;
+            let yes_logit = row
+                .get(yes as usize)
+                .ok_or(err!(ShapeMismatch, "Invalid yes token"))?;
+            let no_logit = row
+                .get(no as usize)
+                .ok_or(err!(ShapeMismatch, "Invalid no token"))?;
+            scores.push(yes_logit - no_logit);
+        }
+        Ok(scores)
+    }
+}
+
+impl Reranker {
+    /// Scores one tiny pair so the first real `rerank` doesn't pay for graph
+    /// initialization.
+    pub fn warmup(&mut self) -> Result<(), FlutterEmbedderError> {
+        self.rerank(WARMUP_TEXT.to_string(), vec![WARMUP_TEXT.to_string()], None)?;
+        Ok(())
+    }
+
+    /// Same as `rerank`, but not `sync` on the Dart side.
+    pub fn rerank_async(
+        &mut self,
+        query: String,
+        passages: Vec<String>,
+        top_k: Option<u32>,
+    ) -> Result<Vec<RerankResult>, FlutterEmbedderError> {
+        self.rerank(query, passages, top_k)
+    }
+}
+
+/// Token ids of "yes"/"no" for `RerankerKind::Qwen3`, whose score compares
+/// their logits.
+fn yes_no_ids(tokenizer: &tokenizers::Tokenizer, kind: RerankerKind) -> Result<Option<(u32, u32)>> {
+    match kind {
+        RerankerKind::CrossEncoder => Ok(None),
+        RerankerKind::Qwen3 => {
+            let yes = tokenizer.token_to_id("yes");
+            let no = tokenizer.token_to_id("no");
+            match (yes, no) {
+                (Some(yes), Some(no)) => Ok(Some((yes, no))),
+                _ => Err(err!(
+                    TokenizerLoad,
+                    "Tokenizer has no \"yes\"/\"no\" tokens"
+                )),
+            }
+        }
+    }
+}
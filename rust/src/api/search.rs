@@ -0,0 +1,76 @@
+const DEFAULT_GAP_FACTOR: f32 = 2.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    pub id: String,
+    pub score: f32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CutoffOptions {
+    /// Absolute score threshold applied before the elbow detection.
+    pub min_score: Option<f32>,
+    /// Never cut below this many hits (as long as they pass `min_score`).
+    pub min_results: Option<u32>,
+    pub max_results: Option<u32>,
+    /// A drop between consecutive scores counts as an elbow when it is at
+    /// least this many times the average drop (default 2.0).
+    pub gap_factor: Option<f32>,
+}
+
+/// Drops hits scoring below `min_score`, keeping the input order.
+#[flutter_rust_bridge::frb(sync)]
+pub fn filter_by_threshold(hits: Vec<SearchHit>, min_score: f32) -> Vec<SearchHit> {
+    hits.into_iter().filter(|h| h.score >= min_score).collect()
+}
+
+/// Returns only the confident head of a result list: hits are sorted by
+/// score, thresholded, and cut at the largest score drop ("elbow") when that
+/// drop clearly stands out from the rest of the curve.
+#[flutter_rust_bridge::frb(sync)]
+pub fn adaptive_cutoff(hits: Vec<SearchHit>, options: Option<CutoffOptions>) -> Vec<SearchHit> {
+    let options = options.unwrap_or_default();
+    let mut hits = hits;
+    hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+    if let Some(min_score) = options.min_score {
+        hits.retain(|h| h.score >= min_score);
+    }
+    if let Some(max) = options.max_results {
+        hits.truncate(max as usize);
+    }
+
+    let scores: Vec<f32> = hits.iter().map(|h| h.score).collect();
+    let min_results = options.min_results.unwrap_or(1) as usize;
+    let keep = elbow_index(
+        &scores,
+        options.gap_factor.unwrap_or(DEFAULT_GAP_FACTOR),
+        min_results,
+    );
+    hits.truncate(keep);
+    hits
+}
+
+/// Number of leading scores (sorted descending) to keep before the elbow.
+pub(crate) fn elbow_index(scores: &[f32], gap_factor: f32, min_results: usize) -> usize {
+    if scores.len() < 3 || min_results >= scores.len() {
+        return scores.len();
+    }
+    let drops: Vec<f32> = scores.windows(2).map(|w| w[0] - w[1]).collect();
+    let mean_drop = (scores[0] - scores[scores.len() - 1]) / drops.len() as f32;
+    if mean_drop <= 0.0 {
+        return scores.len();
+    }
+    let start = min_results.saturating_sub(1);
+    let (best, drop) = drops
+        .iter()
+        .enumerate()
+        .skip(start)
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .map(|(i, d)| (i, *d))
+        .unwrap_or((0, 0.0));
+    if drop >= gap_factor * mean_drop {
+        best + 1
+    } else {
+        scores.len()
+    }
+}
@@ -0,0 +1,448 @@
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use flutter_rust_bridge::frb;
+use serde_json::Value;
+
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::utils::cosine_similarity;
+
+const MAGIC: &[u8; 4] = b"FEST";
+const FORMAT_VERSION: u32 = 1;
+const HEADER_LEN: usize = 8;
+const OP_UPSERT: u8 = 1;
+const OP_DELETE: u8 = 2;
+
+/// One stored vector with its metadata.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredEmbedding {
+    pub vector: Vec<f32>,
+    pub metadata_json: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoreHit {
+    pub id: String,
+    /// Cosine similarity to the query.
+    pub score: f32,
+    pub metadata_json: Option<String>,
+}
+
+/// Durable id → vector + JSON metadata store.
+///
+/// Every `upsert`/`delete` is appended to the file as a checksummed record
+/// and synced before returning, so a crash loses at most the write in
+/// flight; a torn trailing record is dropped on the next `open`, while a
+/// damaged record elsewhere fails it. The log grows with overwrites and
+/// deletes until `compact` rewrites it.
+/// `query` is an exact (brute-force) cosine search over the stored vectors.
+#[frb(opaque)]
+pub struct EmbeddingStore {
+    path: PathBuf,
+    file: File,
+    records: HashMap<String, StoredEmbedding>,
+    /// Log records that no longer describe a live entry.
+    stale: usize,
+}
+
+#[frb(sync)]
+impl EmbeddingStore {
+    /// Opens the store at `path`, creating it when missing.
+    pub fn open(path: String) -> Result<Self, FlutterEmbedderError> {
+        let path = PathBuf::from(path);
+        if !path.exists() {
+            write_log(&path, std::iter::empty())?;
+        }
+        let bytes =
+            std::fs::read(&path).map_err(|e| err!(Io, "Failed to read {}: {e}", path.display()))?;
+        if bytes.len() < HEADER_LEN || &bytes[..4] != MAGIC {
+            return Err(err!(Io, "{} is not an embedding store", path.display()));
+        }
+        let version = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        if version != FORMAT_VERSION {
+            return Err(err!(Io, "Unsupported embedding store version {version}"));
+        }
+
+        let mut records = HashMap::new();
+        let mut stale = 0;
+        let mut offset = HEADER_LEN;
+        while let Some((op, next)) = read_record(&bytes, offset) {
+            let replaced = match op {
+                LogOp::Upsert(id, record) => records.insert(id, record).is_some(),
+                LogOp::Delete(id) => {
+                    stale += 1;
+                    records.remove(&id).is_some()
+                }
+            };
+            stale += replaced as usize;
+            offset = next;
+        }
+
+        let file = OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .map_err(|e| err!(Io, "Failed to open {}: {e}", path.display()))?;
+        if offset < bytes.len() {
+            if !reaches_end(&bytes, offset) {
+                return Err(err!(
+                    Io,
+                    "Corrupt record at byte {offset} of {}",
+                    path.display()
+                ));
+            }
+            // Torn write from a crash: drop the incomplete tail.
+            file.set_len(offset as u64)?;
+            file.sync_all()?;
+        }
+        Ok(Self {
+            path,
+            file,
+            records,
+            stale,
+        })
+    }
+
+    /// Number of stored vectors.
+    pub fn len(&self) -> u32 {
+        self.records.len() as u32
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn contains(&self, id: String) -> bool {
+        self.records.contains_key(&id)
+    }
+
+    /// Vector dimension of the stored entries (0 while empty).
+    pub fn dimension(&self) -> u32 {
+        self.records.values().next().map_or(0, |r| r.vector.len()) as u32
+    }
+
+    pub fn get(&self, id: String) -> Option<StoredEmbedding> {
+        self.records.get(&id).cloned()
+    }
+
+    /// Inserts or replaces `id`. `metadata_json`, when given, must be valid
+    /// JSON; objects can be matched by `query` filters.
+    pub fn upsert(
+        &mut self,
+        id: String,
+        vector: Vec<f32>,
+        metadata_json: Option<String>,
+    ) -> Result<(), FlutterEmbedderError> {
+        if vector.is_empty() {
+            return Err(err!(InvalidInput, "Vector must not be empty"));
+        }
+        let dimension = self.dimension() as usize;
+        if dimension != 0 && vector.len() != dimension && !self.only_holds(&id) {
+            return Err(err!(
+                InvalidInput,
+                "Vector has dimension {}, store expects {dimension}",
+                vector.len()
+            ));
+        }
+        if let Some(metadata) = &metadata_json {
+            serde_json::from_str::<Value>(metadata)
+                .map_err(|e| err!(InvalidInput, "Invalid metadata JSON: {e}"))?;
+        }
+        let record = StoredEmbedding {
+            vector,
+            metadata_json,
+        };
+        self.append(&encode_upsert(&id, &record))?;
+        if self.records.insert(id, record).is_some() {
+            self.stale += 1;
+        }
+        Ok(())
+    }
+
+    /// Removes `id`; returns whether it was present.
+    pub fn delete(&mut self, id: String) -> Result<bool, FlutterEmbedderError> {
+        if !self.records.contains_key(&id) {
+            return Ok(false);
+        }
+        self.append(&encode_delete(&id))?;
+        self.records.remove(&id);
+        self.stale += 2;
+        Ok(true)
+    }
+
+    /// The `k` entries most similar to `vector`, best first. `filter` is a
+    /// JSON object; only entries whose metadata has an equal value for every
+    /// key in it are considered.
+    pub fn query(
+        &self,
+        vector: Vec<f32>,
+        k: u32,
+        filter: Option<String>,
+    ) -> Result<Vec<StoreHit>, FlutterEmbedderError> {
+        let dimension = self.dimension() as usize;
+        if dimension != 0 && vector.len() != dimension {
+            return Err(err!(
+                InvalidInput,
+                "Query has dimension {}, store expects {dimension}",
+                vector.len()
+            ));
+        }
+        let filter = filter
+            .map(|f| match serde_json::from_str(&f) {
+                Ok(Value::Object(map)) => Ok(map),
+                Ok(_) => Err(err!(InvalidInput, "Filter must be a JSON object")),
+                Err(e) => Err(err!(InvalidInput, "Invalid filter JSON: {e}")),
+            })
+            .transpose()?;
+
+        let mut hits: Vec<StoreHit> = self
+            .records
+            .iter()
+            .filter(|(_, record)| {
+                filter
+                    .as_ref()
+                    .is_none_or(|filter| metadata_matches(record, filter))
+            })
+            .map(|(id, record)| StoreHit {
+                id: id.clone(),
+                score: cosine_similarity(&vector, &record.vector),
+                metadata_json: record.metadata_json.clone(),
+            })
+            .collect();
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+        hits.truncate(k as usize);
+        Ok(hits)
+    }
+
+    /// Rewrites the file with only the live entries. The new file replaces
+    /// the old one atomically, so a crash leaves either of them intact.
+    pub fn compact(&mut self) -> Result<(), FlutterEmbedderError> {
+        write_log(
+            &self.path,
+            self.records
+                .iter()
+                .map(|(id, record)| encode_upsert(id, record)),
+        )?;
+        self.file = OpenOptions::new()
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| err!(Io, "Failed to open {}: {e}", self.path.display()))?;
+        self.stale = 0;
+        Ok(())
+    }
+
+    /// Number of log records `compact` would drop.
+    pub fn stale_records(&self) -> u32 {
+        self.stale as u32
+    }
+}
+
+impl EmbeddingStore {
+    /// `upsert` without metadata for many entries, synced once at the end.
+    pub(crate) fn upsert_batch(&mut self, entries: Vec<(String, Vec<f32>)>) -> Result<()> {
+        let dimension = match self.dimension() as usize {
+            0 => entries.first().map_or(0, |(_, vector)| vector.len()),
+            dimension => dimension,
+        };
+        if let Some((_, vector)) = entries
+            .iter()
+            .find(|(_, vector)| vector.is_empty() || vector.len() != dimension)
+        {
+            return Err(err!(
+                InvalidInput,
+                "Vector has dimension {}, store expects {dimension}",
+                vector.len()
+            ));
+        }
+        let mut log = Vec::new();
+        for (id, vector) in entries {
+            let record = StoredEmbedding {
+                vector,
+                metadata_json: None,
+            };
+            log.extend(encode_upsert(&id, &record));
+            if self.records.insert(id, record).is_some() {
+                self.stale += 1;
+            }
+        }
+        self.append(&log)
+    }
+
+    fn append(&mut self, record: &[u8]) -> Result<()> {
+        let len = self.file.metadata()?.len();
+        if let Err(e) = self
+            .file
+            .write_all(record)
+            .and_then(|()| self.file.sync_data())
+        {
+            // Cut off a partial record so later appends don't follow it.
+            let _ = self.file.set_len(len);
+            return Err(e.into());
+        }
+        Ok(())
+    }
+
+    /// Whether `id` is the only entry, so replacing it may change the
+    /// dimension.
+    fn only_holds(&self, id: &str) -> bool {
+        self.records.len() == 1 && self.records.contains_key(id)
+    }
+}
+
+enum LogOp {
+    Upsert(String, StoredEmbedding),
+    Delete(String),
+}
+
+/// Writes header + `records` to a temporary file next to `path`, syncs it
+/// and renames it over `path`.
+fn write_log(path: &Path, records: impl Iterator<Item = Vec<u8>>) -> Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    let mut out = Vec::from(&MAGIC[..]);
+    out.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+    for record in records {
+        out.extend_from_slice(&record);
+    }
+    let mut file =
+        File::create(&tmp).map_err(|e| err!(Io, "Failed to create {}: {e}", tmp.display()))?;
+    file.write_all(&out)?;
+    file.sync_all()?;
+    std::fs::rename(&tmp, path)
+        .map_err(|e| err!(Io, "Failed to replace {}: {e}", path.display()))?;
+    Ok(())
+}
+
+/// Frames `payload` as `len | payload | checksum`.
+fn frame(payload: Vec<u8>) -> Vec<u8> {
+    let mut out = Vec::with_capacity(payload.len() + 8);
+    out.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    out.extend_from_slice(&payload);
+    out.extend_from_slice(&checksum(&payload).to_le_bytes());
+    out
+}
+
+fn encode_upsert(id: &str, record: &StoredEmbedding) -> Vec<u8> {
+    let mut payload = vec![OP_UPSERT];
+    put_bytes(&mut payload, id.as_bytes());
+    payload.extend_from_slice(&(record.vector.len() as u32).to_le_bytes());
+    for value in &record.vector {
+        payload.extend_from_slice(&value.to_le_bytes());
+    }
+    match &record.metadata_json {
+        Some(metadata) => {
+            payload.push(1);
+            put_bytes(&mut payload, metadata.as_bytes());
+        }
+        None => payload.push(0),
+    }
+    frame(payload)
+}
+
+fn encode_delete(id: &str) -> Vec<u8> {
+    let mut payload = vec![OP_DELETE];
+    put_bytes(&mut payload, id.as_bytes());
+    frame(payload)
+}
+
+fn put_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    out.extend_from_slice(bytes);
+}
+
+/// Decodes the record at `offset`, returning it and the next offset. `None`
+/// at the end of the log or at a truncated/corrupt record.
+fn read_record(bytes: &[u8], offset: usize) -> Option<(LogOp, usize)> {
+    let mut cursor = Cursor { bytes, offset };
+    let len = cursor.u32()? as usize;
+    let payload = cursor.take(len)?;
+    if cursor.u32()? != checksum(payload) {
+        return None;
+    }
+    let next = cursor.offset;
+
+    let mut cursor = Cursor {
+        bytes: payload,
+        offset: 0,
+    };
+    let op = cursor.take(1)?[0];
+    let id_len = cursor.u32()? as usize;
+    let id = String::from_utf8(cursor.take(id_len)?.to_vec()).ok()?;
+    let op = match op {
+        OP_UPSERT => {
+            let dim = cursor.u32()? as usize;
+            let vector = cursor
+                .take(dim.checked_mul(4)?)?
+                .chunks_exact(4)
+                .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+                .collect();
+            let metadata_json = match cursor.take(1)?[0] {
+                0 => None,
+                _ => {
+                    let len = cursor.u32()? as usize;
+                    Some(String::from_utf8(cursor.take(len)?.to_vec()).ok()?)
+                }
+            };
+            LogOp::Upsert(
+                id,
+                StoredEmbedding {
+                    vector,
+                    metadata_json,
+                },
+            )
+        }
+        OP_DELETE => LogOp::Delete(id),
+        _ => return None,
+    };
+    Some((op, next))
+}
+
+/// Whether the record at `offset` runs to or past the end of the log, i.e.
+/// is the last one and may have been torn by a crash.
+fn reaches_end(bytes: &[u8], offset: usize) -> bool {
+    let mut cursor = Cursor { bytes, offset };
+    match cursor.u32() {
+        Some(len) => (len as usize).saturating_add(offset + 8) >= bytes.len(),
+        None => true,
+    }
+}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let end = self.offset.checked_add(len)?;
+        let slice = self.bytes.get(self.offset..end)?;
+        self.offset = end;
+        Some(slice)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take(4)
+            .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+    }
+}
+
+/// FNV-1a; enough to detect torn writes, not tampering.
+fn checksum(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c_9dc5u32, |hash, &b| {
+        (hash ^ b as u32).wrapping_mul(0x0100_0193)
+    })
+}
+
+fn metadata_matches(record: &StoredEmbedding, filter: &serde_json::Map<String, Value>) -> bool {
+    let Some(metadata) = &record.metadata_json else {
+        return filter.is_empty();
+    };
+    let Ok(Value::Object(metadata)) = serde_json::from_str::<Value>(metadata) else {
+        return filter.is_empty();
+    };
+    filter
+        .iter()
+        .all(|(key, value)| metadata.get(key) == Some(value))
+}
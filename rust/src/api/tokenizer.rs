@@ -3,11 +3,14 @@ use std::fs;
 use std::str::FromStr;
 use std::sync::{
     atomic::{AtomicU64, Ordering},
-    OnceLock, RwLock,
+    Arc, OnceLock, RwLock,
 };
 
 use tokenizers::{AddedToken, Tokenizer};
 
+use crate::api::embeddings::pad_rows;
+use crate::api::error::{err, FlutterEmbedderError, Result};
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct TokenOffsets {
     pub start: u32,
@@ -22,10 +25,20 @@ pub struct EncodeOutput {
     pub special_tokens_mask: Vec<u32>,
     pub offsets: Vec<TokenOffsets>,
     pub tokens: Vec<String>,
+    /// Index of the pre-tokenized word each token belongs to, e.g. to label
+    /// whole words in NER; `None` for special and padding tokens.
+    pub word_ids: Vec<Option<u32>>,
+    /// Which text of a pair (0 or 1) each token comes from; `None` for
+    /// special and padding tokens.
+    pub sequence_ids: Vec<Option<u32>>,
+    /// The rest of the text when truncation cut it off, as further
+    /// encodings of at most the truncation length. With a truncation
+    /// `stride`, each piece repeats that many tokens of the one before.
+    pub overflowing: Vec<EncodeOutput>,
 }
 
 impl From<tokenizers::Encoding> for EncodeOutput {
-    fn from(value: tokenizers::Encoding) -> Self {
+    fn from(mut value: tokenizers::Encoding) -> Self {
         let offsets = value
             .get_offsets()
             .iter()
@@ -42,11 +55,23 @@ impl From<tokenizers::Encoding> for EncodeOutput {
             special_tokens_mask: value.get_special_tokens_mask().to_vec(),
             offsets,
             tokens: value.get_tokens().to_vec(),
+            word_ids: value.get_word_ids().to_vec(),
+            sequence_ids: value
+                .get_sequence_ids()
+                .into_iter()
+                .map(|id| id.map(|id| id as u32))
+                .collect(),
+            overflowing: value
+                .take_overflowing()
+                .into_iter()
+                .map(EncodeOutput::from)
+                .collect(),
         }
     }
 }
 
-type TokenizerStore = HashMap<u64, Tokenizer>;
+/// Tokenizers are shared with the embedders they were registered from.
+type TokenizerStore = HashMap<u64, Arc<Tokenizer>>;
 
 fn store() -> &'static RwLock<TokenizerStore> {
     static STORE: OnceLock<RwLock<TokenizerStore>> = OnceLock::new();
@@ -58,14 +83,33 @@ fn next_id() -> u64 {
     COUNTER.fetch_add(1, Ordering::Relaxed)
 }
 
-fn insert_tokenizer(tokenizer: Tokenizer) -> Result<u64, String> {
+fn insert_tokenizer(tokenizer: Tokenizer) -> Result<u64> {
     let id = next_id();
 
     let mut guard = store()
         .write()
-        .map_err(|e| format!("Failed to acquire tokenizer store: {e}"))?;
-    guard.insert(id, tokenizer);
+        .map_err(|e| err!(Internal, "Failed to acquire tokenizer store: {e}"))?;
+    guard.insert(id, Arc::new(tokenizer));
+
+    Ok(id)
+}
 
+/// Id of `tokenizer` in the store. `known`, the id handed out for it
+/// before, is kept while still registered, even once reconfiguring it has
+/// replaced the stored copy; otherwise `tokenizer` is added. The store
+/// holds another reference, not a copy.
+pub(crate) fn register_shared_tokenizer(
+    tokenizer: Arc<Tokenizer>,
+    known: Option<u64>,
+) -> Result<u64> {
+    let mut guard = store()
+        .write()
+        .map_err(|e| err!(Internal, "Failed to acquire tokenizer store: {e}"))?;
+    if let Some(id) = known.filter(|id| guard.contains_key(id)) {
+        return Ok(id);
+    }
+    let id = next_id();
+    guard.insert(id, tokenizer);
     Ok(id)
 }
 
@@ -80,36 +124,40 @@ fn add_special_tokens_internal(tokenizer: &mut Tokenizer, tokens: Vec<String>) -
     tokenizer.add_special_tokens(&added_tokens) as u32
 }
 
-fn with_tokenizer<R, F>(id: u64, f: F) -> Result<R, String>
+pub(crate) fn with_tokenizer<R, F>(id: u64, f: F) -> Result<R>
 where
-    F: FnOnce(&Tokenizer) -> Result<R, String>,
+    F: FnOnce(&Tokenizer) -> Result<R>,
 {
-    let guard = store().read().map_err(|e| e.to_string())?;
+    let guard = store()
+        .read()
+        .map_err(|e| err!(Internal, "Failed to acquire tokenizer store: {e}"))?;
     let tokenizer = guard
         .get(&id)
-        .ok_or_else(|| "Unknown tokenizer id".to_string())?;
+        .ok_or_else(|| err!(InvalidInput, "Unknown tokenizer id"))?;
     f(tokenizer)
 }
 
-fn with_tokenizer_mut<R, F>(id: u64, f: F) -> Result<R, String>
+/// Runs `f` on the tokenizer registered as `id`. A tokenizer shared with an
+/// embedder is copied first, so the embedder keeps its settings.
+fn with_tokenizer_mut<R, F>(id: u64, f: F) -> Result<R>
 where
-    F: FnOnce(&mut Tokenizer) -> Result<R, String>,
+    F: FnOnce(&mut Tokenizer) -> Result<R>,
 {
     let mut guard = store()
         .write()
-        .map_err(|e| format!("Failed to acquire tokenizer store: {e}"))?;
+        .map_err(|e| err!(Internal, "Failed to acquire tokenizer store: {e}"))?;
     let tokenizer = guard
         .get_mut(&id)
-        .ok_or_else(|| "Unknown tokenizer id".to_string())?;
-    f(tokenizer)
+        .ok_or_else(|| err!(InvalidInput, "Unknown tokenizer id"))?;
+    f(Arc::make_mut(tokenizer))
 }
 
 fn load_tokenizer_from_json_inner(
     json: String,
     special_tokens: Option<Vec<String>>,
-) -> Result<u64, String> {
-    let mut tokenizer =
-        Tokenizer::from_str(&json).map_err(|err| format!("Failed to load tokenizer: {err}"))?;
+) -> Result<u64> {
+    let mut tokenizer = Tokenizer::from_str(&json)
+        .map_err(|err| err!(TokenizerLoad, "Failed to load tokenizer: {err}"))?;
 
     if let Some(tokens) = special_tokens {
         add_special_tokens_internal(&mut tokenizer, tokens);
@@ -119,7 +167,7 @@ fn load_tokenizer_from_json_inner(
 }
 
 #[flutter_rust_bridge::frb(sync)]
-pub fn load_tokenizer_from_json(json: String) -> Result<u64, String> {
+pub fn load_tokenizer_from_json(json: String) -> Result<u64, FlutterEmbedderError> {
     load_tokenizer_from_json_inner(json, None)
 }
 
@@ -127,14 +175,18 @@ pub fn load_tokenizer_from_json(json: String) -> Result<u64, String> {
 pub fn load_tokenizer_from_json_with_special_tokens(
     json: String,
     special_tokens: Vec<String>,
-) -> Result<u64, String> {
+) -> Result<u64, FlutterEmbedderError> {
     load_tokenizer_from_json_inner(json, Some(special_tokens))
 }
 
 #[flutter_rust_bridge::frb(sync)]
-pub fn load_tokenizer_from_bytes(bytes: Vec<u8>) -> Result<u64, String> {
-    let json = String::from_utf8(bytes)
-        .map_err(|err| format!("Tokenizer bytes are not valid UTF-8 JSON: {err}"))?;
+pub fn load_tokenizer_from_bytes(bytes: Vec<u8>) -> Result<u64, FlutterEmbedderError> {
+    let json = String::from_utf8(bytes).map_err(|err| {
+        err!(
+            TokenizerLoad,
+            "Tokenizer bytes are not valid UTF-8 JSON: {err}"
+        )
+    })?;
     load_tokenizer_from_json(json)
 }
 
@@ -142,16 +194,20 @@ pub fn load_tokenizer_from_bytes(bytes: Vec<u8>) -> Result<u64, String> {
 pub fn load_tokenizer_from_bytes_with_special_tokens(
     bytes: Vec<u8>,
     special_tokens: Vec<String>,
-) -> Result<u64, String> {
-    let json = String::from_utf8(bytes)
-        .map_err(|err| format!("Tokenizer bytes are not valid UTF-8 JSON: {err}"))?;
+) -> Result<u64, FlutterEmbedderError> {
+    let json = String::from_utf8(bytes).map_err(|err| {
+        err!(
+            TokenizerLoad,
+            "Tokenizer bytes are not valid UTF-8 JSON: {err}"
+        )
+    })?;
     load_tokenizer_from_json_inner(json, Some(special_tokens))
 }
 
 #[flutter_rust_bridge::frb(sync)]
-pub fn load_tokenizer_from_file(path: String) -> Result<u64, String> {
+pub fn load_tokenizer_from_file(path: String) -> Result<u64, FlutterEmbedderError> {
     let json = fs::read_to_string(&path)
-        .map_err(|err| format!("Failed to read tokenizer file {path}: {err}"))?;
+        .map_err(|err| err!(TokenizerLoad, "Failed to read tokenizer file {path}: {err}"))?;
     load_tokenizer_from_json(json)
 }
 
@@ -159,14 +215,17 @@ pub fn load_tokenizer_from_file(path: String) -> Result<u64, String> {
 pub fn load_tokenizer_from_file_with_special_tokens(
     path: String,
     special_tokens: Vec<String>,
-) -> Result<u64, String> {
+) -> Result<u64, FlutterEmbedderError> {
     let json = fs::read_to_string(&path)
-        .map_err(|err| format!("Failed to read tokenizer file {path}: {err}"))?;
+        .map_err(|err| err!(TokenizerLoad, "Failed to read tokenizer file {path}: {err}"))?;
     load_tokenizer_from_json_inner(json, Some(special_tokens))
 }
 
 #[flutter_rust_bridge::frb(sync)]
-pub fn add_special_tokens(tokenizer_id: u64, tokens: Vec<String>) -> Result<u32, String> {
+pub fn add_special_tokens(
+    tokenizer_id: u64,
+    tokens: Vec<String>,
+) -> Result<u32, FlutterEmbedderError> {
     with_tokenizer_mut(tokenizer_id, |tokenizer| {
         Ok(add_special_tokens_internal(tokenizer, tokens))
     })
@@ -177,11 +236,11 @@ pub fn encode(
     tokenizer_id: u64,
     text: String,
     add_special_tokens: Option<bool>,
-) -> Result<EncodeOutput, String> {
+) -> Result<EncodeOutput, FlutterEmbedderError> {
     with_tokenizer(tokenizer_id, |tokenizer| {
         let encoding = tokenizer
             .encode(text, add_special_tokens.unwrap_or(true))
-            .map_err(|err| format!("Encode failed: {err}"))?;
+            .map_err(|err| err!(Tokenization, "Encode failed: {err}"))?;
         Ok(EncodeOutput::from(encoding))
     })
 }
@@ -191,25 +250,169 @@ pub fn encode_batch(
     tokenizer_id: u64,
     texts: Vec<String>,
     add_special_tokens: Option<bool>,
-) -> Result<Vec<EncodeOutput>, String> {
+) -> Result<Vec<EncodeOutput>, FlutterEmbedderError> {
     with_tokenizer(tokenizer_id, |tokenizer| {
         let encodings = tokenizer
             .encode_batch(texts, add_special_tokens.unwrap_or(true))
-            .map_err(|err| format!("Encode batch failed: {err}"))?;
+            .map_err(|err| err!(Tokenization, "Encode batch failed: {err}"))?;
+        Ok(encodings.into_iter().map(EncodeOutput::from).collect())
+    })
+}
+
+/// Encodes `text_a` and `text_b` as one sequence pair, e.g. query and
+/// passage for a cross-encoder or premise and hypothesis for NLI. Tokens of
+/// `text_b` get type id 1 when the post-processor assigns type ids, and
+/// their offsets point into `text_b`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn encode_pair(
+    tokenizer_id: u64,
+    text_a: String,
+    text_b: String,
+    add_special_tokens: Option<bool>,
+) -> Result<EncodeOutput, FlutterEmbedderError> {
+    with_tokenizer(tokenizer_id, |tokenizer| {
+        let encoding = tokenizer
+            .encode((text_a, text_b), add_special_tokens.unwrap_or(true))
+            .map_err(|err| err!(Tokenization, "Encode failed: {err}"))?;
+        Ok(EncodeOutput::from(encoding))
+    })
+}
+
+/// `encode_pair` for each `(text_a, text_b)` of `pairs`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn encode_pair_batch(
+    tokenizer_id: u64,
+    pairs: Vec<(String, String)>,
+    add_special_tokens: Option<bool>,
+) -> Result<Vec<EncodeOutput>, FlutterEmbedderError> {
+    with_tokenizer(tokenizer_id, |tokenizer| {
+        let encodings = tokenizer
+            .encode_batch(pairs, add_special_tokens.unwrap_or(true))
+            .map_err(|err| err!(Tokenization, "Encode batch failed: {err}"))?;
         Ok(encodings.into_iter().map(EncodeOutput::from).collect())
     })
 }
 
+/// A batch of encodings as row-major `[batch_size, seq_len]` tensors, ready
+/// for the `input_ids`, `attention_mask` and `token_type_ids` inputs of an
+/// ONNX session.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaddedBatch {
+    pub input_ids: Vec<i64>,
+    pub attention_mask: Vec<i64>,
+    pub token_type_ids: Vec<i64>,
+    /// `[batch_size, seq_len]`.
+    pub shape: Vec<u32>,
+}
+
+/// Encodes `texts` with special tokens and pads them into one tensor per
+/// input. Rows are as long as the longest encoding or, when `max_len` is
+/// given, exactly `max_len` tokens, for models exported with a fixed
+/// sequence length; longer texts are truncated as `set_truncation` would.
+/// Padding uses the tokenizer's pad id, or 0 when none is set.
+#[flutter_rust_bridge::frb(sync)]
+pub fn encode_batch_padded(
+    tokenizer_id: u64,
+    texts: Vec<String>,
+    max_len: Option<u32>,
+) -> Result<PaddedBatch, FlutterEmbedderError> {
+    if max_len == Some(0) {
+        return Err(err!(InvalidInput, "max_len must be positive"));
+    }
+    let max_len = max_len.map(|len| len as usize);
+    with_tokenizer(tokenizer_id, |tokenizer| {
+        let truncation = tokenizer.get_truncation();
+        let encodings = match max_len {
+            // Truncating to `max_len` needs the tokenizer to account for the
+            // special tokens, so a copy with that truncation does the encoding.
+            Some(len) if truncation.is_none_or(|params| params.max_length > len) => {
+                let mut capped = tokenizer.clone();
+                let params = tokenizers::TruncationParams {
+                    max_length: len,
+                    ..truncation.cloned().unwrap_or_default()
+                };
+                capped
+                    .with_truncation(Some(params))
+                    .map_err(|err| err!(InvalidInput, "Failed to set truncation: {err}"))?;
+                capped.encode_batch(texts, true)
+            }
+            _ => tokenizer.encode_batch(texts, true),
+        }
+        .map_err(|err| err!(Tokenization, "Encode batch failed: {err}"))?;
+
+        let seq_len = max_len.unwrap_or_else(|| {
+            encodings
+                .iter()
+                .map(|e| e.get_ids().len())
+                .max()
+                .unwrap_or(0)
+        });
+        let pad_id = tokenizer
+            .get_padding()
+            .map_or(0, |params| params.pad_id as i64);
+        Ok(PaddedBatch {
+            input_ids: pad_rows(&encodings, seq_len, pad_id, tokenizers::Encoding::get_ids),
+            attention_mask: pad_rows(
+                &encodings,
+                seq_len,
+                0,
+                tokenizers::Encoding::get_attention_mask,
+            ),
+            token_type_ids: pad_rows(&encodings, seq_len, 0, tokenizers::Encoding::get_type_ids),
+            shape: vec![encodings.len() as u32, seq_len as u32],
+        })
+    })
+}
+
+/// Number of tokens `encode` would produce for `text`, after truncation but
+/// without padding.
+#[flutter_rust_bridge::frb(sync)]
+pub fn count_tokens(
+    tokenizer_id: u64,
+    text: String,
+    add_special_tokens: Option<bool>,
+) -> Result<u32, FlutterEmbedderError> {
+    with_tokenizer(tokenizer_id, |tokenizer| {
+        let encoding = tokenizer
+            .encode(text, add_special_tokens.unwrap_or(true))
+            .map_err(|err| err!(Tokenization, "Encode failed: {err}"))?;
+        Ok(unpadded_len(&encoding))
+    })
+}
+
+/// `count_tokens` for each of `texts`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn count_tokens_batch(
+    tokenizer_id: u64,
+    texts: Vec<String>,
+    add_special_tokens: Option<bool>,
+) -> Result<Vec<u32>, FlutterEmbedderError> {
+    with_tokenizer(tokenizer_id, |tokenizer| {
+        let encodings = tokenizer
+            .encode_batch(texts, add_special_tokens.unwrap_or(true))
+            .map_err(|err| err!(Tokenization, "Encode batch failed: {err}"))?;
+        Ok(encodings.iter().map(unpadded_len).collect())
+    })
+}
+
+pub(crate) fn unpadded_len(encoding: &tokenizers::Encoding) -> u32 {
+    encoding
+        .get_attention_mask()
+        .iter()
+        .filter(|&&m| m != 0)
+        .count() as u32
+}
+
 #[flutter_rust_bridge::frb(sync)]
 pub fn decode(
     tokenizer_id: u64,
     ids: Vec<u32>,
     skip_special_tokens: Option<bool>,
-) -> Result<String, String> {
+) -> Result<String, FlutterEmbedderError> {
     with_tokenizer(tokenizer_id, |tokenizer| {
         tokenizer
             .decode(&ids, skip_special_tokens.unwrap_or(true))
-            .map_err(|err| format!("Decode failed: {err}"))
+            .map_err(|err| err!(Tokenization, "Decode failed: {err}"))
     })
 }
 
@@ -218,7 +421,7 @@ pub fn decode_batch(
     tokenizer_id: u64,
     batch_ids: Vec<Vec<u32>>,
     skip_special_tokens: Option<bool>,
-) -> Result<Vec<String>, String> {
+) -> Result<Vec<String>, FlutterEmbedderError> {
     with_tokenizer(tokenizer_id, |tokenizer| {
         let sentences = batch_ids;
         let sentence_refs: Vec<&[u32]> = sentences.iter().map(|s| s.as_slice()).collect();
@@ -227,7 +430,7 @@ pub fn decode_batch(
                 sentence_refs.as_slice(),
                 skip_special_tokens.unwrap_or(true),
             )
-            .map_err(|err| format!("Decode batch failed: {err}"))
+            .map_err(|err| err!(Tokenization, "Decode batch failed: {err}"))
     })
 }
 
@@ -237,7 +440,7 @@ pub fn encode_batch_async(
     tokenizer_id: u64,
     texts: Vec<String>,
     add_special_tokens: Option<bool>,
-) -> Result<Vec<EncodeOutput>, String> {
+) -> Result<Vec<EncodeOutput>, FlutterEmbedderError> {
     encode_batch(tokenizer_id, texts, add_special_tokens)
 }
 
@@ -246,6 +449,332 @@ pub fn decode_batch_async(
     tokenizer_id: u64,
     batch_ids: Vec<Vec<u32>>,
     skip_special_tokens: Option<bool>,
-) -> Result<Vec<String>, String> {
+) -> Result<Vec<String>, FlutterEmbedderError> {
     decode_batch(tokenizer_id, batch_ids, skip_special_tokens)
 }
+
+/// Character (Unicode scalar) span of token `token_index` in `text`, the
+/// text `encoding` was produced from by `encode`. `None` for special and
+/// padding tokens. Spans cover whole characters even where a byte-level
+/// token splits one.
+#[flutter_rust_bridge::frb(sync)]
+pub fn token_to_chars(
+    text: String,
+    encoding: EncodeOutput,
+    token_index: u32,
+) -> Result<Option<TokenOffsets>, FlutterEmbedderError> {
+    let Some((start, end)) = token_byte_span(&text, &encoding, token_index as usize)? else {
+        return Ok(None);
+    };
+    let chars = || text.char_indices();
+    let start_char = chars()
+        .take_while(|(i, c)| i + c.len_utf8() <= start)
+        .count();
+    let end_char = chars().take_while(|&(i, _)| i < end).count();
+    Ok(Some(TokenOffsets {
+        start: start_char as u32,
+        end: end_char.max(start_char) as u32,
+    }))
+}
+
+/// Index of the token in `encoding` that covers character `char_index` of
+/// `text`, the text `encoding` was produced from by `encode`. `None` when
+/// no token does, e.g. for whitespace or truncated text.
+#[flutter_rust_bridge::frb(sync)]
+pub fn char_to_token(
+    text: String,
+    encoding: EncodeOutput,
+    char_index: u32,
+) -> Result<Option<u32>, FlutterEmbedderError> {
+    let Some((byte, _)) = text.char_indices().nth(char_index as usize) else {
+        return Err(err!(
+            InvalidInput,
+            "char_index {char_index} is past the end of the text"
+        ));
+    };
+    for index in 0..encoding.offsets.len() {
+        if let Some((start, end)) = token_byte_span(&text, &encoding, index)? {
+            if start <= byte && byte < end {
+                return Ok(Some(index as u32));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Byte span of a token that came from `text`, checked against its length.
+fn token_byte_span(
+    text: &str,
+    encoding: &EncodeOutput,
+    index: usize,
+) -> Result<Option<(usize, usize)>> {
+    let offsets = encoding.offsets.get(index).ok_or_else(|| {
+        err!(
+            InvalidInput,
+            "Token index {index} is out of range for {} tokens",
+            encoding.offsets.len()
+        )
+    })?;
+    let special = encoding.special_tokens_mask.get(index) == Some(&1);
+    let padding = encoding.attention_mask.get(index) == Some(&0);
+    if special || padding {
+        return Ok(None);
+    }
+    let (start, end) = (offsets.start as usize, offsets.end as usize);
+    if start > end || end > text.len() {
+        return Err(err!(
+            InvalidInput,
+            "Token offsets {start}..{end} don't fit a text of {} bytes",
+            text.len()
+        ));
+    }
+    Ok(Some((start, end)))
+}
+
+/// Which sequence(s) of a pair lose tokens when truncating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TruncationStrategy {
+    LongestFirst,
+    OnlyFirst,
+    OnlySecond,
+}
+
+impl From<TruncationStrategy> for tokenizers::TruncationStrategy {
+    fn from(value: TruncationStrategy) -> Self {
+        match value {
+            TruncationStrategy::LongestFirst => tokenizers::TruncationStrategy::LongestFirst,
+            TruncationStrategy::OnlyFirst => tokenizers::TruncationStrategy::OnlyFirst,
+            TruncationStrategy::OnlySecond => tokenizers::TruncationStrategy::OnlySecond,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaddingStrategy {
+    /// Pad every encoding in a batch to the longest one.
+    BatchLongest,
+    /// Pad every encoding to a fixed length.
+    Fixed,
+}
+
+/// Caps encodings at `max_length` tokens (special tokens included), e.g. to
+/// a model's context window. `stride` tokens overlap between the overflowing
+/// pieces. Replaces any truncation from the tokenizer JSON.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_truncation(
+    tokenizer_id: u64,
+    max_length: u32,
+    strategy: Option<TruncationStrategy>,
+    stride: Option<u32>,
+) -> Result<(), FlutterEmbedderError> {
+    if max_length == 0 {
+        return Err(err!(InvalidInput, "max_length must be positive"));
+    }
+    with_tokenizer_mut(tokenizer_id, |tokenizer| {
+        let params = tokenizers::TruncationParams {
+            max_length: max_length as usize,
+            strategy: strategy
+                .map(Into::into)
+                .unwrap_or(tokenizers::TruncationStrategy::LongestFirst),
+            stride: stride.unwrap_or(0) as usize,
+            ..Default::default()
+        };
+        tokenizer
+            .with_truncation(Some(params))
+            .map_err(|err| err!(InvalidInput, "Failed to set truncation: {err}"))?;
+        Ok(())
+    })
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn disable_truncation(tokenizer_id: u64) -> Result<(), FlutterEmbedderError> {
+    with_tokenizer_mut(tokenizer_id, |tokenizer| {
+        tokenizer
+            .with_truncation(None)
+            .map_err(|err| err!(InvalidInput, "Failed to disable truncation: {err}"))?;
+        Ok(())
+    })
+}
+
+/// Pads encodings with `pad_token`/`pad_id`; `fixed_length` is required
+/// with `PaddingStrategy::Fixed`. When only `pad_token` is given its id is
+/// looked up in the vocabulary; when neither is given the tokenizer's
+/// current padding token (or `[PAD]`/0) is kept.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_padding(
+    tokenizer_id: u64,
+    strategy: PaddingStrategy,
+    fixed_length: Option<u32>,
+    pad_id: Option<u32>,
+    pad_token: Option<String>,
+) -> Result<(), FlutterEmbedderError> {
+    let strategy = match (strategy, fixed_length) {
+        (PaddingStrategy::BatchLongest, _) => tokenizers::PaddingStrategy::BatchLongest,
+        (PaddingStrategy::Fixed, Some(length)) if length > 0 => {
+            tokenizers::PaddingStrategy::Fixed(length as usize)
+        }
+        (PaddingStrategy::Fixed, _) => {
+            return Err(err!(
+                InvalidInput,
+                "Fixed padding needs a positive fixed_length"
+            ))
+        }
+    };
+    with_tokenizer_mut(tokenizer_id, |tokenizer| {
+        let current = tokenizer.get_padding().cloned().unwrap_or_default();
+        let pad_id = match (pad_id, &pad_token) {
+            (Some(id), _) => id,
+            (None, Some(token)) => tokenizer
+                .token_to_id(token)
+                .ok_or_else(|| err!(InvalidInput, "Unknown pad token {token}"))?,
+            (None, None) => current.pad_id,
+        };
+        tokenizer.with_padding(Some(tokenizers::PaddingParams {
+            strategy,
+            pad_id,
+            pad_token: pad_token.unwrap_or(current.pad_token),
+            ..current
+        }));
+        Ok(())
+    })
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn disable_padding(tokenizer_id: u64) -> Result<(), FlutterEmbedderError> {
+    with_tokenizer_mut(tokenizer_id, |tokenizer| {
+        tokenizer.with_padding(None);
+        Ok(())
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TruncationInfo {
+    pub max_length: u32,
+    pub strategy: TruncationStrategy,
+    pub stride: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaddingInfo {
+    pub strategy: PaddingStrategy,
+    /// Set for `PaddingStrategy::Fixed`.
+    pub fixed_length: Option<u32>,
+    pub pad_id: u32,
+    pub pad_token: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenizerInfo {
+    pub id: u64,
+    /// Vocabulary size including added tokens.
+    pub vocab_size: u32,
+    pub truncation: Option<TruncationInfo>,
+    pub padding: Option<PaddingInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VocabEntry {
+    pub id: u32,
+    pub token: String,
+}
+
+/// Id of `token` in the vocabulary (added tokens included), or `None`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn token_to_id(tokenizer_id: u64, token: String) -> Result<Option<u32>, FlutterEmbedderError> {
+    with_tokenizer(tokenizer_id, |tokenizer| Ok(tokenizer.token_to_id(&token)))
+}
+
+/// Token with `id` in the vocabulary (added tokens included), or `None`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn id_to_token(tokenizer_id: u64, id: u32) -> Result<Option<String>, FlutterEmbedderError> {
+    with_tokenizer(tokenizer_id, |tokenizer| Ok(tokenizer.id_to_token(id)))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_vocab_size(
+    tokenizer_id: u64,
+    with_added_tokens: bool,
+) -> Result<u32, FlutterEmbedderError> {
+    with_tokenizer(tokenizer_id, |tokenizer| {
+        Ok(tokenizer.get_vocab_size(with_added_tokens) as u32)
+    })
+}
+
+/// One page of the vocabulary ordered by id: up to `limit` entries after
+/// skipping the first `offset`. Vocabularies run to hundreds of thousands
+/// of tokens, so fetch them in pages.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_vocab(
+    tokenizer_id: u64,
+    with_added_tokens: bool,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<VocabEntry>, FlutterEmbedderError> {
+    with_tokenizer(tokenizer_id, |tokenizer| {
+        let mut vocab: Vec<(String, u32)> =
+            tokenizer.get_vocab(with_added_tokens).into_iter().collect();
+        vocab.sort_unstable_by_key(|&(_, id)| id);
+        Ok(vocab
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|(token, id)| VocabEntry { id, token })
+            .collect())
+    })
+}
+
+/// Removes a tokenizer from the store, freeing its memory. Returns whether
+/// `tokenizer_id` was loaded.
+#[flutter_rust_bridge::frb(sync)]
+pub fn dispose_tokenizer(tokenizer_id: u64) -> Result<bool, FlutterEmbedderError> {
+    let mut guard = store()
+        .write()
+        .map_err(|e| err!(Internal, "Failed to acquire tokenizer store: {e}"))?;
+    Ok(guard.remove(&tokenizer_id).is_some())
+}
+
+/// Ids of all loaded tokenizers, ascending.
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_tokenizers() -> Result<Vec<u64>, FlutterEmbedderError> {
+    let guard = store()
+        .read()
+        .map_err(|e| err!(Internal, "Failed to acquire tokenizer store: {e}"))?;
+    let mut ids: Vec<u64> = guard.keys().copied().collect();
+    ids.sort_unstable();
+    Ok(ids)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn tokenizer_info(tokenizer_id: u64) -> Result<TokenizerInfo, FlutterEmbedderError> {
+    with_tokenizer(tokenizer_id, |tokenizer| {
+        let truncation = tokenizer.get_truncation().map(|params| TruncationInfo {
+            max_length: params.max_length as u32,
+            strategy: match params.strategy {
+                tokenizers::TruncationStrategy::LongestFirst => TruncationStrategy::LongestFirst,
+                tokenizers::TruncationStrategy::OnlyFirst => TruncationStrategy::OnlyFirst,
+                tokenizers::TruncationStrategy::OnlySecond => TruncationStrategy::OnlySecond,
+            },
+            stride: params.stride as u32,
+        });
+        let padding = tokenizer.get_padding().map(|params| {
+            let (strategy, fixed_length) = match params.strategy {
+                tokenizers::PaddingStrategy::BatchLongest => (PaddingStrategy::BatchLongest, None),
+                tokenizers::PaddingStrategy::Fixed(length) => {
+                    (PaddingStrategy::Fixed, Some(length as u32))
+                }
+            };
+            PaddingInfo {
+                strategy,
+                fixed_length,
+                pad_id: params.pad_id,
+                pad_token: params.pad_token.clone(),
+            }
+        });
+        Ok(TokenizerInfo {
+            id: tokenizer_id,
+            vocab_size: tokenizer.get_vocab_size(true) as u32,
+            truncation,
+            padding,
+        })
+    })
+}
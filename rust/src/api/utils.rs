@@ -1,11 +1,19 @@
 pub use ndarray::Array2;
 pub use ndarray::Array2 as FrbArray2Alias;
-use ndarray::{Array1, Axis};
+use ndarray::{s, Array1, Axis};
+use rayon::prelude::*;
+
+use crate::api::error::{err, FlutterEmbedderError, Result};
+use crate::api::projection::{pca, SplitMix64};
+
+const PCA_SEED: u64 = 42;
+/// Rows per tile in `find_duplicates`; a tile of similarities is 256 KiB.
+const DUPLICATE_BLOCK: usize = 256;
 
 #[flutter_rust_bridge::frb(sync)]
-pub fn cosine_distance(a: Vec<f32>, b: Vec<f32>) -> Result<f32, String> {
+pub fn cosine_distance(a: Vec<f32>, b: Vec<f32>) -> Result<f32, FlutterEmbedderError> {
     if a.len() != b.len() {
-        return Err("Vectors must have the same length".into());
+        return Err(err!(InvalidInput, "Vectors must have the same length"));
     }
 
     let mut dot_product = 0.0;
@@ -19,7 +27,10 @@ pub fn cosine_distance(a: Vec<f32>, b: Vec<f32>) -> Result<f32, String> {
     }
 
     if norm_sq_a == 0.0 || norm_sq_b == 0.0 {
-        return Err("Cannot compute cosine distance on zero vectors".into());
+        return Err(err!(
+            InvalidInput,
+            "Cannot compute cosine distance on zero vectors"
+        ));
     }
 
     let similarity = dot_product / (norm_sq_a.sqrt() * norm_sq_b.sqrt());
@@ -39,7 +50,7 @@ pub fn mean_pooling_ndarray(embeddings: &Array2<f32>, attention_mask: &[u32]) ->
         .map(|&v| if v == 0 { 0.0 } else { 1.0 })
         .collect();
     if mask.len() < seq_len {
-        mask.extend(std::iter::repeat(0.0).take(seq_len - mask.len()));
+        mask.extend(std::iter::repeat_n(0.0, seq_len - mask.len()));
     }
 
     let mask = Array1::from(mask);
@@ -80,6 +91,43 @@ pub fn mean_pooling_vec(embeddings: Vec<Vec<f32>>, attention_mask: Vec<u32>) ->
     }
 }
 
+pub(crate) fn embeddings_to_matrix(embeddings: &[Vec<f32>]) -> Result<Array2<f32>> {
+    let n = embeddings.len();
+    if n == 0 {
+        return Err(err!(InvalidInput, "Embeddings must not be empty"));
+    }
+    let dim = embeddings[0].len();
+    if dim == 0 {
+        return Err(err!(InvalidInput, "Embeddings must not be zero-length"));
+    }
+    if embeddings.iter().any(|e| e.len() != dim) {
+        return Err(err!(InvalidInput, "Vectors must have the same length"));
+    }
+    let flat: Vec<f32> = embeddings.iter().flatten().copied().collect();
+    Ok(Array2::from_shape_vec((n, dim), flat)?)
+}
+
+/// Plain PCA: projects `embeddings` onto their top `target_dims` principal
+/// axes, unscaled. Unlike `projection::project_embeddings` there is no layout
+/// refinement, so distances along each axis keep their meaning.
+#[flutter_rust_bridge::frb(sync)]
+pub fn pca_project(
+    embeddings: Vec<Vec<f32>>,
+    target_dims: u32,
+) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+    let data = embeddings_to_matrix(&embeddings)?;
+    let dims = target_dims as usize;
+    if dims == 0 || dims > data.ncols() {
+        return Err(err!(
+            InvalidInput,
+            "target_dims must be between 1 and {}",
+            data.ncols()
+        ));
+    }
+    let coords = pca(&data, dims, &mut SplitMix64::new(PCA_SEED));
+    Ok(coords.outer_iter().map(|row| row.to_vec()).collect())
+}
+
 #[flutter_rust_bridge::frb(sync)]
 pub fn normalize(embedding: &[f32]) -> Vec<f32> {
     let norm: f32 = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
@@ -94,3 +142,521 @@ where
 {
     a.iter().take(count).cloned().collect()
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreNormalization {
+    /// Rescale to [0, 1] using the batch minimum and maximum.
+    MinMax,
+    /// Standardise to zero mean and unit variance.
+    ZScore,
+    /// Replace each score by its percentile rank in [0, 1].
+    Percentile,
+}
+
+/// Normalizes a batch of scores so results from different retrievers
+/// (dense, sparse, BM25) can be fused on a comparable scale.
+#[flutter_rust_bridge::frb(sync)]
+pub fn normalize_scores(scores: Vec<f32>, method: ScoreNormalization) -> Vec<f32> {
+    match method {
+        ScoreNormalization::MinMax => min_max_normalize(&scores),
+        ScoreNormalization::ZScore => z_score_normalize(&scores),
+        ScoreNormalization::Percentile => percentile_normalize(&scores),
+    }
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn min_max_normalize(scores: &[f32]) -> Vec<f32> {
+    if scores.is_empty() {
+        return Vec::new();
+    }
+    let min = scores.iter().cloned().fold(f32::INFINITY, f32::min);
+    let max = scores.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    let range = max - min;
+    if range < 1e-9 {
+        // All scores are equal; treat them as equally (fully) relevant.
+        return vec![1.0; scores.len()];
+    }
+    scores.iter().map(|s| (s - min) / range).collect()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn z_score_normalize(scores: &[f32]) -> Vec<f32> {
+    if scores.is_empty() {
+        return Vec::new();
+    }
+    let n = scores.len() as f32;
+    let mean = scores.iter().sum::<f32>() / n;
+    let variance = scores.iter().map(|s| (s - mean) * (s - mean)).sum::<f32>() / n;
+    let std = variance.sqrt();
+    if std < 1e-9 {
+        return vec![0.0; scores.len()];
+    }
+    scores.iter().map(|s| (s - mean) / std).collect()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn percentile_normalize(scores: &[f32]) -> Vec<f32> {
+    let n = scores.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    if n == 1 {
+        return vec![1.0];
+    }
+    let scale = (n - 1) as f32;
+    ranks(scores).into_iter().map(|rank| rank / scale).collect()
+}
+
+/// 0-based rank of each value in ascending order; ties share the average
+/// rank of their group.
+pub(crate) fn ranks(values: &[f32]) -> Vec<f32> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+    let mut out = vec![0.0; values.len()];
+    let mut i = 0;
+    while i < order.len() {
+        let mut j = i;
+        while j + 1 < order.len() && values[order[j + 1]] == values[order[i]] {
+            j += 1;
+        }
+        let rank = (i + j) as f32 / 2.0;
+        for &idx in &order[i..=j] {
+            out[idx] = rank;
+        }
+        i = j + 1;
+    }
+    out
+}
+
+/// Sparse term-weight vector, e.g. SPLADE output: `values[i]` is the weight
+/// of vocabulary id `indices[i]`. Indices are sorted ascending.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SparseVector {
+    pub indices: Vec<u32>,
+    pub values: Vec<f32>,
+}
+
+/// Dot product of two sparse vectors over their shared indices.
+#[flutter_rust_bridge::frb(sync)]
+pub fn sparse_dot(a: SparseVector, b: SparseVector) -> Result<f32, FlutterEmbedderError> {
+    if a.indices.len() != a.values.len() || b.indices.len() != b.values.len() {
+        return Err(err!(
+            InvalidInput,
+            "indices and values must have the same length"
+        ));
+    }
+    if !is_sorted(&a.indices) || !is_sorted(&b.indices) {
+        return Err(err!(InvalidInput, "indices must be sorted ascending"));
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut dot = 0.0;
+    while i < a.indices.len() && j < b.indices.len() {
+        match a.indices[i].cmp(&b.indices[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                dot += a.values[i] * b.values[j];
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    Ok(dot)
+}
+
+fn is_sorted(indices: &[u32]) -> bool {
+    indices.windows(2).all(|w| w[0] < w[1])
+}
+
+/// Reciprocal rank fusion: every list adds `1 / (k + rank)` (rank starting at
+/// 1, taken from list order) to each id it contains. Input scores are only
+/// used by the caller to order the lists, so retrievers with incomparable
+/// score scales can be merged directly. `k` is commonly 60.
+#[flutter_rust_bridge::frb(sync)]
+pub fn reciprocal_rank_fusion(rankings: Vec<Vec<(String, f32)>>, k: u32) -> Vec<(String, f32)> {
+    let mut fused = FusedScores::new();
+    for ranking in rankings {
+        for (rank, (id, _)) in ranking.into_iter().enumerate() {
+            fused.add(id, 1.0 / (k as f32 + rank as f32 + 1.0));
+        }
+    }
+    fused.into_sorted()
+}
+
+/// Weighted score fusion: normalizes each result list with `method`, then
+/// sums `weights[i] * score` per id. Ids missing from a list contribute
+/// nothing for that list.
+#[flutter_rust_bridge::frb(sync)]
+pub fn weighted_score_fusion(
+    results: Vec<Vec<(String, f32)>>,
+    weights: Vec<f32>,
+    method: ScoreNormalization,
+) -> Result<Vec<(String, f32)>, FlutterEmbedderError> {
+    if results.len() != weights.len() {
+        return Err(err!(
+            InvalidInput,
+            "Expected {} weights, got {}",
+            results.len(),
+            weights.len()
+        ));
+    }
+    let mut fused = FusedScores::new();
+    for (list, weight) in results.into_iter().zip(weights) {
+        let (ids, scores): (Vec<String>, Vec<f32>) = list.into_iter().unzip();
+        for (id, score) in ids.into_iter().zip(normalize_scores(scores, method)) {
+            fused.add(id, weight * score);
+        }
+    }
+    Ok(fused.into_sorted())
+}
+
+/// Accumulates scores per id, remembering first-seen order for stable ties.
+struct FusedScores {
+    positions: std::collections::HashMap<String, usize>,
+    scores: Vec<(String, f32)>,
+}
+
+impl FusedScores {
+    fn new() -> Self {
+        Self {
+            positions: std::collections::HashMap::new(),
+            scores: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, id: String, score: f32) {
+        match self.positions.get(&id) {
+            Some(&i) => self.scores[i].1 += score,
+            None => {
+                self.positions.insert(id.clone(), self.scores.len());
+                self.scores.push((id, score));
+            }
+        }
+    }
+
+    fn into_sorted(mut self) -> Vec<(String, f32)> {
+        self.scores.sort_by(|a, b| b.1.total_cmp(&a.1));
+        self.scores
+    }
+}
+
+/// Symmetric int8 quantization of one embedding: `values[i] * scale`
+/// approximates the original component.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Int8Embedding {
+    pub values: Vec<i8>,
+    pub scale: f32,
+}
+
+/// Quantizes `embedding` to int8 with a per-vector scale (`max |x| / 127`),
+/// cutting storage 4x.
+#[flutter_rust_bridge::frb(sync)]
+pub fn quantize_int8(embedding: Vec<f32>) -> Int8Embedding {
+    let max_abs = embedding.iter().fold(0f32, |max, x| max.max(x.abs()));
+    if max_abs == 0.0 {
+        return Int8Embedding {
+            values: vec![0; embedding.len()],
+            scale: 1.0,
+        };
+    }
+    let scale = max_abs / 127.0;
+    Int8Embedding {
+        values: embedding
+            .iter()
+            .map(|x| (x / scale).round().clamp(-127.0, 127.0) as i8)
+            .collect(),
+        scale,
+    }
+}
+
+/// Approximate dot product of two int8-quantized embeddings; equals the
+/// cosine similarity when the originals were normalized.
+#[flutter_rust_bridge::frb(sync)]
+pub fn int8_dot(a: Int8Embedding, b: Int8Embedding) -> Result<f32, FlutterEmbedderError> {
+    if a.values.len() != b.values.len() {
+        return Err(err!(InvalidInput, "Vectors must have the same length"));
+    }
+    let dot: i32 = a
+        .values
+        .iter()
+        .zip(&b.values)
+        .map(|(&x, &y)| x as i32 * y as i32)
+        .sum();
+    Ok(dot as f32 * a.scale * b.scale)
+}
+
+/// Binary quantization: one bit per dimension (set when the component is
+/// positive), packed most-significant bit first, cutting storage 32x.
+#[flutter_rust_bridge::frb(sync)]
+pub fn quantize_binary(embedding: Vec<f32>) -> Vec<u8> {
+    embedding
+        .chunks(8)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .filter(|(_, &x)| x > 0.0)
+                .fold(0u8, |byte, (i, _)| byte | (0x80 >> i))
+        })
+        .collect()
+}
+
+/// Number of differing bits between two binary-quantized embeddings; lower
+/// is more similar.
+#[flutter_rust_bridge::frb(sync)]
+pub fn hamming_distance(a: Vec<u8>, b: Vec<u8>) -> Result<u32, FlutterEmbedderError> {
+    if a.len() != b.len() {
+        return Err(err!(InvalidInput, "Vectors must have the same length"));
+    }
+    Ok(a.iter().zip(&b).map(|(x, y)| (x ^ y).count_ones()).sum())
+}
+
+/// How `top_k` compares a document with the query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistanceMetric {
+    /// Cosine similarity; higher is closer.
+    Cosine,
+    /// Raw dot product; equals cosine for normalized embeddings, but cheaper.
+    DotProduct,
+    /// L2 distance; lower is closer.
+    Euclidean,
+    /// L1 distance; lower is closer.
+    Manhattan,
+}
+
+impl DistanceMetric {
+    fn score(self, a: &[f32], b: &[f32]) -> f32 {
+        match self {
+            DistanceMetric::Cosine => dot(a, b) / (norm(a) * norm(b)).max(1e-9),
+            DistanceMetric::DotProduct => dot(a, b),
+            DistanceMetric::Euclidean => a
+                .iter()
+                .zip(b)
+                .map(|(x, y)| (x - y) * (x - y))
+                .sum::<f32>()
+                .sqrt(),
+            DistanceMetric::Manhattan => a.iter().zip(b).map(|(x, y)| (x - y).abs()).sum(),
+        }
+    }
+
+    fn higher_is_closer(self) -> bool {
+        matches!(self, DistanceMetric::Cosine | DistanceMetric::DotProduct)
+    }
+}
+
+pub(crate) fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    DistanceMetric::Cosine.score(a, b)
+}
+
+/// Dot product of two vectors.
+#[flutter_rust_bridge::frb(sync)]
+pub fn dot_product(a: Vec<f32>, b: Vec<f32>) -> Result<f32, FlutterEmbedderError> {
+    pairwise(DistanceMetric::DotProduct, &a, &b)
+}
+
+/// Euclidean (L2) distance between two vectors.
+#[flutter_rust_bridge::frb(sync)]
+pub fn euclidean_distance(a: Vec<f32>, b: Vec<f32>) -> Result<f32, FlutterEmbedderError> {
+    pairwise(DistanceMetric::Euclidean, &a, &b)
+}
+
+/// Manhattan (L1) distance between two vectors.
+#[flutter_rust_bridge::frb(sync)]
+pub fn manhattan_distance(a: Vec<f32>, b: Vec<f32>) -> Result<f32, FlutterEmbedderError> {
+    pairwise(DistanceMetric::Manhattan, &a, &b)
+}
+
+fn pairwise(metric: DistanceMetric, a: &[f32], b: &[f32]) -> Result<f32> {
+    if a.len() != b.len() {
+        return Err(err!(InvalidInput, "Vectors must have the same length"));
+    }
+    Ok(metric.score(a, b))
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn norm(v: &[f32]) -> f32 {
+    dot(v, v).sqrt()
+}
+
+fn check_dimensions(expected: usize, vectors: &[Vec<f32>]) -> Result<()> {
+    match vectors.iter().position(|v| v.len() != expected) {
+        Some(i) => Err(err!(
+            InvalidInput,
+            "Vector {i} has dimension {}, expected {expected}",
+            vectors[i].len()
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Cosine similarity of every query against every document:
+/// `result[i][j]` compares `queries[i]` with `docs[j]`. Rows are computed in
+/// parallel.
+#[flutter_rust_bridge::frb(sync)]
+pub fn cosine_similarity_matrix(
+    queries: Vec<Vec<f32>>,
+    docs: Vec<Vec<f32>>,
+) -> Result<Vec<Vec<f32>>, FlutterEmbedderError> {
+    let Some(dim) = queries.first().or(docs.first()).map(Vec::len) else {
+        return Ok(Vec::new());
+    };
+    check_dimensions(dim, &queries)?;
+    check_dimensions(dim, &docs)?;
+    let docs: Vec<Vec<f32>> = docs.par_iter().map(|d| normalize(d)).collect();
+    Ok(queries
+        .par_iter()
+        .map(|q| {
+            let q = normalize(q);
+            docs.iter().map(|d| dot(&q, d)).collect()
+        })
+        .collect())
+}
+
+/// The `k` documents closest to `query` as `(index into docs, score)`, best
+/// first. The score is the metric's value, so it descends for similarities
+/// and ascends for distances. Documents are scored in parallel.
+#[flutter_rust_bridge::frb(sync)]
+pub fn top_k(
+    query: Vec<f32>,
+    docs: Vec<Vec<f32>>,
+    k: u32,
+    metric: DistanceMetric,
+) -> Result<Vec<(u32, f32)>, FlutterEmbedderError> {
+    check_dimensions(query.len(), &docs)?;
+    let mut scored: Vec<(u32, f32)> = docs
+        .par_iter()
+        .enumerate()
+        .map(|(i, doc)| (i as u32, metric.score(&query, doc)))
+        .collect();
+    let k = (k as usize).min(scored.len());
+    let higher_is_closer = metric.higher_is_closer();
+    let by_score = |a: &(u32, f32), b: &(u32, f32)| {
+        let order = if higher_is_closer {
+            b.1.total_cmp(&a.1)
+        } else {
+            a.1.total_cmp(&b.1)
+        };
+        order.then(a.0.cmp(&b.0))
+    };
+    if k < scored.len() {
+        scored.select_nth_unstable_by(k, by_score);
+        scored.truncate(k);
+    }
+    scored.sort_by(by_score);
+    Ok(scored)
+}
+
+/// Maximal marginal relevance: picks `k` candidates one at a time, each
+/// maximizing `lambda * sim(query, c) - (1 - lambda) * max sim(c, picked)`,
+/// so near-duplicates of earlier picks are skipped. `lambda = 1` is plain
+/// cosine top-k, `0` only maximizes diversity. Returns `(index into
+/// candidates, cosine similarity to query)` in pick order.
+#[flutter_rust_bridge::frb(sync)]
+pub fn mmr(
+    query_vec: Vec<f32>,
+    candidate_vecs: Vec<Vec<f32>>,
+    lambda: f32,
+    k: u32,
+) -> Result<Vec<(u32, f32)>, FlutterEmbedderError> {
+    if !(0.0..=1.0).contains(&lambda) {
+        return Err(err!(InvalidInput, "lambda must be between 0 and 1"));
+    }
+    check_dimensions(query_vec.len(), &candidate_vecs)?;
+    let query = normalize(&query_vec);
+    let candidates: Vec<Vec<f32>> = candidate_vecs.par_iter().map(|c| normalize(c)).collect();
+    let relevance: Vec<f32> = candidates.par_iter().map(|c| dot(&query, c)).collect();
+    // Highest similarity of each candidate to anything picked so far.
+    let mut redundancy = vec![f32::NEG_INFINITY; candidates.len()];
+    let mut picked = vec![false; candidates.len()];
+    let k = (k as usize).min(candidates.len());
+    let mut selection = Vec::with_capacity(k);
+    while selection.len() < k {
+        let score = |i: usize| {
+            let penalty = if selection.is_empty() {
+                0.0
+            } else {
+                redundancy[i]
+            };
+            lambda * relevance[i] - (1.0 - lambda) * penalty
+        };
+        let best = (0..candidates.len())
+            .filter(|&i| !picked[i])
+            .max_by(|&a, &b| score(a).total_cmp(&score(b)).then(b.cmp(&a)))
+            .expect("fewer picks than candidates");
+        picked[best] = true;
+        selection.push((best as u32, relevance[best]));
+        redundancy
+            .par_iter_mut()
+            .zip(&candidates)
+            .for_each(|(r, c)| *r = r.max(dot(&candidates[best], c)));
+    }
+    Ok(selection)
+}
+
+/// Groups near-identical embeddings, e.g. to dedupe imported documents
+/// before indexing. Pairs with cosine similarity at or above `threshold`
+/// (typically 0.95 or higher) share a cluster, transitively. Returns the
+/// clusters with two or more members as ascending indices, ordered by their
+/// first index.
+///
+/// Exact: similarities are computed as matrix products tile by tile, in
+/// parallel, so the full similarity matrix is never held in memory. Low
+/// thresholds match most pairs and are slow.
+pub fn find_duplicates(
+    embeddings: Vec<Vec<f32>>,
+    threshold: f32,
+) -> Result<Vec<Vec<u32>>, FlutterEmbedderError> {
+    if !(threshold > 0.0 && threshold <= 1.0) {
+        return Err(err!(InvalidInput, "threshold must be in (0, 1]"));
+    }
+    if embeddings.is_empty() {
+        return Ok(Vec::new());
+    }
+    let normalized: Vec<Vec<f32>> = embeddings.par_iter().map(|e| normalize(e)).collect();
+    let data = embeddings_to_matrix(&normalized)?;
+    let n = data.nrows();
+    let pairs: Vec<(usize, usize)> = (0..n)
+        .into_par_iter()
+        .step_by(DUPLICATE_BLOCK)
+        .flat_map_iter(|row_start| {
+            let rows = data.slice(s![row_start..(row_start + DUPLICATE_BLOCK).min(n), ..]);
+            let mut pairs = Vec::new();
+            // Tiles on and right of the diagonal; the rest are mirror images.
+            for col_start in (row_start..n).step_by(DUPLICATE_BLOCK) {
+                let cols = data.slice(s![col_start..(col_start + DUPLICATE_BLOCK).min(n), ..]);
+                let sims = rows.dot(&cols.t());
+                for ((i, j), &sim) in sims.indexed_iter() {
+                    let (a, b) = (row_start + i, col_start + j);
+                    if a < b && sim >= threshold {
+                        pairs.push((a, b));
+                    }
+                }
+            }
+            pairs
+        })
+        .collect();
+
+    // Union-find whose roots are the smallest index of their cluster.
+    let mut parent: Vec<usize> = (0..n).collect();
+    for (a, b) in pairs {
+        let (a, b) = (find_root(&mut parent, a), find_root(&mut parent, b));
+        parent[a.max(b)] = a.min(b);
+    }
+    let mut members: Vec<Vec<u32>> = vec![Vec::new(); n];
+    for i in 0..n {
+        let root = find_root(&mut parent, i);
+        members[root].push(i as u32);
+    }
+    Ok(members.into_iter().filter(|m| m.len() > 1).collect())
+}
+
+fn find_root(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
@@ -1,2 +1,2 @@
 pub mod api;
-mod frb_generated;
\ No newline at end of file
+mod frb_generated;
@@ -0,0 +1,74 @@
+use std::f32::consts::PI;
+
+use flutter_embedder::api::audio::{decode_wav, resample_audio};
+use flutter_embedder::api::error::ErrorKind;
+
+fn wav(channels: u16, sample_rate: u32, samples: &[i16]) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+    bytes.extend_from_slice(b"WAVE");
+    bytes.extend_from_slice(b"fmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&channels.to_le_bytes());
+    bytes.extend_from_slice(&sample_rate.to_le_bytes());
+    bytes.extend_from_slice(&(sample_rate * channels as u32 * 2).to_le_bytes());
+    bytes.extend_from_slice(&(channels * 2).to_le_bytes());
+    bytes.extend_from_slice(&16u16.to_le_bytes());
+    // An unknown chunk with odd size is skipped, including its padding.
+    bytes.extend_from_slice(b"LIST");
+    bytes.extend_from_slice(&3u32.to_le_bytes());
+    bytes.extend_from_slice(&[1, 2, 3, 0]);
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_len.to_le_bytes());
+    for sample in samples {
+        bytes.extend_from_slice(&sample.to_le_bytes());
+    }
+    bytes
+}
+
+fn zero_crossings(samples: &[f32]) -> usize {
+    samples
+        .windows(2)
+        .filter(|w| (w[0] < 0.0) != (w[1] < 0.0))
+        .count()
+}
+
+#[test]
+fn decodes_pcm_wav_to_mono() {
+    let clip = decode_wav(wav(2, 8000, &[16384, 0, -32768, -32768, 0, 16384])).unwrap();
+    assert_eq!(clip.sample_rate, 8000);
+    assert_eq!(clip.samples, vec![0.25, -1.0, 0.25]);
+
+    let err = decode_wav(b"RIFF\0\0\0\0WAVEdata".to_vec()).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidInput);
+    assert!(decode_wav(b"not audio".to_vec()).is_err());
+}
+
+#[test]
+fn resampling_keeps_pitch() {
+    // One second of 440 Hz at 48 kHz.
+    let tone: Vec<f32> = (0..48_000)
+        .map(|i| (2.0 * PI * 440.0 * i as f32 / 48_000.0).sin())
+        .collect();
+    let down = resample_audio(tone.clone(), 48_000, 16_000).unwrap();
+    assert_eq!(down.len(), 16_000);
+    assert!(zero_crossings(&down).abs_diff(880) <= 2);
+    let peak = down[100..15_900].iter().fold(0.0f32, |m, x| m.max(x.abs()));
+    assert!((peak - 1.0).abs() < 0.05, "peak {peak}");
+
+    // Above the new Nyquist frequency nothing survives downsampling.
+    let high: Vec<f32> = (0..48_000)
+        .map(|i| (2.0 * PI * 12_000.0 * i as f32 / 48_000.0).sin())
+        .collect();
+    let filtered = resample_audio(high, 48_000, 16_000).unwrap();
+    let peak = filtered[100..15_900]
+        .iter()
+        .fold(0.0f32, |m, x| m.max(x.abs()));
+    assert!(peak < 0.05, "peak {peak}");
+
+    assert_eq!(resample_audio(tone.clone(), 48_000, 48_000).unwrap(), tone);
+    assert!(resample_audio(tone, 0, 16_000).is_err());
+}
@@ -0,0 +1,57 @@
+use flutter_embedder::api::bench::{run_benchmark, BenchmarkConfig};
+use flutter_embedder::api::embeddings::EmbedderKind;
+use flutter_embedder::api::error::ErrorKind;
+use flutter_embedder::api::ort::init_ort;
+
+mod config;
+use config::{init_test_config, MINILM_EMBEDDING_MODEL_PATH, MINILM_TOKENIZER_PATH, ORT_LIB_PATH};
+
+#[test]
+fn minilm_benchmark_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("bench_ort".to_string(), Some(ort_path)).unwrap();
+    let config = BenchmarkConfig {
+        batch_sizes: vec![1, 4],
+        sequence_lengths: vec![8, 1000],
+        iterations: Some(3),
+        warmup_iterations: Some(1),
+        ort_options: None,
+    };
+    let report = run_benchmark(
+        EmbedderKind::MiniLm,
+        model_path.clone(),
+        tokenizer_path.clone(),
+        Some(config),
+    )
+    .unwrap();
+    assert_eq!(report.kind, EmbedderKind::MiniLm);
+    assert_eq!(report.dimension, 384);
+    assert_eq!(report.results.len(), 4);
+    let short = &report.results[0];
+    assert_eq!((short.batch_size, short.sequence_length), (1, 8));
+    // Plus [CLS] and [SEP].
+    assert_eq!(short.tokens_per_text, 10);
+    // Truncated to the model limit.
+    assert!(report.results[1].tokens_per_text <= 512);
+    for result in &report.results {
+        assert!(result.p50_ms > 0.0 && result.p50_ms <= result.p95_ms);
+        assert!(result.tokens_per_second > 0.0);
+    }
+
+    let invalid = BenchmarkConfig {
+        batch_sizes: vec![0],
+        ..Default::default()
+    };
+    let err = run_benchmark(
+        EmbedderKind::MiniLm,
+        model_path,
+        tokenizer_path,
+        Some(invalid),
+    )
+    .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidInput);
+}
@@ -1,5 +1,12 @@
 use flutter_embedder::api::embeddings::bge::BgeEmbedder;
-use flutter_embedder::api::ort::init_ort;
+use flutter_embedder::api::error::ErrorKind;
+use flutter_embedder::api::ort::{
+    available_execution_providers, clear_optimized_model_cache, device_capabilities, init_ort,
+    inspect_onnx_model, take_session_warnings, CoreMlComputeUnits, ExecutionProviderConfig,
+    ExecutionProviderKind, NamedTensor, OrtInitOptions, OrtSessionHandle, OrtSessionOptions,
+    TensorDtype, Workload,
+};
+use flutter_embedder::api::tokenizer::{encode, load_tokenizer_from_file};
 use ndarray::{Array, Array2};
 
 mod config;
@@ -14,7 +21,7 @@ fn bge_embedding_test() {
     let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
 
     init_ort("bge_ort".to_string(), Some(ort_path)).unwrap();
-    let mut embedder = BgeEmbedder::create(model_path, tokenizer_path).unwrap();
+    let embedder = BgeEmbedder::create(model_path, tokenizer_path).unwrap();
 
     // Basic embedding example.
     let texts = ["Hello world.".to_string(), "Example sentence.".to_string()];
@@ -22,6 +29,15 @@ fn bge_embedding_test() {
     assert_eq!(outputs.len(), 2);
     let embedding_size = outputs[0].len();
     assert_eq!(embedding_size, 384);
+    assert_eq!(embedder.dimension().unwrap(), embedding_size);
+
+    // Duplicates in a batch get the same vector as a single call.
+    let repeated = embedder
+        .embed(vec![texts[0].clone(), texts[1].clone(), texts[0].clone()])
+        .unwrap();
+    assert_eq!(repeated.len(), 3);
+    assert_eq!(repeated[0], repeated[2]);
+    assert_eq!(repeated[..2], outputs[..]);
 
     // Retrieval example with query prefix.
     let docs = [
@@ -38,6 +54,12 @@ fn bge_embedding_test() {
 
     let query = BgeEmbedder::format_query("What is a panda?".to_string());
     let query_embedding = embedder.embed(vec![query]).unwrap();
+    assert_eq!(
+        embedder
+            .embed_queries(vec!["What is a panda?".to_string()])
+            .unwrap(),
+        query_embedding
+    );
 
     let query_vec = query_embedding[0].to_vec();
     let doc_matrix: Array2<f32> = Array::from_shape_vec(
@@ -56,3 +78,453 @@ fn bge_embedding_test() {
         .unwrap();
     assert_eq!(best_idx, 1);
 }
+
+#[test]
+fn bge_workload_routing_test() {
+    init_test_config();
+    let tokenizer_path: String = BGE_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = BGE_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("bge_ort".to_string(), Some(ort_path)).unwrap();
+    let mut embedder = BgeEmbedder::create(model_path.clone(), tokenizer_path).unwrap();
+    let bulk_options = OrtInitOptions {
+        environment: None,
+        session: Some(OrtSessionOptions {
+            intra_threads: Some(1),
+            optimization_level: Some(1),
+            ..Default::default()
+        }),
+    };
+    embedder
+        .add_session(Workload::Bulk, model_path, Some(bulk_options))
+        .unwrap();
+
+    let texts = vec!["Hello world.".to_string(), "Example sentence.".to_string()];
+    let interactive = embedder.embed(texts.clone()).unwrap();
+    let bulk = embedder.embed_with_workload(texts, Workload::Bulk).unwrap();
+    for (a, b) in interactive.iter().zip(&bulk) {
+        let sim: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        assert!(sim > 0.999, "similarity {sim}");
+    }
+    assert!(embedder.remove_session(Workload::Bulk));
+    assert!(!embedder.remove_session(Workload::Bulk));
+}
+
+#[test]
+fn bge_session_pool_test() {
+    init_test_config();
+    let tokenizer_path: String = BGE_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = BGE_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("bge_ort".to_string(), Some(ort_path)).unwrap();
+    let mut embedder = BgeEmbedder::create(model_path.clone(), tokenizer_path).unwrap();
+    let texts = vec!["Hello world.".to_string()];
+    let expected = embedder.embed(texts.clone()).unwrap();
+
+    embedder
+        .set_pool_size(Workload::Interactive, 3, model_path.clone(), None)
+        .unwrap();
+    assert_eq!(embedder.pool_size(Workload::Interactive), 3);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| scope.spawn(|| embedder.embed(texts.clone()).unwrap()))
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), expected);
+        }
+    });
+    embedder
+        .set_pool_size(Workload::Interactive, 0, model_path, None)
+        .unwrap();
+    assert_eq!(embedder.pool_size(Workload::Interactive), 1);
+}
+
+#[test]
+fn bge_close_test() {
+    init_test_config();
+    let tokenizer_path: String = BGE_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = BGE_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("bge_ort".to_string(), Some(ort_path)).unwrap();
+    let mut embedder = BgeEmbedder::create(model_path, tokenizer_path).unwrap();
+    assert!(!embedder.is_closed());
+    embedder.close();
+    assert!(embedder.is_closed());
+
+    let error = embedder
+        .embed(vec!["Hello world.".to_string()])
+        .unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Closed);
+    assert!(embedder.model_info().is_err());
+}
+
+#[test]
+fn bge_reload_model_test() {
+    init_test_config();
+    let tokenizer_path: String = BGE_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = BGE_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("bge_ort".to_string(), Some(ort_path)).unwrap();
+    let mut embedder = BgeEmbedder::create(model_path.clone(), tokenizer_path.clone()).unwrap();
+    let texts = vec!["Hello world.".to_string()];
+    let expected = embedder.embed(texts.clone()).unwrap();
+
+    assert!(embedder
+        .reload_model("missing.onnx".to_string(), None, None)
+        .is_err());
+    assert_eq!(embedder.embed(texts.clone()).unwrap(), expected);
+
+    embedder
+        .reload_model(model_path.clone(), None, None)
+        .unwrap();
+    assert_eq!(embedder.embed(texts.clone()).unwrap(), expected);
+
+    embedder.close();
+    assert!(embedder
+        .reload_model(model_path.clone(), None, None)
+        .is_err());
+    embedder
+        .reload_model(model_path, Some(tokenizer_path), None)
+        .unwrap();
+    assert!(!embedder.is_closed());
+    assert_eq!(embedder.embed(texts).unwrap(), expected);
+}
+
+#[test]
+fn bge_embed_async_test() {
+    init_test_config();
+    let tokenizer_path: String = BGE_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = BGE_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("bge_ort".to_string(), Some(ort_path)).unwrap();
+    let embedder = BgeEmbedder::create(model_path, tokenizer_path).unwrap();
+
+    let texts = vec!["Hello world.".to_string()];
+    let sync = embedder.embed(texts.clone()).unwrap();
+    let async_outputs = embedder.embed_async(texts).unwrap();
+    assert_eq!(sync, async_outputs);
+}
+
+#[test]
+fn bge_embed_with_stats_test() {
+    init_test_config();
+    let tokenizer_path: String = BGE_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = BGE_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("bge_ort".to_string(), Some(ort_path)).unwrap();
+    let embedder = BgeEmbedder::create(model_path, tokenizer_path).unwrap();
+    let texts = vec!["Hello world.".to_string(), "Example sentence.".to_string()];
+    let result = embedder.embed_with_stats(texts.clone()).unwrap();
+    assert_eq!(result.embeddings, embedder.embed(texts).unwrap());
+    assert!(result.stats.tokens > 0);
+    assert!(result.stats.inference_ms > 0.0);
+    assert!(result.stats.tokens_per_second > 0.0);
+    if cfg!(target_os = "linux") {
+        assert!(result.stats.peak_memory_bytes.unwrap() > 0);
+    }
+}
+
+#[test]
+fn bge_memory_options_test() {
+    init_test_config();
+    let tokenizer_path: String = BGE_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = BGE_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("bge_ort".to_string(), Some(ort_path)).unwrap();
+    let texts = vec!["Hello world.".to_string()];
+    let embedder = BgeEmbedder::create(model_path.clone(), tokenizer_path.clone()).unwrap();
+    let expected = embedder.embed(texts.clone()).unwrap();
+
+    let options = OrtInitOptions {
+        environment: None,
+        session: Some(OrtSessionOptions {
+            memory_arena: Some(false),
+            memory_pattern: Some(false),
+            ..Default::default()
+        }),
+    };
+    let lean =
+        BgeEmbedder::create_with_options(model_path.clone(), tokenizer_path.clone(), Some(options))
+            .unwrap();
+    let outputs = lean.embed(texts.clone()).unwrap();
+    for (a, b) in outputs[0].iter().zip(&expected[0]) {
+        assert!((a - b).abs() < 1e-5);
+    }
+
+    // The arena setting applies to an explicit CPU provider too.
+    let options = OrtInitOptions {
+        environment: None,
+        session: Some(OrtSessionOptions {
+            memory_arena: Some(false),
+            execution_providers: vec![ExecutionProviderConfig {
+                kind: ExecutionProviderKind::Cpu,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }),
+    };
+    let cpu = BgeEmbedder::create_with_options(model_path, tokenizer_path, Some(options)).unwrap();
+    let outputs = cpu.embed(texts).unwrap();
+    for (a, b) in outputs[0].iter().zip(&expected[0]) {
+        assert!((a - b).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn bge_deterministic_test() {
+    init_test_config();
+    let tokenizer_path: String = BGE_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = BGE_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("bge_ort".to_string(), Some(ort_path)).unwrap();
+    let options = OrtInitOptions {
+        environment: None,
+        session: Some(OrtSessionOptions {
+            deterministic: Some(true),
+            ..Default::default()
+        }),
+    };
+    let texts = vec!["Hello world.".to_string()];
+    let first = BgeEmbedder::create_with_options(
+        model_path.clone(),
+        tokenizer_path.clone(),
+        Some(options.clone()),
+    )
+    .unwrap();
+    let second =
+        BgeEmbedder::create_with_options(model_path, tokenizer_path, Some(options)).unwrap();
+    let expected = first.embed(texts.clone()).unwrap();
+    assert_eq!(first.embed(texts.clone()).unwrap(), expected);
+    assert_eq!(second.embed(texts.clone()).unwrap(), expected);
+
+    // The same text alone and inside a mixed batch gives the same bits,
+    // duplicates and longer neighbours included.
+    let mixed = vec![
+        "A much longer sentence that would otherwise pad the batch.".to_string(),
+        texts[0].clone(),
+        "Short.".to_string(),
+        texts[0].clone(),
+    ];
+    let outputs = first.embed(mixed).unwrap();
+    assert_eq!(outputs[1], expected[0]);
+    assert_eq!(outputs[3], expected[0]);
+}
+
+#[test]
+fn bge_optimized_model_cache_test() {
+    init_test_config();
+    let tokenizer_path: String = BGE_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = BGE_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("bge_ort".to_string(), Some(ort_path)).unwrap();
+    let dir = std::env::temp_dir().join(format!("optimized_cache_{}", std::process::id()));
+    let cache_dir = dir.to_string_lossy().to_string();
+    let options = OrtInitOptions {
+        environment: None,
+        session: Some(OrtSessionOptions {
+            optimized_model_cache_dir: Some(cache_dir.clone()),
+            ..Default::default()
+        }),
+    };
+    let texts = vec!["Hello world.".to_string()];
+    let first = BgeEmbedder::create_with_options(
+        model_path.clone(),
+        tokenizer_path.clone(),
+        Some(options.clone()),
+    )
+    .unwrap();
+    let expected = first.embed(texts.clone()).unwrap();
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+    let cached =
+        BgeEmbedder::create_with_options(model_path, tokenizer_path, Some(options)).unwrap();
+    let outputs = cached.embed(texts).unwrap();
+    for (a, b) in outputs[0].iter().zip(&expected[0]) {
+        assert!((a - b).abs() < 1e-5);
+    }
+
+    assert_eq!(clear_optimized_model_cache(cache_dir.clone()).unwrap(), 1);
+    assert_eq!(clear_optimized_model_cache(cache_dir).unwrap(), 0);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn bge_execution_provider_fallback_test() {
+    init_test_config();
+    let tokenizer_path: String = BGE_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = BGE_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("bge_ort".to_string(), Some(ort_path)).unwrap();
+    let options = OrtInitOptions {
+        environment: None,
+        session: Some(OrtSessionOptions {
+            execution_providers: vec![
+                ExecutionProviderConfig {
+                    kind: ExecutionProviderKind::CoreMl,
+                    coreml_compute_units: Some(CoreMlComputeUnits::CpuAndNeuralEngine),
+                    ..Default::default()
+                },
+                ExecutionProviderConfig {
+                    kind: ExecutionProviderKind::Cpu,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }),
+    };
+    let embedder =
+        BgeEmbedder::create_with_options(model_path, tokenizer_path, Some(options)).unwrap();
+    let outputs = embedder.embed(vec!["Hello world.".to_string()]).unwrap();
+    assert_eq!(outputs[0].len(), 384);
+
+    if !cfg!(target_vendor = "apple") {
+        let warnings = take_session_warnings();
+        assert!(
+            warnings.iter().any(|w| w.contains("CoreML")),
+            "warnings {warnings:?}"
+        );
+    }
+}
+
+#[test]
+fn execution_provider_discovery_test() {
+    init_test_config();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+    init_ort("bge_ort".to_string(), Some(ort_path)).unwrap();
+
+    let names = available_execution_providers();
+    assert!(names.contains(&"CPUExecutionProvider".to_string()));
+    let capabilities = device_capabilities();
+    assert!(capabilities.cpu_cores >= 1);
+    assert_eq!(capabilities.execution_providers.len(), names.len());
+    assert_eq!(
+        capabilities.execution_providers[0],
+        ExecutionProviderKind::Cpu
+    );
+    if !cfg!(target_os = "android") {
+        assert!(!capabilities
+            .execution_providers
+            .contains(&ExecutionProviderKind::Nnapi));
+    }
+}
+
+#[test]
+fn bge_model_info_test() {
+    init_test_config();
+    let tokenizer_path: String = BGE_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = BGE_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("bge_ort".to_string(), Some(ort_path)).unwrap();
+    let embedder = BgeEmbedder::create(model_path.clone(), tokenizer_path).unwrap();
+
+    let info = embedder.model_info().unwrap();
+    let input_ids = info.inputs.iter().find(|i| i.name == "input_ids").unwrap();
+    assert_eq!(input_ids.dtype, "i64");
+    assert_eq!(input_ids.shape.len(), 2);
+    assert!(info.outputs.iter().any(|o| o.shape.last() == Some(&384)));
+
+    assert_eq!(inspect_onnx_model(model_path).unwrap(), info);
+    assert!(inspect_onnx_model("missing.onnx".to_string()).is_err());
+
+    // A corrupt model is a load error, not an inference one.
+    let corrupt = std::env::temp_dir().join("flutter_embedder_corrupt.onnx");
+    std::fs::write(&corrupt, b"not an onnx model").unwrap();
+    let error = inspect_onnx_model(corrupt.to_string_lossy().to_string()).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::ModelLoad);
+    let error = OrtSessionHandle::create_from_bytes(b"not an onnx model".to_vec(), None)
+        .err()
+        .unwrap();
+    assert_eq!(error.kind, ErrorKind::ModelLoad);
+    std::fs::remove_file(corrupt).unwrap();
+}
+
+#[test]
+fn bge_raw_session_test() {
+    init_test_config();
+    let tokenizer_path: String = BGE_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = BGE_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("bge_ort".to_string(), Some(ort_path)).unwrap();
+    let embedder = BgeEmbedder::create(model_path.clone(), tokenizer_path.clone()).unwrap();
+    let mut session = OrtSessionHandle::create(model_path.clone(), None).unwrap();
+    assert_eq!(
+        session.model_info().unwrap(),
+        embedder.model_info().unwrap()
+    );
+
+    let tokenizer_id = load_tokenizer_from_file(tokenizer_path).unwrap();
+    let encoding = encode(tokenizer_id, "Hello world.".to_string(), None).unwrap();
+    let len = encoding.ids.len() as i64;
+    let input = |name: &str, data: Vec<i64>| NamedTensor {
+        name: name.to_string(),
+        dtype: TensorDtype::Int64,
+        shape: vec![1, len],
+        i64_data: data,
+        ..Default::default()
+    };
+    let ids = encoding.ids.iter().map(|&id| id as i64).collect();
+    let mut inputs = vec![
+        input("input_ids", ids),
+        input("attention_mask", vec![1; len as usize]),
+        input("token_type_ids", vec![0; len as usize]),
+    ];
+    let outputs = session.run(inputs.clone(), None).unwrap();
+    let hidden = &outputs[0];
+    assert_eq!(hidden.dtype, TensorDtype::Float32);
+    assert_eq!(hidden.shape, vec![1, len, 384]);
+    assert_eq!(hidden.f32_data.len(), len as usize * 384);
+
+    // BGE embeddings are the normalized CLS vector.
+    let cls = &hidden.f32_data[..384];
+    let norm = cls.iter().map(|v| v * v).sum::<f32>().sqrt();
+    let expected = embedder.embed(vec!["Hello world.".to_string()]).unwrap();
+    for (a, b) in cls.iter().zip(&expected[0]) {
+        assert!((a / norm - b).abs() < 1e-4);
+    }
+
+    // Uint8 data is converted to the model's int64 inputs.
+    inputs[1] = NamedTensor {
+        name: "attention_mask".to_string(),
+        dtype: TensorDtype::Uint8,
+        shape: vec![1, len],
+        u8_data: vec![1; len as usize],
+        ..Default::default()
+    };
+    let named = session
+        .run(inputs.clone(), Some(vec![hidden.name.clone()]))
+        .unwrap();
+    assert_eq!(named, outputs[..1]);
+
+    let err = session
+        .run(inputs.clone(), Some(vec!["missing".to_string()]))
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MissingOutput);
+    inputs[0].name = "missing".to_string();
+    let err = session.run(inputs.clone(), None).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidInput);
+    inputs[0].name = "input_ids".to_string();
+    inputs[0].shape = vec![1, len + 1];
+    let err = session.run(inputs.clone(), None).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ShapeMismatch);
+
+    let bytes = std::fs::read(&model_path).unwrap();
+    let from_bytes = OrtSessionHandle::create_from_bytes(bytes, None).unwrap();
+    assert_eq!(from_bytes.model_info().unwrap().inputs.len(), 3);
+
+    session.close();
+    assert!(session.is_closed());
+    let err = session.run(inputs, None).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Closed);
+}
@@ -0,0 +1,95 @@
+use flutter_embedder::api::bm25::{Bm25Index, Bm25Options};
+use flutter_embedder::api::tokenizer::load_tokenizer_from_json;
+
+fn corpus() -> Bm25Index {
+    let mut index = Bm25Index::new(None).unwrap();
+    index
+        .add_many(
+            vec!["a".into(), "b".into(), "c".into()],
+            vec![
+                "The quick brown fox jumps over the lazy dog".into(),
+                "Error code E1234 when syncing the database".into(),
+                "A fox, a fox! Foxes everywhere in the brown forest".into(),
+            ],
+        )
+        .unwrap();
+    index
+}
+
+fn ids(hits: &[flutter_embedder::api::search::SearchHit]) -> Vec<&str> {
+    hits.iter().map(|h| h.id.as_str()).collect()
+}
+
+#[test]
+fn ranks_by_term_statistics() {
+    let index = corpus();
+    assert_eq!(index.len(), 3);
+
+    // Exact identifiers match case-insensitively.
+    let hits = index.search("e1234".into(), 10).unwrap();
+    assert_eq!(ids(&hits), ["b"]);
+
+    // Higher term frequency in a comparable document wins.
+    let hits = index.search("fox".into(), 10).unwrap();
+    assert_eq!(ids(&hits), ["c", "a"]);
+    assert!(hits[0].score > hits[1].score);
+
+    // Rare terms outweigh common ones.
+    let hits = index.search("the database".into(), 1).unwrap();
+    assert_eq!(ids(&hits), ["b"]);
+
+    assert!(index.search("unrelated".into(), 10).unwrap().is_empty());
+}
+
+#[test]
+fn remove_and_replace() {
+    let mut index = corpus();
+    assert!(index.remove("c".into()));
+    assert!(!index.remove("c".into()));
+    assert_eq!(ids(&index.search("fox".into(), 10).unwrap()), ["a"]);
+
+    index
+        .add("a".into(), "database migration notes".into())
+        .unwrap();
+    assert_eq!(index.len(), 2);
+    assert!(index.search("fox".into(), 10).unwrap().is_empty());
+    assert_eq!(index.search("database".into(), 10).unwrap().len(), 2);
+
+    assert!(index.add_many(vec!["x".into()], vec![]).is_err());
+    assert!(Bm25Index::new(Some(Bm25Options {
+        b: Some(2.0),
+        ..Default::default()
+    }))
+    .is_err());
+}
+
+#[test]
+fn tokenizer_analysis() {
+    let json = r#"{
+        "version": "1.0",
+        "truncation": null,
+        "padding": null,
+        "added_tokens": [],
+        "normalizer": null,
+        "pre_tokenizer": { "type": "Whitespace" },
+        "post_processor": null,
+        "decoder": null,
+        "model": {
+            "type": "WordLevel",
+            "vocab": { "[UNK]": 0, "alpha": 1, "beta": 2 },
+            "unk_token": "[UNK]"
+        }
+    }"#;
+    let tokenizer = load_tokenizer_from_json(json.to_string()).unwrap();
+    let mut index = Bm25Index::new(Some(Bm25Options {
+        tokenizer_id: Some(tokenizer),
+        ..Default::default()
+    }))
+    .unwrap();
+    index.add("1".into(), "alpha beta".into()).unwrap();
+    index.add("2".into(), "beta gamma".into()).unwrap();
+
+    assert_eq!(ids(&index.search("alpha".into(), 10).unwrap()), ["1"]);
+    // Out-of-vocabulary words all become [UNK].
+    assert_eq!(ids(&index.search("delta".into(), 10).unwrap()), ["2"]);
+}
@@ -0,0 +1,44 @@
+use flutter_embedder::api::cache::EmbeddingCache;
+
+fn cache_dir(name: &str) -> String {
+    let path = std::env::temp_dir().join(format!("{name}_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&path);
+    path.to_string_lossy().into_owned()
+}
+
+#[test]
+fn put_get_and_reopen() {
+    let dir = cache_dir("embedding_cache");
+    let mut cache = EmbeddingCache::open(dir.clone(), "bge-small@v1".into()).unwrap();
+    assert!(cache.is_empty());
+    cache.put("hello".into(), vec![1.0, 0.0]).unwrap();
+    cache.put("world".into(), vec![0.0, 1.0]).unwrap();
+    cache.put("hello".into(), vec![0.6, 0.8]).unwrap();
+    assert!(cache.put("short".into(), vec![1.0]).is_err());
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get("hello".into()), Some(vec![0.6, 0.8]));
+    assert_eq!(cache.get("missing".into()), None);
+    cache.compact().unwrap();
+    drop(cache);
+
+    let cache = EmbeddingCache::open(dir.clone(), "bge-small@v1".into()).unwrap();
+    assert_eq!(cache.model_tag(), "bge-small@v1");
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get("world".into()), Some(vec![0.0, 1.0]));
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn model_change_invalidates() {
+    let dir = cache_dir("embedding_cache_tag");
+    let mut cache = EmbeddingCache::open(dir.clone(), "bge-small@v1".into()).unwrap();
+    cache.put("hello".into(), vec![1.0, 0.0]).unwrap();
+    drop(cache);
+
+    let cache = EmbeddingCache::open(dir.clone(), "bge-small@v2".into()).unwrap();
+    assert!(cache.is_empty());
+    drop(cache);
+    let cache = EmbeddingCache::open(dir.clone(), "bge-small@v1".into()).unwrap();
+    assert!(cache.is_empty());
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,115 @@
+use flutter_embedder::api::calibration::{
+    apply_calibration, calibrate_similarities, calibration_from_json, calibration_to_json,
+    fit_calibration, fit_curve_calibration, fit_min_max_calibration, fit_temperature_calibration,
+    sigmoid_calibrate, softmax_with_temperature, CalibrationMethod, CalibrationParams,
+    CalibrationPoint, SimilarityCalibration,
+};
+
+#[test]
+fn sigmoid_and_softmax_calibration() {
+    let probs = sigmoid_calibrate(vec![-4.0, 0.0, 4.0], None).unwrap();
+    assert!(probs[0] < 0.05 && (probs[1] - 0.5).abs() < 1e-6 && probs[2] > 0.95);
+
+    let softer = sigmoid_calibrate(vec![4.0], Some(4.0)).unwrap();
+    assert!(softer[0] < probs[2]);
+
+    let dist = softmax_with_temperature(vec![1.0, 2.0, 3.0], Some(0.5)).unwrap();
+    assert!((dist.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+    assert!(dist[2] > dist[1] && dist[1] > dist[0]);
+
+    assert!(sigmoid_calibrate(vec![1.0], Some(0.0)).is_err());
+}
+
+#[test]
+fn fit_calibration_orders_labels() {
+    let scores = vec![-3.0, -2.5, -1.0, -0.5, 0.5, 1.0, 2.5, 3.0];
+    let labels = vec![false, false, false, true, false, true, true, true];
+    let params = fit_calibration(scores.clone(), labels).unwrap();
+    assert!(params.scale > 0.0);
+
+    let calibrated = apply_calibration(scores, params);
+    assert!(calibrated.windows(2).all(|w| w[0] <= w[1]));
+    assert!(calibrated[0] < 0.2 && calibrated[7] > 0.8);
+
+    let identity = apply_calibration(vec![0.0], CalibrationParams::default());
+    assert!((identity[0] - 0.5).abs() < 1e-6);
+    assert!(fit_calibration(vec![1.0, 2.0], vec![true, true]).is_err());
+}
+
+#[test]
+fn similarity_calibration_fits_and_round_trips() {
+    let sims: Vec<f32> = (0..=100).map(|i| 0.3 + i as f32 * 0.005).collect();
+    let min_max = fit_min_max_calibration(sims.clone()).unwrap();
+    let scores = calibrate_similarities(vec![0.0, 0.55, 1.0], min_max.clone());
+    assert_eq!(scores[0], 0.0);
+    assert!((scores[1] - 0.5).abs() < 0.02);
+    assert_eq!(scores[2], 1.0);
+    assert!(fit_min_max_calibration(vec![0.5]).is_err());
+
+    let sims = vec![0.55, 0.6, 0.62, 0.7, 0.68, 0.75, 0.8, 0.85];
+    let labels = vec![false, false, false, true, false, true, true, true];
+    let temperature = fit_temperature_calibration(sims.clone(), labels.clone()).unwrap();
+    assert_eq!(temperature.method, CalibrationMethod::Temperature);
+    let center = temperature.center;
+    assert!(center > 0.6 && center < 0.75);
+    let scores = calibrate_similarities(vec![0.5, center, 0.9], temperature);
+    assert!(scores[0] < 0.5 && (scores[1] - 0.5).abs() < 1e-5 && scores[2] > 0.5);
+    let reversed = labels.iter().map(|l| !l).collect();
+    assert!(fit_temperature_calibration(sims.clone(), reversed).is_err());
+
+    let curve = fit_curve_calibration(sims, labels).unwrap();
+    assert_eq!(curve.method, CalibrationMethod::Curve);
+    assert!(curve.points.windows(2).all(|w| w[0].score <= w[1].score));
+    let scores = calibrate_similarities(vec![0.0, 0.72, 1.0], curve.clone());
+    assert_eq!(scores[0], 0.0);
+    assert!(scores[1] > 0.0 && scores[1] < 1.0);
+    assert_eq!(scores[2], 1.0);
+
+    let json = calibration_to_json(curve.clone()).unwrap();
+    assert!(json.contains(r#""method":"curve""#));
+    assert_eq!(calibration_from_json(json).unwrap(), curve);
+    let json = calibration_to_json(min_max.clone()).unwrap();
+    assert_eq!(calibration_from_json(json).unwrap(), min_max);
+    let invalid_temperature =
+        r#"{"method":"temperature","points":[],"center":0.5,"temperature":0}"#;
+    assert!(calibration_from_json(invalid_temperature.into()).is_err());
+    let empty_curve = r#"{"method":"curve","points":[],"center":0,"temperature":1}"#;
+    assert!(calibration_from_json(empty_curve.into()).is_err());
+    assert!(calibration_from_json(r#"{"method":"curve"}"#.into()).is_err());
+
+    let manual = SimilarityCalibration {
+        method: CalibrationMethod::Curve,
+        points: vec![
+            CalibrationPoint {
+                similarity: 0.2,
+                score: 0.0,
+            },
+            CalibrationPoint {
+                similarity: 0.6,
+                score: 1.0,
+            },
+        ],
+        center: 0.0,
+        temperature: 1.0,
+    };
+    assert!((calibrate_similarities(vec![0.3], manual.clone())[0] - 0.25).abs() < 1e-6);
+    assert!(calibrate_similarities(vec![f32::NAN], manual)[0].is_nan());
+
+    let single =
+        r#"{"method":"curve","points":[{"similarity":0.5,"score":1}],"center":0,"temperature":1}"#;
+    let single = calibration_from_json(single.into()).unwrap();
+    assert!(calibrate_similarities(vec![f32::NAN], single)[0].is_nan());
+    let nan_point = SimilarityCalibration {
+        method: CalibrationMethod::Curve,
+        points: vec![CalibrationPoint {
+            similarity: f32::NAN,
+            score: 1.0,
+        }],
+        center: 0.0,
+        temperature: 1.0,
+    };
+    assert!(calibrate_similarities(vec![0.5], nan_point)[0].is_nan());
+    let infinite =
+        r#"{"method":"curve","points":[{"similarity":1e39,"score":1}],"center":0,"temperature":1}"#;
+    assert!(calibration_from_json(infinite.into()).is_err());
+}
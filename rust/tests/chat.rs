@@ -0,0 +1,100 @@
+use flutter_embedder::api::chat::{apply_chat_template, chat_template_from_config, ChatTemplate};
+
+/// Trimmed-down Qwen-style template: system prompt, tools as JSON, and
+/// Python string methods.
+const TEMPLATE: &str = r#"
+{%- if tools %}
+<|im_start|>system
+{% for tool in tools %}{{ tool | tojson }}
+{% endfor %}<|im_end|>
+{% endif %}
+{%- for message in messages %}
+{%- if message.role not in ["system", "user", "assistant"] %}
+{{- raise_exception("Unknown role " + message.role) }}
+{%- endif %}
+<|im_start|>{{ message.role }}
+{{ message.content.strip() }}<|im_end|>
+{% endfor %}
+{%- if add_generation_prompt %}
+{{- '<|im_start|>assistant\n' }}
+{%- if enable_thinking is defined and enable_thinking is false %}
+{{- '<think>\n\n</think>\n\n' }}
+{%- endif %}
+{%- endif %}"#;
+
+fn template() -> ChatTemplate {
+    ChatTemplate {
+        source: TEMPLATE.to_string(),
+        bos_token: None,
+        eos_token: Some("<|im_end|>".to_string()),
+    }
+}
+
+const MESSAGES: &str = r#"[
+    {"role": "system", "content": "Be brief."},
+    {"role": "user", "content": "  Héllo?  "}
+]"#;
+
+#[test]
+fn renders_messages_and_generation_prompt() {
+    let prompt = apply_chat_template(template(), MESSAGES.to_string(), false, None).unwrap();
+    assert_eq!(
+        prompt,
+        "<|im_start|>system\nBe brief.<|im_end|>\n<|im_start|>user\nHéllo?<|im_end|>\n"
+    );
+
+    let prompt = apply_chat_template(template(), MESSAGES.to_string(), true, None).unwrap();
+    assert!(prompt.ends_with("Héllo?<|im_end|>\n<|im_start|>assistant\n"));
+
+    let extra = r#"{"enable_thinking": false}"#.to_string();
+    let prompt = apply_chat_template(template(), MESSAGES.to_string(), true, Some(extra)).unwrap();
+    assert!(prompt.ends_with("<|im_start|>assistant\n<think>\n\n</think>\n\n"));
+}
+
+#[test]
+fn tools_render_like_python_json() {
+    let extra = r#"{"tools": [{"name": "search", "parameters": {"query": "string", "limit": 5}}]}"#;
+    let prompt =
+        apply_chat_template(template(), "[]".to_string(), false, Some(extra.to_string())).unwrap();
+    assert_eq!(
+        prompt,
+        "<|im_start|>system\n{\"name\": \"search\", \"parameters\": {\"query\": \"string\", \"limit\": 5}}\n<|im_end|>\n"
+    );
+}
+
+#[test]
+fn invalid_input_is_rejected() {
+    let tool = r#"[{"role": "tool", "content": "x"}]"#.to_string();
+    let error = apply_chat_template(template(), tool, false, None).unwrap_err();
+    assert!(error.to_string().contains("Unknown role tool"));
+
+    let object = r#"{"role": "user"}"#.to_string();
+    assert!(apply_chat_template(template(), object, false, None).is_err());
+    assert!(apply_chat_template(template(), "[]".into(), false, Some("[1]".into())).is_err());
+    let broken = ChatTemplate {
+        source: "{% for %}".to_string(),
+        ..template()
+    };
+    assert!(apply_chat_template(broken, "[]".into(), false, None).is_err());
+}
+
+#[test]
+fn template_from_config() {
+    let config = r#"{
+        "bos_token": {"content": "<s>", "lstrip": false},
+        "eos_token": "</s>",
+        "chat_template": [
+            {"name": "tool_use", "template": "tools"},
+            {"name": "default", "template": "{{ bos_token }}{{ messages[0].content }}{{ eos_token }}"}
+        ]
+    }"#;
+    let template = chat_template_from_config(config.to_string()).unwrap();
+    assert_eq!(template.bos_token.as_deref(), Some("<s>"));
+    assert_eq!(template.eos_token.as_deref(), Some("</s>"));
+    let messages = r#"[{"role": "user", "content": "hi"}]"#.to_string();
+    let prompt = apply_chat_template(template, messages, false, None).unwrap();
+    assert_eq!(prompt, "<s>hi</s>");
+
+    assert!(chat_template_from_config(r#"{"eos_token": "</s>"}"#.to_string()).is_err());
+    assert!(chat_template_from_config("not json".to_string()).is_err());
+}
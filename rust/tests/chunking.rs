@@ -0,0 +1,106 @@
+use flutter_embedder::api::chunking::{split_by_paragraphs, split_by_sentences, split_by_tokens};
+use flutter_embedder::api::tokenizer::{count_tokens, load_tokenizer_from_json};
+
+/// Word-level tokenizer that maps every word and punctuation run to `[UNK]`,
+/// so token counts are easy to reason about.
+fn word_tokenizer() -> u64 {
+    let json = r#"{
+        "version": "1.0",
+        "truncation": null,
+        "padding": null,
+        "added_tokens": [],
+        "normalizer": null,
+        "pre_tokenizer": { "type": "Whitespace" },
+        "post_processor": null,
+        "decoder": null,
+        "model": { "type": "WordLevel", "vocab": { "[UNK]": 0 }, "unk_token": "[UNK]" }
+    }"#;
+    load_tokenizer_from_json(json.to_string()).unwrap()
+}
+
+fn texts(chunks: &[flutter_embedder::api::chunking::TextChunk]) -> Vec<&str> {
+    chunks.iter().map(|c| c.text.as_str()).collect()
+}
+
+#[test]
+fn token_windows_overlap() {
+    let tokenizer = word_tokenizer();
+    let text = "w0 w1 w2 w3 w4 w5 w6 w7 w8 w9".to_string();
+    let chunks = split_by_tokens(tokenizer, text, 4, 1).unwrap();
+    assert_eq!(
+        texts(&chunks),
+        vec!["w0 w1 w2 w3", "w3 w4 w5 w6", "w6 w7 w8 w9"]
+    );
+    assert!(chunks.iter().all(|c| c.token_count == 4));
+    assert_eq!((chunks[1].start, chunks[1].end), (9, 20));
+}
+
+#[test]
+fn offsets_are_in_characters() {
+    let tokenizer = word_tokenizer();
+    let text = "héllo wörld ok".to_string();
+    let chunks = split_by_tokens(tokenizer, text.clone(), 1, 0).unwrap();
+    assert_eq!(texts(&chunks), vec!["héllo", "wörld", "ok"]);
+    let chars: Vec<char> = text.chars().collect();
+    for chunk in &chunks {
+        let slice: String = chars[chunk.start as usize..chunk.end as usize]
+            .iter()
+            .collect();
+        assert_eq!(slice, chunk.text);
+    }
+}
+
+#[test]
+fn sentences_are_kept_whole() {
+    let tokenizer = word_tokenizer();
+    let text = "One two. Three four five! Six?".to_string();
+    let chunks = split_by_sentences(tokenizer, text, 6).unwrap();
+    assert_eq!(texts(&chunks), vec!["One two.", "Three four five! Six?"]);
+    assert!(chunks.iter().all(|c| c.token_count <= 6));
+}
+
+#[test]
+fn long_paragraphs_fall_back_to_smaller_units() {
+    let tokenizer = word_tokenizer();
+    let text = "A b c.\n\nD e.\n\nF g h i j k l m.".to_string();
+    let chunks = split_by_paragraphs(tokenizer, text, 5).unwrap();
+    assert_eq!(
+        texts(&chunks),
+        vec!["A b c.", "D e.", "F g h i j", "k l m."]
+    );
+
+    let chunks = split_by_paragraphs(tokenizer, "A b.\n\nC d.".to_string(), 8).unwrap();
+    assert_eq!(texts(&chunks), vec!["A b.\n\nC d."]);
+}
+
+#[test]
+fn packed_counts_match_the_tokenizer() {
+    let tokenizer = word_tokenizer();
+    let text = (0..200)
+        .map(|i| match i % 3 {
+            0 => format!("Sentence {i} is short."),
+            1 => format!("Sentence {i} is a little longer than that!"),
+            _ => format!("Paragraph {i} ends here.\n\n"),
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    for chunks in [
+        split_by_sentences(tokenizer, text.clone(), 16).unwrap(),
+        split_by_paragraphs(tokenizer, text.clone(), 16).unwrap(),
+    ] {
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            let tokens = count_tokens(tokenizer, chunk.text.clone(), Some(false)).unwrap();
+            assert_eq!(chunk.token_count, tokens);
+            assert!(tokens <= 16);
+        }
+    }
+}
+
+#[test]
+fn rejects_invalid_budgets() {
+    let tokenizer = word_tokenizer();
+    assert!(split_by_tokens(tokenizer, "a b".to_string(), 0, 0).is_err());
+    assert!(split_by_tokens(tokenizer, "a b".to_string(), 2, 2).is_err());
+    assert!(split_by_sentences(u64::MAX, "a b".to_string(), 2).is_err());
+}
@@ -0,0 +1,54 @@
+use std::f32::consts::PI;
+
+use flutter_embedder::api::embeddings::clap::ClapEmbedder;
+use flutter_embedder::api::ort::init_ort;
+use flutter_embedder::api::utils::cosine_distance;
+
+mod config;
+use config::{
+    init_test_config, CLAP_AUDIO_MODEL_PATH, CLAP_TEXT_MODEL_PATH, CLAP_TOKENIZER_PATH,
+    ORT_LIB_PATH,
+};
+
+/// Two seconds of a pure tone at 44.1 kHz.
+fn tone(frequency: f32) -> Vec<f32> {
+    (0..88_200)
+        .map(|i| 0.5 * (2.0 * PI * frequency * i as f32 / 44_100.0).sin())
+        .collect()
+}
+
+/// @reference https://huggingface.co/Xenova/clap-htsat-unfused
+#[test]
+fn clap_audio_text_test() {
+    init_test_config();
+    let tokenizer_path: String = CLAP_TOKENIZER_PATH.get().unwrap().into();
+    let text_model_path: String = CLAP_TEXT_MODEL_PATH.get().unwrap().into();
+    let audio_model_path: String = CLAP_AUDIO_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("clap_ort".to_string(), Some(ort_path)).unwrap();
+    let mut embedder =
+        ClapEmbedder::create(text_model_path, audio_model_path, tokenizer_path).unwrap();
+
+    // Longer than the 10 s window, too.
+    let long = tone(1000.0).repeat(6);
+    let audio = embedder
+        .embed_audio(vec![tone(1000.0), long], 44_100)
+        .unwrap();
+    let texts = embedder
+        .embed_texts(vec![
+            "a high-pitched electronic beep".to_string(),
+            "a dog barking".to_string(),
+        ])
+        .unwrap();
+    assert_eq!(audio.len(), 2);
+    assert_eq!(audio[0].len(), texts[0].len());
+
+    let beep = cosine_distance(audio[0].clone(), texts[0].clone()).unwrap();
+    let dog = cosine_distance(audio[0].clone(), texts[1].clone()).unwrap();
+    assert!(beep < dog, "{beep} vs {dog}");
+    let same = cosine_distance(audio[0].clone(), audio[1].clone()).unwrap();
+    assert!(same < 0.1, "{same}");
+
+    assert!(embedder.embed_audio(vec![vec![]], 44_100).is_err());
+}
@@ -0,0 +1,48 @@
+use flutter_embedder::api::classification::SequenceClassifier;
+use flutter_embedder::api::error::ErrorKind;
+use flutter_embedder::api::ort::init_ort;
+
+mod config;
+use config::{init_test_config, ORT_LIB_PATH, SENTIMENT_MODEL_PATH, SENTIMENT_TOKENIZER_PATH};
+
+/// @reference https://huggingface.co/distilbert/distilbert-base-uncased-finetuned-sst-2-english
+#[test]
+fn sentiment_test() {
+    init_test_config();
+    let tokenizer_path: String = SENTIMENT_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = SENTIMENT_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("classification_ort".to_string(), Some(ort_path)).unwrap();
+    let config = r#"{"id2label": {"0": "NEGATIVE", "1": "POSITIVE"}}"#.to_string();
+    let mut classifier =
+        SequenceClassifier::create(model_path, tokenizer_path, Some(config)).unwrap();
+    assert!(!classifier.is_multi_label());
+
+    let results = classifier
+        .classify(vec![
+            "I love this, it works perfectly.".to_string(),
+            "Terrible. It broke after a day.".to_string(),
+        ])
+        .unwrap();
+    assert_eq!(results[0][0].label, "POSITIVE");
+    assert_eq!(results[1][0].label, "NEGATIVE");
+    for scores in &results {
+        let total: f32 = scores.iter().map(|s| s.score).sum();
+        assert!((total - 1.0).abs() < 1e-4, "total {total}");
+    }
+
+    classifier.set_multi_label(true);
+    let results = classifier.classify(vec!["Fine.".to_string()]).unwrap();
+    assert!(results[0].iter().all(|s| (0.0..=1.0).contains(&s.score)));
+}
+
+#[test]
+fn invalid_config() {
+    let classifier = SequenceClassifier::create(
+        "missing.onnx".to_string(),
+        "missing.json".to_string(),
+        Some("{".to_string()),
+    );
+    assert_eq!(classifier.err().unwrap().kind, ErrorKind::InvalidInput);
+}
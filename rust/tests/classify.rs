@@ -0,0 +1,45 @@
+use flutter_embedder::api::classify::ZeroShotClassifier;
+use flutter_embedder::api::error::ErrorKind;
+use flutter_embedder::api::ort::init_ort;
+
+mod config;
+use config::{init_test_config, NLI_MODEL_PATH, NLI_TOKENIZER_PATH, ORT_LIB_PATH};
+
+/// @reference https://huggingface.co/MoritzLaurer/deberta-v3-base-zeroshot-v2.0
+#[test]
+fn zero_shot_test() {
+    init_test_config();
+    let tokenizer_path: String = NLI_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = NLI_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("classify_ort".to_string(), Some(ort_path)).unwrap();
+    let mut classifier = ZeroShotClassifier::create(model_path, tokenizer_path, None).unwrap();
+
+    let text = "The new phone has a great camera but the battery dies by noon.".to_string();
+    let labels = vec![
+        "technology".to_string(),
+        "cooking".to_string(),
+        "sports".to_string(),
+    ];
+    let single = classifier
+        .classify(text.clone(), labels.clone(), false)
+        .unwrap();
+    assert_eq!(single[0].label, "technology");
+    let total: f32 = single.iter().map(|s| s.score).sum();
+    assert!((total - 1.0).abs() < 1e-4, "total {total}");
+
+    let multi = classifier.classify(text, labels, true).unwrap();
+    assert_eq!(multi[0].label, "technology");
+    assert!(multi.iter().all(|s| (0.0..=1.0).contains(&s.score)));
+}
+
+#[test]
+fn invalid_config() {
+    let no_labels = ZeroShotClassifier::create(
+        "missing.onnx".to_string(),
+        "missing.json".to_string(),
+        Some(r#"{"id2label": {"0": "positive", "1": "negative"}}"#.to_string()),
+    );
+    assert_eq!(no_labels.err().unwrap().kind, ErrorKind::InvalidInput);
+}
@@ -0,0 +1,53 @@
+use std::io::Cursor;
+
+use flutter_embedder::api::embeddings::clip::ClipEmbedder;
+use flutter_embedder::api::ort::init_ort;
+use flutter_embedder::api::utils::cosine_distance;
+
+mod config;
+use config::{
+    init_test_config, CLIP_TEXT_MODEL_PATH, CLIP_TOKENIZER_PATH, CLIP_VISION_MODEL_PATH,
+    ORT_LIB_PATH,
+};
+
+fn solid_png(rgb: [u8; 3]) -> Vec<u8> {
+    let image = image::RgbImage::from_pixel(320, 240, image::Rgb(rgb));
+    let mut bytes = Cursor::new(Vec::new());
+    image.write_to(&mut bytes, image::ImageFormat::Png).unwrap();
+    bytes.into_inner()
+}
+
+/// @reference https://huggingface.co/openai/clip-vit-base-patch32
+#[test]
+fn clip_image_text_test() {
+    init_test_config();
+    let tokenizer_path: String = CLIP_TOKENIZER_PATH.get().unwrap().into();
+    let text_model_path: String = CLIP_TEXT_MODEL_PATH.get().unwrap().into();
+    let vision_model_path: String = CLIP_VISION_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("clip_ort".to_string(), Some(ort_path)).unwrap();
+    let mut embedder =
+        ClipEmbedder::create(text_model_path, vision_model_path, tokenizer_path).unwrap();
+
+    let images = embedder
+        .embed_images(vec![solid_png([220, 20, 20]), solid_png([20, 20, 220])])
+        .unwrap();
+    let texts = embedder
+        .embed_texts(vec![
+            "a photo of the color red".to_string(),
+            "a photo of the color blue".to_string(),
+        ])
+        .unwrap();
+    assert_eq!(images.len(), 2);
+    assert_eq!(images[0].len(), texts[0].len());
+    assert_eq!(embedder.dimension().unwrap(), texts[0].len());
+
+    let red_to_red = cosine_distance(images[0].clone(), texts[0].clone()).unwrap();
+    let red_to_blue = cosine_distance(images[0].clone(), texts[1].clone()).unwrap();
+    assert!(red_to_red < red_to_blue, "{red_to_red} vs {red_to_blue}");
+
+    assert!(embedder
+        .embed_images(vec![b"not an image".to_vec()])
+        .is_err());
+}
@@ -0,0 +1,13 @@
+use flutter_embedder::api::embeddings::colbert::max_sim;
+
+#[test]
+fn max_sim_sums_best_matches() {
+    let query = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+    let doc = vec![vec![0.6, 0.8], vec![1.0, 0.0], vec![-1.0, 0.0]];
+    // Best match for [1, 0] is 1.0, for [0, 1] it is 0.8.
+    assert!((max_sim(query.clone(), doc) - 1.8).abs() < 1e-6);
+
+    let opposite = vec![vec![-1.0, 0.0]];
+    assert!((max_sim(query.clone(), opposite) + 1.0).abs() < 1e-6);
+    assert_eq!(max_sim(query, Vec::new()), 0.0);
+}
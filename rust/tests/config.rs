@@ -13,6 +13,43 @@ pub static BGE_TOKENIZER_PATH: OnceLock<String> = OnceLock::new();
 //
 pub static MINILM_EMBEDDING_MODEL_PATH: OnceLock<String> = OnceLock::new();
 pub static MINILM_TOKENIZER_PATH: OnceLock<String> = OnceLock::new();
+//
+pub static BGE_RERANKER_MODEL_PATH: OnceLock<String> = OnceLock::new();
+pub static BGE_RERANKER_TOKENIZER_PATH: OnceLock<String> = OnceLock::new();
+//
+pub static CLIP_TEXT_MODEL_PATH: OnceLock<String> = OnceLock::new();
+pub static CLIP_VISION_MODEL_PATH: OnceLock<String> = OnceLock::new();
+pub static CLIP_TOKENIZER_PATH: OnceLock<String> = OnceLock::new();
+//
+pub static E5_EMBEDDING_MODEL_PATH: OnceLock<String> = OnceLock::new();
+pub static E5_TOKENIZER_PATH: OnceLock<String> = OnceLock::new();
+//
+pub static NOMIC_EMBEDDING_MODEL_PATH: OnceLock<String> = OnceLock::new();
+pub static NOMIC_TOKENIZER_PATH: OnceLock<String> = OnceLock::new();
+
+pub static NLI_MODEL_PATH: OnceLock<String> = OnceLock::new();
+pub static NLI_TOKENIZER_PATH: OnceLock<String> = OnceLock::new();
+
+pub static NER_MODEL_PATH: OnceLock<String> = OnceLock::new();
+pub static NER_TOKENIZER_PATH: OnceLock<String> = OnceLock::new();
+pub static NER_CONFIG_PATH: OnceLock<String> = OnceLock::new();
+
+pub static SENTIMENT_MODEL_PATH: OnceLock<String> = OnceLock::new();
+pub static SENTIMENT_TOKENIZER_PATH: OnceLock<String> = OnceLock::new();
+
+pub static GENERATION_MODEL_PATH: OnceLock<String> = OnceLock::new();
+pub static GENERATION_TOKENIZER_PATH: OnceLock<String> = OnceLock::new();
+
+pub static WHISPER_ENCODER_MODEL_PATH: OnceLock<String> = OnceLock::new();
+pub static WHISPER_DECODER_MODEL_PATH: OnceLock<String> = OnceLock::new();
+pub static WHISPER_TOKENIZER_PATH: OnceLock<String> = OnceLock::new();
+pub static SPEECH_SAMPLE_PATH: OnceLock<String> = OnceLock::new();
+
+pub static CLAP_TEXT_MODEL_PATH: OnceLock<String> = OnceLock::new();
+pub static CLAP_AUDIO_MODEL_PATH: OnceLock<String> = OnceLock::new();
+pub static CLAP_TOKENIZER_PATH: OnceLock<String> = OnceLock::new();
+
+pub static DINOV2_MODEL_PATH: OnceLock<String> = OnceLock::new();
 
 pub fn init_test_config() {
     let env_vars = dotenvy::dotenv()
@@ -77,4 +114,160 @@ pub fn init_test_config() {
             .set(tokenizer_path.to_string())
             .expect("Failed to set TOKENIZER_MINILM_PATH");
     }
+
+    if let Some(model_path) = env_vars.get("RERANKER_BGE_MODEL_PATH") {
+        BGE_RERANKER_MODEL_PATH
+            .set(model_path.to_string())
+            .expect("Failed to set BGE_RERANKER_MODEL_PATH");
+    }
+
+    if let Some(tokenizer_path) = env_vars.get("TOKENIZER_BGE_RERANKER_PATH") {
+        BGE_RERANKER_TOKENIZER_PATH
+            .set(tokenizer_path.to_string())
+            .expect("Failed to set BGE_RERANKER_TOKENIZER_PATH");
+    }
+
+    if let Some(model_path) = env_vars.get("EMBEDDING_CLIP_TEXT_MODEL_PATH") {
+        CLIP_TEXT_MODEL_PATH
+            .set(model_path.to_string())
+            .expect("Failed to set CLIP_TEXT_MODEL_PATH");
+    }
+
+    if let Some(model_path) = env_vars.get("EMBEDDING_CLIP_VISION_MODEL_PATH") {
+        CLIP_VISION_MODEL_PATH
+            .set(model_path.to_string())
+            .expect("Failed to set CLIP_VISION_MODEL_PATH");
+    }
+
+    if let Some(tokenizer_path) = env_vars.get("TOKENIZER_CLIP_PATH") {
+        CLIP_TOKENIZER_PATH
+            .set(tokenizer_path.to_string())
+            .expect("Failed to set CLIP_TOKENIZER_PATH");
+    }
+
+    if let Some(model_path) = env_vars.get("EMBEDDING_E5_MODEL_PATH") {
+        E5_EMBEDDING_MODEL_PATH
+            .set(model_path.to_string())
+            .expect("Failed to set E5_EMBEDDING_MODEL_PATH");
+    }
+
+    if let Some(tokenizer_path) = env_vars.get("TOKENIZER_E5_PATH") {
+        E5_TOKENIZER_PATH
+            .set(tokenizer_path.to_string())
+            .expect("Failed to set E5_TOKENIZER_PATH");
+    }
+
+    if let Some(model_path) = env_vars.get("EMBEDDING_NOMIC_MODEL_PATH") {
+        NOMIC_EMBEDDING_MODEL_PATH
+            .set(model_path.to_string())
+            .expect("Failed to set NOMIC_EMBEDDING_MODEL_PATH");
+    }
+
+    if let Some(tokenizer_path) = env_vars.get("TOKENIZER_NOMIC_PATH") {
+        NOMIC_TOKENIZER_PATH
+            .set(tokenizer_path.to_string())
+            .expect("Failed to set NOMIC_TOKENIZER_PATH");
+    }
+
+    if let Some(model_path) = env_vars.get("CLASSIFIER_NLI_MODEL_PATH") {
+        NLI_MODEL_PATH
+            .set(model_path.to_string())
+            .expect("Failed to set NLI_MODEL_PATH");
+    }
+
+    if let Some(tokenizer_path) = env_vars.get("TOKENIZER_NLI_PATH") {
+        NLI_TOKENIZER_PATH
+            .set(tokenizer_path.to_string())
+            .expect("Failed to set NLI_TOKENIZER_PATH");
+    }
+
+    if let Some(model_path) = env_vars.get("NER_MODEL_PATH") {
+        NER_MODEL_PATH
+            .set(model_path.to_string())
+            .expect("Failed to set NER_MODEL_PATH");
+    }
+
+    if let Some(tokenizer_path) = env_vars.get("TOKENIZER_NER_PATH") {
+        NER_TOKENIZER_PATH
+            .set(tokenizer_path.to_string())
+            .expect("Failed to set NER_TOKENIZER_PATH");
+    }
+
+    if let Some(config_path) = env_vars.get("NER_CONFIG_PATH") {
+        NER_CONFIG_PATH
+            .set(config_path.to_string())
+            .expect("Failed to set NER_CONFIG_PATH");
+    }
+
+    if let Some(model_path) = env_vars.get("CLASSIFIER_SENTIMENT_MODEL_PATH") {
+        SENTIMENT_MODEL_PATH
+            .set(model_path.to_string())
+            .expect("Failed to set SENTIMENT_MODEL_PATH");
+    }
+
+    if let Some(tokenizer_path) = env_vars.get("TOKENIZER_SENTIMENT_PATH") {
+        SENTIMENT_TOKENIZER_PATH
+            .set(tokenizer_path.to_string())
+            .expect("Failed to set SENTIMENT_TOKENIZER_PATH");
+    }
+
+    if let Some(model_path) = env_vars.get("GENERATION_MODEL_PATH") {
+        GENERATION_MODEL_PATH
+            .set(model_path.to_string())
+            .expect("Failed to set GENERATION_MODEL_PATH");
+    }
+
+    if let Some(tokenizer_path) = env_vars.get("TOKENIZER_GENERATION_PATH") {
+        GENERATION_TOKENIZER_PATH
+            .set(tokenizer_path.to_string())
+            .expect("Failed to set GENERATION_TOKENIZER_PATH");
+    }
+
+    if let Some(model_path) = env_vars.get("WHISPER_ENCODER_MODEL_PATH") {
+        WHISPER_ENCODER_MODEL_PATH
+            .set(model_path.to_string())
+            .expect("Failed to set WHISPER_ENCODER_MODEL_PATH");
+    }
+
+    if let Some(model_path) = env_vars.get("WHISPER_DECODER_MODEL_PATH") {
+        WHISPER_DECODER_MODEL_PATH
+            .set(model_path.to_string())
+            .expect("Failed to set WHISPER_DECODER_MODEL_PATH");
+    }
+
+    if let Some(tokenizer_path) = env_vars.get("TOKENIZER_WHISPER_PATH") {
+        WHISPER_TOKENIZER_PATH
+            .set(tokenizer_path.to_string())
+            .expect("Failed to set WHISPER_TOKENIZER_PATH");
+    }
+
+    if let Some(audio_path) = env_vars.get("SPEECH_SAMPLE_PATH") {
+        SPEECH_SAMPLE_PATH
+            .set(audio_path.to_string())
+            .expect("Failed to set SPEECH_SAMPLE_PATH");
+    }
+
+    if let Some(model_path) = env_vars.get("CLAP_TEXT_MODEL_PATH") {
+        CLAP_TEXT_MODEL_PATH
+            .set(model_path.to_string())
+            .expect("Failed to set CLAP_TEXT_MODEL_PATH");
+    }
+
+    if let Some(model_path) = env_vars.get("CLAP_AUDIO_MODEL_PATH") {
+        CLAP_AUDIO_MODEL_PATH
+            .set(model_path.to_string())
+            .expect("Failed to set CLAP_AUDIO_MODEL_PATH");
+    }
+
+    if let Some(tokenizer_path) = env_vars.get("TOKENIZER_CLAP_PATH") {
+        CLAP_TOKENIZER_PATH
+            .set(tokenizer_path.to_string())
+            .expect("Failed to set CLAP_TOKENIZER_PATH");
+    }
+
+    if let Some(model_path) = env_vars.get("VISION_DINOV2_MODEL_PATH") {
+        DINOV2_MODEL_PATH
+            .set(model_path.to_string())
+            .expect("Failed to set DINOV2_MODEL_PATH");
+    }
 }
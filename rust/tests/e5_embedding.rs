@@ -0,0 +1,37 @@
+use flutter_embedder::api::embeddings::e5::E5Embedder;
+use flutter_embedder::api::ort::init_ort;
+use flutter_embedder::api::utils::cosine_distance;
+
+mod config;
+use config::{init_test_config, E5_EMBEDDING_MODEL_PATH, E5_TOKENIZER_PATH, ORT_LIB_PATH};
+
+/// @reference https://huggingface.co/intfloat/multilingual-e5-small
+#[test]
+fn e5_embedding_test() {
+    init_test_config();
+    let tokenizer_path: String = E5_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = E5_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("e5_ort".to_string(), Some(ort_path)).unwrap();
+    let embedder = E5Embedder::create(model_path, tokenizer_path).unwrap();
+
+    let query = E5Embedder::format_query("how much protein should a female eat".to_string());
+    assert_eq!(query, "query: how much protein should a female eat");
+    let docs = [
+        "As a general guideline, the CDC's average requirement of protein for women ages 19 to 70 is 46 grams per day.",
+        "Definition of summit for English Language Learners: the highest point of a mountain.",
+    ]
+    .iter()
+    .map(|d| E5Embedder::format_document(d.to_string()))
+    .collect::<Vec<_>>();
+
+    let query_embedding = embedder.embed(vec![query]).unwrap().remove(0);
+    let doc_embeddings = embedder.embed(docs).unwrap();
+    assert_eq!(doc_embeddings.len(), 2);
+    assert_eq!(query_embedding.len(), doc_embeddings[0].len());
+
+    let relevant = cosine_distance(query_embedding.clone(), doc_embeddings[0].clone()).unwrap();
+    let unrelated = cosine_distance(query_embedding, doc_embeddings[1].clone()).unwrap();
+    assert!(relevant < unrelated, "{relevant} vs {unrelated}");
+}
@@ -0,0 +1,38 @@
+use flutter_embedder::api::embeddings::generic::GenericEmbedder;
+use flutter_embedder::api::error::ErrorKind;
+use flutter_embedder::api::ort::inspect_onnx_model;
+use flutter_embedder::api::tokenizer::load_tokenizer_from_json;
+use flutter_embedder::api::utils::cosine_distance;
+
+#[test]
+fn error_kinds() {
+    let e = cosine_distance(vec![1.0], vec![1.0, 2.0]).unwrap_err();
+    assert_eq!(e.kind, ErrorKind::InvalidInput);
+
+    let e = load_tokenizer_from_json("not json".to_string()).unwrap_err();
+    assert_eq!(e.kind, ErrorKind::TokenizerLoad);
+    assert!(e.to_string().starts_with("TokenizerLoad: "));
+
+    let e = inspect_onnx_model("missing.onnx".to_string()).unwrap_err();
+    assert_eq!(e.kind, ErrorKind::ModelLoad);
+}
+
+#[test]
+fn generic_config_errors() {
+    for config in [
+        "not json",
+        r#"{ "pooling": "max" }"#,
+        r#"{ "output": [] }"#,
+        r#"{ "pooling": "cls", "unknown": 1 }"#,
+        r#"{ "instructions": "prefix" }"#,
+    ] {
+        let e = GenericEmbedder::create(
+            "missing.onnx".to_string(),
+            "missing.json".to_string(),
+            config.to_string(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(e.kind, ErrorKind::InvalidInput, "{config}");
+    }
+}
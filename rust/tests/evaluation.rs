@@ -0,0 +1,59 @@
+use flutter_embedder::api::evaluation::{
+    check_reference_vectors, compare_embedding_sets, ReferenceCase,
+};
+
+#[test]
+fn compare_identical_and_rotated_sets() {
+    let a = vec![
+        vec![1.0, 0.0, 0.0],
+        vec![0.9, 0.1, 0.0],
+        vec![0.0, 1.0, 0.0],
+        vec![0.0, 0.9, 0.2],
+        vec![0.0, 0.0, 1.0],
+    ];
+    // Same geometry in a different (padded, permuted) space.
+    let b: Vec<Vec<f32>> = a.iter().map(|v| vec![v[2], 0.0, v[0], v[1]]).collect();
+
+    let report = compare_embedding_sets(a.clone(), b, Some(2)).unwrap();
+    assert_eq!(report.count, 5);
+    assert!(report.pearson > 0.999);
+    assert!(report.spearman > 0.999);
+    assert!((report.neighbor_overlap - 1.0).abs() < 1e-6);
+    assert_eq!(report.stats_a.dimension, 3);
+    assert_eq!(report.stats_b.dimension, 4);
+
+    let reversed: Vec<Vec<f32>> = a.iter().rev().cloned().collect();
+    let shuffled = compare_embedding_sets(a.clone(), reversed, Some(1)).unwrap();
+    assert!(shuffled.neighbor_overlap < 1.0);
+
+    assert!(compare_embedding_sets(a, vec![vec![1.0]], None).is_err());
+}
+
+#[test]
+fn reference_vectors_detect_drift() {
+    let cases = vec![
+        ReferenceCase {
+            text: "stable".to_string(),
+            expected: vec![1.0, 0.0, 0.0],
+            tolerance: 0.01,
+        },
+        ReferenceCase {
+            text: "drifted".to_string(),
+            expected: vec![0.0, 1.0, 0.0],
+            tolerance: 0.01,
+        },
+        ReferenceCase {
+            text: "wrong model".to_string(),
+            expected: vec![0.0, 0.0, 1.0],
+            tolerance: 0.01,
+        },
+    ];
+    let actual = vec![vec![0.99, 0.01, 0.0], vec![0.5, 0.5, 0.0], vec![1.0, 0.0]];
+
+    let checks = check_reference_vectors(cases.clone(), actual).unwrap();
+    assert!(checks[0].passed);
+    assert!(!checks[1].passed && checks[1].deviation > 0.2);
+    assert!(!checks[2].passed && checks[2].message.is_some());
+
+    assert!(check_reference_vectors(cases, vec![]).is_err());
+}
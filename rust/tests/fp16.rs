@@ -0,0 +1,179 @@
+use flutter_embedder::api::fp16::convert_model_to_fp16;
+
+// Tiny protobuf helpers to build and inspect an ONNX model by hand.
+
+fn varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn int_field(out: &mut Vec<u8>, number: u64, value: u64) {
+    varint(out, number << 3);
+    varint(out, value);
+}
+
+fn bytes_field(out: &mut Vec<u8>, number: u64, value: &[u8]) {
+    varint(out, (number << 3) | 2);
+    varint(out, value.len() as u64);
+    out.extend_from_slice(value);
+}
+
+enum Value {
+    Int(u64),
+    Bytes(Vec<u8>),
+}
+
+fn parse(mut buf: &[u8]) -> Vec<(u64, Value)> {
+    fn read(buf: &mut &[u8]) -> u64 {
+        let mut value = 0;
+        let mut shift = 0;
+        loop {
+            let byte = buf[0];
+            *buf = &buf[1..];
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return value;
+            }
+            shift += 7;
+        }
+    }
+    let mut fields = Vec::new();
+    while !buf.is_empty() {
+        let key = read(&mut buf);
+        match key & 7 {
+            0 => fields.push((key >> 3, Value::Int(read(&mut buf)))),
+            2 => {
+                let len = read(&mut buf) as usize;
+                fields.push((key >> 3, Value::Bytes(buf[..len].to_vec())));
+                buf = &buf[len..];
+            }
+            wire => panic!("unexpected wire type {wire}"),
+        }
+    }
+    fields
+}
+
+fn bytes_of(fields: &[(u64, Value)], number: u64) -> Vec<Vec<u8>> {
+    fields
+        .iter()
+        .filter_map(|(n, v)| match v {
+            Value::Bytes(b) if *n == number => Some(b.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn int_of(fields: &[(u64, Value)], number: u64) -> Option<u64> {
+    fields.iter().find_map(|(n, v)| match v {
+        Value::Int(i) if *n == number => Some(*i),
+        _ => None,
+    })
+}
+
+fn float_value_info(name: &str) -> Vec<u8> {
+    let mut tensor_type = Vec::new();
+    int_field(&mut tensor_type, 1, 1);
+    let mut type_proto = Vec::new();
+    bytes_field(&mut type_proto, 1, &tensor_type);
+    let mut value_info = Vec::new();
+    bytes_field(&mut value_info, 1, name.as_bytes());
+    bytes_field(&mut value_info, 2, &type_proto);
+    value_info
+}
+
+/// `y = x + w` with a two-element fp32 initializer `w`.
+fn add_model() -> Vec<u8> {
+    let mut node = Vec::new();
+    bytes_field(&mut node, 1, b"x");
+    bytes_field(&mut node, 1, b"w");
+    bytes_field(&mut node, 2, b"y");
+    bytes_field(&mut node, 4, b"Add");
+
+    let mut initializer = Vec::new();
+    int_field(&mut initializer, 1, 2);
+    int_field(&mut initializer, 2, 1);
+    bytes_field(&mut initializer, 8, b"w");
+    let raw: Vec<u8> = [1.5f32, -1.0e9]
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect();
+    bytes_field(&mut initializer, 9, &raw);
+
+    let mut graph = Vec::new();
+    bytes_field(&mut graph, 1, &node);
+    bytes_field(&mut graph, 5, &initializer);
+    bytes_field(&mut graph, 11, &float_value_info("x"));
+    bytes_field(&mut graph, 12, &float_value_info("y"));
+
+    let mut model = Vec::new();
+    int_field(&mut model, 1, 8);
+    bytes_field(&mut model, 7, &graph);
+    model
+}
+
+#[test]
+fn converts_weights_and_keeps_io_types() {
+    let dir = std::env::temp_dir().join(format!("fp16_test_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let input = dir.join("add.onnx");
+    let output = dir.join("add.fp16.onnx");
+    std::fs::write(&input, add_model()).unwrap();
+
+    convert_model_to_fp16(
+        input.to_str().unwrap().to_string(),
+        output.to_str().unwrap().to_string(),
+    )
+    .unwrap();
+
+    let model = parse(&std::fs::read(&output).unwrap());
+    assert_eq!(int_of(&model, 1), Some(8));
+    let graph = parse(&bytes_of(&model, 7)[0]);
+
+    let initializer = parse(&bytes_of(&graph, 5)[0]);
+    assert_eq!(int_of(&initializer, 2), Some(10));
+    let raw = &bytes_of(&initializer, 9)[0];
+    assert_eq!(raw.len(), 4);
+    assert_eq!(half::f16::from_le_bytes([raw[0], raw[1]]).to_f32(), 1.5);
+    // Out-of-range values are clamped instead of becoming infinite.
+    assert_eq!(half::f16::from_le_bytes([raw[2], raw[3]]), half::f16::MIN);
+
+    // Graph inputs/outputs keep fp32 and are bridged with Cast nodes.
+    for value_info in bytes_of(&graph, 11).iter().chain(&bytes_of(&graph, 12)) {
+        let type_proto = parse(&bytes_of(&parse(value_info), 2)[0]);
+        let tensor_type = parse(&bytes_of(&type_proto, 1)[0]);
+        assert_eq!(int_of(&tensor_type, 1), Some(1));
+    }
+    let nodes: Vec<Vec<(u64, Value)>> = bytes_of(&graph, 1).iter().map(|n| parse(n)).collect();
+    let op_types: Vec<Vec<u8>> = nodes.iter().map(|n| bytes_of(n, 4)[0].clone()).collect();
+    assert_eq!(
+        op_types,
+        vec![b"Cast".to_vec(), b"Add".to_vec(), b"Cast".to_vec()]
+    );
+    assert_eq!(
+        bytes_of(&nodes[1], 1),
+        vec![b"x__fp16".to_vec(), b"w".to_vec()]
+    );
+    assert_eq!(bytes_of(&nodes[1], 2), vec![b"y__fp16".to_vec()]);
+    assert_eq!(bytes_of(&nodes[2], 2), vec![b"y".to_vec()]);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn rejects_invalid_models() {
+    let dir = std::env::temp_dir().join(format!("fp16_invalid_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let input = dir.join("broken.onnx");
+    std::fs::write(&input, [0x3a, 0xff]).unwrap();
+
+    let result = convert_model_to_fp16(
+        input.to_str().unwrap().to_string(),
+        dir.join("out.onnx").to_str().unwrap().to_string(),
+    );
+    assert!(result.is_err());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
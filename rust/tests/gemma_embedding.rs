@@ -1,4 +1,5 @@
 use flutter_embedder::api::embeddings::gemma::GemmaEmbedder;
+use flutter_embedder::api::embeddings::EmbedOptions;
 use flutter_embedder::api::ort::init_ort;
 use ndarray::{array, Array, Array2};
 
@@ -14,7 +15,7 @@ fn gemma_embedding_test() {
     let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
 
     init_ort("gemma_ort".to_string(), Some(ort_path)).unwrap();
-    let mut embedder = GemmaEmbedder::create(model_path, tokenizer_path).unwrap();
+    let embedder = GemmaEmbedder::create(model_path, tokenizer_path).unwrap();
 
     // Each query must come with a one-sentence instruction that describes the task
     let query = GemmaEmbedder::format_query("Which planet is known as the Red Planet?".to_string());
@@ -35,7 +36,7 @@ fn gemma_embedding_test() {
             "Saturn, famous for its rings, is sometimes mistaken for the Red Planet.".to_string(),
         ),
     ];
-    let inputs = vec![query]
+    let inputs = [query]
         .iter()
         .chain(documents.iter())
         .cloned()
@@ -46,16 +47,17 @@ fn gemma_embedding_test() {
 
     let embedding_size = outputs[2].len();
     assert_eq!(embedding_size, 768);
+    assert_eq!(embedder.dimension().unwrap(), embedding_size);
 
     let queries: Array2<f32> = Array::from_shape_vec(
         (1, embedding_size),
-        outputs[0..1].to_vec().into_iter().flatten().collect(),
+        outputs[0..1].iter().flatten().copied().collect(),
     )
     .unwrap();
 
     let docs = Array::from_shape_vec(
         (4, embedding_size),
-        outputs[1..].to_vec().into_iter().flatten().collect(),
+        outputs[1..].iter().flatten().copied().collect(),
     )
     .unwrap();
 
@@ -63,3 +65,38 @@ fn gemma_embedding_test() {
     let target_sims = array![0.30109745, 0.635883, 0.49304956, 0.48887485];
     assert!(sims.dot(&target_sims.t())[0] > 0.95); // cosine similarity
 }
+
+#[test]
+fn gemma_matryoshka_test() {
+    init_test_config();
+    let tokenizer_path: String = GEMMA_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = GEMMA_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("gemma_ort".to_string(), Some(ort_path)).unwrap();
+    let embedder = GemmaEmbedder::create(model_path, tokenizer_path).unwrap();
+
+    let texts = vec![GemmaEmbedder::format_query("Red planet".to_string())];
+    let full = embedder.embed(texts.clone()).unwrap();
+    let truncated = embedder
+        .embed_with_options(
+            texts.clone(),
+            EmbedOptions {
+                output_dim: Some(256),
+            },
+        )
+        .unwrap();
+    assert_eq!(truncated[0].len(), 256);
+    let norm: f32 = truncated[0].iter().map(|x| x * x).sum::<f32>().sqrt();
+    assert!((norm - 1.0).abs() < 1e-4);
+    // Same direction as the full vector's prefix.
+    let prefix_norm: f32 = full[0][..256].iter().map(|x| x * x).sum::<f32>().sqrt();
+    for (t, f) in truncated[0].iter().zip(&full[0][..256]) {
+        assert!((t - f / prefix_norm).abs() < 1e-5);
+    }
+
+    let too_wide = EmbedOptions {
+        output_dim: Some(4096),
+    };
+    assert!(embedder.embed_with_options(texts, too_wide).is_err());
+}
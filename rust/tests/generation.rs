@@ -0,0 +1,78 @@
+use flutter_embedder::api::error::ErrorKind;
+use flutter_embedder::api::generation::{FinishReason, GenerationOptions, TextGenerator};
+use flutter_embedder::api::ort::init_ort;
+
+mod config;
+use config::{init_test_config, GENERATION_MODEL_PATH, GENERATION_TOKENIZER_PATH, ORT_LIB_PATH};
+
+const PROMPT: &str = "<|im_start|>user\nWhat is the capital of France? Answer in one word.<|im_end|>\n<|im_start|>assistant\n";
+
+/// @reference https://huggingface.co/onnx-community/Qwen2.5-0.5B-Instruct
+#[test]
+fn qwen_generation_test() {
+    init_test_config();
+    let tokenizer_path: String = GENERATION_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = GENERATION_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("generation_ort".to_string(), Some(ort_path)).unwrap();
+    let mut generator = TextGenerator::create(model_path, tokenizer_path).unwrap();
+    assert!(generator.has_kv_cache());
+    assert!(!generator.eos_token_ids().is_empty());
+
+    let greedy = generator
+        .generate(
+            PROMPT.to_string(),
+            Some(GenerationOptions {
+                max_new_tokens: Some(16),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+    assert!(greedy.text.contains("Paris"), "{}", greedy.text);
+    assert_eq!(greedy.finish_reason, FinishReason::Eos);
+
+    // Greedy decoding is deterministic.
+    let again = generator.generate(PROMPT.to_string(), None).unwrap();
+    assert_eq!(again.token_ids, greedy.token_ids);
+
+    let sampling = GenerationOptions {
+        max_new_tokens: Some(4),
+        temperature: Some(0.8),
+        top_k: Some(40),
+        top_p: Some(0.9),
+        seed: Some(7),
+        eos_token_ids: Some(vec![]),
+        ..Default::default()
+    };
+    let sampled = generator
+        .generate(PROMPT.to_string(), Some(sampling.clone()))
+        .unwrap();
+    assert_eq!(sampled.token_ids.len(), 4);
+    assert_eq!(sampled.finish_reason, FinishReason::MaxTokens);
+    let resampled = generator
+        .generate(PROMPT.to_string(), Some(sampling))
+        .unwrap();
+    assert_eq!(resampled.token_ids, sampled.token_ids);
+
+    let stopped = generator
+        .generate(
+            PROMPT.to_string(),
+            Some(GenerationOptions {
+                stop_sequences: Some(vec!["ris".to_string()]),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+    assert_eq!(stopped.finish_reason, FinishReason::StopSequence);
+    assert_eq!(stopped.text, "Pa");
+
+    let invalid = generator.generate(
+        PROMPT.to_string(),
+        Some(GenerationOptions {
+            top_p: Some(0.0),
+            ..Default::default()
+        }),
+    );
+    assert_eq!(invalid.err().unwrap().kind, ErrorKind::InvalidInput);
+}
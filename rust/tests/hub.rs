@@ -0,0 +1,37 @@
+use flutter_embedder::api::hub::resolve_hf_model;
+
+#[test]
+fn offline_resolution() {
+    let dir = std::env::temp_dir().join(format!("hub_test_{}", std::process::id()));
+    let cache_dir = dir.to_string_lossy().into_owned();
+    let resolve = |filename: &str, revision: Option<&str>| {
+        resolve_hf_model(
+            "org/model".to_string(),
+            filename.to_string(),
+            revision.map(str::to_string),
+            cache_dir.clone(),
+            true,
+        )
+    };
+
+    // Nothing cached yet, and offline mode never downloads.
+    assert!(resolve("tokenizer.json", None).is_err());
+
+    let cached = dir
+        .join("org--model")
+        .join("main")
+        .join("onnx")
+        .join("model.onnx");
+    std::fs::create_dir_all(cached.parent().unwrap()).unwrap();
+    std::fs::write(&cached, b"onnx").unwrap();
+    assert_eq!(
+        resolve("onnx/model.onnx", None).unwrap(),
+        cached.to_string_lossy()
+    );
+    // Revisions are cached separately.
+    assert!(resolve("onnx/model.onnx", Some("v2")).is_err());
+
+    assert!(resolve("../escape.onnx", None).is_err());
+    assert!(resolve("/etc/passwd", None).is_err());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,92 @@
+use std::io::Cursor;
+
+use flutter_embedder::api::image::{
+    preprocess, preprocess_rgba, PreprocessOptions, ResizeFilter, ResizeMode, RgbaPixels,
+    TensorLayout,
+};
+
+/// 40x20 PNG: red left half, blue right half.
+fn red_blue_png() -> Vec<u8> {
+    let image = image::RgbImage::from_fn(40, 20, |x, _| {
+        image::Rgb(if x < 20 { [255, 0, 0] } else { [0, 0, 255] })
+    });
+    let mut bytes = Cursor::new(Vec::new());
+    image.write_to(&mut bytes, image::ImageFormat::Png).unwrap();
+    bytes.into_inner()
+}
+
+fn options(mode: ResizeMode, layout: TensorLayout) -> PreprocessOptions {
+    PreprocessOptions {
+        width: 4,
+        height: 4,
+        mean: vec![0.0; 3],
+        std: vec![1.0; 3],
+        layout,
+        filter: ResizeFilter::Nearest,
+        mode,
+        crop_fraction: None,
+    }
+}
+
+#[test]
+fn layouts_and_resize_modes() {
+    let chw = preprocess(
+        red_blue_png(),
+        options(ResizeMode::Stretch, TensorLayout::Chw),
+    )
+    .unwrap();
+    assert_eq!(chw.len(), 48);
+    // Red plane: left columns 1, right columns 0.
+    assert_eq!(&chw[..4], &[1.0, 1.0, 0.0, 0.0]);
+    // Blue plane.
+    assert_eq!(&chw[32..36], &[0.0, 0.0, 1.0, 1.0]);
+
+    let hwc = preprocess(
+        red_blue_png(),
+        options(ResizeMode::Stretch, TensorLayout::Hwc),
+    )
+    .unwrap();
+    assert_eq!(&hwc[..6], &[1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+    assert_eq!(&hwc[9..12], &[0.0, 0.0, 1.0]);
+
+    // The 2:1 image scaled to a height of 4 and cropped to its middle.
+    let crop = preprocess(
+        red_blue_png(),
+        options(ResizeMode::CenterCrop, TensorLayout::Chw),
+    )
+    .unwrap();
+    assert_eq!(&crop[..4], &[1.0, 1.0, 0.0, 0.0]);
+
+    // Letterboxed: rows 0 and 3 are black padding.
+    let pad = preprocess(red_blue_png(), options(ResizeMode::Pad, TensorLayout::Chw)).unwrap();
+    assert_eq!(&pad[..4], &[0.0; 4]);
+    assert_eq!(&pad[4..8], &[1.0, 1.0, 0.0, 0.0]);
+    assert_eq!(&pad[12..16], &[0.0; 4]);
+}
+
+#[test]
+fn normalization_and_raw_pixels() {
+    let pixels = RgbaPixels {
+        width: 1,
+        height: 1,
+        rgba: vec![255, 51, 0, 7],
+    };
+    let mut options = options(ResizeMode::Stretch, TensorLayout::Chw);
+    options.width = 1;
+    options.height = 1;
+    options.mean = vec![0.5; 3];
+    options.std = vec![0.5; 3];
+    let values = preprocess_rgba(pixels.clone(), options.clone()).unwrap();
+    assert_eq!(values.len(), 3);
+    assert!((values[0] - 1.0).abs() < 1e-6);
+    assert!((values[1] + 0.6).abs() < 1e-6);
+    assert!((values[2] + 1.0).abs() < 1e-6);
+
+    options.mean = vec![0.5];
+    assert!(preprocess_rgba(pixels.clone(), options.clone()).is_err());
+    options.mean = vec![0.5; 3];
+    options.crop_fraction = Some(0.0);
+    options.mode = ResizeMode::CenterCrop;
+    assert!(preprocess_rgba(pixels, options.clone()).is_err());
+    assert!(preprocess(b"not an image".to_vec(), options).is_err());
+}
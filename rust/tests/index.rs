@@ -0,0 +1,169 @@
+use flutter_embedder::api::index::{VectorIndex, VectorIndexOptions};
+
+fn random_vectors(count: usize, dim: usize) -> Vec<Vec<f32>> {
+    let mut state = 42u64;
+    (0..count)
+        .map(|_| {
+            (0..dim)
+                .map(|_| {
+                    state = state
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    ((state >> 33) as f32 / u32::MAX as f32) - 0.25
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let na: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let nb: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    dot / (na * nb)
+}
+
+fn build(vectors: &[Vec<f32>]) -> VectorIndex {
+    let mut index = VectorIndex::new(None);
+    let ids = (0..vectors.len()).map(|i| i.to_string()).collect();
+    index.add_many(ids, vectors.to_vec()).unwrap();
+    index
+}
+
+#[test]
+fn search_matches_brute_force() {
+    let vectors = random_vectors(500, 32);
+    let index = build(&vectors);
+    assert_eq!(index.len(), 500);
+    assert_eq!(index.dimension(), 32);
+
+    let queries = random_vectors(520, 32).split_off(500);
+    let mut hits = 0;
+    for query in &queries {
+        let mut exact: Vec<(usize, f32)> = vectors
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (i, cosine(query, v)))
+            .collect();
+        exact.sort_by(|a, b| b.1.total_cmp(&a.1));
+        let found = index.search(query.clone(), 10).unwrap();
+        assert_eq!(found.len(), 10);
+        assert!(found.windows(2).all(|w| w[0].score >= w[1].score));
+        hits += exact[..10]
+            .iter()
+            .filter(|(i, _)| found.iter().any(|h| h.id == i.to_string()))
+            .count();
+    }
+    let recall = hits as f32 / (queries.len() * 10) as f32;
+    assert!(recall >= 0.9, "recall {recall}");
+}
+
+#[test]
+fn remove_and_replace() {
+    let vectors = random_vectors(200, 8);
+    let mut index = build(&vectors);
+
+    let top = index.search(vectors[5].clone(), 1).unwrap();
+    assert_eq!(top[0].id, "5");
+    assert!((top[0].score - 1.0).abs() < 1e-5);
+
+    assert!(index.remove("5".to_string()));
+    assert!(!index.remove("5".to_string()));
+    assert!(!index.contains("5".to_string()));
+    let top = index.search(vectors[5].clone(), 3).unwrap();
+    assert!(top.iter().all(|h| h.id != "5"));
+
+    // Re-adding an id replaces its vector.
+    index.add("7".to_string(), vectors[5].clone()).unwrap();
+    assert_eq!(index.len(), 199);
+    let top = index.search(vectors[5].clone(), 1).unwrap();
+    assert_eq!(top[0].id, "7");
+
+    index.compact();
+    assert_eq!(index.len(), 199);
+    let top = index.search(vectors[5].clone(), 1).unwrap();
+    assert_eq!(top[0].id, "7");
+
+    assert!(index.add("x".to_string(), vec![1.0; 3]).is_err());
+    assert!(index.search(vec![1.0; 3], 1).is_err());
+}
+
+#[test]
+fn save_and_load() {
+    let vectors = random_vectors(300, 16);
+    let mut index = VectorIndex::new(Some(VectorIndexOptions {
+        m: Some(8),
+        ..Default::default()
+    }));
+    let ids = (0..vectors.len()).map(|i| i.to_string()).collect();
+    index.add_many(ids, vectors.clone()).unwrap();
+    index.remove("3".to_string());
+
+    let path = std::env::temp_dir().join(format!("vector_index_{}.bin", std::process::id()));
+    let path = path.to_str().unwrap().to_string();
+    index.save(path.clone()).unwrap();
+    let loaded = VectorIndex::load(path.clone()).unwrap();
+    let mut mapped = VectorIndex::open(path.clone()).unwrap();
+
+    assert_eq!(loaded.len(), index.len());
+    assert_eq!(mapped.len(), index.len());
+    for query in vectors.iter().take(20) {
+        let expected = index.search(query.clone(), 5).unwrap();
+        assert_eq!(loaded.search(query.clone(), 5).unwrap(), expected);
+        assert_eq!(mapped.search(query.clone(), 5).unwrap(), expected);
+    }
+
+    // Adding to a mapped index copies it to memory first.
+    mapped.add("new".to_string(), vectors[3].clone()).unwrap();
+    drop(loaded);
+    std::fs::remove_file(&path).unwrap();
+    let top = mapped.search(vectors[3].clone(), 1).unwrap();
+    assert_eq!(top[0].id, "new");
+    assert_eq!(mapped.search(vectors[4].clone(), 1).unwrap()[0].id, "4");
+
+    std::fs::write(&path, b"nope").unwrap();
+    assert!(VectorIndex::load(path.clone()).is_err());
+    assert!(VectorIndex::open(path.clone()).is_err());
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn save_back_to_the_opened_file() {
+    let vectors = random_vectors(2000, 64);
+    let path = std::env::temp_dir().join(format!("vector_index_{}_save.bin", std::process::id()));
+    let path = path.to_str().unwrap().to_string();
+    build(&vectors).save(path.clone()).unwrap();
+
+    let mut mapped = VectorIndex::open(path.clone()).unwrap();
+    assert!(mapped.remove("7".to_string()));
+    mapped.save(path.clone()).unwrap();
+    assert_eq!(mapped.search(vectors[8].clone(), 1).unwrap()[0].id, "8");
+
+    let reopened = VectorIndex::open(path.clone()).unwrap();
+    assert_eq!(reopened.len(), 1999);
+    assert!(!reopened.contains("7".to_string()));
+    assert_eq!(reopened.search(vectors[8].clone(), 1).unwrap()[0].id, "8");
+    drop(mapped);
+    drop(reopened);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn corrupt_lengths_are_rejected() {
+    let path =
+        std::env::temp_dir().join(format!("vector_index_{}_corrupt.bin", std::process::id()));
+    let path = path.to_str().unwrap().to_string();
+    build(&random_vectors(2, 4)).save(path.clone()).unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+
+    // 40-byte header and 2 × 4 floats, then node 0: id length, id "0",
+    // deleted flag, level count.
+    for offset in [72, 78] {
+        let mut corrupt = bytes.clone();
+        corrupt[offset..offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        std::fs::write(&path, &corrupt).unwrap();
+        assert!(VectorIndex::load(path.clone()).is_err());
+        assert!(VectorIndex::open(path.clone()).is_err());
+    }
+    std::fs::remove_file(&path).unwrap();
+}
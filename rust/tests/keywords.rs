@@ -0,0 +1,34 @@
+use flutter_embedder::api::embeddings::TokenSalience;
+use flutter_embedder::api::keywords::keywords_from_salience;
+use flutter_embedder::api::tokenizer::TokenOffsets;
+
+fn span(start: u32, end: u32) -> TokenOffsets {
+    TokenOffsets { start, end }
+}
+
+#[test]
+fn keyword_weights_merge_subwords() {
+    let text = "the tokenizer works".to_string();
+    let salience = TokenSalience {
+        tokens: ["[CLS]", "the", "token", "##izer", "works", "[SEP]"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+        offsets: vec![
+            span(0, 0),
+            span(0, 3),
+            span(4, 9),
+            span(9, 13),
+            span(14, 19),
+            span(0, 0),
+        ],
+        scores: vec![0.5, 0.05, 0.2, 0.15, 0.1, 0.5],
+    };
+
+    let keywords = keywords_from_salience(text, salience, Some(2));
+    assert_eq!(keywords.len(), 2);
+    assert_eq!(keywords[0].word, "tokenizer");
+    assert_eq!((keywords[0].start, keywords[0].end), (4, 13));
+    assert!((keywords[0].weight - 0.35 / 0.5).abs() < 1e-5);
+    assert_eq!(keywords[1].word, "works");
+}
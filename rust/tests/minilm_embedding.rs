@@ -1,5 +1,19 @@
+use flutter_embedder::api::chunking::TextChunk;
+use flutter_embedder::api::embeddings::generic::GenericEmbedder;
 use flutter_embedder::api::embeddings::minilm::MiniLmEmbedder;
+use flutter_embedder::api::embeddings::{
+    create_embedder, dispose_embedder, embed_with, embedder_kind, list_embedders, load_embedder,
+    BatchOptions, CancellationToken, EmbedManyOptions, EmbedProgress, EmbedderKind,
+};
+use flutter_embedder::api::error::ErrorKind;
+use flutter_embedder::api::evaluation::{
+    compare_embedders, verify_against_reference, verify_model, ReferenceCase,
+};
 use flutter_embedder::api::ort::init_ort;
+use flutter_embedder::api::queue::{job_status, submit_job, take_job_result};
+use flutter_embedder::api::tokenizer::{
+    count_tokens, dispose_tokenizer, encode, load_tokenizer_from_file, set_truncation,
+};
 use ndarray::{Array, Array2};
 
 mod config;
@@ -14,7 +28,7 @@ fn minilm_embedding_test() {
     let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
 
     init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
-    let mut embedder = MiniLmEmbedder::create(model_path, tokenizer_path).unwrap();
+    let embedder = MiniLmEmbedder::create(model_path, tokenizer_path).unwrap();
 
     let sentences = [
         MiniLmEmbedder::format_query("This is an example sentence".to_string()),
@@ -26,10 +40,560 @@ fn minilm_embedding_test() {
     let embedding_size = outputs[0].len();
     assert_eq!(embedding_size, 384);
 
-    let embeddings: Array2<f32> = Array::from_shape_vec(
-        (2, embedding_size),
-        outputs.into_iter().flatten().collect(),
+    let embeddings: Array2<f32> =
+        Array::from_shape_vec((2, embedding_size), outputs.into_iter().flatten().collect())
+            .unwrap();
+    println!("{:?}", embeddings);
+}
+
+#[test]
+fn minilm_token_embeddings_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let embedder = MiniLmEmbedder::create(model_path.clone(), tokenizer_path.clone()).unwrap();
+
+    let texts = vec![
+        "short".to_string(),
+        "a somewhat longer sentence".to_string(),
+    ];
+    let outputs = embedder.embed_tokens(texts.clone()).unwrap();
+    assert_eq!(outputs.len(), 2);
+    for output in &outputs {
+        assert_eq!(output.tokens.len(), output.embeddings.len());
+        assert_eq!(output.offsets.len(), output.embeddings.len());
+        assert!(output.embeddings.iter().all(|e| e.len() == 384));
+    }
+    // Padding positions are dropped, so the shorter text has fewer tokens.
+    assert!(outputs[0].tokens.len() < outputs[1].tokens.len());
+
+    let dynamic = create_embedder(EmbedderKind::MiniLm, model_path, tokenizer_path, None).unwrap();
+    let dynamic_outputs = dynamic.embed_tokens(texts).unwrap();
+    assert_eq!(dynamic_outputs[1].tokens, outputs[1].tokens);
+    assert_eq!(dynamic_outputs[1].embeddings, outputs[1].embeddings);
+}
+
+#[test]
+fn minilm_late_chunking_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let embedder = MiniLmEmbedder::create(model_path, tokenizer_path).unwrap();
+
+    let text = "Berlin is the capital of Germany. Its population is 3.85 million.";
+    let chunk = |start: u32, end: u32| TextChunk {
+        text: text[start as usize..end as usize].to_string(),
+        start,
+        end,
+        token_count: 0,
+    };
+    let chunks = vec![chunk(0, 33), chunk(34, text.len() as u32)];
+    let outputs = embedder
+        .embed_late_chunks(text.to_string(), chunks.clone())
+        .unwrap();
+    assert_eq!(outputs.len(), 2);
+    for output in &outputs {
+        assert_eq!(output.len(), 384);
+        let norm: f32 = output.iter().map(|x| x * x).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-4);
+    }
+    // Each chunk is pooled in the context of the whole text.
+    let separate = embedder.embed(vec![chunks[1].text.clone()]).unwrap();
+    assert_ne!(outputs[1], separate[0]);
+
+    let out_of_range = TextChunk {
+        end: 500,
+        ..chunks[0].clone()
+    };
+    let e = embedder
+        .embed_late_chunks(text.to_string(), vec![out_of_range])
+        .unwrap_err();
+    assert_eq!(e.kind, ErrorKind::InvalidInput);
+}
+
+#[test]
+fn minilm_embed_many_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let embedder = MiniLmEmbedder::create(model_path, tokenizer_path).unwrap();
+
+    let texts: Vec<String> = [
+        "a rather long sentence about the weather in the mountains today",
+        "short",
+        "medium length text",
+        "tiny",
+        "another sentence of moderate size",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    let expected = embedder.embed(texts.clone()).unwrap();
+
+    let progress = EmbedProgress::new();
+    let options = EmbedManyOptions {
+        batch_size: Some(2),
+        sort_by_length: Some(true),
+    };
+    let outputs = embedder
+        .embed_many_with_progress(texts, Some(options), &progress)
+        .unwrap();
+    assert_eq!(progress.completed(), 5);
+    assert_eq!(progress.total(), 5);
+
+    // Chunked, length-sorted results come back in input order.
+    for (a, b) in expected.iter().zip(&outputs) {
+        let sim: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        assert!(sim > 0.999, "similarity {sim}");
+    }
+}
+
+#[test]
+fn minilm_instructions_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let embedder = MiniLmEmbedder::create(model_path, tokenizer_path).unwrap();
+
+    let text = "The giant panda is a bear species endemic to China.".to_string();
+    let plain = embedder.embed(vec![text.clone()]).unwrap();
+    let outputs = embedder
+        .embed_with_instructions(vec![
+            (None, text.clone()),
+            (Some("Represent the document: ".to_string()), text.clone()),
+        ])
+        .unwrap();
+    assert_eq!(outputs.len(), 2);
+
+    let dot = |a: &[f32], b: &[f32]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f32>();
+    // Items without an instruction match plain `embed`.
+    assert!(dot(&plain[0], &outputs[0]) > 0.999);
+    // The instruction conditions the text but isn't pooled itself.
+    let with_prefix = embedder
+        .embed(vec![format!("Represent the document: {text}")])
+        .unwrap();
+    assert!(dot(&outputs[1], &with_prefix[0]) < 0.999);
+    assert!(dot(&outputs[1], &plain[0]) > 0.8);
+}
+
+#[test]
+fn minilm_create_embedder_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let embedder = create_embedder(EmbedderKind::MiniLm, model_path, tokenizer_path, None).unwrap();
+    assert_eq!(embedder.kind(), EmbedderKind::MiniLm);
+    assert_eq!(embedder.dimension().unwrap(), 384);
+
+    let query = embedder.format_query("This is an example sentence".to_string());
+    let outputs = embedder.embed(vec![query]).unwrap();
+    assert_eq!(outputs[0].len(), 384);
+}
+
+#[test]
+fn minilm_verify_model_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let embedder = create_embedder(EmbedderKind::MiniLm, model_path, tokenizer_path, None).unwrap();
+    let inputs = vec![
+        "The quick brown fox jumps over the lazy dog.".to_string(),
+        "A cat sleeps on the windowsill.".to_string(),
+    ];
+    let mut references = embedder.embed(inputs.clone()).unwrap();
+
+    let verification = verify_model(&embedder, inputs.clone(), references.clone(), 1e-4).unwrap();
+    assert!(verification.passed);
+    assert!(verification.max_deviation < 1e-4);
+
+    references[1] = references[0].clone();
+    let verification = verify_model(&embedder, inputs.clone(), references.clone(), 1e-4).unwrap();
+    assert!(!verification.passed);
+    assert!(verification.checks[0].passed && !verification.checks[1].passed);
+
+    assert!(verify_model(&embedder, inputs, vec![], 1e-4).is_err());
+}
+
+#[test]
+fn minilm_compare_embedders_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let a = load_embedder(
+        EmbedderKind::MiniLm,
+        model_path.clone(),
+        tokenizer_path.clone(),
+        None,
     )
     .unwrap();
-    println!("{:?}", embeddings);
+    let b = load_embedder(EmbedderKind::MiniLm, model_path, tokenizer_path, None).unwrap();
+    let texts = vec![
+        "The quick brown fox jumps over the lazy dog.".to_string(),
+        "A cat sleeps on the windowsill.".to_string(),
+        "Stock markets fell sharply today.".to_string(),
+        "The dog chased the fox.".to_string(),
+    ];
+
+    let comparison = compare_embedders(a, b, texts.clone(), Some(2)).unwrap();
+    assert_eq!(comparison.count, 4);
+    assert_eq!(comparison.k, 2);
+    assert!(comparison.pearson > 0.999);
+    assert!((comparison.neighbor_overlap - 1.0).abs() < 1e-6);
+    assert_eq!(comparison.stats_a.dimension, 384);
+    assert_eq!(comparison.stats_b.dimension, 384);
+
+    assert!(dispose_embedder(b).unwrap());
+    assert!(compare_embedders(a, b, texts, None).is_err());
+    assert!(dispose_embedder(a).unwrap());
+}
+
+#[test]
+fn minilm_verify_against_reference_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let id = load_embedder(EmbedderKind::MiniLm, model_path, tokenizer_path, None).unwrap();
+    let texts = vec![
+        "The quick brown fox jumps over the lazy dog.".to_string(),
+        "A cat sleeps on the windowsill.".to_string(),
+    ];
+    let references = embed_with(id, texts.clone()).unwrap();
+
+    let cases = vec![
+        ReferenceCase {
+            text: texts[0].clone(),
+            expected: references[0].clone(),
+            tolerance: 1e-4,
+        },
+        // Wrong reference, but within a tolerance loose enough to accept it.
+        ReferenceCase {
+            text: texts[1].clone(),
+            expected: references[0].clone(),
+            tolerance: 2.0,
+        },
+        ReferenceCase {
+            text: texts[1].clone(),
+            expected: references[0].clone(),
+            tolerance: 1e-4,
+        },
+    ];
+    let checks = verify_against_reference(id, cases.clone()).unwrap();
+    assert_eq!(checks.len(), 3);
+    assert!(checks[0].passed && checks[0].deviation < 1e-4);
+    assert!(checks[1].passed);
+    assert!(!checks[2].passed);
+
+    assert!(dispose_embedder(id).unwrap());
+    assert!(verify_against_reference(id, cases).is_err());
+}
+
+#[test]
+fn minilm_tokenizer_id_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let mut embedder = create_embedder(
+        EmbedderKind::MiniLm,
+        model_path,
+        tokenizer_path.clone(),
+        None,
+    )
+    .unwrap();
+    let tokenizer_id = embedder.tokenizer_id().unwrap();
+    assert_eq!(embedder.tokenizer_id().unwrap(), tokenizer_id);
+
+    let text = "The quick brown fox jumps over the lazy dog.".to_string();
+    let separate = load_tokenizer_from_file(tokenizer_path).unwrap();
+    let expected = encode(separate, text.clone(), None).unwrap().ids;
+    assert_eq!(
+        encode(tokenizer_id, text.clone(), None).unwrap().ids,
+        expected
+    );
+
+    // Reconfiguring the registered tokenizer doesn't touch the embedder's.
+    let before = embedder.embed(vec![text.clone()]).unwrap();
+    set_truncation(tokenizer_id, 4, None, None).unwrap();
+    assert_eq!(count_tokens(tokenizer_id, text.clone(), None).unwrap(), 4);
+    assert_eq!(embedder.embed(vec![text.clone()]).unwrap(), before);
+    assert_eq!(embedder.tokenizer_id().unwrap(), tokenizer_id);
+
+    embedder.close();
+    assert!(embedder.tokenizer_id().is_err());
+    assert!(dispose_tokenizer(tokenizer_id).unwrap());
+}
+
+#[test]
+fn minilm_embedder_registry_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let id = load_embedder(EmbedderKind::MiniLm, model_path, tokenizer_path, None).unwrap();
+    assert!(list_embedders().unwrap().contains(&id));
+    assert_eq!(embedder_kind(id).unwrap(), EmbedderKind::MiniLm);
+
+    let texts = vec!["This is an example sentence".to_string()];
+    let outputs = std::thread::spawn(move || embed_with(id, texts).unwrap())
+        .join()
+        .unwrap();
+    assert_eq!(outputs[0].len(), 384);
+
+    assert!(dispose_embedder(id).unwrap());
+    assert!(!dispose_embedder(id).unwrap());
+    assert!(embed_with(id, vec!["gone".to_string()]).is_err());
+}
+
+#[test]
+fn minilm_queue_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let id = load_embedder(EmbedderKind::MiniLm, model_path, tokenizer_path, None).unwrap();
+    let texts = vec!["First sentence".to_string(), "Second sentence".to_string()];
+    let expected = embed_with(id, texts.clone()).unwrap();
+
+    let job = submit_job(id, texts, 1).unwrap();
+    let started = std::time::Instant::now();
+    let outputs = loop {
+        if let Some(outputs) = take_job_result(job).unwrap() {
+            break outputs;
+        }
+        assert!(started.elapsed().as_secs() < 30);
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    };
+    assert_eq!(outputs, expected);
+    assert!(job_status(job).unwrap().is_none());
+    dispose_embedder(id).unwrap();
+}
+
+#[test]
+fn minilm_embed_windows_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let tokenizer_id = load_tokenizer_from_file(tokenizer_path.clone()).unwrap();
+    let embedder = create_embedder(EmbedderKind::MiniLm, model_path, tokenizer_path, None).unwrap();
+
+    let text = "one two three four five six seven eight nine ten".to_string();
+    let windows = embedder
+        .embed_windows(tokenizer_id, text.clone(), 4, 2)
+        .unwrap();
+    assert!(windows.len() > 1);
+    assert_eq!(windows[0].passage.start, 0);
+    assert_eq!(windows.last().unwrap().passage.end, text.len() as u32);
+    for window in &windows {
+        assert_eq!(window.embedding.len(), 384);
+        let span: String = text
+            .chars()
+            .skip(window.passage.start as usize)
+            .take((window.passage.end - window.passage.start) as usize)
+            .collect();
+        assert_eq!(span, window.passage.text);
+    }
+    assert!(windows[1].passage.start < windows[0].passage.end);
+
+    assert!(embedder.embed_windows(tokenizer_id, text, 4, 5).is_err());
+}
+
+#[test]
+fn minilm_cancellation_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let embedder = MiniLmEmbedder::create(model_path, tokenizer_path).unwrap();
+    let texts = vec!["This is an example sentence".to_string()];
+
+    let token = CancellationToken::new();
+    let outputs = embedder
+        .embed_async_cancellable(texts.clone(), &token)
+        .unwrap();
+    assert_eq!(outputs, embedder.embed(texts.clone()).unwrap());
+
+    token.cancel();
+    assert!(token.is_cancelled());
+    let err = embedder.embed_async_cancellable(texts, &token).unwrap_err();
+    assert!(err.to_string().contains("cancelled"), "{err}");
+}
+
+#[test]
+fn minilm_from_bytes_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let model_bytes = std::fs::read(&model_path).unwrap();
+    let tokenizer_json = std::fs::read_to_string(&tokenizer_path).unwrap();
+    let from_file = MiniLmEmbedder::create(model_path, tokenizer_path).unwrap();
+    let from_bytes = MiniLmEmbedder::create_from_bytes(model_bytes, tokenizer_json).unwrap();
+
+    let texts = vec!["This is an example sentence".to_string()];
+    let expected = from_file.embed(texts.clone()).unwrap();
+    let actual = from_bytes.embed(texts).unwrap();
+    for (a, b) in expected[0].iter().zip(&actual[0]) {
+        assert!((a - b).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn minilm_warmup_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let embedder = create_embedder(EmbedderKind::MiniLm, model_path, tokenizer_path, None).unwrap();
+    embedder.warmup().unwrap();
+    let outputs = embedder.embed(vec!["after warmup".to_string()]).unwrap();
+    assert_eq!(outputs[0].len(), 384);
+}
+
+#[test]
+fn minilm_generic_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let minilm = MiniLmEmbedder::create(model_path.clone(), tokenizer_path.clone()).unwrap();
+    let config =
+        r#"{ "pooling": "mean", "output": "last_hidden_state", "instructions": "instructor" }"#;
+    let generic = GenericEmbedder::create(model_path, tokenizer_path, config.to_string()).unwrap();
+
+    let texts = vec!["This is an example sentence".to_string()];
+    let expected = minilm.embed(texts.clone()).unwrap();
+    let actual = generic.embed(texts).unwrap();
+    for (a, b) in expected[0].iter().zip(&actual[0]) {
+        assert!((a - b).abs() < 1e-5);
+    }
+
+    let items = vec![(
+        Some("Represent the sentence: ".to_string()),
+        "This is an example sentence".to_string(),
+    )];
+    let expected = minilm.embed_with_instructions(items.clone()).unwrap();
+    let actual = generic.embed_with_instructions(items).unwrap();
+    for (a, b) in expected[0].iter().zip(&actual[0]) {
+        assert!((a - b).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn minilm_micro_batch_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let mut embedder = MiniLmEmbedder::create(model_path, tokenizer_path).unwrap();
+    let texts: Vec<String> = [
+        "a rather long sentence about the weather in the mountains today",
+        "short",
+        "medium length text",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    let expected = embedder.embed(texts.clone()).unwrap();
+
+    // Small limits split the input across several inference calls.
+    embedder.set_batch_options(BatchOptions {
+        max_batch_size: Some(2),
+        max_tokens_per_batch: Some(16),
+    });
+    let outputs = embedder.embed(texts).unwrap();
+    for (a, b) in expected.iter().zip(&outputs) {
+        let sim: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        assert!(sim > 0.999, "similarity {sim}");
+    }
+}
+
+#[test]
+fn minilm_padded_tokenizer_test() {
+    init_test_config();
+    let tokenizer_path: String = MINILM_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = MINILM_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("minilm_ort".to_string(), Some(ort_path)).unwrap();
+    let mut json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&tokenizer_path).unwrap()).unwrap();
+    json["padding"] = serde_json::json!({
+        "strategy": { "Fixed": 128 },
+        "direction": "Right",
+        "pad_to_multiple_of": null,
+        "pad_id": 0,
+        "pad_type_id": 0,
+        "pad_token": "[PAD]",
+    });
+    let padded_path =
+        std::env::temp_dir().join(format!("minilm_padded_{}.json", std::process::id()));
+    std::fs::write(&padded_path, json.to_string()).unwrap();
+    let padded_path = padded_path.to_str().unwrap().to_string();
+
+    let texts: Vec<String> = ["short", "medium length text", "a few more words than that"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let tokenizer = load_tokenizer_from_file(tokenizer_path.clone()).unwrap();
+    let real_tokens: u32 = texts
+        .iter()
+        .map(|text| count_tokens(tokenizer, text.clone(), None).unwrap())
+        .sum();
+    assert!(dispose_tokenizer(tokenizer).unwrap());
+
+    let embedder = MiniLmEmbedder::create(model_path.clone(), tokenizer_path).unwrap();
+    let padded = MiniLmEmbedder::create(model_path, padded_path.clone()).unwrap();
+    std::fs::remove_file(&padded_path).unwrap();
+    let expected = embedder.embed(texts.clone()).unwrap();
+    // Texts are measured, and micro-batched, at their own length rather
+    // than the tokenizer's fixed 128.
+    let result = padded.embed_with_stats(texts).unwrap();
+    assert_eq!(result.stats.tokens, real_tokens as u64);
+    for (a, b) in expected.iter().zip(&result.embeddings) {
+        let sim: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        assert!(sim > 0.999, "similarity {sim}");
+    }
 }
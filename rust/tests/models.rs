@@ -0,0 +1,77 @@
+use flutter_embedder::api::models::{
+    find_installed_model, list_installed_models, register_model, remove_installed_model,
+    sha256_file, InstalledModel,
+};
+
+fn model(dir: &std::path::Path, name: &str, revision: &str) -> InstalledModel {
+    let path = dir.join(format!("{name}-{revision}.onnx"));
+    std::fs::write(&path, name.as_bytes()).unwrap();
+    InstalledModel {
+        name: name.to_string(),
+        revision: revision.to_string(),
+        path: path.to_string_lossy().into_owned(),
+        size: name.len() as u64,
+    }
+}
+
+#[test]
+fn registry() {
+    let dir = std::env::temp_dir().join(format!("models_test_{}", std::process::id()));
+    let models_dir = dir.to_string_lossy().into_owned();
+    assert!(list_installed_models(models_dir.clone())
+        .unwrap()
+        .is_empty());
+
+    std::fs::create_dir_all(&dir).unwrap();
+    let bge_v1 = model(&dir, "bge", "v1");
+    let bge_v2 = model(&dir, "bge", "v2");
+    let minilm = model(&dir, "minilm", "main");
+    register_model(models_dir.clone(), minilm.clone()).unwrap();
+    register_model(models_dir.clone(), bge_v1.clone()).unwrap();
+    register_model(models_dir.clone(), bge_v2.clone()).unwrap();
+    // Re-registering replaces the entry instead of duplicating it.
+    register_model(models_dir.clone(), bge_v1.clone()).unwrap();
+
+    let installed = list_installed_models(models_dir.clone()).unwrap();
+    assert_eq!(
+        installed,
+        vec![bge_v1.clone(), bge_v2.clone(), minilm.clone()]
+    );
+    assert_eq!(
+        find_installed_model(models_dir.clone(), "bge".to_string(), None).unwrap(),
+        Some(bge_v1.clone())
+    );
+    assert_eq!(
+        find_installed_model(
+            models_dir.clone(),
+            "bge".to_string(),
+            Some("v2".to_string())
+        )
+        .unwrap(),
+        Some(bge_v2.clone())
+    );
+    assert_eq!(
+        find_installed_model(models_dir.clone(), "e5".to_string(), None).unwrap(),
+        None
+    );
+
+    assert!(remove_installed_model(models_dir.clone(), "bge".to_string(), None, true).unwrap());
+    assert!(!std::path::Path::new(&bge_v1.path).exists());
+    assert!(!remove_installed_model(models_dir.clone(), "bge".to_string(), None, true).unwrap());
+
+    // Entries whose file disappeared are not listed.
+    std::fs::remove_file(&minilm.path).unwrap();
+    assert!(list_installed_models(models_dir).unwrap().is_empty());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn sha256() {
+    let path = std::env::temp_dir().join(format!("models_sha256_{}", std::process::id()));
+    std::fs::write(&path, b"abc").unwrap();
+    assert_eq!(
+        sha256_file(path.to_string_lossy().into_owned()).unwrap(),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,50 @@
+use flutter_embedder::api::error::ErrorKind;
+use flutter_embedder::api::ner::NerTagger;
+use flutter_embedder::api::ort::init_ort;
+
+mod config;
+use config::{init_test_config, NER_CONFIG_PATH, NER_MODEL_PATH, NER_TOKENIZER_PATH, ORT_LIB_PATH};
+
+/// @reference https://huggingface.co/dslim/bert-base-NER
+#[test]
+fn ner_test() {
+    init_test_config();
+    let tokenizer_path: String = NER_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = NER_MODEL_PATH.get().unwrap().into();
+    let config_path: String = NER_CONFIG_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("ner_ort".to_string(), Some(ort_path)).unwrap();
+    let config_json = std::fs::read_to_string(config_path).unwrap();
+    let mut tagger = NerTagger::create(model_path, tokenizer_path, config_json).unwrap();
+
+    let text = "Wolfgang lives in Berlin and works for Siemens.".to_string();
+    let entities = tagger.extract(text.clone()).unwrap();
+    let found: Vec<(&str, &str)> = entities
+        .iter()
+        .map(|e| (e.label.as_str(), e.text.as_str()))
+        .collect();
+    assert_eq!(
+        found,
+        [("PER", "Wolfgang"), ("LOC", "Berlin"), ("ORG", "Siemens")]
+    );
+    for entity in &entities {
+        let span: String = text
+            .chars()
+            .skip(entity.start as usize)
+            .take((entity.end - entity.start) as usize)
+            .collect();
+        assert_eq!(span, entity.text);
+        assert!(entity.score > 0.5);
+    }
+}
+
+#[test]
+fn invalid_config() {
+    let tagger = NerTagger::create(
+        "missing.onnx".to_string(),
+        "missing.json".to_string(),
+        r#"{"id2label": {"0": "O", "5": "B-PER"}}"#.to_string(),
+    );
+    assert_eq!(tagger.err().unwrap().kind, ErrorKind::InvalidInput);
+}
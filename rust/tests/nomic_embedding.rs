@@ -0,0 +1,56 @@
+use flutter_embedder::api::embeddings::nomic::{NomicEmbedder, NomicTask};
+use flutter_embedder::api::ort::init_ort;
+use flutter_embedder::api::utils::cosine_distance;
+
+mod config;
+use config::{init_test_config, NOMIC_EMBEDDING_MODEL_PATH, NOMIC_TOKENIZER_PATH, ORT_LIB_PATH};
+
+/// @reference https://huggingface.co/nomic-ai/nomic-embed-text-v1.5
+#[test]
+fn nomic_embedding_test() {
+    init_test_config();
+    let tokenizer_path: String = NOMIC_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = NOMIC_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("nomic_ort".to_string(), Some(ort_path)).unwrap();
+    let mut embedder = NomicEmbedder::create(model_path, tokenizer_path).unwrap();
+
+    assert_eq!(
+        NomicEmbedder::format_with_task("x".to_string(), NomicTask::Clustering),
+        "clustering: x"
+    );
+    let query = NomicEmbedder::format_query("What is TSNE?".to_string());
+    let docs = vec![
+        "TSNE is a dimensionality reduction algorithm created by Laurens van der Maaten"
+            .to_string(),
+        "Pandas are bears native to China".to_string(),
+    ];
+
+    let query_embedding = embedder.embed(vec![query.clone()]).unwrap().remove(0);
+    let doc_embeddings = embedder
+        .embed_with_task(docs.clone(), NomicTask::SearchDocument)
+        .unwrap();
+    assert_eq!(query_embedding.len(), 768);
+    let relevant = cosine_distance(query_embedding.clone(), doc_embeddings[0].clone()).unwrap();
+    let unrelated = cosine_distance(query_embedding, doc_embeddings[1].clone()).unwrap();
+    assert!(relevant < unrelated, "{relevant} vs {unrelated}");
+
+    // Matryoshka truncation keeps the ranking at a quarter of the size.
+    embedder.set_output_dim(Some(256));
+    let query_embedding = embedder.embed(vec![query]).unwrap().remove(0);
+    let doc_embeddings = embedder
+        .embed_with_task(docs, NomicTask::SearchDocument)
+        .unwrap();
+    assert_eq!(query_embedding.len(), 256);
+    let norm: f32 = query_embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+    assert!((norm - 1.0).abs() < 1e-4);
+    let relevant = cosine_distance(query_embedding.clone(), doc_embeddings[0].clone()).unwrap();
+    let unrelated = cosine_distance(query_embedding, doc_embeddings[1].clone()).unwrap();
+    assert!(relevant < unrelated, "{relevant} vs {unrelated}");
+
+    embedder.set_output_dim(Some(4096));
+    assert!(embedder
+        .embed(vec!["search_query: too wide".to_string()])
+        .is_err());
+}
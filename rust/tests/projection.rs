@@ -0,0 +1,76 @@
+use flutter_embedder::api::projection::{project_embeddings, ProjectionOptions};
+use flutter_embedder::api::utils::pca_project;
+
+fn blob(center: &[f32], count: usize, jitter: f32) -> Vec<Vec<f32>> {
+    (0..count)
+        .map(|i| {
+            center
+                .iter()
+                .enumerate()
+                .map(|(d, c)| c + jitter * (((i * 7 + d * 13) % 11) as f32 / 11.0 - 0.5))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn projection_separates_clusters() {
+    let mut embeddings = blob(&[1.0, 0.0, 0.0, 0.0, 0.0, 0.0], 10, 0.1);
+    embeddings.extend(blob(&[0.0, 0.0, 0.0, 1.0, 1.0, 0.0], 10, 0.1));
+
+    let result = project_embeddings(
+        embeddings,
+        Some(ProjectionOptions {
+            clusters: Some(2),
+            ..Default::default()
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(result.coordinates.len(), 20);
+    assert!(result.coordinates.iter().all(|c| c.len() == 2));
+    assert_eq!(result.cluster_count, 2);
+    assert!(result.clusters[..10]
+        .iter()
+        .all(|&c| c == result.clusters[0]));
+    assert!(result.clusters[10..]
+        .iter()
+        .all(|&c| c == result.clusters[10]));
+    assert_ne!(result.clusters[0], result.clusters[10]);
+}
+
+#[test]
+fn projection_is_deterministic_and_validates_input() {
+    let embeddings = blob(&[0.5, -0.5, 0.25, 1.0], 12, 0.5);
+    let options = ProjectionOptions {
+        dims: Some(3),
+        seed: Some(7),
+        ..Default::default()
+    };
+    let a = project_embeddings(embeddings.clone(), Some(options.clone())).unwrap();
+    let b = project_embeddings(embeddings, Some(options)).unwrap();
+    assert_eq!(a.coordinates, b.coordinates);
+    assert!(a.coordinates.iter().all(|c| c.len() == 3));
+
+    assert!(project_embeddings(vec![], None).is_err());
+    assert!(project_embeddings(vec![vec![1.0, 2.0], vec![1.0]], None).is_err());
+}
+
+#[test]
+fn pca_keeps_the_dominant_axis() {
+    // Points spread along x with a little noise on y and z.
+    let embeddings: Vec<Vec<f32>> = (0..8)
+        .map(|i| vec![i as f32, 0.01 * (i % 3) as f32, -0.01 * (i % 2) as f32])
+        .collect();
+    let coords = pca_project(embeddings, 1).unwrap();
+    assert_eq!(coords.len(), 8);
+    // Same spacing as the inputs along x, up to sign.
+    let step = coords[1][0] - coords[0][0];
+    assert!((step.abs() - 1.0).abs() < 0.05, "step {step}");
+    for w in coords.windows(2) {
+        assert!((w[1][0] - w[0][0] - step).abs() < 0.05);
+    }
+
+    assert!(pca_project(vec![vec![1.0, 2.0]], 0).is_err());
+    assert!(pca_project(vec![vec![1.0, 2.0]], 3).is_err());
+}
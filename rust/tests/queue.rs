@@ -0,0 +1,63 @@
+use flutter_embedder::api::error::ErrorKind;
+use flutter_embedder::api::queue::{
+    cancel_job, is_queue_paused, job_status, list_jobs, pause_queue, resume_queue,
+    set_queue_concurrency, submit_job, take_job_result, JobState,
+};
+use std::time::{Duration, Instant};
+
+fn wait_for(job_id: u64, state: JobState) {
+    let started = Instant::now();
+    while job_status(job_id).unwrap().unwrap().state != state {
+        assert!(
+            started.elapsed() < Duration::from_secs(10),
+            "job never became {state:?}"
+        );
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn queue_pause_cancel_and_fail() {
+    assert!(set_queue_concurrency(0).is_err());
+    set_queue_concurrency(2).unwrap();
+
+    pause_queue().unwrap();
+    assert!(is_queue_paused().unwrap());
+    let low = submit_job(u64::MAX, vec!["a".into()], 0).unwrap();
+    let high = submit_job(u64::MAX, vec!["b".into(), "c".into()], 5).unwrap();
+    let cancelled = submit_job(u64::MAX, vec!["d".into()], 1).unwrap();
+
+    let status = job_status(high).unwrap().unwrap();
+    assert_eq!(status.state, JobState::Queued);
+    assert_eq!(status.priority, 5);
+    assert_eq!(status.text_count, 2);
+    assert_eq!(
+        list_jobs()
+            .unwrap()
+            .iter()
+            .map(|s| s.job_id)
+            .collect::<Vec<_>>(),
+        [low, high, cancelled]
+    );
+    assert_eq!(take_job_result(low).unwrap(), None);
+
+    assert!(cancel_job(cancelled).unwrap());
+    assert!(!cancel_job(cancelled).unwrap());
+    assert_eq!(
+        take_job_result(cancelled).unwrap_err().kind,
+        ErrorKind::Cancelled
+    );
+    assert!(job_status(cancelled).unwrap().is_none());
+
+    // No embedder is loaded under the id, so both jobs fail once resumed.
+    resume_queue().unwrap();
+    assert!(!is_queue_paused().unwrap());
+    wait_for(low, JobState::Failed);
+    wait_for(high, JobState::Failed);
+    assert_eq!(
+        take_job_result(high).unwrap_err().kind,
+        ErrorKind::InvalidInput
+    );
+    assert!(take_job_result(high).is_err());
+    assert!(!cancel_job(low).unwrap());
+}
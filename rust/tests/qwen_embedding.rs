@@ -14,7 +14,7 @@ fn qwen_embedding() {
     let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
 
     init_ort("qwen_ort".to_string(), Some(ort_path)).unwrap();
-    let mut embedder = Qwen3Embedder::create(model_path, tokenizer_path).unwrap();
+    let embedder = Qwen3Embedder::create(model_path, tokenizer_path).unwrap();
 
     // Each query must come with a one-sentence instruction that describes the task
     let queries = [
@@ -30,19 +30,67 @@ fn qwen_embedding() {
         .chain(documents.iter().map(|s| s.to_string()))
         .collect::<Vec<String>>();
     let outputs = embedder.embed(inputs).unwrap();
-    let queries: Array2<f32> = Array::from_shape_vec(
-        (2, 1024),
-        outputs[0..2].to_vec().into_iter().flatten().collect(),
-    )
-    .unwrap();
-    let docs = Array::from_shape_vec(
-        (2, 1024),
-        outputs[2..4].to_vec().into_iter().flatten().collect(),
-    )
-    .unwrap();
+    let queries: Array2<f32> =
+        Array::from_shape_vec((2, 1024), outputs[0..2].iter().flatten().copied().collect())
+            .unwrap();
+    let docs = Array::from_shape_vec((2, 1024), outputs[2..4].iter().flatten().copied().collect())
+        .unwrap();
 
     let sims = queries.dot(&docs.t());
     println!("Similarities:\n{sims}");
     let target_sims = array![0.7646, 0.1414, 0.1355, 0.6000];
     assert!(sims.flatten().dot(&target_sims.t()) > 0.98); // cosine similarity
 }
+
+#[test]
+fn qwen_embedding_appends_eos() {
+    init_test_config();
+    let tokenizer_path: String = QWEN_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = QWEN_EMBEDDING_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("qwen_ort".to_string(), Some(ort_path)).unwrap();
+    let mut embedder = Qwen3Embedder::create(model_path, tokenizer_path).unwrap();
+    assert!(embedder.eos_token_id().is_some());
+
+    let query = Qwen3Embedder::format_query("What is the capital of China?".to_string());
+    let document = Qwen3Embedder::format_document("The capital of China is Beijing.".to_string());
+    let auto = embedder
+        .embed(vec![query.clone(), document.clone()])
+        .unwrap();
+
+    // Appending EOS by hand with auto-append disabled must match.
+    embedder.set_append_eos(false);
+    let manual = embedder
+        .embed(vec![
+            format!("{query}<|endoftext|>"),
+            format!("{document}<|endoftext|>"),
+        ])
+        .unwrap();
+    for (a, m) in auto.iter().zip(&manual) {
+        let sim: f32 = a.iter().zip(m).map(|(x, y)| x * y).sum();
+        assert!(sim > 0.999, "auto/manual EOS similarity {sim}");
+    }
+
+    // Reference similarity from the model card for the matching pair.
+    let sim: f32 = auto[0].iter().zip(&auto[1]).map(|(x, y)| x * y).sum();
+    assert!(
+        (sim - 0.7646).abs() < 0.02,
+        "query/document similarity {sim}"
+    );
+}
+
+#[test]
+fn qwen_format_query_with_task() {
+    assert_eq!(
+        Qwen3Embedder::format_query_with_task(
+            "Classify the sentiment of a review".to_string(),
+            "Great value".to_string()
+        ),
+        "Instruct: Classify the sentiment of a review\nQuery:Great value"
+    );
+    assert_eq!(
+        Qwen3Embedder::format_query("Explain gravity".to_string()),
+        "Instruct: Given a web search query, retrieve relevant passages that answer the query\nQuery:Explain gravity"
+    );
+}
@@ -0,0 +1,34 @@
+use flutter_embedder::api::ort::init_ort;
+use flutter_embedder::api::rerank::{Reranker, RerankerKind};
+
+mod config;
+use config::{
+    init_test_config, BGE_RERANKER_MODEL_PATH, BGE_RERANKER_TOKENIZER_PATH, ORT_LIB_PATH,
+};
+
+/// @reference https://huggingface.co/BAAI/bge-reranker-v2-m3
+#[test]
+fn bge_reranker_test() {
+    init_test_config();
+    let tokenizer_path: String = BGE_RERANKER_TOKENIZER_PATH.get().unwrap().into();
+    let model_path: String = BGE_RERANKER_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("rerank_ort".to_string(), Some(ort_path)).unwrap();
+    let mut reranker =
+        Reranker::create(model_path, tokenizer_path, RerankerKind::CrossEncoder).unwrap();
+
+    let query = "what is panda?".to_string();
+    let passages = vec![
+        "hi".to_string(),
+        "The giant panda (Ailuropoda melanoleuca), sometimes called a panda bear or simply panda, is a bear species endemic to China.".to_string(),
+    ];
+    let scores = reranker.score(query.clone(), passages.clone()).unwrap();
+    assert_eq!(scores.len(), 2);
+    assert!(scores[1] > scores[0], "scores {scores:?}");
+
+    let ranked = reranker.rerank(query, passages, Some(1)).unwrap();
+    assert_eq!(ranked.len(), 1);
+    assert_eq!(ranked[0].index, 1);
+    assert_eq!(ranked[0].score, scores[1]);
+}
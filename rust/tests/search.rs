@@ -0,0 +1,37 @@
+use flutter_embedder::api::search::{
+    adaptive_cutoff, filter_by_threshold, CutoffOptions, SearchHit,
+};
+
+fn hits(scores: &[f32]) -> Vec<SearchHit> {
+    scores
+        .iter()
+        .enumerate()
+        .map(|(i, &score)| SearchHit {
+            id: format!("doc{i}"),
+            score,
+        })
+        .collect()
+}
+
+#[test]
+fn threshold_and_elbow_cutoff() {
+    let filtered = filter_by_threshold(hits(&[0.9, 0.2, 0.6]), 0.5);
+    assert_eq!(filtered.len(), 2);
+
+    // Clear gap after the third hit.
+    let confident = adaptive_cutoff(hits(&[0.25, 0.9, 0.88, 0.3, 0.86, 0.28]), None);
+    let ids: Vec<&str> = confident.iter().map(|h| h.id.as_str()).collect();
+    assert_eq!(ids, ["doc1", "doc2", "doc4"]);
+
+    // A smooth decline has no elbow, so everything is kept.
+    assert_eq!(adaptive_cutoff(hits(&[0.9, 0.8, 0.7, 0.6]), None).len(), 4);
+
+    let bounded = adaptive_cutoff(
+        hits(&[0.9, 0.88, 0.86, 0.3, 0.28, 0.25]),
+        Some(CutoffOptions {
+            min_score: Some(0.87),
+            ..Default::default()
+        }),
+    );
+    assert_eq!(bounded.len(), 2);
+}
@@ -0,0 +1,119 @@
+use flutter_embedder::api::store::EmbeddingStore;
+
+fn store_path(name: &str) -> String {
+    let path = std::env::temp_dir().join(format!("{name}_{}.store", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path.to_string_lossy().into_owned()
+}
+
+#[test]
+fn upsert_query_and_reopen() {
+    let path = store_path("embedding_store");
+    let mut store = EmbeddingStore::open(path.clone()).unwrap();
+    assert!(store.is_empty());
+    store
+        .upsert("a".into(), vec![1.0, 0.0], Some(r#"{"lang":"en"}"#.into()))
+        .unwrap();
+    store
+        .upsert("b".into(), vec![0.0, 1.0], Some(r#"{"lang":"de"}"#.into()))
+        .unwrap();
+    store.upsert("c".into(), vec![1.0, 1.0], None).unwrap();
+    store
+        .upsert("b".into(), vec![0.1, 1.0], Some(r#"{"lang":"de"}"#.into()))
+        .unwrap();
+    assert!(store.delete("c".into()).unwrap());
+    assert!(!store.delete("c".into()).unwrap());
+    assert_eq!(store.stale_records(), 3);
+
+    let hits = store.query(vec![1.0, 0.2], 5, None).unwrap();
+    assert_eq!(
+        hits.iter().map(|h| h.id.as_str()).collect::<Vec<_>>(),
+        ["a", "b"]
+    );
+    let hits = store
+        .query(vec![1.0, 0.2], 5, Some(r#"{"lang":"de"}"#.into()))
+        .unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].metadata_json.as_deref(), Some(r#"{"lang":"de"}"#));
+
+    assert!(store.upsert("d".into(), vec![1.0], None).is_err());
+    assert!(store
+        .upsert("d".into(), vec![1.0, 0.0], Some("{".into()))
+        .is_err());
+    assert!(store.query(vec![1.0, 0.0], 1, Some("[1]".into())).is_err());
+    drop(store);
+
+    let mut store = EmbeddingStore::open(path.clone()).unwrap();
+    assert_eq!(store.len(), 2);
+    assert_eq!(store.get("b".into()).unwrap().vector, vec![0.1, 1.0]);
+    assert!(!store.contains("c".into()));
+
+    store.compact().unwrap();
+    assert_eq!(store.stale_records(), 0);
+    store.upsert("e".into(), vec![0.5, 0.5], None).unwrap();
+    drop(store);
+    let store = EmbeddingStore::open(path.clone()).unwrap();
+    assert_eq!(store.len(), 3);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn torn_write_is_dropped() {
+    let path = store_path("embedding_store_torn");
+    let mut store = EmbeddingStore::open(path.clone()).unwrap();
+    store.upsert("a".into(), vec![1.0, 2.0], None).unwrap();
+    store.upsert("b".into(), vec![3.0, 4.0], None).unwrap();
+    drop(store);
+
+    // Simulate a crash halfway through the last record.
+    let len = std::fs::metadata(&path).unwrap().len();
+    let file = std::fs::OpenOptions::new().write(true).open(&path).unwrap();
+    file.set_len(len - 5).unwrap();
+    drop(file);
+
+    let mut store = EmbeddingStore::open(path.clone()).unwrap();
+    assert_eq!(store.len(), 1);
+    assert!(store.contains("a".into()));
+    store.upsert("c".into(), vec![5.0, 6.0], None).unwrap();
+    drop(store);
+    assert_eq!(EmbeddingStore::open(path.clone()).unwrap().len(), 2);
+
+    std::fs::write(&path, b"not a store").unwrap();
+    assert!(EmbeddingStore::open(path.clone()).is_err());
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn corrupt_record_before_the_end_is_an_error() {
+    let path = store_path("embedding_store_corrupt");
+    let mut store = EmbeddingStore::open(path.clone()).unwrap();
+    store.upsert("a".into(), vec![1.0, 2.0], None).unwrap();
+    store.upsert("b".into(), vec![3.0, 4.0], None).unwrap();
+    drop(store);
+
+    // Flip a byte inside the first record; the second one is intact.
+    let mut bytes = std::fs::read(&path).unwrap();
+    bytes[14] ^= 0xff;
+    std::fs::write(&path, &bytes).unwrap();
+    assert!(EmbeddingStore::open(path.clone()).is_err());
+    assert_eq!(std::fs::read(&path).unwrap(), bytes);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn compact_leaves_sibling_files_alone() {
+    let path = store_path("embedding_store_sibling");
+    let sibling = std::path::Path::new(&path).with_extension("tmp");
+    std::fs::write(&sibling, b"unrelated").unwrap();
+    let mut store = EmbeddingStore::open(path.clone()).unwrap();
+    store.upsert("a".into(), vec![1.0, 2.0], None).unwrap();
+    store.upsert("a".into(), vec![3.0, 4.0], None).unwrap();
+    store.compact().unwrap();
+    drop(store);
+
+    assert_eq!(std::fs::read(&sibling).unwrap(), b"unrelated");
+    let store = EmbeddingStore::open(path.clone()).unwrap();
+    assert_eq!(store.get("a".into()).unwrap().vector, vec![3.0, 4.0]);
+    std::fs::remove_file(sibling).unwrap();
+    std::fs::remove_file(path).unwrap();
+}
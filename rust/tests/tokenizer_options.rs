@@ -0,0 +1,296 @@
+use flutter_embedder::api::tokenizer::{
+    add_special_tokens, char_to_token, count_tokens, count_tokens_batch, disable_padding,
+    disable_truncation, dispose_tokenizer, encode, encode_batch, encode_batch_padded, encode_pair,
+    encode_pair_batch, get_vocab, get_vocab_size, id_to_token, list_tokenizers,
+    load_tokenizer_from_json, set_padding, set_truncation, token_to_chars, token_to_id,
+    tokenizer_info, PaddingStrategy, TruncationInfo, TruncationStrategy, VocabEntry,
+};
+
+/// Word-level tokenizer with a tiny vocabulary and no truncation/padding.
+fn word_tokenizer() -> u64 {
+    let json = r#"{
+        "version": "1.0",
+        "truncation": null,
+        "padding": null,
+        "added_tokens": [],
+        "normalizer": null,
+        "pre_tokenizer": { "type": "Whitespace" },
+        "post_processor": null,
+        "decoder": null,
+        "model": {
+            "type": "WordLevel",
+            "vocab": { "[UNK]": 0, "<pad>": 1, "a": 2, "b": 3 },
+            "unk_token": "[UNK]"
+        }
+    }"#;
+    load_tokenizer_from_json(json.to_string()).unwrap()
+}
+
+#[test]
+fn truncation() {
+    let tokenizer = word_tokenizer();
+    let text = "a b a b a b".to_string();
+    set_truncation(tokenizer, 4, Some(TruncationStrategy::LongestFirst), None).unwrap();
+    assert_eq!(encode(tokenizer, text.clone(), None).unwrap().ids.len(), 4);
+
+    disable_truncation(tokenizer).unwrap();
+    assert_eq!(encode(tokenizer, text.clone(), None).unwrap().ids.len(), 6);
+
+    assert!(set_truncation(tokenizer, 0, None, None).is_err());
+    assert!(set_truncation(u64::MAX, 4, None, None).is_err());
+}
+
+#[test]
+fn padding() {
+    let tokenizer = word_tokenizer();
+    let texts = vec!["a".to_string(), "a b a".to_string()];
+
+    set_padding(
+        tokenizer,
+        PaddingStrategy::BatchLongest,
+        None,
+        None,
+        Some("<pad>".to_string()),
+    )
+    .unwrap();
+    let encodings = encode_batch(tokenizer, texts.clone(), None).unwrap();
+    assert_eq!(encodings[0].ids, vec![2, 1, 1]);
+    assert_eq!(encodings[0].attention_mask, vec![1, 0, 0]);
+
+    set_padding(tokenizer, PaddingStrategy::Fixed, Some(5), None, None).unwrap();
+    let encodings = encode_batch(tokenizer, texts.clone(), None).unwrap();
+    assert!(encodings.iter().all(|e| e.ids.len() == 5));
+    assert_eq!(encodings[1].ids, vec![2, 3, 2, 1, 1]);
+
+    disable_padding(tokenizer).unwrap();
+    let encodings = encode_batch(tokenizer, texts, None).unwrap();
+    assert_eq!(encodings[0].ids.len(), 1);
+
+    assert!(set_padding(
+        tokenizer,
+        PaddingStrategy::BatchLongest,
+        None,
+        None,
+        Some("<missing>".to_string())
+    )
+    .is_err());
+    assert!(set_padding(tokenizer, PaddingStrategy::Fixed, None, None, None).is_err());
+}
+
+#[test]
+fn token_counts() {
+    let tokenizer = word_tokenizer();
+    assert_eq!(
+        count_tokens(tokenizer, "a b a".to_string(), None).unwrap(),
+        3
+    );
+
+    // Padding doesn't count, truncation does.
+    set_padding(tokenizer, PaddingStrategy::Fixed, Some(5), None, None).unwrap();
+    set_truncation(tokenizer, 4, None, None).unwrap();
+    let texts = vec!["a".to_string(), "a b a b a b".to_string()];
+    assert_eq!(
+        count_tokens_batch(tokenizer, texts, None).unwrap(),
+        vec![1, 4]
+    );
+
+    assert!(count_tokens(u64::MAX, "a".to_string(), None).is_err());
+}
+
+#[test]
+fn info_and_dispose() {
+    let tokenizer = word_tokenizer();
+    let info = tokenizer_info(tokenizer).unwrap();
+    assert_eq!(info.vocab_size, 4);
+    assert!(info.truncation.is_none() && info.padding.is_none());
+
+    set_truncation(tokenizer, 8, None, Some(2)).unwrap();
+    set_padding(tokenizer, PaddingStrategy::Fixed, Some(8), Some(1), None).unwrap();
+    let info = tokenizer_info(tokenizer).unwrap();
+    assert_eq!(
+        info.truncation,
+        Some(TruncationInfo {
+            max_length: 8,
+            strategy: TruncationStrategy::LongestFirst,
+            stride: 2,
+        })
+    );
+    let padding = info.padding.unwrap();
+    assert_eq!(padding.strategy, PaddingStrategy::Fixed);
+    assert_eq!((padding.fixed_length, padding.pad_id), (Some(8), 1));
+
+    assert!(list_tokenizers().unwrap().contains(&tokenizer));
+    assert!(dispose_tokenizer(tokenizer).unwrap());
+    assert!(!dispose_tokenizer(tokenizer).unwrap());
+    assert!(!list_tokenizers().unwrap().contains(&tokenizer));
+    assert!(tokenizer_info(tokenizer).is_err());
+}
+
+/// Word-level tokenizer that wraps sequences in `[CLS]`/`[SEP]` like BERT.
+fn bert_tokenizer() -> u64 {
+    let json = r#"{
+        "version": "1.0",
+        "truncation": null,
+        "padding": null,
+        "added_tokens": [],
+        "normalizer": null,
+        "pre_tokenizer": { "type": "Whitespace" },
+        "post_processor": {
+            "type": "BertProcessing",
+            "sep": ["[SEP]", 3],
+            "cls": ["[CLS]", 2]
+        },
+        "decoder": null,
+        "model": {
+            "type": "WordLevel",
+            "vocab": { "[UNK]": 0, "<pad>": 1, "[CLS]": 2, "[SEP]": 3, "héllo": 4, "b": 5 },
+            "unk_token": "[UNK]"
+        }
+    }"#;
+    load_tokenizer_from_json(json.to_string()).unwrap()
+}
+
+#[test]
+fn char_and_token_mapping() {
+    let tokenizer = bert_tokenizer();
+    let text = "héllo  b".to_string();
+    let encoding = encode(tokenizer, text.clone(), None).unwrap();
+    assert_eq!(encoding.ids, vec![2, 4, 5, 3]);
+
+    let span = |index| token_to_chars(text.clone(), encoding.clone(), index).unwrap();
+    assert!(span(0).is_none() && span(3).is_none());
+    let hello = span(1).unwrap();
+    assert_eq!((hello.start, hello.end), (0, 5));
+    let b = span(2).unwrap();
+    assert_eq!((b.start, b.end), (7, 8));
+    assert!(token_to_chars(text.clone(), encoding.clone(), 4).is_err());
+
+    let token = |index| char_to_token(text.clone(), encoding.clone(), index).unwrap();
+    assert_eq!((token(1), token(4), token(7)), (Some(1), Some(1), Some(2)));
+    assert_eq!(token(5), None);
+    assert!(char_to_token(text.clone(), encoding.clone(), 8).is_err());
+    assert!(token_to_chars("hé".to_string(), encoding, 2).is_err());
+}
+
+#[test]
+fn vocab_lookups() {
+    let tokenizer = word_tokenizer();
+    assert_eq!(token_to_id(tokenizer, "b".to_string()).unwrap(), Some(3));
+    assert_eq!(token_to_id(tokenizer, "zzz".to_string()).unwrap(), None);
+    assert_eq!(id_to_token(tokenizer, 1).unwrap().as_deref(), Some("<pad>"));
+    assert_eq!(id_to_token(tokenizer, 99).unwrap(), None);
+
+    add_special_tokens(tokenizer, vec!["<eos>".to_string()]).unwrap();
+    assert_eq!(get_vocab_size(tokenizer, false).unwrap(), 4);
+    assert_eq!(get_vocab_size(tokenizer, true).unwrap(), 5);
+    assert_eq!(
+        token_to_id(tokenizer, "<eos>".to_string()).unwrap(),
+        Some(4)
+    );
+
+    let page = get_vocab(tokenizer, true, 3, 10).unwrap();
+    assert_eq!(
+        page,
+        vec![
+            VocabEntry {
+                id: 3,
+                token: "b".to_string()
+            },
+            VocabEntry {
+                id: 4,
+                token: "<eos>".to_string()
+            },
+        ]
+    );
+    assert_eq!(get_vocab(tokenizer, false, 0, 2).unwrap().len(), 2);
+    assert!(get_vocab(tokenizer, false, 10, 2).unwrap().is_empty());
+    assert!(token_to_id(u64::MAX, "a".to_string()).is_err());
+}
+
+#[test]
+fn pair_encoding() {
+    let tokenizer = bert_tokenizer();
+    let encoding = encode_pair(tokenizer, "héllo".to_string(), "b b".to_string(), None).unwrap();
+    assert_eq!(encoding.ids, vec![2, 4, 3, 5, 5, 3]);
+    assert_eq!(encoding.type_ids, vec![0, 0, 0, 1, 1, 1]);
+    assert_eq!(encoding.special_tokens_mask, vec![1, 0, 1, 0, 0, 1]);
+    assert_eq!((encoding.offsets[4].start, encoding.offsets[4].end), (2, 3));
+
+    let plain = encode_pair(tokenizer, "héllo".to_string(), "b".to_string(), Some(false)).unwrap();
+    assert_eq!(plain.ids, vec![4, 5]);
+    assert_eq!(plain.type_ids, vec![0, 1]);
+
+    let pairs = vec![
+        ("héllo".to_string(), "b".to_string()),
+        ("b".to_string(), "héllo b".to_string()),
+    ];
+    let batch = encode_pair_batch(tokenizer, pairs, None).unwrap();
+    assert_eq!(batch[0].ids, vec![2, 4, 3, 5, 3]);
+    assert_eq!(batch[1].type_ids, vec![0, 0, 0, 1, 1, 1]);
+}
+
+#[test]
+fn word_sequence_ids_and_overflow() {
+    let tokenizer = bert_tokenizer();
+    let encoding = encode_pair(tokenizer, "héllo b".to_string(), "b".to_string(), None).unwrap();
+    assert_eq!(
+        encoding.word_ids,
+        vec![None, Some(0), Some(1), None, Some(0), None]
+    );
+    assert_eq!(
+        encoding.sequence_ids,
+        vec![None, Some(0), Some(0), None, Some(1), None]
+    );
+    assert!(encoding.overflowing.is_empty());
+
+    let tokenizer = word_tokenizer();
+    set_truncation(tokenizer, 3, None, Some(1)).unwrap();
+    let encoding = encode(tokenizer, "a b a b a b".to_string(), None).unwrap();
+    assert_eq!(encoding.ids, vec![2, 3, 2]);
+    let pieces: Vec<Vec<u32>> = encoding.overflowing.iter().map(|o| o.ids.clone()).collect();
+    assert_eq!(pieces, vec![vec![2, 3, 2], vec![2, 3]]);
+    assert_eq!(
+        encoding.overflowing[0].word_ids,
+        vec![Some(2), Some(3), Some(4)]
+    );
+}
+
+#[test]
+fn padded_batch() {
+    let tokenizer = bert_tokenizer();
+    let texts = vec!["héllo b".to_string(), "b".to_string()];
+    let batch = encode_batch_padded(tokenizer, texts.clone(), None).unwrap();
+    assert_eq!(batch.shape, vec![2, 4]);
+    assert_eq!(batch.input_ids, vec![2, 4, 5, 3, 2, 5, 3, 0]);
+    assert_eq!(batch.attention_mask, vec![1, 1, 1, 1, 1, 1, 1, 0]);
+    assert_eq!(batch.token_type_ids, vec![0; 8]);
+
+    // Truncation keeps the special tokens.
+    let batch = encode_batch_padded(tokenizer, texts.clone(), Some(3)).unwrap();
+    assert_eq!(batch.shape, vec![2, 3]);
+    assert_eq!(batch.input_ids, vec![2, 4, 3, 2, 5, 3]);
+
+    set_padding(
+        tokenizer,
+        PaddingStrategy::BatchLongest,
+        None,
+        Some(1),
+        None,
+    )
+    .unwrap();
+    let batch = encode_batch_padded(tokenizer, texts.clone(), Some(5)).unwrap();
+    assert_eq!(batch.shape, vec![2, 5]);
+    assert_eq!(batch.input_ids, vec![2, 4, 5, 3, 1, 2, 5, 3, 1, 1]);
+    assert_eq!(batch.attention_mask, vec![1, 1, 1, 1, 0, 1, 1, 1, 0, 0]);
+
+    // The tokenizer's own truncation is left alone.
+    assert_eq!(
+        encode(tokenizer, texts[0].clone(), None).unwrap().ids.len(),
+        4
+    );
+    assert!(encode_batch_padded(tokenizer, texts, Some(0)).is_err());
+    assert_eq!(
+        encode_batch_padded(tokenizer, vec![], None).unwrap().shape,
+        vec![0, 0]
+    );
+}
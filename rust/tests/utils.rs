@@ -0,0 +1,196 @@
+use flutter_embedder::api::utils::{
+    cosine_similarity_matrix, dot_product, euclidean_distance, find_duplicates, hamming_distance,
+    int8_dot, manhattan_distance, mmr, normalize, normalize_scores, quantize_binary, quantize_int8,
+    reciprocal_rank_fusion, sparse_dot, top_k, weighted_score_fusion, DistanceMetric,
+    ScoreNormalization, SparseVector,
+};
+
+fn approx_eq(a: &[f32], b: &[f32]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-5)
+}
+
+#[test]
+fn score_normalization() {
+    let scores = vec![2.0, 4.0, 6.0, 4.0];
+
+    let min_max = normalize_scores(scores.clone(), ScoreNormalization::MinMax);
+    assert!(approx_eq(&min_max, &[0.0, 0.5, 1.0, 0.5]));
+
+    let z = normalize_scores(scores.clone(), ScoreNormalization::ZScore);
+    assert!(z.iter().sum::<f32>().abs() < 1e-5);
+    assert!(z[2] > z[1] && z[1] > z[0]);
+
+    let pct = normalize_scores(scores, ScoreNormalization::Percentile);
+    assert!(approx_eq(&pct, &[0.0, 0.5, 1.0, 0.5]));
+
+    assert!(normalize_scores(vec![], ScoreNormalization::MinMax).is_empty());
+    assert!(approx_eq(
+        &normalize_scores(vec![3.0, 3.0], ScoreNormalization::MinMax),
+        &[1.0, 1.0]
+    ));
+}
+
+#[test]
+fn sparse_dot_product() {
+    let a = SparseVector {
+        indices: vec![1, 4, 7],
+        values: vec![0.5, 2.0, 1.0],
+    };
+    let b = SparseVector {
+        indices: vec![0, 4, 7, 9],
+        values: vec![3.0, 1.5, -1.0, 2.0],
+    };
+    assert!((sparse_dot(a.clone(), b).unwrap() - 2.0).abs() < 1e-6);
+    assert_eq!(sparse_dot(a.clone(), SparseVector::default()).unwrap(), 0.0);
+
+    let unsorted = SparseVector {
+        indices: vec![4, 1],
+        values: vec![1.0, 1.0],
+    };
+    assert!(sparse_dot(a, unsorted).is_err());
+}
+
+fn results(items: &[(&str, f32)]) -> Vec<(String, f32)> {
+    items.iter().map(|(id, s)| (id.to_string(), *s)).collect()
+}
+
+#[test]
+fn rank_fusion() {
+    let dense = results(&[("a", 0.9), ("b", 0.8), ("c", 0.1)]);
+    let sparse = results(&[("b", 12.0), ("d", 7.0)]);
+
+    let fused = reciprocal_rank_fusion(vec![dense.clone(), sparse.clone()], 60);
+    let ids: Vec<&str> = fused.iter().map(|(id, _)| id.as_str()).collect();
+    assert_eq!(ids, vec!["b", "a", "d", "c"]);
+    assert!((fused[0].1 - (1.0 / 62.0 + 1.0 / 61.0)).abs() < 1e-6);
+
+    let fused = weighted_score_fusion(
+        vec![dense, sparse],
+        vec![0.5, 0.5],
+        ScoreNormalization::MinMax,
+    )
+    .unwrap();
+    assert_eq!(fused[0].0, "b");
+    assert!((fused[0].1 - (0.5 * 0.875 + 0.5)).abs() < 1e-5);
+    assert_eq!(fused[1], ("a".to_string(), 0.5));
+
+    assert!(weighted_score_fusion(vec![vec![]], vec![], ScoreNormalization::MinMax).is_err());
+}
+
+#[test]
+fn quantization() {
+    let a = normalize(&[0.3, -0.7, 0.1, 0.5, -0.2, 0.0, 0.9, -0.4, 0.05]);
+    let b = normalize(&[0.2, -0.6, 0.3, 0.4, 0.1, -0.1, 0.8, -0.5, -0.3]);
+    let exact: f32 = a.iter().zip(&b).map(|(x, y)| x * y).sum();
+
+    let qa = quantize_int8(a.clone());
+    assert_eq!(qa.values.len(), 9);
+    assert_eq!(qa.values.iter().map(|v| v.abs()).max(), Some(127));
+    let approx = int8_dot(qa.clone(), quantize_int8(b.clone())).unwrap();
+    assert!((approx - exact).abs() < 0.01, "{approx} vs {exact}");
+    assert_eq!(quantize_int8(vec![0.0; 3]).values, vec![0, 0, 0]);
+    assert!(int8_dot(qa, quantize_int8(vec![1.0])).is_err());
+
+    let ba = quantize_binary(a);
+    assert_eq!(ba, vec![0b1011_0010, 0b1000_0000]);
+    let bb = quantize_binary(b);
+    assert_eq!(hamming_distance(ba.clone(), ba.clone()).unwrap(), 0);
+    assert_eq!(hamming_distance(ba.clone(), bb).unwrap(), 2);
+    assert!(hamming_distance(ba, vec![0]).is_err());
+}
+
+#[test]
+fn similarity_matrix_and_top_k() {
+    let docs = vec![vec![1.0, 0.0], vec![0.0, 2.0], vec![1.0, 1.0]];
+    let matrix =
+        cosine_similarity_matrix(vec![vec![2.0, 0.0], vec![0.0, 1.0]], docs.clone()).unwrap();
+    assert_eq!(matrix.len(), 2);
+    assert!(approx_eq(&matrix[0], &[1.0, 0.0, 0.5f32.sqrt()]));
+    assert!(approx_eq(&matrix[1], &[0.0, 1.0, 0.5f32.sqrt()]));
+    assert!(cosine_similarity_matrix(vec![vec![1.0]], docs.clone()).is_err());
+
+    let hits = top_k(vec![1.0, 0.1], docs.clone(), 2, DistanceMetric::Cosine).unwrap();
+    assert_eq!(hits.iter().map(|h| h.0).collect::<Vec<_>>(), [0, 2]);
+    // Unnormalized, the longer vector wins on dot product.
+    let hits = top_k(vec![0.1, 1.0], docs.clone(), 5, DistanceMetric::DotProduct).unwrap();
+    assert_eq!(hits.iter().map(|h| h.0).collect::<Vec<_>>(), [1, 2, 0]);
+    assert!(approx_eq(&[hits[0].1], &[2.0]));
+    assert!(top_k(vec![1.0], docs, 1, DistanceMetric::Cosine).is_err());
+}
+
+#[test]
+fn distance_metrics() {
+    let (a, b) = (vec![1.0, 2.0], vec![4.0, -2.0]);
+    assert_eq!(dot_product(a.clone(), b.clone()).unwrap(), 0.0);
+    assert_eq!(euclidean_distance(a.clone(), b.clone()).unwrap(), 5.0);
+    assert_eq!(manhattan_distance(a.clone(), b).unwrap(), 7.0);
+    assert!(euclidean_distance(a, vec![1.0]).is_err());
+
+    // Distances rank the nearest document first, ascending.
+    let docs = vec![vec![3.0, 0.0], vec![1.0, 1.0], vec![0.0, 0.0]];
+    for metric in [DistanceMetric::Euclidean, DistanceMetric::Manhattan] {
+        let hits = top_k(vec![1.0, 0.5], docs.clone(), 2, metric).unwrap();
+        assert_eq!(hits.iter().map(|h| h.0).collect::<Vec<_>>(), [1, 2]);
+        assert!(hits[0].1 <= hits[1].1);
+    }
+}
+
+#[test]
+fn mmr_skips_near_duplicates() {
+    let query = vec![1.0, 0.0, 0.0];
+    let candidates = vec![
+        vec![1.0, 0.1, 0.0],
+        vec![1.0, 0.12, 0.0],
+        vec![0.7, 0.0, 0.7],
+        vec![0.0, 0.0, 1.0],
+    ];
+    let relevance_only = mmr(query.clone(), candidates.clone(), 1.0, 3).unwrap();
+    let indices: Vec<u32> = relevance_only.iter().map(|&(i, _)| i).collect();
+    assert_eq!(indices, [0, 1, 2]);
+
+    let diverse = mmr(query.clone(), candidates.clone(), 0.5, 2).unwrap();
+    let indices: Vec<u32> = diverse.iter().map(|&(i, _)| i).collect();
+    assert_eq!(indices, [0, 2]);
+    assert!((diverse[0].1 - 0.995).abs() < 1e-3);
+
+    assert_eq!(
+        mmr(query.clone(), candidates.clone(), 0.5, 10)
+            .unwrap()
+            .len(),
+        4
+    );
+    assert!(mmr(query.clone(), vec![], 0.5, 3).unwrap().is_empty());
+    assert!(mmr(query.clone(), candidates, 1.5, 3).is_err());
+    assert!(mmr(query, vec![vec![1.0]], 0.5, 1).is_err());
+}
+
+#[test]
+fn find_duplicates_clusters_across_tiles() {
+    // Pseudo-random vectors, nowhere near each other in 32 dimensions.
+    let mut seed = 7u64;
+    let mut next = || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as f32 / (1u64 << 31) as f32 - 0.5
+    };
+    let mut embeddings: Vec<Vec<f32>> = (0..700)
+        .map(|_| (0..32).map(|_| next()).collect())
+        .collect();
+    // 3 and 650 are scaled copies of 10 (different tiles); 20 and 21 nearly
+    // match each other.
+    embeddings[3] = embeddings[10].iter().map(|x| x * 2.0).collect();
+    embeddings[650] = embeddings[10].clone();
+    embeddings[21] = embeddings[20].clone();
+    embeddings[21][0] += 0.01;
+
+    let clusters = find_duplicates(embeddings.clone(), 0.99).unwrap();
+    assert_eq!(clusters, vec![vec![3, 10, 650], vec![20, 21]]);
+    let exact = find_duplicates(embeddings.clone(), 1.0 - 1e-6).unwrap();
+    assert_eq!(exact, vec![vec![3, 10, 650]]);
+
+    assert!(find_duplicates(Vec::new(), 0.9).unwrap().is_empty());
+    assert!(find_duplicates(embeddings.clone(), 0.0).is_err());
+    embeddings[5].pop();
+    assert!(find_duplicates(embeddings, 0.9).is_err());
+}
@@ -0,0 +1,66 @@
+use std::io::Cursor;
+
+use flutter_embedder::api::embeddings::vision::{VisionEmbedder, VisionPreset};
+use flutter_embedder::api::image::RgbaPixels;
+use flutter_embedder::api::ort::init_ort;
+use flutter_embedder::api::utils::cosine_distance;
+
+mod config;
+use config::{init_test_config, DINOV2_MODEL_PATH, ORT_LIB_PATH};
+
+/// A 320x240 image, left half `left` and right half `right`.
+fn split_image(left: [u8; 3], right: [u8; 3]) -> image::RgbImage {
+    image::RgbImage::from_fn(320, 240, |x, _| {
+        image::Rgb(if x < 160 { left } else { right })
+    })
+}
+
+fn png(image: &image::RgbImage) -> Vec<u8> {
+    let mut bytes = Cursor::new(Vec::new());
+    image.write_to(&mut bytes, image::ImageFormat::Png).unwrap();
+    bytes.into_inner()
+}
+
+/// @reference https://huggingface.co/facebook/dinov2-small
+#[test]
+fn dinov2_image_test() {
+    init_test_config();
+    let model_path: String = DINOV2_MODEL_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("vision_ort".to_string(), Some(ort_path)).unwrap();
+    let mut embedder = VisionEmbedder::create(model_path, VisionPreset::Dinov2).unwrap();
+    assert_eq!(embedder.image_size(), 224);
+
+    let red_white = split_image([220, 20, 20], [255, 255, 255]);
+    let embeddings = embedder
+        .embed_images(vec![
+            png(&red_white),
+            png(&split_image([200, 30, 30], [250, 250, 250])),
+            png(&split_image([20, 20, 220], [0, 0, 0])),
+        ])
+        .unwrap();
+    let similar = cosine_distance(embeddings[0].clone(), embeddings[1].clone()).unwrap();
+    let different = cosine_distance(embeddings[0].clone(), embeddings[2].clone()).unwrap();
+    assert!(similar < different, "{similar} vs {different}");
+
+    // Raw RGBA pixels give the same embedding as the encoded image.
+    let rgba = image::DynamicImage::ImageRgb8(red_white).to_rgba8();
+    let raw = embedder
+        .embed_rgba(vec![RgbaPixels {
+            width: rgba.width(),
+            height: rgba.height(),
+            rgba: rgba.into_raw(),
+        }])
+        .unwrap();
+    let distance = cosine_distance(raw[0].clone(), embeddings[0].clone()).unwrap();
+    assert!(distance < 1e-4, "{distance}");
+
+    assert!(embedder
+        .embed_rgba(vec![RgbaPixels {
+            width: 2,
+            height: 2,
+            rgba: vec![0; 3],
+        }])
+        .is_err());
+}
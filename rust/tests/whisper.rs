@@ -0,0 +1,55 @@
+use flutter_embedder::api::audio::read_wav;
+use flutter_embedder::api::audio::whisper::{TranscriptionOptions, WhisperTranscriber};
+use flutter_embedder::api::ort::init_ort;
+
+mod config;
+use config::{
+    init_test_config, ORT_LIB_PATH, SPEECH_SAMPLE_PATH, WHISPER_DECODER_MODEL_PATH,
+    WHISPER_ENCODER_MODEL_PATH, WHISPER_TOKENIZER_PATH,
+};
+
+/// @reference https://huggingface.co/onnx-community/whisper-tiny
+/// `SPEECH_SAMPLE_PATH` is an English WAV recording of "hello world".
+#[test]
+fn whisper_transcription_test() {
+    init_test_config();
+    let encoder_path: String = WHISPER_ENCODER_MODEL_PATH.get().unwrap().into();
+    let decoder_path: String = WHISPER_DECODER_MODEL_PATH.get().unwrap().into();
+    let tokenizer_path: String = WHISPER_TOKENIZER_PATH.get().unwrap().into();
+    let audio_path: String = SPEECH_SAMPLE_PATH.get().unwrap().into();
+    let ort_path: String = ORT_LIB_PATH.get().unwrap().into();
+
+    init_ort("whisper_ort".to_string(), Some(ort_path)).unwrap();
+    let mut transcriber =
+        WhisperTranscriber::create(encoder_path, decoder_path, tokenizer_path).unwrap();
+    let clip = read_wav(audio_path).unwrap();
+
+    let result = transcriber
+        .transcribe(clip.samples.clone(), clip.sample_rate, None)
+        .unwrap();
+    assert_eq!(result.language, "en");
+    let text = result.text.to_lowercase();
+    assert!(text.contains("hello") && text.contains("world"), "{text}");
+
+    let forced = transcriber
+        .transcribe(
+            clip.samples,
+            clip.sample_rate,
+            Some(TranscriptionOptions {
+                language: Some("en".to_string()),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+    assert_eq!(forced.text, result.text);
+
+    let unknown = transcriber.transcribe(
+        vec![0.0; 16_000],
+        16_000,
+        Some(TranscriptionOptions {
+            language: Some("xx".to_string()),
+            ..Default::default()
+        }),
+    );
+    assert!(unknown.is_err());
+}